@@ -0,0 +1,29 @@
+//! Fuzzes the parser and the lowering with arbitrary source files, they should never panic, even
+//! with malformed trees, and should report the errors as diagnostics instead.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sol_driver::RootDb;
+use sol_hir::package::{Package, PackageKind, Version};
+use sol_vfs::SourceFile;
+
+fuzz_target!(|data: &[u8]| {
+    let text = String::from_utf8_lossy(data).into_owned();
+    let db = RootDb::default();
+
+    let file = SourceFile::new(&db, "Main.sol".into(), "Main".into(), text);
+    let source = sol_syntax::parse(&db, file);
+
+    let package = db.register_package(Package::new(
+        &db,
+        /* name    = */ "fuzz".into(),
+        /* version = */ Version(0, 0, 1),
+        /* sources = */ source,
+        /* kind    = */ PackageKind::Binary,
+        /* files   = */ Default::default(),
+        /* exports = */ None,
+    ));
+
+    sol_hir_lowering::hir_lower(&db, package, source);
+});
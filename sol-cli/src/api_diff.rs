@@ -0,0 +1,141 @@
+//! Defines the loading of the APIs of the previous versions of a package, that are compared
+//! against the current one by `sol api-diff`. The previous version is either an artifact
+//! emitted by `sol build --emit api`, or a git revision, like:
+//!
+//! ```sh
+//! sol build --emit api
+//! sol api-diff --baseline target/dev/my-package.api.json
+//! sol api-diff --rev v0.1.0
+//! ```
+
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use sol_driver::RootDb;
+use sol_eyre::{bail, eyre, Context};
+use sol_hir::{
+    api::{package_api, PackageApi, API_FORMAT_VERSION},
+    package::HasManifest,
+};
+
+use crate::{artifacts::TargetDir, build::Manifest};
+
+/// Gets the API of the package in the folder, it fails if the package has errors, as the
+/// signatures of the definitions that can't be resolved aren't reliable.
+pub fn load_api(db: &RootDb, folder: PathBuf) -> sol_eyre::Result<PackageApi> {
+    let mut manifest = Manifest::load_in_folder(db, folder)?;
+    manifest.register_packages()?;
+    manifest.resolve_all_files()?;
+    if manifest.has_errors() {
+        bail!("could not compute the API of the package, due to previous errors");
+    }
+
+    let package = db
+        .all_packages()
+        .into_iter()
+        .find(|package| *package.name(db) == manifest.config.name)
+        .ok_or_else(|| eyre!("could not locate the package"))?;
+
+    Ok(package_api(db, package))
+}
+
+/// Reads the API emitted by `sol build --emit api`.
+pub fn read_baseline(path: &Path) -> sol_eyre::Result<PackageApi> {
+    let text = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+    let api: PackageApi = serde_json::from_str(&text)
+        .wrap_err_with(|| format!("Failed to parse {}", path.display()))?;
+    if api.format_version != API_FORMAT_VERSION {
+        bail!(
+            "the API in {} has the format version {}, but this compiler uses the version {}, \
+             emit it again with `sol build --emit api`",
+            path.display(),
+            api.format_version,
+            API_FORMAT_VERSION
+        );
+    }
+
+    Ok(api)
+}
+
+/// Gets the API of the package at the git revision. The files of the revision are exported
+/// into the cache folder of the target directory, and loaded in a new database, so the
+/// definitions of the two versions doesn't conflict.
+pub fn api_at_rev(
+    target_dir: &TargetDir,
+    project: &Path,
+    rev: &str,
+) -> sol_eyre::Result<PackageApi> {
+    let prefix = git(project, &["rev-parse", "--show-prefix"])?;
+
+    // The revision is resolved to a commit first, so it can't be read as an option by git.
+    let commit = git(project, &[
+        "rev-parse",
+        "--verify",
+        "--end-of-options",
+        &format!("{rev}^{{commit}}"),
+    ])
+    .wrap_err_with(|| format!("`{rev}` isn't a commit"))?;
+    let folder = target_dir.cache().join("api-diff").join(sanitize(rev));
+    target_dir.clean(&folder)?;
+    target_dir.create(folder.clone())?;
+
+    let mut archive = Command::new("git")
+        .current_dir(project)
+        .args(["archive", "--format=tar", "--", commit.trim()])
+        .stdout(Stdio::piped())
+        .spawn()
+        .wrap_err("Failed to run git")?;
+    let extracted = Command::new("tar")
+        .current_dir(&folder)
+        .arg("-x")
+        .stdin(
+            archive
+                .stdout
+                .take()
+                .ok_or_else(|| eyre!("could not read the git archive"))?,
+        )
+        .status()
+        .wrap_err("Failed to run tar")?;
+    let archived = archive.wait().wrap_err("Failed to run git")?;
+    if !archived.success() || !extracted.success() {
+        bail!("could not export the revision `{rev}`");
+    }
+
+    let db = RootDb::default();
+    load_api(&db, folder.join(prefix.trim()))
+}
+
+/// Runs the git command in the folder, returning its output.
+fn git(folder: &Path, args: &[&str]) -> sol_eyre::Result<String> {
+    let output = Command::new("git")
+        .current_dir(folder)
+        .args(args)
+        .output()
+        .wrap_err("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Replaces the characters of the revision that can't be in a folder name, like the `/` of the
+/// branches.
+fn sanitize(rev: &str) -> String {
+    rev.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
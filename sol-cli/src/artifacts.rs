@@ -0,0 +1,115 @@
+//! Defines the layout of the target directory, where the build artifacts are stored, like the
+//! caches, the emitted code of each backend, and the generated documentation.
+//!
+//! The layout is:
+//!
+//! ```text
+//! target/
+//!   cache/            # caches that are shared between the profiles
+//!   doc/              # the generated documentation
+//!   package/          # the published versions, like `<name>-<version>`, with their APIs
+//!   <profile>/
+//!     <backend>/      # the emitted code, like `c`
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use sol_eyre::Context;
+
+/// The backends that emit code into the target directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    C,
+}
+
+impl Backend {
+    /// Gets the name of the folder of the backend, inside the profile folder.
+    pub fn folder_name(self) -> &'static str {
+        match self {
+            Backend::C => "c",
+        }
+    }
+}
+
+/// The target directory of a project, it's the `target` folder in the root of the project, if
+/// it's not overridden by the `--target-dir` flag.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TargetDir {
+    root: PathBuf,
+}
+
+impl TargetDir {
+    /// The default name of the target directory.
+    pub const DEFAULT: &'static str = "target";
+
+    /// Creates the target directory of the project, using the `overridden` path, if any.
+    pub fn new(project_root: &Path, overridden: Option<PathBuf>) -> Self {
+        Self {
+            root: overridden.unwrap_or_else(|| project_root.join(Self::DEFAULT)),
+        }
+    }
+
+    /// Gets the root of the target directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Gets the folder of the caches, that are shared between the profiles.
+    pub fn cache(&self) -> PathBuf {
+        self.root.join("cache")
+    }
+
+    /// Gets the folder of the packages downloaded from the registry, like
+    /// `target/cache/registry/foo-1.2.0`.
+    pub fn registry(&self) -> PathBuf {
+        self.cache().join("registry")
+    }
+
+    /// Gets the folder of the generated documentation.
+    pub fn docs(&self) -> PathBuf {
+        self.root.join("doc")
+    }
+
+    /// Gets the folder of the published versions of the package.
+    pub fn package(&self) -> PathBuf {
+        self.root.join("package")
+    }
+
+    /// Gets the folder of the versions that are being published, they're moved to the
+    /// [`Self::package`] folder after they're uploaded, like `target/package/.staging`.
+    pub fn staging(&self) -> PathBuf {
+        self.package().join(".staging")
+    }
+
+    /// Gets the folder of the profile, like `target/dev`.
+    pub fn profile(&self, profile: &str) -> PathBuf {
+        self.root.join(profile)
+    }
+
+    /// Gets the folder of the emitted code of the backend, for the profile, like
+    /// `target/dev/js`.
+    pub fn emit(&self, profile: &str, backend: Backend) -> PathBuf {
+        self.profile(profile).join(backend.folder_name())
+    }
+
+    /// Creates the folder, and its parents, if they doesn't exist, and returns it.
+    pub fn create(&self, folder: PathBuf) -> sol_eyre::Result<PathBuf> {
+        std::fs::create_dir_all(&folder)
+            .wrap_err_with(|| format!("Failed to create {}", folder.display()))?;
+
+        Ok(folder)
+    }
+
+    /// Removes the folder of the target directory, with all its artifacts, like the
+    /// [`Self::root`] to remove everything. It returns `false` if the folder doesn't exist.
+    pub fn clean(&self, folder: &Path) -> sol_eyre::Result<bool> {
+        if !folder.exists() {
+            return Ok(false);
+        }
+
+        std::fs::remove_dir_all(folder)
+            .wrap_err_with(|| format!("Failed to remove {}", folder.display()))?;
+
+        Ok(true)
+    }
+}
@@ -1,26 +1,70 @@
-use std::{collections::HashMap, fmt::Display, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
 
 use fxhash::FxBuildHasher;
-use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use sol_diagnostic::Diagnostics;
+use sol_diagnostic::{lints::Lints, Diagnostic, Diagnostics};
 use sol_driver::RootDb;
 use sol_eyre::Context;
 use sol_hir::{
-    package::{HasManifest, Package, Version},
+    package::{Exports, HasManifest, Package, ProfileSettings, Version},
     source::HirSource,
+    todos::todo_lint,
+    unused::unused_definition_lint,
 };
 use sol_hir_lowering::hir_lower;
 use sol_syntax::Source;
 use sol_vfs::SourceFile;
 
+use crate::{
+    artifacts::TargetDir, cache::PackageCache, config::CliConfig, hooks::Hooks, plan::BuildPlan,
+};
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
     pub name: String,
     pub version: String,
-    #[serde(default = "source_folder_default")]
-    pub source: String,
+    #[serde(default)]
+    pub source: SourceConfig,
     pub dependencies: HashMap<String, Dependency, FxBuildHasher>,
+
+    /// The lint levels of the project, like `unused_definition = "deny"`, that overrides the
+    /// severity of the diagnostics.
+    #[serde(default)]
+    pub lints: Lints,
+
+    /// The build profiles of the project, like `[profile.dev]` and `[profile.release]`.
+    #[serde(default)]
+    pub profile: Profiles,
+
+    /// The public API of the package, like `modules = ["Data.List"]`. If it's missing, all the
+    /// definitions are exported.
+    pub exports: Option<Exports>,
+
+    /// The commands that are run by `sol build`, before, and after, the compilation.
+    #[serde(default)]
+    pub hooks: Hooks,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Profiles {
+    #[serde(default = "ProfileSettings::dev")]
+    pub dev: ProfileSettings,
+
+    #[serde(default = "ProfileSettings::release")]
+    pub release: ProfileSettings,
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Self {
+            dev: ProfileSettings::dev(),
+            release: ProfileSettings::release(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -37,27 +81,42 @@ impl<'db> Manifest<'db> {
 
     pub fn load_in_folder(db: &'db RootDb, folder: PathBuf) -> sol_eyre::Result<Self> {
         let manifest_path = folder.join(Self::FILE_NAME);
-        let manifest_content = std::fs::read_to_string(manifest_path.clone())
-            .wrap_err_with(|| format!("Unable to find manifest file for folder {folder:?}"))?;
-        let manifest: Config = toml::from_str(&manifest_content)?;
+        let manifest = Self::read_config(folder)?;
         let root_folder = manifest_path.parent().unwrap().to_path_buf();
 
         Ok(Self {
             db,
-            soruce_folder: root_folder.join(&manifest.source),
+            soruce_folder: root_folder.join(manifest.source.folder()),
             root_folder,
             config: manifest,
             diagnostics: Default::default(),
         })
     }
 
+    /// Reads the manifest file of the folder, without loading the packages. It's used to read
+    /// the settings that should be applied to the database before loading, like the profiles.
+    pub fn read_config(folder: PathBuf) -> sol_eyre::Result<Config> {
+        let manifest_path = folder.join(Self::FILE_NAME);
+        let manifest_content = std::fs::read_to_string(manifest_path)
+            .wrap_err_with(|| format!("Unable to find manifest file for folder {folder:?}"))?;
+
+        Ok(toml::from_str(&manifest_content)?)
+    }
+
+    /// Merges the lint levels of the configuration files, that are discovered once by the
+    /// command line, with the ones of the manifest, that override them.
+    pub fn configure(mut self, config: &CliConfig) -> Self {
+        self.config.lints = config.lints(&self.config.lints);
+        self
+    }
+
     pub fn read_file(&mut self, folder: PathBuf, path: PathBuf) -> sol_eyre::Result<Source> {
         let path = folder.join(path);
         let contents = std::fs::read_to_string(&path)
             .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
 
         let name = folder.strip_prefix(&self.soruce_folder)?;
-        let mut name = name
+        let segments = name
             .components()
             .map(|c| c.as_os_str().to_string_lossy().into_owned())
             .chain(std::iter::once(
@@ -67,7 +126,16 @@ impl<'db> Manifest<'db> {
                     .to_string_lossy()
                     .into_owned(),
             ))
-            .join(".");
+            .collect::<Vec<_>>();
+
+        // The files are mapped to modules, so their names should be valid identifiers, and
+        // shouldn't collide with another file on case-insensitive filesystems.
+        segments
+            .iter()
+            .try_for_each(|segment| validate_module_name(segment))?;
+        validate_case_collision(&path)?;
+
+        let mut name = segments.join(".");
 
         // WORKAROUND: If it's the main file, simple use the package name. This
         // is a workaround for the fact that the main file is not in a folder
@@ -79,15 +147,17 @@ impl<'db> Manifest<'db> {
         let file = SourceFile::new(self.db, path, name, contents);
         let cst = sol_syntax::parse(self.db, file);
         let diagnostics = sol_syntax::parse::accumulated::<Diagnostics>(self.db, file);
+        self.db.register_includes(cst);
 
-        self.diagnostics.extend(diagnostics);
+        self.report(diagnostics);
+        self.report(self.db.plugins_after_parse(cst));
 
         Ok(cst)
     }
 
     pub fn as_package(&mut self) -> sol_eyre::Result<Package> {
         let version = parse_version(&self.config.version)?;
-        let source = self.root_folder.join(&self.config.source);
+        let source = self.root_folder.join(self.config.source.folder());
 
         let source = self.read_file(source, PathBuf::from("Main.sol"))?;
 
@@ -98,12 +168,13 @@ impl<'db> Manifest<'db> {
             /* sources = */ source,
             /* kind    = */ sol_hir::package::PackageKind::Binary,
             /* files   = */ Default::default(),
+            /* exports = */ self.config.exports.clone(),
         ))
     }
 
     pub fn register_packages(&mut self) -> sol_eyre::Result<()> {
-        for dependency in self.config.dependencies.values() {
-            let folder = self.root_folder.join(&dependency.path).canonicalize()?;
+        for (name, dependency) in &self.config.dependencies {
+            let folder = self.dependency_folder(name, dependency)?;
             let mut manifest = Manifest::load_in_folder(self.db, folder)?;
             let package = manifest.as_package()?;
 
@@ -117,10 +188,96 @@ impl<'db> Manifest<'db> {
         Ok(())
     }
 
+    /// Gets the folder of the dependency, or the folder that replaces it, like the vendored
+    /// one, if there's any.
+    pub fn dependency_folder(
+        &self,
+        name: &str,
+        dependency: &Dependency,
+    ) -> sol_eyre::Result<PathBuf> {
+        match self.config.source.replacement(name) {
+            Some(folder) => {
+                let folder = self.root_folder.join(folder);
+                folder.canonicalize().wrap_err_with(|| {
+                    format!(
+                        "Failed to find the replacement of `{name}`, {}",
+                        folder.display()
+                    )
+                })
+            }
+            None => dependency.folder(name, &self.root_folder),
+        }
+    }
+
+    /// Creates the [`BuildPlan`] of the package and its dependencies, without lowering or type
+    /// checking them. The files are still read and parsed, to discover the modules.
+    pub fn build_plan(&mut self, profile: &str) -> sol_eyre::Result<BuildPlan> {
+        let mut plan = BuildPlan::new(profile);
+        let root_folder = self
+            .root_folder
+            .canonicalize()
+            .wrap_err_with(|| format!("Failed to find {}", self.root_folder.display()))?;
+        let mut chain = vec![(root_folder, self.config.name.clone())];
+
+        let dependencies = self.plan_dependencies(&mut plan, &mut chain, &mut HashMap::new())?;
+        let package = self.as_package()?;
+        plan.add_package(self.db, package, &dependencies);
+
+        Ok(plan)
+    }
+
+    /// Adds the units of the dependencies to the plan, and of their dependencies, before the
+    /// units that depend on them. It returns the lowering units of the direct dependencies.
+    ///
+    /// The `chain` has the folders, and the names, of the packages that are being planned, to
+    /// find the cyclic dependencies, and `planned` has the lowering units of the packages that
+    /// were already planned, by their folders, so the shared dependencies are planned once.
+    fn plan_dependencies(
+        &self,
+        plan: &mut BuildPlan,
+        chain: &mut Vec<(PathBuf, String)>,
+        planned: &mut HashMap<PathBuf, Vec<usize>>,
+    ) -> sol_eyre::Result<Vec<usize>> {
+        let mut dependencies = vec![];
+
+        for (name, dependency) in &self.config.dependencies {
+            let folder = self.dependency_folder(name, dependency)?;
+            if let Some(position) = chain.iter().position(|(planning, _)| *planning == folder) {
+                let cycle = chain[position..]
+                    .iter()
+                    .chain(std::iter::once(&chain[position]))
+                    .map(|(_, name)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                sol_eyre::bail!("cyclic dependency between the packages {cycle}");
+            }
+            if let Some(units) = planned.get(&folder) {
+                dependencies.extend(units.iter().copied());
+                continue;
+            }
+
+            let mut manifest = Manifest::load_in_folder(self.db, folder.clone())?;
+            chain.push((folder.clone(), manifest.config.name.clone()));
+            let transitive = manifest.plan_dependencies(plan, chain, planned)?;
+            chain.pop();
+
+            let package = manifest.as_package()?;
+            let units = plan.add_package(self.db, package, &transitive);
+            dependencies.extend(units.iter().copied());
+            planned.insert(folder, units);
+        }
+
+        Ok(dependencies)
+    }
+
     pub fn resolve_all_files(&mut self) -> sol_eyre::Result<SourceMap> {
         // Clear diagnostics for new revision
         self.diagnostics = Default::default();
 
+        // The files are elaborated in the environment of the database, so the elaborations of
+        // the definitions that they share are reused, by the other queries too.
+        let global_env = self.db.global_env();
+
         let mut files = im::HashMap::default();
         for package in self.db.all_packages() {
             for file in package.all_files(self.db) {
@@ -128,26 +285,201 @@ impl<'db> Manifest<'db> {
                 let diagnostics = hir_lower::accumulated::<Diagnostics>(self.db, package, file);
 
                 // Add HIR errors' diagnostics to the manifest
-                self.diagnostics.extend(diagnostics);
+                self.report(diagnostics);
+                self.report(self.db.plugins_after_hir_lower(package, hir));
+                self.report(self.db.plugins_after_elaboration(global_env, hir));
+
+                // The compile-time commands, like `#eval` and `#static_assert`, are executed
+                // after the file is elaborated, and their outputs are reported too.
+                self.report(self.db.run_commands(hir));
 
                 files.insert(package, hir);
             }
+
+            // The remaining `todo`s and the unused definitions are listed per package, after all
+            // of its files are lowered.
+            self.report(todo_lint(self.db, package));
+            self.report(unused_definition_lint(self.db, package));
         }
 
         Ok(SourceMap(files))
     }
+
+    /// Checks if there's any error diagnostic, the warnings and advices doesn't stop the build.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(Diagnostic::is_error)
+    }
+
+    /// Adds the diagnostics to the manifest, applying the lint levels of the project.
+    fn report(&mut self, diagnostics: Vec<Diagnostic>) {
+        let diagnostics = diagnostics
+            .into_iter()
+            .filter_map(|diagnostic| self.config.lints.apply(diagnostic))
+            .collect::<Vec<_>>();
+
+        self.diagnostics.extend(diagnostics);
+    }
+}
+
+/// The sources of the project, that is either the source folder, like `source = "src"`, or a
+/// table with the folders that replace the dependencies, like the vendored ones:
+///
+/// ```toml
+/// [source]
+/// folder = "src"
+///
+/// [source.replace]
+/// foo = "vendor/foo"
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum SourceConfig {
+    Folder(String),
+    Table {
+        #[serde(default = "source_folder_default")]
+        folder: String,
+
+        /// The folders that replace the dependencies, relative to the manifest.
+        #[serde(default)]
+        replace: HashMap<String, String, FxBuildHasher>,
+    },
+}
+
+impl SourceConfig {
+    /// Gets the source folder, relative to the manifest.
+    pub fn folder(&self) -> &str {
+        match self {
+            SourceConfig::Folder(folder) | SourceConfig::Table { folder, .. } => folder,
+        }
+    }
+
+    /// Gets the folder that replaces the dependency, if there's any.
+    pub fn replacement(&self, name: &str) -> Option<&str> {
+        match self {
+            SourceConfig::Folder(_) => None,
+            SourceConfig::Table { replace, .. } => replace.get(name).map(String::as_str),
+        }
+    }
+}
+
+impl Default for SourceConfig {
+    fn default() -> Self {
+        SourceConfig::Folder(source_folder_default())
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Dependency {
-    pub path: String,
+    /// The folder of a local dependency, relative to the manifest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// The version of a dependency from the registry, that is downloaded by `sol add` into the
+    /// package cache.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl Dependency {
+    /// Gets the folder of the dependency named `name`, of the package in the `root_folder`. The
+    /// dependencies from the registry are in the registry folder of the default target
+    /// directory, they're unpacked from the package cache if they're missing, without using
+    /// the network.
+    pub fn folder(&self, name: &str, root_folder: &Path) -> sol_eyre::Result<PathBuf> {
+        let folder = match (&self.path, &self.version) {
+            (Some(path), _) => root_folder.join(path),
+            (None, Some(version)) => {
+                let folder = TargetDir::new(root_folder, None)
+                    .registry()
+                    .join(format!("{name}-{version}"));
+                if !folder.exists() {
+                    PackageCache::new(true)?
+                        .fetch(None, name, parse_version(version)?, &folder)
+                        .wrap_err_with(|| {
+                            format!(
+                                "The dependency `{name}` isn't downloaded, run `sol add {name}`"
+                            )
+                        })?;
+                }
+                folder
+            }
+            (None, None) => {
+                sol_eyre::bail!("the dependency `{name}` should have a `path`, or a `version`")
+            }
+        };
+
+        folder
+            .canonicalize()
+            .wrap_err_with(|| format!("Failed to find the dependency `{name}`"))
+    }
 }
 
 fn source_folder_default() -> String {
     "src".to_string()
 }
 
-fn parse_version(version: &str) -> sol_eyre::Result<Version> {
+/// Validates that the segment of the module name, that is a folder or a file name, is a valid
+/// identifier, without spaces or leading digits.
+fn validate_module_name(segment: &str) -> sol_eyre::Result<()> {
+    let mut chars = segment.chars();
+    let valid = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_');
+
+    if !valid {
+        sol_eyre::bail!(
+            "invalid module name `{segment}`, it should be a valid identifier, like `{}`",
+            expected_module_name(segment)
+        );
+    }
+
+    Ok(())
+}
+
+/// Suggests a valid module name for the segment, joining its words in pascal case, and removing
+/// the leading digits, like `1 my file` into `MyFile`.
+fn expected_module_name(segment: &str) -> String {
+    let name = segment
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+    let name = name.trim_start_matches(|c: char| c.is_ascii_digit());
+
+    match name.is_empty() {
+        true => "Module".into(),
+        false => name.into(),
+    }
+}
+
+/// Validates that there's no other file in the same folder, whose name only differs in the
+/// case, as they would be the same module on case-insensitive filesystems.
+fn validate_case_collision(path: &Path) -> sol_eyre::Result<()> {
+    let (Some(folder), Some(file_name)) = (path.parent(), path.file_name()) else {
+        return Ok(());
+    };
+    let file_name = file_name.to_string_lossy();
+
+    for entry in std::fs::read_dir(folder)?.flatten() {
+        let other = entry.file_name().to_string_lossy().into_owned();
+        if other != file_name && other.to_lowercase() == file_name.to_lowercase() {
+            sol_eyre::bail!(
+                "the module file `{}` collides with `{other}` on case-insensitive filesystems, \
+                 rename one of them",
+                path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses the version of the manifest, like `1.2.0`.
+pub fn parse_version(version: &str) -> sol_eyre::Result<Version> {
     let mut split = version.split('.');
     let major = split.next().unwrap();
     let minor = split.next().unwrap();
@@ -0,0 +1,178 @@
+//! Defines the package cache, that is shared by the projects of the user, and stores the
+//! tarballs downloaded from the registry by the hashes of their contents, so each version is
+//! downloaded only once, and the projects can be built offline.
+//!
+//! The layout is:
+//!
+//! ```text
+//! ~/.cache/sol/
+//!   blobs/<sha256>.tar.gz  # the tarballs, by the hashes of their contents
+//!   index/<name>/<version> # the hash of the tarball of the version
+//! ```
+//!
+//! It's in `$SOL_CACHE_DIR`, or in `$XDG_CACHE_HOME/sol`, if they're set.
+
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use sha2::{Digest, Sha256};
+use sol_eyre::{bail, eyre, Context};
+use sol_hir::package::Version;
+
+use crate::{build::parse_version, registry::Registry};
+
+/// The shared cache of the packages downloaded from the registry.
+#[derive(Debug, Clone)]
+pub struct PackageCache {
+    root: PathBuf,
+
+    /// Doesn't use the network, so the packages that aren't cached can't be fetched.
+    offline: bool,
+}
+
+impl PackageCache {
+    /// The variable that overrides the folder of the cache.
+    pub const DIR_VARIABLE: &'static str = "SOL_CACHE_DIR";
+
+    /// Creates the cache of the user, it fails if the home folder can't be found.
+    pub fn new(offline: bool) -> sol_eyre::Result<Self> {
+        let root = match std::env::var_os(Self::DIR_VARIABLE) {
+            Some(folder) => PathBuf::from(folder),
+            None => match std::env::var_os("XDG_CACHE_HOME") {
+                Some(folder) => PathBuf::from(folder).join("sol"),
+                None => std::env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".cache").join("sol"))
+                    .ok_or_else(|| {
+                        eyre!(
+                            "could not find the package cache, set it with the `{}` variable",
+                            Self::DIR_VARIABLE
+                        )
+                    })?,
+            },
+        };
+
+        Ok(Self { root, offline })
+    }
+
+    /// Gets the root folder of the cache.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Gets the file with the hash of the tarball of the version of the package.
+    fn index(&self, name: &str, version: Version) -> PathBuf {
+        self.root
+            .join("index")
+            .join(name)
+            .join(format!("{version:?}"))
+    }
+
+    /// Gets the tarball with the hash.
+    fn blob(&self, hash: &str) -> PathBuf {
+        self.root.join("blobs").join(format!("{hash}.tar.gz"))
+    }
+
+    /// Gets the tarball of the version of the package, if it's cached.
+    pub fn get(&self, name: &str, version: Version) -> Option<PathBuf> {
+        let hash = std::fs::read_to_string(self.index(name, version)).ok()?;
+        let blob = self.blob(hash.trim());
+
+        blob.exists().then_some(blob)
+    }
+
+    /// Gets the cached versions of the package.
+    pub fn versions(&self, name: &str) -> sol_eyre::Result<Vec<Version>> {
+        let folder = self.root.join("index").join(name);
+        if !folder.exists() {
+            return Ok(vec![]);
+        }
+
+        std::fs::read_dir(&folder)?
+            .map(|entry| parse_version(&entry?.file_name().to_string_lossy()))
+            .collect()
+    }
+
+    /// Stores the tarball of the version of the package, by the hash of its contents, and
+    /// returns its file.
+    pub fn insert(
+        &self,
+        name: &str,
+        version: Version,
+        tarball: &[u8],
+    ) -> sol_eyre::Result<PathBuf> {
+        let hash = Sha256::digest(tarball)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+
+        let blob = self.blob(&hash);
+        if !blob.exists() {
+            // Writes into a temporary file first, so the blobs are never partially written.
+            let temporary = blob.with_extension("partial");
+            std::fs::create_dir_all(self.root.join("blobs"))?;
+            std::fs::write(&temporary, tarball)?;
+            std::fs::rename(&temporary, &blob)
+                .wrap_err_with(|| format!("Failed to write {}", blob.display()))?;
+        }
+
+        let index = self.index(name, version);
+        std::fs::create_dir_all(index.parent().unwrap())?;
+        std::fs::write(&index, hash)
+            .wrap_err_with(|| format!("Failed to write {}", index.display()))?;
+
+        Ok(blob)
+    }
+
+    /// Unpacks the version of the package into the folder, downloading it from the registry if
+    /// it isn't cached. It fails fast if it isn't cached, and the cache is offline.
+    pub fn fetch(
+        &self,
+        registry: Option<&Registry>,
+        name: &str,
+        version: Version,
+        folder: &Path,
+    ) -> sol_eyre::Result<()> {
+        let tarball = match self.get(name, version) {
+            Some(tarball) => tarball,
+            None if self.offline => bail!(
+                "`{name}@{version:?}` isn't in the package cache, {}, and it can't be downloaded \
+                 offline, run the command again without `--offline`",
+                self.root.display()
+            ),
+            None => {
+                let Some(registry) = registry else {
+                    bail!(
+                        "`{name}@{version:?}` isn't in the package cache, set the registry to \
+                         download it with `--registry`, the `{}` variable, or `.sol/config.toml`",
+                        Registry::URL_VARIABLE
+                    );
+                };
+
+                self.insert(name, version, &registry.download(name, version)?)?
+            }
+        };
+
+        let file = std::fs::File::open(&tarball)
+            .wrap_err_with(|| format!("Failed to read {}", tarball.display()))?;
+        std::fs::create_dir_all(folder)
+            .wrap_err_with(|| format!("Failed to create {}", folder.display()))?;
+        tar::Archive::new(GzDecoder::new(file))
+            .unpack(folder)
+            .wrap_err_with(|| format!("Failed to unpack `{name}@{version:?}`"))?;
+
+        Ok(())
+    }
+
+    /// Removes the cache, with all the downloaded packages. It returns `false` if the cache
+    /// doesn't exist.
+    pub fn clean(&self) -> sol_eyre::Result<bool> {
+        if !self.root.exists() {
+            return Ok(false);
+        }
+
+        std::fs::remove_dir_all(&self.root)
+            .wrap_err_with(|| format!("Failed to remove {}", self.root.display()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,312 @@
+//! Defines the configuration of the CLI, that is discovered in the `.sol/config.toml` files of
+//! the current folder, of its parents, and of the home folder, like:
+//!
+//! ```toml
+//! [build]
+//! release = true
+//! offline = true
+//! target-dir = "../target"
+//! plugins = ["plugins/libcheck.so"]
+//!
+//! [registry]
+//! url = "https://registry.example.com"
+//!
+//! [lints]
+//! unused_definition = "deny"
+//! ```
+//!
+//! The settings are merged with the precedence, from the highest to the lowest:
+//!
+//! 1. The flags, like `--target-dir`, or `--no-release`.
+//! 2. The environment variables, like `SOL_REGISTRY`.
+//! 3. The nearest configuration file, then the ones of the parent folders.
+//! 4. The user configuration, `~/.sol/config.toml`.
+//! 5. The defaults.
+//!
+//! The plugins, and the `[registry]` are only read from the user configuration, as the ones of
+//! a cloned project could load any library, or send the token to any registry, so they're
+//! ignored, with a warning, in the other files. The plugins of the flags are loaded after the
+//! ones of the configuration. The `[lints]` of the manifest of the package override the ones
+//! of the configuration. The relative paths are resolved against the folder that has the
+//! `.sol` folder.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use sol_diagnostic::lints::Lints;
+use sol_eyre::Context;
+
+use crate::Cli;
+
+/// The folder with the configuration file.
+pub const CONFIG_FOLDER: &str = ".sol";
+
+/// The name of the configuration file, in the [`CONFIG_FOLDER`].
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// The configuration of the CLI, merged from the discovered files.
+#[derive(Deserialize, Default, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct CliConfig {
+    /// The default flags of the commands.
+    pub build: BuildConfig,
+
+    /// The default registry, of `sol add` and `sol publish`.
+    pub registry: RegistryConfig,
+
+    /// The default lint levels, like `unused_definition = "deny"`.
+    pub lints: Lints,
+}
+
+/// The `[build]` table of the configuration.
+#[derive(Deserialize, Default, Debug, Clone)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct BuildConfig {
+    /// Builds with the `release` profile, like `--release`.
+    pub release: Option<bool>,
+
+    /// Doesn't use the network, like `--offline`.
+    pub offline: Option<bool>,
+
+    /// The target directory, like `--target-dir`.
+    pub target_dir: Option<PathBuf>,
+
+    /// The compiler plugins, like `--plugin`. Only read from the user configuration.
+    pub plugins: Vec<PathBuf>,
+}
+
+/// The `[registry]` table of the configuration. Only read from the user configuration.
+#[derive(Deserialize, Default, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct RegistryConfig {
+    /// The URL of the registry, like `--registry`.
+    pub url: Option<String>,
+
+    /// The token to publish to the registry, like `--token`.
+    pub token: Option<String>,
+}
+
+impl CliConfig {
+    /// Gets the user configuration file, `~/.sol/config.toml`, it's `None` if the home folder
+    /// can't be found.
+    pub fn user_path() -> Option<PathBuf> {
+        std::env::var_os("HOME").map(|home| {
+            PathBuf::from(home)
+                .join(CONFIG_FOLDER)
+                .join(CONFIG_FILE_NAME)
+        })
+    }
+
+    /// Discovers the configuration files of the folder, and of its parents, merging them over
+    /// the user configuration, so the nearest ones override the settings of the farthest ones.
+    pub fn discover(folder: &Path) -> sol_eyre::Result<Self> {
+        let user_path = Self::user_path().filter(|path| path.is_file());
+        let mut config = match &user_path {
+            Some(path) => Self::read(path)?,
+            None => Self::default(),
+        };
+
+        let folders = folder.ancestors().collect::<Vec<_>>();
+        for folder in folders.into_iter().rev() {
+            let path = folder.join(CONFIG_FOLDER).join(CONFIG_FILE_NAME);
+            if path.is_file() && Some(&path) != user_path.as_ref() {
+                config = config.merge(Self::read(&path)?.untrusted(&path));
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Drops the settings that are only read from the user configuration, warning about them,
+    /// as the file can come from a cloned project.
+    fn untrusted(mut self, path: &Path) -> Self {
+        let registry = self.registry.url.is_some() || self.registry.token.is_some();
+        if !self.build.plugins.is_empty() || registry {
+            eprintln!(
+                "warning: ignoring the `plugins` and the `[registry]` of {}, they're only read \
+                 from the user configuration",
+                path.display()
+            );
+        }
+
+        self.build.plugins.clear();
+        self.registry = RegistryConfig::default();
+        self
+    }
+
+    /// Reads the configuration file, resolving its relative paths against the folder that has
+    /// the `.sol` folder.
+    pub fn read(path: &Path) -> sol_eyre::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        let mut config: Self = toml::from_str(&text)
+            .wrap_err_with(|| format!("Failed to parse {}", path.display()))?;
+
+        let root = path
+            .parent()
+            .and_then(Path::parent)
+            .unwrap_or(Path::new(""));
+        if let Some(target_dir) = &mut config.build.target_dir {
+            *target_dir = root.join(&*target_dir);
+        }
+        for plugin in &mut config.build.plugins {
+            *plugin = root.join(&*plugin);
+        }
+
+        Ok(config)
+    }
+
+    /// Merges the `nearer` configuration into this one, overriding its settings.
+    fn merge(mut self, nearer: Self) -> Self {
+        self.build.release = nearer.build.release.or(self.build.release);
+        self.build.offline = nearer.build.offline.or(self.build.offline);
+        self.build.target_dir = nearer.build.target_dir.or(self.build.target_dir);
+        self.build.plugins.extend(nearer.build.plugins);
+        self.registry.url = nearer.registry.url.or(self.registry.url);
+        self.registry.token = nearer.registry.token.or(self.registry.token);
+        self.lints.0.extend(nearer.lints.0);
+        self
+    }
+
+    /// Applies the configuration to the flags, that have precedence over it. The `--no-*`
+    /// flags disable the settings that the configuration enables.
+    pub fn apply(&self, mut cli: Cli) -> Cli {
+        cli.release = flag(cli.release, cli.no_release, self.build.release);
+        cli.offline = flag(cli.offline, cli.no_offline, self.build.offline);
+        cli.target_dir = cli.target_dir.or_else(|| self.build.target_dir.clone());
+        cli.plugins = self
+            .build
+            .plugins
+            .iter()
+            .cloned()
+            .chain(cli.plugins)
+            .collect();
+        cli
+    }
+
+    /// Gets the lint levels of the manifest, merged over the ones of the configuration.
+    pub fn lints(&self, manifest: &Lints) -> Lints {
+        let mut lints = self.lints.clone();
+        lints.0.extend(manifest.0.clone());
+        lints
+    }
+}
+
+/// Resolves the flag that can be enabled, like `--release`, or disabled, like `--no-release`,
+/// falling back to the configuration.
+fn flag(enabled: bool, disabled: bool, config: Option<bool>) -> bool {
+    match (enabled, disabled) {
+        (true, _) => true,
+        (_, true) => false,
+        _ => config.unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use sol_diagnostic::lints::LintLevel;
+
+    use super::*;
+
+    fn config(text: &str) -> CliConfig {
+        toml::from_str(text).unwrap()
+    }
+
+    /// The user configuration, that is the farthest one.
+    fn user_config() -> CliConfig {
+        config(
+            r#"
+[build]
+release = true
+offline = true
+plugins = ["user.so"]
+
+[registry]
+url = "https://registry.example.com"
+
+[lints]
+unused_definition = "warn"
+todo = "allow"
+"#,
+        )
+    }
+
+    #[test]
+    fn test_merge_nearer_overrides() {
+        let project = config(
+            r#"
+[build]
+release = false
+target-dir = "out"
+
+[lints]
+unused_definition = "deny"
+"#,
+        );
+        let merged = user_config().merge(project);
+
+        assert_eq!(merged.build.release, Some(false));
+        assert_eq!(merged.build.offline, Some(true));
+        assert_eq!(merged.build.target_dir, Some(PathBuf::from("out")));
+        assert_eq!(merged.build.plugins, vec![PathBuf::from("user.so")]);
+        assert_eq!(
+            merged.registry.url.as_deref(),
+            Some("https://registry.example.com")
+        );
+        assert_eq!(
+            merged.lints.level("unused_definition"),
+            Some(LintLevel::Deny)
+        );
+        assert_eq!(merged.lints.level("todo"), Some(LintLevel::Allow));
+    }
+
+    #[test]
+    fn test_unknown_lint() {
+        let error = toml::from_str::<CliConfig>("[lints]\nunused_import = \"allow\"\n")
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("unknown lint `unused_import`"));
+    }
+
+    #[test]
+    fn test_flag() {
+        assert!(flag(true, false, Some(false)));
+        assert!(!flag(false, true, Some(true)));
+        assert!(flag(false, false, Some(true)));
+        assert!(!flag(false, false, None));
+    }
+
+    #[test]
+    fn test_cli_overrides_config() {
+        let cli = Cli::parse_from([
+            "sol",
+            "--no-release",
+            "--target-dir",
+            "cli",
+            "--plugin",
+            "cli.so",
+            "type-check",
+        ]);
+        let cli = user_config().apply(cli);
+
+        assert!(!cli.release);
+        assert!(cli.offline);
+        assert_eq!(cli.target_dir, Some(PathBuf::from("cli")));
+        assert_eq!(cli.plugins, vec![
+            PathBuf::from("user.so"),
+            PathBuf::from("cli.so")
+        ]);
+    }
+
+    #[test]
+    fn test_config_fills_missing_flags() {
+        let cli = Cli::parse_from(["sol", "type-check"]);
+        let cli = config("[build]\ntarget-dir = \"out\"").apply(cli);
+
+        assert!(!cli.release);
+        assert!(!cli.offline);
+        assert_eq!(cli.target_dir, Some(PathBuf::from("out")));
+    }
+}
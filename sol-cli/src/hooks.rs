@@ -0,0 +1,121 @@
+//! Defines the hooks of the build, that are the commands of the `[hooks]` table of the manifest,
+//! that are run by `sol build` before, and after, the compilation, like:
+//!
+//! ```toml
+//! [hooks]
+//! pre-build = "python3 scripts/generate.py"
+//! post-build = "cp target/dev/c/*.h include/"
+//! ```
+//!
+//! The commands are run by the shell, in the root of the package, with the variables:
+//!
+//! ```text
+//! SOL_PACKAGE_NAME     # the name of the package
+//! SOL_PACKAGE_VERSION  # the version of the package
+//! SOL_MANIFEST_DIR     # the root of the package, with the manifest
+//! SOL_SOURCE_DIR       # the source folder of the package
+//! SOL_PROFILE          # the profile, like `dev` or `release`
+//! SOL_TARGET_DIR       # the target directory
+//! SOL_OUT_DIR          # the folder of the profile, in the target directory
+//! SOL_EMIT             # the artifact of `--emit`, like `header`, if any
+//! ```
+//!
+//! So the pre-build hook can generate the Sol sources, or the assets, before they're loaded.
+
+use std::{fmt::Display, process::Command};
+
+use serde::{Deserialize, Serialize};
+use sol_eyre::{bail, Context};
+
+use crate::{artifacts::TargetDir, build::Manifest};
+
+/// The `[hooks]` table of the manifest.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Hooks {
+    /// The command that is run before the sources are loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_build: Option<String>,
+
+    /// The command that is run after the artifacts are emitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_build: Option<String>,
+}
+
+/// The hooks that `sol build` runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hook {
+    PreBuild,
+    PostBuild,
+}
+
+impl Display for Hook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hook::PreBuild => write!(f, "pre-build"),
+            Hook::PostBuild => write!(f, "post-build"),
+        }
+    }
+}
+
+impl Hooks {
+    /// Gets the command of the hook, if it's configured.
+    pub fn command(&self, hook: Hook) -> Option<&str> {
+        match hook {
+            Hook::PreBuild => self.pre_build.as_deref(),
+            Hook::PostBuild => self.post_build.as_deref(),
+        }
+    }
+}
+
+/// Runs the hook of the package, if it's configured. It fails if the command fails, so the
+/// build is stopped.
+pub fn run(
+    manifest: &Manifest,
+    hook: Hook,
+    profile: &str,
+    target_dir: &TargetDir,
+    emit: Option<&str>,
+) -> sol_eyre::Result<()> {
+    let Some(command) = manifest.config.hooks.command(hook) else {
+        return Ok(());
+    };
+
+    println!("Running {hook} hook: {command}");
+
+    let mut process = shell(command);
+    process
+        .current_dir(&manifest.root_folder)
+        .env("SOL_PACKAGE_NAME", &manifest.config.name)
+        .env("SOL_PACKAGE_VERSION", &manifest.config.version)
+        .env("SOL_MANIFEST_DIR", &manifest.root_folder)
+        .env("SOL_SOURCE_DIR", &manifest.soruce_folder)
+        .env("SOL_PROFILE", profile)
+        .env("SOL_TARGET_DIR", target_dir.root())
+        .env("SOL_OUT_DIR", target_dir.profile(profile));
+    if let Some(emit) = emit {
+        process.env("SOL_EMIT", emit);
+    }
+
+    let status = process
+        .status()
+        .wrap_err_with(|| format!("Failed to run the {hook} hook `{command}`"))?;
+    if !status.success() {
+        bail!("the {hook} hook `{command}` failed with {status}");
+    }
+
+    Ok(())
+}
+
+/// Creates the process of the shell, that runs the command.
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut process = Command::new("cmd");
+        process.args(["/C", command]);
+        process
+    } else {
+        let mut process = Command::new("sh");
+        process.args(["-c", command]);
+        process
+    }
+}
@@ -1,9 +1,20 @@
+use std::path::PathBuf;
+
 use clap::*;
 use itertools::Itertools;
 use sol_driver::RootDb;
 use sol_eyre::eyre;
+use sol_hir::package::HasManifest;
+use sol_vfs::{line_index::PositionEncoding, SourceFile};
 
-use crate::build::Manifest;
+use crate::{
+    artifacts::{Backend, TargetDir},
+    build::Manifest,
+    cache::PackageCache,
+    config::CliConfig,
+    hooks::Hook,
+    registry::{Registry, RegistryArgs},
+};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -11,6 +22,31 @@ use crate::build::Manifest;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Command,
+
+    /// Builds with the `release` profile, instead of the `dev` profile.
+    #[clap(long, global = true, overrides_with = "no_release")]
+    pub release: bool,
+
+    /// Builds with the `dev` profile, even if the configuration enables the `release` one.
+    #[clap(long, global = true, overrides_with = "release")]
+    pub no_release: bool,
+
+    /// The directory where the build artifacts are stored, instead of the `target` folder of
+    /// the project.
+    #[clap(long, global = true)]
+    pub target_dir: Option<PathBuf>,
+
+    /// Doesn't use the network, so the dependencies should be in the package cache.
+    #[clap(long, global = true, overrides_with = "no_offline")]
+    pub offline: bool,
+
+    /// Uses the network, even if the configuration enables the offline mode.
+    #[clap(long, global = true, overrides_with = "offline")]
+    pub no_offline: bool,
+
+    /// Loads the compiler plugin of the shared library, it can be repeated.
+    #[clap(long = "plugin", global = true)]
+    pub plugins: Vec<PathBuf>,
 }
 
 #[derive(Subcommand, Debug, Clone)]
@@ -23,21 +59,219 @@ pub enum Command {
         package: String,
     },
     TypeCheck,
+
+    /// Builds the package, checking its files.
+    Build {
+        /// Doesn't execute the build.
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Prints the build plan as JSON, describing each unit of work, so external build
+        /// systems can orchestrate the compilation.
+        #[clap(long, requires = "dry_run")]
+        plan: bool,
+
+        /// Emits an additional artifact, like the C header of the exported functions.
+        #[clap(long, value_enum)]
+        emit: Option<Emit>,
+    },
+
+    /// Finds the symbols of the workspace, whose names fuzzy-matches the given name.
+    Find {
+        name: String,
+    },
+
+    /// Generates the bindings of the functions exported with `@export("name")`, to call them
+    /// from other languages.
+    Bindgen {
+        #[command(subcommand)]
+        language: BindgenLanguage,
+    },
+
+    /// Searches the expressions that structurally match the pattern, like `$x + 0`, and
+    /// replaces them with the replacement, like `$x`.
+    Sed {
+        pattern: String,
+        replacement: String,
+
+        /// Writes the replacements into the files, instead of only printing them.
+        #[clap(short, long)]
+        write: bool,
+    },
+
+    /// Formats the files of the package.
+    Fmt {
+        /// Sorts the `using` top-levels, merges the duplicated ones, removes the unused ones,
+        /// and groups them by their origins.
+        #[clap(long)]
+        organize_imports: bool,
+
+        /// Only prints the files that would be changed, without writing them, and fails if
+        /// there's any.
+        #[clap(long)]
+        check: bool,
+    },
+
+    /// Reports the statistics of each package, like the counts of modules and definitions,
+    /// and the largest functions.
+    Stats {
+        /// The number of the largest functions to report.
+        #[clap(long, default_value_t = 5)]
+        top: usize,
+    },
+
+    /// Inspects the syntax trees of the Sol files.
+    Ast {
+        #[command(subcommand)]
+        command: AstCommand,
+    },
+
+    /// Compares the exported signatures of the package against a previous version of it, and
+    /// reports the breaking changes, like the removed definitions and the changed types.
+    ApiDiff {
+        /// The API of the previous version, emitted by `sol build --emit api`.
+        #[clap(long, conflicts_with = "rev", required_unless_present = "rev")]
+        baseline: Option<PathBuf>,
+
+        /// The git revision of the previous version, like a tag or a commit.
+        #[clap(long)]
+        rev: Option<String>,
+    },
+
+    /// Publishes the package into the target directory, with its API, after checking that it
+    /// builds, and uploads it to the registry, if there's one configured.
+    Publish {
+        /// Refuses to publish if the bump of the version doesn't allow the changes of the API
+        /// since the previous version, like removing a definition in a minor bump.
+        #[clap(long)]
+        check_semver: bool,
+
+        /// The git revision of the previous version, instead of the latest published one.
+        #[clap(long, requires = "check_semver")]
+        rev: Option<String>,
+
+        #[command(flatten)]
+        registry: RegistryArgs,
+    },
+
+    /// Adds a dependency from the registry to the manifest, and downloads it.
+    Add {
+        /// The package, with an optional version requirement, like `foo@1.2`, `foo@^1.2` or
+        /// `foo@~1.2`.
+        package: String,
+
+        #[command(flatten)]
+        registry: RegistryArgs,
+    },
+
+    /// Copies the dependencies into the `vendor` folder, and replaces them in the manifest, so
+    /// the builds doesn't need the network, neither the other folders.
+    Vendor {
+        /// The folder of the vendored dependencies, relative to the manifest.
+        #[clap(long, default_value = vendor::VENDOR_FOLDER)]
+        folder: PathBuf,
+    },
+
+    /// Manages the package cache, that is shared by the projects.
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+
+    /// Removes the target directory, with all the build artifacts.
+    Clean {
+        /// Removes only the generated documentation.
+        #[clap(long)]
+        doc: bool,
+    },
+}
+
+/// The commands to inspect the syntax trees.
+#[derive(Subcommand, Debug, Clone)]
+pub enum AstCommand {
+    /// Runs a tree-sitter query against the file, and prints the captures with their ranges.
+    Query {
+        /// The Sol file to parse.
+        file: PathBuf,
+
+        /// The file with the tree-sitter query, like `(clause name: (path) @name)`.
+        query: PathBuf,
+    },
+}
+
+/// The commands to manage the package cache.
+#[derive(Subcommand, Debug, Clone)]
+pub enum CacheCommand {
+    /// Removes the package cache, with all the downloaded packages.
+    Clean,
+}
+
+/// The languages that the bindings can be generated for.
+#[derive(Subcommand, Debug, Clone)]
+pub enum BindgenLanguage {
+    /// Generates a Rust module, with wrappers of the exported functions.
+    Rust {
+        /// The file to write the bindings, they are printed if it's missing.
+        #[clap(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// The additional artifacts that the build can emit.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emit {
+    /// The C header of the functions exported with `@export("name")`.
+    Header,
+
+    /// The low-level intermediate representation, that is shared by the backends.
+    Lir,
+
+    /// The signatures of the exported definitions, to be compared by `sol api-diff`.
+    Api,
 }
 
+pub mod api_diff;
+pub mod artifacts;
 pub mod build;
+pub mod cache;
+pub mod config;
+pub mod hooks;
+pub mod plan;
+pub mod publish;
+pub mod registry;
+pub mod vendor;
 
 fn main() -> sol_eyre::Result<()> {
-    let cli = Cli::parse();
-    let db = RootDb::default();
+    // The flags have precedence over the `.sol/config.toml` files of the current folder, and
+    // of the user.
+    let config = CliConfig::discover(&std::env::current_dir()?)?;
+    let cli = config.apply(Cli::parse());
+    let mut db = RootDb::default();
+    let profile = if cli.release { "release" } else { "dev" };
+    let target_dir = TargetDir::new(&std::env::current_dir()?, cli.target_dir.clone());
+
+    for plugin in &cli.plugins {
+        db.load_plugin(plugin)?;
+    }
+
+    // Selects the profile before loading the packages, so the queries doesn't need to be
+    // invalidated.
+    if let Ok(config) = Manifest::read_config(std::env::current_dir()?) {
+        if cli.release {
+            db.set_profile(profile, config.profile.release);
+        } else {
+            db.set_profile(profile, config.profile.dev);
+        }
+    }
 
     match cli.command {
         Command::Js { package, .. } => {
-            let mut manifest = Manifest::load_in_folder(&db, std::env::current_dir()?)?;
+            let mut manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
             manifest.register_packages()?;
 
             let source_map = manifest.resolve_all_files()?;
-            if manifest.diagnostics.is_empty() {
+            if !manifest.has_errors() {
                 let current_source = source_map
                     .get_in_db(manifest.db, package)
                     .ok_or_else(|| eyre!("could not locate the package"))?;
@@ -46,6 +280,419 @@ fn main() -> sol_eyre::Result<()> {
             }
         }
         Command::TypeCheck => todo!(),
+        Command::Build {
+            dry_run,
+            plan,
+            emit,
+        } => {
+            let mut manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
+            if dry_run {
+                let build_plan = manifest.build_plan(profile)?;
+                if plan {
+                    println!("{}", serde_json::to_string_pretty(&build_plan)?);
+                }
+            } else {
+                // The hooks are run before the sources are loaded, so they can generate them.
+                let emitted = emit.and_then(|emit| emit.to_possible_value());
+                let emitted = emitted.as_ref().map(|emit| emit.get_name());
+                hooks::run(&manifest, Hook::PreBuild, profile, &target_dir, emitted)?;
+
+                manifest.register_packages()?;
+                manifest.resolve_all_files()?;
+                if manifest.has_errors() {
+                    sol_eyre::bail!("could not build the package, due to previous errors");
+                }
+
+                let package = db
+                    .all_packages()
+                    .into_iter()
+                    .find(|package| *package.name(&db) == manifest.config.name)
+                    .ok_or_else(|| eyre!("could not locate the package"))?;
+
+                let reachability = db.reachability(package);
+                if !reachability.pruned.is_empty() {
+                    println!("{reachability}");
+                    for (definition, nodes) in &reachability.pruned {
+                        log::info!("pruned `{}`, with {nodes} nodes", definition.to_string(&db));
+                    }
+                }
+
+                let erasures = db.erasures(package);
+                if !erasures.diagnostics.is_empty() {
+                    for diagnostic in &erasures.diagnostics {
+                        eprintln!("{diagnostic:?}");
+                    }
+
+                    sol_eyre::bail!("could not build the package, due to previous errors");
+                }
+
+                let specialized = db.specialize(package);
+                if !specialized.is_empty() {
+                    println!("Specialized {} instantiations", specialized.len());
+                }
+
+                if emit == Some(Emit::Header) {
+                    let output = target_dir.create(target_dir.emit(profile, Backend::C))?;
+                    let path = output.join(format!("{}.h", manifest.config.name));
+
+                    std::fs::write(&path, db.c_header(package))?;
+                    println!("Emitted {}", path.display());
+                }
+
+                if emit == Some(Emit::Lir) {
+                    let output = target_dir.create(target_dir.profile(profile))?;
+                    let path = output.join(format!("{}.lir", manifest.config.name));
+
+                    std::fs::write(&path, db.lir_program(package).to_string())?;
+                    println!("Emitted {}", path.display());
+                }
+
+                if emit == Some(Emit::Api) {
+                    let output = target_dir.create(target_dir.profile(profile))?;
+                    let path = output.join(format!("{}.api.json", manifest.config.name));
+                    let api = sol_hir::api::package_api(&db, package);
+
+                    std::fs::write(&path, serde_json::to_string_pretty(&api)?)?;
+                    println!("Emitted {}", path.display());
+                }
+
+                hooks::run(&manifest, Hook::PostBuild, profile, &target_dir, emitted)?;
+            }
+        }
+        Command::Find { name } => {
+            let mut manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
+            manifest.register_packages()?;
+            manifest.resolve_all_files()?;
+
+            let query = sol_hir::symbols::SymbolQuery::new(&db, name);
+            for symbol in sol_hir::symbols::workspace_symbols(&db, query) {
+                let Some(source) = symbol.location.source() else {
+                    continue;
+                };
+
+                let offset = symbol.location.start().offset();
+                let line_col = source
+                    .file(&db)
+                    .line_col(&db, offset, PositionEncoding::Utf32)
+                    .unwrap_or_default();
+
+                println!(
+                    "{:?} {} in {} at {}:{}:{}",
+                    symbol.kind,
+                    symbol.name,
+                    symbol.container,
+                    source.file_path(&db).display(),
+                    line_col.line + 1,
+                    line_col.column + 1,
+                );
+            }
+        }
+        Command::Bindgen {
+            language: BindgenLanguage::Rust { output },
+        } => {
+            let mut manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
+            manifest.register_packages()?;
+            manifest.resolve_all_files()?;
+
+            let package = db
+                .all_packages()
+                .into_iter()
+                .find(|package| *package.name(&db) == manifest.config.name)
+                .ok_or_else(|| eyre!("could not locate the package"))?;
+            let bindings = db.rust_bindings(package);
+
+            match output {
+                Some(path) => std::fs::write(path, bindings)?,
+                None => print!("{bindings}"),
+            }
+        }
+        Command::Sed {
+            pattern,
+            replacement,
+            write,
+        } => {
+            let mut manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
+            manifest.register_packages()?;
+            manifest.resolve_all_files()?;
+
+            let package = db
+                .all_packages()
+                .into_iter()
+                .find(|package| *package.name(&db) == manifest.config.name)
+                .ok_or_else(|| eyre!("could not locate the package"))?;
+
+            let edits = db
+                .sed(package, &pattern, &replacement)?
+                .into_iter()
+                .filter_map(|edit| Some((edit.old_location.source()?, edit)))
+                .into_group_map();
+
+            for (source, mut edits) in edits {
+                // Applies the edits from the end of the file, so the offsets of the next edits
+                // are still valid.
+                edits.sort_by_key(|edit| std::cmp::Reverse(edit.old_location.start().offset()));
+
+                let mut text = source.source_text(&db).clone();
+                let line_index = source.file(&db).line_index(&db);
+                for edit in edits {
+                    let start = edit.old_location.start().offset();
+                    let end = edit.old_location.end().offset();
+                    let line_col = line_index
+                        .line_col(start, PositionEncoding::Utf32)
+                        .unwrap_or_default();
+
+                    println!(
+                        "{}:{}:{}: {} => {}",
+                        source.file_path(&db).display(),
+                        line_col.line + 1,
+                        line_col.column + 1,
+                        &text[start..end],
+                        edit.new_text,
+                    );
+                    text.replace_range(start..end, &edit.new_text);
+                }
+
+                if write {
+                    std::fs::write(source.file_path(&db), text)?;
+                }
+            }
+        }
+        Command::Fmt {
+            organize_imports,
+            check,
+        } => {
+            if !organize_imports {
+                sol_eyre::bail!("only the `--organize-imports` formatting is supported");
+            }
+
+            let mut manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
+            manifest.register_packages()?;
+            manifest.resolve_all_files()?;
+
+            let package = db
+                .all_packages()
+                .into_iter()
+                .find(|package| *package.name(&db) == manifest.config.name)
+                .ok_or_else(|| eyre!("could not locate the package"))?;
+
+            let mut unorganized = 0;
+            for file in package.all_files(&db) {
+                let hir = sol_hir_lowering::hir_lower(&db, package, file);
+                let mut edits = sol_hir::imports::organize_imports(&db, hir);
+                if edits.is_empty() {
+                    continue;
+                }
+
+                // Applies the edits from the end of the file, so the offsets of the next edits
+                // are still valid.
+                edits.sort_by_key(|edit| std::cmp::Reverse(edit.location.start().offset()));
+
+                let mut text = file.source_text(&db).clone();
+                for edit in edits {
+                    let start = edit.location.start().offset();
+                    let end = edit.location.end().offset();
+                    text.replace_range(start..end, &edit.new_text);
+                }
+
+                println!("Organized the imports of {}", file.file_path(&db).display());
+                if !check {
+                    std::fs::write(file.file_path(&db), text)?;
+                }
+                unorganized += 1;
+            }
+
+            if check && unorganized > 0 {
+                sol_eyre::bail!("the imports of {unorganized} files aren't organized");
+            }
+        }
+        Command::Stats { top } => {
+            let mut manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
+            manifest.register_packages()?;
+            manifest.resolve_all_files()?;
+
+            let mut packages = db.all_packages();
+            packages.sort_by_key(|package| package.name(&db).clone());
+
+            for package in packages {
+                let stats = db.package_stats(package);
+
+                println!("{}", stats.name);
+                println!("  modules: {}", stats.modules);
+                println!("  lines: {}", stats.lines);
+                println!("  definitions: {}", stats.definitions);
+                println!("  inductives: {}", stats.inductives);
+                println!("  elaboration errors: {}", stats.elaboration_errors);
+                println!("  largest functions:");
+                for (name, nodes) in stats.functions.iter().take(top) {
+                    println!("    {name}: {nodes} nodes");
+                }
+            }
+        }
+        Command::Ast {
+            command: AstCommand::Query { file, query },
+        } => {
+            let text = std::fs::read_to_string(&file)?;
+            let query = std::fs::read_to_string(query)?;
+            let module = file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+
+            let source = sol_syntax::parse(&db, SourceFile::new(&db, file.clone(), module, text));
+
+            for capture in source.query(&db, &query)? {
+                println!(
+                    "{}:{}:{}-{}:{} @{} {:?}",
+                    file.display(),
+                    capture.start.row + 1,
+                    capture.start.column + 1,
+                    capture.end.row + 1,
+                    capture.end.column + 1,
+                    capture.name,
+                    capture.text,
+                );
+            }
+        }
+        Command::ApiDiff { baseline, rev } => {
+            let project = std::env::current_dir()?;
+            let old = match (baseline, rev) {
+                (Some(path), _) => api_diff::read_baseline(&path)?,
+                (None, Some(rev)) => api_diff::api_at_rev(&target_dir, &project, &rev)?,
+                (None, None) => unreachable!("required by the command line parser"),
+            };
+            let new = api_diff::load_api(&db, project)?;
+
+            let changes = sol_hir::api::diff(&old, &new);
+            for change in &changes {
+                println!("{change}");
+            }
+
+            let breaking = changes.iter().filter(|change| change.is_breaking()).count();
+            if breaking > 0 {
+                sol_eyre::bail!(
+                    "found {breaking} breaking changes in the API of `{}`",
+                    new.package
+                );
+            }
+            if changes.is_empty() {
+                println!("The API of `{}` didn't change", new.package);
+            }
+        }
+        Command::Publish {
+            check_semver,
+            rev,
+            registry,
+        } => {
+            let project = std::env::current_dir()?;
+            let registry = Registry::new(registry, &config.registry);
+            if cli.offline && registry.is_some() {
+                sol_eyre::bail!("can't publish to the registry offline, run without `--offline`");
+            }
+
+            let api = api_diff::load_api(&db, project.clone())?;
+            let manifest = Manifest::load_in_folder(&db, project.clone())?.configure(&config);
+            let version = build::parse_version(&api.version)?;
+
+            if check_semver {
+                let previous = match (rev, &registry) {
+                    (Some(rev), _) => Some(api_diff::api_at_rev(&target_dir, &project, &rev)?),
+                    (None, Some(registry)) => publish::previous_registry_api(
+                        registry,
+                        &PackageCache::new(cli.offline)?,
+                        &target_dir,
+                        &api.package,
+                        version,
+                    )?,
+                    (None, None) => publish::previous_api(&target_dir, &api.package, version)?,
+                };
+
+                match previous {
+                    Some(previous) => publish::check_semver(&previous, &api)?,
+                    None => println!("No previous version of `{}` to check", api.package),
+                }
+            }
+
+            // The version is staged until it's uploaded, so a failed upload can be retried.
+            let staged = publish::stage(&target_dir, &manifest, &api)?;
+            if let Some(registry) = &registry {
+                registry.publish(&api.package, version, &staged.tarball)?;
+            }
+            let tarball = staged.commit(&target_dir)?;
+            println!("Published {}", tarball.display());
+
+            match registry {
+                Some(_) => println!("Uploaded {}@{}", api.package, api.version),
+                None => println!(
+                    "No registry configured, set it with `--registry`, the `{}` variable, or \
+                     `~/.sol/config.toml`",
+                    Registry::URL_VARIABLE
+                ),
+            }
+        }
+        Command::Add { package, registry } => {
+            let project = std::env::current_dir()?;
+            let cache = PackageCache::new(cli.offline)?;
+            let registry = Registry::new(registry, &config.registry);
+
+            // The versions are only found in the package cache, when offline.
+            let (name, requirement) = registry::parse_requirement(&package)?;
+            let versions = match &registry {
+                _ if cli.offline => cache.versions(&name)?,
+                Some(registry) => registry.versions(&name)?,
+                None => sol_eyre::bail!(
+                    "no registry configured, set it with `--registry`, the `{}` variable, or \
+                     `~/.sol/config.toml`",
+                    Registry::URL_VARIABLE
+                ),
+            };
+            let version = registry::select_version(&versions, &requirement)
+                .ok_or_else(|| eyre!("no version of `{name}` matches `{package}`"))?;
+
+            let folder = target_dir.registry().join(format!("{name}-{version:?}"));
+            if !folder.exists() {
+                cache.fetch(registry.as_ref(), &name, version, &folder)?;
+            }
+            registry::add_dependency(&project.join(Manifest::FILE_NAME), &name, version)?;
+
+            // Resolves the dependencies again, so the new one is checked.
+            let mut manifest = Manifest::load_in_folder(&db, project)?.configure(&config);
+            manifest.register_packages()?;
+            println!("Added {name}@{version:?}");
+        }
+        Command::Vendor { folder } => {
+            let manifest =
+                Manifest::load_in_folder(&db, std::env::current_dir()?)?.configure(&config);
+            let vendored = vendor::vendor(&manifest, &folder)?;
+            vendor::replace_sources(&manifest.root_folder.join(Manifest::FILE_NAME), &vendored)?;
+
+            for (name, folder) in &vendored {
+                println!("Vendored {name} into {}", folder.display());
+            }
+        }
+        Command::Cache {
+            command: CacheCommand::Clean,
+        } => {
+            let cache = PackageCache::new(cli.offline)?;
+            if cache.clean()? {
+                println!("Removed {}", cache.root().display());
+            }
+        }
+        Command::Clean { doc } => {
+            let folder = match doc {
+                true => target_dir.docs(),
+                false => target_dir.root().to_path_buf(),
+            };
+
+            if target_dir.clean(&folder)? {
+                println!("Removed {}", folder.display());
+            }
+        }
     }
     Ok(())
 }
@@ -0,0 +1,96 @@
+//! Defines the build plan, that describes each unit of work of the build, without executing it,
+//! so external build systems can orchestrate the compilation. It's printed as JSON by
+//! `sol build --dry-run --plan`.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+use sol_driver::RootDb;
+use sol_hir::package::Package;
+
+/// The phase of the compilation that a [`Unit`] executes.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum Phase {
+    /// Parses the file into the concrete syntax tree.
+    Parse,
+
+    /// Lowers the concrete syntax tree into the high-level intermediate representation, and
+    /// resolves the names.
+    Lower,
+
+    /// Elaborates the high-level intermediate representation, checking its types.
+    TypeCheck,
+}
+
+/// A unit of work of the build, it's executed after all its dependencies.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Unit {
+    pub id: usize,
+    pub package: String,
+    pub file: PathBuf,
+    pub module: String,
+    pub phase: Phase,
+
+    /// The ids of the units that should be executed before this unit.
+    pub dependencies: Vec<usize>,
+}
+
+/// The build plan, with all the units of work, sorted in an order that they can be executed.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuildPlan {
+    pub profile: String,
+    pub units: Vec<Unit>,
+}
+
+impl BuildPlan {
+    /// Creates an empty build plan for the profile.
+    pub fn new(profile: &str) -> Self {
+        Self {
+            profile: profile.into(),
+            units: vec![],
+        }
+    }
+
+    /// Adds the units of all files of the package. The lowering of the files depends on the
+    /// given `dependencies`, that are the lowering units of the dependency packages, as the
+    /// names are resolved against them.
+    ///
+    /// It returns the ids of the lowering units of the package.
+    pub fn add_package(
+        &mut self,
+        db: &RootDb,
+        package: Package,
+        dependencies: &[usize],
+    ) -> Vec<usize> {
+        let mut lowered = vec![];
+
+        for file in package.all_files(db) {
+            let mut unit = |phase: Phase, after: Vec<usize>| {
+                let id = self.units.len();
+                self.units.push(Unit {
+                    id,
+                    package: package.name(db).clone(),
+                    file: file.file_path(db).clone(),
+                    module: file.module_name(db).clone(),
+                    phase,
+                    dependencies: after,
+                });
+                id
+            };
+
+            let parse = unit(Phase::Parse, vec![]);
+            let lower = unit(
+                Phase::Lower,
+                std::iter::once(parse)
+                    .chain(dependencies.iter().copied())
+                    .collect(),
+            );
+            unit(Phase::TypeCheck, vec![lower]);
+
+            lowered.push(lower);
+        }
+
+        lowered
+    }
+}
@@ -0,0 +1,232 @@
+//! Defines the publishing of the packages, that copies the manifest, and the sources, of the
+//! package into the target directory, with its API, like:
+//!
+//! ```text
+//! target/package/my-package-1.2.0/
+//!   sol.toml
+//!   api.json
+//!   src/
+//! ```
+//!
+//! The folder is packed into a tarball, `target/package/my-package-1.2.0.tar.gz`, that is
+//! uploaded to the registry, if there's one configured. Both are staged in
+//! `target/package/.staging`, and only moved when the upload succeeds, so a failed upload
+//! doesn't mark the version as published.
+//!
+//! The API of the previous published version is the baseline of `sol publish --check-semver`,
+//! that refuses to publish a version whose bump doesn't allow the changes of the API, like
+//! removing a definition without bumping the major version.
+
+use std::path::{Path, PathBuf};
+
+use sol_eyre::{bail, Context};
+use sol_hir::{
+    api::{ApiChange, PackageApi, VersionBump},
+    package::Version,
+};
+
+use crate::{
+    api_diff::read_baseline,
+    artifacts::TargetDir,
+    build::{parse_version, Manifest},
+    cache::PackageCache,
+    registry::{pack, Registry},
+};
+
+/// The name of the file with the API, in the folder of a published version.
+pub const API_FILE_NAME: &str = "api.json";
+
+/// Finds the API of the latest published version of the package that is older than the
+/// `version`, it returns `None` if it wasn't published yet.
+pub fn previous_api(
+    target_dir: &TargetDir,
+    name: &str,
+    version: Version,
+) -> sol_eyre::Result<Option<PackageApi>> {
+    let folder = target_dir.package();
+    if !folder.exists() {
+        return Ok(None);
+    }
+
+    let mut previous = None;
+    for entry in std::fs::read_dir(&folder)? {
+        let path = entry?.path().join(API_FILE_NAME);
+        if !path.exists() {
+            continue;
+        }
+
+        let api = read_baseline(&path)?;
+        let api_version = parse_version(&api.version)?;
+        if api.package != name || api_version >= version {
+            continue;
+        }
+        if previous
+            .as_ref()
+            .map_or(true, |(latest, _)| api_version > *latest)
+        {
+            previous = Some((api_version, api));
+        }
+    }
+
+    Ok(previous.map(|(_, api)| api))
+}
+
+/// Finds the API of the latest version of the package in the registry that is older than the
+/// `version`, unpacking it into the registry folder of the target directory. It returns `None`
+/// if it wasn't published yet.
+pub fn previous_registry_api(
+    registry: &Registry,
+    cache: &PackageCache,
+    target_dir: &TargetDir,
+    name: &str,
+    version: Version,
+) -> sol_eyre::Result<Option<PackageApi>> {
+    let versions = registry.versions(name)?;
+    let Some(previous) = versions.into_iter().filter(|other| *other < version).max() else {
+        return Ok(None);
+    };
+
+    let folder = target_dir.registry().join(format!("{name}-{previous:?}"));
+    if !folder.exists() {
+        cache.fetch(Some(registry), name, previous, &folder)?;
+    }
+
+    read_baseline(&folder.join(API_FILE_NAME)).map(Some)
+}
+
+/// Checks that the bump from the `old` version to the `new` one allows the changes of the API,
+/// printing the changes classified by their exports.
+pub fn check_semver(old: &PackageApi, new: &PackageApi) -> sol_eyre::Result<()> {
+    let changes = sol_hir::api::diff(old, new);
+    for change in &changes {
+        let class = match change {
+            ApiChange::Added(_) => "addition",
+            _ => "breaking",
+        };
+        println!("{class}: {change}");
+    }
+
+    let Some(bump) =
+        VersionBump::between(parse_version(&old.version)?, parse_version(&new.version)?)
+    else {
+        bail!(
+            "the version {} of `{}` isn't greater than the published version {}",
+            new.version,
+            new.package,
+            old.version
+        );
+    };
+
+    let required = VersionBump::required(&changes);
+    if bump < required {
+        bail!(
+            "the version {} of `{}` is a {bump} bump from {}, but the changes of the API \
+             require a {required} bump",
+            new.version,
+            new.package,
+            old.version
+        );
+    }
+
+    Ok(())
+}
+
+/// A version of the package that is staged to be published, with its folder, and its tarball,
+/// in the [`TargetDir::staging`] folder. It's only moved to the folder of its version by
+/// [`StagedPackage::commit`], after the upload, so a failed upload can be retried.
+pub struct StagedPackage {
+    /// The name of the folder of the version, like `my-package-1.2.0`.
+    pub name: String,
+    pub folder: PathBuf,
+    pub tarball: PathBuf,
+}
+
+/// Copies the manifest, and the sources, of the package into the staging folder of its
+/// version, with its API, and packs it into a tarball. The leftovers of a failed publish of
+/// the same version are replaced.
+pub fn stage(
+    target_dir: &TargetDir,
+    manifest: &Manifest,
+    api: &PackageApi,
+) -> sol_eyre::Result<StagedPackage> {
+    let name = format!("{}-{}", manifest.config.name, manifest.config.version);
+    if target_dir.package().join(&name).exists() {
+        bail!(
+            "the version {} of `{}` is already published",
+            manifest.config.version,
+            manifest.config.name
+        );
+    }
+
+    let folder = target_dir.staging().join(&name);
+    if folder.exists() {
+        std::fs::remove_dir_all(&folder)
+            .wrap_err_with(|| format!("Failed to remove {}", folder.display()))?;
+    }
+
+    let folder = target_dir.create(folder)?;
+    std::fs::copy(
+        manifest.root_folder.join(Manifest::FILE_NAME),
+        folder.join(Manifest::FILE_NAME),
+    )?;
+    copy_dir(
+        &manifest.soruce_folder,
+        &folder.join(manifest.config.source.folder()),
+        target_dir.root(),
+    )?;
+    std::fs::write(
+        folder.join(API_FILE_NAME),
+        serde_json::to_string_pretty(api)?,
+    )?;
+
+    let tarball = target_dir.staging().join(format!("{name}.tar.gz"));
+    pack(&folder, &tarball)?;
+
+    Ok(StagedPackage {
+        name,
+        folder,
+        tarball,
+    })
+}
+
+impl StagedPackage {
+    /// Moves the staged version into the folder of its version, marking it as published, and
+    /// returns the path of its tarball.
+    pub fn commit(self, target_dir: &TargetDir) -> sol_eyre::Result<PathBuf> {
+        let folder = target_dir.package().join(&self.name);
+        std::fs::rename(&self.folder, &folder)
+            .wrap_err_with(|| format!("Failed to move {}", self.folder.display()))?;
+
+        let tarball = target_dir.package().join(format!("{}.tar.gz", self.name));
+        std::fs::rename(&self.tarball, &tarball)
+            .wrap_err_with(|| format!("Failed to move {}", self.tarball.display()))?;
+
+        Ok(tarball)
+    }
+}
+
+/// Copies the folder, and its sub-folders, into the `target`, except the `excluded` folder, like
+/// the target directory when the sources are in the root of the project.
+pub fn copy_dir(source: &Path, target: &Path, excluded: &Path) -> sol_eyre::Result<()> {
+    std::fs::create_dir_all(target)
+        .wrap_err_with(|| format!("Failed to create {}", target.display()))?;
+
+    for entry in std::fs::read_dir(source)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        if path == excluded {
+            continue;
+        }
+
+        if path.is_dir() {
+            copy_dir(&path, &target.join(name), excluded)?;
+        } else {
+            std::fs::copy(&path, target.join(name))
+                .wrap_err_with(|| format!("Failed to copy {}", path.display()))?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,348 @@
+//! Defines the client of the package registry, that stores the published versions of the
+//! packages as tarballs, with their manifests. The registry is configured by the `--registry`
+//! flag, or the `SOL_REGISTRY` variable, and the publishing is authenticated by the `--token`
+//! flag, or the `SOL_REGISTRY_TOKEN` variable. Both can be set in the `[registry]` table of the
+//! user configuration, `~/.sol/config.toml`, and its token is only sent to its registry, like:
+//!
+//! ```sh
+//! SOL_REGISTRY_TOKEN=... sol publish --registry https://registry.example.com
+//! sol add foo@1.2 --registry https://registry.example.com
+//! ```
+//!
+//! The registry has the endpoints:
+//!
+//! ```text
+//! GET /api/v1/packages/<name>                     # {"versions": ["1.2.0", ...]}
+//! GET /api/v1/packages/<name>/<version>/download  # the tarball of the version
+//! PUT /api/v1/packages/<name>/<version>           # publishes the tarball, authenticated
+//! ```
+
+use std::{fs::File, io::Read, path::Path};
+
+use clap::Args;
+use flate2::{write::GzEncoder, Compression};
+use serde::Deserialize;
+use sol_eyre::{bail, eyre, Context};
+use sol_hir::package::Version;
+
+use crate::{build::parse_version, config::RegistryConfig};
+
+/// The options of the registry, shared by the commands that use it.
+#[derive(Args, Debug, Clone)]
+pub struct RegistryArgs {
+    /// The URL of the registry, it's read from the `SOL_REGISTRY` variable if it's missing.
+    #[clap(long)]
+    pub registry: Option<String>,
+
+    /// The token to publish to the registry, it's read from the `SOL_REGISTRY_TOKEN` variable
+    /// if it's missing.
+    #[clap(long)]
+    pub token: Option<String>,
+}
+
+/// The response of the registry with the published versions of a package.
+#[derive(Deserialize, Debug, Clone)]
+struct PackageVersions {
+    versions: Vec<String>,
+}
+
+/// A client of the registry.
+#[derive(Debug, Clone)]
+pub struct Registry {
+    url: String,
+    token: Option<String>,
+}
+
+impl Registry {
+    /// The variable with the token to publish to the registry.
+    pub const TOKEN_VARIABLE: &'static str = "SOL_REGISTRY_TOKEN";
+    /// The variable with the URL of the registry.
+    pub const URL_VARIABLE: &'static str = "SOL_REGISTRY";
+
+    /// Creates the client from the options, the environment variables, or the configuration,
+    /// in this order. It returns `None` if there's no registry configured.
+    ///
+    /// The token of the configuration is only used with the URL of the configuration, so it's
+    /// never sent to a registry of another source.
+    pub fn new(args: RegistryArgs, config: &RegistryConfig) -> Option<Self> {
+        let token = args
+            .token
+            .or_else(|| std::env::var(Self::TOKEN_VARIABLE).ok());
+        let url = args
+            .registry
+            .or_else(|| std::env::var(Self::URL_VARIABLE).ok());
+        let (url, token) = match url {
+            Some(url) => (url, token),
+            None => (config.url.clone()?, token.or_else(|| config.token.clone())),
+        };
+
+        Some(Self {
+            url: url.trim_end_matches('/').to_string(),
+            token,
+        })
+    }
+
+    /// Gets the URL of the endpoint of the package.
+    fn endpoint(&self, name: &str) -> String {
+        format!("{}/api/v1/packages/{name}", self.url)
+    }
+
+    /// Gets the published versions of the package.
+    pub fn versions(&self, name: &str) -> sol_eyre::Result<Vec<Version>> {
+        let response: PackageVersions = ureq::get(&self.endpoint(name))
+            .call()
+            .wrap_err_with(|| format!("Failed to find `{name}` in the registry {}", self.url))?
+            .into_json()?;
+
+        parse_published_versions(&response.versions)
+    }
+
+    /// Downloads the tarball of the version of the package.
+    pub fn download(&self, name: &str, version: Version) -> sol_eyre::Result<Vec<u8>> {
+        let url = format!("{}/{version:?}/download", self.endpoint(name));
+        let response = ureq::get(&url)
+            .call()
+            .wrap_err_with(|| format!("Failed to download `{name}@{version:?}`"))?;
+
+        let mut contents = vec![];
+        response
+            .into_reader()
+            .read_to_end(&mut contents)
+            .wrap_err_with(|| format!("Failed to download `{name}@{version:?}`"))?;
+
+        Ok(contents)
+    }
+
+    /// Publishes the tarball of the version of the package, authenticated by the token.
+    pub fn publish(&self, name: &str, version: Version, tarball: &Path) -> sol_eyre::Result<()> {
+        let Some(token) = &self.token else {
+            bail!(
+                "publishing requires a token, set it with `--token`, the `{}` variable, or \
+                 `.sol/config.toml`",
+                Self::TOKEN_VARIABLE
+            );
+        };
+
+        let contents = std::fs::read(tarball)
+            .wrap_err_with(|| format!("Failed to read {}", tarball.display()))?;
+        ureq::put(&format!("{}/{version:?}", self.endpoint(name)))
+            .set("Authorization", &format!("Bearer {token}"))
+            .set("Content-Type", "application/gzip")
+            .send_bytes(&contents)
+            .wrap_err_with(|| format!("Failed to publish `{name}@{version:?}` to {}", self.url))?;
+
+        Ok(())
+    }
+}
+
+/// Packs the folder of a published version into a gzipped tarball, with the files at its
+/// root, like the manifest.
+pub fn pack(folder: &Path, tarball: &Path) -> sol_eyre::Result<()> {
+    let file = File::create(tarball)
+        .wrap_err_with(|| format!("Failed to create {}", tarball.display()))?;
+
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    builder.append_dir_all(".", folder)?;
+    builder.into_inner()?.finish()?;
+
+    Ok(())
+}
+
+/// The version requirement of `sol add`, like in Cargo, with the given parts of the minimum
+/// version, like `[1, 2]` for `1.2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Requirement {
+    /// The requirement `^1.2`, or just `1.2`, matches the versions that are compatible with the
+    /// minimum one, that have the same leftmost non-zero part, like `>=1.2.0, <2.0.0`, or like
+    /// `>=0.2.0, <0.3.0` for `^0.2`. The empty requirement matches any version.
+    Caret(Vec<u8>),
+
+    /// The requirement `~1.2` matches the versions with the same major and minor parts, like
+    /// `>=1.2.0, <1.3.0`, or the same major part, if only the major is given.
+    Tilde(Vec<u8>),
+}
+
+impl Requirement {
+    /// Checks if the version matches the requirement.
+    pub fn matches(&self, version: Version) -> bool {
+        let (parts, fixed) = match self {
+            Requirement::Caret(parts) => {
+                // The parts up to the leftmost non-zero one are fixed, or all the given parts
+                // if they're zeros, like `^0.0` is `>=0.0.0, <0.1.0`.
+                let fixed = parts
+                    .iter()
+                    .position(|part| *part != 0)
+                    .map_or(parts.len(), |index| index + 1);
+                (parts, fixed)
+            }
+            Requirement::Tilde(parts) => (parts, parts.len().min(2)),
+        };
+
+        let part = |index: usize| parts.get(index).copied().unwrap_or_default();
+        let minimum = Version(part(0), part(1), part(2));
+        let Version(major, minor, patch) = version;
+
+        version >= minimum && [major, minor, patch][..fixed] == parts[..fixed]
+    }
+}
+
+/// Parses the package of `sol add`, with its optional version requirement, like `foo@1.2`,
+/// `foo@^1.2` or `foo@~1.2`. The requirements of pre-releases, like `foo@1.2.0-beta`, aren't
+/// supported.
+pub fn parse_requirement(spec: &str) -> sol_eyre::Result<(String, Requirement)> {
+    let Some((name, requirement)) = spec.split_once('@') else {
+        return Ok((spec.to_string(), Requirement::Caret(vec![])));
+    };
+
+    let (kind, parts): (fn(Vec<u8>) -> Requirement, _) = match requirement.chars().next() {
+        Some('^') => (Requirement::Caret, &requirement[1..]),
+        Some('~') => (Requirement::Tilde, &requirement[1..]),
+        _ => (Requirement::Caret, requirement),
+    };
+
+    let parts = parts
+        .split('.')
+        .map(|part| part.parse::<u8>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| eyre!("invalid version requirement `{requirement}`, like `1.2`"))?;
+    if parts.len() > 3 {
+        bail!("invalid version requirement `{spec}`, like `foo@1.2`");
+    }
+
+    Ok((name.to_string(), kind(parts)))
+}
+
+/// Parses the published versions of a package, skipping the pre-releases, like `1.2.0-beta`,
+/// as they're never selected by the requirements.
+pub fn parse_published_versions(versions: &[String]) -> sol_eyre::Result<Vec<Version>> {
+    versions
+        .iter()
+        .filter(|version| !version.contains('-'))
+        .map(|version| parse_version(version))
+        .collect()
+}
+
+/// Selects the latest version that matches the requirement, see [`Requirement`].
+pub fn select_version(versions: &[Version], requirement: &Requirement) -> Option<Version> {
+    versions
+        .iter()
+        .copied()
+        .filter(|version| requirement.matches(*version))
+        .max()
+}
+
+/// Adds the dependency from the registry to the manifest, keeping its formatting and comments,
+/// like `foo = { version = "1.2.0" }`.
+pub fn add_dependency(manifest: &Path, name: &str, version: Version) -> sol_eyre::Result<()> {
+    let text = std::fs::read_to_string(manifest)
+        .wrap_err_with(|| format!("Failed to read {}", manifest.display()))?;
+    let mut document = text
+        .parse::<toml_edit::Document>()
+        .wrap_err_with(|| format!("Failed to parse {}", manifest.display()))?;
+
+    let mut dependency = toml_edit::InlineTable::new();
+    dependency.insert("version", format!("{version:?}").into());
+    document["dependencies"][name] = toml_edit::value(dependency);
+
+    std::fs::write(manifest, document.to_string())
+        .wrap_err_with(|| format!("Failed to write {}", manifest.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions() -> Vec<Version> {
+        vec![
+            Version(0, 2, 0),
+            Version(0, 2, 5),
+            Version(0, 3, 1),
+            Version(1, 2, 0),
+            Version(1, 4, 2),
+            Version(2, 0, 0),
+        ]
+    }
+
+    #[test]
+    fn test_parse_requirement() {
+        let requirement = |spec: &str| parse_requirement(spec).unwrap();
+
+        assert_eq!(
+            requirement("foo"),
+            ("foo".into(), Requirement::Caret(vec![]))
+        );
+        assert_eq!(
+            requirement("foo@1.2"),
+            ("foo".into(), Requirement::Caret(vec![1, 2]))
+        );
+        assert_eq!(
+            requirement("foo@^1.2"),
+            ("foo".into(), Requirement::Caret(vec![1, 2]))
+        );
+        assert_eq!(
+            requirement("foo@~1.2.3"),
+            ("foo".into(), Requirement::Tilde(vec![1, 2, 3]))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_requirement() {
+        assert!(parse_requirement("foo@1.2.3.4").is_err());
+        assert!(parse_requirement("foo@1.x").is_err());
+        assert!(parse_requirement("foo@1.2.0-beta").is_err());
+    }
+
+    #[test]
+    fn test_select_caret_version() {
+        let select = |parts: &[u8]| select_version(&versions(), &Requirement::Caret(parts.into()));
+
+        assert_eq!(select(&[]), Some(Version(2, 0, 0)));
+        assert_eq!(select(&[1]), Some(Version(1, 4, 2)));
+        assert_eq!(select(&[1, 2]), Some(Version(1, 4, 2)));
+        assert_eq!(select(&[0, 2]), Some(Version(0, 2, 5)));
+        assert_eq!(select(&[0, 2, 1]), Some(Version(0, 2, 5)));
+        assert_eq!(select(&[0]), Some(Version(0, 3, 1)));
+    }
+
+    #[test]
+    fn test_select_tilde_version() {
+        let select = |parts: &[u8]| select_version(&versions(), &Requirement::Tilde(parts.into()));
+
+        assert_eq!(select(&[1]), Some(Version(1, 4, 2)));
+        assert_eq!(select(&[1, 2]), Some(Version(1, 2, 0)));
+        assert_eq!(select(&[0, 2, 3]), Some(Version(0, 2, 5)));
+    }
+
+    #[test]
+    fn test_select_no_matching_version() {
+        let versions = versions();
+
+        assert_eq!(
+            select_version(&versions, &Requirement::Caret(vec![3])),
+            None
+        );
+        assert_eq!(
+            select_version(&versions, &Requirement::Caret(vec![1, 5])),
+            None
+        );
+        assert_eq!(
+            select_version(&versions, &Requirement::Tilde(vec![1, 3])),
+            None
+        );
+        assert_eq!(select_version(&[], &Requirement::Caret(vec![])), None);
+    }
+
+    #[test]
+    fn test_skip_pre_releases() {
+        let published = ["1.2.0", "1.3.0-beta.1", "2.0.0-rc.1"].map(String::from);
+        let versions = parse_published_versions(&published).unwrap();
+
+        assert_eq!(versions, vec![Version(1, 2, 0)]);
+        assert_eq!(
+            select_version(&versions, &Requirement::Caret(vec![1])),
+            Some(Version(1, 2, 0))
+        );
+    }
+}
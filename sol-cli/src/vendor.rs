@@ -0,0 +1,146 @@
+//! Defines the vendoring of the dependencies, and of their dependencies, that copies them into
+//! the `vendor` folder of the project, and replaces them in the manifest, so the builds are
+//! offline, and hermetic, like:
+//!
+//! ```toml
+//! [dependencies]
+//! foo = { version = "1.2.0" }
+//!
+//! [source.replace]
+//! foo = "vendor/foo"
+//! ```
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use sol_eyre::{bail, Context};
+
+use crate::{artifacts::TargetDir, build::Manifest, publish::copy_dir};
+
+/// The default folder of the vendored dependencies, relative to the manifest.
+pub const VENDOR_FOLDER: &str = "vendor";
+
+/// Copies the dependencies of the package, and their dependencies, into the `vendor` folder,
+/// replacing the previous copies, and returns the folders of the dependencies, relative to the
+/// manifest, by their names.
+///
+/// The dependencies of the vendored packages are replaced by their vendored siblings, like
+/// `bar = { path = "../bar" }`, as their original folders are relative to the original
+/// packages.
+pub fn vendor(manifest: &Manifest, vendor: &Path) -> sol_eyre::Result<Vec<(String, PathBuf)>> {
+    let mut sources = HashMap::new();
+    dependency_folders(manifest, &mut sources)?;
+
+    let mut vendored = vec![];
+    for (name, source) in sources {
+        let relative = vendor.join(&name);
+        let target = manifest.root_folder.join(&relative);
+
+        // The dependencies that are already vendored are kept as they are.
+        if target.canonicalize().is_ok_and(|target| target == source) {
+            vendored.push((name, relative));
+            continue;
+        }
+
+        if target.exists() {
+            std::fs::remove_dir_all(&target)
+                .wrap_err_with(|| format!("Failed to remove {}", target.display()))?;
+        }
+        copy_dir(&source, &target, &source.join(TargetDir::DEFAULT))?;
+        replace_dependency_paths(&target.join(Manifest::FILE_NAME))?;
+        vendored.push((name, relative));
+    }
+
+    vendored.sort();
+    Ok(vendored)
+}
+
+/// Finds the folders of the dependencies of the package, and of their dependencies, by their
+/// names. The dependencies with the same name should be the same package, as they're vendored
+/// in the same folder.
+fn dependency_folders(
+    manifest: &Manifest,
+    folders: &mut HashMap<String, PathBuf>,
+) -> sol_eyre::Result<()> {
+    for (name, dependency) in &manifest.config.dependencies {
+        let folder = manifest.dependency_folder(name, dependency)?;
+        match folders.get(name) {
+            Some(found) if *found == folder => continue,
+            Some(found) => bail!(
+                "the dependency `{name}` is both {} and {}, they can't be vendored together",
+                found.display(),
+                folder.display()
+            ),
+            None => {}
+        }
+
+        // The folder is added before its dependencies, so the cyclic ones are only vendored
+        // once.
+        folders.insert(name.clone(), folder.clone());
+        let dependency = Manifest::load_in_folder(manifest.db, folder)?;
+        dependency_folders(&dependency, folders)?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the dependencies of a vendored manifest by their vendored siblings, like
+/// `bar = { path = "../bar" }`, keeping its formatting and comments.
+fn replace_dependency_paths(manifest: &Path) -> sol_eyre::Result<()> {
+    let text = std::fs::read_to_string(manifest)
+        .wrap_err_with(|| format!("Failed to read {}", manifest.display()))?;
+    let mut document = text
+        .parse::<toml_edit::Document>()
+        .wrap_err_with(|| format!("Failed to parse {}", manifest.display()))?;
+
+    let Some(dependencies) = document
+        .get_mut("dependencies")
+        .and_then(|dependencies| dependencies.as_table_like_mut())
+    else {
+        return Ok(());
+    };
+    for (name, dependency) in dependencies.iter_mut() {
+        let path = toml_edit::value(format!("../{}", name.get()));
+        if let Some(dependency) = dependency.as_table_like_mut() {
+            dependency.insert("path", path);
+        }
+    }
+
+    std::fs::write(manifest, document.to_string())
+        .wrap_err_with(|| format!("Failed to write {}", manifest.display()))?;
+
+    Ok(())
+}
+
+/// Replaces the dependencies with their vendored folders in the `[source.replace]` table of the
+/// manifest, keeping its formatting and comments. The source folder, like `source = "src"`, is
+/// moved into the `[source]` table.
+pub fn replace_sources(manifest: &Path, vendored: &[(String, PathBuf)]) -> sol_eyre::Result<()> {
+    let text = std::fs::read_to_string(manifest)
+        .wrap_err_with(|| format!("Failed to read {}", manifest.display()))?;
+    let mut document = text
+        .parse::<toml_edit::Document>()
+        .wrap_err_with(|| format!("Failed to parse {}", manifest.display()))?;
+
+    let folder = document
+        .get("source")
+        .and_then(|source| source.as_str())
+        .map(str::to_string);
+    if let Some(folder) = folder {
+        let mut source = toml_edit::Table::new();
+        source.insert("folder", toml_edit::value(folder));
+        document.insert("source", toml_edit::Item::Table(source));
+    }
+
+    for (name, folder) in vendored {
+        let folder = folder.to_string_lossy().replace('\\', "/");
+        document["source"]["replace"][name.as_str()] = toml_edit::value(folder);
+    }
+
+    std::fs::write(manifest, document.to_string())
+        .wrap_err_with(|| format!("Failed to write {}", manifest.display()))?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+//! Defines the diffing of the diagnostics of the files between revisions, so the language
+//! server, and the watch mode, only republish the diagnostics of the files that changed, in
+//! big workspaces.
+//!
+//! The diagnostics are compared by their codes, messages, severities and labels, as the same
+//! diagnostic reported by a new revision is a different [`Diagnostic`].
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Mutex,
+};
+
+use miette::Severity;
+
+use crate::Diagnostic;
+
+/// The maximum number of snapshots kept for each file, the deltas since older revisions have
+/// all the current diagnostics as added.
+const MAX_SNAPSHOTS: usize = 16;
+
+/// The structural key of a diagnostic, that is used to compare them between revisions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DiagnosticKey {
+    code: Option<String>,
+    message: String,
+    severity: Severity,
+    labels: Vec<(usize, usize)>,
+}
+
+impl DiagnosticKey {
+    fn of(diagnostic: &Diagnostic) -> Self {
+        let labels = diagnostic
+            .as_miette()
+            .and_then(|diagnostic| diagnostic.labels())
+            .map(|labels| labels.map(|label| (label.offset(), label.len())).collect())
+            .unwrap_or_default();
+
+        Self {
+            code: diagnostic
+                .as_miette()
+                .and_then(|diagnostic| diagnostic.code().map(|code| code.to_string())),
+            message: diagnostic.0.to_string(),
+            severity: diagnostic.severity(),
+            labels,
+        }
+    }
+}
+
+/// The diagnostics of a file that were added, or removed, since a revision.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticsDelta {
+    /// The current revision of the history, it should be given to the next delta.
+    pub revision: u64,
+    pub added: Vec<Diagnostic>,
+    pub removed: Vec<Diagnostic>,
+}
+
+impl DiagnosticsDelta {
+    /// Checks if the diagnostics of the file are unchanged, so they don't need to be
+    /// republished.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct HistoryState {
+    revision: u64,
+
+    /// The snapshots of the diagnostics of each file, with the revisions they were recorded
+    /// at, the latest one is the last.
+    files: HashMap<String, VecDeque<(u64, Vec<Diagnostic>)>>,
+}
+
+/// The history of the diagnostics of the files, it's kept by the drivers, and it's updated
+/// after each check of the files.
+#[derive(Default)]
+pub struct DiagnosticsHistory {
+    state: Mutex<HistoryState>,
+}
+
+impl DiagnosticsHistory {
+    /// Records the diagnostics of the file at a new revision, and returns it. The snapshot is
+    /// only stored if the diagnostics changed since the latest one.
+    pub fn record(&self, file: &str, diagnostics: Vec<Diagnostic>) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        state.revision += 1;
+
+        let revision = state.revision;
+        let snapshots = state.files.entry(file.to_string()).or_default();
+        let unchanged = snapshots
+            .back()
+            .is_some_and(|(_, latest)| keys(latest) == keys(&diagnostics));
+        if !unchanged {
+            snapshots.push_back((revision, diagnostics));
+            if snapshots.len() > MAX_SNAPSHOTS {
+                snapshots.pop_front();
+            }
+        }
+
+        revision
+    }
+
+    /// Gets the diagnostics of the file that were added, or removed, since the revision. The
+    /// revision `0` has no diagnostics, so all the current diagnostics are added.
+    pub fn diagnostics_delta(&self, file: &str, since_revision: u64) -> DiagnosticsDelta {
+        let state = self.state.lock().unwrap();
+        let Some(snapshots) = state.files.get(file) else {
+            return DiagnosticsDelta {
+                revision: state.revision,
+                ..Default::default()
+            };
+        };
+
+        let empty = vec![];
+        let current = snapshots.back().map_or(&empty, |(_, latest)| latest);
+        let previous = snapshots
+            .iter()
+            .rev()
+            .find(|(revision, _)| *revision <= since_revision)
+            .map_or(&empty, |(_, diagnostics)| diagnostics);
+
+        let current_keys = keys(current);
+        let previous_keys = keys(previous);
+
+        DiagnosticsDelta {
+            revision: state.revision,
+            added: current
+                .iter()
+                .filter(|diagnostic| !previous_keys.contains(&DiagnosticKey::of(diagnostic)))
+                .cloned()
+                .collect(),
+            removed: previous
+                .iter()
+                .filter(|diagnostic| !current_keys.contains(&DiagnosticKey::of(diagnostic)))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+fn keys(diagnostics: &[Diagnostic]) -> HashSet<DiagnosticKey> {
+    diagnostics.iter().map(DiagnosticKey::of).collect()
+}
@@ -7,6 +7,9 @@ use salsa::DbWithJar;
 
 extern crate salsa_2022 as salsa;
 
+pub mod delta;
+pub mod lints;
+
 #[salsa::jar(db = DiagnosticDb)]
 pub struct Jar(crate::Diagnostics);
 
@@ -56,9 +59,12 @@ impl<T> IntoSolDiagnostic<T> for Result<T, sol_eyre::Report> {
 
 /// Fail with miette error.
 pub fn fail<U, E: Into<miette::Report>>(report: E) -> Result<U> {
-    Err(Diagnostic(Arc::new(sol_eyre::Report::Miette(
-        report.into(),
-    ))))
+    Err(diagnostic(report))
+}
+
+/// Creates a diagnostic with miette error, without reporting it.
+pub fn diagnostic<E: Into<miette::Report>>(report: E) -> Diagnostic {
+    Diagnostic(Arc::new(sol_eyre::Report::Miette(report.into())))
 }
 
 #[derive(Clone)]
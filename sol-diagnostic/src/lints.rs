@@ -0,0 +1,189 @@
+//! Defines the lint levels, that can be configured per project, like in the `[lints]` table of
+//! the `sol.toml` manifest, to change the severity of the diagnostics, or to ignore them.
+
+use std::{collections::HashMap, fmt::Display, sync::Arc};
+
+use miette::{LabeledSpan, Severity, SourceCode};
+
+use crate::Diagnostic;
+
+/// The level of a lint, that overrides the severity of the diagnostics with the lint code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The diagnostics are ignored.
+    Allow,
+
+    /// The diagnostics are reported as warnings.
+    Warn,
+
+    /// The diagnostics are reported as errors.
+    Deny,
+}
+
+/// The names of the lints, that are the codes of the diagnostics that can be configured. The
+/// other diagnostics, like the type errors, always keep their severity.
+pub const LINTS: &[&str] = &[
+    "confusable_identifier",
+    "shadowing",
+    "todo",
+    "unused_definition",
+];
+
+/// The lint levels of a project, keyed by the lint name, that is the last segment of the
+/// diagnostic code, like `unused_definition`. Only the names in [`LINTS`] are accepted.
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(
+    try_from = "HashMap<String, LintLevel>",
+    into = "HashMap<String, LintLevel>"
+)]
+pub struct Lints(pub HashMap<String, LintLevel>);
+
+impl TryFrom<HashMap<String, LintLevel>> for Lints {
+    type Error = String;
+
+    fn try_from(levels: HashMap<String, LintLevel>) -> Result<Self, Self::Error> {
+        if let Some(name) = levels.keys().find(|name| !LINTS.contains(&name.as_str())) {
+            return Err(format!(
+                "unknown lint `{name}`, the lints are: {}",
+                LINTS.join(", ")
+            ));
+        }
+
+        Ok(Self(levels))
+    }
+}
+
+impl From<Lints> for HashMap<String, LintLevel> {
+    fn from(lints: Lints) -> Self {
+        lints.0
+    }
+}
+
+impl Lints {
+    /// Gets the configured level of the lint, if any.
+    pub fn level(&self, name: &str) -> Option<LintLevel> {
+        self.0.get(name).copied()
+    }
+
+    /// Applies the configured level to the diagnostic. It returns [`None`] if the diagnostic
+    /// is allowed, and should not be reported. The diagnostics that aren't lints are returned
+    /// unchanged, so the errors can't be allowed.
+    pub fn apply(&self, diagnostic: Diagnostic) -> Option<Diagnostic> {
+        let Some(level) = diagnostic
+            .lint_name()
+            .filter(|name| LINTS.contains(&name.as_str()))
+            .and_then(|name| self.level(&name))
+        else {
+            return Some(diagnostic);
+        };
+
+        let severity = match level {
+            LintLevel::Allow => return None,
+            LintLevel::Warn => Severity::Warning,
+            LintLevel::Deny => Severity::Error,
+        };
+
+        Some(crate::diagnostic(LintDiagnostic {
+            inner: diagnostic.0,
+            severity,
+        }))
+    }
+}
+
+impl Diagnostic {
+    /// Gets the lint name of the diagnostic, that is the last segment of its code, like
+    /// `todo` for `solc::todo`.
+    pub fn lint_name(&self) -> Option<String> {
+        let code = self.as_miette()?.code()?.to_string();
+
+        Some(code.rsplit("::").next().unwrap_or(&code).to_string())
+    }
+
+    /// Gets the severity of the diagnostic, the diagnostics without severity are errors.
+    pub fn severity(&self) -> Severity {
+        self.as_miette()
+            .and_then(|diagnostic| diagnostic.severity())
+            .unwrap_or(Severity::Error)
+    }
+
+    /// Checks if the diagnostic is an error, the warnings and advices doesn't stop the build.
+    pub fn is_error(&self) -> bool {
+        self.severity() == Severity::Error
+    }
+
+    pub(crate) fn as_miette(&self) -> Option<&dyn miette::Diagnostic> {
+        match self.0.as_ref() {
+            sol_eyre::Report::Miette(report) => Some(&**report),
+            sol_eyre::Report::Eyre(_) => None,
+        }
+    }
+}
+
+/// A diagnostic with the severity overridden by a [`LintLevel`]. Everything else is delegated
+/// to the original diagnostic.
+struct LintDiagnostic {
+    inner: Arc<sol_eyre::Report>,
+    severity: Severity,
+}
+
+impl LintDiagnostic {
+    fn inner(&self) -> &dyn miette::Diagnostic {
+        match self.inner.as_ref() {
+            sol_eyre::Report::Miette(report) => &**report,
+            sol_eyre::Report::Eyre(_) => unreachable!("only miette diagnostics have lint codes"),
+        }
+    }
+}
+
+impl std::fmt::Debug for LintDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl Display for LintDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+impl std::error::Error for LintDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.inner().source()
+    }
+}
+
+impl miette::Diagnostic for LintDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.inner().code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.inner().help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.inner().url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.inner().source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.inner().labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn miette::Diagnostic> + 'a>> {
+        self.inner().related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn miette::Diagnostic> {
+        self.inner().diagnostic_source()
+    }
+}
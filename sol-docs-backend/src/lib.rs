@@ -1 +1,30 @@
+//! The documentation backend of Sol, it collects the definitions of the packages that should
+//! be documented.
 
+use sol_hir::{lowering::HirLowering, package::Package, solver::Definition, HirDb};
+
+/// Collects the definitions of the package to be documented, with their qualified names,
+/// sorted by them. By default, only the exported definitions are documented, the private ones
+/// are only included if `include_private` is set.
+pub fn documented_definitions(
+    db: &dyn HirDb,
+    package: Package,
+    include_private: bool,
+) -> Vec<(String, Definition)> {
+    let mut definitions = vec![];
+
+    for file in package.all_files(db) {
+        let hir = db.hir_declare(package, file);
+        let module = file.module_name(db);
+
+        for (name, definition) in hir.scope(db).all_definitions() {
+            let name = format!("{module}.{name}");
+            if include_private || package.is_exported(db, &name) {
+                definitions.push((name, definition));
+            }
+        }
+    }
+
+    definitions.sort_by(|(a, _), (b, _)| a.cmp(b));
+    definitions
+}
@@ -0,0 +1,141 @@
+//! Defines the generation of Rust bindings, for the functions exported with `@export("name")`,
+//! so the Rust projects can call the Sol libraries produced by the C and LLVM backends, and
+//! adopt Sol incrementally.
+//!
+//! Each exported function has an `extern "C"` declaration, and a wrapper, that converts the
+//! Rust types to the ABI types. The wrappers that take opaque pointers are `unsafe`, as the
+//! pointers are dereferenced by the library.
+
+use std::fmt::Write;
+
+use sol_hir::package::Package;
+
+use crate::{
+    header::{AbiType, ExportedFunction},
+    RootDb,
+};
+
+impl AbiType {
+    /// Gets the Rust type of the ABI type, as it's declared in the `extern "C"` block.
+    pub fn rust_type(self) -> &'static str {
+        match self {
+            AbiType::Void => "()",
+            AbiType::Bool => "bool",
+            AbiType::Int(true, 8) => "i8",
+            AbiType::Int(true, 16) => "i16",
+            AbiType::Int(true, 32) => "i32",
+            AbiType::Int(true, _) => "i64",
+            AbiType::Int(false, 8) => "u8",
+            AbiType::Int(false, 16) => "u16",
+            AbiType::Int(false, 32) => "u32",
+            AbiType::Int(false, _) => "u64",
+            AbiType::String => "*const std::ffi::c_char",
+            AbiType::Opaque => "*mut std::ffi::c_void",
+        }
+    }
+
+    /// Gets the Rust type of the ABI type, as it's taken by the wrapper.
+    fn wrapper_type(self) -> &'static str {
+        match self {
+            AbiType::String => "&std::ffi::CStr",
+            _ => self.rust_type(),
+        }
+    }
+
+    /// Converts the argument of the wrapper to the ABI type.
+    fn wrapper_argument(self, name: &str) -> String {
+        match self {
+            AbiType::String => format!("{name}.as_ptr()"),
+            _ => name.into(),
+        }
+    }
+}
+
+impl RootDb {
+    /// Generates the Rust bindings of the functions exported by the package, as a module that
+    /// links against the compiled artifact of the package.
+    pub fn rust_bindings(&self, package: Package) -> String {
+        let functions = self.exported_functions(package);
+
+        let mut bindings = String::new();
+        let _ = writeln!(bindings, "//! Generated by the Sol compiler, do not edit.");
+        let _ = writeln!(bindings);
+        let _ = writeln!(bindings, "mod ffi {{");
+        let _ = writeln!(bindings, "    #[link(name = \"{}\")]", package.name(self));
+        let _ = writeln!(bindings, "    extern \"C\" {{");
+        for function in &functions {
+            let parameters = parameters(function, AbiType::rust_type);
+            let _ = writeln!(
+                bindings,
+                "        pub fn {}({parameters}){};",
+                function.name,
+                return_type(function)
+            );
+        }
+        let _ = writeln!(bindings, "    }}");
+        let _ = writeln!(bindings, "}}");
+
+        for function in &functions {
+            let parameters = parameters(function, AbiType::wrapper_type);
+            let arguments = function
+                .parameters
+                .iter()
+                .enumerate()
+                .map(|(index, parameter)| parameter.wrapper_argument(&format!("arg{index}")))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            // The opaque pointers can't be checked, so the callers must uphold that they're
+            // values of the library.
+            let qualifier = if function.parameters.contains(&AbiType::Opaque) {
+                "unsafe "
+            } else {
+                ""
+            };
+
+            let _ = writeln!(bindings);
+            if !qualifier.is_empty() {
+                let _ = writeln!(bindings, "/// # Safety");
+                let _ = writeln!(bindings, "///");
+                let _ = writeln!(
+                    bindings,
+                    "/// The pointer arguments must be values returned by this library, that are"
+                );
+                let _ = writeln!(bindings, "/// still alive.");
+            }
+            let _ = writeln!(
+                bindings,
+                "pub {qualifier}fn {}({parameters}){} {{",
+                function.name,
+                return_type(function)
+            );
+            let _ = writeln!(
+                bindings,
+                "    unsafe {{ ffi::{}({arguments}) }}",
+                function.name
+            );
+            let _ = writeln!(bindings, "}}");
+        }
+
+        bindings
+    }
+}
+
+/// Formats the parameters of the function, named by their positions.
+fn parameters(function: &ExportedFunction, f: fn(AbiType) -> &'static str) -> String {
+    function
+        .parameters
+        .iter()
+        .enumerate()
+        .map(|(index, parameter)| format!("arg{index}: {}", f(*parameter)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Formats the return type of the function, it's omitted if the function returns unit.
+fn return_type(function: &ExportedFunction) -> String {
+    match function.return_type {
+        AbiType::Void => String::new(),
+        return_type => format!(" -> {}", return_type.rust_type()),
+    }
+}
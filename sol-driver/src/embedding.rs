@@ -0,0 +1,76 @@
+//! Defines the builder of the [`RootDb`], for the embedders, like game engines, or the hosts
+//! of domain specific languages, that use Sol as a scripting, or verification, language:
+//!
+//! ```rust,ignore
+//! let db = RootDb::builder()
+//!     .primitive_type("Entity")
+//!     .primitive_function("Entity.health", ["Entity"], "Int", |arguments| ...)
+//!     .build();
+//! ```
+//!
+//! The primitives should be registered before the lowering, as the resolution of the names,
+//! and the elaboration of the types depend on them, so they're registered with the builder,
+//! instead of the database itself.
+
+use std::sync::Arc;
+
+use sol_hir::{
+    primitives::{PrimitiveBag, PrimitiveFunction},
+    source::literal::Literal,
+};
+
+use crate::RootDb;
+
+/// The builder of the [`RootDb`], with the primitives of the embedder.
+#[derive(Default)]
+pub struct RootDbBuilder {
+    primitives: PrimitiveBag,
+}
+
+impl RootDb {
+    /// Creates a builder of the database, to register the primitives of the embedder.
+    pub fn builder() -> RootDbBuilder {
+        RootDbBuilder::default()
+    }
+}
+
+impl RootDbBuilder {
+    /// Registers an opaque primitive type, like `Entity`, whose values are only created and
+    /// consumed by the primitive functions.
+    pub fn primitive_type(self, name: &str) -> Self {
+        self.primitives.register_type(name);
+        self
+    }
+
+    /// Registers a primitive function, with the names of the types of its parameters, and of
+    /// its return type, that can be the builtins, like `Int`, or the registered primitive
+    /// types.
+    ///
+    /// The hook evaluates the saturated applications to literal arguments at compile time, and
+    /// returns [`None`] to keep the application symbolic.
+    pub fn primitive_function<const N: usize>(
+        self,
+        name: &str,
+        parameters: [&str; N],
+        return_type: &str,
+        hook: impl Fn(&[Literal]) -> Option<Literal> + Send + Sync + 'static,
+    ) -> Self {
+        self.primitives.register_function(
+            name,
+            PrimitiveFunction {
+                parameters: parameters.iter().map(|name| name.to_string()).collect(),
+                return_type: return_type.into(),
+                hook: Arc::new(hook),
+            },
+        );
+        self
+    }
+
+    /// Creates the database, with the registered primitives.
+    pub fn build(self) -> RootDb {
+        RootDb {
+            primitives: Arc::new(self.primitives),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,465 @@
+//! Defines the erasure analysis, that detects the computationally irrelevant parameters, like
+//! the type parameters, and removes them before the code is emitted by the backends.
+//!
+//! The candidates are the implicit parameters, the parameters whose type is `Type`, and the
+//! proofs of propositions. They are erased if they're only used in irrelevant positions, like
+//! the types, the proofs, or the erased arguments of other functions. It's a fixed point, as
+//! erasing the parameter of a function can make the arguments of its call sites irrelevant.
+//!
+//! The parameters can be forced to be erased with the `@erased(x)` attribute, and an error is
+//! reported if they're used relevantly.
+
+use fxhash::FxHashMap;
+use sol_diagnostic::{diagnostic, Diagnostic};
+use sol_hir::{
+    lowering::HirLowering,
+    package::Package,
+    solver::{Definition, DefinitionId},
+    source::{declaration::AttributeArgument, top_level::TopLevel, Location},
+};
+use sol_thir::{
+    debruijin::{Index, Level},
+    shared::{Constructor, ConstructorKind, Context, Implicitness},
+    source::Term,
+    value::{Pi, Type, Value},
+};
+use sol_thir_lowering::relevance::is_proposition;
+
+use crate::RootDb;
+
+/// The attribute that forces the parameters to be erased, like `@erased(a)`.
+pub const ERASED_ATTRIBUTE: &str = "erased";
+
+/// Erased parameter that is used relevantly, so it can't be removed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the erased parameter `{name}` is used relevantly")]
+#[diagnostic(code(solc::erased_parameter_used), url(docsrs))]
+pub struct ErasedParameterUsedError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "used here"]
+    pub location: Location,
+}
+
+/// The erasure of the leading parameters of a function, `true` if the parameter is erased.
+pub type Erasure = Vec<bool>;
+
+/// The result of the erasure analysis of a package.
+#[derive(Debug, Clone, Default)]
+pub struct Erasures {
+    pub functions: FxHashMap<DefinitionId, Erasure>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// The function that is analysed, with the names of its leading parameters.
+struct Function {
+    definition: Definition,
+    term: Term,
+    parameters: Vec<String>,
+
+    /// The parameters that are forced to be erased, by the `@erased` attribute.
+    forced: Vec<bool>,
+}
+
+impl RootDb {
+    /// Analyses the functions of the package, finding the parameters that can be erased.
+    pub fn erasures(&self, package: Package) -> Erasures {
+        let global_env = self.global_env();
+        let ctx = Context::default_with_env(self, global_env, package);
+        let mut functions = FxHashMap::default();
+        let mut erasures = Erasures::default();
+
+        for file in package.all_files(self) {
+            let hir = self.hir_lower(package, file);
+            let table = sol_typer::infer_type_table(self, global_env, hir);
+
+            for top_level in hir.contents(self).iter() {
+                let TopLevel::BindingGroup(group) = top_level else {
+                    continue;
+                };
+
+                let signature = group.signature(self);
+                let definition = signature.name(self);
+                let Some((_, (term, type_repr))) = table
+                    .iter()
+                    .find(|(name, _)| name.id(self) == definition.id(self))
+                else {
+                    continue;
+                };
+
+                let forced_names = signature
+                    .attributes(self)
+                    .into_iter()
+                    .filter(|attribute| {
+                        attribute.name(self).to_string(self).as_deref() == Some(ERASED_ATTRIBUTE)
+                    })
+                    .flat_map(|attribute| attribute.arguments(self))
+                    .filter_map(|argument| match argument {
+                        AttributeArgument::Path(path) => path.to_string(self),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                let parameters = lambda_parameters(self, term);
+                let forced = parameters
+                    .iter()
+                    .map(|name| forced_names.contains(name))
+                    .collect::<Vec<_>>();
+                let candidates = self
+                    .parameter_kinds(ctx, type_repr.clone())
+                    .into_iter()
+                    .chain(std::iter::repeat(false))
+                    .zip(&forced)
+                    .map(|(candidate, forced)| candidate || *forced)
+                    .collect::<Vec<_>>();
+
+                erasures.functions.insert(definition.id(self), candidates);
+                functions.insert(
+                    definition.id(self),
+                    Function {
+                        definition,
+                        term: term.clone(),
+                        parameters,
+                        forced,
+                    },
+                );
+            }
+        }
+
+        // Removes the candidates that are used relevantly, until nothing changes, the forced
+        // parameters are kept erased, and their relevant uses are reported.
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for (id, function) in &functions {
+                let mut uses = vec![];
+                self.relevant_uses(&erasures.functions, &function.term, 0, &mut uses);
+
+                let erasure = erasures.functions.get_mut(id).unwrap();
+                for (level, _) in uses {
+                    if erasure.get(level) == Some(&true) && !function.forced[level] {
+                        erasure[level] = false;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        for function in functions.values() {
+            let mut uses = vec![];
+            self.relevant_uses(&erasures.functions, &function.term, 0, &mut uses);
+
+            for (level, location) in uses {
+                if function.forced.get(level) == Some(&true) {
+                    erasures
+                        .diagnostics
+                        .push(diagnostic(ErasedParameterUsedError {
+                            name: function.parameters[level].clone(),
+                            location: location
+                                .unwrap_or_else(|| function.definition.location(self)),
+                        }));
+                }
+            }
+        }
+
+        erasures
+    }
+
+    /// Removes the erased parameters of the function, and the erased arguments of its call
+    /// sites, so the backends only see the relevant parameters.
+    pub fn erase(&self, erasures: &Erasures, definition: Definition, term: Term) -> Term {
+        let term = self.erase_arguments(erasures, term);
+        let Some(erasure) = erasures.functions.get(&definition.id(self)) else {
+            return term;
+        };
+
+        erase_parameters(term, erasure)
+    }
+
+    /// Gets if each parameter of the function type is a candidate to be erased, that is, if
+    /// it's implicit, if it's a type, or if it's a proof.
+    fn parameter_kinds(&self, ctx: Context, mut type_repr: Type) -> Vec<bool> {
+        let mut kinds = vec![];
+        let mut level = 0;
+
+        loop {
+            match type_repr {
+                Value::Location(_, value) | Value::Glued(_, _, value) => type_repr = *value,
+                Value::Pi(Pi {
+                    implicitness,
+                    domain,
+                    codomain,
+                    ..
+                }) => {
+                    kinds.push(
+                        implicitness == Implicitness::Implicit
+                            || is_type(&domain)
+                            || is_proposition(self, ctx, *domain),
+                    );
+
+                    let argument = Value::new_var(Level::new(self, level), None);
+                    level += 1;
+
+                    match codomain.apply(self, argument) {
+                        Ok(value) => type_repr = value,
+                        Err(_) => return kinds,
+                    }
+                }
+                _ => return kinds,
+            }
+        }
+    }
+
+    /// Collects the relevant uses of the leading parameters of the function, by their levels,
+    /// the uses inside of the types, and in the erased arguments are irrelevant.
+    fn relevant_uses(
+        &self,
+        erasures: &FxHashMap<DefinitionId, Erasure>,
+        term: &Term,
+        depth: usize,
+        uses: &mut Vec<(usize, Option<Location>)>,
+    ) {
+        match term {
+            Term::Var(Index(index), reference) if *index < depth => {
+                let location = reference.as_ref().map(|reference| reference.location(self));
+                uses.push((depth - 1 - index, location));
+            }
+            Term::Lam(_, _, body) => self.relevant_uses(erasures, body, depth + 1, uses),
+            Term::Ann(value, _) => self.relevant_uses(erasures, value, depth, uses),
+            Term::Location(_, term) | Term::Glued(_, _, term) => {
+                self.relevant_uses(erasures, term, depth, uses)
+            }
+            Term::Record(fields) => {
+                for (_, value) in fields {
+                    self.relevant_uses(erasures, value, depth, uses);
+                }
+            }
+            Term::Project(record, _, _) => self.relevant_uses(erasures, record, depth, uses),
+            Term::Transport(_, value) => self.relevant_uses(erasures, value, depth, uses),
+            Term::Array(terms) | Term::Intrinsic(_, terms, _) => {
+                for term in terms {
+                    self.relevant_uses(erasures, term, depth, uses);
+                }
+            }
+            Term::Match(scrutinee, tree) => {
+                self.relevant_uses(erasures, scrutinee, depth, uses);
+                for term in tree.terms() {
+                    self.relevant_uses(erasures, term, depth, uses);
+                }
+            }
+            Term::App(..) => {
+                let (head, arguments) = term.spine();
+                let erasure = self.callee_erasure(erasures, head);
+
+                self.relevant_uses(erasures, head, depth, uses);
+                for (position, argument) in arguments.into_iter().enumerate() {
+                    if !erasure.get(position).copied().unwrap_or_default() {
+                        self.relevant_uses(erasures, argument, depth, uses);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Removes the erased arguments of the call sites of the term.
+    fn erase_arguments(&self, erasures: &Erasures, term: Term) -> Term {
+        match term {
+            Term::Lam(name, implicitness, body) => Term::Lam(
+                name,
+                implicitness,
+                self.erase_arguments(erasures, *body).into(),
+            ),
+            Term::Ann(value, type_repr) => {
+                Term::Ann(self.erase_arguments(erasures, *value).into(), type_repr)
+            }
+            Term::Location(location, term) => {
+                Term::Location(location, self.erase_arguments(erasures, *term).into())
+            }
+            Term::Glued(_, _, term) => self.erase_arguments(erasures, *term),
+            Term::Record(fields) => Term::Record(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, self.erase_arguments(erasures, value)))
+                    .collect(),
+            ),
+            Term::Project(record, name, position) => Term::Project(
+                self.erase_arguments(erasures, *record).into(),
+                name,
+                position,
+            ),
+            Term::Transport(equality, value) => {
+                Term::Transport(equality, self.erase_arguments(erasures, *value).into())
+            }
+            Term::Array(items) => Term::Array(
+                items
+                    .into_iter()
+                    .map(|item| self.erase_arguments(erasures, item))
+                    .collect(),
+            ),
+            Term::Intrinsic(intrinsic, arguments, location) => Term::Intrinsic(
+                intrinsic,
+                arguments
+                    .into_iter()
+                    .map(|argument| self.erase_arguments(erasures, argument))
+                    .collect(),
+                location,
+            ),
+            Term::Match(scrutinee, tree) => Term::Match(
+                self.erase_arguments(erasures, *scrutinee).into(),
+                tree.map(&mut |term| self.erase_arguments(erasures, term)),
+            ),
+            Term::App(..) => {
+                let (head, arguments) = term.spine();
+                let erasure = self.callee_erasure(&erasures.functions, head);
+
+                arguments.into_iter().enumerate().fold(
+                    self.erase_arguments(erasures, head.clone()),
+                    |callee, (position, argument)| {
+                        if erasure.get(position).copied().unwrap_or_default() {
+                            return callee;
+                        }
+
+                        let argument = self.erase_arguments(erasures, argument.clone());
+                        Term::App(callee.into(), argument.into())
+                    },
+                )
+            }
+            term => term,
+        }
+    }
+
+    /// Gets the erasure of the callee, if it's a reference to an analysed function.
+    fn callee_erasure(&self, erasures: &FxHashMap<DefinitionId, Erasure>, head: &Term) -> Erasure {
+        match head {
+            Term::Constructor(Constructor {
+                kind: ConstructorKind::Reference(reference),
+                ..
+            }) => erasures
+                .get(&reference.definition(self).id(self))
+                .cloned()
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+}
+
+/// Gets the names of the leading lambdas of the term, that are the parameters of the function.
+fn lambda_parameters(db: &RootDb, term: &Term) -> Vec<String> {
+    match term {
+        Term::Lam(name, _, body) => std::iter::once(name.to_string(db))
+            .chain(lambda_parameters(db, body))
+            .collect(),
+        Term::Location(_, term) => lambda_parameters(db, term),
+        _ => vec![],
+    }
+}
+
+/// Removes the leading lambdas of the erased parameters.
+fn erase_parameters(term: Term, erasure: &[bool]) -> Term {
+    match (term, erasure) {
+        (Term::Location(location, term), _) => {
+            Term::Location(location, erase_parameters(*term, erasure).into())
+        }
+        (Term::Lam(_, _, body), [true, erasure @ ..]) => {
+            erase_parameters(remove_binder(*body, 0), erasure)
+        }
+        (Term::Lam(name, implicitness, body), [false, erasure @ ..]) => {
+            Term::Lam(name, implicitness, erase_parameters(*body, erasure).into())
+        }
+        (term, _) => term,
+    }
+}
+
+/// Removes the binder at the `depth`, its irrelevant uses are replaced by `Type`, and the
+/// variables that are bound outside of it are shifted.
+fn remove_binder(term: Term, depth: usize) -> Term {
+    match term {
+        Term::Var(Index(index), _) if index == depth => Term::U,
+        Term::Var(Index(index), reference) if index > depth => {
+            Term::Var(Index(index - 1), reference)
+        }
+        Term::Lam(name, implicitness, body) => {
+            Term::Lam(name, implicitness, remove_binder(*body, depth + 1).into())
+        }
+        Term::Pi(name, implicitness, domain, codomain) => Term::Pi(
+            name,
+            implicitness,
+            remove_binder(*domain, depth).into(),
+            remove_binder(*codomain, depth + 1).into(),
+        ),
+        Term::App(callee, argument) => Term::App(
+            remove_binder(*callee, depth).into(),
+            remove_binder(*argument, depth).into(),
+        ),
+        Term::Ann(value, type_repr) => Term::Ann(
+            remove_binder(*value, depth).into(),
+            remove_binder(*type_repr, depth).into(),
+        ),
+        Term::Location(location, term) => {
+            Term::Location(location, remove_binder(*term, depth).into())
+        }
+        Term::Record(fields) => Term::Record(remove_binder_fields(fields, depth)),
+        Term::RecordType(fields) => Term::RecordType(remove_binder_fields(fields, depth)),
+        Term::Project(record, name, position) => {
+            Term::Project(remove_binder(*record, depth).into(), name, position)
+        }
+        Term::Eq(type_repr, lhs, rhs) => Term::Eq(
+            remove_binder(*type_repr, depth).into(),
+            remove_binder(*lhs, depth).into(),
+            remove_binder(*rhs, depth).into(),
+        ),
+        Term::Transport(equality, value) => Term::Transport(
+            remove_binder(*equality, depth).into(),
+            remove_binder(*value, depth).into(),
+        ),
+        Term::ArrayType(type_repr) => Term::ArrayType(remove_binder(*type_repr, depth).into()),
+        Term::Array(items) => Term::Array(
+            items
+                .into_iter()
+                .map(|item| remove_binder(item, depth))
+                .collect(),
+        ),
+        Term::Intrinsic(intrinsic, arguments, location) => Term::Intrinsic(
+            intrinsic,
+            arguments
+                .into_iter()
+                .map(|argument| remove_binder(argument, depth))
+                .collect(),
+            location,
+        ),
+        Term::Irrelevant(term) => Term::Irrelevant(remove_binder(*term, depth).into()),
+        Term::Match(scrutinee, tree) => Term::Match(
+            remove_binder(*scrutinee, depth).into(),
+            tree.map(&mut |term| remove_binder(term, depth)),
+        ),
+        Term::Glued(name, spine, term) => Term::Glued(
+            name,
+            spine
+                .into_iter()
+                .map(|argument| remove_binder(argument, depth))
+                .collect(),
+            remove_binder(*term, depth).into(),
+        ),
+        term => term,
+    }
+}
+
+/// Removes the binder at the `depth` from the fields of a record, like [`remove_binder`].
+fn remove_binder_fields(fields: Vec<(String, Term)>, depth: usize) -> Vec<(String, Term)> {
+    fields
+        .into_iter()
+        .map(|(name, value)| (name, remove_binder(value, depth)))
+        .collect()
+}
+
+/// Checks if the type is `Type`, so the values of the type are types.
+fn is_type(type_repr: &Type) -> bool {
+    match type_repr {
+        Value::Location(_, value) | Value::Glued(_, _, value) => is_type(value),
+        Value::U | Value::Prop => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,212 @@
+//! Defines the generation of C headers, for the functions exported with `@export("name")`, so
+//! the native programs can link against the Sol libraries produced by the C and LLVM backends.
+//!
+//! The types are erased to their ABI representation: the primitive types are mapped to the C
+//! types, the implicit parameters are erased, and any other type is passed as an opaque
+//! pointer.
+
+use std::fmt::Write;
+
+use sol_hir::{
+    lowering::HirLowering,
+    package::Package,
+    source::{declaration::AttributeArgument, top_level::TopLevel},
+};
+use sol_thir::{
+    debruijin::Level,
+    shared::{Constructor, ConstructorKind, Implicitness},
+    value::{Pi, Type, Value},
+};
+
+use crate::RootDb;
+
+/// The type of a parameter or of the return of an exported function, erased to the ABI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbiType {
+    Void,
+    Bool,
+    /// Integer, that is signed or not, with its width in bits.
+    Int(bool, u8),
+    String,
+
+    /// Any type that doesn't have an ABI representation, it's passed as an opaque pointer.
+    Opaque,
+}
+
+impl AbiType {
+    /// Gets the C type of the ABI type.
+    pub fn c_type(self) -> &'static str {
+        match self {
+            AbiType::Void => "void",
+            AbiType::Bool => "bool",
+            AbiType::Int(true, 8) => "int8_t",
+            AbiType::Int(true, 16) => "int16_t",
+            AbiType::Int(true, 32) => "int32_t",
+            AbiType::Int(true, _) => "int64_t",
+            AbiType::Int(false, 8) => "uint8_t",
+            AbiType::Int(false, 16) => "uint16_t",
+            AbiType::Int(false, 32) => "uint32_t",
+            AbiType::Int(false, _) => "uint64_t",
+            AbiType::String => "const char *",
+            AbiType::Opaque => "void *",
+        }
+    }
+}
+
+/// An exported function, with its C name and erased signature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExportedFunction {
+    pub name: String,
+    pub parameters: Vec<AbiType>,
+    pub return_type: AbiType,
+}
+
+impl RootDb {
+    /// Gets the functions exported by the package with the `@export` attribute, with their
+    /// signatures erased to the C ABI. The name of the export defaults to the qualified name of
+    /// the function, with the dots replaced by underscores.
+    pub fn exported_functions(&self, package: Package) -> Vec<ExportedFunction> {
+        let global_env = self.global_env();
+        let mut functions = vec![];
+
+        for file in package.all_files(self) {
+            let hir = self.hir_lower(package, file);
+            let table = sol_typer::infer_type_table(self, global_env, hir);
+
+            for top_level in hir.contents(self).iter() {
+                let TopLevel::BindingGroup(group) = top_level else {
+                    continue;
+                };
+
+                let signature = group.signature(self);
+                let Some(export) = signature.attributes(self).into_iter().find(|attribute| {
+                    attribute.name(self).to_string(self).as_deref() == Some("export")
+                }) else {
+                    continue;
+                };
+
+                let definition = signature.name(self);
+                let Some((_, (_, type_repr))) = table
+                    .iter()
+                    .find(|(name, _)| name.id(self) == definition.id(self))
+                else {
+                    continue;
+                };
+
+                let name = export
+                    .arguments(self)
+                    .first()
+                    .and_then(AttributeArgument::as_string)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| definition.to_string(self).replace('.', "_"));
+
+                let (parameters, return_type) = self.erase_signature(type_repr.clone());
+
+                functions.push(ExportedFunction {
+                    name,
+                    parameters,
+                    return_type,
+                });
+            }
+        }
+
+        functions
+    }
+
+    /// Generates the C header of the functions exported by the package.
+    pub fn c_header(&self, package: Package) -> String {
+        let guard = format!(
+            "{}_H",
+            package
+                .name(self)
+                .to_uppercase()
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        );
+
+        let mut header = String::new();
+        let _ = writeln!(header, "// Generated by the Sol compiler, do not edit.");
+        let _ = writeln!(header, "#ifndef {guard}");
+        let _ = writeln!(header, "#define {guard}");
+        let _ = writeln!(header);
+        let _ = writeln!(header, "#include <stdbool.h>");
+        let _ = writeln!(header, "#include <stdint.h>");
+        let _ = writeln!(header);
+
+        for function in self.exported_functions(package) {
+            let parameters = match function.parameters.is_empty() {
+                true => "void".to_string(),
+                false => function
+                    .parameters
+                    .iter()
+                    .map(|parameter| parameter.c_type())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            };
+
+            let _ = writeln!(
+                header,
+                "{} {}({parameters});",
+                function.return_type.c_type(),
+                function.name
+            );
+        }
+
+        let _ = writeln!(header);
+        let _ = writeln!(header, "#endif // {guard}");
+        header
+    }
+
+    /// Erases the type of a function to the C ABI, returning the types of the explicit
+    /// parameters and the return type. The unit parameters are erased too, as they don't carry
+    /// any data.
+    fn erase_signature(&self, mut type_repr: Type) -> (Vec<AbiType>, AbiType) {
+        let mut parameters = vec![];
+        let mut level = 0;
+
+        loop {
+            match type_repr {
+                Value::Location(_, value) | Value::Glued(_, _, value) => type_repr = *value,
+                Value::Pi(Pi {
+                    implicitness,
+                    domain,
+                    codomain,
+                    ..
+                }) => {
+                    let parameter = abi_type(&domain);
+                    if implicitness == Implicitness::Explicit && parameter != AbiType::Void {
+                        parameters.push(parameter);
+                    }
+
+                    let argument = Value::new_var(Level::new(self, level), None);
+                    level += 1;
+
+                    match codomain.apply(self, argument) {
+                        Ok(value) => type_repr = value,
+                        Err(_) => return (parameters, AbiType::Opaque),
+                    }
+                }
+                value => return (parameters, abi_type(&value)),
+            }
+        }
+    }
+}
+
+/// Maps the erased type to the ABI type, the types that doesn't have an ABI representation are
+/// passed as opaque pointers.
+fn abi_type(type_repr: &Type) -> AbiType {
+    match type_repr {
+        Value::Location(_, value) | Value::Glued(_, _, value) => abi_type(value),
+        Value::Constructor(Constructor { kind, .. }) => match kind {
+            ConstructorKind::UnitType => AbiType::Void,
+            ConstructorKind::BooleanType => AbiType::Bool,
+            ConstructorKind::StringType => AbiType::String,
+            ConstructorKind::IntType(signed, width @ (8 | 16 | 32 | 64)) => {
+                AbiType::Int(*signed, *width as u8)
+            }
+            // The `Nat` and `Integer` values can be promoted to big integers in the heap, so
+            // they're opaque, like the other objects.
+            _ => AbiType::Opaque,
+        },
+        _ => AbiType::Opaque,
+    }
+}
@@ -1,23 +1,73 @@
 use std::{
-    path::PathBuf,
-    sync::{Arc, Mutex},
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock},
 };
 
 use dashmap::{DashMap, DashSet};
 use salsa::DebugWithDb;
+use sol_diagnostic::{
+    delta::{DiagnosticsDelta, DiagnosticsHistory},
+    Diagnostic,
+};
 use sol_hir::{
+    commands::{Command, CommandEvaluator, CommandRegistry},
     lowering::HirLowering,
-    package::{HasManifest, Package},
+    package::{HasManifest, Package, Profile, ProfileSettings},
     primitives::{PrimitiveBag, PrimitiveProvider},
-    source::expr::Expr,
+    solver::Definition,
+    source::{expr::Expr, HirSource},
+};
+use sol_thir::{
+    display,
+    shared::{Context, GlobalEnv},
+    source::Term,
+    value::Type,
+    ElaboratedTerm, ThirLowering, ThirTyping, TypeTable, Typer,
 };
-use sol_thir::{source::Term, value::Type, ElaboratedTerm, ThirLowering, ThirTyping};
 
 /// Defines watcher strategies for [`RootDb`].
 pub mod watcher;
 
 pub mod rename;
 
+/// Defines the read-only semantic model, for external tools.
+pub mod semantic;
+
+/// Defines the memory usage reporting, and the eviction of the query memos.
+pub mod memory;
+
+/// Defines the generation of C headers for the exported functions.
+pub mod header;
+
+/// Defines the generation of Rust bindings for the exported functions.
+pub mod bindgen;
+
+/// Defines the structural search and replace over the HIR.
+pub mod sed;
+
+/// Defines the compiler plugins, for third-party lints, commands and codegen steps.
+pub mod plugin;
+
+/// Defines the compile statistics of the packages.
+pub mod stats;
+
+/// Defines the reachability analysis, that prunes the unreachable definitions before the
+/// codegen.
+pub mod reachability;
+
+/// Defines the specialization of the polymorphic functions, for the backends.
+pub mod specialization;
+
+/// Defines the erasure of the computationally irrelevant parameters, for the backends.
+pub mod erasure;
+
+/// Defines the lowering of the packages into the LIR, that is shared by the backends.
+pub mod lir;
+
+/// Defines the builder of the database, to register the primitives of the embedders.
+pub mod embedding;
+
 #[allow(unused)]
 pub mod suite;
 
@@ -50,6 +100,23 @@ pub struct RootDb {
     primitives: Arc<PrimitiveBag>,
     files: DashMap<PathBuf, sol_vfs::SourceFile>,
     logs: Option<Arc<Mutex<Vec<String>>>>,
+
+    /// The compile-time commands, like `#eval`, that can be extended by the drivers.
+    commands: Arc<CommandRegistry<RootDb>>,
+
+    /// The selected build profile, it's created lazily with the `dev` settings.
+    profile: Arc<OnceLock<Profile>>,
+
+    /// The compiler plugins, that are called after each phase of the compilation.
+    plugins: Arc<plugin::PluginRegistry>,
+
+    /// The history of the diagnostics of the files, used to only republish the diagnostics
+    /// that changed.
+    diagnostics_history: Arc<DiagnosticsHistory>,
+
+    /// The global environment of the elaboration, it's created lazily, and shared by all the
+    /// queries, so the type tables are only inferred once.
+    global_env: Arc<OnceLock<GlobalEnv>>,
 }
 
 /// Bridges the [`RootDb`] with the [`sol_hir_lowering::HirLowering`] trait.
@@ -63,6 +130,24 @@ impl HirLowering for RootDb {
     }
 }
 
+/// Bridges the [`RootDb`] with the [`sol_thir::Typer`] trait.
+impl Typer for RootDb {
+    fn infer_type_table(&self, source: HirSource) -> sol_diagnostic::Result<TypeTable> {
+        Ok(sol_typer::infer_type_table(self, self.global_env(), source))
+    }
+
+    fn infer_definition_type(
+        &self,
+        global_env: GlobalEnv,
+        package: Package,
+        definition: Definition,
+    ) -> sol_diagnostic::Result<Option<(Term, Type)>> {
+        Ok(sol_typer::dependencies::definition_type(
+            self, global_env, package, definition,
+        ))
+    }
+}
+
 /// Bridges the [`RootDb`] with the [`sol_thir::ThirLowering`] trait.
 impl ThirLowering for RootDb {
     fn thir_eval(
@@ -102,12 +187,147 @@ impl ThirTyping for RootDb {
     }
 }
 
+/// Bridges the [`RootDb`] with the [`sol_hir::commands::CommandEvaluator`] trait, that is used by
+/// the built-in commands.
+impl CommandEvaluator for RootDb {
+    fn eval_expr(&self, pkg: Package, expr: Expr) -> sol_diagnostic::Result<String> {
+        sol_typer::eval_expr(self, self.global_env(), pkg, expr)
+    }
+
+    fn assert_expr(&self, pkg: Package, expr: Expr) -> sol_diagnostic::Result<bool> {
+        sol_typer::assert_expr(self, self.global_env(), pkg, expr)
+    }
+
+    fn type_of_expr(&self, pkg: Package, expr: Expr) -> sol_diagnostic::Result<String> {
+        let ctx = Context::default_with_env(self, self.global_env(), pkg);
+        let ElaboratedTerm(_, type_repr) = self.thir_infer(ctx, expr)?;
+
+        display::show_value(self, ctx, type_repr)
+    }
+
+    fn elaborate_definition(
+        &self,
+        source: HirSource,
+        definition: Definition,
+    ) -> sol_diagnostic::Result<Option<(String, String)>> {
+        let global_env = self.global_env();
+        let table = sol_typer::infer_type_table(self, global_env, source);
+
+        // The definitions of the type table are the ones of the lowered source, so they're
+        // compared by their ids.
+        let Some((_, (term, type_repr))) = table
+            .into_iter()
+            .find(|(name, _)| name.id(self) == definition.id(self))
+        else {
+            return Ok(None);
+        };
+
+        let ctx = Context::default_with_env(self, global_env, source.package(self));
+
+        Ok(Some((
+            display::show_term(self, ctx, &term),
+            display::show_value(self, ctx, type_repr)?,
+        )))
+    }
+}
+
 impl RootDb {
     /// Registers a package in the database.
     pub fn register_package(&self, package: Package) -> Package {
         self.packages.insert(package);
         package
     }
+
+    /// Reads the files included by the `#include_source` commands of the source file, and the
+    /// files included by them, registering them in the virtual file system, so they can be
+    /// lowered. The files that can't be read, or that are included cyclically, aren't
+    /// registered, so they're reported by the lowering.
+    pub fn register_includes(&self, source: sol_syntax::Source) {
+        self.register_includes_of(source, &mut vec![source.file_path(self).clone()]);
+    }
+
+    fn register_includes_of(&self, source: sol_syntax::Source, chain: &mut Vec<PathBuf>) {
+        for path in sol_hir_lowering::include::included_paths(self, source) {
+            if chain.contains(&path) {
+                continue;
+            }
+            let Ok(text) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+
+            // The files are only replaced if they changed, so their queries are reused. The
+            // included files are in the module of the file that includes them.
+            let file = match self.files.get(&path).map(|file| *file) {
+                Some(file) if *file.source_text(self) == text => file,
+                _ => {
+                    let module_name = source.module_name(self).clone();
+                    let file = sol_vfs::SourceFile::new(self, path.clone(), module_name, text);
+                    self.files.insert(path.clone(), file);
+                    file
+                }
+            };
+
+            chain.push(path);
+            self.register_includes_of(sol_syntax::parse(self, file), chain);
+            chain.pop();
+        }
+    }
+
+    /// Registers a compile-time command, replacing the built-in command with the same name, if
+    /// any.
+    pub fn register_command<C: Command<RootDb> + 'static>(&self, command: C) {
+        self.commands.register(command);
+    }
+
+    /// Executes the compile-time commands of the source file, returning their outputs and
+    /// errors as diagnostics.
+    pub fn run_commands(&self, source: HirSource) -> Vec<Diagnostic> {
+        self.commands.execute(self, source)
+    }
+
+    /// Records the diagnostics of the file, returning the revision of the history they were
+    /// recorded at.
+    pub fn record_diagnostics(&self, source: HirSource, diagnostics: Vec<Diagnostic>) -> u64 {
+        let file = source
+            .source(self)
+            .file_path(self)
+            .to_string_lossy()
+            .to_string();
+        self.diagnostics_history.record(&file, diagnostics)
+    }
+
+    /// Gets the diagnostics of the file that were added, or removed, since the revision.
+    pub fn diagnostics_delta(&self, source: HirSource, since_revision: u64) -> DiagnosticsDelta {
+        let file = source
+            .source(self)
+            .file_path(self)
+            .to_string_lossy()
+            .to_string();
+        self.diagnostics_history
+            .diagnostics_delta(&file, since_revision)
+    }
+
+    /// Runs the function, catching the cancellation of the queries. The queries running in a
+    /// snapshot are cancelled when an input changes in the main database, like when a new edit
+    /// arrives, so the LSP and the watch mode can drop the stale results.
+    pub fn cancellable<T>(&self, f: impl FnOnce(&Self) -> T) -> Result<T, salsa::Cancelled> {
+        salsa::Cancelled::catch(AssertUnwindSafe(|| f(self)))
+    }
+
+    /// Gets the global environment of the elaboration, that is shared by all the queries of
+    /// the database.
+    pub fn global_env(&self) -> GlobalEnv {
+        *self
+            .global_env
+            .get_or_init(|| GlobalEnv::new(self, Default::default()))
+    }
+
+    /// Selects the build profile, invalidating the queries that depends on it.
+    pub fn set_profile(&mut self, name: &str, settings: ProfileSettings) {
+        let profile = self.profile();
+        profile.set_name(self).to(name.into());
+        profile.set_settings(self).to(settings);
+    }
 }
 
 impl PrimitiveProvider for RootDb {
@@ -121,6 +341,18 @@ impl HasManifest for RootDb {
     fn all_packages(&self) -> Vec<Package> {
         self.packages.iter().map(|p| *p).collect::<Vec<_>>()
     }
+
+    fn profile(&self) -> Profile {
+        *self
+            .profile
+            .get_or_init(|| Profile::new(self, "dev".into(), ProfileSettings::dev()))
+    }
+
+    fn included_source(&self, path: &Path) -> Option<sol_syntax::Source> {
+        let file = self.files.get(path).map(|file| *file)?;
+
+        Some(sol_syntax::parse(self, file))
+    }
 }
 
 impl salsa::Database for RootDb {
@@ -145,6 +377,11 @@ impl salsa::ParallelDatabase for RootDb {
             logs: self.logs.clone(),
             files: self.files.clone(),
             packages: self.packages.clone(),
+            commands: self.commands.clone(),
+            profile: self.profile.clone(),
+            plugins: self.plugins.clone(),
+            diagnostics_history: self.diagnostics_history.clone(),
+            global_env: self.global_env.clone(),
         })
     }
 }
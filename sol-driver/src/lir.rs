@@ -0,0 +1,40 @@
+use sol_hir::{
+    lowering::HirLowering,
+    package::{HasManifest, Package},
+};
+use sol_lir::{lowering::LirLowering, rc, tail_calls, Program};
+
+use crate::RootDb;
+
+impl RootDb {
+    /// Lowers the package into the LIR, that is shared by the backends. Only the reachable
+    /// definitions are lowered, their irrelevant parameters are erased, their tail calls are
+    /// optimized, and the reference counting instructions are inserted.
+    pub fn lir_program(&self, package: Package) -> Program {
+        let global_env = self.global_env();
+        let reachability = self.reachability(package);
+        let erasures = self.erasures(package);
+        let mut lowering = LirLowering::new(self);
+
+        let mut definitions = package
+            .all_files(self)
+            .into_iter()
+            .flat_map(|file| {
+                sol_typer::infer_type_table(self, global_env, self.hir_lower(package, file))
+            })
+            .filter(|(definition, _)| reachability.is_reachable(self, *definition))
+            .collect::<Vec<_>>();
+        definitions.sort_by_key(|(definition, _)| definition.to_string(self));
+
+        for (definition, (term, _)) in definitions {
+            let term = self.erase(&erasures, definition, term);
+            lowering.definition(definition.to_string(self), &term);
+        }
+
+        let mut program = lowering.finish();
+        program.overflow = self.profile().settings(self).overflow;
+        tail_calls::optimize(&mut program);
+        rc::insert(&mut program);
+        program
+    }
+}
@@ -0,0 +1,185 @@
+use std::{fmt::Display, mem::size_of};
+
+use sol_diagnostic::{Diagnostic, Diagnostics};
+use sol_hir::{package::HasManifest, solver::Definition, source_map::SourceMapEntry};
+use sol_thir::{shared::GlobalEnv, source::Term, value::Type, ElaboratedTerm};
+
+use crate::RootDb;
+
+/// The memory usage of a jar, it's an estimation, as salsa doesn't expose the sizes of its
+/// tables.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JarMemory {
+    pub name: &'static str,
+    pub entities: usize,
+    pub estimated_bytes: usize,
+}
+
+impl JarMemory {
+    fn empty(name: &'static str) -> Self {
+        Self {
+            name,
+            entities: 0,
+            estimated_bytes: 0,
+        }
+    }
+
+    fn add(&mut self, entities: usize, estimated_bytes: usize) {
+        self.entities += entities;
+        self.estimated_bytes += estimated_bytes;
+    }
+
+    /// Adds the diagnostics, estimated by the reports and their messages.
+    fn add_diagnostics(&mut self, diagnostics: &[Diagnostic]) {
+        for diagnostic in diagnostics {
+            let message = diagnostic.0.to_string().len();
+            self.add(1, size_of::<sol_eyre::Report>() + message);
+        }
+    }
+}
+
+/// The memory usage of the database, per jar, returned by [`RootDb::report_memory`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MemoryReport {
+    pub jars: Vec<JarMemory>,
+}
+
+impl MemoryReport {
+    /// The estimated bytes of all the jars.
+    pub fn total_bytes(&self) -> usize {
+        self.jars.iter().map(|jar| jar.estimated_bytes).sum()
+    }
+}
+
+impl Display for MemoryReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for jar in &self.jars {
+            writeln!(
+                f,
+                "{}: {} entities, ~{} bytes",
+                jar.name, jar.entities, jar.estimated_bytes
+            )?;
+        }
+
+        write!(f, "total: ~{} bytes", self.total_bytes())
+    }
+}
+
+impl RootDb {
+    /// Reports the number of entities, and the estimated bytes, of each jar of the database.
+    /// The elaboration jars are measured through the type tables of the files, elaborated in
+    /// the `global_env`, that should be the environment of the running elaboration, so its
+    /// memos are reused.
+    ///
+    /// It does lower, and elaborate, the files that aren't yet, so it should not be called in
+    /// the hot paths, like on every keystroke.
+    pub fn report_memory(&self, global_env: GlobalEnv) -> MemoryReport {
+        let files = self
+            .files
+            .iter()
+            .map(|file| *file.value())
+            .collect::<Vec<_>>();
+        let mut vfs = JarMemory::empty("sol_vfs");
+        let mut syntax = JarMemory::empty("sol_syntax");
+        let mut diagnostic = JarMemory::empty("sol_diagnostic");
+        let mut hir = JarMemory::empty("sol_hir");
+        let mut hir_lowering = JarMemory::empty("sol_hir_lowering");
+        let mut thir = JarMemory::empty("sol_thir");
+        let mut thir_lowering = JarMemory::empty("sol_thir_lowering");
+        let mut typer = JarMemory::empty("sol_typer");
+
+        for file in files {
+            vfs.add(1, file.source_text(self).len());
+
+            let diagnostics = sol_syntax::parse::accumulated::<Diagnostics>(self, file);
+            diagnostic.add_diagnostics(&diagnostics);
+        }
+
+        for package in self.all_packages() {
+            for source in package.all_files(self) {
+                let nodes = count_nodes(source.syntax_node(self));
+                syntax.add(
+                    nodes,
+                    source.source_text(self).len() + nodes * SYNTAX_NODE_BYTES,
+                );
+
+                let hir_source = sol_hir_lowering::hir_lower(self, package, source);
+                let entries =
+                    sol_hir_lowering::source_map::source_map(self, package, source).element_count();
+                hir.add(entries, entries * size_of::<sol_hir::source::expr::Expr>());
+                hir_lowering.add(entries, entries * size_of::<SourceMapEntry>());
+
+                // The type tables only hold the roots of the elaborated terms, so the trees are
+                // estimated by their roots.
+                let table = sol_typer::infer_type_table(self, global_env, hir_source);
+                let definitions = table.len();
+                typer.add(
+                    definitions,
+                    definitions * size_of::<(Definition, (Term, Type))>(),
+                );
+                thir.add(
+                    definitions * 2,
+                    definitions * (size_of::<Term>() + size_of::<Type>()),
+                );
+                thir_lowering.add(definitions, definitions * size_of::<ElaboratedTerm>());
+
+                let lowering =
+                    sol_hir_lowering::hir_lower::accumulated::<Diagnostics>(self, package, source);
+                let elaboration = sol_typer::infer_type_table::accumulated::<Diagnostics>(
+                    self, global_env, hir_source,
+                );
+                diagnostic.add_diagnostics(&lowering);
+                diagnostic.add_diagnostics(&elaboration);
+            }
+        }
+
+        MemoryReport {
+            jars: vec![
+                vfs,
+                syntax,
+                diagnostic,
+                hir,
+                hir_lowering,
+                thir,
+                thir_lowering,
+                typer,
+            ],
+        }
+    }
+
+    /// Sets the capacity of the LRU caches of the elaboration queries, the least recently used
+    /// memos are evicted when there are more than `capacity` of them. A capacity of `0`
+    /// disables the eviction.
+    pub fn set_lru_capacity(&self, capacity: usize) {
+        sol_typer::infer_type_table::set_lru_capacity(self, capacity);
+        sol_typer::infer_binding_group::set_lru_capacity(self, capacity);
+    }
+}
+
+/// The estimated size of a tree-sitter node, in bytes.
+const SYNTAX_NODE_BYTES: usize = 32;
+
+/// Counts the nodes of the syntax tree, walking it with a cursor.
+fn count_nodes(tree: &sol_syntax::ParseTree) -> usize {
+    let mut cursor = tree.walk();
+    let mut count = 1;
+
+    loop {
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            count += 1;
+            continue;
+        }
+
+        // Goes up until there's a sibling to visit, or the root is reached.
+        loop {
+            if !cursor.goto_parent() {
+                return count;
+            }
+
+            if cursor.goto_next_sibling() {
+                count += 1;
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Defines the compiler plugins, that let external crates add custom lints, commands and
+//! codegen steps, without forking the compiler.
+//!
+//! The plugins are registered in the [`RootDb`], either at compile-time, with
+//! [`RootDb::register_plugin`], or dynamically, with [`RootDb::load_plugin`], that loads a
+//! shared library declared with [`declare_plugin!`], like `sol --plugin my_lint.so build`.
+//!
+//! The hooks are called in the order of the compilation, and the diagnostics that they return
+//! are reported just like the diagnostics of the compiler, so the lint levels apply to them.
+
+use std::sync::{Arc, RwLock};
+
+use sol_diagnostic::Diagnostic;
+use sol_hir::{package::Package, source::HirSource};
+use sol_syntax::Source;
+use sol_thir::{shared::GlobalEnv, TypeTable};
+
+use crate::RootDb;
+
+/// A compiler plugin, all the hooks have a default implementation that does nothing, so the
+/// plugins only override the hooks they care about.
+#[allow(unused_variables)]
+pub trait CompilerPlugin: Send + Sync {
+    /// The name of the plugin, it's used in the logs.
+    fn name(&self) -> &str;
+
+    /// Called when the plugin is registered, it's used to register the compile-time commands,
+    /// with [`RootDb::register_command`].
+    fn register(&self, db: &RootDb) {}
+
+    /// Called after the file is parsed into the concrete syntax tree.
+    fn after_parse(&self, db: &RootDb, source: Source) -> Vec<Diagnostic> {
+        vec![]
+    }
+
+    /// Called after the file is lowered into the high-level intermediate representation, and
+    /// its names are resolved.
+    fn after_hir_lower(&self, db: &RootDb, package: Package, hir: HirSource) -> Vec<Diagnostic> {
+        vec![]
+    }
+
+    /// Called after the definitions of the file are elaborated, with their elaborated terms
+    /// and types.
+    fn after_elaboration(&self, db: &RootDb, hir: HirSource, table: &TypeTable) -> Vec<Diagnostic> {
+        vec![]
+    }
+}
+
+/// The constructor of the plugin, that is exported by the shared libraries with the
+/// [`PLUGIN_CONSTRUCTOR`] name.
+pub type PluginConstructor = unsafe fn() -> Box<dyn CompilerPlugin>;
+
+/// The name of the symbol of the [`PluginConstructor`], in the shared libraries.
+pub const PLUGIN_CONSTRUCTOR: &str = "_sol_plugin_create";
+
+/// Declares the plugin of a shared library, so it can be loaded with `--plugin`. The library
+/// should be a `cdylib`, compiled with the same Rust version as the compiler, as the plugins
+/// are passed through the Rust ABI.
+///
+/// ```ignore
+/// sol_driver::declare_plugin!(MyLint, MyLint::default);
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($plugin:ty, $constructor:path) => {
+        #[no_mangle]
+        pub fn _sol_plugin_create() -> Box<dyn $crate::plugin::CompilerPlugin> {
+            let plugin: $plugin = $constructor();
+            Box::new(plugin)
+        }
+    };
+}
+
+/// The registry of the plugins, they're called in the order they were registered.
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: RwLock<Vec<Arc<dyn CompilerPlugin>>>,
+
+    /// The loaded shared libraries, they're declared after the plugins, so they're only
+    /// unloaded after the plugins are dropped.
+    #[cfg(feature = "dynamic-plugins")]
+    libraries: std::sync::Mutex<Vec<libloading::Library>>,
+}
+
+impl PluginRegistry {
+    /// Gets the registered plugins.
+    pub fn all(&self) -> Vec<Arc<dyn CompilerPlugin>> {
+        self.plugins.read().unwrap().clone()
+    }
+
+    /// Checks if there's no plugin registered, so the hooks can be skipped.
+    pub fn is_empty(&self) -> bool {
+        self.plugins.read().unwrap().is_empty()
+    }
+}
+
+impl RootDb {
+    /// Registers a plugin, calling its [`CompilerPlugin::register`] hook.
+    pub fn register_plugin<P: CompilerPlugin + 'static>(&self, plugin: P) {
+        self.register_boxed_plugin(Box::new(plugin));
+    }
+
+    /// Loads the plugin of the shared library, that is declared with [`declare_plugin!`].
+    #[cfg(feature = "dynamic-plugins")]
+    pub fn load_plugin(&self, path: &std::path::Path) -> sol_eyre::Result<()> {
+        use sol_eyre::Context;
+
+        // SAFETY: The library is kept loaded while the plugin is alive, and the constructor is
+        // declared with the `declare_plugin!` macro, so it has the expected signature.
+        let plugin = unsafe {
+            let library = libloading::Library::new(path)
+                .wrap_err_with(|| format!("Failed to load the plugin {}", path.display()))?;
+            let constructor = library
+                .get::<PluginConstructor>(PLUGIN_CONSTRUCTOR.as_bytes())
+                .wrap_err_with(|| format!("{} doesn't declare a plugin", path.display()))?;
+            let plugin = constructor();
+
+            self.plugins.libraries.lock().unwrap().push(library);
+            plugin
+        };
+
+        log::info!("loaded the plugin `{}`", plugin.name());
+        self.register_boxed_plugin(plugin);
+
+        Ok(())
+    }
+
+    /// Runs the [`CompilerPlugin::after_parse`] hooks.
+    pub fn plugins_after_parse(&self, source: Source) -> Vec<Diagnostic> {
+        self.plugins
+            .all()
+            .iter()
+            .flat_map(|plugin| plugin.after_parse(self, source))
+            .collect()
+    }
+
+    /// Runs the [`CompilerPlugin::after_hir_lower`] hooks.
+    pub fn plugins_after_hir_lower(&self, package: Package, hir: HirSource) -> Vec<Diagnostic> {
+        self.plugins
+            .all()
+            .iter()
+            .flat_map(|plugin| plugin.after_hir_lower(self, package, hir))
+            .collect()
+    }
+
+    /// Runs the [`CompilerPlugin::after_elaboration`] hooks. The source is only elaborated if
+    /// there's some plugin registered, in the `global_env` of the running elaboration, so its
+    /// memos are reused.
+    pub fn plugins_after_elaboration(
+        &self,
+        global_env: GlobalEnv,
+        hir: HirSource,
+    ) -> Vec<Diagnostic> {
+        if self.plugins.is_empty() {
+            return vec![];
+        }
+
+        let table = sol_typer::infer_type_table(self, global_env, hir);
+
+        self.plugins
+            .all()
+            .iter()
+            .flat_map(|plugin| plugin.after_elaboration(self, hir, &table))
+            .collect()
+    }
+
+    fn register_boxed_plugin(&self, plugin: Box<dyn CompilerPlugin>) {
+        let plugin: Arc<dyn CompilerPlugin> = plugin.into();
+        plugin.register(self);
+
+        self.plugins.plugins.write().unwrap().push(plugin);
+    }
+}
@@ -0,0 +1,230 @@
+//! Defines the reachability analysis, that runs before the code is emitted by the backends, so
+//! the unreachable definitions are pruned from the output.
+//!
+//! The analysis starts from the roots, that are the `main` function of the binaries, the
+//! definitions exported by the package, and the definitions with the `@keep` attribute, and
+//! follows the references of the elaborated terms, that are the edges of the THIR call graph.
+
+use std::fmt::Display;
+
+use fxhash::{FxHashMap, FxHashSet};
+use sol_hir::{
+    lowering::HirLowering,
+    package::{HasManifest, Package, PackageKind},
+    solver::{Definition, DefinitionId},
+    source::top_level::{Signature, TopLevel},
+};
+use sol_thir::{
+    shared::{Constructor, ConstructorKind},
+    source::Term,
+    TypeTable,
+};
+
+use crate::RootDb;
+
+/// The attribute that forces a definition to be kept, even if it's unreachable.
+pub const KEEP_ATTRIBUTE: &str = "keep";
+
+/// The result of the reachability analysis of a package.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Reachability {
+    reachable: FxHashSet<DefinitionId>,
+
+    /// The unreachable definitions, with the number of THIR nodes of their terms.
+    pub pruned: Vec<(Definition, usize)>,
+}
+
+impl Reachability {
+    /// Checks if the definition is reachable, so it should be emitted.
+    pub fn is_reachable(&self, db: &RootDb, definition: Definition) -> bool {
+        self.reachable.contains(&definition.id(db))
+    }
+
+    /// Removes the unreachable definitions of the type table.
+    pub fn prune(&self, db: &RootDb, table: TypeTable) -> TypeTable {
+        table
+            .into_iter()
+            .filter(|(definition, _)| self.is_reachable(db, *definition))
+            .collect()
+    }
+
+    /// The number of THIR nodes that are pruned.
+    pub fn pruned_nodes(&self) -> usize {
+        self.pruned.iter().map(|(_, nodes)| nodes).sum()
+    }
+}
+
+impl Display for Reachability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pruned {} unreachable definitions, with {} nodes",
+            self.pruned.len(),
+            self.pruned_nodes()
+        )
+    }
+}
+
+impl RootDb {
+    /// Computes the definitions that are reachable from the roots of the package. The
+    /// definitions of the dependencies are analysed too, as they're emitted with the package.
+    pub fn reachability(&self, package: Package) -> Reachability {
+        let global_env = self.global_env();
+        let mut terms = FxHashMap::default();
+        let mut roots = vec![];
+
+        for current in self.all_packages() {
+            for file in current.all_files(self) {
+                let hir = self.hir_lower(current, file);
+                let table = sol_typer::infer_type_table(self, global_env, hir);
+
+                for (definition, (term, _)) in table.iter() {
+                    terms.insert(definition.id(self), (*definition, term.clone()));
+                }
+
+                if current != package {
+                    continue;
+                }
+
+                for top_level in hir.contents(self).iter() {
+                    let TopLevel::BindingGroup(group) = top_level else {
+                        continue;
+                    };
+
+                    let signature = group.signature(self);
+                    if self.is_root(package, file == package.main(self), signature) {
+                        roots.push(signature.name(self).id(self));
+                    }
+                }
+            }
+        }
+
+        let mut reachable = FxHashSet::default();
+        while let Some(id) = roots.pop() {
+            if !reachable.insert(id) {
+                continue;
+            }
+
+            if let Some((_, term)) = terms.get(&id) {
+                let mut references = vec![];
+                self.term_references(term, &mut references);
+                roots.extend(references.into_iter().map(|definition| definition.id(self)));
+            }
+        }
+
+        let mut pruned = terms
+            .into_iter()
+            .filter(|(id, _)| !reachable.contains(id))
+            .map(|(_, (definition, term))| (definition, term_size(&term)))
+            .collect::<Vec<_>>();
+        pruned.sort_by_key(|(definition, _)| definition.to_string(self));
+
+        Reachability { reachable, pruned }
+    }
+
+    /// Checks if the function is a root of the analysis, that is the `main` function of the
+    /// binaries, an exported definition, or a definition with the `@keep` attribute.
+    fn is_root(&self, package: Package, main_file: bool, signature: Signature) -> bool {
+        let name = signature.name(self).to_string(self);
+        let has_attribute = |expected: &str| {
+            signature
+                .attributes(self)
+                .iter()
+                .any(|attribute| attribute.name(self).to_string(self).as_deref() == Some(expected))
+        };
+
+        match package.kind(self) {
+            PackageKind::Binary => {
+                (main_file && name.rsplit('.').next() == Some("main"))
+                    || has_attribute("export")
+                    || has_attribute(KEEP_ATTRIBUTE)
+            }
+            PackageKind::Library => {
+                package.is_exported(self, &name)
+                    || has_attribute("export")
+                    || has_attribute(KEEP_ATTRIBUTE)
+            }
+        }
+    }
+
+    /// Collects the global definitions that are referenced by the term.
+    fn term_references(&self, term: &Term, references: &mut Vec<Definition>) {
+        match term {
+            Term::Constructor(Constructor {
+                kind: ConstructorKind::Reference(reference),
+                ..
+            }) => references.push(reference.definition(self)),
+            Term::Lam(_, _, body) => self.term_references(body, references),
+            Term::App(callee, argument) => {
+                self.term_references(callee, references);
+                self.term_references(argument, references);
+            }
+            Term::Pi(_, _, domain, codomain) => {
+                self.term_references(domain, references);
+                self.term_references(codomain, references);
+            }
+            Term::Ann(value, type_repr) => {
+                self.term_references(value, references);
+                self.term_references(type_repr, references);
+            }
+            Term::Location(_, term) | Term::Glued(_, _, term) => {
+                self.term_references(term, references)
+            }
+            Term::Record(fields) | Term::RecordType(fields) => {
+                for (_, value) in fields {
+                    self.term_references(value, references);
+                }
+            }
+            Term::Project(record, _, _) => self.term_references(record, references),
+            Term::Eq(type_repr, lhs, rhs) => {
+                self.term_references(type_repr, references);
+                self.term_references(lhs, references);
+                self.term_references(rhs, references);
+            }
+            Term::Transport(equality, value) => {
+                self.term_references(equality, references);
+                self.term_references(value, references);
+            }
+            Term::ArrayType(type_repr) => self.term_references(type_repr, references),
+            Term::Array(terms) | Term::Intrinsic(_, terms, _) => {
+                for term in terms {
+                    self.term_references(term, references);
+                }
+            }
+            Term::Match(scrutinee, tree) => {
+                self.term_references(scrutinee, references);
+                for term in tree.terms() {
+                    self.term_references(term, references);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Counts the THIR nodes of the term, it's used to report the savings.
+pub(crate) fn term_size(term: &Term) -> usize {
+    match term {
+        Term::Lam(_, _, body) => 1 + term_size(body),
+        Term::App(a, b) | Term::Pi(_, _, a, b) | Term::Ann(a, b) | Term::Transport(a, b) => {
+            1 + term_size(a) + term_size(b)
+        }
+        Term::Eq(a, b, c) => 1 + term_size(a) + term_size(b) + term_size(c),
+        Term::Location(_, term) | Term::Glued(_, _, term) => term_size(term),
+        Term::Record(fields) | Term::RecordType(fields) => {
+            1 + fields
+                .iter()
+                .map(|(_, value)| term_size(value))
+                .sum::<usize>()
+        }
+        Term::Project(record, _, _) => 1 + term_size(record),
+        Term::ArrayType(type_repr) => 1 + term_size(type_repr),
+        Term::Array(terms) | Term::Intrinsic(_, terms, _) => {
+            1 + terms.iter().map(term_size).sum::<usize>()
+        }
+        Term::Match(scrutinee, tree) => {
+            1 + term_size(scrutinee) + tree.terms().into_iter().map(term_size).sum::<usize>()
+        }
+        _ => 1,
+    }
+}
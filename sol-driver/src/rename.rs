@@ -7,6 +7,7 @@ use sol_hir::{
     reparse::reparse_hir_path,
     solver::{references, Definition},
     source::{HirLocation, Location},
+    span_map::SpanId,
 };
 
 use crate::RootDb;
@@ -61,12 +62,17 @@ impl RootDb {
             // Set the name within new location
             let location = reference.location(&*self);
 
+            let span = SpanId::of(
+                &*self,
+                &location.ending(SourceOffset::from(
+                    old_location.end().offset() + new_name.len(),
+                )),
+            );
+
             reference
-                .set_location(&mut *self)
+                .set_span(&mut *self)
                 .with_durability(Durability::LOW)
-                .to(location.ending(SourceOffset::from(
-                    old_location.end().offset() + new_name.len(),
-                )));
+                .to(span);
         }
 
         RenamesResult {
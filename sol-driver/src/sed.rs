@@ -0,0 +1,305 @@
+//! Defines the structural search and replace over the HIR, that is used by `sol sed`, and is
+//! the foundation of the user-defined lint rules and codemods.
+//!
+//! The pattern is an expression with metavariables, like `$x + 0`, it's lowered just like any
+//! other expression, and matched structurally against the lowered HIR, so the formatting and
+//! the comments doesn't matter. Each metavariable matches any expression, and if it's used more
+//! than once, all the occurrences should have the same text.
+//!
+//! The replacement is a template, where the metavariables are replaced by the text that they
+//! matched, like `$x`.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use sol_hir::{
+    lowering::HirLowering,
+    package::Package,
+    solver::Reference,
+    source::{
+        expr::{CallExpr, Callee, Expr},
+        literal::Literal,
+        top_level::TopLevel,
+        HirElement, Location, Spanned,
+    },
+    walking::{HirListener, Walker},
+};
+use sol_vfs::SourceFile;
+
+use crate::{rename::TextEdit, RootDb};
+
+/// The prefix of the identifiers that the metavariables are desugared to, so the pattern can
+/// be parsed by the Sol grammar.
+const METAVARIABLE_PREFIX: &str = "__sed_";
+
+/// A match of a pattern, with the text locations that each metavariable matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SedMatch {
+    pub location: Location,
+    pub bindings: HashMap<String, Location>,
+}
+
+/// A lowered pattern of the structural search, it's created by [`RootDb::sed_pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SedPattern {
+    expr: Expr,
+}
+
+impl RootDb {
+    /// Parses and lowers the pattern, in the scope of the package, so the names of the pattern
+    /// are resolved just like in the package files.
+    pub fn sed_pattern(&self, package: Package, pattern: &str) -> sol_eyre::Result<SedPattern> {
+        let text = format!("#eval {}\n", desugar_metavariables(pattern));
+        let file = SourceFile::new(self, PathBuf::from("sed.sol"), "Sed".into(), text);
+        let source = sol_syntax::parse(self, file);
+        let hir = self.hir_lower(package, source);
+
+        match hir.contents(self).first() {
+            Some(TopLevel::Command(command)) if command.arguments(self).len() == 1 => {
+                Ok(SedPattern {
+                    expr: command.arguments(self)[0].clone(),
+                })
+            }
+            _ => sol_eyre::bail!("the pattern `{pattern}` is not a valid expression"),
+        }
+    }
+
+    /// Finds the matches of the pattern in all files of the package. The matches doesn't
+    /// overlap, if a match is inside another one, only the outer one is returned.
+    pub fn sed_matches(&self, package: Package, pattern: &SedPattern) -> Vec<SedMatch> {
+        let mut matches: Vec<SedMatch> = vec![];
+
+        for file in package.all_files(self) {
+            let mut collector = ExprCollector::default();
+            self.hir_lower(package, file).accept(self, &mut collector);
+
+            for expr in collector.exprs {
+                let location = expr.location(self);
+                let overlaps = matches.iter().any(|other| {
+                    other.location.source() == location.source()
+                        && other.location.start().offset() <= location.start().offset()
+                        && location.end().offset() <= other.location.end().offset()
+                });
+                if overlaps {
+                    continue;
+                }
+
+                let mut bindings = HashMap::new();
+                if self.match_expr(&pattern.expr, &expr, &mut bindings) {
+                    matches.push(SedMatch { location, bindings });
+                }
+            }
+        }
+
+        matches
+    }
+
+    /// Searches the pattern in the package, and creates the text edits that replaces each match
+    /// with the `replacement` template.
+    pub fn sed(
+        &self,
+        package: Package,
+        pattern: &str,
+        replacement: &str,
+    ) -> sol_eyre::Result<Vec<TextEdit>> {
+        let pattern = self.sed_pattern(package, pattern)?;
+
+        self.sed_matches(package, &pattern)
+            .into_iter()
+            .map(|found| {
+                Ok(TextEdit {
+                    new_text: self.expand_template(replacement, &found)?,
+                    old_location: found.location,
+                })
+            })
+            .collect()
+    }
+
+    /// Replaces the metavariables of the template with the text that they matched.
+    fn expand_template(&self, template: &str, found: &SedMatch) -> sol_eyre::Result<String> {
+        let mut expanded = String::new();
+        let mut chars = template.char_indices().peekable();
+
+        while let Some((index, c)) = chars.next() {
+            let name_len = metavariable_len(&template[index + c.len_utf8()..]);
+            if c != '$' || name_len == 0 {
+                expanded.push(c);
+                continue;
+            }
+
+            let name = &template[index + 1..index + 1 + name_len];
+            let Some(location) = found.bindings.get(name) else {
+                sol_eyre::bail!("the metavariable `${name}` isn't bound by the pattern");
+            };
+            expanded.push_str(&self.location_text(location));
+
+            while chars
+                .next_if(|(next, _)| *next <= index + name_len)
+                .is_some()
+            {}
+        }
+
+        Ok(expanded)
+    }
+
+    /// Matches the pattern against the expression, binding the metavariables of the pattern.
+    fn match_expr(
+        &self,
+        pattern: &Expr,
+        expr: &Expr,
+        bindings: &mut HashMap<String, Location>,
+    ) -> bool {
+        if let Expr::Path(reference) = pattern {
+            if let Some(name) = self.metavariable(*reference) {
+                return self.bind(name, expr.location(self), bindings);
+            }
+        }
+
+        match (pattern, expr) {
+            (Expr::Empty, Expr::Empty) => true,
+            (Expr::Hole(_), Expr::Hole(_)) => true,
+            (Expr::Type(pattern, _), Expr::Type(expr, _)) => pattern == expr,
+            (Expr::Path(pattern), Expr::Path(expr)) => self.match_reference(*pattern, *expr),
+            (Expr::Literal(pattern), Expr::Literal(expr)) => match_literal(pattern, expr),
+            (Expr::Call(pattern), Expr::Call(expr)) => self.match_call(pattern, expr, bindings),
+            _ => false,
+        }
+    }
+
+    /// Matches the call expressions, the do-notation blocks aren't supported in the patterns
+    /// yet, so they only match calls without blocks.
+    fn match_call(
+        &self,
+        pattern: &CallExpr,
+        expr: &CallExpr,
+        bindings: &mut HashMap<String, Location>,
+    ) -> bool {
+        let callee = match (&pattern.callee, &expr.callee) {
+            (Callee::Reference(reference), callee) if self.metavariable(*reference).is_some() => {
+                let location = match callee {
+                    Callee::Reference(reference) => reference.location(self),
+                    Callee::Expr(expr) => expr.location(self),
+                    _ => return false,
+                };
+
+                self.bind(self.metavariable(*reference).unwrap(), location, bindings)
+            }
+            (Callee::Reference(pattern), Callee::Reference(expr)) => {
+                self.match_reference(*pattern, *expr)
+            }
+            (Callee::Expr(pattern), Callee::Expr(expr)) => self.match_expr(pattern, expr, bindings),
+            (pattern, expr) => std::mem::discriminant(pattern) == std::mem::discriminant(expr),
+        };
+
+        callee
+            && pattern.kind == expr.kind
+            && pattern.do_notation.is_none()
+            && expr.do_notation.is_none()
+            && pattern.arguments.len() == expr.arguments.len()
+            && pattern
+                .arguments
+                .iter()
+                .zip(expr.arguments.iter())
+                .all(|(pattern, expr)| self.match_expr(pattern, expr, bindings))
+    }
+
+    /// Matches the references, they match if they resolve to the same definition, or if they
+    /// are spelled the same, as the local variables of the pattern doesn't resolve.
+    fn match_reference(&self, pattern: Reference, expr: Reference) -> bool {
+        pattern.definition(self) == expr.definition(self)
+            || self.location_text(&pattern.location(self))
+                == self.location_text(&expr.location(self))
+    }
+
+    /// Binds the metavariable to the location, if it's already bound, the texts of both
+    /// locations should be the same.
+    fn bind(
+        &self,
+        name: String,
+        location: Location,
+        bindings: &mut HashMap<String, Location>,
+    ) -> bool {
+        match bindings.get(&name) {
+            Some(bound) => self.location_text(bound) == self.location_text(&location),
+            None => {
+                bindings.insert(name, location);
+                true
+            }
+        }
+    }
+
+    /// Gets the name of the metavariable, if the reference is one.
+    fn metavariable(&self, reference: Reference) -> Option<String> {
+        self.location_text(&reference.location(self))
+            .strip_prefix(METAVARIABLE_PREFIX)
+            .map(str::to_string)
+    }
+
+    /// Gets the text of the location, in its source file.
+    fn location_text(&self, location: &Location) -> String {
+        let Some(source) = location.source() else {
+            return String::new();
+        };
+
+        source
+            .source_text(self)
+            .get(location.start().offset()..location.end().offset())
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+/// Matches the literals by their values, ignoring their locations.
+fn match_literal(pattern: &Spanned<Literal>, expr: &Spanned<Literal>) -> bool {
+    pattern.value == expr.value
+}
+
+/// Gets the length of the metavariable name at the start of the text, it's zero if there's
+/// no name, like in the `$` operator.
+fn metavariable_len(text: &str) -> usize {
+    text.char_indices()
+        .find(|(index, c)| !(c.is_alphanumeric() || *c == '_' || (*index > 0 && *c == '\'')))
+        .map(|(index, _)| index)
+        .unwrap_or(text.len())
+}
+
+/// Replaces the metavariables, like `$x`, with identifiers, like `__sed_x`, that are parsed by
+/// the Sol grammar.
+fn desugar_metavariables(pattern: &str) -> String {
+    let mut desugared = String::new();
+    let mut previous = None;
+
+    for (index, c) in pattern.char_indices() {
+        // The `$` is part of the identifier if it isn't at its start, like in `a$b`.
+        let inside_identifier = previous.is_some_and(|p: char| p.is_alphanumeric() || p == '_');
+        previous = Some(c);
+
+        if c == '$' && !inside_identifier && metavariable_len(&pattern[index + 1..]) > 0 {
+            desugared.push_str(METAVARIABLE_PREFIX);
+        } else {
+            desugared.push(c);
+        }
+    }
+
+    desugared
+}
+
+/// Collects all the expressions that can be the root of a match, in the order that they're
+/// visited, so the outer expressions come first.
+#[derive(Default)]
+struct ExprCollector {
+    exprs: Vec<Expr>,
+}
+
+impl HirListener for ExprCollector {
+    fn enter_path_expr(&mut self, definition: Reference) {
+        self.exprs.push(Expr::Path(definition));
+    }
+
+    fn enter_literal_expr(&mut self, literal: Spanned<Literal>) {
+        self.exprs.push(Expr::Literal(literal));
+    }
+
+    fn enter_call_expr(&mut self, call_expr: CallExpr) {
+        self.exprs.push(Expr::Call(call_expr));
+    }
+}
@@ -0,0 +1,221 @@
+//! Defines the read-only semantic model of the Sol programs. It's the public API to build
+//! external tools, like formatters, linters and documentation generators, without reaching
+//! into the salsa internals, that can change between compiler versions.
+
+use std::{fmt::Display, path::PathBuf};
+
+use salsa::DebugWithDb;
+use sol_hir::{
+    fmt::HirFormatter,
+    lowering::HirLowering,
+    package::{HasManifest, Package},
+    reference::ReferenceWalker,
+    solver::{Definition, DefinitionKind, Reference},
+    source::{declaration::Declaration, top_level::TopLevel, HirElement, HirSource, Location},
+};
+use sol_syntax::Source;
+
+use crate::RootDb;
+
+/// A stable identifier of a symbol. Unlike the salsa ids, it does not change between the
+/// revisions, as long as the symbol keeps its package, file, qualified name and kind, so it
+/// can be stored by the external tools.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SymbolId {
+    pub package: String,
+    pub file: PathBuf,
+    pub name: String,
+    pub kind: DefinitionKind,
+}
+
+impl Display for SymbolId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}::{}::{}",
+            self.package,
+            self.file.display(),
+            self.name
+        )
+    }
+}
+
+/// The signature of a symbol, with its documentation, parameters and type, formatted as they
+/// would be written in a source file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SymbolSignature {
+    pub id: SymbolId,
+    pub docs: Vec<String>,
+    pub parameters: Vec<String>,
+    pub type_rep: Option<String>,
+    pub location: Location,
+}
+
+/// The read-only semantic model, that is created by [`RootDb::semantic_model`]. It does
+/// answer questions about the symbols of the registered packages.
+pub struct SemanticModel<'db> {
+    db: &'db RootDb,
+}
+
+impl RootDb {
+    /// Creates the read-only [`SemanticModel`] of the registered packages.
+    pub fn semantic_model(&self) -> SemanticModel<'_> {
+        SemanticModel { db: self }
+    }
+}
+
+impl<'db> SemanticModel<'db> {
+    /// Gets the stable [`SymbolId`] of the definition. It returns [`None`] if the definition
+    /// isn't defined in a source file, like the primitives.
+    pub fn symbol_id(&self, definition: Definition) -> Option<SymbolId> {
+        let source = definition.location(self.db).source()?;
+        let package = self.package_of(source)?;
+
+        Some(SymbolId {
+            package: package.name(self.db).clone(),
+            file: source.file_path(self.db).clone(),
+            name: definition.to_string(self.db),
+            kind: definition.kind(self.db),
+        })
+    }
+
+    /// Finds the definition of the symbol, in the current revision.
+    pub fn definition(&self, id: &SymbolId) -> Option<Definition> {
+        self.symbols(self.find_source(id)?)
+            .into_iter()
+            .find(|definition| self.symbol_id(*definition).as_ref() == Some(id))
+    }
+
+    /// Gets all the top level symbols of the source file, in the order they are declared.
+    pub fn symbols(&self, source: Source) -> Vec<Definition> {
+        let Some(hir) = self.hir(source) else {
+            return vec![];
+        };
+
+        hir.contents(self.db)
+            .iter()
+            .flat_map(|top_level| match top_level {
+                TopLevel::BindingGroup(group) => vec![group.name(self.db)],
+                TopLevel::Inductive(inductive) => std::iter::once(inductive.name(self.db))
+                    .chain(inductive.variants(self.db).iter().map(|v| v.name(self.db)))
+                    .collect(),
+                TopLevel::TypeAlias(alias) => vec![alias.name(self.db)],
+                TopLevel::Trait(trait_decl) => std::iter::once(trait_decl.name(self.db))
+                    .chain(trait_decl.methods(self.db).iter().map(|m| m.name(self.db)))
+                    .collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    /// Gets the symbol at the given byte offset of the source file, either the symbol that is
+    /// declared, or the symbol that is referenced there.
+    pub fn symbol_at(&self, source: Source, offset: usize) -> Option<SymbolId> {
+        let contains = move |location: &Location| {
+            location.source() == Some(source)
+                && (location.start().offset()..=location.end().offset()).contains(&offset)
+        };
+
+        let declared = self
+            .symbols(source)
+            .into_iter()
+            .find(|definition| contains(&definition.location(self.db)));
+        if let Some(definition) = declared {
+            return self.symbol_id(definition);
+        }
+
+        let reference = ReferenceWalker::new(move |db, reference: Reference, _| {
+            contains(&reference.location(db))
+        })
+        .build(self.db)
+        .collect(self.hir(source)?)
+        .into_iter()
+        .next()?;
+
+        self.symbol_id(reference.definition(self.db))
+    }
+
+    /// Gets the signature of the symbol, with its documentation, parameters and type.
+    pub fn signature(&self, id: &SymbolId) -> Option<SymbolSignature> {
+        let definition = self.definition(id)?;
+        let hir = self.hir(self.find_source(id)?)?;
+        let declaration = hir
+            .contents(self.db)
+            .iter()
+            .filter_map(|top_level| -> Option<Box<dyn Declaration>> {
+                match top_level {
+                    TopLevel::BindingGroup(group) => Some(Box::new(*group)),
+                    TopLevel::Inductive(inductive) => Some(Box::new(*inductive)),
+                    TopLevel::TypeAlias(alias) => Some(Box::new(*alias)),
+                    TopLevel::Trait(trait_decl) => Some(Box::new(*trait_decl)),
+                    _ => None,
+                }
+            })
+            .find(|declaration| declaration.name(self.db).id(self.db) == definition.id(self.db))?;
+
+        Some(SymbolSignature {
+            id: id.clone(),
+            docs: declaration
+                .docs(self.db)
+                .into_iter()
+                .filter_map(|doc| doc_text(self.db, doc.location(self.db)))
+                .collect(),
+            parameters: declaration
+                .parameters(self.db)
+                .into_iter()
+                .map(|parameter| format!("{:?}", parameter.formatter().debug_all(self.db)))
+                .collect(),
+            type_rep: declaration
+                .type_rep(self.db)
+                .map(|type_rep| format!("{:?}", type_rep.formatter().debug_all(self.db))),
+            location: declaration.location(self.db),
+        })
+    }
+
+    /// Gets the symbols that are children of the given symbol, like the constructors of an
+    /// inductive type, or the top level symbols of a module.
+    pub fn children(&self, module: &SymbolId) -> Vec<SymbolId> {
+        let symbols = match self.find_source(module) {
+            Some(source) => self.symbols(source),
+            None => return vec![],
+        };
+        let prefix = format!("{}.", module.name);
+
+        symbols
+            .into_iter()
+            .filter_map(|definition| self.symbol_id(definition))
+            .filter(|id| match module.kind {
+                DefinitionKind::Module => true,
+                _ => id.name.starts_with(&prefix),
+            })
+            .collect()
+    }
+
+    fn find_source(&self, id: &SymbolId) -> Option<Source> {
+        self.db
+            .all_packages()
+            .into_iter()
+            .filter(|package| *package.name(self.db) == id.package)
+            .flat_map(|package| package.all_files(self.db))
+            .find(|source| *source.file_path(self.db) == id.file)
+    }
+
+    fn package_of(&self, source: Source) -> Option<Package> {
+        self.db
+            .all_packages()
+            .into_iter()
+            .find(|package| package.all_files(self.db).contains(&source))
+    }
+
+    fn hir(&self, source: Source) -> Option<HirSource> {
+        Some(self.db.hir_lower(self.package_of(source)?, source))
+    }
+}
+
+/// Gets the text of a doc string, without the `//!` prefix.
+fn doc_text(db: &RootDb, location: Location) -> Option<String> {
+    let text = location.source()?.source_text(db);
+    let text = text.get(location.start().offset()..location.end().offset())?;
+
+    Some(text.trim_start_matches("//!").trim().to_string())
+}
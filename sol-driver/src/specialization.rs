@@ -0,0 +1,213 @@
+//! Defines the specialization pass, that duplicates the polymorphic functions for each
+//! concrete instantiation found at the call sites, so the backends can avoid the universal
+//! boxed representations in the hot paths.
+//!
+//! A call site is specialized if all of its implicit arguments are closed terms, like
+//! `id {Int} 10`, and the function is smaller than [`SIZE_THRESHOLD`], as duplicating large
+//! functions increases the code size more than it speeds up the program.
+//!
+//! The pass is optional, it's enabled with `specialize = true` in the build profile, and is
+//! enabled by default in the `release` profile.
+
+use std::collections::VecDeque;
+
+use fxhash::{FxHashMap, FxHashSet};
+use sol_hir::{
+    lowering::HirLowering,
+    package::{HasManifest, Package},
+    solver::{Definition, DefinitionId},
+};
+use sol_thir::{
+    shared::{Constructor, ConstructorKind, Env, Implicitness},
+    source::Term,
+};
+
+use crate::{reachability::term_size, RootDb};
+
+/// The maximum number of THIR nodes of a function, to be specialized.
+pub const SIZE_THRESHOLD: usize = 64;
+
+/// The maximum number of specializations of a single function.
+pub const MAX_INSTANCES: usize = 8;
+
+/// A specialized copy of a polymorphic function, for a concrete instantiation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpecializedFunction {
+    /// The name of the specialized function, like `id$0`.
+    pub name: String,
+    pub definition: Definition,
+
+    /// The closed implicit arguments of the instantiation.
+    pub arguments: Vec<Term>,
+
+    /// The term of the function, with the implicit arguments applied and normalised.
+    pub term: Term,
+}
+
+impl RootDb {
+    /// Specializes the polymorphic functions of the package, for each instantiation found at
+    /// the call sites. It returns nothing if the pass is disabled in the build profile.
+    pub fn specialize(&self, package: Package) -> Vec<SpecializedFunction> {
+        if !self.profile().settings(self).specialize {
+            return vec![];
+        }
+
+        let global_env = self.global_env();
+        let mut terms = FxHashMap::default();
+        for file in package.all_files(self) {
+            let hir = self.hir_lower(package, file);
+            for (definition, (term, _)) in sol_typer::infer_type_table(self, global_env, hir) {
+                terms.insert(definition.id(self), (definition, term));
+            }
+        }
+
+        let mut instantiations = vec![];
+        for (_, term) in terms.values() {
+            self.instantiations(&terms, term, &mut instantiations);
+        }
+
+        let mut seen = FxHashSet::default();
+        let mut counts = FxHashMap::<DefinitionId, usize>::default();
+        let mut specialized = vec![];
+        for (id, arguments) in instantiations {
+            let (definition, term) = &terms[&id];
+            let count = counts.entry(id).or_default();
+            if *count >= MAX_INSTANCES || !seen.insert((id, arguments.clone())) {
+                continue;
+            }
+
+            let applied = arguments.iter().fold(term.clone(), |callee, argument| {
+                Term::App(callee.into(), argument.clone().into())
+            });
+            let Ok(term) = applied.normalise(self, Env::new(self, VecDeque::new())) else {
+                continue;
+            };
+
+            specialized.push(SpecializedFunction {
+                name: format!("{}${count}", definition.to_string(self)),
+                definition: *definition,
+                arguments,
+                term,
+            });
+            *count += 1;
+        }
+
+        specialized
+    }
+
+    /// Collects the instantiations of the polymorphic functions, that are the calls with closed
+    /// implicit arguments, of the functions that are small enough to be specialized. The
+    /// functions should be closed too, as the errors can't be evaluated.
+    fn instantiations(
+        &self,
+        terms: &FxHashMap<DefinitionId, (Definition, Term)>,
+        term: &Term,
+        instantiations: &mut Vec<(DefinitionId, Vec<Term>)>,
+    ) {
+        match term {
+            Term::App(..) => {
+                let (head, arguments) = term.spine();
+                if let Term::Constructor(Constructor {
+                    kind: ConstructorKind::Reference(reference),
+                    ..
+                }) = head
+                {
+                    let id = reference.definition(self).id(self);
+                    let arity = terms
+                        .get(&id)
+                        .filter(|(_, callee)| {
+                            term_size(callee) <= SIZE_THRESHOLD && is_closed(callee)
+                        })
+                        .map_or(0, |(_, callee)| implicit_arity(callee));
+                    let implicits = arguments.iter().take(arity).copied();
+
+                    if arity > 0 && arguments.len() >= arity && implicits.clone().all(is_closed) {
+                        instantiations.push((id, implicits.cloned().collect()));
+                    }
+                }
+
+                for argument in arguments {
+                    self.instantiations(terms, argument, instantiations);
+                }
+            }
+            Term::Lam(_, _, body) => self.instantiations(terms, body, instantiations),
+            Term::Ann(value, _) => self.instantiations(terms, value, instantiations),
+            Term::Location(_, term) | Term::Glued(_, _, term) => {
+                self.instantiations(terms, term, instantiations)
+            }
+            Term::Record(fields) => {
+                for (_, value) in fields {
+                    self.instantiations(terms, value, instantiations);
+                }
+            }
+            Term::Project(record, _, _) => self.instantiations(terms, record, instantiations),
+            Term::Transport(_, value) => self.instantiations(terms, value, instantiations),
+            Term::Array(items) | Term::Intrinsic(_, items, _) => {
+                for item in items {
+                    self.instantiations(terms, item, instantiations);
+                }
+            }
+            Term::Match(scrutinee, tree) => {
+                self.instantiations(terms, scrutinee, instantiations);
+                for term in tree.terms() {
+                    self.instantiations(terms, term, instantiations);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Counts the leading implicit lambdas of the function, that are its type parameters.
+fn implicit_arity(term: &Term) -> usize {
+    match term {
+        Term::Lam(_, Implicitness::Implicit, body) => 1 + implicit_arity(body),
+        Term::Location(_, term) | Term::Glued(_, _, term) => implicit_arity(term),
+        _ => 0,
+    }
+}
+
+/// Checks if the term is closed, so it doesn't depend on the local variables, the unsolved
+/// metavariables or the errors, the solved metavariables, like the inserted implicit
+/// arguments, are closed. The variables below `depth` are bound inside of the term.
+fn is_closed(term: &Term) -> bool {
+    fn is_closed_at(term: &Term, depth: usize) -> bool {
+        match term {
+            Term::Var(index, _) => index.0 < depth,
+            Term::InsertedMeta(meta) => meta.get().is_some(),
+            Term::Sorry(..) => false,
+            Term::Lam(_, _, body) => is_closed_at(body, depth + 1),
+            Term::Pi(_, _, domain, codomain) => {
+                is_closed_at(domain, depth) && is_closed_at(codomain, depth + 1)
+            }
+            Term::App(a, b) | Term::Ann(a, b) | Term::Transport(a, b) => {
+                is_closed_at(a, depth) && is_closed_at(b, depth)
+            }
+            Term::Eq(a, b, c) => {
+                is_closed_at(a, depth) && is_closed_at(b, depth) && is_closed_at(c, depth)
+            }
+            Term::Location(_, term)
+            | Term::ArrayType(term)
+            | Term::Project(term, _, _)
+            | Term::Irrelevant(term)
+            | Term::Glued(_, _, term) => is_closed_at(term, depth),
+            Term::Record(fields) | Term::RecordType(fields) => {
+                fields.iter().all(|(_, value)| is_closed_at(value, depth))
+            }
+            Term::Quote(_, splices) => splices.iter().all(|(_, term)| is_closed_at(term, depth)),
+            Term::Array(terms) | Term::Intrinsic(_, terms, _) => {
+                terms.iter().all(|term| is_closed_at(term, depth))
+            }
+            Term::Match(scrutinee, tree) => {
+                is_closed_at(scrutinee, depth)
+                    && tree
+                        .terms()
+                        .into_iter()
+                        .all(|term| is_closed_at(term, depth))
+            }
+            Term::U | Term::Prop | Term::Constructor(_) | Term::Refl | Term::Panic(..) => true,
+        }
+    }
+
+    is_closed_at(term, 0)
+}
@@ -0,0 +1,163 @@
+//! Defines the compile statistics of the packages, that are reported by `sol stats`, to track
+//! the growth of a codebase. They're computed locally, and are never sent anywhere.
+
+use sol_diagnostic::Diagnostics;
+use sol_hir::{
+    lowering::HirLowering,
+    package::Package,
+    solver::Reference,
+    source::{
+        expr::{AnnExpr, CallExpr, LamExpr, MatchArm, MatchExpr, Pi},
+        literal::Literal,
+        pattern::{BindingPattern, ConstructorPattern},
+        stmt::{AskStmt, Block, LetStmt},
+        top_level::{BindingGroup, Inductive},
+        Location, Spanned,
+    },
+    walking::{HirListener, Walker},
+};
+
+use crate::RootDb;
+
+/// The statistics of a package.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PackageStats {
+    pub name: String,
+    pub modules: usize,
+    pub lines: usize,
+    pub definitions: usize,
+    pub inductives: usize,
+    pub elaboration_errors: usize,
+
+    /// The functions with their HIR node counts, sorted from the largest to the smallest.
+    pub functions: Vec<(String, usize)>,
+}
+
+impl RootDb {
+    /// Computes the statistics of the package, elaborating all of its files.
+    pub fn package_stats(&self, package: Package) -> PackageStats {
+        let global_env = self.global_env();
+        let mut collector = StatsCollector::default();
+        let mut stats = PackageStats {
+            name: package.name(self).clone(),
+            ..Default::default()
+        };
+
+        for file in package.all_files(self) {
+            let hir = self.hir_lower(package, file);
+            hir.accept(self, &mut collector);
+
+            sol_typer::infer_type_table(self, global_env, hir);
+            stats.elaboration_errors +=
+                sol_typer::infer_type_table::accumulated::<Diagnostics>(self, global_env, hir)
+                    .iter()
+                    .filter(|diagnostic| diagnostic.is_error())
+                    .count();
+
+            stats.modules += 1;
+            stats.lines += file.source_text(self).lines().count();
+        }
+
+        stats.definitions = collector.functions.len();
+        stats.inductives = collector.inductives;
+        stats.functions = collector
+            .functions
+            .into_iter()
+            .map(|(group, nodes)| (group.signature(self).name(self).to_string(self), nodes))
+            .collect();
+        stats
+            .functions
+            .sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+
+        stats
+    }
+}
+
+/// Aggregates the counts of the HIR, the nodes are only counted inside of functions.
+#[derive(Default)]
+struct StatsCollector {
+    inductives: usize,
+    functions: Vec<(BindingGroup, usize)>,
+    current: Option<usize>,
+}
+
+impl StatsCollector {
+    fn node(&mut self) {
+        if let Some(nodes) = self.current.as_mut() {
+            *nodes += 1;
+        }
+    }
+}
+
+impl HirListener for StatsCollector {
+    fn enter_binding_top_level(&mut self, binding: BindingGroup) {
+        self.current = Some(0);
+        self.functions.push((binding, 0));
+    }
+
+    fn exit_binding_top_level(&mut self, _: BindingGroup) {
+        if let (Some(nodes), Some((_, count))) = (self.current.take(), self.functions.last_mut()) {
+            *count = nodes;
+        }
+    }
+
+    fn enter_inductive_top_level(&mut self, _: Inductive) {
+        self.inductives += 1;
+    }
+
+    fn visit_reference(&mut self, _: Reference) {
+        self.node();
+    }
+
+    fn visit_hole(&mut self, _: Location) {
+        self.node();
+    }
+
+    fn enter_literal_expr(&mut self, _: Spanned<Literal>) {
+        self.node();
+    }
+
+    fn enter_call_expr(&mut self, _: CallExpr) {
+        self.node();
+    }
+
+    fn enter_ann_expr(&mut self, _: AnnExpr) {
+        self.node();
+    }
+
+    fn enter_lam_expr(&mut self, _: LamExpr) {
+        self.node();
+    }
+
+    fn enter_match_expr(&mut self, _: MatchExpr) {
+        self.node();
+    }
+
+    fn enter_pi(&mut self, _: Pi) {
+        self.node();
+    }
+
+    fn enter_match_arm(&mut self, _: MatchArm) {
+        self.node();
+    }
+
+    fn enter_let_stmt(&mut self, _: LetStmt) {
+        self.node();
+    }
+
+    fn enter_ask_stmt(&mut self, _: AskStmt) {
+        self.node();
+    }
+
+    fn enter_block(&mut self, _: Block) {
+        self.node();
+    }
+
+    fn enter_constructor_pattern(&mut self, _: ConstructorPattern) {
+        self.node();
+    }
+
+    fn enter_binding_pattern(&mut self, _: BindingPattern) {
+        self.node();
+    }
+}
@@ -75,8 +75,8 @@ macro_rules! make_test {
 
 #[macro_export]
 macro_rules! make_test_suite {
-  (tests $e:ident {$($name:ident)*} run $run:expr) => {
-    $($crate::make_test!($e, $name, $run);)*
+  ($(tests $e:ident {$($name:ident)*})* run $run:expr) => {
+    $($($crate::make_test!($e, $name, $run);)*)*
   };
 }
 
@@ -0,0 +1,575 @@
+//! Tests the lowering of the syntax into the HIR, of the features that can be checked without
+//! elaborating the files.
+
+use sol_diagnostic::{Diagnostic, Diagnostics};
+use sol_driver::RootDb;
+use sol_hir::{
+    errors::{HirError, HirErrorKind},
+    source::{
+        declaration::Attribute,
+        expr::{CallExpr, Callee, Expr, LetExpr, MatchArm, RecordExpr},
+        literal::Literal,
+        pattern::{Constructor, ConstructorPattern, Pattern},
+        top_level::{Clause, CommandTopLevel, Instance, Trait},
+        HirSource, Spanned,
+    },
+    walking::{HirListener, Walker},
+};
+use sol_hir_lowering::hir_lower;
+use sol_syntax::parse;
+use sol_vfs::SourceFile;
+use utils::create_package;
+
+pub mod utils;
+
+/// Lowers the source code into the HIR, returning the diagnostics of the parser and of the
+/// lowering.
+fn lower(db: &RootDb, source: &str) -> (HirSource, Vec<Diagnostic>) {
+    let file = SourceFile::new(db, "repl".into(), "Repl".into(), source.into());
+
+    let src = parse(db, file);
+    let local = create_package(db, src, "local");
+    let hir = hir_lower(db, local, src);
+
+    let mut diagnostics = parse::accumulated::<Diagnostics>(db, file);
+    diagnostics.extend(hir_lower::accumulated::<Diagnostics>(db, local, src));
+
+    (hir, diagnostics)
+}
+
+/// Gets the kinds of the HIR errors of the diagnostics.
+fn hir_errors(diagnostics: &[Diagnostic]) -> Vec<HirErrorKind> {
+    diagnostics
+        .iter()
+        .filter_map(|diagnostic| match diagnostic.0.as_ref() {
+            sol_eyre::Report::Eyre(report) => report.downcast_ref::<HirError>(),
+            sol_eyre::Report::Miette(report) => report.downcast_ref::<HirError>(),
+        })
+        .map(|error| error.kind.clone())
+        .collect()
+}
+
+/// Collects the HIR nodes that are checked by the tests, in the order they're walked.
+#[derive(Default)]
+struct Collector {
+    match_arms: Vec<MatchArm>,
+    literals: Vec<Literal>,
+    attributes: Vec<Attribute>,
+    commands: Vec<CommandTopLevel>,
+    let_exprs: Vec<LetExpr>,
+    traits: Vec<Trait>,
+    instances: Vec<Instance>,
+    records: Vec<RecordExpr>,
+    clauses: Vec<Clause>,
+    calls: Vec<CallExpr>,
+}
+
+impl HirListener for Collector {
+    fn enter_call_expr(&mut self, call_expr: CallExpr) {
+        self.calls.push(call_expr);
+    }
+
+    fn enter_clause(&mut self, clause: Clause) {
+        self.clauses.push(clause);
+    }
+
+    fn enter_record_expr(&mut self, record_expr: RecordExpr) {
+        self.records.push(record_expr);
+    }
+
+    fn enter_trait_top_level(&mut self, trait_decl: Trait) {
+        self.traits.push(trait_decl);
+    }
+
+    fn enter_instance_top_level(&mut self, instance: Instance) {
+        self.instances.push(instance);
+    }
+
+    fn enter_let_expr(&mut self, let_expr: LetExpr) {
+        self.let_exprs.push(let_expr);
+    }
+
+    fn enter_command_top_level(&mut self, command: CommandTopLevel) {
+        self.commands.push(command);
+    }
+
+    fn enter_attribute(&mut self, attribute: Attribute) {
+        self.attributes.push(attribute);
+    }
+
+    fn enter_literal_expr(&mut self, literal: Spanned<Literal>) {
+        self.literals.push(literal.value);
+    }
+
+    fn enter_match_arm(&mut self, match_arm: MatchArm) {
+        self.match_arms.push(match_arm);
+    }
+}
+
+/// Walks the HIR, collecting its nodes.
+fn collect(db: &RootDb, hir: HirSource) -> Collector {
+    let mut collector = Collector::default();
+    hir.accept(db, &mut collector);
+    collector
+}
+
+#[test]
+fn guarded_match_arms() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Choose = |flag n| match n {
+  0 => {
+    0
+  }
+  m if flag => {
+    m
+  }
+  m => {
+    1
+  }
+}
+"#,
+    );
+
+    let guards = collect(&db, hir)
+        .match_arms
+        .iter()
+        .map(|arm| arm.guard.is_some())
+        .collect::<Vec<_>>();
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(guards, [false, true, false]);
+}
+
+#[test]
+fn prefixed_integer_literals() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Mask : UInt8
+Mask = 0xffu8
+
+Flags : UInt16
+Flags = 0b1010_1010u16
+
+Million : Int64
+Million = 1_000_000i64
+"#,
+    );
+
+    let literals = collect(&db, hir).literals;
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert!(literals.contains(&Literal::UInt8(0xff)));
+    assert!(literals.contains(&Literal::UInt16(0b1010_1010)));
+    assert!(literals.contains(&Literal::Int64(1_000_000)));
+}
+
+#[test]
+fn invalid_integer_literals() {
+    let db = RootDb::default();
+    let (_, diagnostics) = lower(
+        &db,
+        r#"
+Empty = 0x
+
+Large = 300u8
+"#,
+    );
+
+    assert_eq!(hir_errors(&diagnostics), [
+        HirErrorKind::MissingDigits("0x".into()),
+        HirErrorKind::LiteralOutOfRange("UInt8".into()),
+    ]);
+}
+
+#[test]
+fn structured_attribute_arguments() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+@deprecated(since = "0.1", note = "use Two")
+@inline
+One : Int
+One = 1
+"#,
+    );
+
+    let attributes = collect(&db, hir).attributes;
+    let deprecated = attributes
+        .iter()
+        .find(|attribute| attribute.name(&db).to_string(&db).as_deref() == Some("deprecated"))
+        .expect("the attribute should be lowered");
+    let argument = |key| {
+        deprecated
+            .argument(&db, key)
+            .and_then(|argument| argument.as_string().map(String::from))
+    };
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(argument("since").as_deref(), Some("0.1"));
+    assert_eq!(argument("note").as_deref(), Some("use Two"));
+}
+
+#[test]
+fn invalid_attribute_arguments() {
+    let db = RootDb::default();
+    let (_, diagnostics) = lower(
+        &db,
+        r#"
+@inline(always)
+One : Int
+One = 1
+
+@deprecated(Std.note = "use One")
+Two : Int
+Two = 2
+"#,
+    );
+
+    let errors = hir_errors(&diagnostics);
+
+    assert!(errors.contains(&HirErrorKind::InvalidAttributeArguments(
+        "inline".into(),
+        "no arguments".into()
+    )));
+    assert!(errors.contains(&HirErrorKind::InvalidAttributeKey));
+}
+
+#[test]
+fn unknown_commands() {
+    let db = RootDb::default();
+    let (hir, _) = lower(
+        &db,
+        r#"
+Three : Int
+Three = 3
+
+#frobnicate 1
+
+#print_scope
+
+#eval
+"#,
+    );
+
+    // Only the commands that don't elaborate their arguments are used, and they still run
+    // after the unknown command.
+    let messages = db
+        .run_commands(hir)
+        .iter()
+        .map(|diagnostic| diagnostic.0.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[0], "unknown command `#frobnicate`");
+    assert!(messages[1].contains("Three"));
+    assert_eq!(
+        messages[2],
+        "the command `#eval` expects at least one argument"
+    );
+}
+
+#[test]
+fn static_assert_arguments() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Enabled : Bool
+Enabled = 1u1
+
+Disabled : Bool
+Disabled = 0u1
+
+#static_assert Enabled, 1u1
+"#,
+    );
+
+    let collector = collect(&db, hir);
+    let [command] = collector.commands[..] else {
+        panic!("expected a single command");
+    };
+    let arguments = command.arguments(&db);
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(command.path(&db).to_string(&db), "static_assert");
+    assert_eq!(arguments.len(), 2);
+    assert!(matches!(arguments[0], Expr::Path(_)));
+    assert!(collector.literals.contains(&Literal::TRUE));
+    assert!(collector.literals.contains(&Literal::FALSE));
+}
+
+#[test]
+fn out_of_range_booleans() {
+    let db = RootDb::default();
+    let (_, diagnostics) = lower(&db, "Enabled = 2u1\n");
+
+    assert_eq!(hir_errors(&diagnostics), [HirErrorKind::LiteralOutOfRange(
+        "Bool".into()
+    )]);
+}
+
+#[test]
+fn let_expressions() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(&db, "Greeting = let name = \"world\" in name\n");
+
+    let collector = collect(&db, hir);
+    let [LetExpr {
+        pattern: Pattern::Binding(binding),
+        value,
+        body,
+        ..
+    }] = &collector.let_exprs[..]
+    else {
+        panic!("expected a single let expression binding a name");
+    };
+    let Expr::Path(reference) = body.as_ref() else {
+        panic!("expected the body to be a reference");
+    };
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(binding.name.to_string(&db), "name");
+    assert_eq!(reference.definition(&db), binding.name);
+    assert!(matches!(
+        value.as_ref(),
+        Expr::Literal(Spanned {
+            value: Literal::String(_),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn instance_methods() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+trait Show (a : U) {
+  show : a -> String
+}
+
+instance Show Bool {
+  show = |b| "bool"
+}
+"#,
+    );
+
+    let collector = collect(&db, hir);
+    let ([trait_decl], [instance]) = (&collector.traits[..], &collector.instances[..]) else {
+        panic!("expected a single trait, and a single instance");
+    };
+    let methods = instance
+        .methods(&db)
+        .iter()
+        .map(|method| method.name(&db).to_string(&db))
+        .collect::<Vec<_>>();
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(
+        instance.trait_reference(&db).definition(&db),
+        trait_decl.name(&db)
+    );
+    assert_eq!(instance.arguments(&db).len(), 1);
+    assert_eq!(methods, ["show"]);
+}
+
+#[test]
+fn record_updates() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Origin = { name = "origin", visible = 1u1 }
+
+Hidden = { Origin | visible = 0u1 }
+"#,
+    );
+
+    let records = collect(&db, hir).records;
+    let fields = |record: &RecordExpr| {
+        record
+            .fields
+            .iter()
+            .map(|field| field.name.contents(&db))
+            .collect::<Vec<_>>()
+    };
+    let (Some(literal), Some(update)) = (
+        records.iter().find(|record| record.record.is_none()),
+        records.iter().find(|record| record.record.is_some()),
+    ) else {
+        panic!("expected a record literal, and a record update");
+    };
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(fields(literal), ["name", "visible"]);
+    assert_eq!(fields(update), ["visible"]);
+    assert!(matches!(update.record.as_deref(), Some(Expr::Path(_))));
+}
+
+#[test]
+fn with_abstractions() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Same : Bool -> Bool
+Same b with b
+Same b | c = c
+"#,
+    );
+
+    let clauses = collect(&db, hir).clauses;
+    let clause = |name: &str| {
+        clauses
+            .iter()
+            .find(|clause| clause.name(&db).to_string(&db) == name)
+            .unwrap_or_else(|| panic!("expected a clause of `{name}`"))
+    };
+    let Expr::Call(CallExpr {
+        callee: Callee::Reference(auxiliary),
+        arguments,
+        ..
+    }) = clause("Same").value(&db)
+    else {
+        panic!("expected the clause to call its auxiliary definition");
+    };
+
+    // The auxiliary definition receives the argument of the clause, and the abstracted value.
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(auxiliary.definition(&db), clause("Same.with").name(&db));
+    assert_eq!(arguments.len(), 2);
+    assert_eq!(clause("Same.with").arguments(&db).len(), 2);
+}
+
+#[test]
+fn rewrite_expressions() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Symmetry : {a : U} -> {x : a} -> {y : a} -> Eq x y -> Eq y x
+Symmetry = |p| rewrite p in refl
+"#,
+    );
+
+    let calls = collect(&db, hir).calls;
+    let Some(rewrite) = calls.iter().find(|call| call.callee == Callee::Rewrite) else {
+        panic!("expected a rewrite expression");
+    };
+
+    // The arguments are the equality, and the value whose goal is rewritten.
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert!(matches!(rewrite.arguments[..], [
+        Expr::Path(_),
+        Expr::Call(CallExpr {
+            callee: Callee::Refl,
+            ..
+        })
+    ]));
+}
+
+#[test]
+fn tuple_values_and_patterns() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Pair = ("name", 1u1)
+
+Nested = ("outer", (0u1, "inner"))
+
+First = Pair.0
+
+Second = let (name, flag) = Pair in flag
+"#,
+    );
+
+    let collector = collect(&db, hir);
+    let tuples = collector
+        .calls
+        .iter()
+        .filter(|call| call.callee == Callee::Tuple)
+        .map(|call| call.arguments.len())
+        .collect::<Vec<_>>();
+    let [LetExpr {
+        pattern:
+            Pattern::Constructor(ConstructorPattern {
+                name: Constructor::Tuple,
+                arguments,
+                ..
+            }),
+        ..
+    }] = &collector.let_exprs[..]
+    else {
+        panic!("expected a single let expression destructuring a tuple");
+    };
+
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(tuples, [2, 2, 2]);
+    assert!(collector
+        .calls
+        .iter()
+        .any(|call| call.callee == Callee::Project(0)));
+    assert!(matches!(arguments[..], [
+        Pattern::Binding(_),
+        Pattern::Binding(_)
+    ]));
+}
+
+#[test]
+fn map_and_set_literals() {
+    let db = RootDb::default();
+    let (hir, diagnostics) = lower(
+        &db,
+        r#"
+Map.fromList = |entries| entries
+
+Set.fromList = |items| items
+
+Names = #{"a", "b"}
+
+Flags = #{"a": 1u1, "b": 0u1}
+"#,
+    );
+
+    let calls = collect(&db, hir).calls;
+    let items_of = |name: &str| {
+        let call = calls
+            .iter()
+            .find(|call| match &call.callee {
+                Callee::Reference(reference) => reference.definition(&db).to_string(&db) == name,
+                _ => false,
+            })
+            .unwrap_or_else(|| panic!("expected a call to `{name}`"));
+        let [Expr::Call(CallExpr {
+            callee: Callee::Array,
+            arguments,
+            ..
+        })] = &call.arguments[..]
+        else {
+            panic!("expected `{name}` to be called with an array");
+        };
+
+        arguments.clone()
+    };
+
+    // The entries of the maps are the pairs of their keys and values.
+    assert!(hir_errors(&diagnostics).is_empty());
+    assert_eq!(items_of("Set.fromList").len(), 2);
+    assert!(items_of("Map.fromList").iter().all(|entry| matches!(
+        entry,
+        Expr::Call(CallExpr {
+            callee: Callee::Tuple,
+            arguments,
+            ..
+        }) if arguments.len() == 2
+    )));
+    assert_eq!(items_of("Map.fromList").len(), 2);
+}
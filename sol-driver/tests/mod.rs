@@ -32,7 +32,8 @@ make_test_suite! {
     push_fancy_errors(output, &[
       sol_syntax::parse::accumulated::<Diagnostics>(&db, file),
       hir_lower::accumulated::<Diagnostics>(&db, local, src),
-      sol_typer::infer_type_table::accumulated::<Diagnostics>(&db, global_env, hir)
+      sol_typer::infer_type_table::accumulated::<Diagnostics>(&db, global_env, hir),
+      db.run_commands(hir),
     ])?;
 
     Ok(())
@@ -7,7 +7,7 @@ pub fn create_package(db: &RootDb, source: Source, name: &str) -> Package {
     let kind = PackageKind::Binary;
 
     // Creates a new package with the given `name`, `version`, `source` and `kind`.
-    let package = Package::new(db, name.into(), version, source, kind, vec![]);
+    let package = Package::new(db, name.into(), version, source, kind, vec![], None);
 
     // Registers the package in the database.
     db.register_package(package)
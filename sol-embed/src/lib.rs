@@ -0,0 +1,200 @@
+//! The embeddable API of the Sol compiler. It lets Rust programs compile Sol sources, query
+//! their diagnostics and evaluate expressions, without dealing with the salsa database, the
+//! jars or the intermediate representations.
+//!
+//! ```ignore
+//! let mut compiler = sol_embed::Compiler::new("example");
+//! compiler.add_source("Main", "id : {a : Type} -> a -> a\nid x = x");
+//!
+//! assert!(!compiler.compile().iter().any(|diagnostic| diagnostic.is_error()));
+//! ```
+
+use std::path::PathBuf;
+
+use sol_diagnostic::Diagnostics;
+use sol_driver::RootDb;
+use sol_hir::{
+    commands::CommandEvaluator,
+    lowering::HirLowering,
+    package::{Package, PackageKind, Version},
+    source::top_level::TopLevel,
+};
+use sol_vfs::SourceFile;
+
+pub use sol_diagnostic::Diagnostic;
+
+/// The identifier of a source added to the [`Compiler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceId(usize);
+
+/// Error of the embedded compiler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// There's no source in the compiler, so there's nothing to compile.
+    NoSources,
+
+    /// The compilation failed, with the given diagnostics.
+    Diagnostics(Vec<Diagnostic>),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoSources => write!(f, "there's no source to compile"),
+            Error::Diagnostics(diagnostics) => {
+                for diagnostic in diagnostics {
+                    writeln!(f, "{}", diagnostic.0)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// An embedded compiler of a single package. The sources are kept in memory, and are compiled
+/// incrementally, so compiling again after changing a source only does the needed work.
+pub struct Compiler {
+    db: RootDb,
+    name: String,
+    files: Vec<SourceFile>,
+    package: Option<Package>,
+}
+
+impl Compiler {
+    /// Creates a compiler of a package with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            db: RootDb::default(),
+            name: name.into(),
+            files: vec![],
+            package: None,
+        }
+    }
+
+    /// Adds a source to the package, with the given module name, like `Data.List`. The first
+    /// source is the main module of the package.
+    pub fn add_source(&mut self, module: impl Into<String>, text: impl Into<String>) -> SourceId {
+        let module = module.into();
+        let path = PathBuf::from(format!("{}.sol", module.replace('.', "/")));
+
+        self.files
+            .push(SourceFile::new(&self.db, path, module, text.into()));
+
+        SourceId(self.files.len() - 1)
+    }
+
+    /// Replaces the text of the source, the next compilation only redoes the work that depends
+    /// on it.
+    pub fn set_source(&mut self, id: SourceId, text: impl Into<String>) {
+        self.files[id.0]
+            .set_source_text(&mut self.db)
+            .to(text.into());
+    }
+
+    /// Compiles the package, returning the diagnostics of all sources, including the warnings.
+    pub fn compile(&mut self) -> Vec<Diagnostic> {
+        let Some(package) = self.package() else {
+            return vec![];
+        };
+
+        let global_env = self.db.global_env();
+        let mut diagnostics = vec![];
+
+        for file in self.files.clone() {
+            let source = sol_syntax::parse(&self.db, file);
+            let hir = self.db.hir_lower(package, source);
+            sol_typer::infer_type_table(&self.db, global_env, hir);
+
+            diagnostics.extend(sol_syntax::parse::accumulated::<Diagnostics>(
+                &self.db, file,
+            ));
+            diagnostics.extend(sol_hir_lowering::hir_lower::accumulated::<Diagnostics>(
+                &self.db, package, source,
+            ));
+            diagnostics.extend(sol_typer::infer_type_table::accumulated::<Diagnostics>(
+                &self.db, global_env, hir,
+            ));
+        }
+
+        diagnostics
+    }
+
+    /// Evaluates the expression in the scope of the main module, returning its normal form.
+    pub fn eval(&mut self, expr: &str) -> Result<String, Error> {
+        let Some(package) = self.package() else {
+            return Err(Error::NoSources);
+        };
+
+        // The expression is evaluated as an `#eval` command appended to the main module, so it
+        // can refer to its definitions.
+        let main = self.files[0];
+        let text = format!("{}\n#eval {expr}\n", main.source_text(&self.db));
+        let file = SourceFile::new(
+            &self.db,
+            main.file_path(&self.db).clone(),
+            main.module_name(&self.db).clone(),
+            text,
+        );
+
+        let source = sol_syntax::parse(&self.db, file);
+        let hir = self.db.hir_lower(package, source);
+        let Some(TopLevel::Command(command)) = hir.contents(&self.db).last().cloned() else {
+            let diagnostics = sol_syntax::parse::accumulated::<Diagnostics>(&self.db, file);
+            return Err(Error::Diagnostics(diagnostics));
+        };
+
+        command
+            .arguments(&self.db)
+            .into_iter()
+            .map(|expr| self.db.eval_expr(package, expr))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|values| values.join(" "))
+            .map_err(|diagnostic| Error::Diagnostics(vec![diagnostic]))
+    }
+
+    /// Gets the database of the compiler, to use the lower level APIs of the compiler, like the
+    /// [`sol_driver::semantic::SemanticModel`].
+    pub fn db(&self) -> &RootDb {
+        &self.db
+    }
+
+    /// Gets the package, creating it on the first call, and updating its sources on the next
+    /// calls. It returns [`None`] if there's no source.
+    fn package(&mut self) -> Option<Package> {
+        let sources = self
+            .files
+            .iter()
+            .map(|file| sol_syntax::parse(&self.db, *file))
+            .collect::<Vec<_>>();
+        let (main, files) = sources.split_first()?;
+
+        match self.package {
+            Some(package) => {
+                if package.main(&self.db) != *main {
+                    package.set_main(&mut self.db).to(*main);
+                }
+                if package.files(&self.db) != files {
+                    package.set_files(&mut self.db).to(files.to_vec());
+                }
+
+                Some(package)
+            }
+            None => {
+                let package = Package::new(
+                    &self.db,
+                    /* name    = */ self.name.clone(),
+                    /* version = */ Version(0, 0, 1),
+                    /* sources = */ *main,
+                    /* kind    = */ PackageKind::Library,
+                    /* files   = */ files.to_vec(),
+                    /* exports = */ None,
+                );
+                self.package = Some(self.db.register_package(package));
+                self.package
+            }
+        }
+    }
+}
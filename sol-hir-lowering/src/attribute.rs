@@ -0,0 +1,183 @@
+//! Defines a module for resolving attribute arguments, that are kept structured instead of
+//! being resolved as regular expressions, and for validating the arguments of the attributes
+//! known by the compiler. It's a helper module for the [`LowerHir`] struct.
+
+use sol_diagnostic::report_error;
+use sol_hir::{
+    errors::{HirError, HirErrorKind},
+    source::{declaration::AttributeArgument, HirElement},
+};
+
+use super::*;
+
+#[rustfmt::skip]
+type SyntaxExpr<'tree> = sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>;
+
+/// The shape of the arguments of a known attribute.
+#[derive(Debug, Clone, Copy)]
+enum ArgumentShape {
+    /// No arguments, like `@inline`.
+    Empty,
+
+    /// At least one path, like `@derive(Show, Eq)`.
+    Paths,
+
+    /// Exactly one path, like `@repr(C)`.
+    SinglePath,
+
+    /// An optional message, or key-value pairs with the given keys with string values, like
+    /// `@deprecated(since = "0.1", note = "use bar")`.
+    Message(&'static [&'static str]),
+}
+
+impl ArgumentShape {
+    /// The shapes of the attributes known by the compiler. The unknown attributes aren't
+    /// validated, so they can be consumed by tooling.
+    fn of(name: &str) -> Option<Self> {
+        Some(match name {
+            "inline" => Self::Empty,
+            "derive" => Self::Paths,
+            "repr" => Self::SinglePath,
+            "deprecated" => Self::Message(&["since", "note"]),
+            "export" => Self::Message(&[]),
+            "keep" => Self::Empty,
+            "erased" => Self::Paths,
+            "test" => Self::Empty,
+            _ => return None,
+        })
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Self::Empty => "no arguments".into(),
+            Self::Paths => "one or more paths".into(),
+            Self::SinglePath => "a single path".into(),
+            Self::Message(keys) => {
+                format!("a string, or string values for `{}`", keys.join("`, `"))
+            }
+        }
+    }
+
+    fn matches(self, db: &dyn HirDb, arguments: &[AttributeArgument]) -> bool {
+        use AttributeArgument::*;
+
+        match self {
+            Self::Empty => arguments.is_empty(),
+            Self::Paths => {
+                !arguments.is_empty() && arguments.iter().all(|arg| matches!(arg, Path(_)))
+            }
+            Self::SinglePath => matches!(arguments, [Path(_)]),
+            Self::Message(keys) => match arguments {
+                [] => true,
+                [message] if message.as_string().is_some() => true,
+                arguments => arguments.iter().all(|argument| match argument {
+                    KeyValue(name, value) => {
+                        keys.contains(&name.contents(db).as_str()) && value.as_string().is_some()
+                    }
+                    _ => false,
+                }),
+            },
+        }
+    }
+}
+
+impl HirLowering<'_, '_> {
+    /// Resolves an attribute argument.
+    ///
+    /// The paths aren't resolved, because they usually don't refer to definitions, like `C` in
+    /// `@repr(C)`. And `key = value` binary expressions are translated into key-value pairs.
+    pub fn attribute_argument(&mut self, tree: SyntaxExpr) -> AttributeArgument {
+        use sol_syntax::anon_unions::ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr as PrimaryChild;
+        use sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr::*;
+
+        let span = self.span_id(tree.range());
+
+        match tree {
+            BinaryExpr(binary_expr) if self.is_key_value(binary_expr) => {
+                let key = match binary_expr.lhs() {
+                    Ok(Primary(primary)) => match primary.child() {
+                        Ok(PrimaryChild::Path(path)) => self.path(path).segments(self.db).to_vec(),
+                        _ => vec![],
+                    },
+                    _ => vec![],
+                };
+
+                // The keys are labels, so they're single identifiers, like `note`.
+                let [key] = key[..] else {
+                    let error = HirError {
+                        label: span.resolve(self.db),
+                        kind: HirErrorKind::InvalidAttributeKey,
+                    };
+                    report_error(self.db, error.clone());
+
+                    return AttributeArgument::Expr(Expr::Error(error));
+                };
+                let value = binary_expr.rhs().solve(self, |this, node| {
+                    use sol_syntax::anon_unions::BinaryExpr_Primary;
+
+                    match node {
+                        BinaryExpr_Primary::BinaryExpr(node) => {
+                            this.attribute_argument(BinaryExpr(node))
+                        }
+                        BinaryExpr_Primary::Primary(node) => this.attribute_argument(Primary(node)),
+                    }
+                });
+
+                AttributeArgument::KeyValue(key, Box::new(value))
+            }
+            Primary(primary) => primary.child().solve(self, |this, node| match node {
+                PrimaryChild::Path(path) => AttributeArgument::Path(this.path(path)),
+                PrimaryChild::Literal(literal) => {
+                    AttributeArgument::Literal(Spanned::new(this.literal(literal), span))
+                }
+                PrimaryChild::ArrayExpr(array_expr) => {
+                    let arguments = array_expr
+                        .items(&mut array_expr.walk())
+                        .map(|item| item.solve(this, |this, node| this.attribute_argument(node)))
+                        .collect();
+
+                    AttributeArgument::List(arguments, span)
+                }
+                PrimaryChild::TupleExpr(tuple_expr) => {
+                    let arguments = tuple_expr
+                        .children(&mut tuple_expr.walk())
+                        .map(|item| item.solve(this, |this, node| this.attribute_argument(node)))
+                        .collect();
+
+                    AttributeArgument::List(arguments, span)
+                }
+                _ => AttributeArgument::Expr(this.expr(Primary(primary), HirLevel::Expr)),
+            }),
+            _ => AttributeArgument::Expr(self.expr(tree, HirLevel::Expr)),
+        }
+    }
+
+    /// Validates the arguments of the attributes known by the compiler, reporting the ones that
+    /// doesn't match the expected shape.
+    pub fn validate_attribute(&mut self, attribute: Attribute) {
+        let Some(name) = attribute.name(self.db).to_string(self.db) else {
+            return;
+        };
+        let Some(shape) = ArgumentShape::of(&name) else {
+            return;
+        };
+
+        if !shape.matches(self.db, &attribute.arguments(self.db)) {
+            report_error(self.db, HirError {
+                label: attribute.location(self.db),
+                kind: HirErrorKind::InvalidAttributeArguments(name, shape.describe()),
+            });
+        }
+    }
+
+    /// Checks if the binary expression is a key-value pair, like `note = "use bar"`, by its
+    /// operator. The key is checked when it's lowered.
+    fn is_key_value(&self, tree: sol_syntax::BinaryExpr) -> bool {
+        let source_text = self.src.source_text(self.db).as_bytes();
+
+        tree.op()
+            .ok()
+            .and_then(|op| op.utf8_text(source_text).ok())
+            .is_some_and(|op| op == "=")
+    }
+}
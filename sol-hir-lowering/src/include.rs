@@ -0,0 +1,119 @@
+//! Defines a module for including the generated sources, with the `#include_source` command,
+//! that lowers the declarations of another file into the module of the current file, like:
+//!
+//! ```sol
+//! #include_source "generated/Bindings.sol"
+//! ```
+//!
+//! The path is relative to the folder of the current file. The included files are read, and
+//! registered, by the drivers with the paths of [`included_paths`], when the current file is
+//! read. They're lowered on their own, in the module of the current file, so their diagnostics
+//! point to the included file, and they can't use the definitions of the current file, but the
+//! current file can use their definitions.
+
+use std::path::{Path, PathBuf};
+
+use sol_hir::{lowering::HirLowering as _, package::HasManifest};
+
+use super::*;
+
+/// The name of the command that includes the sources.
+pub const INCLUDE_COMMAND: &str = "include_source";
+
+/// Gets the paths of the files included by the source file, resolved against its folder.
+pub fn included_paths(db: &dyn HirDb, src: Source) -> Vec<PathBuf> {
+    let parse_tree = src.syntax_node(db);
+    let text = src.source_text(db);
+    let root = parse_tree.tree.root_node();
+
+    let mut cursor = root.walk();
+    root.named_children(&mut cursor)
+        .filter_map(|node| sol_syntax::Command::try_from(node).ok())
+        .filter(|command| is_include(text, *command))
+        .filter_map(|command| include_argument(text, command))
+        .map(|path| resolve_include(db, src, &path))
+        .collect()
+}
+
+/// Checks if the command is the `#include_source` command.
+fn is_include(text: &str, command: sol_syntax::Command) -> bool {
+    command
+        .command()
+        .ok()
+        .and_then(|path| path.node().utf8_text(text.as_bytes()).ok())
+        .is_some_and(|name| name == INCLUDE_COMMAND)
+}
+
+/// Gets the path of the `#include_source` command, that should be its only argument, as a
+/// string literal. The escape sequences aren't decoded, so the path is the text of the literal.
+fn include_argument(text: &str, command: sol_syntax::Command) -> Option<String> {
+    let arguments = command
+        .arguments(&mut command.walk())
+        .flatten()
+        .filter_map(|node| node.regular())
+        .collect::<Vec<_>>();
+    let [argument] = arguments.as_slice() else {
+        return None;
+    };
+
+    let path = argument
+        .node()
+        .utf8_text(text.as_bytes())
+        .ok()?
+        .strip_prefix('"')?
+        .strip_suffix('"')?;
+    if path.is_empty() || path.contains('"') {
+        return None;
+    }
+
+    Some(path.to_string())
+}
+
+/// Resolves the path of the included file against the folder of the file that includes it.
+fn resolve_include(db: &dyn HirDb, src: Source, path: &str) -> PathBuf {
+    src.file_path(db)
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(path)
+}
+
+impl HirLowering<'_, '_> {
+    /// Checks if the command is the `#include_source` command, that isn't lowered into a
+    /// [`CommandTopLevel`].
+    pub fn is_include(&self, tree: sol_syntax::Command) -> bool {
+        is_include(&self.txt, tree)
+    }
+
+    /// Declares the definitions of the file included by the `#include_source` command in the
+    /// scope of the current file. The declarations of the included file are solved, and added
+    /// to the current file, by [`HirLowering::declare_and_solve`].
+    pub fn hir_include(&mut self, tree: sol_syntax::Command) {
+        let location = self.range(tree.range());
+        let Some(path) = include_argument(&self.txt, tree) else {
+            report_error(
+                self.db,
+                HirError {
+                    label: location,
+                    kind: HirErrorKind::InvalidInclude,
+                },
+            );
+            return;
+        };
+
+        let path = resolve_include(self.db, self.src, &path);
+        let Some(included) = self.db.included_source(&path) else {
+            report_error(
+                self.db,
+                HirError {
+                    label: location,
+                    kind: HirErrorKind::UnresolvedInclude(path.display().to_string()),
+                },
+            );
+            return;
+        };
+
+        let hir = self.db.hir_declare(self.pkg, included);
+        self.scope.extend(hir.scope(self.db));
+        self.includes.push(included);
+    }
+}
@@ -19,9 +19,10 @@ use std::{
 };
 
 use fxhash::FxBuildHasher;
-use salsa::{Cycle, DbWithJar};
-use sol_diagnostic::TextSource;
+use salsa::{Cycle, Database, DbWithJar};
+use sol_diagnostic::{report_error, TextSource};
 use sol_hir::{
+    errors::{AmbiguousConstructorError, DuplicateDefinitionError, HirError, HirErrorKind},
     package::Package,
     scope::{Scope, ScopeKind},
     solver::{
@@ -29,16 +30,19 @@ use sol_hir::{
         DefinitionKind, HirLevel,
     },
     source::{
-        declaration::{Attribute, DocString, Parameter, Vis},
+        declaration::{Attribute, Constraint, DocString, Parameter, Vis},
         expr::{AnnExpr, CallExpr, CallKind, Callee, Expr, LamExpr, Pi, Type},
         pattern::{BindingPattern, Pattern},
         top_level::{
             BindingGroup, Clause, CommandTopLevel, Constructor, ConstructorKind, Inductive,
-            Signature, TopLevel, UsingTopLevel,
+            Instance, Signature, TopLevel, Trait, TypeAlias, UsingTopLevel,
         },
         type_rep::TypeRep,
         DefaultWithDb, HirPath, HirSource, Identifier, Location, OptionExt, Spanned,
     },
+    source_map::HirNode,
+    span_map::SpanId,
+    unicode::ConfusableIdentifier,
     HirDb,
 };
 use sol_syntax::{anon_unions::ExplicitArguments_ImplicitArguments, Source, SourceFile};
@@ -46,7 +50,7 @@ use tree_sitter::{Node, Tree};
 use type_sitter_lib::{ExtraOr, IncorrectKind, NodeResult, OptionNodeResultExt, TypedNode};
 
 #[salsa::jar(db = HirLoweringDb)]
-pub struct Jar(hir_declare, hir_lower);
+pub struct Jar(hir_declare, hir_lower, source_map::source_map);
 
 /// The database that stores all the information about the source code. It is
 /// implemented using the [`salsa`] crate, and it's used by the [`sol-driver`] crate.
@@ -55,7 +59,7 @@ pub trait HirLoweringDb: HirDb + DbWithJar<Jar> {}
 impl<T> HirLoweringDb for T where T: HirDb + DbWithJar<Jar> {}
 
 #[rustfmt::skip]
-type SyntaxDecl<'tree> = sol_syntax::anon_unions::Clause_Command_Inductive_Signature_Using<'tree>;
+type SyntaxDecl<'tree> = sol_syntax::anon_unions::Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using<'tree>;
 
 #[rustfmt::skip]
 type SyntaxIdentifier<'tree> = sol_syntax::anon_unions::SimpleIdentifier_SymbolIdentifier<'tree>;
@@ -88,6 +92,10 @@ pub fn hir_declare(db: &dyn crate::HirLoweringDb, pkg: Package, src: Source) ->
         tree: parse_tree.tree.clone(),
         root_node: parse_tree.tree.root_node(),
         clauses: Default::default(),
+        mutual: vec![],
+        macros: Default::default(),
+        expansions: vec![],
+        includes: vec![],
     };
 
     lower.declare()
@@ -113,6 +121,10 @@ pub fn hir_lower(db: &dyn crate::HirLoweringDb, pkg: Package, src: Source) -> Hi
         tree: parse_tree.tree.clone(),
         root_node: parse_tree.tree.root_node(),
         clauses: Default::default(),
+        mutual: vec![],
+        macros: Default::default(),
+        expansions: vec![],
+        includes: vec![],
     };
 
     lower.declare_and_solve()
@@ -145,6 +157,16 @@ struct HirLowering<'db, 'tree> {
     scope: Scope,
     root_node: Node<'tree>,
     clauses: HashMap<Definition, BindingGroup, FxBuildHasher>,
+
+    /// The inductive types of the mutual block being solved, it's empty outside of the blocks.
+    mutual: Vec<Definition>,
+
+    /// The macros declared in the file, and the expansions that are being lowered.
+    macros: HashMap<String, sol_syntax::MacroDecl<'tree>, FxBuildHasher>,
+    expansions: Vec<macros::MacroFrame>,
+
+    /// The files included by the `#include_source` commands, that are solved after the file.
+    includes: Vec<Source>,
 }
 
 impl<'db, 'tree> HirLowering<'db, 'tree> {
@@ -156,7 +178,10 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     /// search the functions/and other declarations in the scope, but it won't solve the clauses, so
     /// it won't cause any cycles in the queries.
     pub fn declare(mut self) -> HirSource {
-        let ast = SourceFile::try_from(self.root_node).unwrap();
+        let Ok(ast) = SourceFile::try_from(self.root_node) else {
+            return HirSource::new(self.db, self.src, self.pkg, self.scope, vec![]);
+        };
+        self.register_macros();
 
         for node in ast.decls(&mut self.tree.clone().walk()).flatten() {
             if let ExtraOr::Regular(node) = node {
@@ -175,10 +200,19 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     ///
     /// This is the main function of the lowerrer, and it will be used in the [`hir_lower`] query.
     pub fn declare_and_solve(mut self) -> HirSource {
-        let ast = SourceFile::try_from(self.root_node).unwrap();
+        let Ok(ast) = SourceFile::try_from(self.root_node) else {
+            return HirSource::new(self.db, self.src, self.pkg, self.scope, vec![]);
+        };
+        self.register_macros();
 
         for node in ast.decls(&mut self.tree.clone().walk()).flatten() {
+            // Stops lowering large files if there's a new revision, like when the user types
+            // while the file is being lowered.
+            self.db.unwind_if_cancelled();
+
             if let ExtraOr::Regular(node) = node {
+                let syntax = *node.node();
+
                 // Process declaration only if it is not an error, or it's not a junk
                 // declaration.
                 if let Some(solver) = self.define(node) {
@@ -192,11 +226,20 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                         continue;
                     }
 
+                    self.record(syntax, HirNode::TopLevel(decl.clone()));
+
                     self.decls.push(decl);
                 };
             }
         }
 
+        // The included files are solved on their own, so their diagnostics point to them, and
+        // their declarations are added to the current file.
+        for included in take(&mut self.includes) {
+            let hir = self.db.hir_lower(self.pkg, included);
+            self.decls.extend(hir.contents(self.db).iter().cloned());
+        }
+
         HirSource::new(self.db, self.src, self.pkg, self.scope, self.decls)
     }
 
@@ -207,14 +250,23 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     /// It will return `Some` if the declaration is "resolvable", and it will return a solver for
     /// the declaration.
     pub fn define<'a>(&mut self, decl: SyntaxDecl<'a>) -> Option<Solver<'a, TopLevel>> {
-        use sol_syntax::anon_unions::Clause_Command_Inductive_Signature_Using::*;
+        use sol_syntax::anon_unions::Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using::*;
 
         // Creates a new [`TopLevel`] instance.
         let decl = match decl {
+            Command(command) if self.is_include(command) => {
+                self.hir_include(command);
+                return None;
+            }
             Command(command) => self.hir_command(command),
             Clause(clause) => return self.hir_clause(clause).into(),
             Inductive(data_decl) => return self.hir_inductive(data_decl).into(),
+            Instance(instance) => return self.hir_instance(instance).into(),
+            MacroDecl(_) => return None, // Registered before the declarations
+            Mutual(mutual) => return self.hir_mutual(mutual).into(),
             Signature(signature) => return self.hir_signature(signature).into(),
+            TraitDecl(trait_decl) => return self.hir_trait(trait_decl).into(),
+            TypeAlias(alias) => return self.hir_type_alias(alias).into(),
             Using(decl) => return self.hir_using(decl).into(),
         };
 
@@ -234,7 +286,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             .map(|node| self.expr(node, HirLevel::Expr))
             .collect();
 
-        let location = self.range(tree.range());
+        let location = self.span_id(tree.range());
 
         // Search definition of command in the scope, and if it is not found, it will create a new
         // definition with the given name, and the given location.
@@ -249,13 +301,13 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     /// It will return a [`Solver`] for the [`Signature`], and it will solve the [`Signature`] in
     /// the [`hir_lower`] query.
     pub fn hir_using<'a>(&mut self, tree: sol_syntax::Using<'a>) -> Solver<'a, TopLevel> {
-        let range = self.range(tree.range());
+        let range = self.span_id(tree.range());
         let path = tree.path().solve(self, |this, node| this.path(node));
 
         Solver::new(move |db, this| {
             // TODO: search for functions or anything too.
             let (scope, def) = query_module(db, path);
-            let reference = this.scope.using(db, def, path.location(db));
+            let reference = this.scope.using(db, def, path.span(db));
 
             // Extends the scope with the new scope.
             this.scope.extend(scope);
@@ -270,11 +322,59 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     /// It will return a [`Solver`] for the [`Signature`], and it will solve the [`Signature`] in
     /// the [`hir_lower`] query.
     pub fn hir_inductive<'a>(&mut self, tree: sol_syntax::Inductive<'a>) -> Solver<'a, TopLevel> {
+        self.define_inductive(tree).1
+    }
+
+    /// Creates a new high level mutual block solver, for the given concrete syntax tree
+    /// [`sol_syntax::Mutual`].
+    ///
+    /// All the inductive types of the block, and their constructors, are defined before solving
+    /// any of them, so the constructors can reference the types declared later in the block.
+    pub fn hir_mutual<'a>(&mut self, tree: sol_syntax::Mutual<'a>) -> Solver<'a, TopLevel> {
         let range = self.range(tree.range());
+
+        let (block, solvers): (Vec<_>, Vec<_>) = tree
+            .inductives(&mut tree.walk())
+            .flatten()
+            .filter_map(|inductive| inductive.regular())
+            .map(|inductive| self.define_inductive(inductive))
+            .unzip();
+
+        Solver::new(move |_, this| {
+            this.mutual = block;
+
+            let mut inductives = solvers
+                .into_iter()
+                .map(|solver| solver.run_solver(this))
+                .collect::<Vec<_>>();
+
+            this.mutual = vec![];
+
+            // The inductive types are flattened into the top level declarations, in the order
+            // of the block, so the last one is pushed by the caller.
+            let last = inductives.pop().unwrap_or_else(|| {
+                TopLevel::Error(HirError {
+                    kind: HirErrorKind::Empty,
+                    label: range,
+                })
+            });
+            this.decls.extend(inductives);
+
+            last
+        })
+    }
+
+    /// Defines the inductive type, and its constructors on the scope, and returns the
+    /// definition of the type with the [`Solver`] for the [`Inductive`].
+    fn define_inductive<'a>(
+        &mut self,
+        tree: sol_syntax::Inductive<'a>,
+    ) -> (Definition, Solver<'a, TopLevel>) {
+        let range = self.span_id(tree.range());
         let path = tree.name().solve(self, |this, path| this.path(path));
 
         let attrs = self.hir_attributes(tree.attributes(&mut tree.walk()));
-        let docs = self.hir_docs(tree.doc_strings(&mut tree.walk()));
+        let docs = self.hir_top_level_docs(tree.into_node());
 
         // Converts the visibility to default visibility, if it is not specified.
         let vis = tree
@@ -282,19 +382,30 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             .map(|vis| vis.solve(self, |this, node| this.hir_visibility(node)))
             .unwrap_or(Spanned::on_call_site(Vis::Public));
 
+        // Reports the inductive types that are already defined in this file, before shadowing
+        // them in the scope.
+        self.check_duplicate(path, DefinitionKind::Type);
+
         // Defines the node on the scope
         let node = self
             .scope
-            .define(self.db, path, range.clone(), DefinitionKind::Type);
+            .define(self.db, path, range, DefinitionKind::Type);
 
         let variants = tree
             .constructors(&mut tree.walk())
             .flatten()
             .filter_map(|constructor| constructor.regular())
-            .filter_map(|constructor| self.hir_variant(constructor))
+            .filter_map(|constructor| self.hir_variant(constructor, path))
             .collect::<Vec<_>>();
 
-        Solver::new(move |db, this| {
+        let solver = Solver::new(move |db, this| {
+            // The inductive types outside of a mutual block are a block on their own.
+            let mutual = if this.mutual.is_empty() {
+                vec![node]
+            } else {
+                this.mutual.clone()
+            };
+
             // Creates a new scope for the function, and it will be used to store the parameters,
             // and the variables.
             this.scope = this.scope.fork(ScopeKind::Data);
@@ -325,7 +436,8 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                 /* parameters  = */ parameters,
                 /* return_type = */ type_rep,
                 /* variants    = */ variants,
-                /* location    = */ range.clone(),
+                /* mutual      = */ mutual,
+                /* span        = */ range,
                 /* scope       = */ this.pop_scope(),
             );
 
@@ -334,15 +446,291 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             // The entire next step, is getting the clauses from the scope, and transforms into
             // declarations, so it is not needed to solve the clause here.
             TopLevel::Inductive(inductive)
+        });
+
+        (node, solver)
+    }
+
+    /// Creates a new high level type synonym [`TypeAlias`] solver, for the given concrete
+    /// syntax tree [`sol_syntax::TypeAlias`].
+    ///
+    /// The alias is defined as a type in the scope, so it can be referenced before its
+    /// declaration, and the value is only lowered in the [`hir_lower`] query.
+    pub fn hir_type_alias<'a>(&mut self, tree: sol_syntax::TypeAlias<'a>) -> Solver<'a, TopLevel> {
+        let range = self.span_id(tree.range());
+        let path = tree.name().solve(self, |this, path| this.path(path));
+
+        let attrs = self.hir_attributes(tree.attributes(&mut tree.walk()));
+        let docs = self.hir_top_level_docs(tree.into_node());
+
+        // Converts the visibility to default visibility, if it is not specified.
+        let vis = tree
+            .visibility()
+            .map(|vis| vis.solve(self, |this, node| this.hir_visibility(node)))
+            .unwrap_or(Spanned::on_call_site(Vis::Public));
+
+        // Reports the types that are already defined in this file, before shadowing them in
+        // the scope.
+        self.check_duplicate(path, DefinitionKind::Type);
+
+        // Defines the node on the scope
+        let node = self
+            .scope
+            .define(self.db, path, range, DefinitionKind::Type);
+
+        Solver::new(move |db, this| {
+            // Creates a new scope for the parameters of the alias, that are only visible
+            // within its value.
+            this.scope = this.scope.fork(ScopeKind::Type);
+
+            let parameters = this.parameters(tree.arguments(&mut tree.walk()));
+            let value = tree.value().solve(this, |this, node| this.type_expr(node));
+
+            TopLevel::TypeAlias(TypeAlias::new(
+                db,
+                /* attributes = */ attrs,
+                /* docs       = */ docs,
+                /* visibility = */ vis,
+                /* name       = */ node,
+                /* parameters = */ parameters,
+                /* value      = */ value,
+                /* span       = */ range,
+                /* scope      = */ this.pop_scope(),
+            ))
+        })
+    }
+
+    /// Creates a new high level trait declaration [`Trait`] solver, for the given concrete
+    /// syntax tree [`sol_syntax::TraitDecl`].
+    ///
+    /// The methods are defined in the file scope, just like the constructors of an inductive
+    /// type, so they can be referenced without qualifying them by the trait.
+    pub fn hir_trait<'a>(&mut self, tree: sol_syntax::TraitDecl<'a>) -> Solver<'a, TopLevel> {
+        let range = self.span_id(tree.range());
+        let path = tree.name().solve(self, |this, path| this.path(path));
+
+        let attrs = self.hir_attributes(tree.attributes(&mut tree.walk()));
+        let docs = self.hir_top_level_docs(tree.into_node());
+
+        // Converts the visibility to default visibility, if it is not specified.
+        let vis = tree
+            .visibility()
+            .map(|vis| vis.solve(self, |this, node| this.hir_visibility(node)))
+            .unwrap_or(Spanned::on_call_site(Vis::Public));
+
+        // Reports the traits that are already defined in this file, before shadowing them in
+        // the scope.
+        self.check_duplicate(path, DefinitionKind::Trait);
+
+        // Defines the node on the scope
+        let node = self
+            .scope
+            .define(self.db, path, range, DefinitionKind::Trait);
+
+        let methods = tree
+            .methods(&mut tree.walk())
+            .flatten()
+            .filter_map(|method| method.regular())
+            .map(|method| {
+                let path = method.name().solve(self, |this, path| this.path(path));
+                let location = self.span_id(method.range());
+
+                self.check_duplicate(path, DefinitionKind::Function);
+
+                let name = self
+                    .scope
+                    .define(self.db, path, location, DefinitionKind::Function);
+
+                (method, name)
+            })
+            .collect::<Vec<_>>();
+
+        Solver::new(move |db, this| {
+            // Creates a new scope for the trait, and it will be used to store the parameters,
+            // that are visible in the superclasses and in the methods.
+            this.scope = this.scope.fork(ScopeKind::Trait);
+
+            let parameters = this.parameters(tree.arguments(&mut tree.walk()));
+
+            let superclasses = tree
+                .requirements(&mut tree.walk())
+                .flatten()
+                .filter_map(|node| node.regular())
+                .map(|node| this.hir_constraint(node))
+                .collect();
+
+            let methods = methods
+                .into_iter()
+                .map(|(method, name)| this.hir_method_signature(method, name))
+                .collect();
+
+            TopLevel::Trait(Trait::new(
+                db,
+                /* attributes   = */ attrs,
+                /* docs         = */ docs,
+                /* visibility   = */ vis,
+                /* name         = */ node,
+                /* parameters   = */ parameters,
+                /* superclasses = */ superclasses,
+                /* methods      = */ methods,
+                /* span         = */ range,
+                /* scope        = */ this.pop_scope(),
+            ))
+        })
+    }
+
+    /// Creates a new high level trait [`Constraint`], for the given concrete syntax tree
+    /// [`sol_syntax::Constraint`], like `Eq a`.
+    pub fn hir_constraint(&mut self, tree: sol_syntax::Constraint) -> Constraint {
+        let location = self.span_id(tree.range());
+        let path = tree.name().solve(self, |this, path| this.path(path));
+
+        let definition = self.qualify(path, DefinitionKind::Trait);
+        let trait_reference = self.scope.using(self.db, definition, path.span(self.db));
+
+        let arguments = tree
+            .arguments(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .map(|node| self.primary(node, HirLevel::Type).upgrade(self.db))
+            .collect();
+
+        Constraint::new(self.db, trait_reference, arguments, location)
+    }
+
+    /// Creates a new high level method [`Signature`], for the given concrete syntax tree
+    /// [`sol_syntax::Signature`], within a trait scope. The `name` is already defined in the
+    /// file scope by the [`HirLowering::hir_trait`].
+    pub fn hir_method_signature(
+        &mut self,
+        tree: sol_syntax::Signature,
+        name: Definition,
+    ) -> Signature {
+        let location = self.span_id(tree.range());
+
+        let attrs = self.hir_attributes(tree.attributes(&mut tree.walk()));
+        let docs = self.hir_top_level_docs(tree.into_node());
+
+        // Creates a new scope for the method, and it will be used to store the parameters.
+        self.scope = self.scope.fork(ScopeKind::Method);
+
+        let parameters = self.parameters(tree.arguments(&mut tree.walk()));
+        let return_type = tree
+            .clause_type()
+            .flatten()
+            .map(|node| self.type_expr(node))
+            .unwrap_or_default_with_db(self.db);
+
+        self.pop_scope();
+
+        Signature::new(
+            self.db,
+            /* attributes  = */ attrs,
+            /* docs        = */ docs,
+            /* visibility  = */ Spanned::on_call_site(Vis::Public),
+            /* name        = */ name,
+            /* parameters  = */ parameters,
+            /* return_type = */ return_type,
+            /* span        = */ location,
+        )
+    }
+
+    /// Creates a new high level trait instance [`Instance`] solver, for the given concrete
+    /// syntax tree [`sol_syntax::Instance`].
+    ///
+    /// The instance isn't defined in the scope, because it can't be referenced by name, it's
+    /// only registered in the instance environment of the trait.
+    pub fn hir_instance<'a>(&mut self, tree: sol_syntax::Instance<'a>) -> Solver<'a, TopLevel> {
+        let range = self.span_id(tree.range());
+        let path = tree.name().solve(self, |this, path| this.path(path));
+
+        let attrs = self.hir_attributes(tree.attributes(&mut tree.walk()));
+        let docs = self.hir_top_level_docs(tree.into_node());
+
+        // Converts the visibility to default visibility, if it is not specified.
+        let vis = tree
+            .visibility()
+            .map(|vis| vis.solve(self, |this, node| this.hir_visibility(node)))
+            .unwrap_or(Spanned::on_call_site(Vis::Public));
+
+        Solver::new(move |db, this| {
+            let definition = this.qualify(path, DefinitionKind::Trait);
+            let trait_reference = this.scope.using(db, definition, path.span(db));
+
+            // Creates a new scope for the instance, so the methods don't leak into the file
+            // scope.
+            this.scope = this.scope.fork(ScopeKind::Class);
+
+            let arguments = tree
+                .arguments(&mut tree.walk())
+                .flatten()
+                .filter_map(|node| node.regular())
+                .map(|node| this.primary(node, HirLevel::Type).upgrade(db))
+                .collect();
+
+            let methods = tree
+                .methods(&mut tree.walk())
+                .flatten()
+                .filter_map(|node| node.regular())
+                .map(|node| this.hir_method(node))
+                .collect();
+
+            TopLevel::Instance(Instance::new(
+                db,
+                /* attributes      = */ attrs,
+                /* docs            = */ docs,
+                /* visibility      = */ vis,
+                /* trait_reference = */ trait_reference,
+                /* arguments       = */ arguments,
+                /* methods         = */ methods,
+                /* span            = */ range,
+                /* scope           = */ this.pop_scope(),
+            ))
         })
     }
 
+    /// Creates a new high level method [`Clause`], for the given concrete syntax tree
+    /// [`sol_syntax::Clause`], within an instance scope.
+    ///
+    /// The clauses of the same method share the definition, that is only searched in the
+    /// instance scope, so they don't refer to the functions of the file.
+    pub fn hir_method(&mut self, tree: sol_syntax::Clause) -> Clause {
+        let path = tree.name().solve(self, |this, path| this.path(path));
+        let location = self.span_id(tree.range());
+
+        let name = path
+            .to_string(self.db)
+            .and_then(|name| self.scope.local(&name, DefinitionKind::Function))
+            .unwrap_or_else(|| {
+                self.scope
+                    .define(self.db, path, location, DefinitionKind::Function)
+            });
+
+        // Creates a new scope for the method, and it will be used to store the parameters,
+        // and the variables.
+        self.scope = self.scope.fork(ScopeKind::Method);
+
+        let patterns = self.patterns(tree.patterns(&mut tree.walk()));
+        let value = tree
+            .value()
+            .map(|value| value.solve(self, |this, node| this.expr(node, HirLevel::Expr)))
+            .unwrap_or_default_with_db(self.db);
+
+        self.pop_scope();
+
+        Clause::new(self.db, name, patterns, value, location)
+    }
+
     /// Creates a new high level constructor declaration [`Constructor`] solver, for the given
     /// concrete syntax tree [`sol_syntax::Constructor`].
     ///
     /// It will return [`None`] if the constructor is a comma, because it is not a constructor, it
     /// is just a separator.
-    pub fn hir_variant<'a>(&mut self, tree: SyntaxVariant<'a>) -> Option<Solver<'a, Constructor>> {
+    pub fn hir_variant<'a>(
+        &mut self,
+        tree: SyntaxVariant<'a>,
+        inductive: HirPath,
+    ) -> Option<Solver<'a, Constructor>> {
         use sol_syntax::anon_unions::Comma_FunctionConstructor_SignatureConstructor::*;
 
         // Creates constructor declarations only if it is not a comma
@@ -353,12 +741,11 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                 let docs = self.hir_docs(tree.doc_strings(&mut tree.walk()));
                 let name = tree.name().solve(self, |this, path| this.path(path));
 
-                let location = self.range(tree.range());
+                let location = self.span_id(tree.range());
 
-                // Defines the node on the scope
-                let name =
-                    self.scope
-                        .define(self.db, name, location.clone(), DefinitionKind::Constructor);
+                // Defines the node on the scope, both unqualified and qualified by the
+                // inductive type, like `Cons` and `List.Cons`.
+                let name = self.define_constructor(inductive, name, location);
 
                 Some(Solver::new(move |_, this| {
                     this.scope = this.scope.fork(ScopeKind::Pi);
@@ -380,9 +767,9 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                         expr: Box::new(Expr::Pi(Pi {
                             parameters,
                             value: Box::new(TypeRep {
-                                expr: Box::new(Expr::Type(Type::This, location.clone())),
+                                expr: Box::new(Expr::Type(Type::This, location)),
                             }),
-                            location: location.clone(),
+                            span: location,
                         })),
                     };
 
@@ -393,7 +780,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                         /* docs        = */ docs,
                         /* name        = */ name,
                         /* return_type = */ type_rep,
-                        /* location    = */ location,
+                        /* span        = */ location,
                     )
                 }))
             }
@@ -402,12 +789,11 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                 let docs = self.hir_docs(tree.doc_strings(&mut tree.walk()));
                 let name = tree.name().solve(self, |this, path| this.path(path));
 
-                let location = self.range(tree.range());
+                let location = self.span_id(tree.range());
 
-                // Defines the node on the scope
-                let name =
-                    self.scope
-                        .define(self.db, name, location.clone(), DefinitionKind::Constructor);
+                // Defines the node on the scope, both unqualified and qualified by the
+                // inductive type, like `Cons` and `List.Cons`.
+                let name = self.define_constructor(inductive, name, location);
 
                 Some(Solver::new(move |_db, this| {
                     // As it's a GADT constructor, it's already defined the type of the constructor, so
@@ -423,7 +809,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                         /* docs        = */ docs,
                         /* name        = */ name,
                         /* return_type = */ type_rep,
-                        /* location    = */ location,
+                        /* span        = */ location,
                     )
                 }))
             }
@@ -435,19 +821,21 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     ///
     /// It will return a [`Solver`] for the [`Clause`], and it will solve the [`Clause`] in the
     /// [`hir_lower`] query.
+    ///
+    /// The clauses with `|` patterns belong to the auxiliary definitions of the `with`
+    /// abstractions, see the `with` module.
     pub fn hir_clause<'a>(&mut self, tree: sol_syntax::Clause<'a>) -> Solver<'a, TopLevel> {
         let path = tree.name().solve(self, |this, path| this.path(path));
-        let location = self.range(tree.range());
+        let location = self.span_id(tree.range());
 
-        // Defines in the scope if it is not defined yet, and it will return the definition.
-        let name = self
-            .scope
-            .search(self.db, path, DefinitionKind::Function)
-            .unwrap_or_else(|| {
-                // Defines the node on the scope
-                self.scope
-                    .define(self.db, path, location.clone(), DefinitionKind::Function)
-            });
+        // Defines in the scope if it is not defined yet, and it will return the definition, or
+        // the auxiliary definition of the `with` abstractions.
+        let depth = tree.with_patterns(&mut tree.walk()).count();
+        let name = self.with_definition(path, depth, location);
+        let auxiliary = tree
+            .with_values(&mut tree.walk())
+            .next()
+            .map(|_| self.with_definition(path, depth + 1, location));
 
         Solver::new(move |db, this| {
             //
@@ -455,19 +843,32 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             // and the variables.
             this.scope = this.scope.fork(ScopeKind::Function);
 
-            let patterns = this.patterns(tree.patterns(&mut tree.walk()));
+            let mut patterns = this.patterns(tree.patterns(&mut tree.walk()));
+            patterns.extend(this.patterns(tree.with_patterns(&mut tree.walk())));
 
             // Transforms the patterns into bindings, to be used in the scope.
-            let value = tree
-                .value()
-                .map(|value| value.solve(this, |this, node| this.expr(node, HirLevel::Expr)))
-                .unwrap_or_default_with_db(db);
+            let value = match auxiliary {
+                Some(auxiliary) => {
+                    let values = tree
+                        .with_values(&mut tree.walk())
+                        .flatten()
+                        .filter_map(|node| node.regular())
+                        .map(|node| this.expr(node, HirLevel::Expr))
+                        .collect();
+
+                    this.with_call(auxiliary, patterns.clone(), values, location)
+                }
+                None => tree
+                    .value()
+                    .map(|value| value.solve(this, |this, node| this.expr(node, HirLevel::Expr)))
+                    .unwrap_or_default_with_db(db),
+            };
 
-            let clause = Clause::new(db, name, patterns, value, location.clone());
+            let clause = Clause::new(db, name, patterns, value, location);
 
             let binding_group = this.clauses.entry(name).or_insert_with(|| {
                 let return_type = TypeRep {
-                    expr: Box::new(Expr::Hole(location.clone())),
+                    expr: Box::new(Expr::Hole(location)),
                 };
 
                 // Creates a dummy signature implementation, to be used in the clause.
@@ -479,7 +880,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                     /* name        = */ name,
                     /* parameters  = */ vec![],
                     /* return_type = */ return_type,
-                    /* location    = */ location,
+                    /* span        = */ location,
                 );
 
                 BindingGroup::new(db, signature, HashSet::default())
@@ -507,11 +908,11 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     /// It will return a [`Solver`] for the [`Signature`], and it will solve the [`Signature`] in
     /// the [`hir_lower`] query.
     pub fn hir_signature<'a>(&mut self, tree: sol_syntax::Signature<'a>) -> Solver<'a, TopLevel> {
-        let range = self.range(tree.range());
+        let range = self.span_id(tree.range());
         let path = tree.name().solve(self, |this, node| this.path(node));
 
         let attrs = self.hir_attributes(tree.attributes(&mut tree.walk()));
-        let docs = self.hir_docs(tree.doc_strings(&mut tree.walk()));
+        let docs = self.hir_top_level_docs(tree.into_node());
 
         // Converts the visibility to default visibility, if it is not specified.
         let vis = tree
@@ -519,10 +920,14 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             .map(|vis| vis.solve(self, |this, node| this.hir_visibility(node)))
             .unwrap_or(Spanned::on_call_site(Vis::Public));
 
+        // Reports the signatures that are already defined in this file. The clauses are grouped
+        // with the signature of the same name, but two signatures with the same name conflicts.
+        self.check_duplicate(path, DefinitionKind::Function);
+
         // Defines the node on the scope
         let node = self
             .scope
-            .define(self.db, path, range.clone(), DefinitionKind::Function);
+            .define(self.db, path, range, DefinitionKind::Function);
 
         Solver::new(move |db, this| {
             // Creates a new scope for the function, and it will be used to store the parameters,
@@ -543,6 +948,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                 .map(|node| this.type_expr(node))
                 .unwrap_or_default_with_db(db);
 
+            #[rustfmt::skip]
             let signature = Signature::new(
                 this.db,
                 /* attributes  = */ attrs,
@@ -551,7 +957,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                 /* name        = */ node,
                 /* parameters  = */ parameters,
                 /* return_type = */ type_rep,
-                /* location    = */ range.clone(),
+                /* span        = */ range,
             );
 
             let clause = this
@@ -576,13 +982,13 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                     /* name       = */ node,
                     /* arguments  = */ arguments,
                     /* value      = */ Expr::block(this.db, value),
-                    /* location   = */ range,
+                    /* span       = */ range,
                 ));
             }
 
             // Adds the clause to the scope, and solve it
-            this.clauses
-                .insert(node, BindingGroup::new(db, signature, clauses));
+            let group = BindingGroup::new(db, signature, clauses);
+            this.clauses.insert(node, group);
 
             this.pop_scope();
 
@@ -590,7 +996,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             //
             // The entire next step, is getting the clauses from the scope, and transforms into
             // declarations, so it is not needed to solve the clause here.
-            TopLevel::BindingGroup(*this.clauses.get(&node).unwrap())
+            TopLevel::BindingGroup(group)
         })
     }
 
@@ -651,7 +1057,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             .parameter_type()
             .solve(self, |this, expr| this.type_expr(expr));
 
-        let location = self.range(tree.range());
+        let location = self.span_id(tree.range());
 
         Parameter::new(
             self.db,
@@ -660,7 +1066,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             /* is_implicit = */ implicit,
             /* rigid       = */ rigid,
             /* level       = */ HirLevel::Expr,
-            /* location    = */ location,
+            /* span        = */ location,
         )
     }
 
@@ -692,7 +1098,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                     .parameter_type()
                     .solve(self, |this, expr| this.type_expr(expr));
 
-                let location = self.range(parameter.range());
+                let location = self.span_id(parameter.range());
 
                 self::Parameter::new(
                     self.db,
@@ -701,7 +1107,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                     /* is_implicit = */ implicit,
                     /* rigid       = */ rigid,
                     /* level       = */ HirLevel::Expr,
-                    /* location    = */ location,
+                    /* span        = */ location,
                 )
             }
         }
@@ -717,7 +1123,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     /// given parameters.
     pub fn forall_parameter(&mut self, tree: sol_syntax::ForallParameter) -> Parameter {
         let name = tree.identifier().with_db(self, |this, segment| {
-            let range = this.range(segment.range());
+            let range = segment.range();
             let identifer = segment.child().ok()?;
             let txt = this.txt.clone();
 
@@ -727,35 +1133,35 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                 SyntaxIdentifier::SimpleIdentifier(value) => {
                     let string = value.utf8_text(txt.as_bytes()).ok().unwrap_or_default();
 
-                    Identifier::new(this.db, string.into(), false, range.clone())
+                    this.identifier(string, false, range)
                 }
                 SyntaxIdentifier::SymbolIdentifier(value) => {
                     let string = value
                         .child()
                         .with_db(this, |_, node| node.utf8_text(txt.as_bytes()).ok());
 
-                    Identifier::new(this.db, string.into(), true, range.clone())
+                    this.identifier(string, true, range)
                 }
             };
 
-            Some(HirPath::new(this.db, range, vec![unique]))
+            Some(HirPath::new(this.db, this.span_id(range), vec![unique]))
         });
-        let location = self.range(tree.range());
+        let location = self.span_id(tree.range());
 
         let definition = self.scope.define(
             self.db,
             /* name     = */ name,
-            /* location = */ name.location(self.db),
+            /* span     = */ name.span(self.db),
             /* kind     = */ DefinitionKind::Type,
         );
 
         let pattern = Pattern::Binding(BindingPattern {
             name: definition,
-            location: name.location(self.db),
+            span: name.span(self.db),
         });
 
         let type_rep = TypeRep {
-            expr: Box::new(Expr::Hole(location.clone())),
+            expr: Box::new(Expr::Hole(location)),
         };
 
         Parameter::new(
@@ -765,7 +1171,7 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             /* is_implicit = */ true,
             /* rigid       = */ true,
             /* level       = */ HirLevel::Type,
-            /* location    = */ location,
+            /* span        = */ location,
         )
     }
 
@@ -782,13 +1188,31 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             .flatten()
             .filter_map(|attr| {
                 let value = attr.regular()?;
-                let range = self.range(value.range());
+                let range = self.span_id(value.range());
 
                 Some(DocString::new(self.db, range))
             })
             .collect()
     }
 
+    /// Handles the doc strings associated with a top-level declaration, by the
+    /// [`sol_syntax::Source::doc_comments`] query. Unlike [`HirLowering::hir_docs`], it does
+    /// include the doc strings that aren't directly adjacent to the declaration, like the ones
+    /// separated by a blank line, or by a plain comment.
+    pub fn hir_top_level_docs(&mut self, node: Node) -> Vec<DocString> {
+        self.src
+            .doc_comments(self.db)
+            .get(node.start_byte())
+            .iter()
+            .map(|range| {
+                DocString::new(
+                    self.db,
+                    SpanId::new(self.db, self.src, range.start, range.end),
+                )
+            })
+            .collect()
+    }
+
     /// Handles a list of raw attributes in to a list of high level attributes to be shown in the
     /// IDE, or handled by resolution. It will return a list of [`Attribute`].
     ///
@@ -803,10 +1227,18 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             .filter_map(|attribute| {
                 let value = attribute.regular()?;
                 let name = value.name().solve(self, |this, path| this.path(path));
-                let arguments = vec![];
-                let range = self.range(attribute.range());
-
-                Some(Attribute::new(self.db, name, arguments, range))
+                let arguments = value
+                    .arguments(&mut value.walk())
+                    .map(|argument| {
+                        argument.solve(self, |this, node| this.attribute_argument(node))
+                    })
+                    .collect();
+                let range = self.span_id(attribute.range());
+
+                let attribute = Attribute::new(self.db, name, arguments, range);
+                self.validate_attribute(attribute);
+
+                Some(attribute)
             })
             .collect()
     }
@@ -824,10 +1256,113 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
             Ok("internal") => Vis::Internal,
             _ => Vis::Public,
         };
-        let location = self.range(visibility.range());
+        let location = self.span_id(visibility.range());
         Spanned::new(value, location)
     }
 
+    /// Reports the top level [`HirPath`] that is already defined in the current file, for the
+    /// given `kind`, pointing to both definitions. The definitions imported from other files can
+    /// be shadowed, so they aren't reported.
+    pub fn check_duplicate(&mut self, path: HirPath, kind: DefinitionKind) {
+        let Some(name) = path.to_string(self.db) else {
+            return;
+        };
+        let Some(previous) = self.scope.local(&name, kind) else {
+            return;
+        };
+
+        let previous = previous.location(self.db);
+        if previous.source() != Some(self.src) {
+            return;
+        }
+
+        report_error(self.db, DuplicateDefinitionError {
+            name,
+            location: path.location(self.db),
+            previous,
+        });
+    }
+
+    /// Defines the constructor in the scope, both unqualified, like `Cons`, and qualified by
+    /// its inductive type, like `List.Cons`, so the constructors with the same name in
+    /// different inductive types can be disambiguated.
+    ///
+    /// Only the constructors of the same inductive type are reported as duplicated.
+    pub fn define_constructor(
+        &mut self,
+        inductive: HirPath,
+        name: HirPath,
+        span: SpanId,
+    ) -> Definition {
+        let segments = inductive
+            .segments(self.db)
+            .iter()
+            .chain(name.segments(self.db))
+            .copied()
+            .collect();
+        let qualified = HirPath::new(self.db, name.span(self.db), segments);
+
+        self.check_duplicate(qualified, DefinitionKind::Constructor);
+
+        let definition = self
+            .scope
+            .define(self.db, name, span, DefinitionKind::Constructor);
+        if let Some(qualified) = qualified.to_string(self.db) {
+            self.scope.create(self.db, qualified, definition);
+        }
+
+        definition
+    }
+
+    /// Reports the unqualified constructor names that matches constructors of multiple
+    /// inductive types, like `Cons` if both `List.Cons` and `Stream.Cons` are in the scope.
+    pub fn check_ambiguous_constructor(&mut self, path: HirPath) {
+        if path.segments(self.db).len() != 1 {
+            return;
+        }
+        let Some(name) = path.to_string(self.db) else {
+            return;
+        };
+
+        let candidates = self.scope.constructor_candidates(&name);
+        if candidates.len() < 2 {
+            return;
+        }
+
+        report_error(self.db, AmbiguousConstructorError {
+            name,
+            candidates: candidates
+                .into_iter()
+                .map(|(candidate, _)| format!("`{candidate}`"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            location: path.location(self.db),
+        });
+    }
+
+    /// Creates a new [`Identifier`], normalized to NFC, and reports the identifiers that mixes
+    /// ASCII letters with characters that looks like them, like the cyrillic `а` in `pаir`.
+    pub fn identifier(
+        &mut self,
+        contents: &str,
+        refers_symbol: bool,
+        range: tree_sitter::Range,
+    ) -> Identifier {
+        if let Some((confusable, ascii)) = sol_hir::unicode::find_confusable(contents) {
+            report_error(self.db, ConfusableIdentifier {
+                name: contents.into(),
+                confusable,
+                ascii,
+                location: self.range(range),
+            });
+        }
+
+        match refers_symbol {
+            true => Identifier::symbol(self.db, contents, self.span_id(range)),
+            false => Identifier::simple(self.db, contents, self.span_id(range)),
+        }
+    }
+
     /// Creates a new [`HirPath`] from the given [`Path`]. It does transform the raw path, in a
     /// high level path, to be handled within [`Self::qualify`] function.
     ///
@@ -836,11 +1371,11 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
         let mut new_segments = vec![];
         let source_text = self.src.source_text(self.db).as_bytes();
 
-        let range = self.range(path.range());
+        let range = self.span_id(path.range());
 
         for segment in path.segments(&mut path.walk()) {
             segment.or_default_error(self, |this, segment: sol_syntax::Identifier| {
-                let range = this.range(segment.range());
+                let range = segment.range();
 
                 let identifer = match segment.child() {
                     Ok(name) => name,
@@ -851,14 +1386,14 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
                     SyntaxIdentifier::SimpleIdentifier(value) => {
                         let string = value.utf8_text(source_text).ok().unwrap_or_default();
 
-                        Identifier::new(this.db, string.into(), false, range)
+                        this.identifier(string, false, range)
                     }
                     SyntaxIdentifier::SymbolIdentifier(value) => {
                         let string = value
                             .child()
                             .with_db(this, |_, node| node.utf8_text(source_text).ok());
 
-                        Identifier::new(this.db, string.into(), true, range)
+                        this.identifier(string, true, range)
                     }
                 });
             });
@@ -893,14 +1428,26 @@ impl<'db, 'tree> HirLowering<'db, 'tree> {
     /// Creates a new [`Location`] from the given [`tree_sitter::Range`]. It does transforms the
     /// raw location, in a high level location, to be handled within resolution.
     pub fn range(&self, range: tree_sitter::Range) -> Location {
+        self.span(range.start_byte, range.end_byte)
+    }
+
+    /// Creates a new [`SpanId`] from the given [`tree_sitter::Range`], that is stored by the HIR
+    /// nodes instead of the [`Location`], so moving the declarations doesn't change them.
+    pub fn span_id(&self, range: tree_sitter::Range) -> SpanId {
+        SpanId::new(self.db, self.src, range.start_byte, range.end_byte)
+    }
+
+    /// Creates a location from byte offsets, it's useful to point to a part of a node, like a
+    /// single digit of a literal.
+    pub fn span(&self, start_byte: usize, end_byte: usize) -> Location {
         Location::new(
             self.src,
             TextSource::new(
                 self.src.file_path(self.db).to_string_lossy(),
                 self.txt.clone(),
             ),
-            range.start_byte,
-            range.end_byte,
+            start_byte,
+            end_byte,
         )
     }
 
@@ -1049,7 +1596,12 @@ mod util {
     }
 }
 
+mod attribute;
+pub mod include;
 mod literal;
+mod macros;
 mod pattern;
+pub mod source_map;
 mod stmt;
 mod term;
+mod with;
@@ -4,7 +4,12 @@
 //!
 //! It's only a module, to organization purposes.
 
-use sol_hir::source::literal::Literal;
+use num_bigint::BigUint;
+use sol_diagnostic::report_error;
+use sol_hir::{
+    errors::{HirError, HirErrorKind},
+    source::literal::Literal,
+};
 
 use super::*;
 
@@ -16,22 +21,186 @@ impl HirLowering<'_, '_> {
             .utf8_text(self.src.source_text(self.db).as_bytes())
             .unwrap_or_default();
 
-        tree.child().with_db(self, |_, node| match node {
-            Char(..) => todo!("Not implemented Char literal"),
-            F32(..) => todo!("Not implemented F32 literal"),
-            F64(..) => todo!("Not implemented F64 literal"),
-            I8(..) => text.parse::<i8>().ok().map(Literal::Int8),
-            I16(..) => text.parse::<i16>().ok().map(Literal::Int16),
-            I64(..) => text.parse::<i64>().ok().map(Literal::Int64),
-            I128(..) => todo!("Not implemented I128 literal"),
-            U1(..) => text.parse::<bool>().ok().map(Literal::Boolean),
-            U8(..) => text.parse::<u8>().ok().map(Literal::UInt8),
-            U16(..) => text.parse::<u16>().ok().map(Literal::UInt16),
-            U32(..) => text.parse::<u32>().ok().map(Literal::UInt32),
-            U64(..) => text.parse::<u64>().ok().map(Literal::UInt64),
-            U128(..) => todo!("Not implemented U128 literal"),
-            Nat(..) => todo!("Not implemented Nat literal"),
-            String(..) => Some(Literal::String((&text[1..text.len() - 1]).into())),
+        tree.child().with_db(self, |this, node| match node {
+            Char(..) => this.unsupported(tree, "char"),
+            F32(..) => this.unsupported(tree, "f32"),
+            F64(..) => this.unsupported(tree, "f64"),
+            I8(..) => this.integer(tree, text, "i8").map(Literal::Int8),
+            I16(..) => this.integer(tree, text, "i16").map(Literal::Int16),
+            I64(..) => this.integer(tree, text, "i64").map(Literal::Int64),
+            I128(..) => this.unsupported(tree, "i128"),
+            U1(..) => this.boolean(tree, text).map(Literal::Boolean),
+            U8(..) => this.integer(tree, text, "u8").map(Literal::UInt8),
+            U16(..) => this.integer(tree, text, "u16").map(Literal::UInt16),
+            U32(..) => this.integer(tree, text, "u32").map(Literal::UInt32),
+            U64(..) => this.integer(tree, text, "u64").map(Literal::UInt64),
+            U128(..) => this.unsupported(tree, "u128"),
+            Nat(..) => this.digits(tree, text, "n").map(Literal::Nat),
+            String(..) => this.string(tree, text).map(Literal::String),
         })
     }
+
+    /// Reports the literals that aren't supported yet, instead of panicking, so the malformed
+    /// files can still be lowered.
+    fn unsupported(&mut self, tree: sol_syntax::Literal, name: &str) -> Option<Literal> {
+        report_error(self.db, HirError {
+            label: self.range(tree.range()),
+            kind: HirErrorKind::UnsupportedLiteral(name.into()),
+        });
+
+        None
+    }
+
+    /// Parses an integer literal of a fixed-width type, the literals that doesn't fit in the
+    /// type are reported pointing to the whole literal, with the name of the Sol type, like
+    /// `UInt8`.
+    fn integer<T: TryFrom<BigUint>>(
+        &mut self,
+        tree: sol_syntax::Literal,
+        text: &str,
+        suffix: &str,
+    ) -> Option<T> {
+        let value = self.digits(tree, text, suffix)?;
+
+        match T::try_from(value) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                report_error(self.db, HirError {
+                    label: self.range(tree.range()),
+                    kind: HirErrorKind::LiteralOutOfRange(type_name(suffix)),
+                });
+
+                None
+            }
+        }
+    }
+
+    /// Parses a boolean literal, that is written as a single bit, like `1u1` for `true`, and
+    /// `0u1` for `false`. The other values are reported as out of range for `Bool`.
+    fn boolean(&mut self, tree: sol_syntax::Literal, text: &str) -> Option<bool> {
+        let value = self.digits(tree, text, "u1")?;
+        if value > BigUint::from(1u8) {
+            report_error(self.db, HirError {
+                label: self.range(tree.range()),
+                kind: HirErrorKind::LiteralOutOfRange("Bool".into()),
+            });
+
+            return None;
+        }
+
+        Some(value == BigUint::from(1u8))
+    }
+
+    /// Parses the digits of an integer literal, that can be written in decimal, or in
+    /// hexadecimal, octal and binary with the `0x`, `0o` and `0b` prefixes, and can be separated
+    /// by underscores, like `1_000_000`. It's normalized into an arbitrary-precision integer, so
+    /// the next phases doesn't need to handle the different notations.
+    ///
+    /// The lexer only accepts the digits of the radix, so the prefixes without digits, like
+    /// `0x`, are the only malformed literals, and they're reported pointing to the prefix.
+    fn digits(&mut self, tree: sol_syntax::Literal, text: &str, suffix: &str) -> Option<BigUint> {
+        let digits = text.strip_suffix(suffix).unwrap_or(text);
+        let (radix, prefix) = match digits.get(..2).map(|p| p.to_ascii_lowercase()).as_deref() {
+            Some("0x") => (16, 2),
+            Some("0o") => (8, 2),
+            Some("0b") => (2, 2),
+            _ => (10, 0),
+        };
+
+        let digits = digits[prefix..]
+            .chars()
+            .filter_map(|digit| digit.to_digit(radix))
+            .collect::<Vec<_>>();
+        if digits.is_empty() {
+            let start = tree.range().start_byte;
+
+            report_error(self.db, HirError {
+                label: self.span(start, start + prefix),
+                kind: HirErrorKind::MissingDigits(text[..prefix].into()),
+            });
+
+            return None;
+        }
+
+        Some(
+            digits
+                .into_iter()
+                .fold(BigUint::default(), |value, digit| value * radix + digit),
+        )
+    }
+
+    /// Decodes a string literal, with the escape sequences `\n`, `\t`, `\r`, `\0`, `\"`,
+    /// `\\` and `\u{...}`, so the next phases, like the evaluator and the backends, see the
+    /// decoded string.
+    ///
+    /// Malformed escape sequences are reported pointing to the escape sequence itself.
+    fn string(&mut self, tree: sol_syntax::Literal, text: &str) -> Option<String> {
+        let start_byte = tree.range().start_byte + 1;
+
+        // The strings without their quotes, like the missing strings of the error recovery,
+        // are already reported by the parser.
+        let contents = text.strip_prefix('"')?.strip_suffix('"')?;
+
+        let mut value = String::with_capacity(contents.len());
+        let mut valid = true;
+        let mut chars = contents.char_indices().peekable();
+        while let Some((index, character)) = chars.next() {
+            if character != '\\' {
+                value.push(character);
+                continue;
+            }
+
+            let decoded = match chars.next() {
+                Some((_, 'n')) => Some('\n'),
+                Some((_, 't')) => Some('\t'),
+                Some((_, 'r')) => Some('\r'),
+                Some((_, '0')) => Some('\0'),
+                Some((_, '"')) => Some('"'),
+                Some((_, '\\')) => Some('\\'),
+                Some((_, 'u')) if chars.peek().map(|(_, next)| *next) == Some('{') => {
+                    chars.next();
+
+                    let mut digits = String::new();
+                    let mut closed = false;
+                    for (_, digit) in chars.by_ref() {
+                        if digit == '}' {
+                            closed = true;
+                            break;
+                        }
+                        digits.push(digit);
+                    }
+
+                    Some(digits)
+                        .filter(|digits| closed && (1..=6).contains(&digits.len()))
+                        .and_then(|digits| u32::from_str_radix(&digits, 16).ok())
+                        .and_then(char::from_u32)
+                }
+                _ => None,
+            };
+
+            match decoded {
+                Some(decoded) => value.push(decoded),
+                None => {
+                    let end = chars.peek().map_or(contents.len(), |(end, _)| *end);
+
+                    report_error(self.db, HirError {
+                        label: self.span(start_byte + index, start_byte + end),
+                        kind: HirErrorKind::InvalidEscape(contents[index..end].into()),
+                    });
+
+                    valid = false;
+                }
+            }
+        }
+
+        valid.then_some(value)
+    }
+}
+
+/// Gets the name of the Sol type of the integer literals with the suffix, like `UInt8` for `u8`.
+fn type_name(suffix: &str) -> String {
+    let (signedness, width) = suffix.split_at(1);
+    let unsigned = if signedness == "u" { "U" } else { "" };
+
+    format!("{unsigned}Int{width}")
 }
@@ -0,0 +1,206 @@
+//! Defines a module for expanding the user macros, that are pattern-based rewrites of the
+//! expressions, like:
+//!
+//! ```sol
+//! macro unless {
+//!   ($cond, $body) => if $cond then () else $body,
+//! }
+//!
+//! main = unless!(debug, print "release")
+//! ```
+//!
+//! The macros are expanded while lowering, before the names of their bodies are resolved. The
+//! arguments are lowered in the scope of the call, before the body, and the body is lowered in
+//! the scope of the file, so the binders of the body can't capture the names of the arguments,
+//! and the local variables of the call can't capture the free names of the body.
+
+use sol_hir::{
+    errors::{MacroExpansionDepthError, NoMatchingMacroRuleError},
+    macros::{MacroExpansion, MacroExpansions},
+};
+use sol_syntax::anon_unions::Literal_MacroVariable;
+
+use super::*;
+
+/// The maximum depth of the nested expansions, like when a macro calls itself.
+const MAX_MACRO_DEPTH: usize = 64;
+
+/// An expansion that is being lowered, with the arguments bound to the variables of the rule.
+pub struct MacroFrame {
+    bindings: HashMap<String, Expr, FxBuildHasher>,
+}
+
+impl HirLowering<'_, '_> {
+    /// Registers the macros of the file before the other declarations, so they can be called
+    /// before being declared. The macros are local to the file.
+    pub fn register_macros(&mut self) {
+        let root = self.root_node;
+        let mut cursor = root.walk();
+        for node in root.named_children(&mut cursor) {
+            let Ok(decl) = sol_syntax::MacroDecl::try_from(node) else {
+                continue;
+            };
+            let Ok(name) = decl.name() else {
+                continue;
+            };
+
+            let name = self.node_text(*name.node());
+            self.macros.insert(name, decl);
+        }
+    }
+
+    /// Expands the macro call, lowering the body of the first rule whose patterns match the
+    /// arguments.
+    pub fn macro_call(&mut self, tree: sol_syntax::MacroCall, level: HirLevel) -> Expr {
+        let location = self.range(tree.range());
+        let name = tree
+            .name()
+            .map(|name| self.node_text(*name.node()))
+            .unwrap_or_default();
+
+        let Some(decl) = self.macros.get(&name).copied() else {
+            return self.macro_error(location, HirErrorKind::UnresolvedMacro(name));
+        };
+        let definition = self.range(decl.range());
+
+        if self.expansions.len() >= MAX_MACRO_DEPTH {
+            report_error(
+                self.db,
+                MacroExpansionDepthError {
+                    name,
+                    limit: MAX_MACRO_DEPTH,
+                    location: location.clone(),
+                    definition,
+                },
+            );
+            return Expr::Empty;
+        }
+
+        // The arguments are lowered in the scope of the call, so they're resolved before the
+        // binders of the body exist.
+        let arguments = tree
+            .arguments(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .map(|node| (self.node_text(*node.node()), self.expr(node, level)))
+            .collect::<Vec<_>>();
+
+        let Some((rule, bindings)) = decl
+            .rules(&mut decl.walk())
+            .flatten()
+            .filter_map(|rule| rule.regular())
+            .find_map(|rule| Some((rule, self.match_rule(rule, &arguments)?)))
+        else {
+            report_error(
+                self.db,
+                NoMatchingMacroRuleError {
+                    name,
+                    arity: arguments.len(),
+                    location: location.clone(),
+                    definition,
+                },
+            );
+            return Expr::Empty;
+        };
+
+        let Ok(body) = rule.body() else {
+            return Expr::Empty;
+        };
+        MacroExpansions::push(
+            self.db,
+            MacroExpansion {
+                name,
+                definition: self.range(body.range()),
+                call_site: location,
+            },
+        );
+
+        // The body is lowered in a fork of the scope of the file, so the local variables of
+        // the call can't capture its free names.
+        let file_scope = self.file_scope().fork(ScopeKind::Block);
+        let scope = std::mem::replace(&mut self.scope, file_scope);
+        self.expansions.push(MacroFrame { bindings });
+        let expr = self.expr(body, level);
+        self.expansions.pop();
+        self.scope = scope;
+
+        expr
+    }
+
+    /// Resolves the macro variable, to the argument bound to it by the current expansion.
+    pub fn macro_variable(&mut self, tree: sol_syntax::MacroVariable) -> Expr {
+        let location = self.range(tree.range());
+        let text = self.node_text(*tree.node());
+        let name = text.trim_start_matches('$');
+
+        match self
+            .expansions
+            .last()
+            .and_then(|frame| frame.bindings.get(name))
+        {
+            Some(expr) => expr.clone(),
+            None => self.macro_error(location, HirErrorKind::UnboundMacroVariable(text)),
+        }
+    }
+
+    /// Reports the error of the expansion, and returns it as an expression.
+    fn macro_error(&self, label: Location, kind: HirErrorKind) -> Expr {
+        let error = HirError { label, kind };
+        report_error(self.db, error.clone());
+
+        Expr::Error(error)
+    }
+
+    /// Matches the arguments against the patterns of the rule, returning the arguments bound
+    /// to its variables. The literal patterns match the arguments with the same text.
+    fn match_rule(
+        &self,
+        rule: sol_syntax::MacroRule,
+        arguments: &[(String, Expr)],
+    ) -> Option<HashMap<String, Expr, FxBuildHasher>> {
+        let patterns = rule
+            .patterns(&mut rule.walk())
+            .flatten()
+            .filter_map(|pattern| pattern.regular())
+            .collect::<Vec<_>>();
+        if patterns.len() != arguments.len() {
+            return None;
+        }
+
+        let mut bindings = HashMap::default();
+        for (pattern, (text, expr)) in patterns.into_iter().zip(arguments) {
+            match pattern {
+                Literal_MacroVariable::Literal(literal) => {
+                    if self.node_text(*literal.node()) != text.trim() {
+                        return None;
+                    }
+                }
+                Literal_MacroVariable::MacroVariable(variable) => {
+                    let name = self.node_text(*variable.node());
+                    bindings.insert(name.trim_start_matches('$').to_string(), expr.clone());
+                }
+            }
+        }
+
+        Some(bindings)
+    }
+
+    /// Gets the scope of the file, that is the outermost parent of the current scope.
+    fn file_scope(&self) -> Scope {
+        let mut scope = self.scope.clone();
+        while scope.kind != ScopeKind::File {
+            let Some(parent) = scope.parent.clone() else {
+                break;
+            };
+            scope = parent.deref().clone();
+        }
+        scope
+    }
+
+    /// Gets the source text of the node.
+    fn node_text(&self, node: Node) -> String {
+        node.utf8_text(self.txt.as_bytes())
+            .unwrap_or_default()
+            .to_string()
+    }
+}
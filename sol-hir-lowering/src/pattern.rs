@@ -3,18 +3,21 @@
 //!
 //! It's only a module, to organization purposes.
 
-use sol_hir::source::pattern::{BindingPattern, Constructor, ConstructorPattern, Pattern};
+use sol_hir::{
+    shadowing::ShadowingWarning,
+    source::pattern::{BindingPattern, Constructor, ConstructorPattern, Pattern},
+};
 
 use super::*;
 
 #[rustfmt::skip]
-type SyntaxPattern<'tree> = sol_syntax::anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>;
+type SyntaxPattern<'tree> = sol_syntax::anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>;
 
 impl HirLowering<'_, '_> {
     pub fn trait_pattern(&mut self, tree: SyntaxPattern) -> Pattern {
-        use sol_syntax::anon_unions::ConsPattern_GroupPattern_Literal_RestPattern::*;
+        use sol_syntax::anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern::*;
 
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
         match tree {
             // Defines a custom solver for the constructor pattern,
@@ -22,26 +25,24 @@ impl HirLowering<'_, '_> {
             ConsPattern(pattern) => {
                 let name = pattern.name().solve(self, |this, node| this.path(node));
                 let arguments = self.trait_patterns(pattern.patterns(&mut pattern.walk()));
-                let location = self.range(pattern.range());
+                let span = self.span_id(pattern.range());
 
                 // If the patterns are empty, it's a binding pattern, otherwise, it's a constructor
                 // pattern.
                 if arguments.is_empty() {
                     // Defines the node on the scope
-                    let name =
-                        self.scope
-                            .define(self.db, name, location.clone(), DefinitionKind::Type);
+                    let name = self.scope.define(self.db, name, span, DefinitionKind::Type);
 
-                    Pattern::Binding(BindingPattern { name, location })
+                    Pattern::Binding(BindingPattern { name, span })
                 } else {
                     let def = self.qualify(name, DefinitionKind::Trait);
-                    let reference = self.scope.using(self.db, def, name.location(self.db));
+                    let reference = self.scope.using(self.db, def, name.span(self.db));
                     let name = Constructor::Path(reference);
 
                     Pattern::Constructor(ConstructorPattern {
                         name,
                         arguments,
-                        location,
+                        span,
                     })
                 }
             }
@@ -51,22 +52,32 @@ impl HirLowering<'_, '_> {
                     .pattern()
                     .solve(self, |this, node| this.trait_pattern(node))
             }
-            Literal(literal) => self.literal(literal).upgrade_pattern(location, self.db),
-            RestPattern(_) => Pattern::Rest(location),
+            Literal(literal) => self.literal(literal).upgrade_pattern(span, self.db),
+            RestPattern(_) => Pattern::Rest(span),
+            TuplePattern(tuple_pattern) => Pattern::Constructor(ConstructorPattern {
+                name: Constructor::Tuple,
+                arguments: self.trait_patterns(tuple_pattern.patterns(&mut tuple_pattern.walk())),
+                span,
+            }),
         }
     }
 
     pub fn pattern(&mut self, tree: SyntaxPattern) -> Pattern {
-        use sol_syntax::anon_unions::ConsPattern_GroupPattern_Literal_RestPattern::*;
+        use sol_syntax::anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern::*;
 
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
-        match tree {
+        let node = *tree.node();
+        let pattern = match tree {
             ConsPattern(cons_pattern) => self.cons_pattern(cons_pattern),
             GroupPattern(group_pattern) => self.group_pattern(group_pattern),
-            Literal(literal) => self.literal(literal).upgrade_pattern(location, self.db),
-            RestPattern(_) => Pattern::Rest(location),
-        }
+            Literal(literal) => self.literal(literal).upgrade_pattern(span, self.db),
+            RestPattern(_) => Pattern::Rest(span),
+            TuplePattern(tuple_pattern) => self.tuple_pattern(tuple_pattern),
+        };
+
+        self.record(node, HirNode::Pattern(pattern.clone()));
+        pattern
     }
 
     pub fn group_pattern(&mut self, group: sol_syntax::GroupPattern) -> Pattern {
@@ -74,37 +85,60 @@ impl HirLowering<'_, '_> {
         return group.pattern().solve(self, |this, node| this.pattern(node));
     }
 
+    /// Resolves a tuple pattern, like `(a, b)`, into a constructor pattern of the tuple, that
+    /// destructures the elements by their positions.
+    pub fn tuple_pattern(&mut self, pattern: sol_syntax::TuplePattern) -> Pattern {
+        let arguments = self.patterns(pattern.patterns(&mut pattern.walk()));
+        let span = self.span_id(pattern.range());
+
+        Pattern::Constructor(ConstructorPattern {
+            name: Constructor::Tuple,
+            arguments,
+            span,
+        })
+    }
+
     pub fn cons_pattern(&mut self, pattern: sol_syntax::ConsPattern) -> Pattern {
         let name = pattern.name().solve(self, |this, node| this.path(node));
         let arguments = self.patterns(pattern.patterns(&mut pattern.walk()));
-        let location = self.range(pattern.range());
+        let span = self.span_id(pattern.range());
 
         // If the patterns are empty, it's a binding pattern, otherwise, it's a constructor
         // pattern.
         if arguments.is_empty()
             && name
                 .to_string(self.db)
-                .unwrap()
-                .chars()
-                .next()
-                .unwrap()
-                .is_lowercase()
+                .and_then(|name| name.chars().next())
+                .is_some_and(char::is_lowercase)
         {
+            if self
+                .scope
+                .search(self.db, name, DefinitionKind::Variable)
+                .is_some()
+            {
+                report_error(self.db, ShadowingWarning {
+                    name: name.to_string(self.db).unwrap_or_default(),
+                    location: self.range(pattern.range()),
+                });
+            }
+
             // Defines the node on the scope
             let name = self
                 .scope
-                .define(self.db, name, location.clone(), DefinitionKind::Variable);
+                .define(self.db, name, span, DefinitionKind::Variable);
 
-            Pattern::Binding(BindingPattern { name, location })
+            Pattern::Binding(BindingPattern { name, span })
         } else {
+            self.check_ambiguous_constructor(name);
+
             let def = self.qualify(name, DefinitionKind::Constructor);
-            let reference = self.scope.using(self.db, def, name.location(self.db));
+            let reference = self.scope.using(self.db, def, name.span(self.db));
             let name = Constructor::Path(reference);
 
             Pattern::Constructor(ConstructorPattern {
                 name,
                 arguments,
-                location,
+                span,
             })
         }
     }
@@ -0,0 +1,89 @@
+//! Defines the recording of the source map while lowering, and its resolution against the syntax
+//! trees, like:
+//!
+//! ```rust,ignore
+//! let source_map = source_map(db, pkg, src);
+//! let tree = src.syntax_node(db).tree;
+//!
+//! // From a HIR element, like the expression of a diagnostic, to its syntax node.
+//! let node = node_for_location(&source_map, &tree, &expr.location(db));
+//!
+//! // From a syntax node, like the one under the cursor, to its HIR elements.
+//! let elements = hir_for_node(&source_map, node);
+//! ```
+
+use sol_hir::{
+    source::HirElement,
+    source_map::{HirNode, SourceMap, SourceMapEntries, SourceMapEntry, SyntaxPtr},
+};
+
+use super::*;
+
+/// Defines the [`source_map`] query.
+///
+/// It collects the entries recorded by [`hir_lower`], of the given file only, as the lowering
+/// can depend on the declarations of the other files.
+#[salsa::tracked]
+pub fn source_map(db: &dyn crate::HirLoweringDb, pkg: Package, src: Source) -> SourceMap {
+    let entries = hir_lower::accumulated::<SourceMapEntries>(db, pkg, src)
+        .into_iter()
+        .filter(|entry| entry.hir.location(db).source() == Some(src))
+        .collect();
+
+    SourceMap::new(db, entries)
+}
+
+/// Creates a pointer to the syntax node, to be stored in the source map.
+pub fn syntax_ptr(node: Node) -> SyntaxPtr {
+    SyntaxPtr {
+        kind_id: node.kind_id(),
+        start_byte: node.start_byte(),
+        end_byte: node.end_byte(),
+    }
+}
+
+/// Resolves the pointer into the syntax node of the tree. It returns `None` if the tree doesn't
+/// have the node, like when it's from another revision.
+pub fn resolve_ptr(tree: &Tree, ptr: SyntaxPtr) -> Option<Node<'_>> {
+    let mut node = tree
+        .root_node()
+        .descendant_for_byte_range(ptr.start_byte, ptr.end_byte)?;
+
+    // The smallest node in the range can be wrapped by nodes with the same range, like the
+    // paths by the primaries, so it walks up until the kind matches.
+    loop {
+        if node.kind_id() == ptr.kind_id && node.byte_range() == (ptr.start_byte..ptr.end_byte) {
+            return Some(node);
+        }
+        node = node
+            .parent()
+            .filter(|parent| parent.byte_range() == (ptr.start_byte..ptr.end_byte))?;
+    }
+}
+
+/// Gets the syntax node that the HIR element at the location was lowered from.
+pub fn node_for_location<'tree>(
+    source_map: &SourceMap,
+    tree: &'tree Tree,
+    location: &Location,
+) -> Option<Node<'tree>> {
+    resolve_ptr(tree, source_map.node_for_location(location)?)
+}
+
+/// Gets the HIR elements that the syntax node was lowered into, in the lowering order.
+pub fn hir_for_node<'a>(source_map: &'a SourceMap, node: Node) -> &'a [HirNode] {
+    source_map.hir_for_node(syntax_ptr(node))
+}
+
+impl HirLowering<'_, '_> {
+    /// Records the HIR element that the syntax node was lowered into, in the source map.
+    pub fn record(&self, node: Node, hir: HirNode) {
+        SourceMapEntries::push(
+            self.db,
+            SourceMapEntry {
+                node: syntax_ptr(node),
+                hir,
+            },
+        );
+    }
+}
@@ -17,7 +17,20 @@ use sol_hir::{
 
 use super::*;
 
-type SyntaxStmt<'tree> = sol_syntax::anon_unions::AskStmt_ExprStmt_IfStmt_LetStmt<'tree>;
+#[rustfmt::skip]
+type SyntaxStmt<'tree> = sol_syntax::anon_unions::AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt<'tree>;
+
+#[rustfmt::skip]
+type SyntaxArmBody<'tree> = sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>;
+
+#[rustfmt::skip]
+type SyntaxExpr<'tree> = sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>;
+
+/// The std combinator that the for statements are lowered into, like `forM_ xs (|x| body)`.
+const FOR_COMBINATOR: &str = "forM_";
+
+/// The std combinator that the while statements are lowered into, like `whileM_ cond body`.
+const WHILE_COMBINATOR: &str = "whileM_";
 
 impl HirLowering<'_, '_> {
     /// Resolves an statement.
@@ -25,14 +38,21 @@ impl HirLowering<'_, '_> {
     /// It does resolves a syntax statement,
     /// into a high-level statement.
     pub fn stmt(&mut self, stmt: SyntaxStmt, level: HirLevel) -> Stmt {
-        use sol_syntax::anon_unions::AskStmt_ExprStmt_IfStmt_LetStmt::*;
+        use sol_syntax::anon_unions::AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt::*;
 
-        match stmt {
+        let node = *stmt.node();
+        let stmt = match stmt {
             AskStmt(ask_stmt) => self.ask_stmt(ask_stmt, level),
+            AssertStmt(assert_stmt) => self.assert_stmt(assert_stmt, level),
             ExprStmt(expr_stmt) => self.expr_stmt(expr_stmt, level),
+            ForStmt(for_stmt) => self.for_stmt(for_stmt, level),
             IfStmt(if_stmt) => self.if_stmt(if_stmt, level),
             LetStmt(let_stmt) => self.let_stmt(let_stmt, level),
-        }
+            WhileStmt(while_stmt) => self.while_stmt(while_stmt, level),
+        };
+
+        self.record(node, HirNode::Stmt(stmt.clone()));
+        stmt
     }
 
     /// Resolves an ask statement.
@@ -45,12 +65,12 @@ impl HirLowering<'_, '_> {
             .value()
             .solve(self, |this, node| this.expr(node, level));
 
-        let location = self.range(stmt.range());
+        let span = self.span_id(stmt.range());
 
         Stmt::Ask(AskStmt {
             pattern,
             value: expr,
-            location,
+            span,
         })
     }
 
@@ -75,12 +95,18 @@ impl HirLowering<'_, '_> {
             .condition()
             .solve(self, |this, node| this.expr(node, level));
 
+        // The pattern of the if let, like `if let Just x = y`, is resolved before the branches,
+        // so its bindings are visible in them.
+        let pattern = stmt
+            .pattern()
+            .map(|node| node.solve(self, |this, node| this.pattern(node)));
+
         let then = stmt.then().solve(self, |this, node| {
             use sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr::*;
 
             node.child().solve(this, |this, node| match node {
                 Block(block) => Expr::block(this.db, this.block(block, level)),
-                _ => this.expr(node.into_node().try_into().unwrap(), level),
+                _ => SyntaxExpr::try_from(node.into_node()).solve(this, |this, node| this.expr(node, level)),
             })
         });
 
@@ -90,35 +116,155 @@ impl HirLowering<'_, '_> {
 
                 node.value().solve(this, |this, node| match node {
                   Block(block) => Expr::block(this.db, this.block(block, level)),
-                  _ => this.expr(node.into_node().try_into().unwrap(), level),
+                  _ => SyntaxExpr::try_from(node.into_node()).solve(this, |this, node| this.expr(node, level)),
                 })
               })
-            })
-          .unwrap_or_else(|| Expr::call_unit_expr(Location::CallSite));
+            });
+
+        let span = self.span_id(stmt.range());
+
+        if let Some(pattern) = pattern {
+            return Stmt::Downgrade(Expr::Match(MatchExpr {
+                kind: MatchKind::StmtLevel(Box::new(MatchKind::Match)),
+                scrutinee: Box::new(scrutinee),
+                clauses: self.if_let_arms(pattern, then, otherwise, span),
+                span,
+            }));
+        }
+
+        let otherwise = otherwise.unwrap_or_else(|| Expr::call_unit_expr(SpanId::CallSite));
 
         let clauses = vec![
             MatchArm {
                 pattern: Pattern::Literal(Spanned::on_call_site(Literal::TRUE)),
-                location: then.location(self.db),
+                guard: None,
+                span: SpanId::of(self.db, &then.location(self.db)),
                 value: then,
             },
             MatchArm {
                 pattern: Pattern::Literal(Spanned::on_call_site(Literal::FALSE)),
-                location: otherwise.location(self.db),
+                guard: None,
+                span: SpanId::of(self.db, &otherwise.location(self.db)),
                 value: otherwise,
             },
         ];
 
-        let location = self.range(stmt.range());
-
         Stmt::Downgrade(Expr::Match(MatchExpr {
             kind: MatchKind::StmtLevel(Box::new(MatchKind::If)),
             scrutinee: Box::new(scrutinee),
             clauses,
-            location,
+            span,
         }))
     }
 
+    /// Resolves an assert statement, like `assert x > 0`.
+    ///
+    /// It's lowered into `if x > 0 then () else panic "..."`, where the message holds the
+    /// source of the condition, and where it's located, so the VM reports which assertion
+    /// failed.
+    pub fn assert_stmt(&mut self, stmt: sol_syntax::AssertStmt, level: HirLevel) -> Stmt {
+        let span = self.span_id(stmt.range());
+        let condition = stmt
+            .condition()
+            .solve(self, |this, node| this.expr(node, level));
+
+        let text = stmt
+            .condition()
+            .ok()
+            .and_then(|node| node.node().utf8_text(self.txt.as_bytes()).ok())
+            .unwrap_or_default();
+        let panic = self.panic_call(Callee::Panic, &format!("assertion failed: `{text}`"), span);
+
+        let clauses = vec![
+            MatchArm {
+                pattern: Pattern::Literal(Spanned::on_call_site(Literal::TRUE)),
+                guard: None,
+                value: Expr::call_unit_expr(span),
+                span,
+            },
+            MatchArm {
+                pattern: Pattern::Literal(Spanned::on_call_site(Literal::FALSE)),
+                guard: None,
+                value: panic,
+                span,
+            },
+        ];
+
+        Stmt::Downgrade(Expr::Match(MatchExpr {
+            kind: MatchKind::StmtLevel(Box::new(MatchKind::If)),
+            scrutinee: Box::new(condition),
+            clauses,
+            span,
+        }))
+    }
+
+    /// Resolves a for statement, like `for x in xs do print x`.
+    ///
+    /// It's a syntax sugar for the `forM_` combinator, so it's lowered into the call
+    /// `forM_ xs (|x| print x)`. The pattern is bound by the lambda, so the refutable patterns
+    /// are matched in the body, like in the parameters of the lambdas.
+    pub fn for_stmt(&mut self, stmt: sol_syntax::ForStmt, level: HirLevel) -> Stmt {
+        let span = self.span_id(stmt.range());
+        let iterable = stmt
+            .iterable()
+            .solve(self, |this, node| this.expr(node, level));
+
+        self.scope = self.scope.fork(ScopeKind::Lambda);
+
+        let mut refutable = vec![];
+        let parameter = stmt.pattern().solve(self, |this, node| {
+            let parameter_span = this.span_id(node.range());
+            let pattern = this.pattern(node);
+            this.lambda_parameter(pattern, parameter_span, &mut refutable)
+        });
+        let body = self.loop_body(stmt.body(), level);
+        let body = Self::match_refutable(body, refutable, span);
+
+        let scope = self.pop_scope();
+
+        let lambda = Expr::Lam(LamExpr {
+            parameters: vec![parameter],
+            value: Box::new(body),
+            span,
+            scope,
+        });
+
+        self.combinator(FOR_COMBINATOR, vec![iterable, lambda], span)
+    }
+
+    /// Resolves a while statement, like `while running do step`.
+    ///
+    /// It's a syntax sugar for the `whileM_` combinator, so it's lowered into the call
+    /// `whileM_ running step`, and the condition is an action, that is run before each
+    /// iteration.
+    pub fn while_stmt(&mut self, stmt: sol_syntax::WhileStmt, level: HirLevel) -> Stmt {
+        let span = self.span_id(stmt.range());
+        let condition = stmt
+            .condition()
+            .solve(self, |this, node| this.expr(node, level));
+        let body = self.loop_body(stmt.body(), level);
+
+        self.combinator(WHILE_COMBINATOR, vec![condition, body], span)
+    }
+
+    /// Resolves the body of a loop, that can be a block, or an expression.
+    fn loop_body(&mut self, body: NodeResult<SyntaxArmBody>, level: HirLevel) -> Expr {
+        body.solve(self, |this, node| {
+            use sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr::*;
+
+            match node {
+                Block(block) => Expr::block(this.db, this.block(block, level)),
+                _ => SyntaxExpr::try_from(node.into_node())
+                    .solve(this, |this, node| this.expr(node, level)),
+            }
+        })
+    }
+
+    /// Calls the std combinator, like [`HirLowering::std_call`].
+    fn combinator(&mut self, name: &str, arguments: Vec<Expr>, span: SpanId) -> Stmt {
+        Stmt::Downgrade(self.std_call(name, arguments, span))
+    }
+
     /// Resolves a let statement.
     ///
     /// It's a statement that binds a pattern to a value.
@@ -128,12 +274,12 @@ impl HirLowering<'_, '_> {
             .value()
             .solve(self, |this, node| this.expr(node, level));
 
-        let location = self.range(stmt.range());
+        let span = self.span_id(stmt.range());
 
         Stmt::Let(LetStmt {
             pattern,
             value: expr,
-            location,
+            span,
         })
     }
 
@@ -151,7 +297,7 @@ impl HirLowering<'_, '_> {
 
         Block {
             statements,
-            location: self.range(block.range()),
+            span: self.span_id(block.range()),
             scope: self.scope.clone().into(),
         }
     }
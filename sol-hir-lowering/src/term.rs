@@ -9,7 +9,7 @@ use sol_hir::{
     errors::{HirError, HirErrorKind},
     solver::HirLevel,
     source::{
-        expr::{MatchArm, MatchExpr, MatchKind, Pi, Type},
+        expr::{LetExpr, MatchArm, MatchExpr, MatchKind, Pi, RecordExpr, RecordField, Type},
         literal::Literal,
         pattern::Pattern,
         HirElement,
@@ -24,6 +24,18 @@ type SyntaxExpr<'tree> = sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_Lam
 #[rustfmt::skip]
 type SyntaxTypeRep<'tree> = sol_syntax::anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<'tree>;
 
+#[rustfmt::skip]
+type SyntaxPattern<'tree> = sol_syntax::anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>;
+
+/// The pipeline operator, like `x |> f`, that is lowered into an application.
+const PIPELINE_OPERATOR: &str = "|>";
+
+/// The std function that the map literals are lowered into, like `Map.fromList [("a", 1)]`.
+const MAP_FROM_LIST: &str = "Map.fromList";
+
+/// The std function that the set literals are lowered into, like `Set.fromList [1, 2]`.
+const SET_FROM_LIST: &str = "Set.fromList";
+
 impl HirLowering<'_, '_> {
     /// Resolves a type level expression.
     ///
@@ -32,7 +44,8 @@ impl HirLowering<'_, '_> {
     pub fn type_expr(&mut self, tree: SyntaxTypeRep) -> TypeRep {
         use sol_syntax::anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr::*;
 
-        match tree {
+        let node = *tree.node();
+        let type_rep = match tree {
             // SECTION: type_expr
             //
             // Upgrades the expressions to type level expressions, to be easier to handle errors
@@ -49,7 +62,10 @@ impl HirLowering<'_, '_> {
             PiExpr(pi) => self.pi_expr(pi),
             SigmaExpr(sigma) => self.sigma_expr(sigma),
             TypeAppExpr(type_app) => self.type_app_expr(type_app),
-        }
+        };
+
+        self.record(node, HirNode::Expr(type_rep.downgrade()));
+        type_rep
     }
 
     /// Resolves an expression.
@@ -59,7 +75,8 @@ impl HirLowering<'_, '_> {
     pub fn expr(&mut self, tree: SyntaxExpr, level: HirLevel) -> Expr {
         use sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr::*;
 
-        match tree {
+        let node = *tree.node();
+        let expr = match tree {
             // SECTION: expr
             Primary(primary) => self.primary(primary, level),
             AnnExpr(ann_expr) => self.ann_expr(ann_expr, level),
@@ -71,7 +88,10 @@ impl HirLowering<'_, '_> {
             // Type level expressions
             PiExpr(pi) => self.pi_expr(pi).downgrade(),
             SigmaExpr(sigma) => self.sigma_expr(sigma).downgrade(),
-        }
+        };
+
+        self.record(node, HirNode::Expr(expr.clone()));
+        expr
     }
 
     /// Resolves an annotation expression.
@@ -85,12 +105,12 @@ impl HirLowering<'_, '_> {
         let type_rep = tree
             .against()
             .solve(self, |this, expr| this.type_expr(expr));
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
         Expr::Ann(AnnExpr {
             value: Box::new(value),
             type_rep,
-            location,
+            span,
         })
     }
 
@@ -108,28 +128,98 @@ impl HirLowering<'_, '_> {
                 Primary(primary) => this.primary(primary, level),
             }
         });
-        let op = tree.op().solve(self, |this, node| {
-            let location = this.range(node.range());
+        let span = self.span_id(tree.range());
+
+        self.apply_infix_op(tree.op(), lhs, rhs, span)
+    }
+
+    /// Applies the infix operator to the operands, of a binary expression, or of an operator
+    /// section.
+    fn apply_infix_op(
+        &mut self,
+        op: NodeResult<sol_syntax::InfixOp>,
+        lhs: Expr,
+        rhs: Expr,
+        span: SpanId,
+    ) -> Expr {
+        // The pipeline operator is a syntax sugar for the application, so `x |> f` is lowered
+        // into `f x`, instead of a reference to an operator definition. The call keeps the
+        // location of its stage, so the diagnostics point to the right one.
+        let is_pipeline = op.as_ref().is_ok_and(|node| {
+            node.utf8_text(self.src.source_text(self.db).as_bytes()) == Ok(PIPELINE_OPERATOR)
+        });
+        if is_pipeline {
+            return Expr::Call(CallExpr {
+                kind: CallKind::Prefix,
+                callee: Callee::Expr(rhs.into()),
+                arguments: vec![lhs],
+                do_notation: None,
+                span,
+            });
+        }
+
+        let op = op.solve(self, |this, node| {
+            let range = node.range();
             let identifier = node
                 .utf8_text(this.src.source_text(this.db).as_bytes())
                 .unwrap_or_default();
 
-            let identifier = Identifier::symbol(this.db, identifier, location.clone());
+            let identifier = this.identifier(identifier, true, range);
 
-            HirPath::new(this.db, location, vec![identifier])
+            HirPath::new(this.db, this.span_id(range), vec![identifier])
         });
-        let location = self.range(tree.range());
 
         let op = self.qualify(op, DefinitionKind::Function);
 
-        let reference = self.scope.using(self.db, op, location.clone());
+        let reference = self.scope.using(self.db, op, span);
 
         Expr::Call(CallExpr {
             kind: CallKind::Infix,
             callee: Callee::Reference(reference),
             arguments: vec![lhs, rhs],
             do_notation: None,
-            location,
+            span,
+        })
+    }
+
+    /// Resolves an operator section, like `(+ 1)` or `(1 +)`, into a lambda over the missing
+    /// operand, like `|x| x + 1`.
+    ///
+    /// The operand is resolved before forking the scope, so it can't capture the fresh variable.
+    pub fn section_expr(&mut self, tree: sol_syntax::SectionExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+        let lhs = tree
+            .lhs()
+            .map(|node| node.solve(self, |this, node| this.expr(node, level)));
+        let rhs = tree.rhs().map(|node| {
+            node.solve(self, |this, node| {
+                use sol_syntax::anon_unions::BinaryExpr_Primary::*;
+
+                match node {
+                    BinaryExpr(binary_expr) => this.binary_expr(binary_expr, level),
+                    Primary(primary) => this.primary(primary, level),
+                }
+            })
+        });
+
+        self.scope = self.scope.fork(ScopeKind::Lambda);
+
+        let (parameter, variable) = self.fresh_parameter(span);
+        let value = match lhs {
+            Some(lhs) => self.apply_infix_op(tree.op(), lhs, variable, span),
+            None => {
+                let rhs = rhs.unwrap_or(Expr::Empty);
+                self.apply_infix_op(tree.op(), variable, rhs, span)
+            }
+        };
+
+        let scope = self.pop_scope();
+
+        Expr::Lam(LamExpr {
+            parameters: vec![parameter],
+            value: Box::new(value),
+            span,
+            scope,
         })
     }
 
@@ -140,6 +230,162 @@ impl HirLowering<'_, '_> {
     pub fn lam_expr(&mut self, tree: sol_syntax::LamExpr, level: HirLevel) -> Expr {
         self.scope = self.scope.fork(ScopeKind::Lambda);
 
+        let span = self.span_id(tree.range());
+        let arms = tree
+            .arms(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .collect::<Vec<_>>();
+
+        // The lambdas with clauses, like `\ Nil => 0 | Cons _ _ => 1`, are desugared into a
+        // lambda over a fresh variable, that is matched against the clauses.
+        if !arms.is_empty() {
+            let (parameter, scrutinee) = self.fresh_parameter(span);
+            let clauses = arms
+                .into_iter()
+                .map(|arm| self.match_arm(arm, level))
+                .collect();
+            let value = Expr::Match(MatchExpr {
+                kind: MatchKind::Match,
+                scrutinee: Box::new(scrutinee),
+                clauses,
+                span,
+            });
+
+            let scope = self.pop_scope();
+
+            return Expr::Lam(LamExpr {
+                parameters: vec![parameter],
+                value: Box::new(value),
+                span,
+                scope,
+            });
+        }
+
+        // The parameters with refutable patterns, like `|Cons x _| x`, are replaced by fresh
+        // variables, that are matched against the patterns in the body.
+        let mut refutable = vec![];
+        let parameters = tree
+            .parameters(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .map(|node| {
+                let parameter_span = self.span_id(node.range());
+                let pattern = self.pattern(node);
+                self.lambda_parameter(pattern, parameter_span, &mut refutable)
+            })
+            .collect::<Vec<_>>();
+
+        let value = tree
+            .value()
+            .map(|value| value.solve(self, |this, node| this.expr(node, level)))
+            .unwrap_or(Expr::Empty);
+        let value = Self::match_refutable(value, refutable, span);
+
+        let scope = self.pop_scope();
+
+        Expr::Lam(LamExpr {
+            parameters,
+            value: Box::new(value),
+            span,
+            scope,
+        })
+    }
+
+    /// Resolves the pattern of a parameter of a lambda. The refutable patterns, like `Cons x _`,
+    /// are replaced by fresh variables, that are pushed with the patterns into `refutable`, to
+    /// be matched in the body with [`HirLowering::match_refutable`].
+    pub fn lambda_parameter(
+        &mut self,
+        pattern: Pattern,
+        span: SpanId,
+        refutable: &mut Vec<(Expr, Pattern)>,
+    ) -> Pattern {
+        match pattern {
+            pattern @ (Pattern::Constructor(_) | Pattern::Literal(_)) => {
+                let (parameter, scrutinee) = self.fresh_parameter(span);
+                refutable.push((scrutinee, pattern));
+                parameter
+            }
+            pattern => pattern,
+        }
+    }
+
+    /// Matches the fresh variables of the refutable parameters against their patterns, in
+    /// order, around the body of the lambda.
+    pub fn match_refutable(value: Expr, refutable: Vec<(Expr, Pattern)>, span: SpanId) -> Expr {
+        refutable
+            .into_iter()
+            .rev()
+            .fold(value, |value, (scrutinee, pattern)| {
+                Expr::Match(MatchExpr {
+                    kind: MatchKind::Match,
+                    scrutinee: Box::new(scrutinee),
+                    clauses: vec![MatchArm {
+                        pattern,
+                        guard: None,
+                        value,
+                        span,
+                    }],
+                    span,
+                })
+            })
+    }
+
+    /// Defines a fresh variable, that can't be written in the source code, returning its
+    /// binding pattern, and a reference to it. It's named by the offset of the span, relative
+    /// to its anchor, so the fresh variables of the same lambda don't clash.
+    fn fresh_parameter(&mut self, span: SpanId) -> (Pattern, Expr) {
+        let offset = match span {
+            SpanId::Anchored { anchor, start, .. } => format!("{anchor}_{start}"),
+            SpanId::CallSite => "0".into(),
+        };
+        let name = HirPath::create(self.db, &format!("$lam{offset}"));
+
+        let definition = self
+            .scope
+            .define(self.db, name, span, DefinitionKind::Variable);
+        let reference = self.scope.using(self.db, definition, span);
+
+        let pattern = Pattern::Binding(BindingPattern {
+            name: definition,
+            span,
+        });
+
+        (pattern, Expr::Path(reference))
+    }
+
+    /// Resolves a let expression, like `let x = 10 in x + 1`.
+    ///
+    /// The value is resolved before forking the scope, so the pattern bindings are only visible
+    /// in the body.
+    pub fn let_expr(&mut self, tree: sol_syntax::LetExpr, level: HirLevel) -> Expr {
+        let value = tree
+            .value()
+            .solve(self, |this, node| this.expr(node, level));
+
+        self.scope = self.scope.fork(ScopeKind::Let);
+
+        let pattern = tree.pattern().solve(self, |this, node| this.pattern(node));
+        let body = tree.body().solve(self, |this, node| this.expr(node, level));
+        let span = self.span_id(tree.range());
+
+        let scope = self.pop_scope();
+
+        Expr::Let(LetExpr {
+            pattern,
+            value: Box::new(value),
+            body: Box::new(body),
+            span,
+            scope,
+        })
+    }
+
+    /// Resolves a trailing lambda, like `{ x => x + 1 }` in `map xs { x => x + 1 }`, that is
+    /// passed as the last argument of the call.
+    pub fn trailing_lambda(&mut self, tree: sol_syntax::TrailingLambda, level: HirLevel) -> Expr {
+        self.scope = self.scope.fork(ScopeKind::Lambda);
+
         let parameters = tree
             .parameters(&mut tree.walk())
             .flatten()
@@ -150,14 +396,14 @@ impl HirLowering<'_, '_> {
         let value = tree
             .value()
             .solve(self, |this, node| this.expr(node, level));
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
         let scope = self.pop_scope();
 
         Expr::Lam(LamExpr {
             parameters,
             value: Box::new(value),
-            location,
+            span,
             scope,
         })
     }
@@ -176,8 +422,14 @@ impl HirLowering<'_, '_> {
             .flatten()
             .flat_map(|node| node.regular())
             .map(|node| self.primary(node, level))
+            .chain(
+                tree.trailing()
+                    .map(|node| node.solve(self, |this, node| this.trailing_lambda(node, level))),
+            )
             .collect::<Vec<_>>();
 
+        // The trailing lambda is a field, so it's not captured as the do-notation block, even
+        // if both are written between braces.
         let do_notation = tree
             .children(&mut tree.walk())
             .flatten()
@@ -186,14 +438,14 @@ impl HirLowering<'_, '_> {
             .map(|node| self.block(node, level))
             .last();
 
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
         Expr::Call(CallExpr {
             kind: CallKind::Infix,
             callee: Callee::Expr(callee.into()),
             arguments,
             do_notation,
-            location,
+            span,
         })
     }
 
@@ -213,7 +465,7 @@ impl HirLowering<'_, '_> {
             .map(|node| self.primary(node, HirLevel::Type).upgrade(self.db))
             .collect::<Vec<_>>();
 
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
         TypeRep {
             expr: Box::new(Expr::Call(CallExpr {
@@ -221,7 +473,7 @@ impl HirLowering<'_, '_> {
                 callee: Callee::Expr(callee.downgrade().into()),
                 do_notation: None,
                 arguments: arguments.into_iter().map(|expr| expr.downgrade()).collect(),
-                location,
+                span,
             })),
         }
     }
@@ -255,7 +507,12 @@ impl HirLowering<'_, '_> {
                         .map(|parameter| this.parameter(false, true, parameter))
                         .collect::<Vec<_>>();
                 }
-                _ => this.type_expr(node.into_node().try_into().unwrap()),
+                _ => TypeRep {
+                    expr: Box::new(
+                        SyntaxTypeRep::try_from(node.into_node())
+                            .solve(this, |this, node| this.type_expr(node).downgrade()),
+                    ),
+                },
             };
 
             // This handles the case where the parameter is unnamed, and only haves a type. The name
@@ -266,11 +523,28 @@ impl HirLowering<'_, '_> {
         let value = tree.value().solve(self, |this, expr| this.type_expr(expr));
         let _ = self.pop_scope();
 
+        // The pi types without the arrow are recovered by the parser, so the missing arrow is
+        // reported here, right after the parameters.
+        let node = *tree.node();
+        let has_arrow = node
+            .children(&mut node.walk())
+            .any(|child| child.kind() == "->");
+        if !has_arrow {
+            let end = node
+                .child_by_field_name("parameter")
+                .map_or(node.start_byte(), |parameter| parameter.end_byte());
+
+            report_error(self.db, HirError {
+                label: self.span(end, end),
+                kind: HirErrorKind::MissingPiArrow,
+            });
+        }
+
         TypeRep {
             expr: Box::new(Expr::Pi(Pi {
                 parameters,
                 value: Box::new(value),
-                location: self.range(tree.range()),
+                span: self.span_id(tree.range()),
             })),
         }
     }
@@ -287,31 +561,30 @@ impl HirLowering<'_, '_> {
             .flatten()
             .filter_map(|parameter| parameter.regular())
             .map(|parameter| {
-                use sol_syntax::anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern::*;
+                use sol_syntax::anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern::*;
 
                 match parameter {
                     Parameter(parameter) => self.parameter(true, true, parameter),
                     _ => {
-                        let parameter = parameter.into_node().try_into().unwrap();
-
                         // This handles the case where the parameter is unnamed, and only haves a
                         // pattern. The name should not be shown in the IDE in this case.
-                        let pattern = self.trait_pattern(parameter);
+                        let pattern = SyntaxPattern::try_from(parameter.into_node())
+                            .solve(self, |this, parameter| this.trait_pattern(parameter));
 
-                        // The location of the parameter is the location of the pattern
-                        let location = pattern.location(self.db);
+                        // The span of the parameter is the span of the pattern
+                        let span = SpanId::of(self.db, &pattern.location(self.db));
 
                         // Creates a new parameter, with no type, but a pattern
                         self::Parameter::new(
                             self.db,
                             /* binding     = */ pattern,
                             /* type_rep    = */ TypeRep {
-                                expr: Box::new(Expr::Hole(location.clone())),
+                                expr: Box::new(Expr::Hole(span)),
                             },
                             /* is_implicit = */ true,
                             /* rigid       = */ false,
                             /* level       = */ HirLevel::Type,
-                            /* location    = */ location,
+                            /* span        = */ span,
                         )
                     }
                 }
@@ -325,7 +598,7 @@ impl HirLowering<'_, '_> {
             expr: Box::new(Expr::Sigma(Pi {
                 parameters,
                 value: Box::new(value),
-                location: self.range(tree.range()),
+                span: self.span_id(tree.range()),
             })),
         }
     }
@@ -339,36 +612,47 @@ impl HirLowering<'_, '_> {
             .scrutinee()
             .solve(self, |this, node| this.expr(node, level));
 
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
-        let clauses = tree.arms(&mut tree.walk())
-      .flatten()
-      .filter_map(|node| node.regular())
-      .map(|node| {
-        let pattern = node.pattern().solve(self, |this, pattern| this.pattern(pattern));
+        let clauses = tree
+            .arms(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .map(|node| self.match_arm(node, level))
+            .collect();
+
+        Expr::Match(MatchExpr {
+            kind: MatchKind::Match,
+            scrutinee: Box::new(scrutinee),
+            clauses,
+            span,
+        })
+    }
+
+    /// Resolves a match arm, like `Cons x _ if x > 0 => x`, of a match expression, or of a
+    /// lambda with clauses.
+    pub fn match_arm(&mut self, node: sol_syntax::MatchArm, level: HirLevel) -> MatchArm {
+        let pattern = node
+            .pattern()
+            .solve(self, |this, pattern| this.pattern(pattern));
+        let guard = node
+            .guard()
+            .map(|guard| guard.solve(self, |this, node| this.expr(node, HirLevel::Expr)));
         let body = node.body().solve(self, |this, node| {
           use sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr::*;
 
           match node {
             Block(block) => Expr::block(this.db, this.block(block, level)),
-            _ => this.expr(node.into_node().try_into().unwrap(), level)
+            _ => SyntaxExpr::try_from(node.into_node()).solve(this, |this, node| this.expr(node, level))
           }
         });
 
         MatchArm {
-          pattern,
-          value: body,
-          location: self.range(node.range()),
+            pattern,
+            guard,
+            value: body,
+            span: self.span_id(node.range()),
         }
-      })
-      .collect();
-
-        Expr::Match(MatchExpr {
-            kind: MatchKind::Match,
-            scrutinee: Box::new(scrutinee),
-            clauses,
-            location,
-        })
     }
 
     /// Resolves a if expression.
@@ -380,12 +664,18 @@ impl HirLowering<'_, '_> {
             .condition()
             .solve(self, |this, node| this.expr(node, level));
 
+        // The pattern of the if let, like `if let Just x = y`, is resolved before the branches,
+        // so its bindings are visible in them.
+        let pattern = tree
+            .pattern()
+            .map(|node| node.solve(self, |this, node| this.pattern(node)));
+
         let then = tree.then().solve(self, |this, node| {
         use sol_syntax::anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr::*;
 
         node.child().solve(this, |this, node| match node {
           Block(block) => Expr::block(this.db, this.block(block, level)),
-          _ => this.expr(node.into_node().try_into().unwrap(), level),
+          _ => SyntaxExpr::try_from(node.into_node()).solve(this, |this, node| this.expr(node, level)),
         })
       });
 
@@ -394,39 +684,91 @@ impl HirLowering<'_, '_> {
 
         node.value().solve(this, |this, node| match node {
           Block(block) => Expr::block(this.db, this.block(block, level)),
-          _ => this.expr(node.into_node().try_into().unwrap(), level),
+          _ => SyntaxExpr::try_from(node.into_node()).solve(this, |this, node| this.expr(node, level)),
         })
       });
 
+        let span = self.span_id(tree.range());
+
+        if let Some(pattern) = pattern {
+            return Expr::Match(MatchExpr {
+                kind: MatchKind::Match,
+                scrutinee: Box::new(scrutinee),
+                clauses: self.if_let_arms(pattern, then, Some(otherwise), span),
+                span,
+            });
+        }
+
         let clauses = vec![
             MatchArm {
                 pattern: Pattern::Literal(Spanned::on_call_site(Literal::TRUE)),
-                location: then.location(self.db),
+                guard: None,
+                span: SpanId::of(self.db, &then.location(self.db)),
                 value: then,
             },
             MatchArm {
                 pattern: Pattern::Literal(Spanned::on_call_site(Literal::FALSE)),
-                location: otherwise.location(self.db),
+                guard: None,
+                span: SpanId::of(self.db, &otherwise.location(self.db)),
                 value: otherwise,
             },
         ];
 
-        let location = self.range(tree.range());
-
         Expr::Match(MatchExpr {
             kind: MatchKind::If,
             scrutinee: Box::new(scrutinee),
             clauses,
-            location,
+            span,
         })
     }
 
+    /// Creates the arms of an if let, like `if let Just x = y then x else 0`, that matches the
+    /// pattern, or falls back to the else branch.
+    ///
+    /// The else branch can only be omitted if the pattern is irrefutable, otherwise the match
+    /// wouldn't be exhaustive, so it's reported.
+    pub fn if_let_arms(
+        &mut self,
+        pattern: Pattern,
+        then: Expr,
+        otherwise: Option<Expr>,
+        span: SpanId,
+    ) -> Vec<MatchArm> {
+        let irrefutable = matches!(
+            pattern,
+            Pattern::Hole | Pattern::Wildcard(_) | Pattern::Binding(_) | Pattern::Error(_)
+        );
+
+        let mut arms = vec![MatchArm {
+            pattern,
+            guard: None,
+            span: SpanId::of(self.db, &then.location(self.db)),
+            value: then,
+        }];
+
+        match otherwise {
+            Some(otherwise) => arms.push(MatchArm {
+                pattern: Pattern::Wildcard(SpanId::of(self.db, &otherwise.location(self.db))),
+                guard: None,
+                span: SpanId::of(self.db, &otherwise.location(self.db)),
+                value: otherwise,
+            }),
+            None if !irrefutable => report_error(self.db, HirError {
+                label: span.resolve(self.db),
+                kind: HirErrorKind::RefutableIfLet,
+            }),
+            None => {}
+        }
+
+        arms
+    }
+
     /// Resolves an array expression.
     ///
     /// It does translate the syntax array expression
     /// into a high-level array expression.
     pub fn array_expr(&mut self, tree: sol_syntax::ArrayExpr, level: HirLevel) -> Expr {
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
         let items = tree
             .items(&mut tree.walk())
@@ -438,7 +780,157 @@ impl HirLowering<'_, '_> {
             callee: Callee::Array,
             arguments: items,
             do_notation: None,
-            location,
+            span,
+        })
+    }
+
+    /// Resolves a map literal, like `#{"a": 1, "b": 2}`.
+    ///
+    /// It's a syntax sugar for the `Map.fromList` function of the std library, so it's lowered
+    /// into the call `Map.fromList [("a", 1), ("b", 2)]`, and it's elaborated against the type
+    /// of the function.
+    pub fn map_expr(&mut self, tree: sol_syntax::MapExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+
+        let entries = tree
+            .entries(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .map(|entry| {
+                let span = self.span_id(entry.range());
+                let key = entry.key().solve(self, |this, node| this.expr(node, level));
+                let value = entry
+                    .value()
+                    .solve(self, |this, node| this.expr(node, level));
+
+                Expr::Call(CallExpr {
+                    kind: CallKind::Prefix,
+                    callee: Callee::Tuple,
+                    arguments: vec![key, value],
+                    do_notation: None,
+                    span,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let entries = Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Array,
+            arguments: entries,
+            do_notation: None,
+            span,
+        });
+
+        self.std_call(MAP_FROM_LIST, vec![entries], span)
+    }
+
+    /// Resolves a set literal, like `#{1, 2}`.
+    ///
+    /// It's a syntax sugar for the `Set.fromList` function of the std library, so it's lowered
+    /// into the call `Set.fromList [1, 2]`, like the map literals.
+    pub fn set_expr(&mut self, tree: sol_syntax::SetExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+
+        let items = tree
+            .items(&mut tree.walk())
+            .map(|item| item.solve(self, |this, node| this.expr(node, level)))
+            .collect::<Vec<_>>();
+
+        let items = Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Array,
+            arguments: items,
+            do_notation: None,
+            span,
+        });
+
+        self.std_call(SET_FROM_LIST, vec![items], span)
+    }
+
+    /// Calls the function of the std library, that is resolved like the operators, so it can
+    /// be defined by the std library, or by the user.
+    pub fn std_call(&mut self, name: &str, arguments: Vec<Expr>, span: SpanId) -> Expr {
+        let path = HirPath::create(self.db, name);
+        let definition = self.qualify(path, DefinitionKind::Function);
+        let reference = self.scope.using(self.db, definition, span);
+
+        Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Reference(reference),
+            arguments,
+            do_notation: None,
+            span,
+        })
+    }
+
+    /// Resolves a record literal, like `{ x = 1, y = 2 }`.
+    pub fn record_expr(&mut self, tree: sol_syntax::RecordExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+
+        let fields = tree
+            .fields(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .filter_map(|node| self.record_field(node, level))
+            .collect::<Vec<_>>();
+
+        Expr::Record(RecordExpr {
+            record: None,
+            fields,
+            span,
+        })
+    }
+
+    /// Resolves a functional update of a record, like `{ r | x = 3 }`.
+    pub fn record_update(&mut self, tree: sol_syntax::RecordUpdate, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+
+        let record = tree
+            .record()
+            .solve(self, |this, node| this.expr(node, level));
+
+        let fields = tree
+            .fields(&mut tree.walk())
+            .flatten()
+            .filter_map(|node| node.regular())
+            .filter_map(|node| self.record_field(node, level))
+            .collect::<Vec<_>>();
+
+        Expr::Record(RecordExpr {
+            record: Some(Box::new(record)),
+            fields,
+            span,
+        })
+    }
+
+    /// Resolves a field of a record expression, like `x = 1`. The name of the field is a label,
+    /// so it isn't resolved in the scope, and it can't be qualified.
+    fn record_field(
+        &mut self,
+        tree: sol_syntax::RecordField,
+        level: HirLevel,
+    ) -> Option<RecordField> {
+        let path = self.path(tree.name().ok()?);
+        let name = match path.segments(self.db).as_slice() {
+            [name] => *name,
+            _ => {
+                report_error(self.db, HirError {
+                    label: path.location(self.db),
+                    kind: HirErrorKind::QualifiedRecordField(path.to_string(self.db)?),
+                });
+
+                return None;
+            }
+        };
+
+        let value = tree
+            .value()
+            .solve(self, |this, node| this.expr(node, level));
+
+        Some(RecordField {
+            name,
+            value,
+            span: self.span_id(tree.range()),
         })
     }
 
@@ -447,7 +939,22 @@ impl HirLowering<'_, '_> {
     /// It does translate the syntax tuple expression
     /// into a high-level tuple expression.
     pub fn tuple_expr(&mut self, tree: sol_syntax::TupleExpr, level: HirLevel) -> Expr {
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
+
+        // The parentheses left open at the end of the line are closed by the parser, without a
+        // `)` node, so they're reported here, pointing to the `(`.
+        let node = *tree.node();
+        let is_closed = node
+            .children(&mut node.walk())
+            .any(|child| child.kind() == ")");
+        if !is_closed {
+            let start = node.start_byte();
+
+            report_error(self.db, HirError {
+                label: self.span(start, start + 1),
+                kind: HirErrorKind::UnclosedParenthesis,
+            });
+        }
 
         let arguments = tree
             .children(&mut tree.walk())
@@ -459,7 +966,45 @@ impl HirLowering<'_, '_> {
             callee: Callee::Tuple,
             arguments,
             do_notation: None,
-            location,
+            span,
+        })
+    }
+
+    /// Resolves a projection of a tuple, like `pair.0`, into a call to the projection of the
+    /// position.
+    pub fn projection_expr(&mut self, tree: sol_syntax::ProjectionExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+        let value = tree
+            .value()
+            .solve(self, |this, node| this.primary(node, level));
+        let text = tree
+            .position()
+            .ok()
+            .and_then(|position| position.utf8_text(self.txt.as_bytes()).ok())
+            .unwrap_or_default();
+        let Some(position) = text
+            .strip_prefix('.')
+            .and_then(|position| position.parse().ok())
+        else {
+            let label = match tree.position() {
+                Ok(position) => self.range(position.range()),
+                Err(_) => span.resolve(self.db),
+            };
+            let error = HirError {
+                label,
+                kind: HirErrorKind::InvalidTuplePosition(text.trim_start_matches('.').into()),
+            };
+            report_error(self.db, error.clone());
+
+            return Expr::Error(error);
+        };
+
+        Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Project(position),
+            arguments: vec![value],
+            do_notation: None,
+            span,
         })
     }
 
@@ -468,7 +1013,7 @@ impl HirLowering<'_, '_> {
     /// It does translate the syntax return expression
     /// into a high-level return expression.
     pub fn return_expr(&mut self, tree: sol_syntax::ReturnExpr, level: HirLevel) -> Expr {
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
         // Reports errors, because "return expression" is equivalent
         // to pure expression, and it's only allowed inside do notation.
@@ -476,7 +1021,7 @@ impl HirLowering<'_, '_> {
         // Or in other words, it's only allowed inside a do notation scope.
         if !self.scope.is_do_notation_scope() {
             report_error(self.db, HirError {
-                label: location.clone(),
+                label: span.resolve(self.db),
                 kind: HirErrorKind::ReturnOutsideDoNotation,
             })
         }
@@ -486,14 +1031,113 @@ impl HirLowering<'_, '_> {
         let value = tree
             .value()
             .map(|node| node.solve(self, |this, node| this.expr(node, level)))
-            .unwrap_or_else(|| Expr::call_unit_expr(location.clone()));
+            .unwrap_or_else(|| Expr::call_unit_expr(span));
 
         Expr::Call(CallExpr {
             kind: CallKind::Prefix,
             callee: Callee::Pure,
             arguments: vec![value],
             do_notation: None,
-            location,
+            span,
+        })
+    }
+
+    /// Resolves a rewrite expression, like `rewrite eq in value`.
+    ///
+    /// It does translate the syntax rewrite expression into a call to the rewrite primitive,
+    /// with the equality and the value as arguments.
+    pub fn rewrite_expr(&mut self, tree: sol_syntax::RewriteExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+        let equality = tree
+            .equality()
+            .solve(self, |this, node| this.expr(node, level));
+        let value = tree
+            .value()
+            .solve(self, |this, node| this.expr(node, level));
+
+        Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Rewrite,
+            arguments: vec![equality, value],
+            do_notation: None,
+            span,
+        })
+    }
+
+    /// Resolves a quote expression, like `quote { x + 1 }`.
+    ///
+    /// The quoted expression is resolved in the current scope, so the quoted names refer to the
+    /// definitions visible at the quote, wherever it's spliced.
+    pub fn quote_expr(&mut self, tree: sol_syntax::QuoteExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+        let value = tree
+            .value()
+            .solve(self, |this, node| this.expr(node, level));
+
+        Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Quote,
+            arguments: vec![value],
+            do_notation: None,
+            span,
+        })
+    }
+
+    /// Resolves a splice expression, like `splice { twice (quote { x }) }`.
+    pub fn splice_expr(&mut self, tree: sol_syntax::SpliceExpr, level: HirLevel) -> Expr {
+        let span = self.span_id(tree.range());
+        let value = tree
+            .value()
+            .solve(self, |this, node| this.expr(node, level));
+
+        Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Splice,
+            arguments: vec![value],
+            do_notation: None,
+            span,
+        })
+    }
+
+    /// Resolves a panic expression, like `panic "message"`, `todo` or `unreachable`, into a
+    /// call to the panic built-in, that has any type, as it never returns.
+    pub fn panic_expr(&mut self, tree: sol_syntax::PanicExpr) -> Expr {
+        let span = self.span_id(tree.range());
+        let keyword = tree
+            .node()
+            .child(0)
+            .map(|node| node.kind())
+            .unwrap_or_default();
+
+        match keyword {
+            "todo" => self.panic_call(Callee::Todo, "not yet implemented", span),
+            "unreachable" => self.panic_call(Callee::Panic, "entered unreachable code", span),
+            _ => {
+                let message = match tree.message() {
+                    Some(Ok(literal)) => match self.literal(literal) {
+                        Literal::String(message) => message,
+                        _ => literal
+                            .utf8_text(self.txt.as_bytes())
+                            .unwrap_or_default()
+                            .to_string(),
+                    },
+                    _ => "explicit panic".into(),
+                };
+
+                self.panic_call(Callee::Panic, &message, span)
+            }
+        }
+    }
+
+    /// Creates a call to the panic built-in with the message. The call keeps the [`SpanId`], so
+    /// the codegen resolves where the program panicked, instead of baking it into the message.
+    pub fn panic_call(&self, callee: Callee, message: &str, span: SpanId) -> Expr {
+        Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee,
+            arguments: vec![Literal::String(message.into()).upgrade_expr(span, self.db)],
+            do_notation: None,
+            span,
         })
     }
 
@@ -502,18 +1146,31 @@ impl HirLowering<'_, '_> {
     /// It does translate the syntax primary expression
     /// using the level supplied.
     pub fn primary(&mut self, tree: sol_syntax::Primary, level: HirLevel) -> Expr {
-        use sol_syntax::anon_unions::ArrayExpr_FreeVariable_IfExpr_Literal_MatchExpr_Path_ReturnExpr_TupleExpr_UniverseExpr::*;
+        use sol_syntax::anon_unions::ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr::*;
 
-        let location = self.range(tree.range());
+        let span = self.span_id(tree.range());
 
-        tree.child().solve(self, |this, node| match node {
+        let expr = tree.child().solve(self, |this, node| match node {
             // SECTION: primary
             ArrayExpr(array_expr) => this.array_expr(array_expr, level),
             IfExpr(if_expr) => this.if_expr(if_expr, level),
-            Literal(literal) => this.literal(literal).upgrade_expr(location, this.db),
+            LetExpr(let_expr) => this.let_expr(let_expr, level),
+            Literal(literal) => this.literal(literal).upgrade_expr(span, this.db),
+            MapExpr(map_expr) => this.map_expr(map_expr, level),
             MatchExpr(match_expr) => this.match_expr(match_expr, level),
+            PanicExpr(panic_expr) => this.panic_expr(panic_expr),
+            ProjectionExpr(projection_expr) => this.projection_expr(projection_expr, level),
+            QuoteExpr(quote_expr) => this.quote_expr(quote_expr, level),
+            RecordExpr(record_expr) => this.record_expr(record_expr, level),
+            RecordUpdate(record_update) => this.record_update(record_update, level),
             ReturnExpr(return_expr) => this.return_expr(return_expr, level),
+            RewriteExpr(rewrite_expr) => this.rewrite_expr(rewrite_expr, level),
+            SectionExpr(section_expr) => this.section_expr(section_expr, level),
+            SetExpr(set_expr) => this.set_expr(set_expr, level),
+            SpliceExpr(splice_expr) => this.splice_expr(splice_expr, level),
             TupleExpr(tuple_expr) => this.tuple_expr(tuple_expr, level),
+            MacroCall(macro_call) => this.macro_call(macro_call, level),
+            MacroVariable(macro_variable) => this.macro_variable(macro_variable),
 
             // SECTION: identifier
             // It will match agains't the identifier, and it will create a new [`Expr::Path`]
@@ -522,7 +1179,7 @@ impl HirLowering<'_, '_> {
             // It will search for the definition in the scope, and if it is not present in the
             // it will query the compiler.
             Path(identifier) => {
-                let location = this.range(identifier.range());
+                let span = this.span_id(identifier.range());
 
                 // Create a new path with the identifier, and search for the definition in the
                 // scope, and if it is not present in the scope, it will invoke a compiler query
@@ -533,9 +1190,12 @@ impl HirLowering<'_, '_> {
                     HirLevel::Expr => this.qualify(path, DefinitionKind::Function),
                     HirLevel::Type => this.qualify(path, DefinitionKind::Type),
                 };
+                if def.kind(this.db) == DefinitionKind::Constructor {
+                    this.check_ambiguous_constructor(path);
+                }
 
-                // Creates a new [`Reference`] from the [`Definition`] and the location.
-                let reference = this.scope.using(this.db, def, location);
+                // Creates a new [`Reference`] from the [`Definition`] and the span.
+                let reference = this.scope.using(this.db, def, span);
 
                 // Creates a new [`Expr`] with the [`Definition`] as the callee.
                 Expr::Path(reference)
@@ -545,7 +1205,7 @@ impl HirLowering<'_, '_> {
             //
             // TODO: add to a list of free-variables, to build the forall type
             FreeVariable(identifier) => {
-                let location = this.range(identifier.range());
+                let range = identifier.range();
 
                 // Create a new path with the identifier, and search for the definition in the
                 // scope, and if it is not present in the scope, it will invoke a compiler query
@@ -556,14 +1216,23 @@ impl HirLowering<'_, '_> {
                 let text = identifier
                     .utf8_text(this.src.source_text(this.db).as_bytes())
                     .unwrap_or_default();
-                let identifier =
-                    Identifier::symbol(this.db, &text[1..text.len()], location.clone());
-                let path = HirPath::new(this.db, location.clone(), vec![identifier]);
+                let identifier = this.identifier(&text[1..text.len()], true, range);
+                let path = HirPath::new(this.db, this.span_id(range), vec![identifier]);
 
                 // Creates a new [`Expr`] with the [`Definition`] as the callee.
                 Expr::Path(this.scope.insert_free_variable(this.db, path))
             }
-            UniverseExpr(_) => Expr::Type(Type::Universe, location),
-        })
+            UniverseExpr(_) => Expr::Type(Type::Universe, span),
+            ReflExpr(_) => Expr::Call(CallExpr {
+                kind: CallKind::Prefix,
+                callee: Callee::Refl,
+                arguments: vec![],
+                do_notation: None,
+                span,
+            }),
+        });
+
+        self.record(*tree.node(), HirNode::Expr(expr.clone()));
+        expr
     }
 }
@@ -0,0 +1,110 @@
+//! Defines a module for lowering the `with` abstractions of the clauses, that abstracts
+//! intermediate expressions into extra columns of pattern matching, just like in Agda:
+//!
+//! ```hs
+//! filter p (Cons x xs) with p x
+//! filter p (Cons x xs) | True  = Cons x (filter p xs)
+//! filter p (Cons x xs) | False = filter p xs
+//! ```
+//!
+//! The clause with the `with` values is lowered into a call to an auxiliary definition, like
+//! `filter.with`, that receives the arguments of the clause, and the abstracted values. The
+//! clauses with the `|` patterns are the clauses of the auxiliary definition.
+
+use sol_hir::source::pattern::{Constructor as PatternConstructor, ConstructorPattern};
+
+use super::*;
+
+impl HirLowering<'_, '_> {
+    /// Searches the auxiliary definition of the clauses, with the given depth of `with`
+    /// abstractions, or defines it if it is not defined yet. The depth of `f.with.with` is 2,
+    /// and the depth of `f` itself is 0.
+    pub fn with_definition(&mut self, path: HirPath, depth: usize, span: SpanId) -> Definition {
+        let keyword = Identifier::new(
+            self.db,
+            /* contents      = */ "with".into(),
+            /* refers_symbol = */ false,
+            /* span          = */ span,
+        );
+        let segments = path
+            .segments(self.db)
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(keyword).take(depth))
+            .collect();
+        let path = HirPath::new(self.db, path.span(self.db), segments);
+
+        self.scope
+            .search(self.db, path, DefinitionKind::Function)
+            .unwrap_or_else(|| {
+                self.scope
+                    .define(self.db, path, span, DefinitionKind::Function)
+            })
+    }
+
+    /// Creates the call to the auxiliary definition of a `with` clause, passing the arguments
+    /// of the clause, reconstructed from its patterns, and the abstracted values.
+    pub fn with_call(
+        &mut self,
+        auxiliary: Definition,
+        patterns: Vec<Pattern>,
+        values: Vec<Expr>,
+        span: SpanId,
+    ) -> Expr {
+        let reference = self.scope.using(self.db, auxiliary, span);
+        let arguments = patterns
+            .into_iter()
+            .map(|pattern| self.pattern_expr(pattern, span))
+            .chain(values)
+            .collect();
+
+        Expr::Call(CallExpr {
+            kind: CallKind::Prefix,
+            callee: Callee::Reference(reference),
+            arguments,
+            do_notation: None,
+            span,
+        })
+    }
+
+    /// Reconstructs the value matched by the pattern as an expression. The wildcards can't be
+    /// reconstructed, so they are holes to be inferred.
+    fn pattern_expr(&mut self, pattern: Pattern, span: SpanId) -> Expr {
+        match pattern {
+            Pattern::Hole => Expr::Hole(span),
+            Pattern::Wildcard(span) | Pattern::Rest(span) => Expr::Hole(span),
+            Pattern::Error(error) => Expr::Error(error),
+            Pattern::Literal(literal) => Expr::Literal(literal),
+            Pattern::Binding(binding) => {
+                Expr::Path(self.scope.using(self.db, binding.name, binding.span))
+            }
+            Pattern::Constructor(ConstructorPattern {
+                name,
+                arguments,
+                span,
+            }) => {
+                let callee = match name {
+                    PatternConstructor::Array => Callee::Array,
+                    PatternConstructor::Tuple => Callee::Tuple,
+                    PatternConstructor::Unit => Callee::Unit,
+                    PatternConstructor::Path(reference) if arguments.is_empty() => {
+                        return Expr::Path(reference);
+                    }
+                    PatternConstructor::Path(reference) => Callee::Reference(reference),
+                };
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| self.pattern_expr(argument, span))
+                    .collect();
+
+                Expr::Call(CallExpr {
+                    kind: CallKind::Prefix,
+                    callee,
+                    arguments,
+                    do_notation: None,
+                    span,
+                })
+            }
+        }
+    }
+}
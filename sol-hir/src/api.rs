@@ -0,0 +1,314 @@
+//! Defines the public API of the packages, that is the signatures of their exported
+//! definitions, like:
+//!
+//! ```sol
+//! public Maybe.unwrap : {a : U} -> Maybe a -> a
+//! Maybe.unwrap = |maybe| maybe (|value| value) Sorry
+//! ```
+//!
+//! The signatures are serialized without the locations, the documentation, and the bodies, so
+//! the API of two versions of a package can be compared, and the breaking changes, like the
+//! removed definitions and the changed types, can be reported.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{
+    fmt::HirFormatter,
+    package::{Package, Version},
+    solver::Definition,
+    source::{
+        declaration::{Parameter, Vis},
+        top_level::{Signature, TopLevel},
+        type_rep::TypeRep,
+        Spanned,
+    },
+};
+
+/// The version of the serialization of the API, it's increased when the signatures are
+/// rendered differently, as they can't be compared with the older ones.
+pub const API_FORMAT_VERSION: u32 = 1;
+
+/// The kind of an item of the API.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiItemKind {
+    Function,
+    Inductive,
+    Constructor,
+    TypeAlias,
+    Trait,
+    Method,
+}
+
+/// An exported definition, with its qualified name, and its signature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ApiItem {
+    pub name: String,
+    pub kind: ApiItemKind,
+    pub signature: String,
+}
+
+impl Display for ApiItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} {} {}", self.kind, self.name, self.signature)
+    }
+}
+
+/// The public API of a package, with the items sorted by their names.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct PackageApi {
+    pub format_version: u32,
+    pub package: String,
+
+    /// The version of the package, like `1.2.0`.
+    #[serde(default)]
+    pub version: String,
+    pub items: Vec<ApiItem>,
+}
+
+/// A change between the APIs of two versions of a package.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ApiChange {
+    Removed(ApiItem),
+    Changed { old: ApiItem, new: ApiItem },
+    Added(ApiItem),
+}
+
+impl ApiChange {
+    /// Checks if the change breaks the dependent packages, that is, if the item was removed, or
+    /// its signature was changed.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(self, ApiChange::Added(_))
+    }
+}
+
+impl Display for ApiChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiChange::Removed(item) => write!(f, "- {item}"),
+            ApiChange::Changed { old, new } => write!(f, "~ {old}\n  => {new}"),
+            ApiChange::Added(item) => write!(f, "+ {item}"),
+        }
+    }
+}
+
+/// The bump of the version of a package, by the semantic versioning, from the smallest to the
+/// largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VersionBump {
+    /// Only fixes, the API didn't change.
+    Patch,
+
+    /// Compatible changes, like new definitions.
+    Minor,
+
+    /// Breaking changes, like removed definitions, or changed types.
+    Major,
+}
+
+impl VersionBump {
+    /// Gets the bump from the `old` version to the `new` one, it returns `None` if the new
+    /// version isn't greater. The `0.x` versions are unstable, so, like in Cargo, their minor
+    /// bumps are major bumps, and their patch bumps are minor bumps.
+    pub fn between(old: Version, new: Version) -> Option<Self> {
+        let (Version(old_major, old_minor, _), Version(new_major, new_minor, _)) = (old, new);
+        if new <= old {
+            return None;
+        }
+
+        Some(match (old_major, new_major) {
+            (0, 0) if old_minor != new_minor => VersionBump::Major,
+            (0, 0) => VersionBump::Minor,
+            _ if old_major != new_major => VersionBump::Major,
+            _ if old_minor != new_minor => VersionBump::Minor,
+            _ => VersionBump::Patch,
+        })
+    }
+
+    /// Gets the smallest bump that allows the changes of the API.
+    pub fn required(changes: &[ApiChange]) -> Self {
+        if changes.iter().any(ApiChange::is_breaking) {
+            VersionBump::Major
+        } else if changes.is_empty() {
+            VersionBump::Patch
+        } else {
+            VersionBump::Minor
+        }
+    }
+}
+
+impl Display for VersionBump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionBump::Patch => write!(f, "patch"),
+            VersionBump::Minor => write!(f, "minor"),
+            VersionBump::Major => write!(f, "major"),
+        }
+    }
+}
+
+/// Defines the [`package_api`] query.
+///
+/// It collects the signatures of the definitions that are visible to the dependent packages,
+/// that are the public, or sealed, definitions exported by the manifest.
+#[salsa::tracked]
+pub fn package_api(db: &dyn crate::HirDb, package: Package) -> PackageApi {
+    let mut items = vec![];
+
+    for file in package.all_files(db) {
+        let hir = db.hir_lower(package, file);
+        let module = file.module_name(db);
+        let mut push = |definition: Definition, kind, signature| {
+            let name = format!("{module}.{}", definition.to_string(db));
+            if package.is_exported(db, &name) {
+                items.push(ApiItem {
+                    name,
+                    kind,
+                    signature,
+                });
+            }
+        };
+
+        for top_level in hir.contents(db) {
+            match top_level {
+                TopLevel::BindingGroup(group) => {
+                    let signature = group.signature(db);
+                    if is_visible(signature.visibility(db)) {
+                        push(
+                            signature.name(db),
+                            ApiItemKind::Function,
+                            render_signature(db, signature),
+                        );
+                    }
+                }
+                TopLevel::Inductive(inductive) if is_visible(inductive.visibility(db)) => {
+                    let parameters = inductive.parameters(db);
+                    let return_type = inductive.return_type(db);
+                    push(
+                        inductive.name(db),
+                        ApiItemKind::Inductive,
+                        render_type(db, &parameters, &return_type),
+                    );
+
+                    for constructor in inductive.variants(db) {
+                        push(
+                            constructor.name(db),
+                            ApiItemKind::Constructor,
+                            render(db, &constructor.return_type(db)),
+                        );
+                    }
+                }
+                TopLevel::TypeAlias(alias) if is_visible(alias.visibility(db)) => {
+                    let parameters = render_parameters(db, &alias.parameters(db));
+                    let value = render(db, &alias.value(db));
+                    push(
+                        alias.name(db),
+                        ApiItemKind::TypeAlias,
+                        format!("{parameters}= {value}"),
+                    );
+                }
+                TopLevel::Trait(trait_decl) if is_visible(trait_decl.visibility(db)) => {
+                    let superclasses = trait_decl
+                        .superclasses(db)
+                        .iter()
+                        .map(|superclass| render(db, superclass))
+                        .collect::<Vec<_>>();
+                    let mut signature = render_parameters(db, &trait_decl.parameters(db));
+                    if !superclasses.is_empty() {
+                        signature.push_str(&format!("requires {}", superclasses.join(", ")));
+                    }
+                    push(
+                        trait_decl.name(db),
+                        ApiItemKind::Trait,
+                        signature.trim_end().to_string(),
+                    );
+
+                    for method in trait_decl.methods(db) {
+                        push(
+                            method.name(db),
+                            ApiItemKind::Method,
+                            render_signature(db, method),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    items.sort_by(|a, b| a.name.cmp(&b.name).then(a.kind.cmp(&b.kind)));
+
+    PackageApi {
+        format_version: API_FORMAT_VERSION,
+        package: package.name(db).clone(),
+        version: format!("{:?}", package.version(db)),
+        items,
+    }
+}
+
+/// Compares the APIs of two versions of a package, the `old` one and the `new` one, returning
+/// the changes sorted by the names of the items, with the breaking changes first.
+pub fn diff(old: &PackageApi, new: &PackageApi) -> Vec<ApiChange> {
+    let by_name = |api: &PackageApi| {
+        api.items
+            .iter()
+            .map(|item| ((item.name.clone(), item.kind), item.clone()))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let old_items = by_name(old);
+    let mut new_items = by_name(new);
+
+    let mut changes = vec![];
+    for (key, old) in old_items {
+        match new_items.remove(&key) {
+            None => changes.push(ApiChange::Removed(old)),
+            Some(new) if new.signature != old.signature => {
+                changes.push(ApiChange::Changed { old, new })
+            }
+            Some(_) => {}
+        }
+    }
+    changes.extend(new_items.into_values().map(ApiChange::Added));
+    changes.sort_by_key(|change| !change.is_breaking());
+    changes
+}
+
+/// Checks if the visibility makes the definition visible to the dependent packages.
+fn is_visible(visibility: Spanned<Vis>) -> bool {
+    matches!(visibility.value, Vis::Public | Vis::Sealed)
+}
+
+/// Renders the HIR node without the locations, so moving a declaration isn't a change.
+fn render<T: HirFormatter>(db: &dyn crate::HirDb, node: &T) -> String {
+    use salsa::DebugWithDb;
+
+    format!("{:?}", node.formatter().debug_all(db))
+}
+
+/// Renders the parameters, with a trailing space if there's any. The implicit parameters are
+/// rendered within braces, like `{a : Type}`.
+fn render_parameters(db: &dyn crate::HirDb, parameters: &[Parameter]) -> String {
+    parameters
+        .iter()
+        .map(|parameter| match parameter.is_implicit(db) {
+            true => format!("{{{}}} ", render(db, parameter)),
+            false => format!("({}) ", render(db, parameter)),
+        })
+        .collect()
+}
+
+/// Renders the type of a definition, with its parameters, like `{a : U} (x : a) : a`.
+fn render_type(db: &dyn crate::HirDb, parameters: &[Parameter], return_type: &TypeRep) -> String {
+    format!(
+        "{}: {}",
+        render_parameters(db, parameters),
+        render(db, return_type)
+    )
+}
+
+/// Renders the type of a function, or of a trait method.
+fn render_signature(db: &dyn crate::HirDb, signature: Signature) -> String {
+    render_type(db, &signature.parameters(db), &signature.return_type(db))
+}
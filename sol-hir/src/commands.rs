@@ -0,0 +1,365 @@
+//! Defines the compile-time commands, that are the top level declarations like `#eval 1 + 2`.
+//! They are executed by a [`CommandRegistry`], that maps the names of the commands to their
+//! implementations, so the drivers can register their own commands.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use sol_diagnostic::{diagnostic, Diagnostic};
+
+use crate::{
+    derive::DeriveJsonCommand,
+    package::Package,
+    solver::Definition,
+    source::{
+        expr::Expr,
+        top_level::{CommandTopLevel, TopLevel},
+        HirElement, HirSource, Location,
+    },
+    HirDb,
+};
+
+/// The output of a command, it's reported as an advice diagnostic, so it can be shown in the
+/// editor, and in the command line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("{message}")]
+#[diagnostic(code(solc::command_output), severity(Advice))]
+pub struct CommandOutput {
+    pub message: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Command that is not registered in the [`CommandRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("unknown command `#{name}`")]
+#[diagnostic(code(solc::unknown_command), url(docsrs))]
+pub struct UnknownCommandError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Command that received the wrong number of arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the command `#{name}` expects at least one argument")]
+#[diagnostic(code(solc::missing_command_arguments), url(docsrs))]
+pub struct MissingCommandArgumentsError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Static assertion whose expression doesn't normalise to `true`, like `#static_assert 1 == 2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("static assertion failed: `{source_text}`")]
+#[diagnostic(code(solc::static_assertion_failed), url(docsrs))]
+pub struct StaticAssertionError {
+    pub source_text: String,
+
+    #[source_code]
+    #[label = "it doesn't normalise to `true`"]
+    pub location: Location,
+}
+
+/// Command argument that should be a definition, like `#print_type id`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("expected a definition")]
+#[diagnostic(code(solc::expected_definition), url(docsrs))]
+pub struct ExpectedDefinitionError {
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// A compile-time command, like `#eval`. The commands are registered in the [`CommandRegistry`]
+/// by their names, without the `#` prefix.
+///
+/// It's generic over the database, so the drivers can implement commands that needs more than
+/// the [`HirDb`], like the type checker.
+pub trait Command<DB>: Send + Sync {
+    /// The name of the command, without the `#` prefix.
+    fn name(&self) -> &str;
+
+    /// Executes the command, returning the outputs to be reported.
+    fn execute(
+        &self,
+        db: &DB,
+        source: HirSource,
+        command: CommandTopLevel,
+    ) -> sol_diagnostic::Result<Vec<CommandOutput>>;
+}
+
+/// Bridges the built-in commands with the evaluation and the type checking of the expressions.
+/// It's a trait, so we can have this separated from this crate.
+///
+/// This trait is implemented by the `sol-driver` crate.
+pub trait CommandEvaluator {
+    /// Normalises the expression, returning its normal form.
+    fn eval_expr(&self, pkg: Package, expr: Expr) -> sol_diagnostic::Result<String>;
+
+    /// Evaluates the boolean expression, returning if it normalises to `true`.
+    fn assert_expr(&self, pkg: Package, expr: Expr) -> sol_diagnostic::Result<bool>;
+
+    /// Infers the type of the expression, returning its normal form.
+    fn type_of_expr(&self, pkg: Package, expr: Expr) -> sol_diagnostic::Result<String>;
+
+    /// Gets the elaborated term and type of the definition, if it's a definition with a value,
+    /// like a function.
+    fn elaborate_definition(
+        &self,
+        source: HirSource,
+        definition: Definition,
+    ) -> sol_diagnostic::Result<Option<(String, String)>>;
+}
+
+/// The `#eval` command, that normalises each argument, and reports its normal form.
+pub struct EvalCommand;
+
+impl<DB: HirDb + CommandEvaluator> Command<DB> for EvalCommand {
+    fn name(&self) -> &str {
+        "eval"
+    }
+
+    fn execute(
+        &self,
+        db: &DB,
+        source: HirSource,
+        command: CommandTopLevel,
+    ) -> sol_diagnostic::Result<Vec<CommandOutput>> {
+        expect_arguments(db, Command::<DB>::name(self), command)?
+            .into_iter()
+            .map(|expr| {
+                Ok(CommandOutput {
+                    location: expr.location(db),
+                    message: db.eval_expr(source.package(db), expr)?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The `#check` command, that infers the type of each argument, and reports it.
+pub struct CheckCommand;
+
+impl<DB: HirDb + CommandEvaluator> Command<DB> for CheckCommand {
+    fn name(&self) -> &str {
+        "check"
+    }
+
+    fn execute(
+        &self,
+        db: &DB,
+        source: HirSource,
+        command: CommandTopLevel,
+    ) -> sol_diagnostic::Result<Vec<CommandOutput>> {
+        expect_arguments(db, Command::<DB>::name(self), command)?
+            .into_iter()
+            .map(|expr| {
+                Ok(CommandOutput {
+                    location: expr.location(db),
+                    message: format!(": {}", db.type_of_expr(source.package(db), expr)?),
+                })
+            })
+            .collect()
+    }
+}
+
+/// The `#static_assert` command, that evaluates each argument at compile time, failing if it
+/// doesn't normalise to `true`. It doesn't report anything when the assertions hold.
+pub struct StaticAssertCommand;
+
+impl<DB: HirDb + CommandEvaluator> Command<DB> for StaticAssertCommand {
+    fn name(&self) -> &str {
+        "static_assert"
+    }
+
+    fn execute(
+        &self,
+        db: &DB,
+        source: HirSource,
+        command: CommandTopLevel,
+    ) -> sol_diagnostic::Result<Vec<CommandOutput>> {
+        for expr in expect_arguments(db, Command::<DB>::name(self), command)? {
+            let location = expr.location(db);
+            if !db.assert_expr(source.package(db), expr)? {
+                let text = location.clone().text_source();
+                let source_text = text
+                    .data()
+                    .get(location.start().offset()..location.end().offset())
+                    .unwrap_or_default()
+                    .to_string();
+
+                return sol_diagnostic::fail(StaticAssertionError {
+                    source_text,
+                    location,
+                });
+            }
+        }
+
+        Ok(vec![])
+    }
+}
+
+/// The `#print_scope` command, that reports the definitions of the module scope, sorted by
+/// their names, to debug the name resolution.
+pub struct PrintScopeCommand;
+
+impl<DB: HirDb> Command<DB> for PrintScopeCommand {
+    fn name(&self) -> &str {
+        "print_scope"
+    }
+
+    fn execute(
+        &self,
+        db: &DB,
+        source: HirSource,
+        command: CommandTopLevel,
+    ) -> sol_diagnostic::Result<Vec<CommandOutput>> {
+        let mut definitions = source
+            .scope(db)
+            .all_definitions()
+            .into_iter()
+            .map(|(name, definition)| format!("{name} : {:?}", definition.kind(db)))
+            .collect::<Vec<_>>();
+        definitions.sort();
+
+        let message = definitions.join("\n");
+
+        Ok(vec![CommandOutput {
+            message,
+            location: command.location(db),
+        }])
+    }
+}
+
+/// The `#print_type` command, that reports the elaborated term and type of each definition
+/// given as argument, to debug the elaboration.
+pub struct PrintTypeCommand;
+
+impl<DB: HirDb + CommandEvaluator> Command<DB> for PrintTypeCommand {
+    fn name(&self) -> &str {
+        "print_type"
+    }
+
+    fn execute(
+        &self,
+        db: &DB,
+        source: HirSource,
+        command: CommandTopLevel,
+    ) -> sol_diagnostic::Result<Vec<CommandOutput>> {
+        expect_arguments(db, Command::<DB>::name(self), command)?
+            .into_iter()
+            .map(|expr| {
+                let location = expr.location(db);
+                let Expr::Path(reference) = expr else {
+                    return sol_diagnostic::fail(ExpectedDefinitionError { location });
+                };
+
+                let definition = reference.definition(db);
+                let message = match db.elaborate_definition(source, definition)? {
+                    Some((term, type_repr)) => {
+                        format!("{} : {type_repr} = {term}", definition.to_string(db))
+                    }
+                    None => format!("{} has no elaborated term", definition.to_string(db)),
+                };
+
+                Ok(CommandOutput { message, location })
+            })
+            .collect()
+    }
+}
+
+/// Gets the arguments of the command, failing if there's no arguments.
+pub(crate) fn expect_arguments<DB: HirDb>(
+    db: &DB,
+    name: &str,
+    command: CommandTopLevel,
+) -> sol_diagnostic::Result<Vec<Expr>> {
+    let arguments = command.arguments(db);
+    if arguments.is_empty() {
+        return sol_diagnostic::fail(MissingCommandArgumentsError {
+            name: name.into(),
+            location: command.location(db),
+        });
+    }
+
+    Ok(arguments)
+}
+
+/// The registry of the compile-time commands, that maps the names of the commands to their
+/// implementations.
+///
+/// The registry can be shared, and the commands can be registered after its creation, so the
+/// drivers can extend the built-in commands.
+pub struct CommandRegistry<DB> {
+    commands: DashMap<String, Arc<dyn Command<DB>>>,
+}
+
+impl<DB: HirDb + CommandEvaluator> Default for CommandRegistry<DB> {
+    /// Creates a registry with the built-in commands, that are `#eval`, `#check`,
+    /// `#static_assert`, `#print_scope`, `#print_type` and `#derive_json`.
+    fn default() -> Self {
+        let registry = Self::empty();
+        registry.register(EvalCommand);
+        registry.register(CheckCommand);
+        registry.register(StaticAssertCommand);
+        registry.register(PrintScopeCommand);
+        registry.register(PrintTypeCommand);
+        registry.register(DeriveJsonCommand);
+        registry
+    }
+}
+
+impl<DB: HirDb> CommandRegistry<DB> {
+    /// Creates a registry without any command.
+    pub fn empty() -> Self {
+        Self {
+            commands: DashMap::new(),
+        }
+    }
+
+    /// Registers a command, replacing the command with the same name, if any.
+    pub fn register<C: Command<DB> + 'static>(&self, command: C) {
+        self.commands
+            .insert(command.name().to_string(), Arc::new(command));
+    }
+
+    /// Gets the command with the given name, without the `#` prefix.
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Command<DB>>> {
+        self.commands.get(name).map(|command| command.clone())
+    }
+
+    /// Executes all the commands of the source file, in the order they are declared, returning
+    /// the outputs and the errors of the commands as diagnostics.
+    pub fn execute(&self, db: &DB, source: HirSource) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        for top_level in source.contents(db).iter() {
+            let TopLevel::Command(command) = top_level else {
+                continue;
+            };
+
+            let name = command.path(db).to_string(db);
+            let Some(implementation) = self.get(&name) else {
+                diagnostics.push(diagnostic(UnknownCommandError {
+                    name,
+                    location: command.location(db),
+                }));
+                continue;
+            };
+
+            match implementation.execute(db, source, *command) {
+                Ok(outputs) => diagnostics.extend(outputs.into_iter().map(diagnostic)),
+                Err(error) => diagnostics.push(error),
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -20,18 +20,39 @@ pub struct HirCompletion {
     pub location: Location,
     pub docs: Option<Vec<DocString>>,
     pub visibility: Vis,
+
+    /// If the result type of the definition unifies with the expected type at the cursor.
+    pub matches_expected: bool,
+
+    /// The snippet that inserts the definition with placeholders for its required arguments,
+    /// like `f ${1:x}`, if it matches the expected type.
+    pub snippet: Option<String>,
+}
+
+/// The expected type at the cursor, computed by the elaborator from the goal of the hole at
+/// the cursor. The HIR doesn't know the types, so the elaborator gives the definitions whose
+/// result types unify with the goal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExpectedType {
+    /// The definitions whose result types unify with the expected type, with the names of the
+    /// explicit parameters that should be applied to them.
+    pub candidates: Vec<(Definition, Vec<String>)>,
 }
 
 /// Defines the [`completion`] query.
 ///
 /// Takes the path to the cursor. It's used to search the name completions, and the
 /// location of the cursor. It's used to search for the scope completions.
+///
+/// The completions whose result types unify with the expected type, if any, are ranked first,
+/// and the other ones are sorted by their names.
 #[salsa::tracked]
 pub fn completions(
     db: &dyn crate::HirDb,
     file: HirSource,
     path: String,
     position: SourceOffset,
+    expected: Option<ExpectedType>,
 ) -> Vec<HirCompletion> {
     // TODO: filter by path
     let _ = path;
@@ -41,7 +62,7 @@ pub fn completions(
 
     ReferenceWalker::<()>::empty()
         .enter_scope(move |db, location, scope| {
-            let is_in_start_bounds = location.end().offset() < position.offset();
+            let is_in_start_bounds = location.start().offset() < position.offset();
             let is_in_end_bounds = location.end().offset() > position.offset();
             if is_in_start_bounds && is_in_end_bounds {
                 scope
@@ -54,6 +75,8 @@ pub fn completions(
                         location: location.clone(),
                         docs: None,
                         visibility: Vis::Public, // TODO: Get from db
+                        matches_expected: false,
+                        snippet: None,
                     })
                     .for_each(|completion| {
                         completions.insert(completion);
@@ -63,5 +86,35 @@ pub fn completions(
         .build(db)
         .collect(file);
 
-    result.iter().map(|completion| completion.clone()).collect()
+    let candidates = expected
+        .map(|expected| expected.candidates)
+        .unwrap_or_default();
+    let mut result = result
+        .iter()
+        .map(|completion| {
+            let mut completion = completion.clone();
+            let parameters = candidates
+                .iter()
+                .find(|(definition, _)| definition.id(db) == completion.definition.id(db));
+            if let Some((_, parameters)) = parameters {
+                completion.matches_expected = true;
+                completion.snippet = Some(snippet(&completion.name, parameters));
+            }
+            completion
+        })
+        .collect::<Vec<_>>();
+    result.sort_by(|a, b| (!a.matches_expected, &a.name).cmp(&(!b.matches_expected, &b.name)));
+
+    result
+}
+
+/// Creates the snippet that applies the definition to placeholders of its parameters, like
+/// `f ${1:x} ${2:y}`.
+fn snippet(name: &str, parameters: &[String]) -> String {
+    let mut snippet = name.to_string();
+    for (index, parameter) in parameters.iter().enumerate() {
+        snippet.push_str(&format!(" ${{{}:{parameter}}}", index + 1));
+    }
+
+    snippet
 }
@@ -0,0 +1,187 @@
+//! Defines the `#derive_json` command, that generates the JSON encoder and decoder of an
+//! inductive type, like in:
+//!
+//! ```sol
+//! inductive List (a : U) {
+//!   Nil,
+//!   Cons a (List a),
+//! }
+//!
+//! #derive_json List
+//! ```
+//!
+//! The constructors are encoded as JSON objects tagged by their names, with their fields in
+//! order. The fields of the inductive type itself are encoded by the derived functions, and
+//! the other fields by the `Std.Json` functions.
+//!
+//! The commands run after the lowering, so the functions are generated as source code, that
+//! is reported by the command, and inserted by the editors.
+
+use crate::{
+    commands::{expect_arguments, Command, CommandOutput},
+    solver::Definition,
+    source::{
+        expr::{Callee, Expr, Type},
+        top_level::{inductive_declaration, CommandTopLevel, Inductive},
+        HirElement, HirSource, Location,
+    },
+    HirDb,
+};
+
+/// Command argument that should be an inductive type, like `#derive_json List`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("expected an inductive type")]
+#[diagnostic(code(solc::expected_inductive), url(docsrs))]
+pub struct ExpectedInductiveError {
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// A field of a constructor, it's recursive if its type is the inductive type itself.
+struct Field {
+    name: String,
+    recursive: bool,
+}
+
+/// Defines the [`derive_json`] query.
+///
+/// Generates the source code of the `to_json` and `from_json` functions of the inductive
+/// type, qualified by its name, like `List.to_json` and `List.from_json`.
+#[salsa::tracked]
+pub fn derive_json(db: &dyn crate::HirDb, inductive: Inductive) -> String {
+    let name = inductive.name(db).to_string(db);
+    let constructors = inductive
+        .variants(db)
+        .into_iter()
+        .map(|constructor| {
+            let path = constructor.name(db).to_string(db);
+            let tag = path.rsplit('.').next().unwrap_or_default().to_string();
+            let fields =
+                constructor_fields(db, inductive.name(db), constructor.return_type(db).expr);
+
+            (tag, fields)
+        })
+        .collect::<Vec<_>>();
+
+    let mut encoder = format!("{name}.to_json (value : {name}) : Std.Json.Value =\n");
+    encoder.push_str("  match value {\n");
+    for (tag, fields) in &constructors {
+        let pattern = pattern(tag, fields);
+        let values = fields
+            .iter()
+            .map(|field| match field.recursive {
+                true => format!("{name}.to_json {}", field.name),
+                false => format!("Std.Json.encode {}", field.name),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        encoder.push_str(&format!(
+            "    {pattern} => Std.Json.tagged \"{tag}\" [{values}]\n"
+        ));
+    }
+    encoder.push_str("  }\n");
+
+    let mut decoder = format!("{name}.from_json (json : Std.Json.Value) : Std.Maybe {name} =\n");
+    decoder.push_str("  match Std.Json.tag json {\n");
+    for (tag, fields) in &constructors {
+        if fields.is_empty() {
+            decoder.push_str(&format!("    \"{tag}\" => pure {tag}\n"));
+            continue;
+        }
+
+        decoder.push_str(&format!("    \"{tag}\" => do {{\n"));
+        for (index, field) in fields.iter().enumerate() {
+            let decode = match field.recursive {
+                true => format!("{name}.from_json"),
+                false => "Std.Json.decode".into(),
+            };
+            decoder.push_str(&format!(
+                "      {} <- {decode} (Std.Json.field json {index})\n",
+                field.name
+            ));
+        }
+        decoder.push_str(&format!("      pure ({})\n", pattern(tag, fields)));
+        decoder.push_str("    }\n");
+    }
+    decoder.push_str("    _ => Std.Maybe.Nothing\n");
+    decoder.push_str("  }\n");
+
+    format!("{encoder}\n{decoder}")
+}
+
+/// Gets the explicit fields of the constructor, from its type, like `a` and `List a` in
+/// `Cons a (List a)`. They're named by their positions, like `x0` and `x1`.
+fn constructor_fields(db: &dyn crate::HirDb, inductive: Definition, expr: Box<Expr>) -> Vec<Field> {
+    let Expr::Pi(pi) = *expr else {
+        return vec![];
+    };
+
+    pi.parameters
+        .into_iter()
+        .filter(|parameter| !parameter.is_implicit)
+        .enumerate()
+        .map(|(index, parameter)| Field {
+            name: format!("x{index}"),
+            recursive: is_recursive(db, inductive, *parameter.parameter_type.expr),
+        })
+        .collect()
+}
+
+/// Checks if the type of the field is the inductive type, applied or not to its parameters.
+fn is_recursive(db: &dyn crate::HirDb, inductive: Definition, expr: Expr) -> bool {
+    match expr {
+        Expr::Type(Type::This, _) => true,
+        Expr::Path(reference) => reference.definition(db).id(db) == inductive.id(db),
+        Expr::Call(call) => match call.callee {
+            Callee::Reference(reference) => reference.definition(db).id(db) == inductive.id(db),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Creates the pattern of the constructor, like `Cons x0 x1`.
+fn pattern(tag: &str, fields: &[Field]) -> String {
+    std::iter::once(tag.to_string())
+        .chain(fields.iter().map(|field| field.name.clone()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The `#derive_json` command, that reports the JSON encoder and decoder of each inductive
+/// type given as argument.
+pub struct DeriveJsonCommand;
+
+impl<DB: HirDb> Command<DB> for DeriveJsonCommand {
+    fn name(&self) -> &str {
+        "derive_json"
+    }
+
+    fn execute(
+        &self,
+        db: &DB,
+        _source: HirSource,
+        command: CommandTopLevel,
+    ) -> sol_diagnostic::Result<Vec<CommandOutput>> {
+        expect_arguments(db, Command::<DB>::name(self), command)?
+            .into_iter()
+            .map(|expr| {
+                let location = expr.location(db);
+                let Expr::Path(reference) = expr else {
+                    return sol_diagnostic::fail(ExpectedInductiveError { location });
+                };
+
+                let Some(inductive) = inductive_declaration(db, reference.definition(db)) else {
+                    return sol_diagnostic::fail(ExpectedInductiveError { location });
+                };
+
+                Ok(CommandOutput {
+                    message: derive_json(db, inductive),
+                    location,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,179 @@
+//! Defines the entailment of the trait constraints. The superclasses of a trait are required
+//! by every instance of it, so an instance of `Ord Int` entails an instance of `Eq Int`, if
+//! `Ord` requires `Eq`.
+//!
+//! The instances are matched by the heads of their type arguments, like `Int` in `Eq Int`, or
+//! `List` in `Eq (List a)`, as the instances can't overlap.
+
+use std::collections::HashSet;
+
+use fxhash::FxHashMap;
+use salsa::DebugWithDb;
+use sol_diagnostic::report_error;
+
+use crate::{
+    errors::UnsatisfiedConstraintError,
+    fmt::HirFormatter,
+    solver::{instances, Definition, DefinitionId},
+    source::{
+        expr::{Callee, Expr, Type},
+        pattern::Pattern,
+        top_level::{trait_declaration, Instance},
+        type_rep::TypeRep,
+        HirElement,
+    },
+};
+
+/// The head of a type argument, that identifies the instances of a trait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Head {
+    Definition(DefinitionId),
+    Primitive(Type),
+}
+
+impl Head {
+    /// Gets the head of the type representation, if it's a path, a primitive type, or an
+    /// application of them.
+    pub fn of(db: &dyn crate::HirDb, type_rep: &TypeRep) -> Option<Self> {
+        fn head(db: &dyn crate::HirDb, expr: &Expr) -> Option<Head> {
+            match expr {
+                Expr::Type(primitive, _) => Some(Head::Primitive(*primitive)),
+                Expr::Path(reference) => Some(Head::Definition(reference.definition(db).id(db))),
+                Expr::Call(call) => match &call.callee {
+                    Callee::Reference(reference) => {
+                        Some(Head::Definition(reference.definition(db).id(db)))
+                    }
+                    Callee::Expr(callee) => head(db, callee),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+
+        head(db, &type_rep.expr)
+    }
+}
+
+/// A type argument of a constraint, with its head, and its description to be shown in the
+/// diagnostics.
+type Argument = (Option<Head>, String);
+
+/// A constraint that is required to hold, with the chain of the constraints that required
+/// it, from the instance being checked.
+struct Wanted {
+    definition: Definition,
+    arguments: Vec<Argument>,
+    chain: Vec<String>,
+}
+
+/// Checks if there's an instance of the trait for the given type arguments. The arguments
+/// without a known head, like type variables, matches any instance.
+pub fn has_instance(db: &dyn crate::HirDb, definition: Definition, heads: &[Option<Head>]) -> bool {
+    instances(db, definition).into_iter().any(|instance| {
+        let arguments = instance.arguments(db);
+
+        arguments.len() == heads.len()
+            && arguments
+                .iter()
+                .zip(heads)
+                .all(|(argument, head)| head.is_none() || Head::of(db, argument) == *head)
+    })
+}
+
+/// Checks the superclasses of the trait of the instance, reporting the ones that aren't
+/// entailed by other instances, with the chain of constraints that required them.
+///
+/// The superclasses are checked transitively, so if `Ord` requires `Eq`, and `Eq` requires
+/// `PartialEq`, an instance of `Ord Int` requires an instance of `PartialEq Int`.
+pub fn check_superclasses(db: &dyn crate::HirDb, instance: Instance) {
+    let definition = instance.trait_reference(db).definition(db);
+    let arguments = instance
+        .arguments(db)
+        .iter()
+        .map(|argument| (Head::of(db, argument), describe(db, argument)))
+        .collect::<Vec<_>>();
+
+    let mut visited = HashSet::new();
+    let mut stack = vec![Wanted {
+        definition,
+        chain: vec![constraint(db, definition, &arguments)],
+        arguments,
+    }];
+
+    while let Some(wanted) = stack.pop() {
+        let heads = wanted
+            .arguments
+            .iter()
+            .map(|(head, _)| *head)
+            .collect::<Vec<_>>();
+        if !visited.insert((wanted.definition.id(db), heads)) {
+            continue;
+        }
+
+        let Some(declaration) = trait_declaration(db, wanted.definition) else {
+            continue;
+        };
+
+        // Maps the parameters of the trait to the arguments, so the arguments of the
+        // superclasses can be computed.
+        let substitution = declaration
+            .parameters(db)
+            .into_iter()
+            .zip(wanted.arguments.iter().cloned())
+            .filter_map(|(parameter, argument)| match parameter.binding(db) {
+                Pattern::Binding(binding) => Some((binding.name.id(db), argument)),
+                _ => None,
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        for superclass in declaration.superclasses(db) {
+            let definition = superclass.trait_reference(db).definition(db);
+            let arguments = superclass
+                .arguments(db)
+                .iter()
+                .map(|argument| match Head::of(db, argument) {
+                    Some(Head::Definition(id)) if substitution.contains_key(&id) => {
+                        substitution[&id].clone()
+                    }
+                    head => (head, describe(db, argument)),
+                })
+                .collect::<Vec<_>>();
+
+            let mut chain = wanted.chain.clone();
+            chain.push(constraint(db, definition, &arguments));
+
+            let heads = arguments.iter().map(|(head, _)| *head).collect::<Vec<_>>();
+            if !has_instance(db, definition, &heads) {
+                report_error(db, UnsatisfiedConstraintError {
+                    name: chain[0].clone(),
+                    required: chain[chain.len() - 1].clone(),
+                    chain: chain.join(" => "),
+                    location: instance.location(db),
+                });
+                continue;
+            }
+
+            stack.push(Wanted {
+                definition,
+                arguments,
+                chain,
+            });
+        }
+    }
+}
+
+/// Describes a constraint, like `Eq Int`, to be shown in the diagnostics.
+fn constraint(db: &dyn crate::HirDb, definition: Definition, arguments: &[Argument]) -> String {
+    let mut description = definition.name(db).to_string(db).unwrap_or_default();
+    for (_, argument) in arguments {
+        description.push(' ');
+        description.push_str(argument);
+    }
+
+    description
+}
+
+/// Describes a type argument, to be shown in the diagnostics.
+fn describe(db: &dyn crate::HirDb, argument: &TypeRep) -> String {
+    format!("{:?}", argument.formatter().debug(db))
+}
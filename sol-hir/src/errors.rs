@@ -29,6 +29,152 @@ pub enum HirErrorKind {
     #[error("return outside do notation")]
     #[diagnostic(code(solc::hir_return_outside_do_notation), url(docsrs))]
     ReturnOutsideDoNotation,
+
+    /// Prefixed numeric literal without digits.
+    ///
+    /// ```
+    /// 0x
+    /// ```
+    ///
+    /// And the `0x` prefix should be followed by hexadecimal digits.
+    #[error("missing digits after the `{0}` prefix")]
+    #[diagnostic(code(solc::hir_missing_digits), url(docsrs))]
+    MissingDigits(String),
+
+    /// Numeric literal that doesn't fit in its type.
+    #[error("literal out of range for `{0}`")]
+    #[diagnostic(code(solc::hir_literal_out_of_range), url(docsrs))]
+    LiteralOutOfRange(String),
+
+    /// Known attribute with arguments that doesn't match its shape.
+    ///
+    /// ```
+    /// @inline(always)
+    /// ```
+    ///
+    /// And `inline` doesn't take arguments.
+    #[error("invalid arguments for attribute `{0}`, expected {1}")]
+    #[diagnostic(code(solc::hir_invalid_attribute_arguments), url(docsrs))]
+    InvalidAttributeArguments(String, String),
+
+    /// Key-value attribute argument whose key isn't a single identifier.
+    ///
+    /// ```
+    /// @deprecated(Std.note = "use bar")
+    /// ```
+    #[error("the key of the attribute argument should be an identifier")]
+    #[diagnostic(code(solc::hir_invalid_attribute_key), url(docsrs))]
+    InvalidAttributeKey,
+
+    /// Invalid escape sequence in a string literal.
+    ///
+    /// ```
+    /// "\q"
+    /// ```
+    ///
+    /// And `\q` isn't a known escape sequence.
+    #[error("invalid escape sequence `{0}`")]
+    #[diagnostic(code(solc::hir_invalid_escape), url(docsrs))]
+    InvalidEscape(String),
+
+    /// Literal of a type that isn't supported yet.
+    ///
+    /// ```
+    /// 'a'
+    /// ```
+    ///
+    /// And `char` literals aren't supported yet.
+    #[error("`{0}` literals aren't supported yet")]
+    #[diagnostic(code(solc::hir_unsupported_literal), url(docsrs))]
+    UnsupportedLiteral(String),
+
+    /// Record field with a qualified name, the names of the fields are labels.
+    ///
+    /// ```
+    /// { Point.x = 1, y = 2 }
+    /// ```
+    #[error("the field `{0}` of the record can't be qualified")]
+    #[diagnostic(code(solc::hir_qualified_record_field), url(docsrs))]
+    QualifiedRecordField(String),
+
+    /// Projection of a tuple, whose position isn't a number that fits in the positions.
+    ///
+    /// ```
+    /// pair.99999999999999999999
+    /// ```
+    #[error("invalid tuple position `{0}`")]
+    #[diagnostic(code(solc::hir_invalid_tuple_position), url(docsrs))]
+    InvalidTuplePosition(String),
+
+    /// Call of a macro that isn't declared in the file.
+    ///
+    /// ```
+    /// unless!(x, y)
+    /// ```
+    ///
+    /// And there's no `macro unless { ... }` declaration.
+    #[error("could not find macro: {0}")]
+    #[diagnostic(code(solc::hir_unresolved_macro), url(docsrs))]
+    UnresolvedMacro(String),
+
+    /// Macro variable outside of the body of a macro rule, or that isn't bound by its patterns.
+    ///
+    /// ```
+    /// macro twice { ($x) => $y + $y }
+    /// ```
+    #[error("unbound macro variable: {0}")]
+    #[diagnostic(code(solc::hir_unbound_macro_variable), url(docsrs))]
+    UnboundMacroVariable(String),
+
+    /// If let statement without an else branch, whose pattern can fail to match.
+    ///
+    /// ```
+    /// if let Just x = y then println x
+    /// ```
+    #[error("if let without else needs an irrefutable pattern")]
+    #[diagnostic(code(solc::hir_refutable_if_let), url(docsrs))]
+    RefutableIfLet,
+
+    /// Pi type with named parameters, but without the arrow, that is recovered by the parser.
+    ///
+    /// ```
+    /// Vec : (n : Nat) U
+    /// ```
+    #[error("expected `->` after the parameters of the pi type")]
+    #[diagnostic(code(solc::hir_missing_pi_arrow), url(docsrs))]
+    MissingPiArrow,
+
+    /// Parenthesis left open at the end of the line, that is closed by the parser, so the next
+    /// declarations are still parsed.
+    ///
+    /// ```
+    /// main = println (show x
+    /// ```
+    #[error("unclosed parenthesis")]
+    #[diagnostic(
+        code(solc::hir_unclosed_parenthesis),
+        url(docsrs),
+        help("add the `)` before the end of the line")
+    )]
+    UnclosedParenthesis,
+
+    /// Include of a file that couldn't be read, or that includes the file that includes it.
+    ///
+    /// ```
+    /// #include_source "generated/Missing.sol"
+    /// ```
+    #[error("could not include the source `{0}`, it doesn't exist, or the includes are cyclic")]
+    #[diagnostic(code(solc::hir_unresolved_include), url(docsrs))]
+    UnresolvedInclude(String),
+
+    /// Include without the path of the file, as a string literal.
+    ///
+    /// ```
+    /// #include_source Generated
+    /// ```
+    #[error("expected the path of the file to include, like `#include_source \"Generated.sol\"`")]
+    #[diagnostic(code(solc::hir_invalid_include), url(docsrs))]
+    InvalidInclude,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
@@ -43,3 +189,202 @@ pub struct HirError {
     #[diagnostic_source]
     pub kind: HirErrorKind,
 }
+
+/// Top level definition that is defined more than once in the same module, like two signatures
+/// or two inductive types with the same name. The clauses of a function are grouped, so they
+/// aren't duplicates.
+///
+/// ```
+/// f : Int -> Int
+/// f : String -> String
+/// ```
+///
+/// The constructors are namespaced by their inductive types, so two constructors with the same
+/// name only conflicts if they are in the same inductive type.
+///
+/// ```
+/// inductive Bool { True, False, True }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("`{name}` is defined multiple times")]
+#[diagnostic(code(solc::hir_duplicate_definition), url(docsrs))]
+pub struct DuplicateDefinitionError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "redefined here"]
+    pub location: Location,
+
+    #[label = "previous definition here"]
+    pub previous: Location,
+}
+
+/// Unqualified constructor name, that matches constructors of multiple inductive types. It
+/// should be qualified by the inductive type, like `List.Cons`.
+///
+/// ```
+/// inductive List { Cons (a, List a), Nil }
+/// inductive Stream { Cons (a, Stream a) }
+///
+/// head (Cons x _) = x
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the constructor `{name}` is ambiguous")]
+#[diagnostic(
+    code(solc::hir_ambiguous_constructor),
+    url(docsrs),
+    help("qualify it with one of the candidates: {candidates}")
+)]
+pub struct AmbiguousConstructorError {
+    pub name: String,
+    pub candidates: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Occurrence of an inductive type at the left of an arrow in one of its constructors, or in
+/// the constructors of the other types of its mutual block.
+///
+/// ```
+/// inductive Bad { MkBad (Bad -> Int) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the type `{name}` occurs non-positively in the constructor `{constructor}`")]
+#[diagnostic(
+    code(solc::hir_non_positive_occurrence),
+    url(docsrs),
+    help("the inductive types can't occur at the left of an arrow in their constructors")
+)]
+pub struct NonPositiveOccurrenceError {
+    pub name: String,
+    pub constructor: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Constructor of an inductive family, that doesn't construct the family applied to all of
+/// its parameters and indices. The constructors of the indexed families should have their
+/// signatures, as the indices can't be inferred.
+///
+/// ```
+/// inductive Vec (a : Type) : Nat -> Type {
+///   Nil : Vec a
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the constructor `{constructor}` should construct `{inductive}` with {arity} arguments")]
+#[diagnostic(
+    code(solc::hir_invalid_constructor_type),
+    url(docsrs),
+    help("give the parameters and the indices of `{inductive}` in the constructor signature")
+)]
+pub struct InvalidConstructorTypeError {
+    pub constructor: String,
+    pub inductive: String,
+    pub arity: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Pattern of a constructor, whose indices can't unify with the indices of the matched type,
+/// so the arm can never be reached.
+///
+/// ```
+/// head : Vec a Zero -> a
+/// head (Cons x _) = x
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the constructor `{constructor}` can't match a value of type `{expected}`")]
+#[diagnostic(
+    code(solc::hir_impossible_pattern),
+    url(docsrs),
+    help("the indices `{found}` of the constructor don't unify with the matched type")
+)]
+pub struct ImpossiblePatternError {
+    pub constructor: String,
+    pub expected: String,
+    pub found: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Instance of a trait that requires a superclass constraint, that isn't satisfied by any
+/// other instance. The chain shows the constraints that required the missing one.
+///
+/// ```
+/// trait Ord (a : Type) requires Eq a { ... }
+/// instance Ord Int { ... }
+/// ```
+///
+/// And there's no instance of `Eq Int`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("missing instance of `{required}`, required by `{name}`")]
+#[diagnostic(
+    code(solc::hir_unsatisfied_constraint),
+    url(docsrs),
+    help("required by {chain}")
+)]
+pub struct UnsatisfiedConstraintError {
+    pub name: String,
+    pub required: String,
+    pub chain: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Call of a macro, whose arguments don't match the patterns of any of its rules.
+///
+/// ```
+/// macro unless { ($cond, $body) => if $cond then () else $body }
+///
+/// x = unless!(True)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("no rule of the macro `{name}` matches {arity} arguments")]
+#[diagnostic(code(solc::hir_no_matching_macro_rule), url(docsrs))]
+pub struct NoMatchingMacroRuleError {
+    pub name: String,
+    pub arity: usize,
+
+    #[source_code]
+    #[label = "called here"]
+    pub location: Location,
+
+    #[label = "macro defined here"]
+    pub definition: Location,
+}
+
+/// Call of a macro, whose expansion calls macros recursively more than the limit, like when a
+/// macro calls itself without a base rule.
+///
+/// ```
+/// macro loop { ($x) => loop!($x) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the expansion of the macro `{name}` exceeded the depth limit of {limit}")]
+#[diagnostic(
+    code(solc::hir_macro_expansion_depth),
+    url(docsrs),
+    help("the macro is probably calling itself without a base rule")
+)]
+pub struct MacroExpansionDepthError {
+    pub name: String,
+    pub limit: usize,
+
+    #[source_code]
+    #[label = "expanded from here"]
+    pub location: Location,
+
+    #[label = "macro defined here"]
+    pub definition: Location,
+}
@@ -244,6 +244,29 @@ mod impls {
         }
     }
 
+    /// A formatter for [`declaration::AttributeArgument`].
+    impl HirFormatter for declaration::AttributeArgument {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            use declaration::AttributeArgument::*;
+
+            match self {
+                Path(path) => path.hir_fmt(db, f, scope),
+                Literal(literal) => literal.value.hir_fmt(db, f, scope),
+                KeyValue(name, value) => {
+                    name.hir_fmt(db, f, scope)?;
+                    write!(f, " = ")?;
+                    value.hir_fmt(db, f, scope)
+                }
+                List(arguments, _) => {
+                    write!(f, "[")?;
+                    scope.punctuated(db, f, arguments.clone(), ",")?;
+                    write!(f, "]")
+                }
+                Expr(expr) => expr.hir_fmt(db, f, scope),
+            }
+        }
+    }
+
     /// A formatter for [`declaration::DocString`]. It does
     /// takes an attribute and format it as it would be written
     /// in a source file.
@@ -336,6 +359,60 @@ mod impls {
         }
     }
 
+    impl HirFormatter for top_level::TypeAlias {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            scope.write_indent(f)?;
+            write!(f, "type ")?;
+            self.name(db).hir_fmt(db, f, scope)?;
+            scope.punctuated(db, f, self.parameters(db), " ")?;
+            write!(f, " = ")?;
+            self.value(db).hir_fmt(db, f, scope)?;
+            write!(f, ";")
+        }
+    }
+
+    impl HirFormatter for declaration::Constraint {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            self.trait_reference(db).hir_fmt(db, f, scope)?;
+            for argument in self.arguments(db) {
+                write!(f, " ")?;
+                argument.hir_fmt(db, f, scope)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl HirFormatter for top_level::Trait {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            format_decl(self, "trait", db, scope, f, |db, f, scope| {
+                if !self.superclasses(db).is_empty() {
+                    write!(f, " requires ")?;
+                    scope.punctuated(db, f, self.superclasses(db), ",")?;
+                }
+                write!(f, " ")?;
+                code_block(db, scope, f, |db, f, scope| {
+                    scope.unlined(db, f, self.methods(db), ";")
+                })
+            })
+        }
+    }
+
+    impl HirFormatter for top_level::Instance {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            scope.write_indent(f)?;
+            write!(f, "instance ")?;
+            self.trait_reference(db).hir_fmt(db, f, scope)?;
+            for argument in self.arguments(db) {
+                write!(f, " ")?;
+                argument.hir_fmt(db, f, scope)?;
+            }
+            write!(f, " ")?;
+            code_block(db, scope, f, |db, f, scope| {
+                scope.unlined(db, f, self.methods(db), ";")
+            })
+        }
+    }
+
     /// A formatter for [`top_level::TopLevel`]. It does
     /// takes an attribute and format it as it would be written
     /// in a source file.
@@ -349,6 +426,9 @@ mod impls {
                 Command(command_top_level) => command_top_level.hir_fmt(db, f, scope),
                 BindingGroup(binding_group) => binding_group.hir_fmt(db, f, scope),
                 Inductive(inductive) => inductive.hir_fmt(db, f, scope),
+                TypeAlias(alias) => alias.hir_fmt(db, f, scope),
+                Trait(trait_decl) => trait_decl.hir_fmt(db, f, scope),
+                Instance(instance) => instance.hir_fmt(db, f, scope),
             }
         }
     }
@@ -491,7 +571,8 @@ mod impls {
                 UInt32(value) => write!(f, "{value}"),
                 Int64(value) => write!(f, "{value}"),
                 UInt64(value) => write!(f, "{value}"),
-                String(value) => write!(f, "\"{value}\""),
+                Nat(value) => write!(f, "{value}n"),
+                String(value) => write!(f, "{value:?}"),
                 Boolean(value) => write!(f, "{value}"),
                 Char(value) => write!(f, "'{value}'"),
             }
@@ -506,6 +587,13 @@ mod impls {
                 expr::Callee::Unit => write!(f, "()"),
                 expr::Callee::Pure => write!(f, "pure"),
                 expr::Callee::Do => write!(f, "do"),
+                expr::Callee::Refl => write!(f, "refl"),
+                expr::Callee::Rewrite => write!(f, "rewrite"),
+                expr::Callee::Quote => write!(f, "quote"),
+                expr::Callee::Splice => write!(f, "splice"),
+                expr::Callee::Panic => write!(f, "panic"),
+                expr::Callee::Todo => write!(f, "todo"),
+                expr::Callee::Project(position) => write!(f, ".{position}"),
                 expr::Callee::Reference(path) => path.hir_fmt(db, f, scope),
                 expr::Callee::Expr(expr) => expr.hir_fmt(db, f, scope),
             }
@@ -542,6 +630,36 @@ mod impls {
         }
     }
 
+    impl HirFormatter for expr::LetExpr {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            write!(f, "let ")?;
+            self.pattern.hir_fmt(db, f, scope)?;
+            write!(f, " = ")?;
+            self.value.hir_fmt(db, f, scope)?;
+            write!(f, " in ")?;
+            self.body.hir_fmt(db, f, scope)
+        }
+    }
+
+    impl HirFormatter for expr::RecordField {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            write!(f, "{} = ", self.name.contents(db))?;
+            self.value.hir_fmt(db, f, scope)
+        }
+    }
+
+    impl HirFormatter for expr::RecordExpr {
+        fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
+            write!(f, "{{ ")?;
+            if let Some(record) = &self.record {
+                record.hir_fmt(db, f, scope)?;
+                write!(f, " | ")?;
+            }
+            scope.punctuated(db, f, self.fields.clone(), ",")?;
+            write!(f, " }}")
+        }
+    }
+
     impl HirFormatter for expr::AnnExpr {
         fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
             self.value.hir_fmt(db, f, scope)?;
@@ -553,6 +671,10 @@ mod impls {
     impl HirFormatter for expr::MatchArm {
         fn hir_fmt(&self, db: &dyn HirDb, f: &mut Formatter, scope: &Scope) -> std::fmt::Result {
             self.pattern.hir_fmt(db, f, scope)?;
+            if let Some(guard) = &self.guard {
+                write!(f, " if ")?;
+                guard.hir_fmt(db, f, scope)?;
+            }
             write!(f, " => ")?;
             self.value.hir_fmt(db, f, scope)
         }
@@ -597,6 +719,8 @@ mod impls {
                 Call(call_expr) => call_expr.hir_fmt(db, f, scope),
                 Ann(ann_expr) => ann_expr.hir_fmt(db, f, scope),
                 Lam(abs_expr) => abs_expr.hir_fmt(db, f, scope),
+                Let(let_expr) => let_expr.hir_fmt(db, f, scope),
+                Record(record_expr) => record_expr.hir_fmt(db, f, scope),
                 Match(match_expr) => match_expr.hir_fmt(db, f, scope),
                 Type(type_ref, _) => type_ref.hir_fmt(db, f, scope),
                 Pi(pi) => pi.hir_fmt(db, f, scope),
@@ -0,0 +1,172 @@
+//! Defines the organization of the `using` top-levels of a file, that sorts them, merges the
+//! duplicated ones, removes the unused ones, and groups them by their origins, like in:
+//!
+//! ```sol
+//! using Std
+//!
+//! using Json
+//!
+//! using Utils
+//! ```
+//!
+//! The standard library imports come first, then the imports of the dependencies, and then
+//! the imports of the current package.
+
+use miette::SourceOffset;
+
+use crate::{
+    package::Package,
+    reference::ReferenceWalker,
+    solver::DefinitionKind,
+    source::{
+        top_level::{TopLevel, UsingTopLevel},
+        HirElement, HirSource, Location,
+    },
+};
+
+/// The name of the standard library module.
+const STD_MODULE: &str = "Std";
+
+/// A text edit of the organization of the imports.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportEdit {
+    pub location: Location,
+    pub new_text: String,
+}
+
+/// The groups of the imports, in the order they are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ImportGroup {
+    /// The imports of the standard library.
+    Std,
+
+    /// The imports of the modules of the other packages.
+    Dependency,
+
+    /// The imports of the modules of the current package.
+    Local,
+}
+
+/// Defines the [`unused_imports`] query.
+///
+/// Gets the `using` top-levels of the file whose modules aren't referenced by the file. The
+/// unresolved imports are kept, as their modules can't be checked.
+#[salsa::tracked]
+pub fn unused_imports(db: &dyn crate::HirDb, file: HirSource) -> Vec<UsingTopLevel> {
+    let references = ReferenceWalker::new(|_, _, _| true).build(db).collect(file);
+
+    usings(db, file)
+        .into_iter()
+        .filter(|using| {
+            let module = using.path(db).definition(db);
+            if module.kind(db) == DefinitionKind::Unresolved {
+                return false;
+            }
+
+            let module_file = module.location(db).source();
+            !references.iter().any(|reference| {
+                reference.definition(db).kind(db) != DefinitionKind::Module
+                    && reference.definition(db).location(db).source() == module_file
+            })
+        })
+        .collect()
+}
+
+/// Defines the [`organize_imports`] query.
+///
+/// Creates the text edits that organize the `using` top-levels of the file. The first import
+/// is replaced by the organized imports, and the other ones are removed, with their lines. It
+/// returns no edits if the imports are already organized.
+#[salsa::tracked]
+pub fn organize_imports(db: &dyn crate::HirDb, file: HirSource) -> Vec<ImportEdit> {
+    let usings = usings(db, file);
+    let Some(first) = usings.first() else {
+        return vec![];
+    };
+
+    let unused = unused_imports(db, file);
+    let mut imports = usings
+        .iter()
+        .filter(|using| !unused.contains(using))
+        .map(|using| {
+            let name = using.path(db).name(db).to_string(db).unwrap_or_default();
+            (import_group(db, file.package(db), *using, &name), name)
+        })
+        .collect::<Vec<_>>();
+    imports.sort();
+    imports.dedup();
+
+    let mut new_text = String::new();
+    for (index, (group, name)) in imports.iter().enumerate() {
+        if index > 0 && imports[index - 1].0 != *group {
+            new_text.push('\n');
+        }
+        new_text.push_str(&format!("using {name}\n"));
+    }
+
+    // The imports are organized if they are the only contents of their lines, from the first
+    // import until the last one.
+    let text = file.source(db).source_text(db);
+    let start = first.location(db).start().offset();
+    let end = line_location(text, usings[usings.len() - 1].location(db))
+        .end()
+        .offset();
+    if text[start..end] == new_text {
+        return vec![];
+    }
+
+    let mut edits = vec![ImportEdit {
+        location: line_location(text, first.location(db)),
+        new_text,
+    }];
+    for using in usings.iter().skip(1) {
+        edits.push(ImportEdit {
+            location: line_location(text, using.location(db)),
+            new_text: String::new(),
+        });
+    }
+
+    edits
+}
+
+/// Gets the `using` top-levels of the file, in the order they are written.
+fn usings(db: &dyn crate::HirDb, file: HirSource) -> Vec<UsingTopLevel> {
+    file.contents(db)
+        .iter()
+        .filter_map(|top_level| match top_level {
+            TopLevel::Using(using) => Some(*using),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Gets the group of the import, by the package of its module.
+fn import_group(
+    db: &dyn crate::HirDb,
+    package: Package,
+    using: UsingTopLevel,
+    name: &str,
+) -> ImportGroup {
+    if name.split('.').next() == Some(STD_MODULE) {
+        return ImportGroup::Std;
+    }
+
+    match using.path(db).definition(db).location(db).source() {
+        Some(module_file) if !package.all_files(db).contains(&module_file) => {
+            ImportGroup::Dependency
+        }
+        _ => ImportGroup::Local,
+    }
+}
+
+/// Extends the location until the end of its line, including the line break, so removing it
+/// doesn't leave an empty line.
+fn line_location(text: &str, location: Location) -> Location {
+    let end = location.end().offset();
+    let line_end = match text[end..].find('\n') {
+        Some(offset) => end + offset + 1,
+        None => text.len(),
+    };
+
+    location.ending(SourceOffset::from(line_end))
+}
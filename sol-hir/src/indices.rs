@@ -0,0 +1,194 @@
+//! Defines the checking of the indexed inductive families. The indices of a family, unlike its
+//! parameters, can vary between the constructors, like `Zero` and `Succ n` in:
+//!
+//! ```hs
+//! inductive Vec (a : Type) : Nat -> Type {
+//!   Nil  : Vec a Zero,
+//!   Cons : a -> Vec a n -> Vec a (Succ n)
+//! }
+//! ```
+//!
+//! The constructor patterns of the clauses are unified with the indices of the matched types by
+//! their heads, so the arms whose indices are built with other constructors, like `Cons`
+//! against `Vec a Zero`, are reported as impossible.
+
+use salsa::DebugWithDb;
+use sol_diagnostic::report_error;
+
+use crate::{
+    errors::{ImpossiblePatternError, InvalidConstructorTypeError},
+    fmt::HirFormatter,
+    solver::{Definition, DefinitionKind},
+    source::{
+        expr::{Callee, Expr},
+        pattern::{Constructor, Pattern},
+        top_level::{constructor_inductive, BindingGroup, ConstructorKind, Inductive},
+        type_rep::TypeRep,
+        HirElement,
+    },
+};
+
+/// Checks that the constructors of the indexed family construct the family applied to all of
+/// its parameters and indices. The families without indices are not checked, as their
+/// constructors can't vary the type being constructed.
+pub fn check_indices(db: &dyn crate::HirDb, inductive: Inductive) {
+    let indices = inductive.indices(db);
+    if indices.is_empty() {
+        return;
+    }
+
+    let arity = inductive.parameters(db).len() + indices.len();
+    for constructor in inductive.variants(db) {
+        // The function constructors construct the type itself, so they can't give the indices.
+        let valid = match constructor.kind(db) {
+            ConstructorKind::Function => false,
+            ConstructorKind::Gadt => match spine(db, &codomain(constructor.return_type(db))) {
+                Some((head, arguments)) => {
+                    head.id(db) == inductive.name(db).id(db) && arguments.len() == arity
+                }
+                None => false,
+            },
+        };
+
+        if !valid {
+            report_error(db, InvalidConstructorTypeError {
+                constructor: constructor.name(db).to_string(db),
+                inductive: inductive.name(db).to_string(db),
+                arity,
+                location: constructor.location(db),
+            });
+        }
+    }
+}
+
+/// Reports the clauses of the binding group, whose constructor patterns can't match the
+/// indices of the parameter types of the signature.
+pub fn check_impossible_clauses(db: &dyn crate::HirDb, group: BindingGroup) {
+    let signature = group.signature(db);
+    let mut parameters = signature
+        .parameters(db)
+        .into_iter()
+        .filter(|parameter| !parameter.is_implicit(db))
+        .map(|parameter| parameter.parameter_type(db))
+        .collect::<Vec<_>>();
+
+    // The arrows of the return type are matched by the clauses too, like in
+    // `head : Vec a (Succ n) -> a`.
+    let mut return_type = signature.return_type(db);
+    while let Expr::Pi(pi) = *return_type.expr {
+        parameters.extend(
+            pi.parameters
+                .into_iter()
+                .filter(|parameter| !parameter.is_implicit(db))
+                .map(|parameter| parameter.parameter_type(db)),
+        );
+        return_type = *pi.value;
+    }
+
+    for clause in group.clauses(db) {
+        for (argument, parameter_type) in clause.arguments(db).iter().zip(parameters.iter()) {
+            check_pattern(db, argument, parameter_type);
+        }
+    }
+}
+
+/// Checks that the indices of the constructor of the pattern unify with the indices of the
+/// expected type, if it's an application of the constructor's family.
+fn check_pattern(db: &dyn crate::HirDb, pattern: &Pattern, expected: &TypeRep) {
+    let Pattern::Constructor(pattern) = pattern else {
+        return;
+    };
+    let Constructor::Path(reference) = &pattern.name else {
+        return;
+    };
+
+    let definition = reference.definition(db);
+    let Some(inductive) = constructor_inductive(db, definition) else {
+        return;
+    };
+    let Some(constructor) = inductive
+        .variants(db)
+        .into_iter()
+        .find(|constructor| constructor.name(db).id(db) == definition.id(db))
+    else {
+        return;
+    };
+
+    let Some((head, expected_arguments)) = spine(db, &expected.expr) else {
+        return;
+    };
+    let Some((_, found_arguments)) = spine(db, &codomain(constructor.return_type(db))) else {
+        return;
+    };
+    if head.id(db) != inductive.name(db).id(db) {
+        return;
+    }
+
+    // Only the indices can vary between the constructors, the parameters are the same for the
+    // entire family.
+    let parameters = inductive.parameters(db).len();
+    let impossible = expected_arguments
+        .iter()
+        .zip(found_arguments.iter())
+        .skip(parameters)
+        .any(|(expected, found)| disjoint(db, expected, found));
+
+    if impossible {
+        report_error(db, ImpossiblePatternError {
+            constructor: definition.to_string(db),
+            expected: describe(db, &expected.expr),
+            found: found_arguments
+                .iter()
+                .skip(parameters)
+                .map(|argument| describe(db, argument))
+                .collect::<Vec<_>>()
+                .join(" "),
+            location: pattern.span.resolve(db),
+        });
+    }
+}
+
+/// Checks if the indices are built with different constructors, so they can never unify. The
+/// variables, and the other expressions unify with anything.
+fn disjoint(db: &dyn crate::HirDb, lhs: &Expr, rhs: &Expr) -> bool {
+    let (Some((lhs, lhs_arguments)), Some((rhs, rhs_arguments))) = (spine(db, lhs), spine(db, rhs))
+    else {
+        return false;
+    };
+
+    if lhs.kind(db) != DefinitionKind::Constructor || rhs.kind(db) != DefinitionKind::Constructor {
+        return false;
+    }
+
+    lhs.id(db) != rhs.id(db)
+        || lhs_arguments
+            .iter()
+            .zip(rhs_arguments.iter())
+            .any(|(lhs, rhs)| disjoint(db, lhs, rhs))
+}
+
+/// Gets the result type of a function type, following its arrows.
+fn codomain(type_rep: TypeRep) -> Expr {
+    match *type_rep.expr {
+        Expr::Pi(pi) => codomain(*pi.value),
+        expr => expr,
+    }
+}
+
+/// Splits the application into its head, and its arguments, like `Vec` and `a n` in `Vec a n`.
+fn spine(db: &dyn crate::HirDb, expr: &Expr) -> Option<(Definition, Vec<Expr>)> {
+    match expr {
+        Expr::Path(reference) => Some((reference.definition(db), vec![])),
+        Expr::Call(call) => match &call.callee {
+            Callee::Reference(reference) => {
+                Some((reference.definition(db), call.arguments.clone()))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn describe(db: &dyn crate::HirDb, expr: &Expr) -> String {
+    format!("{:?}", expr.formatter().debug(db))
+}
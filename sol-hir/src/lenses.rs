@@ -0,0 +1,146 @@
+//! Defines the code lenses, that are the actions shown by the editors above the declarations,
+//! like running the `@test` definitions, evaluating the `#eval` commands, and showing the
+//! number of references of the top-level definitions.
+
+use crate::{
+    solver::{references, Definition},
+    source::{
+        top_level::{CommandTopLevel, TopLevel},
+        HirElement, HirSource, Location,
+    },
+};
+
+/// The attribute that marks a definition as a test, like `@test`.
+pub const TEST_ATTRIBUTE: &str = "test";
+
+/// The command of a code lens, that is executed by the language server, or by the command
+/// line, when the lens is clicked.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LensCommand {
+    /// Runs the test definition.
+    RunTest(Definition),
+
+    /// Runs the test definition, with a debugger attached.
+    DebugTest(Definition),
+
+    /// Evaluates the arguments of the `#eval` command.
+    Evaluate(CommandTopLevel),
+
+    /// Shows the references of the definition.
+    References(Definition),
+}
+
+impl LensCommand {
+    /// The name of the command, as it's registered by the editors.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LensCommand::RunTest(_) => "sol.runTest",
+            LensCommand::DebugTest(_) => "sol.debugTest",
+            LensCommand::Evaluate(_) => "sol.evaluate",
+            LensCommand::References(_) => "sol.showReferences",
+        }
+    }
+
+    /// The arguments of the command, they're the qualified names of the definitions, or the
+    /// file and the offset of the `#eval` command, so they can be serialized.
+    pub fn arguments(&self, db: &dyn crate::HirDb) -> Vec<String> {
+        match self {
+            LensCommand::RunTest(definition)
+            | LensCommand::DebugTest(definition)
+            | LensCommand::References(definition) => vec![definition.to_string(db)],
+            LensCommand::Evaluate(command) => {
+                let location = command.location(db);
+                let file = location
+                    .source()
+                    .map(|source| source.file_path(db).to_string_lossy().to_string())
+                    .unwrap_or_default();
+
+                vec![file, location.start().offset().to_string()]
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HirCodeLens {
+    pub title: String,
+    pub command: LensCommand,
+    pub location: Location,
+}
+
+/// Defines the [`code_lenses`] query.
+///
+/// Gets the code lenses of the file, in the order of their declarations. The tests have the
+/// run and debug lenses, the `#eval` commands have the evaluate lens, and the top-level
+/// definitions have the number of their references.
+#[salsa::tracked]
+pub fn code_lenses(db: &dyn crate::HirDb, file: HirSource) -> Vec<HirCodeLens> {
+    let mut lenses = vec![];
+    for top_level in file.contents(db).iter() {
+        match top_level {
+            TopLevel::BindingGroup(group) => {
+                let signature = group.signature(db);
+                let location = signature.location(db);
+                let is_test = signature.attributes(db).iter().any(|attribute| {
+                    attribute.name(db).to_string(db).as_deref() == Some(TEST_ATTRIBUTE)
+                });
+
+                lenses.push(references_lens(db, signature.name(db), location.clone()));
+                if is_test {
+                    let definition = signature.name(db);
+                    lenses.push(HirCodeLens {
+                        title: "Run test".into(),
+                        command: LensCommand::RunTest(definition),
+                        location: location.clone(),
+                    });
+                    lenses.push(HirCodeLens {
+                        title: "Debug test".into(),
+                        command: LensCommand::DebugTest(definition),
+                        location,
+                    });
+                }
+            }
+            TopLevel::Inductive(inductive) => {
+                let location = inductive.location(db);
+                lenses.push(references_lens(db, inductive.name(db), location))
+            }
+            TopLevel::TypeAlias(alias) => {
+                lenses.push(references_lens(db, alias.name(db), alias.location(db)))
+            }
+            TopLevel::Trait(trait_declaration) => {
+                let location = trait_declaration.location(db);
+                lenses.push(references_lens(db, trait_declaration.name(db), location))
+            }
+            TopLevel::Command(command) => {
+                if command.path(db).to_string(db) == "eval" {
+                    lenses.push(HirCodeLens {
+                        title: "Evaluate".into(),
+                        command: LensCommand::Evaluate(*command),
+                        location: command.location(db),
+                    });
+                }
+            }
+            TopLevel::Error(_) | TopLevel::Using(_) | TopLevel::Instance(_) => {}
+        }
+    }
+
+    lenses
+}
+
+/// Creates the lens with the number of references of the definition.
+fn references_lens(
+    db: &dyn crate::HirDb,
+    definition: Definition,
+    location: Location,
+) -> HirCodeLens {
+    let title = match references(db, definition).len() {
+        1 => "1 reference".into(),
+        count => format!("{count} references"),
+    };
+
+    HirCodeLens {
+        title,
+        command: LensCommand::References(definition),
+        location,
+    }
+}
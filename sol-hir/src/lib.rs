@@ -16,6 +16,7 @@ extern crate salsa_2022 as salsa;
 pub struct Jar(
     package::Package,
     package::Package_all_files,
+    package::Profile,
     solver::Definition,
     solver::DefinitionId,
     solver::Definition_location,
@@ -30,6 +31,7 @@ pub struct Jar(
     solver::find_trait,
     solver::query_module,
     solver::references,
+    solver::instances,
     source::HirSource,
     source::HirPath,
     source::HirLocation,
@@ -41,17 +43,39 @@ pub struct Jar(
     source::top_level::Signature,
     source::top_level::UsingTopLevel,
     source::top_level::Inductive,
+    source::top_level::inductive_declaration,
+    source::top_level::TypeAlias,
+    source::top_level::type_alias,
+    source::top_level::Trait,
+    source::top_level::trait_declaration,
+    source::top_level::Instance,
     source::top_level::Constructor,
+    source::top_level::constructor_inductive,
     source::top_level::BindingGroup,
     source::top_level::CommandTopLevel,
     source::declaration::Parameter,
     source::declaration::DocString,
     source::declaration::Attribute,
+    source::declaration::Constraint,
+    source::declaration::attributes,
     completions::completions,
+    lenses::code_lenses,
+    derive::derive_json,
+    macros::MacroExpansions,
+    source_map::SourceMapEntries,
+    imports::unused_imports,
+    imports::organize_imports,
+    span_map::span_map,
+    symbols::SymbolQuery,
+    symbols::workspace_symbols,
     reparse::reparse_hir_path,
     primitives::new_type_rep,
     primitives::primitive_type_rep,
     primitives::primitive_type_definition,
+    primitives::primitive_function_definition,
+    todos::todos,
+    unused::unused_definitions,
+    api::package_api,
 );
 
 /// The database that stores all the information about the source code. It is
@@ -70,16 +94,33 @@ impl<DB: HasManifest + HirLowering + PrimitiveProvider> HirDb for DB where
 {
 }
 
+pub mod api;
+pub mod commands;
 pub mod completions;
 pub mod debug;
+pub mod derive;
+pub mod entailment;
 pub mod errors;
 pub mod fmt;
+pub mod imports;
+pub mod indices;
+pub mod lenses;
 pub mod lowering;
+pub mod macros;
 pub mod package;
+pub mod positivity;
 pub mod primitives;
 pub mod reference;
 pub mod reparse;
+pub mod rewriting;
 pub mod scope;
+pub mod shadowing;
 pub mod solver;
 pub mod source;
+pub mod source_map;
+pub mod span_map;
+pub mod symbols;
+pub mod todos;
+pub mod unicode;
+pub mod unused;
 pub mod walking;
@@ -0,0 +1,48 @@
+//! Defines the tracking of the expansions of the user macros, that are pattern-based
+//! rewrites expanded by the lowering, like in:
+//!
+//! ```sol
+//! macro unless {
+//!   ($cond, $body) => if $cond then () else $body,
+//! }
+//!
+//! main = unless!(debug, print "release")
+//! ```
+//!
+//! The expanded expressions keep the locations of the macro bodies, so each expansion is
+//! accumulated into [`MacroExpansions`], and the diagnostics inside of the bodies can be mapped
+//! back to the use sites.
+
+use crate::source::Location;
+
+/// An expansion of a macro, with the location of the body of the rule that was expanded, and
+/// the location of the macro call.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroExpansion {
+    pub name: String,
+    pub definition: Location,
+    pub call_site: Location,
+}
+
+/// The expansions of the macros of the lowering of a file.
+#[salsa::accumulator]
+pub struct MacroExpansions(MacroExpansion);
+
+/// Finds the innermost expansion whose rule body contains the location, that is the use site
+/// of the diagnostics reported at the location. It returns `None` if the location wasn't
+/// expanded from a macro.
+pub fn expansion_site<'a>(
+    expansions: &'a [MacroExpansion],
+    location: &Location,
+) -> Option<&'a MacroExpansion> {
+    expansions
+        .iter()
+        .filter(|expansion| {
+            expansion.definition.source() == location.source()
+                && expansion.definition.start().offset() <= location.start().offset()
+                && location.end().offset() <= expansion.definition.end().offset()
+        })
+        .min_by_key(|expansion| {
+            expansion.definition.end().offset() - expansion.definition.start().offset()
+        })
+}
@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, path::Path};
 
 use sol_syntax::Source;
 
@@ -48,6 +48,34 @@ pub struct Package {
     pub main: Source,
     pub kind: PackageKind,
     pub files: Vec<Source>,
+
+    /// The public API of the package, if it's [`None`], all the definitions are exported.
+    #[return_ref]
+    pub exports: Option<Exports>,
+}
+
+/// Defines the public API of a package, like `[exports]` in the manifest. The definitions that
+/// aren't exported can't be resolved from the dependent packages.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Exports {
+    /// The exported modules, like `Data.List`, all their definitions are exported.
+    #[serde(default)]
+    pub modules: Vec<String>,
+
+    /// The exported definitions, by their qualified names, like `Prelude.id`.
+    #[serde(default)]
+    pub definitions: Vec<String>,
+}
+
+impl Exports {
+    /// Checks if the definition, or the module, with the given qualified name is exported.
+    pub fn contains(&self, name: &str) -> bool {
+        self.definitions.iter().any(|definition| definition == name)
+            || self.modules.iter().any(|module| {
+                name.strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            })
+    }
 }
 
 #[salsa::tracked]
@@ -71,6 +99,17 @@ impl Package {
     }
 }
 
+impl Package {
+    /// Checks if the definition with the given qualified name is part of the public API of the
+    /// package.
+    pub fn is_exported(self, db: &dyn crate::HirDb, name: &str) -> bool {
+        match self.exports(db) {
+            Some(exports) => exports.contains(name),
+            None => true,
+        }
+    }
+}
+
 impl Package {
     /// Makes [`self`] depend on [`another`] package troughough a dependency graph. This is
     /// necessary to make the resolver work.
@@ -87,11 +126,104 @@ impl Package {
     }
 }
 
+/// Defines the build settings of a profile, like `[profile.dev]` and `[profile.release]` in the
+/// manifest. The missing fields are filled with the defaults of the profile.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ProfileSettings {
+    /// The optimization level for the backends, from `0` to `3`.
+    pub opt_level: u8,
+
+    /// If the backends should emit debug information, like source maps.
+    pub debug: bool,
+
+    /// If the termination checking failures should be reported as errors, instead of warnings.
+    pub strict_termination: bool,
+
+    /// If the polymorphic functions should be specialized for each concrete instantiation,
+    /// before the code is emitted by the backends.
+    pub specialize: bool,
+
+    /// The maximum number of nested reductions of the type checker, before it reports a
+    /// possible non-termination, instead of looping forever.
+    pub reduction_fuel: usize,
+
+    /// The behavior of the arithmetic of the fixed-width integers when it overflows.
+    pub overflow: OverflowMode,
+}
+
+impl ProfileSettings {
+    /// The default settings of the `dev` profile, that is used by default.
+    pub fn dev() -> Self {
+        Self {
+            opt_level: 0,
+            debug: true,
+            strict_termination: false,
+            specialize: false,
+            reduction_fuel: 1000,
+            overflow: OverflowMode::Checked,
+        }
+    }
+
+    /// The default settings of the `release` profile, that is selected by `--release`.
+    pub fn release() -> Self {
+        Self {
+            opt_level: 3,
+            debug: false,
+            strict_termination: true,
+            specialize: true,
+            reduction_fuel: 1000,
+            overflow: OverflowMode::Wrapping,
+        }
+    }
+}
+
+/// Defines the behavior of the integer arithmetic when it overflows, like `overflow = "wrapping"`
+/// in the profile. The explicit operations, like `wrapping_add` and `checked_add`, don't depend
+/// on it.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverflowMode {
+    /// Traps with the location of the operation, it's the default of the `dev` profile.
+    #[default]
+    Checked,
+
+    /// Wraps around the bounds of the integer type, it's the default of the `release` profile.
+    Wrapping,
+}
+
+impl Default for ProfileSettings {
+    fn default() -> Self {
+        Self::dev()
+    }
+}
+
+/// Defines the selected build profile, it's an input, so the queries can depend on it, and are
+/// invalidated when the profile changes.
+#[salsa::input]
+pub struct Profile {
+    /// The name of the profile, like `dev` or `release`.
+    #[return_ref]
+    pub name: String,
+
+    #[return_ref]
+    pub settings: ProfileSettings,
+}
+
 /// Defines a not incremental clause for searching all packages, as it's not intendeed to be
 /// "incremented", or be hot. Every change on this can trigger a full rebuild.
 pub trait HasManifest {
     /// Finds all the packages in the current workspace.
     fn all_packages(&self) -> Vec<Package>;
+
+    /// Gets the selected build profile, that is the `dev` profile by default.
+    fn profile(&self) -> Profile;
+
+    /// Finds the file included by `#include_source`, by its path. The included files are
+    /// registered by the drivers, when the file that includes them is read.
+    fn included_source(&self, path: &Path) -> Option<Source>;
 }
 
 pub struct DepGraph<'db> {
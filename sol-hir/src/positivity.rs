@@ -0,0 +1,87 @@
+//! Defines the strict positivity checking of the inductive types. The types being defined can
+//! only occur in strictly positive positions of the constructor parameters, so they can't
+//! occur at the left of an arrow, like in `inductive Bad { MkBad (Bad -> Int) }`.
+//!
+//! The types of a mutual block are defined together, so an occurrence of any type of the
+//! block counts as an occurrence of the type being defined.
+
+use std::collections::HashSet;
+
+use sol_diagnostic::report_error;
+
+use crate::{
+    errors::NonPositiveOccurrenceError,
+    solver::{DefinitionId, Reference},
+    source::{expr::Expr, top_level::Inductive, type_rep::TypeRep},
+    walking::{HirListener, Walker},
+};
+
+/// Collects the references to the types of the mutual block.
+struct Occurrences<'db> {
+    db: &'db dyn crate::HirDb,
+    block: &'db HashSet<DefinitionId>,
+    references: Vec<Reference>,
+}
+
+impl HirListener for Occurrences<'_> {
+    fn visit_reference(&mut self, reference: Reference) {
+        if self
+            .block
+            .contains(&reference.definition(self.db).id(self.db))
+        {
+            self.references.push(reference);
+        }
+    }
+}
+
+/// Checks that the types of the mutual block of the inductive type only occur strictly
+/// positively in its constructors, and reports the other occurrences.
+pub fn check_positivity(db: &dyn crate::HirDb, inductive: Inductive) {
+    let block = inductive
+        .mutual(db)
+        .into_iter()
+        .map(|definition| definition.id(db))
+        .collect::<HashSet<_>>();
+
+    for constructor in inductive.variants(db) {
+        let mut occurrences = Occurrences {
+            db,
+            block: &block,
+            references: vec![],
+        };
+
+        // The parameters of the constructor should be strictly positive, and the return type
+        // is the type being constructed itself.
+        let mut type_rep = constructor.return_type(db);
+        while let Expr::Pi(pi) = *type_rep.expr {
+            for parameter in pi.parameters {
+                negative_occurrences(db, &parameter.parameter_type(db), &mut occurrences);
+            }
+
+            type_rep = *pi.value;
+        }
+
+        for reference in occurrences.references {
+            report_error(
+                db,
+                NonPositiveOccurrenceError {
+                    name: reference.definition(db).to_string(db),
+                    constructor: constructor.name(db).to_string(db),
+                    location: reference.location(db),
+                },
+            );
+        }
+    }
+}
+
+/// Collects the occurrences of the block in the domains of the arrows of the type, that are
+/// the negative positions of a constructor parameter.
+fn negative_occurrences(db: &dyn crate::HirDb, type_rep: &TypeRep, occurrences: &mut Occurrences) {
+    if let Expr::Pi(pi) = &*type_rep.expr {
+        for parameter in pi.parameters.iter() {
+            parameter.parameter_type(db).accept(db, occurrences);
+        }
+
+        negative_occurrences(db, &pi.value, occurrences);
+    }
+}
@@ -1,26 +1,169 @@
 use std::sync::Arc;
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use fxhash::FxBuildHasher;
 
 use crate::{
     solver::{Definition, DefinitionId, DefinitionKind},
     source::{
         expr::{Expr, Type},
+        literal::Literal,
         type_rep::TypeRep,
-        HirPath, Location,
+        HirPath,
     },
+    span_map::SpanId,
 };
 
 /// The primitive map, that stores all the primitives that are available in the
 /// language.
 pub type PrimitiveMap = im::HashMap<String, Definition, FxBuildHasher>;
 
+/// The evaluation hook of a primitive function, it's called at compile time with the literal
+/// arguments of a saturated application, and returns [`None`] to keep the application
+/// symbolic.
+pub type PrimitiveHook = Arc<dyn Fn(&[Literal]) -> Option<Literal> + Send + Sync>;
+
+/// A primitive function registered by the embedder, its parameters and return type are the
+/// names of primitive types, like `Int` or the types registered by the embedder.
+#[derive(Clone)]
+pub struct PrimitiveFunction {
+    pub parameters: Vec<String>,
+    pub return_type: String,
+    pub hook: PrimitiveHook,
+}
+
+/// A built-in function, whose type can't be written with the names of the primitive types, as
+/// it's polymorphic, so it's elaborated by the type checker with its arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Intrinsic {
+    /// Gets the number of items of an array, like `Array.length [1, 2, 3]`.
+    ArrayLength,
+
+    /// Gets the item of an array at the index, starting from zero, like `Array.get xs 0`.
+    ArrayGet,
+}
+
+impl Intrinsic {
+    /// Gets the intrinsic bound to the name, like `Array.length`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "Array.length" => Self::ArrayLength,
+            "Array.get" => Self::ArrayGet,
+            _ => return None,
+        })
+    }
+
+    /// The name of the intrinsic, that it's bound to.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ArrayLength => "Array.length",
+            Self::ArrayGet => "Array.get",
+        }
+    }
+
+    /// The number of explicit arguments of the intrinsic, its applications are elaborated
+    /// saturated.
+    pub fn arity(self) -> usize {
+        match self {
+            Self::ArrayLength => 1,
+            Self::ArrayGet => 2,
+        }
+    }
+}
+
+/// A built-in arithmetic operator, its applications to the integer literals are folded at compile
+/// time, and the overflow of the implicit operators, like `+`, follows the profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    WrappingAdd,
+    WrappingSub,
+    WrappingMul,
+    CheckedAdd,
+    CheckedSub,
+    CheckedMul,
+}
+
+impl Operator {
+    /// Gets the operator bound to the name, like `+` or `wrapping_add`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "+" => Self::Add,
+            "-" => Self::Sub,
+            "*" => Self::Mul,
+            "/" => Self::Div,
+            "%" => Self::Rem,
+            "wrapping_add" => Self::WrappingAdd,
+            "wrapping_sub" => Self::WrappingSub,
+            "wrapping_mul" => Self::WrappingMul,
+            "checked_add" => Self::CheckedAdd,
+            "checked_sub" => Self::CheckedSub,
+            "checked_mul" => Self::CheckedMul,
+            _ => return None,
+        })
+    }
+}
+
 /// The primitive bag, that stores all the primitive maps.
 #[derive(Default, Clone)]
 pub struct PrimitiveBag {
     type_representations: DashMap<String, Definition>,
     type_definitions: DashMap<Definition, TypeRep>,
+
+    /// The opaque types registered by the embedder, they're declared with the builtins.
+    registered_types: DashSet<String>,
+
+    /// The functions registered by the embedder, with their definitions, that are created
+    /// lazily when they're resolved.
+    functions: DashMap<String, PrimitiveFunction>,
+    function_definitions: DashMap<String, Definition>,
+
+    /// The definitions of the intrinsics, that are created lazily when they're resolved, like
+    /// the functions registered by the embedder.
+    intrinsics: DashMap<Definition, Intrinsic>,
+
+    /// The definitions of the built-in operators, that are only used if the operator isn't
+    /// defined by the packages.
+    operators: DashMap<Definition, Operator>,
+}
+
+impl PrimitiveBag {
+    /// Registers an opaque primitive type, like `Vec3`, that can only be created and consumed
+    /// by the primitive functions.
+    pub fn register_type(&self, name: impl Into<String>) {
+        self.registered_types.insert(name.into());
+    }
+
+    /// Registers a primitive function, like `Vec3.length`, replacing the function with the
+    /// same name, if any.
+    pub fn register_function(&self, name: impl Into<String>, function: PrimitiveFunction) {
+        self.functions.insert(name.into(), function);
+    }
+
+    /// Gets the primitive function of the definition, if it's a registered function.
+    pub fn function(&self, definition: Definition) -> Option<PrimitiveFunction> {
+        let (name, _) = self
+            .function_definitions
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .find(|(_, value)| *value == definition)?;
+
+        self.functions.get(&name).map(|function| function.clone())
+    }
+
+    /// Gets the intrinsic of the definition, if it's a built-in function, like `Array.length`.
+    pub fn intrinsic(&self, definition: Definition) -> Option<Intrinsic> {
+        self.intrinsics.get(&definition).map(|intrinsic| *intrinsic)
+    }
+
+    /// Gets the operator of the definition, if it's a built-in operator, like `+`.
+    pub fn operator(&self, definition: Definition) -> Option<Operator> {
+        self.operators.get(&definition).map(|operator| *operator)
+    }
 }
 
 pub trait PrimitiveProvider {
@@ -38,7 +181,7 @@ pub fn initialize_primitive_bag(db: &dyn crate::HirDb) {
     /// use.
     pub fn new_type_rep(db: &dyn crate::HirDb, name: &str, refr: Type) {
         self::new_type_rep(db, HirPath::create(db, name), TypeRep {
-            expr: Expr::Type(refr, Location::CallSite).into(),
+            expr: Expr::Type(refr, SpanId::CallSite).into(),
         });
     }
 
@@ -60,6 +203,30 @@ pub fn initialize_primitive_bag(db: &dyn crate::HirDb) {
     new_type_rep(db, "Int64", Type::Int64);
     new_type_rep(db, "UInt64", Type::UInt64);
     new_type_rep(db, "Nat", Type::Nat);
+    new_type_rep(db, "Integer", Type::Integer);
+
+    // Defines the universe of propositions
+    new_type_rep(db, "Prop", Type::Prop);
+
+    // Defines the equality type
+    new_type_rep(db, "Eq", Type::Eq);
+
+    // Defines the type of the quoted expressions
+    new_type_rep(db, "Syntax", Type::Syntax);
+
+    // Defines the type of the arrays
+    new_type_rep(db, "Array", Type::Array);
+
+    // Defines the types registered by the embedder
+    let registered_types = db
+        .primitives()
+        .registered_types
+        .iter()
+        .map(|name| name.clone())
+        .collect::<Vec<_>>();
+    for name in registered_types {
+        new_type_rep(db, &name, Type::Primitive(HirPath::create(db, &name)));
+    }
 }
 
 /// Defines the [`new_type_rep`] query.
@@ -80,7 +247,7 @@ pub fn new_type_rep(db: &dyn crate::HirDb, path: HirPath, repr: TypeRep) {
         .type_representations
         .entry(text.clone().unwrap())
         .or_insert_with(move || {
-            let id = DefinitionId::new(db, Location::CallSite, text.clone());
+            let id = DefinitionId::new(db, SpanId::CallSite, text.clone());
             Definition::new(db, id, DefinitionKind::Type, path)
         });
 
@@ -113,3 +280,35 @@ pub fn primitive_type_definition(db: &dyn crate::HirDb, path: HirPath) -> Option
 
     Some(*definition)
 }
+
+/// Defines the [`primitive_function_definition`] query.
+///
+/// Gets the definition of a primitive function registered by the embedder, of an [`Intrinsic`],
+/// or of a built-in [`Operator`].
+#[salsa::tracked]
+pub fn primitive_function_definition(db: &dyn crate::HirDb, path: HirPath) -> Option<Definition> {
+    let primitives = db.primitives();
+    let text = path.to_string(db)?;
+    let intrinsic = Intrinsic::from_name(&text);
+    // The functions registered by the embedder replace the built-in operators.
+    let operator = Operator::from_name(&text).filter(|_| !primitives.functions.contains_key(&text));
+    if intrinsic.is_none() && operator.is_none() && !primitives.functions.contains_key(&text) {
+        return None;
+    }
+
+    let definition = *primitives
+        .function_definitions
+        .entry(text.clone())
+        .or_insert_with(move || {
+            let id = DefinitionId::new(db, SpanId::CallSite, Some(text));
+            Definition::new(db, id, DefinitionKind::Function, path)
+        });
+    if let Some(intrinsic) = intrinsic {
+        primitives.intrinsics.insert(definition, intrinsic);
+    }
+    if let Some(operator) = operator {
+        primitives.operators.insert(definition, operator);
+    }
+
+    Some(definition)
+}
@@ -1,4 +1,7 @@
-use crate::source::{HirLocation, Identifier};
+use crate::{
+    source::{HirLocation, Identifier},
+    span_map::SpanId,
+};
 
 /// Defines the [`reparse_hir_path`] query.
 ///
@@ -12,6 +15,6 @@ pub fn reparse_hir_path(
 ) -> Vec<Identifier> {
     new_name
         .split('.')
-        .map(|name| Identifier::new(db, name.to_string(), false, base.location(db)))
+        .map(|name| Identifier::simple(db, name, SpanId::of(db, &base.location(db))))
         .collect()
 }
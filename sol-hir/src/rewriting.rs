@@ -0,0 +1,485 @@
+//! Defines a module for rewriting the HIR, producing a transformed HIR. It's the mutable
+//! counterpart of the [`crate::walking`] module, and it's used by derives, desugarings and
+//! refactorings.
+
+use std::{collections::HashSet, hash::Hash};
+
+use fxhash::FxBuildHasher;
+
+use crate::source::{
+    declaration::{Constraint, Parameter},
+    expr::{
+        AnnExpr, CallExpr, Callee, Expr, LamExpr, LetExpr, MatchArm, MatchExpr, Pi, RecordExpr,
+        RecordField,
+    },
+    pattern::{ConstructorPattern, Pattern},
+    stmt::{AskStmt, Block, LetStmt, Stmt},
+    top_level::{
+        BindingGroup, Clause, CommandTopLevel, Constructor, Inductive, Instance, Signature,
+        TopLevel, Trait, TypeAlias,
+    },
+    type_rep::TypeRep,
+    HirSource,
+};
+
+/// A node that can be rewritten by a [`HirRewriter`]. The children are rewritten first, and
+/// then the node itself, so the rewriter always sees the already rewritten children.
+///
+/// The salsa tracked nodes, like [`Clause`], are only recreated if some of their children
+/// changed, so rewriting them should happen inside a salsa query, like the lowering.
+pub trait Rewrite: Sized {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self;
+}
+
+impl<T: Rewrite> Rewrite for Vec<T> {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        self.into_iter()
+            .map(|item| item.rewrite(db, rewriter))
+            .collect()
+    }
+}
+
+impl<T: Rewrite> Rewrite for Option<T> {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        self.map(|item| item.rewrite(db, rewriter))
+    }
+}
+
+impl<T: Rewrite> Rewrite for Box<T> {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        Box::new((*self).rewrite(db, rewriter))
+    }
+}
+
+impl<T: Rewrite + Hash + Eq> Rewrite for HashSet<T, FxBuildHasher> {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        self.into_iter()
+            .map(|item| item.rewrite(db, rewriter))
+            .collect()
+    }
+}
+
+/// A rewriter that is called when a node is rewritten, after its children. It does return the
+/// node that replaces the given one.
+///
+/// All functions in this trait have a default implementation that returns the node unchanged,
+/// so the rewriters only need to override the nodes they care about.
+#[allow(unused_variables)]
+#[rustfmt::skip]
+pub trait HirRewriter {
+    // SECTION: expr
+    fn rewrite_expr(&mut self, db: &dyn crate::HirDb, expr: Expr) -> Expr { expr }
+    fn rewrite_match_arm(&mut self, db: &dyn crate::HirDb, arm: MatchArm) -> MatchArm { arm }
+    fn rewrite_type_rep(&mut self, db: &dyn crate::HirDb, type_rep: TypeRep) -> TypeRep { type_rep }
+
+    // SECTION: stmt
+    fn rewrite_stmt(&mut self, db: &dyn crate::HirDb, stmt: Stmt) -> Stmt { stmt }
+    fn rewrite_block(&mut self, db: &dyn crate::HirDb, block: Block) -> Block { block }
+
+    // SECTION: pattern
+    fn rewrite_pattern(&mut self, db: &dyn crate::HirDb, pattern: Pattern) -> Pattern { pattern }
+
+    // SECTION: declaration
+    fn rewrite_parameter(&mut self, db: &dyn crate::HirDb, parameter: Parameter) -> Parameter { parameter }
+
+    // SECTION: top_level
+    fn rewrite_clause(&mut self, db: &dyn crate::HirDb, clause: Clause) -> Clause { clause }
+    fn rewrite_signature(&mut self, db: &dyn crate::HirDb, signature: Signature) -> Signature { signature }
+    fn rewrite_constructor(&mut self, db: &dyn crate::HirDb, constructor: Constructor) -> Constructor { constructor }
+    fn rewrite_top_level(&mut self, db: &dyn crate::HirDb, top_level: TopLevel) -> TopLevel { top_level }
+}
+
+impl Rewrite for Expr {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let expr = match self {
+            Expr::Call(call_expr) => Expr::Call(CallExpr {
+                callee: match call_expr.callee {
+                    Callee::Expr(expr) => Callee::Expr(expr.rewrite(db, rewriter)),
+                    callee => callee,
+                },
+                arguments: call_expr.arguments.rewrite(db, rewriter),
+                do_notation: call_expr.do_notation.rewrite(db, rewriter),
+                ..call_expr
+            }),
+            Expr::Ann(ann_expr) => Expr::Ann(AnnExpr {
+                value: ann_expr.value.rewrite(db, rewriter),
+                type_rep: ann_expr.type_rep.rewrite(db, rewriter),
+                ..ann_expr
+            }),
+            Expr::Lam(lam_expr) => Expr::Lam(LamExpr {
+                parameters: lam_expr.parameters.rewrite(db, rewriter),
+                value: lam_expr.value.rewrite(db, rewriter),
+                ..lam_expr
+            }),
+            Expr::Let(let_expr) => Expr::Let(LetExpr {
+                pattern: let_expr.pattern.rewrite(db, rewriter),
+                value: let_expr.value.rewrite(db, rewriter),
+                body: let_expr.body.rewrite(db, rewriter),
+                ..let_expr
+            }),
+            Expr::Record(record_expr) => Expr::Record(RecordExpr {
+                record: record_expr.record.rewrite(db, rewriter),
+                fields: record_expr.fields.rewrite(db, rewriter),
+                ..record_expr
+            }),
+            Expr::Match(match_expr) => Expr::Match(MatchExpr {
+                scrutinee: match_expr.scrutinee.rewrite(db, rewriter),
+                clauses: match_expr.clauses.rewrite(db, rewriter),
+                ..match_expr
+            }),
+            Expr::Pi(pi) => Expr::Pi(pi.rewrite(db, rewriter)),
+            Expr::Sigma(pi) => Expr::Sigma(pi.rewrite(db, rewriter)),
+            expr => expr,
+        };
+
+        rewriter.rewrite_expr(db, expr)
+    }
+}
+
+impl Rewrite for Pi {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        Pi {
+            parameters: self.parameters.rewrite(db, rewriter),
+            value: self.value.rewrite(db, rewriter),
+            ..self
+        }
+    }
+}
+
+impl Rewrite for RecordField {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        RecordField {
+            value: self.value.rewrite(db, rewriter),
+            ..self
+        }
+    }
+}
+
+impl Rewrite for MatchArm {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let arm = MatchArm {
+            pattern: self.pattern.rewrite(db, rewriter),
+            guard: self.guard.rewrite(db, rewriter),
+            value: self.value.rewrite(db, rewriter),
+            ..self
+        };
+
+        rewriter.rewrite_match_arm(db, arm)
+    }
+}
+
+impl Rewrite for TypeRep {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let type_rep = TypeRep {
+            expr: self.expr.rewrite(db, rewriter),
+        };
+
+        rewriter.rewrite_type_rep(db, type_rep)
+    }
+}
+
+impl Rewrite for Stmt {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let stmt = match self {
+            Stmt::Ask(ask_stmt) => Stmt::Ask(AskStmt {
+                pattern: ask_stmt.pattern.rewrite(db, rewriter),
+                value: ask_stmt.value.rewrite(db, rewriter),
+                ..ask_stmt
+            }),
+            Stmt::Let(let_stmt) => Stmt::Let(LetStmt {
+                pattern: let_stmt.pattern.rewrite(db, rewriter),
+                value: let_stmt.value.rewrite(db, rewriter),
+                ..let_stmt
+            }),
+            Stmt::Downgrade(expr) => Stmt::Downgrade(expr.rewrite(db, rewriter)),
+            stmt => stmt,
+        };
+
+        rewriter.rewrite_stmt(db, stmt)
+    }
+}
+
+impl Rewrite for Block {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let block = Block {
+            statements: self.statements.rewrite(db, rewriter),
+            ..self
+        };
+
+        rewriter.rewrite_block(db, block)
+    }
+}
+
+impl Rewrite for Pattern {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let pattern = match self {
+            Pattern::Constructor(constructor) => Pattern::Constructor(ConstructorPattern {
+                arguments: constructor.arguments.rewrite(db, rewriter),
+                ..constructor
+            }),
+            pattern => pattern,
+        };
+
+        rewriter.rewrite_pattern(db, pattern)
+    }
+}
+
+impl Rewrite for Parameter {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let binding = self.binding(db).rewrite(db, rewriter);
+        let parameter_type = self.parameter_type(db).rewrite(db, rewriter);
+
+        let unchanged = binding == self.binding(db) && parameter_type == self.parameter_type(db);
+        let parameter = if unchanged {
+            self
+        } else {
+            Parameter::new(
+                db,
+                /* binding     = */ binding,
+                /* type_rep    = */ parameter_type,
+                /* is_implicit = */ self.is_implicit(db),
+                /* rigid       = */ self.rigid(db),
+                /* level       = */ self.level(db),
+                /* span        = */ self.span(db),
+            )
+        };
+
+        rewriter.rewrite_parameter(db, parameter)
+    }
+}
+
+impl Rewrite for Clause {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let arguments = self.arguments(db).rewrite(db, rewriter);
+        let value = self.value(db).rewrite(db, rewriter);
+
+        let clause = if arguments == self.arguments(db) && value == self.value(db) {
+            self
+        } else {
+            Clause::new(db, self.name(db), arguments, value, self.span(db))
+        };
+
+        rewriter.rewrite_clause(db, clause)
+    }
+}
+
+impl Rewrite for Signature {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let parameters = self.parameters(db).rewrite(db, rewriter);
+        let return_type = self.return_type(db).rewrite(db, rewriter);
+
+        let unchanged = parameters == self.parameters(db) && return_type == self.return_type(db);
+        let signature = if unchanged {
+            self
+        } else {
+            Signature::new(
+                db,
+                /* attributes  = */ self.attributes(db),
+                /* docs        = */ self.docs(db),
+                /* visibility  = */ self.visibility(db),
+                /* name        = */ self.name(db),
+                /* parameters  = */ parameters,
+                /* return_type = */ return_type,
+                /* span        = */ self.span(db),
+            )
+        };
+
+        rewriter.rewrite_signature(db, signature)
+    }
+}
+
+impl Rewrite for BindingGroup {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let signature = self.signature(db).rewrite(db, rewriter);
+        let clauses = self.clauses(db).rewrite(db, rewriter);
+
+        if signature == self.signature(db) && clauses == self.clauses(db) {
+            self
+        } else {
+            BindingGroup::new(db, signature, clauses)
+        }
+    }
+}
+
+impl Rewrite for Constructor {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let return_type = self.return_type(db).rewrite(db, rewriter);
+
+        let constructor = if return_type == self.return_type(db) {
+            self
+        } else {
+            Constructor::new(
+                db,
+                /* kind        = */ self.kind(db),
+                /* attributes  = */ self.attributes(db),
+                /* docs        = */ self.docs(db),
+                /* name        = */ self.name(db),
+                /* return_type = */ return_type,
+                /* span        = */ self.span(db),
+            )
+        };
+
+        rewriter.rewrite_constructor(db, constructor)
+    }
+}
+
+impl Rewrite for Inductive {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let parameters = self.parameters(db).rewrite(db, rewriter);
+        let return_type = self.return_type(db).rewrite(db, rewriter);
+        let variants = self.variants(db).rewrite(db, rewriter);
+
+        if parameters == self.parameters(db)
+            && return_type == self.return_type(db)
+            && variants == self.variants(db)
+        {
+            return self;
+        }
+
+        Inductive::new(
+            db,
+            /* attributes  = */ self.attributes(db),
+            /* docs        = */ self.docs(db),
+            /* visibility  = */ self.visibility(db),
+            /* name        = */ self.name(db),
+            /* parameters  = */ parameters,
+            /* return_type = */ return_type,
+            /* variants    = */ variants,
+            /* mutual      = */ self.mutual(db),
+            /* span        = */ self.span(db),
+            /* scope       = */ self.scope(db),
+        )
+    }
+}
+
+impl Rewrite for TypeAlias {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let parameters = self.parameters(db).rewrite(db, rewriter);
+        let value = self.value(db).rewrite(db, rewriter);
+
+        if parameters == self.parameters(db) && value == self.value(db) {
+            return self;
+        }
+
+        TypeAlias::new(
+            db,
+            /* attributes = */ self.attributes(db),
+            /* docs       = */ self.docs(db),
+            /* visibility = */ self.visibility(db),
+            /* name       = */ self.name(db),
+            /* parameters = */ parameters,
+            /* value      = */ value,
+            /* span       = */ self.span(db),
+            /* scope      = */ self.scope(db),
+        )
+    }
+}
+
+impl Rewrite for Constraint {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let arguments = self.arguments(db).rewrite(db, rewriter);
+
+        if arguments == self.arguments(db) {
+            self
+        } else {
+            Constraint::new(db, self.trait_reference(db), arguments, self.span(db))
+        }
+    }
+}
+
+impl Rewrite for Trait {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let parameters = self.parameters(db).rewrite(db, rewriter);
+        let superclasses = self.superclasses(db).rewrite(db, rewriter);
+        let methods = self.methods(db).rewrite(db, rewriter);
+
+        if parameters == self.parameters(db)
+            && superclasses == self.superclasses(db)
+            && methods == self.methods(db)
+        {
+            return self;
+        }
+
+        Trait::new(
+            db,
+            /* attributes   = */ self.attributes(db),
+            /* docs         = */ self.docs(db),
+            /* visibility   = */ self.visibility(db),
+            /* name         = */ self.name(db),
+            /* parameters   = */ parameters,
+            /* superclasses = */ superclasses,
+            /* methods      = */ methods,
+            /* span         = */ self.span(db),
+            /* scope        = */ self.scope(db),
+        )
+    }
+}
+
+impl Rewrite for Instance {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let arguments = self.arguments(db).rewrite(db, rewriter);
+        let methods = self.methods(db).rewrite(db, rewriter);
+
+        if arguments == self.arguments(db) && methods == self.methods(db) {
+            return self;
+        }
+
+        Instance::new(
+            db,
+            /* attributes      = */ self.attributes(db),
+            /* docs            = */ self.docs(db),
+            /* visibility      = */ self.visibility(db),
+            /* trait_reference = */ self.trait_reference(db),
+            /* arguments       = */ arguments,
+            /* methods         = */ methods,
+            /* span            = */ self.span(db),
+            /* scope           = */ self.scope(db),
+        )
+    }
+}
+
+impl Rewrite for CommandTopLevel {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let arguments = self.arguments(db).rewrite(db, rewriter);
+
+        if arguments == self.arguments(db) {
+            self
+        } else {
+            CommandTopLevel::new(db, self.path(db), arguments, self.span(db))
+        }
+    }
+}
+
+impl Rewrite for TopLevel {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let top_level = match self {
+            TopLevel::Command(command) => TopLevel::Command(command.rewrite(db, rewriter)),
+            TopLevel::BindingGroup(group) => TopLevel::BindingGroup(group.rewrite(db, rewriter)),
+            TopLevel::Inductive(inductive) => TopLevel::Inductive(inductive.rewrite(db, rewriter)),
+            TopLevel::TypeAlias(alias) => TopLevel::TypeAlias(alias.rewrite(db, rewriter)),
+            TopLevel::Trait(trait_decl) => TopLevel::Trait(trait_decl.rewrite(db, rewriter)),
+            TopLevel::Instance(instance) => TopLevel::Instance(instance.rewrite(db, rewriter)),
+            top_level => top_level,
+        };
+
+        rewriter.rewrite_top_level(db, top_level)
+    }
+}
+
+impl Rewrite for HirSource {
+    fn rewrite<R: HirRewriter>(self, db: &dyn crate::HirDb, rewriter: &mut R) -> Self {
+        let contents = self.contents(db).clone().rewrite(db, rewriter);
+
+        if &contents == self.contents(db) {
+            return self;
+        }
+
+        HirSource::new(
+            db,
+            self.source(db),
+            self.package(db),
+            self.scope(db),
+            contents,
+        )
+    }
+}
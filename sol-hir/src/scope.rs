@@ -4,7 +4,8 @@ use fxhash::FxBuildHasher;
 
 use crate::{
     solver::{Definition, DefinitionId, DefinitionKind, Reference},
-    source::{HirPath, HirSource, Location},
+    source::{HirPath, HirSource},
+    span_map::SpanId,
     HirDb,
 };
 
@@ -19,6 +20,7 @@ pub enum ScopeKind {
     Class,
     Trait,
     Block,
+    Let,
     Pi,
     Sigma,
     Type,
@@ -114,8 +116,8 @@ impl Scope {
     }
 
     /// Adds a reference to the given `definition` in the current scope.
-    pub fn using(&mut self, db: &dyn crate::HirDb, it: Definition, loc: Location) -> Reference {
-        let reference = Reference::new(db, it, loc);
+    pub fn using(&mut self, db: &dyn crate::HirDb, it: Definition, span: SpanId) -> Reference {
+        let reference = Reference::new(db, it, span);
         self.references.entry(it).or_default().insert(reference);
 
         // Return the reference
@@ -148,10 +150,10 @@ impl Scope {
         &mut self,
         db: &dyn crate::HirDb,
         name: HirPath,
-        location: Location,
+        span: SpanId,
         kind: DefinitionKind,
     ) -> Definition {
-        let id = DefinitionId::new(db, location, None);
+        let id = DefinitionId::new(db, span, None);
         let definition = Definition::new(db, id, kind, name);
         let Some(name) = definition.name(db).to_string(db) else {
             // TODO: report error
@@ -181,6 +183,19 @@ impl Scope {
         def
     }
 
+    /// Gets the definition of the name for the given `kind`, only in the current scope, without
+    /// searching in the parent scope, or in the other kinds.
+    pub fn local(&self, name: &str, kind: DefinitionKind) -> Option<Definition> {
+        match kind {
+            DefinitionKind::Function => self.values.get(name).copied(),
+            DefinitionKind::Constructor => self.constructors.get(name).copied(),
+            DefinitionKind::Type => self.types.get(name).copied(),
+            DefinitionKind::Variable => self.variables.get(name).copied(),
+            DefinitionKind::Trait => self.traits.get(name).copied(),
+            _ => None,
+        }
+    }
+
     /// Searches a name for the given `kind` in the current scope, and returns the definition if
     /// found.
     pub fn search(
@@ -249,25 +264,27 @@ impl Scope {
     /// Create free variable in the current scope.
     pub fn insert_free_variable(&mut self, db: &dyn HirDb, path: HirPath) -> Reference {
         match self.kind {
-            ScopeKind::Block | ScopeKind::Sigma | ScopeKind::Pi => match self.parent {
-                Some(ref parent) => {
-                    let mut parent = parent.clone();
-                    let parent = Arc::make_mut(&mut parent);
-                    parent.insert_free_variable(db, path)
+            ScopeKind::Block | ScopeKind::Let | ScopeKind::Sigma | ScopeKind::Pi => {
+                match self.parent {
+                    Some(ref parent) => {
+                        let mut parent = parent.clone();
+                        let parent = Arc::make_mut(&mut parent);
+                        parent.insert_free_variable(db, path)
+                    }
+                    None => todo!("report unresolved or panic unreachable"),
                 }
-                None => todo!("report unresolved or panic unreachable"),
-            },
+            }
             _ => {
                 if let Some(definition) = self
                     .free_variables
                     .iter()
                     .find(|it| it.name(db).to_string(db) == path.to_string(db))
                 {
-                    return self.using(db, *definition, path.location(db));
+                    return self.using(db, *definition, path.span(db));
                 }
-                let definition = self.define(db, path, path.location(db), DefinitionKind::Type);
+                let definition = self.define(db, path, path.span(db), DefinitionKind::Type);
                 self.free_variables.insert(definition);
-                self.using(db, definition, definition.location(db))
+                self.using(db, definition, definition.name(db).span(db))
             }
         }
     }
@@ -350,6 +367,32 @@ impl Scope {
         definitions
     }
 
+    /// Gets the constructors named `name`, qualified by their inductive types, like `List.Cons`
+    /// and `Stream.Cons` for `Cons`. It does search in the parent scopes too, and the
+    /// candidates are sorted by their qualified names.
+    pub fn constructor_candidates(&self, name: &str) -> Vec<(String, Definition)> {
+        let mut candidates: Vec<(String, Definition)> = vec![];
+        let mut current = Some(self);
+
+        while let Some(scope) = current {
+            for (qualified, definition) in scope.constructors.iter() {
+                let is_candidate = qualified
+                    .strip_suffix(name)
+                    .and_then(|prefix| prefix.strip_suffix('.'))
+                    .is_some_and(|prefix| !prefix.is_empty() && !prefix.contains('.'));
+
+                if is_candidate && !candidates.iter().any(|(_, it)| it == definition) {
+                    candidates.push((qualified.clone(), *definition));
+                }
+            }
+
+            current = scope.parent.as_deref();
+        }
+
+        candidates.sort_by(|(a, _), (b, _)| a.cmp(b));
+        candidates
+    }
+
     pub fn extend(&mut self, scope: Scope) {
         for (name, definition) in scope.constructors {
             self.constructors.insert(name, definition);
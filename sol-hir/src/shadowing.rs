@@ -0,0 +1,29 @@
+//! Defines the `shadowing` lint, that reports the variables that shadow the variables of the
+//! enclosing scopes, like the inner `x` in:
+//!
+//! ```sol
+//! f x = let x = x + 1 in x
+//! ```
+//!
+//! The bindings are checked while lowering, as the enclosing scopes are only known there. Each
+//! shadowing is reported as a warning with the `solc::shadowing` code, so it can be configured
+//! in the `[lints]` table of the manifest, like `shadowing = "allow"`.
+
+use crate::source::Location;
+
+/// A variable that shadows a variable of an enclosing scope.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("the variable `{name}` shadows a variable of an enclosing scope")]
+#[diagnostic(
+    code(solc::shadowing),
+    severity(Warning),
+    url(docsrs),
+    help("rename the variable, or allow the `shadowing` lint")
+)]
+pub struct ShadowingWarning {
+    pub name: String,
+
+    #[source_code]
+    #[label = "shadows the outer `{name}`"]
+    pub location: Location,
+}
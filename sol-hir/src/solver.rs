@@ -1,17 +1,24 @@
 use im::OrdSet;
-use sol_diagnostic::{report_error, TextSource};
+use sol_diagnostic::report_error;
 
 // Re-export the diagnostics. The diagnostics are used to report errors, warnings, and other kinds
 // of diagnostics.
 //
 // They were defined here, so we are rexporting it to avoid confusion!
 use crate::{
-    errors::{HirError, HirErrorKind},
-    primitives::{initialize_primitive_bag, primitive_type_definition},
+    errors::{AmbiguousConstructorError, HirError, HirErrorKind},
+    package::Package,
+    primitives::{
+        initialize_primitive_bag, primitive_function_definition, primitive_type_definition,
+    },
     reference::ReferenceWalker,
     reparse::reparse_hir_path,
     scope::{Scope, ScopeKind},
-    source::{DefaultWithDb, HirLocation, HirPath, Location, VirtualPath},
+    source::{
+        top_level::{Instance, TopLevel},
+        DefaultWithDb, HirLocation, HirPath, Location, VirtualPath,
+    },
+    span_map::SpanId,
 };
 
 /// Represents the kind of a definition in the High-Level Intermediate Representation. It's
@@ -46,8 +53,7 @@ pub enum HirLevel {
 
 #[salsa::interned]
 pub struct DefinitionId {
-    #[return_ref]
-    pub location: Location,
+    pub span: SpanId,
 
     /// Optional synthetic identifier for the definition.
     #[return_ref]
@@ -87,7 +93,14 @@ impl crate::walking::Walker for Definition {
 #[salsa::input]
 pub struct Reference {
     pub definition: Definition,
-    pub location: Location,
+    pub span: SpanId,
+}
+
+impl Reference {
+    /// Gets the location of the reference, resolving its span.
+    pub fn location(self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
+    }
 }
 
 #[salsa::tracked]
@@ -129,7 +142,7 @@ impl Definition {
             kind: HirErrorKind::UnresolvedDefinition(kind_str, name_str),
         });
 
-        let id = DefinitionId::new(db, name.location(db), None);
+        let id = DefinitionId::new(db, name.span(db), None);
 
         // Creates a new `Definition` with the given `kind`, `name`, and `location`.
         Self::new(db, id, DefinitionKind::Unresolved, name)
@@ -138,7 +151,7 @@ impl Definition {
 
 impl DefaultWithDb for Definition {
     fn default_with_db(db: &dyn crate::HirDb) -> Self {
-        let name = HirPath::new(db, Location::call_site(db), vec![]);
+        let name = HirPath::new(db, SpanId::CallSite, vec![]);
 
         Self::not_found(db, DefinitionKind::Unresolved, name)
     }
@@ -152,8 +165,8 @@ impl DefaultWithDb for Definition {
 pub fn unresolved(db: &dyn crate::HirDb, location: HirLocation) -> Definition {
     let input = VirtualPath::new(db, "_".into());
     let segments = reparse_hir_path(db, location, input.path(db));
-    let path = HirPath::new(db, Location::call_site(db), segments);
-    let id = DefinitionId::new(db, path.location(db), None);
+    let path = HirPath::new(db, SpanId::CallSite, segments);
+    let id = DefinitionId::new(db, path.span(db), None);
 
     // Creates a new `Definition` with the given `kind`, `name`, and `location`.
     Definition::new(db, id, DefinitionKind::Unresolved, path)
@@ -181,18 +194,24 @@ pub fn find_function(db: &dyn crate::HirDb, name: HirPath) -> Definition {
             );
 
             if let Some(function) = target.search(db, name, DefinitionKind::Function) {
-                return function;
+                if is_visible(db, package, name) {
+                    return function;
+                }
             }
         }
     }
 
-    Definition::not_found(db, DefinitionKind::Function, name)
+    // Tries to find a primitive function registered by the embedder, bound to the name of
+    // the [`HirPath`].
+    primitive_function_definition(db, name)
+        .unwrap_or_else(|| Definition::not_found(db, DefinitionKind::Function, name))
 }
 
 /// Defines the [`find_constructor`] query.
 ///
 /// It does search for a constructor with the given `name` in all packages, and returns it as a
-/// [`Definition`].
+/// [`Definition`]. The name is qualified by the module, and it can be qualified by the inductive
+/// type too, like `Prelude.List.Cons`, otherwise it's reported if it's ambiguous in the module.
 ///
 /// If it can't find a constructor with the given `name`, it returns a [`Definition`] with the
 /// [`DefinitionKind::Constructor`] and [`DefinitionKind::Unresolved`] kind. And will report an
@@ -211,7 +230,16 @@ pub fn find_constructor(db: &dyn crate::HirDb, name: HirPath) -> Definition {
             );
 
             if let Some(function) = target.search(db, name, DefinitionKind::Constructor) {
-                return function;
+                if is_visible(db, package, name) {
+                    check_ambiguous_constructor(
+                        db,
+                        &hir.scope(db),
+                        hir.source(db).module_name(db),
+                        name,
+                    );
+
+                    return function;
+                }
             }
         }
     }
@@ -219,6 +247,35 @@ pub fn find_constructor(db: &dyn crate::HirDb, name: HirPath) -> Definition {
     Definition::not_found(db, DefinitionKind::Constructor, name)
 }
 
+/// Reports the constructor `name`, qualified only by the module, if it matches constructors of
+/// multiple inductive types of the module, like `Prelude.Cons`.
+fn check_ambiguous_constructor(db: &dyn crate::HirDb, scope: &Scope, module: &str, name: HirPath) {
+    let Some(text) = name.to_string(db) else {
+        return;
+    };
+    let Some(constructor) = text
+        .strip_prefix(module)
+        .and_then(|it| it.strip_prefix('.'))
+    else {
+        return;
+    };
+
+    let candidates = scope.constructor_candidates(constructor);
+    if candidates.len() < 2 {
+        return;
+    }
+
+    report_error(db, AmbiguousConstructorError {
+        name: text,
+        candidates: candidates
+            .into_iter()
+            .map(|(candidate, _)| format!("`{module}.{candidate}`"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        location: name.location(db),
+    });
+}
+
 /// Defines the [`find_trait`] query.
 ///
 /// It does search for a trait with the given `name` in all packages, and returns it as a
@@ -241,7 +298,9 @@ pub fn find_trait(db: &dyn crate::HirDb, name: HirPath) -> Definition {
             );
 
             if let Some(function) = target.search(db, name, DefinitionKind::Trait) {
-                return function;
+                if is_visible(db, package, name) {
+                    return function;
+                }
             }
         }
     }
@@ -271,7 +330,9 @@ pub fn find_type(db: &dyn crate::HirDb, name: HirPath) -> Definition {
             );
 
             if let Some(function) = target.search(db, name, DefinitionKind::Type) {
-                return function;
+                if is_visible(db, package, name) {
+                    return function;
+                }
             }
         }
     }
@@ -293,6 +354,21 @@ pub fn find_type(db: &dyn crate::HirDb, name: HirPath) -> Definition {
         .unwrap_or_else(|| Definition::not_found(db, DefinitionKind::Type, name))
 }
 
+/// Checks if the definitions of the `package` named `name` can be used in the location of the
+/// `name`. The definitions are always visible in their own package, but the other packages
+/// can only use the definitions that are exported.
+fn is_visible(db: &dyn crate::HirDb, package: Package, name: HirPath) -> bool {
+    let Some(source) = name.location(db).source() else {
+        return true;
+    };
+
+    if package.all_files(db).contains(&source) {
+        return true;
+    }
+
+    package.is_exported(db, &name.to_string(db).unwrap_or_default())
+}
+
 /// Defines the [`query_module`] query. It's defined as "query", because it's returning a scope
 /// instead of a single definition.
 ///
@@ -305,6 +381,7 @@ pub fn find_type(db: &dyn crate::HirDb, name: HirPath) -> Definition {
 #[salsa::tracked]
 pub fn query_module(db: &dyn crate::HirDb, name: HirPath) -> (Scope, Definition) {
     let path = name.to_string(db).unwrap_or("~INTERNAL ERROR~".into());
+    let requested = name;
 
     for package in db.all_packages() {
         for file in package.all_files(db) {
@@ -313,13 +390,8 @@ pub fn query_module(db: &dyn crate::HirDb, name: HirPath) -> (Scope, Definition)
 
             // If the name of the file is the same as the name of the module, then it's the
             // module we're looking for.
-            if &path == name {
-                let text = file.source_text(db).to_string();
-                let id = DefinitionId::new(
-                    db,
-                    Location::new(file, TextSource::new(name, text.into()), 0, 0),
-                    None,
-                );
+            if &path == name && is_visible(db, package, requested) {
+                let id = DefinitionId::new(db, SpanId::new(db, file, 0, 0), None);
                 let kind = DefinitionKind::Module;
                 let path = HirPath::create(db, name);
 
@@ -358,3 +430,31 @@ pub fn references(db: &dyn crate::HirDb, definition: Definition) -> OrdSet<Refer
 
     references
 }
+
+/// Defines the [`instances`] query.
+///
+/// It does search for all instances of the given trait `definition` in all packages, and
+/// returns them in the order they were declared. It's the instance environment used to
+/// resolve the trait constraints.
+#[salsa::tracked]
+pub fn instances(db: &dyn crate::HirDb, definition: Definition) -> Vec<Instance> {
+    let mut instances = Vec::new();
+
+    for package in db.all_packages() {
+        for file in package.all_files(db) {
+            let hir_source = db.hir_lower(package, file);
+
+            for top_level in hir_source.contents(db) {
+                let TopLevel::Instance(instance) = top_level else {
+                    continue;
+                };
+
+                if instance.trait_reference(db).definition(db).id(db) == definition.id(db) {
+                    instances.push(*instance);
+                }
+            }
+        }
+    }
+
+    instances
+}
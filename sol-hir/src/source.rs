@@ -20,6 +20,7 @@ use crate::{
     package::Package,
     reparse::reparse_hir_path,
     scope::Scope,
+    span_map::SpanId,
     walking,
 };
 
@@ -207,6 +208,19 @@ impl Location {
             Location::CallSite => TextSource::new("internal_error.txt", Arc::default()),
         }
     }
+
+    /// Formats where the location starts, like `Main.sol:1:8`, with the row and the column
+    /// starting from one. It's [`None`] on the call site.
+    pub fn position(&self) -> Option<String> {
+        let Location::TextRange(range) = self else {
+            return None;
+        };
+        let before = range.text.data().get(..range.start.offset())?;
+        let row = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+
+        Some(format!("{}:{row}:{column}", range.text.name()))
+    }
 }
 
 impl From<Location> for SourceSpan {
@@ -318,17 +332,22 @@ impl walking::Walker for HirSource {
 /// A unresolved path in the HIR. It's used to represent a path that is not resolved yet, and will
 /// be resolved as a [`Definition`] later.
 ///
-/// It does take segments and location.
+/// It does take segments and span.
 #[salsa::input]
 pub struct HirPath {
-    /// The location of the path.
-    pub location: Location,
+    /// The span of the path.
+    pub span: SpanId,
 
     #[return_ref]
     pub segments: Vec<Identifier>,
 }
 
 impl HirPath {
+    /// Gets the location of the path, resolving its span.
+    pub fn location(self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
+    }
+
     /// Creates a new artificial path. It's used to create a path from a string, and it's used for
     /// diagnostics.
     ///
@@ -357,7 +376,7 @@ pub fn new_path(db: &dyn crate::HirDb, new_name: VirtualPath) -> HirPath {
     let base = HirLocation::new(db, Location::CallSite);
     let segments = reparse_hir_path(db, base, new_name.path(db));
 
-    HirPath::new(db, Location::call_site(db), segments)
+    HirPath::new(db, SpanId::CallSite, segments)
 }
 
 #[salsa::tracked]
@@ -384,14 +403,14 @@ impl HirPath {
 
 impl walking::Walker for HirPath {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
         self.segments(db).clone().accept(db, listener);
     }
 }
 
 impl DefaultWithDb for HirPath {
     fn default_with_db(db: &dyn crate::HirDb) -> Self {
-        Self::new(db, Location::call_site(db), vec![])
+        Self::new(db, SpanId::CallSite, vec![])
     }
 }
 
@@ -404,20 +423,32 @@ pub struct Identifier {
     pub contents: String,
     pub refers_symbol: bool,
 
-    /// The location of the identifier.
-    pub location: Location,
+    /// The span of the identifier.
+    pub span: SpanId,
 }
 
 impl walking::Walker for Identifier {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
     }
 }
 
 impl Identifier {
-    /// Creates a new identifier with the given [`contents`] and [`location`].
-    pub fn symbol(db: &dyn crate::HirDb, contents: &str, location: Location) -> Self {
-        Self::new(db, contents.into(), true, location)
+    /// Creates a new symbol identifier with the given [`contents`] and [`span`]. The
+    /// contents are normalized to NFC.
+    pub fn symbol(db: &dyn crate::HirDb, contents: &str, span: SpanId) -> Self {
+        Self::new(db, crate::unicode::normalize(contents), true, span)
+    }
+
+    /// Creates a new simple identifier with the given [`contents`] and [`span`]. The
+    /// contents are normalized to NFC.
+    pub fn simple(db: &dyn crate::HirDb, contents: &str, span: SpanId) -> Self {
+        Self::new(db, crate::unicode::normalize(contents), false, span)
+    }
+
+    /// Gets the location of the identifier, resolving its span.
+    pub fn location(self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
     }
 }
 
@@ -428,36 +459,29 @@ pub struct Spanned<T> {
     /// The value of the spanned value.
     pub value: T,
 
-    /// The location of the value. It can be [`None`] if the value is a call site.
-    ///
-    /// TODO: change directly to `Location`, as it have a `CallSite` variant now.
-    pub location: Option<Location>,
+    /// The span of the value. It's [`SpanId::CallSite`] if the value is a call site.
+    pub span: SpanId,
 }
 
 impl<T> Spanned<T> {
-    /// Creates a new spanned value with the given [`value`] and the location pointing to the
+    /// Creates a new spanned value with the given [`value`] and the span pointing to the
     /// `call_site`.
     pub fn on_call_site(value: T) -> Self {
         Self {
             value,
-            location: None,
+            span: SpanId::CallSite,
         }
     }
 
-    /// Creates a new spanned value with the given [`value`] and [`location`].
-    pub fn new(value: T, location: Location) -> Self {
-        Self {
-            value,
-            location: Some(location),
-        }
+    /// Creates a new spanned value with the given [`value`] and [`span`].
+    pub fn new(value: T, span: SpanId) -> Self {
+        Self { value, span }
     }
 }
 
 impl<A> HirElement for Spanned<A> {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
-        self.location
-            .clone()
-            .unwrap_or_else(|| Location::call_site(db))
+        self.span.resolve(db)
     }
 }
 
@@ -466,7 +490,7 @@ impl<T: Default> Default for Spanned<T> {
     fn default() -> Self {
         Self {
             value: Default::default(),
-            location: None,
+            span: SpanId::CallSite,
         }
     }
 }
@@ -3,11 +3,13 @@
 //!
 //! The other definitions are just like declarations, but they can't be referenced by other.
 
+use std::fmt::{Debug, Formatter};
+
 use fxhash::FxBuildHasher;
 
 use self::expr::{Expr, Type};
 use super::*;
-use crate::solver::{Definition, HirLevel};
+use crate::solver::{Definition, HirLevel, Reference};
 
 /// Represents a declaration in the HIR. It's a definition that can be referenced by other
 /// definitions.
@@ -55,19 +57,36 @@ pub trait Declaration: HirElement {
 ///
 /// TODO: mark reflection as an idea.
 ///
-/// It does have a name, arguments, and a location.
+/// It does have a name, arguments, and a span.
 #[salsa::tracked]
 pub struct Attribute {
     pub name: HirPath,
-    pub arguments: Vec<expr::Expr>,
-    pub location: Location,
+    pub arguments: Vec<AttributeArgument>,
+    pub span: SpanId,
+}
+
+impl Attribute {
+    /// Gets the value of a key-value argument, like `note` in
+    /// `@deprecated(note = "use bar")`.
+    pub fn argument(self, db: &dyn crate::HirDb, key: &str) -> Option<AttributeArgument> {
+        self.arguments(db)
+            .into_iter()
+            .find_map(|argument| match argument {
+                AttributeArgument::KeyValue(name, value) if name.contents(db) == key => {
+                    Some(*value)
+                }
+                _ => None,
+            })
+    }
 }
 
 impl walking::Walker for Attribute {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_attribute(self);
         self.name(db).accept(db, listener);
         self.arguments(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_attribute(self);
     }
 }
 
@@ -75,22 +94,161 @@ impl DefaultWithDb for Attribute {
     fn default_with_db(db: &dyn crate::HirDb) -> Self {
         let name = default_with_db(db);
 
-        Attribute::new(db, name, vec![], Location::call_site(db))
+        Attribute::new(db, name, vec![], SpanId::CallSite)
+    }
+}
+
+impl HirElement for Attribute {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
+    }
+}
+
+/// Defines an argument of an [`Attribute`]. The arguments are kept structured, and aren't
+/// resolved like regular expressions, so tooling and codegen can consume them, like in
+/// `@deprecated(since = "0.1", note = "use bar")`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AttributeArgument {
+    /// An unresolved path, like `C` in `@repr(C)`.
+    Path(HirPath),
+
+    /// A literal value, like `"use bar"`.
+    Literal(Spanned<literal::Literal>),
+
+    /// A key-value pair, like `note = "use bar"`.
+    KeyValue(Identifier, Box<AttributeArgument>),
+
+    /// A nested list of arguments, like `[Show, Eq]`.
+    List(Vec<AttributeArgument>, SpanId),
+
+    /// Any other expression, that is lowered as a regular expression.
+    Expr(expr::Expr),
+}
+
+impl AttributeArgument {
+    /// Gets the string literal value, if it's a string literal.
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Self::Literal(Spanned {
+                value: literal::Literal::String(value),
+                ..
+            }) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl walking::Walker for AttributeArgument {
+    fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        match self {
+            Self::Path(path) => path.accept(db, listener),
+            Self::Literal(literal) => literal.accept(db, listener),
+            Self::KeyValue(name, value) => {
+                name.accept(db, listener);
+                value.accept(db, listener);
+            }
+            Self::List(arguments, span) => {
+                arguments.accept(db, listener);
+                span.accept(db, listener);
+            }
+            Self::Expr(expr) => expr.accept(db, listener),
+        }
+    }
+}
+
+impl DefaultWithDb for AttributeArgument {
+    fn default_with_db(db: &dyn crate::HirDb) -> Self {
+        Self::Expr(Expr::default_with_db(db))
+    }
+
+    fn error(db: &dyn crate::HirDb, error: HirError) -> Self {
+        Self::Expr(Expr::error(db, error))
+    }
+}
+
+impl HirElement for AttributeArgument {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        match self {
+            Self::Path(path) => path.location(db),
+            Self::Literal(literal) => literal.location(db),
+            Self::KeyValue(name, value) => name.location(db).ending(value.location(db).end()),
+            Self::List(_, span) => span.resolve(db),
+            Self::Expr(expr) => expr.location(db),
+        }
     }
 }
 
+impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for AttributeArgument {
+    fn fmt(&self, f: &mut Formatter<'_>, _: &dyn crate::HirDb, _: bool) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Defines the [`attributes`] query.
+///
+/// Finds the attributes of the declaration that defines the given [`Definition`], searching
+/// in the file where it's defined. It's intended to be used by tooling and codegen, that
+/// only have the definition at hand.
+///
+/// The definitions are compared by their [`crate::solver::DefinitionId`], because the same
+/// definition can be created by both the `hir_declare` and `hir_lower` queries.
+#[salsa::tracked]
+pub fn attributes(db: &dyn crate::HirDb, definition: Definition) -> Vec<Attribute> {
+    let Some(src) = definition.location(db).source() else {
+        return vec![];
+    };
+    let id = definition.id(db);
+
+    db.all_packages()
+        .into_iter()
+        .filter(|package| package.all_files(db).contains(&src))
+        .flat_map(|package| db.hir_lower(package, src).contents(db).clone())
+        .find_map(|top_level| match top_level {
+            top_level::TopLevel::BindingGroup(group) => {
+                let signature = group.signature(db);
+
+                (signature.name(db).id(db) == id).then(|| signature.attributes(db))
+            }
+            top_level::TopLevel::Inductive(inductive) if inductive.name(db).id(db) == id => {
+                Some(inductive.attributes(db))
+            }
+            top_level::TopLevel::Inductive(inductive) => inductive
+                .variants(db)
+                .into_iter()
+                .find(|variant| variant.name(db).id(db) == id)
+                .map(|variant| variant.attributes(db)),
+            top_level::TopLevel::TypeAlias(alias) if alias.name(db).id(db) == id => {
+                Some(alias.attributes(db))
+            }
+            top_level::TopLevel::Trait(trait_decl) if trait_decl.name(db).id(db) == id => {
+                Some(trait_decl.attributes(db))
+            }
+            _ => None,
+        })
+        .map(|attributes| attributes.into_iter().collect())
+        .unwrap_or_default()
+}
+
 /// Defines a documentation string for a declaration. It's used to rule how the declaration
 /// will be documented, and presented to others in their IDEs.
 ///
-/// It does have a range, and a location.
+/// It does have a range, that is its span.
 #[salsa::tracked]
 pub struct DocString {
-    pub range: Location,
+    pub range: SpanId,
 }
 
 impl walking::Walker for DocString {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_doc_string(self);
         self.range(db).accept(db, listener);
+        listener.exit_doc_string(self);
+    }
+}
+
+impl HirElement for DocString {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.range(db).resolve(db)
     }
 }
 
@@ -137,7 +295,7 @@ pub struct Parameter {
     /// The level of the parameter. It's used to type check the parameter.
     pub level: HirLevel,
 
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl Parameter {
@@ -147,9 +305,9 @@ impl Parameter {
         db: &dyn crate::HirDb,
         binding: pattern::Pattern,
         type_rep: type_rep::TypeRep,
-        location: Location,
+        span: SpanId,
     ) -> Self {
-        Self::new(db, binding, type_rep, false, true, HirLevel::Expr, location)
+        Self::new(db, binding, type_rep, false, true, HirLevel::Expr, span)
     }
 
     /// Creates a new implicit parameter with the given [`binding`], [`type_rep`], that
@@ -158,9 +316,9 @@ impl Parameter {
         db: &dyn crate::HirDb,
         binding: pattern::Pattern,
         type_rep: type_rep::TypeRep,
-        location: Location,
+        span: SpanId,
     ) -> Self {
-        Self::new(db, binding, type_rep, true, true, HirLevel::Expr, location)
+        Self::new(db, binding, type_rep, true, true, HirLevel::Expr, span)
     }
 
     /// Creates a new explicit parameter with the given [`binding`], [`type_rep`], that
@@ -170,7 +328,7 @@ impl Parameter {
         db: &dyn crate::HirDb,
         binding: pattern::Pattern,
         type_rep: type_rep::TypeRep,
-        location: Location,
+        span: SpanId,
     ) -> Self {
         Self::new(
             db,
@@ -179,7 +337,7 @@ impl Parameter {
             /* is_implicit = */ false,
             /* rigid       = */ false,
             /* level       = */ HirLevel::Expr,
-            /* location    = */ location,
+            /* span        = */ span,
         )
     }
 
@@ -195,16 +353,24 @@ impl Parameter {
             /* is_implicit = */ false,
             /* rigid       = */ true,
             /* level       = */ HirLevel::Expr,
-            /* location    = */ type_rep.location(db),
+            /* span        = */ SpanId::of(db, &type_rep.location(db)),
         )
     }
 }
 
 impl walking::Walker for Parameter {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_parameter(self);
         self.binding(db).accept(db, listener);
         self.parameter_type(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_parameter(self);
+    }
+}
+
+impl HirElement for Parameter {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
     }
 }
 
@@ -213,7 +379,7 @@ impl DefaultWithDb for Parameter {
     /// purposes.
     fn default_with_db(db: &dyn crate::HirDb) -> Self {
         let binding = pattern::Pattern::Hole;
-        let type_rep = Expr::Type(Type::Unit, Location::CallSite).upgrade(db);
+        let type_rep = Expr::Type(Type::Unit, SpanId::CallSite).upgrade(db);
         let level = HirLevel::Expr;
 
         Self::new(
@@ -223,7 +389,40 @@ impl DefaultWithDb for Parameter {
             /* is_implicit = */ false,
             /* rigid       = */ false,
             /* level       = */ level,
-            /* location    = */ Location::call_site(db),
+            /* span        = */ SpanId::CallSite,
         )
     }
 }
+
+/// Defines a trait constraint, like the superclasses of a trait. It's a reference to the
+/// trait, applied to the type arguments.
+///
+/// ## Examples
+///
+/// ```hs
+/// trait Ord (a : Type) requires Eq a { ... }
+/// ```
+///
+/// In this example, `Eq a` is a constraint.
+#[salsa::tracked]
+pub struct Constraint {
+    pub trait_reference: Reference,
+    pub arguments: Vec<type_rep::TypeRep>,
+    pub span: SpanId,
+}
+
+impl walking::Walker for Constraint {
+    fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_constraint(self);
+        self.trait_reference(db).accept(db, listener);
+        self.arguments(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_constraint(self);
+    }
+}
+
+impl HirElement for Constraint {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
+    }
+}
@@ -35,6 +35,33 @@ pub enum Callee {
     Unit,
     Pure,
     Do,
+
+    /// The reflexivity proof of the equality type, like `refl : x = x`.
+    Refl,
+
+    /// The transport of the goal along an equality, like `rewrite eq in value`. The arguments
+    /// are the equality and the value.
+    Rewrite,
+
+    /// Reifies the expression as a value of the `Syntax` type, like `quote { x + 1 }`. The
+    /// argument is the quoted expression.
+    Quote,
+
+    /// Evaluates the `Syntax` value at compile time, and elaborates the expression it holds in
+    /// place, like `splice { twice (quote { x }) }`. The argument is the spliced value.
+    Splice,
+
+    /// Aborts the program with the message, like when an `assert` fails. The argument is the
+    /// string literal of the message, with the location of the failure.
+    Panic,
+
+    /// A placeholder for the code that isn't written yet, like `todo`. It panics like
+    /// [`Callee::Panic`], and it's listed by the `todo` lint of the package.
+    Todo,
+
+    /// Projects the element of the tuple at the position, like `pair.0`. The argument is the
+    /// tuple.
+    Project(usize),
     Reference(Reference),
     Expr(Box<expr::Expr>),
 }
@@ -47,6 +74,13 @@ impl walking::Walker for Callee {
             Callee::Unit => {}
             Callee::Pure => {}
             Callee::Do => {}
+            Callee::Refl => {}
+            Callee::Rewrite => {}
+            Callee::Quote => {}
+            Callee::Splice => {}
+            Callee::Panic => {}
+            Callee::Todo => {}
+            Callee::Project(_) => {}
             Callee::Reference(reference) => reference.accept(db, listener),
             Callee::Expr(expr) => expr.accept(db, listener),
         }
@@ -63,6 +97,13 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for Callee {
             Callee::Unit => write!(f, "()"),
             Callee::Pure => write!(f, "return"),
             Callee::Do => write!(f, "do"),
+            Callee::Refl => write!(f, "refl"),
+            Callee::Rewrite => write!(f, "rewrite"),
+            Callee::Quote => write!(f, "quote"),
+            Callee::Splice => write!(f, "splice"),
+            Callee::Panic => write!(f, "panic"),
+            Callee::Todo => write!(f, "todo"),
+            Callee::Project(position) => write!(f, ".{position}"),
             Callee::Reference(reference) => DebugWithDb::fmt(reference, f, db, true),
             Callee::Expr(expr) => DebugWithDb::fmt(expr, f, db, true),
         }
@@ -90,7 +131,7 @@ pub enum CallKind {
 pub struct LamExpr {
     pub parameters: Vec<pattern::Pattern>,
     pub value: Box<expr::Expr>,
-    pub location: Location,
+    pub span: SpanId,
     pub scope: Arc<Scope>,
 }
 
@@ -99,7 +140,7 @@ impl walking::Walker for LamExpr {
         listener.enter_lam_expr(self.clone());
         self.parameters.clone().accept(db, listener);
         self.value.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_lam_expr(self);
     }
 }
@@ -111,8 +152,100 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for LamExpr
 }
 
 impl HirElement for LamExpr {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
+    }
+}
+
+/// Represents a let expression, like `let x = 10 in x + 1`, it binds the value to the pattern
+/// in the body, so pure code can introduce locals without do-notation blocks.
+///
+/// The value doesn't see the pattern bindings, they're only in the scope of the body.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct LetExpr {
+    pub pattern: pattern::Pattern,
+    pub value: Box<expr::Expr>,
+    pub body: Box<expr::Expr>,
+    pub span: SpanId,
+    pub scope: Arc<Scope>,
+}
+
+impl walking::Walker for LetExpr {
+    fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_let_expr(self.clone());
+        self.value.clone().accept(db, listener);
+        self.pattern.clone().accept(db, listener);
+        self.body.clone().accept(db, listener);
+        self.span.accept(db, listener);
+        listener.exit_let_expr(self);
+    }
+}
+
+impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for LetExpr {
+    fn fmt(&self, f: &mut Formatter<'_>, _: &dyn crate::HirDb, _: bool) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl HirElement for LetExpr {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
+    }
+}
+
+/// Represents a field of a record expression, like `x = 1` in `{ x = 1, y = 2 }`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RecordField {
+    pub name: Identifier,
+    pub value: expr::Expr,
+    pub span: SpanId,
+}
+
+impl walking::Walker for RecordField {
+    fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        self.name.accept(db, listener);
+        self.value.clone().accept(db, listener);
+        self.span.accept(db, listener);
+    }
+}
+
+impl HirElement for RecordField {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
+    }
+}
+
+/// Represents an anonymous record expression, like `{ x = 1, y = 2 }`, or a functional update
+/// of a record, like `{ r | x = 3 }`, that copies the record `r` with the field `x` replaced.
+///
+/// The records are structural, so they don't need a named inductive type to be declared.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RecordExpr {
+    /// The record being updated, it's [`None`] for record literals.
+    pub record: Option<Box<expr::Expr>>,
+    pub fields: Vec<RecordField>,
+    pub span: SpanId,
+}
+
+impl walking::Walker for RecordExpr {
+    fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_record_expr(self.clone());
+        self.record.clone().accept(db, listener);
+        self.fields.clone().accept(db, listener);
+        self.span.accept(db, listener);
+        listener.exit_record_expr(self);
+    }
+}
+
+impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for RecordExpr {
+    fn fmt(&self, f: &mut Formatter<'_>, _: &dyn crate::HirDb, _: bool) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl HirElement for RecordExpr {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -124,7 +257,7 @@ impl HirElement for LamExpr {
 pub struct AnnExpr {
     pub value: Box<expr::Expr>,
     pub type_rep: type_rep::TypeRep,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for AnnExpr {
@@ -132,14 +265,14 @@ impl walking::Walker for AnnExpr {
         listener.enter_ann_expr(self.clone());
         self.value.clone().accept(db, listener);
         self.type_rep.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_ann_expr(self);
     }
 }
 
 impl HirElement for AnnExpr {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -156,25 +289,33 @@ pub struct MatchArm {
     /// The leftmost pattern of the match arm. It's used to match agains't the value.
     pub pattern: pattern::Pattern,
 
+    /// The optional guard of the match arm, written as `pattern if condition => value`. It's
+    /// an extra boolean condition, that should hold after the pattern matches, otherwise the
+    /// match falls through to the next arm.
+    pub guard: Option<expr::Expr>,
+
     /// The rightmost expression of the match arm. It's used to return a value.
     pub value: expr::Expr,
 
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for MatchArm {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
-        self.pattern.accept(db, listener);
-        self.value.accept(db, listener);
-        self.location.accept(db, listener);
+        listener.enter_match_arm(self.clone());
+        self.pattern.clone().accept(db, listener);
+        self.guard.clone().accept(db, listener);
+        self.value.clone().accept(db, listener);
+        self.span.accept(db, listener);
+        listener.exit_match_arm(self);
     }
 }
 
 impl HirElement for MatchArm {
-    fn location(&self, _db: &dyn crate::HirDb) -> Location {
-        // As it's not tracked by salsa, it's not possible to get the location from the salsa
-        // database, so it's just returning the location of the match arm, cloning it
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        // As it's not tracked by salsa, the location is resolved from the span of the match
+        // arm, through the span map of its file
+        self.span.resolve(db)
     }
 }
 
@@ -187,7 +328,7 @@ pub struct MatchExpr {
     pub kind: MatchKind,
     pub scrutinee: Box<expr::Expr>,
     pub clauses: Vec<MatchArm>,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for MatchExpr {
@@ -195,14 +336,14 @@ impl walking::Walker for MatchExpr {
         listener.enter_match_expr(self.clone());
         self.scrutinee.clone().accept(db, listener);
         self.clauses.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_match_expr(self);
     }
 }
 
 impl HirElement for MatchExpr {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -227,7 +368,7 @@ pub struct CallExpr {
     /// Kotlin's syntax sugar for lambdas.
     pub do_notation: Option<stmt::Block>,
 
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for CallExpr {
@@ -236,14 +377,14 @@ impl walking::Walker for CallExpr {
         self.callee.clone().accept(db, listener);
         self.arguments.clone().accept(db, listener);
         self.do_notation.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_call_expr(self);
     }
 }
 
 impl HirElement for CallExpr {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -258,6 +399,10 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for CallExpr
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Type {
     Universe,
+
+    /// The universe of the propositions, their proofs are irrelevant, so all the proofs of a
+    /// proposition are equal.
+    Prop,
     This,
     Unit,
     String,
@@ -271,13 +416,31 @@ pub enum Type {
     Int64,
     UInt64,
     Nat,
+
+    /// The arbitrary-precision integers, like [`Type::Nat`], they can't overflow.
+    Integer,
+
+    /// The propositional equality type, like `Eq x y`, that is inhabited by `refl` when both
+    /// sides are the same.
+    Eq,
+
+    /// The type of the quoted expressions, that are manipulated by the code executed at
+    /// compile time, like `quote { x + 1 } : Syntax`.
+    Syntax,
+
+    /// The type of the arrays, like `Array Int`, it's elaborated with its argument, like
+    /// [`Type::Eq`].
+    Array,
+
+    /// An opaque type registered by the embedder, see [`crate::primitives::PrimitiveBag`].
+    Primitive(HirPath),
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Pi {
     pub parameters: Vec<declaration::Parameter>,
     pub value: Box<type_rep::TypeRep>,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for Pi {
@@ -285,7 +448,7 @@ impl walking::Walker for Pi {
         listener.enter_pi(self.clone());
         self.parameters.clone().accept(db, listener);
         self.value.clone().accept(db, listener);
-        self.location.clone().accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_pi(self);
     }
 }
@@ -305,14 +468,16 @@ pub enum Expr {
     /// it's splitted into a different variant to make it easier to work with, when we don't
     /// have an actual expression, like in [`Self::Error`].
     Empty,
-    Hole(Location),
-    Type(Type, Location),
+    Hole(SpanId),
+    Type(Type, SpanId),
     Error(HirError),
     Path(Reference),
     Literal(Spanned<literal::Literal>),
     Call(CallExpr),
     Ann(AnnExpr),
     Lam(LamExpr),
+    Let(LetExpr),
+    Record(RecordExpr),
     Match(MatchExpr),
     Pi(Pi),
     Sigma(Pi),
@@ -321,13 +486,13 @@ pub enum Expr {
 impl Expr {
     /// Creates a unit expression. It's used to create a unit expression, that is just like a
     /// `()` value.
-    pub fn call_unit_expr(location: Location) -> Self {
+    pub fn call_unit_expr(span: SpanId) -> Self {
         Self::Call(CallExpr {
             kind: CallKind::Prefix,
             callee: Callee::Unit,
             arguments: vec![],
             do_notation: None,
-            location,
+            span,
         })
     }
 
@@ -338,8 +503,8 @@ impl Expr {
             kind: CallKind::Prefix,
             callee: Callee::Do,
             arguments: vec![],
-            do_notation: Some(do_notation.clone()),
-            location: do_notation.location(db),
+            span: do_notation.span,
+            do_notation: Some(do_notation),
         })
     }
 
@@ -372,16 +537,16 @@ impl Expr {
                     callee: Callee::Unit,
                     do_notation: Option::None,
                     arguments,
-                    location,
+                    span,
                     kind: _,
-                }) if arguments.is_empty() => Expr::Type(Type::Unit, location),
+                }) if arguments.is_empty() => Expr::Type(Type::Unit, span),
 
                 // Upgrades a group expression to a type
                 Self::Call(CallExpr {
                     callee: Callee::Tuple,
                     do_notation: None,
                     arguments,
-                    location: _,
+                    span: _,
                     kind: _,
                 }) if arguments.len() == 1 => {
                     return arguments.first().cloned().unwrap().upgrade(db)
@@ -392,7 +557,7 @@ impl Expr {
                     callee: Callee::Reference(reference),
                     do_notation: None,
                     arguments,
-                    location,
+                    span,
                     kind,
                 }) => Self::Call(CallExpr {
                     // Create dummy path type reference
@@ -402,7 +567,7 @@ impl Expr {
                         .map(|argument| *argument.upgrade(db).expr)
                         .collect(),
                     do_notation: None,
-                    location,
+                    span,
                     kind,
                 }),
 
@@ -411,7 +576,7 @@ impl Expr {
                     callee: Callee::Expr(expr),
                     do_notation: Option::None,
                     arguments,
-                    location,
+                    span,
                     kind,
                 }) => Self::Call(CallExpr {
                     callee: Callee::Expr(expr.upgrade(db).expr),
@@ -420,7 +585,7 @@ impl Expr {
                         .map(|argument| *argument.upgrade(db).expr)
                         .collect(),
                     do_notation: None,
-                    location,
+                    span,
                     kind,
                 }),
 
@@ -461,6 +626,8 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for Expr {
             Expr::Call(call_expr) => call_expr.debug_all(db).fmt(f),
             Expr::Ann(ann_expr) => ann_expr.debug_all(db).fmt(f),
             Expr::Lam(abs_expr) => abs_expr.debug_all(db).fmt(f),
+            Expr::Let(let_expr) => let_expr.debug_all(db).fmt(f),
+            Expr::Record(record_expr) => record_expr.debug_all(db).fmt(f),
             Expr::Match(match_expr) => match_expr.debug_all(db).fmt(f),
             Expr::Type(type_ref, _) => write!(f, "Type({:?})", type_ref),
             Expr::Pi(pi) => pi.debug_all(db).fmt(f),
@@ -473,11 +640,13 @@ impl walking::Walker for Expr {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
         match self {
             Expr::Empty => listener.visit_empty_expr(),
-            Expr::Type(type_ref, location) => listener.visit_type(type_ref, location),
-            Expr::Hole(location) => listener.visit_hole(location),
+            Expr::Type(type_ref, span) => listener.visit_type(type_ref, span.resolve(db)),
+            Expr::Hole(span) => listener.visit_hole(span.resolve(db)),
             Expr::Call(call_expr) => call_expr.accept(db, listener),
             Expr::Ann(ann_expr) => ann_expr.accept(db, listener),
             Expr::Lam(abs_expr) => abs_expr.accept(db, listener),
+            Expr::Let(let_expr) => let_expr.accept(db, listener),
+            Expr::Record(record_expr) => record_expr.accept(db, listener),
             Expr::Match(match_expr) => match_expr.accept(db, listener),
             Expr::Error(_) => {}
             Expr::Path(path) => {
@@ -490,11 +659,7 @@ impl walking::Walker for Expr {
                 literal.clone().accept(db, listener);
                 listener.exit_literal_expr(literal);
             }
-            Expr::Pi(pi) => {
-                listener.enter_pi(pi.clone());
-                pi.clone().accept(db, listener);
-                listener.exit_pi(pi);
-            }
+            Expr::Pi(pi) => pi.accept(db, listener),
             Expr::Sigma(pi) => {
                 listener.enter_sigma(pi.clone());
                 pi.clone().accept(db, listener);
@@ -510,15 +675,17 @@ impl HirElement for Expr {
             Self::Empty => Location::call_site(db),
             Self::Error(downcast) => downcast.label.clone(),
             Self::Path(downcast) => downcast.location(db),
-            Self::Literal(downcast) => downcast.location.clone().unwrap(),
+            Self::Literal(downcast) => downcast.location(db),
             Self::Call(downcast) => downcast.location(db),
             Self::Ann(downcast) => downcast.location(db),
             Self::Lam(downcast) => downcast.location(db),
+            Self::Let(downcast) => downcast.location(db),
+            Self::Record(downcast) => downcast.location(db),
             Self::Match(downcast) => downcast.location(db),
-            Self::Pi(downcast) => downcast.location.clone(),
-            Self::Sigma(downcast) => downcast.location.clone(),
-            Self::Type(_, location) => location.clone(),
-            Self::Hole(location) => location.clone(),
+            Self::Pi(downcast) => downcast.span.resolve(db),
+            Self::Sigma(downcast) => downcast.span.resolve(db),
+            Self::Type(_, span) => span.resolve(db),
+            Self::Hole(span) => span.resolve(db),
         }
     }
 }
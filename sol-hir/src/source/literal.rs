@@ -1,6 +1,8 @@
 //! Defines a kind of primaries. It does define terms that are literally literals, and it's used
 //! as numbers, strings, etc... These are the base of the base of the base of the language
 
+use num_bigint::BigUint;
+
 use super::*;
 use crate::walking::HirListener;
 
@@ -19,6 +21,10 @@ pub enum Literal {
     Int64(i64),
     UInt64(u64),
 
+    /// Defines a natural number literal, like `10n`. It's arbitrary-precision, so it can be of
+    /// any size.
+    Nat(BigUint),
+
     /// Defines a string literal. It's used to represent a string value.
     String(String),
 
@@ -43,13 +49,13 @@ impl Literal {
     /// literal.
     /// It's not currently supported by the language, but it will be in the future. So the
     /// compiler will emit an error.
-    pub fn upgrade_pattern(self, loc: Location, _db: &dyn crate::HirDb) -> pattern::Pattern {
-        pattern::Pattern::Literal(Spanned::new(self, loc))
+    pub fn upgrade_pattern(self, span: SpanId, _db: &dyn crate::HirDb) -> pattern::Pattern {
+        pattern::Pattern::Literal(Spanned::new(self, span))
     }
 
     /// Creates a literal expression from a literal. It's used to create a expression from a
     /// literal.
-    pub fn upgrade_expr(self, loc: Location, _db: &dyn crate::HirDb) -> expr::Expr {
-        expr::Expr::Literal(Spanned::new(self, loc))
+    pub fn upgrade_expr(self, span: SpanId, _db: &dyn crate::HirDb) -> expr::Expr {
+        expr::Expr::Literal(Spanned::new(self, span))
     }
 }
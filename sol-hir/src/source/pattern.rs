@@ -44,7 +44,7 @@ impl walking::Walker for Constructor {
 pub struct ConstructorPattern {
     pub name: Constructor,
     pub arguments: Vec<Pattern>,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for ConstructorPattern {
@@ -52,14 +52,14 @@ impl walking::Walker for ConstructorPattern {
         listener.enter_constructor_pattern(self.clone());
         self.name.clone().accept(db, listener);
         self.arguments.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_constructor_pattern(self);
     }
 }
 
 impl HirElement for ConstructorPattern {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -77,21 +77,21 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for Construc
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct BindingPattern {
     pub name: Definition,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for BindingPattern {
     fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
         listener.enter_binding_pattern(self.clone());
         self.name.accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_binding_pattern(self);
     }
 }
 
 impl HirElement for BindingPattern {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -118,8 +118,8 @@ pub enum Pattern {
     /// - [`Pattern::Binding`]
     Hole,
     Literal(Spanned<literal::Literal>),
-    Wildcard(Location),
-    Rest(Location),
+    Wildcard(SpanId),
+    Rest(SpanId),
     Error(HirError),
     Constructor(ConstructorPattern),
     Binding(BindingPattern),
@@ -140,8 +140,8 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for Pattern
         match self {
             Pattern::Hole => write!(f, "Empty"),
             Pattern::Literal(literal) => write!(f, "Literal({literal:?})"),
-            Pattern::Wildcard(wildcard) => write!(f, "Wildcard(location: {wildcard:?})"),
-            Pattern::Rest(rest) => write!(f, "Rest(location: {rest:?})"),
+            Pattern::Wildcard(wildcard) => write!(f, "Wildcard(span: {wildcard:?})"),
+            Pattern::Rest(rest) => write!(f, "Rest(span: {rest:?})"),
             Pattern::Error(error) => write!(f, "Error({error:?})"),
             Pattern::Constructor(constructor) => constructor.debug_all(db).fmt(f),
             Pattern::Binding(binding) => binding.debug_all(db).fmt(f),
@@ -160,15 +160,15 @@ impl walking::Walker for Pattern {
                 literal.clone().accept(db, listener);
                 listener.exit_literal_pattern(literal);
             }
-            Pattern::Wildcard(location) => {
-                listener.enter_wildcard_pattern(location.clone());
-                location.clone().accept(db, listener);
-                listener.exit_wildcard_pattern(location);
+            Pattern::Wildcard(span) => {
+                listener.enter_wildcard_pattern(span.resolve(db));
+                span.accept(db, listener);
+                listener.exit_wildcard_pattern(span.resolve(db));
             }
-            Pattern::Rest(location) => {
-                listener.enter_rest_pattern(location.clone());
-                location.clone().accept(db, listener);
-                listener.exit_rest_pattern(location);
+            Pattern::Rest(span) => {
+                listener.enter_rest_pattern(span.resolve(db));
+                span.accept(db, listener);
+                listener.exit_rest_pattern(span.resolve(db));
             }
             Pattern::Error(_) => {}
         }
@@ -179,9 +179,9 @@ impl HirElement for Pattern {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
         match self {
             Self::Hole => Location::call_site(db),
-            Self::Literal(literal) => literal.location.clone().unwrap(),
-            Self::Wildcard(location) => location.clone(),
-            Self::Rest(location) => location.clone(),
+            Self::Literal(literal) => literal.location(db),
+            Self::Wildcard(span) => span.resolve(db),
+            Self::Rest(span) => span.resolve(db),
             Self::Constructor(downcast) => downcast.location(db),
             Self::Binding(downcast) => downcast.location(db),
             Self::Error(downcast) => downcast.label.clone(),
@@ -15,7 +15,7 @@ use crate::{errors::HirError, scope::ScopeKind, walking::HirListener};
 pub struct AskStmt {
     pub pattern: pattern::Pattern,
     pub value: expr::Expr,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for AskStmt {
@@ -23,14 +23,14 @@ impl walking::Walker for AskStmt {
         listener.enter_ask_stmt(self.clone());
         self.pattern.clone().accept(db, listener);
         self.value.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_ask_stmt(self);
     }
 }
 
 impl HirElement for AskStmt {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -47,7 +47,7 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for AskStmt
 pub struct LetStmt {
     pub pattern: pattern::Pattern,
     pub value: expr::Expr,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for LetStmt {
@@ -55,14 +55,14 @@ impl walking::Walker for LetStmt {
         listener.enter_let_stmt(self.clone());
         self.pattern.clone().accept(db, listener);
         self.value.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_let_stmt(self);
     }
 }
 
 impl HirElement for LetStmt {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -138,7 +138,7 @@ impl HirElement for Stmt {
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
 pub struct Block {
     pub statements: Vec<Stmt>,
-    pub location: Location,
+    pub span: SpanId,
     pub scope: Arc<Scope>,
 }
 
@@ -146,26 +146,26 @@ impl walking::Walker for Block {
     fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
         listener.enter_block(self.clone());
         self.statements.clone().accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span.accept(db, listener);
         listener.exit_block(self);
     }
 }
 
 impl DefaultWithDb for Block {
-    fn default_with_db(db: &dyn crate::HirDb) -> Self {
+    fn default_with_db(_db: &dyn crate::HirDb) -> Self {
         let scope = Scope::new_ref(ScopeKind::Block);
 
         Self {
             statements: vec![],
-            location: Location::call_site(db),
+            span: SpanId::CallSite,
             scope,
         }
     }
 }
 
 impl HirElement for Block {
-    fn location(&self, _: &dyn crate::HirDb) -> Location {
-        self.location.clone()
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span.resolve(db)
     }
 }
 
@@ -37,18 +37,20 @@ pub struct Signature {
     pub name: Definition,
     pub parameters: Vec<declaration::Parameter>,
     pub return_type: type_rep::TypeRep,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for Signature {
     fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_signature(self);
         self.attributes(db).accept(db, listener);
         self.docs(db).accept(db, listener);
         self.visibility(db).accept(db, listener);
         self.name(db).accept(db, listener);
         self.parameters(db).accept(db, listener);
         self.return_type(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_signature(self);
     }
 }
 
@@ -84,7 +86,7 @@ impl declaration::Declaration for Signature {
 
 impl HirElement for Signature {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
-        Self::location(*self, db)
+        self.span(db).resolve(db)
     }
 }
 
@@ -106,21 +108,23 @@ pub struct Clause {
     pub name: Definition,
     pub arguments: Vec<pattern::Pattern>,
     pub value: expr::Expr,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for Clause {
     fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_clause(self);
         self.name(db).accept(db, listener);
         self.arguments(db).accept(db, listener);
         self.value(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_clause(self);
     }
 }
 
 impl HirElement for Clause {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
-        Self::location(*self, db)
+        self.span(db).resolve(db)
     }
 }
 
@@ -189,25 +193,25 @@ impl HirElement for BindingGroup {
 /// Defines a "command-like" top level declaration. It does import a module, or use a top level
 /// definition, into the file scope.
 ///
-/// It does have location, and receives a [`Definition`] and a [`Location`].
+/// It does have span, and receives a [`Definition`] and a [`SpanId`].
 #[salsa::tracked]
 pub struct UsingTopLevel {
     pub path: Reference,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for UsingTopLevel {
     fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
         listener.enter_using_top_level(self);
         self.path(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
         listener.exit_using_top_level(self);
     }
 }
 
 impl HirElement for UsingTopLevel {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
-        Self::location(*self, db)
+        self.span(db).resolve(db)
     }
 }
 
@@ -215,12 +219,12 @@ impl HirElement for UsingTopLevel {
 /// compiler, it's used to generate code, and to do other things that are not related to the
 /// main language semantics.
 ///
-/// It does have location, and receives a [`Definition`] and a [`Location`].
+/// It does have span, and receives a [`Definition`] and a [`SpanId`].
 #[salsa::tracked]
 pub struct CommandTopLevel {
     pub path: Definition,
     pub arguments: Vec<expr::Expr>,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for CommandTopLevel {
@@ -228,14 +232,14 @@ impl walking::Walker for CommandTopLevel {
         listener.enter_command_top_level(self);
         self.path(db).accept(db, listener);
         self.arguments(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
         listener.exit_command_top_level(self);
     }
 }
 
 impl HirElement for CommandTopLevel {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
-        Self::location(*self, db)
+        self.span(db).resolve(db)
     }
 }
 
@@ -248,7 +252,11 @@ pub struct Inductive {
     pub parameters: Vec<declaration::Parameter>,
     pub return_type: type_rep::TypeRep,
     pub variants: Vec<Constructor>,
-    pub location: Location,
+
+    /// The inductive types of the mutual block, that are defined together with this type,
+    /// including itself.
+    pub mutual: Vec<Definition>,
+    pub span: SpanId,
     pub scope: Arc<Scope>,
 }
 
@@ -262,7 +270,7 @@ impl walking::Walker for Inductive {
         self.parameters(db).accept(db, listener);
         self.return_type(db).accept(db, listener);
         self.variants(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
         listener.exit_inductive_top_level(self);
     }
 }
@@ -299,10 +307,67 @@ impl declaration::Declaration for Inductive {
 
 impl HirElement for Inductive {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
-        Self::location(*self, db)
+        self.span(db).resolve(db)
+    }
+}
+
+impl Inductive {
+    /// Gets the indices of the inductive family, that are the parameters of its return type,
+    /// like `Nat` in `inductive Vec (a : Type) : Nat -> Type`. Unlike the parameters, they can
+    /// vary between the constructors.
+    pub fn indices(self, db: &dyn crate::HirDb) -> Vec<declaration::Parameter> {
+        match *self.return_type(db).expr {
+            expr::Expr::Pi(pi) => pi.parameters,
+            _ => vec![],
+        }
     }
 }
 
+/// Defines the [`inductive_declaration`] query.
+///
+/// Finds the inductive type declaration of the given [`Definition`], searching in the file
+/// where it's defined, just like the [`type_alias`] query.
+#[salsa::tracked]
+pub fn inductive_declaration(db: &dyn crate::HirDb, definition: Definition) -> Option<Inductive> {
+    let src = definition.location(db).source()?;
+    let id = definition.id(db);
+
+    db.all_packages()
+        .into_iter()
+        .filter(|package| package.all_files(db).contains(&src))
+        .flat_map(|package| db.hir_lower(package, src).contents(db).clone())
+        .find_map(|top_level| match top_level {
+            TopLevel::Inductive(inductive) if inductive.name(db).id(db) == id => Some(inductive),
+            _ => None,
+        })
+}
+
+/// Defines the [`constructor_inductive`] query.
+///
+/// Finds the inductive type that defines the given constructor [`Definition`], searching in the
+/// file where it's defined, just like the [`type_alias`] query.
+#[salsa::tracked]
+pub fn constructor_inductive(db: &dyn crate::HirDb, definition: Definition) -> Option<Inductive> {
+    let src = definition.location(db).source()?;
+    let id = definition.id(db);
+
+    db.all_packages()
+        .into_iter()
+        .filter(|package| package.all_files(db).contains(&src))
+        .flat_map(|package| db.hir_lower(package, src).contents(db).clone())
+        .find_map(|top_level| match top_level {
+            TopLevel::Inductive(inductive)
+                if inductive
+                    .variants(db)
+                    .iter()
+                    .any(|constructor| constructor.name(db).id(db) == id) =>
+            {
+                Some(inductive)
+            }
+            _ => None,
+        })
+}
+
 #[salsa::tracked]
 pub struct Constructor {
     pub kind: ConstructorKind,
@@ -310,16 +375,18 @@ pub struct Constructor {
     pub docs: Vec<declaration::DocString>,
     pub name: Definition,
     pub return_type: type_rep::TypeRep,
-    pub location: Location,
+    pub span: SpanId,
 }
 
 impl walking::Walker for Constructor {
     fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_constructor(self);
         self.attributes(db).accept(db, listener);
         self.docs(db).accept(db, listener);
         self.name(db).accept(db, listener);
         self.return_type(db).accept(db, listener);
-        self.location(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_constructor(self);
     }
 }
 
@@ -355,7 +422,239 @@ impl declaration::Declaration for Constructor {
 
 impl HirElement for Constructor {
     fn location(&self, db: &dyn crate::HirDb) -> Location {
-        Self::location(*self, db)
+        self.span(db).resolve(db)
+    }
+}
+
+/// Defines a top level type synonym. It's a declaration that can be referenced by other
+/// definitions, and it's transparent: the references to it are unfolded into its value when
+/// type checking.
+///
+/// ## Examples
+///
+/// ```hs
+/// type Pair (a : Type) = (a, a)
+/// ```
+#[salsa::tracked]
+pub struct TypeAlias {
+    pub attributes: HashSet<declaration::Attribute, FxBuildHasher>,
+    pub docs: Vec<declaration::DocString>,
+    pub visibility: Spanned<declaration::Vis>,
+    pub name: Definition,
+    pub parameters: Vec<declaration::Parameter>,
+    pub value: type_rep::TypeRep,
+    pub span: SpanId,
+    pub scope: Arc<Scope>,
+}
+
+impl walking::Walker for TypeAlias {
+    fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_type_alias_top_level(self);
+        self.attributes(db).accept(db, listener);
+        self.docs(db).accept(db, listener);
+        self.visibility(db).accept(db, listener);
+        self.name(db).accept(db, listener);
+        self.parameters(db).accept(db, listener);
+        self.value(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_type_alias_top_level(self);
+    }
+}
+
+impl declaration::Declaration for TypeAlias {
+    fn attributes(&self, db: &dyn crate::HirDb) -> HashSet<declaration::Attribute, FxBuildHasher> {
+        Self::attributes(*self, db)
+    }
+
+    fn visibility(&self, db: &dyn crate::HirDb) -> Spanned<declaration::Vis> {
+        Self::visibility(*self, db)
+    }
+
+    fn docs(&self, db: &dyn crate::HirDb) -> Vec<declaration::DocString> {
+        Self::docs(*self, db)
+    }
+
+    fn name(&self, db: &dyn crate::HirDb) -> Definition {
+        Self::name(*self, db)
+    }
+
+    fn parameters(&self, db: &dyn crate::HirDb) -> Vec<declaration::Parameter> {
+        Self::parameters(*self, db)
+    }
+
+    fn type_rep(&self, db: &dyn crate::HirDb) -> Option<type_rep::TypeRep> {
+        Self::value(*self, db).into()
+    }
+
+    fn upcast(&self, _db: &dyn crate::HirDb) -> top_level::DeclDescriptor {
+        top_level::DeclDescriptor::TypeAlias(*self)
+    }
+}
+
+impl HirElement for TypeAlias {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
+    }
+}
+
+/// Defines the [`type_alias`] query.
+///
+/// Finds the type alias that defines the given [`Definition`], searching in the file where
+/// it's defined. It's used by the type checker to unfold the references to the alias.
+///
+/// The definitions are compared by their [`crate::solver::DefinitionId`], just like in the
+/// [`declaration::attributes`] query.
+#[salsa::tracked]
+pub fn type_alias(db: &dyn crate::HirDb, definition: Definition) -> Option<TypeAlias> {
+    let src = definition.location(db).source()?;
+    let id = definition.id(db);
+
+    db.all_packages()
+        .into_iter()
+        .filter(|package| package.all_files(db).contains(&src))
+        .flat_map(|package| db.hir_lower(package, src).contents(db).clone())
+        .find_map(|top_level| match top_level {
+            TopLevel::TypeAlias(alias) if alias.name(db).id(db) == id => Some(alias),
+            _ => None,
+        })
+}
+
+/// Defines a top level trait declaration. It's a declaration that can be referenced by other
+/// definitions, and it has the signatures of the methods that the instances implement.
+///
+/// The superclasses are the constraints that every instance of the trait requires, for the
+/// same type arguments.
+///
+/// ## Examples
+///
+/// ```hs
+/// trait Ord (a : Type) requires Eq a {
+///   compare : a -> a -> Ordering
+/// }
+/// ```
+#[salsa::tracked]
+pub struct Trait {
+    pub attributes: HashSet<declaration::Attribute, FxBuildHasher>,
+    pub docs: Vec<declaration::DocString>,
+    pub visibility: Spanned<declaration::Vis>,
+    pub name: Definition,
+    pub parameters: Vec<declaration::Parameter>,
+    pub superclasses: Vec<declaration::Constraint>,
+    pub methods: Vec<Signature>,
+    pub span: SpanId,
+    pub scope: Arc<Scope>,
+}
+
+impl walking::Walker for Trait {
+    fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_trait_top_level(self);
+        self.attributes(db).accept(db, listener);
+        self.docs(db).accept(db, listener);
+        self.visibility(db).accept(db, listener);
+        self.name(db).accept(db, listener);
+        self.parameters(db).accept(db, listener);
+        self.superclasses(db).accept(db, listener);
+        self.methods(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_trait_top_level(self);
+    }
+}
+
+impl declaration::Declaration for Trait {
+    fn attributes(&self, db: &dyn crate::HirDb) -> HashSet<declaration::Attribute, FxBuildHasher> {
+        Self::attributes(*self, db)
+    }
+
+    fn visibility(&self, db: &dyn crate::HirDb) -> Spanned<declaration::Vis> {
+        Self::visibility(*self, db)
+    }
+
+    fn docs(&self, db: &dyn crate::HirDb) -> Vec<declaration::DocString> {
+        Self::docs(*self, db)
+    }
+
+    fn name(&self, db: &dyn crate::HirDb) -> Definition {
+        Self::name(*self, db)
+    }
+
+    fn parameters(&self, db: &dyn crate::HirDb) -> Vec<declaration::Parameter> {
+        Self::parameters(*self, db)
+    }
+
+    fn type_rep(&self, _db: &dyn crate::HirDb) -> Option<type_rep::TypeRep> {
+        None
+    }
+
+    fn upcast(&self, _db: &dyn crate::HirDb) -> top_level::DeclDescriptor {
+        top_level::DeclDescriptor::Trait(*self)
+    }
+}
+
+impl HirElement for Trait {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
+    }
+}
+
+/// Defines the [`trait_declaration`] query.
+///
+/// Finds the trait declaration that defines the given [`Definition`], searching in the file
+/// where it's defined, just like the [`type_alias`] query.
+#[salsa::tracked]
+pub fn trait_declaration(db: &dyn crate::HirDb, definition: Definition) -> Option<Trait> {
+    let src = definition.location(db).source()?;
+    let id = definition.id(db);
+
+    db.all_packages()
+        .into_iter()
+        .filter(|package| package.all_files(db).contains(&src))
+        .flat_map(|package| db.hir_lower(package, src).contents(db).clone())
+        .find_map(|top_level| match top_level {
+            TopLevel::Trait(trait_decl) if trait_decl.name(db).id(db) == id => Some(trait_decl),
+            _ => None,
+        })
+}
+
+/// Defines a top level instance of a trait. It implements the methods of the trait for the
+/// given type arguments, and it's registered in the instance environment, so it can't be
+/// referenced by name.
+///
+/// ## Examples
+///
+/// ```hs
+/// instance Show Int {
+///   show x = showInt x
+/// }
+/// ```
+#[salsa::tracked]
+pub struct Instance {
+    pub attributes: HashSet<declaration::Attribute, FxBuildHasher>,
+    pub docs: Vec<declaration::DocString>,
+    pub visibility: Spanned<declaration::Vis>,
+    pub trait_reference: Reference,
+    pub arguments: Vec<type_rep::TypeRep>,
+    pub methods: Vec<Clause>,
+    pub span: SpanId,
+    pub scope: Arc<Scope>,
+}
+
+impl walking::Walker for Instance {
+    fn accept<T: HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
+        listener.enter_instance_top_level(self);
+        self.attributes(db).accept(db, listener);
+        self.docs(db).accept(db, listener);
+        self.visibility(db).accept(db, listener);
+        self.trait_reference(db).accept(db, listener);
+        self.arguments(db).accept(db, listener);
+        self.methods(db).accept(db, listener);
+        self.span(db).accept(db, listener);
+        listener.exit_instance_top_level(self);
+    }
+}
+
+impl HirElement for Instance {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        self.span(db).resolve(db)
     }
 }
 
@@ -388,6 +687,9 @@ pub enum TopLevel {
     Command(CommandTopLevel),
     BindingGroup(BindingGroup),
     Inductive(Inductive),
+    TypeAlias(TypeAlias),
+    Trait(Trait),
+    Instance(Instance),
 }
 
 impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for TopLevel {
@@ -398,6 +700,9 @@ impl salsa::DebugWithDb<<crate::Jar as salsa::jar::Jar<'_>>::DynDb> for TopLevel
             TopLevel::Command(command) => command.debug_all(db).fmt(f),
             TopLevel::BindingGroup(binding) => binding.debug_all(db).fmt(f),
             TopLevel::Inductive(data_decl) => data_decl.debug_all(db).fmt(f),
+            TopLevel::TypeAlias(alias) => alias.debug_all(db).fmt(f),
+            TopLevel::Trait(trait_decl) => trait_decl.debug_all(db).fmt(f),
+            TopLevel::Instance(instance) => instance.debug_all(db).fmt(f),
         }
     }
 }
@@ -410,6 +715,9 @@ impl walking::Walker for TopLevel {
             TopLevel::Command(command) => command.accept(db, listener),
             TopLevel::BindingGroup(binding) => binding.accept(db, listener),
             TopLevel::Inductive(data_decl) => data_decl.accept(db, listener),
+            TopLevel::TypeAlias(alias) => alias.accept(db, listener),
+            TopLevel::Trait(trait_decl) => trait_decl.accept(db, listener),
+            TopLevel::Instance(instance) => instance.accept(db, listener),
         }
     }
 }
@@ -422,6 +730,9 @@ impl HirElement for TopLevel {
             Self::Command(downcast) => downcast.location(db),
             Self::BindingGroup(downcast) => downcast.location(db),
             Self::Inductive(downcast) => downcast.location(db),
+            Self::TypeAlias(downcast) => downcast.location(db),
+            Self::Trait(downcast) => downcast.location(db),
+            Self::Instance(downcast) => downcast.location(db),
         }
     }
 }
@@ -433,6 +744,8 @@ pub enum DeclDescriptor {
     Error(HirError),
     BindingGroup(BindingGroup),
     Inductive(Inductive),
+    TypeAlias(TypeAlias),
+    Trait(Trait),
 }
 
 impl HirElement for DeclDescriptor {
@@ -441,6 +754,8 @@ impl HirElement for DeclDescriptor {
             Self::Error(downcast) => downcast.label.clone(),
             Self::BindingGroup(downcast) => downcast.location(db),
             Self::Inductive(downcast) => downcast.location(db),
+            Self::TypeAlias(downcast) => downcast.location(db),
+            Self::Trait(downcast) => downcast.location(db),
         }
     }
 }
@@ -455,6 +770,8 @@ impl TryFrom<DeclDescriptor> for TopLevel {
             DeclDescriptor::Error(downcast) => Self::Error(downcast),
             DeclDescriptor::BindingGroup(downcast) => Self::BindingGroup(downcast),
             DeclDescriptor::Inductive(downcast) => Self::Inductive(downcast),
+            DeclDescriptor::TypeAlias(downcast) => Self::TypeAlias(downcast),
+            DeclDescriptor::Trait(downcast) => Self::Trait(downcast),
         })
     }
 }
@@ -469,8 +786,11 @@ impl TryFrom<TopLevel> for DeclDescriptor {
             TopLevel::Error(downcast) => Self::Error(downcast),
             TopLevel::Using(_) => return Err(()),
             TopLevel::Command(_) => return Err(()),
+            TopLevel::Instance(_) => return Err(()),
             TopLevel::BindingGroup(downcast) => Self::BindingGroup(downcast),
             TopLevel::Inductive(downcast) => Self::Inductive(downcast),
+            TopLevel::TypeAlias(downcast) => Self::TypeAlias(downcast),
+            TopLevel::Trait(downcast) => Self::Trait(downcast),
         })
     }
 }
@@ -486,6 +806,8 @@ impl declaration::Declaration for DeclDescriptor {
             Self::Error(_) => Default::default(),
             Self::BindingGroup(downcast) => downcast.attributes(db),
             Self::Inductive(downcast) => downcast.attributes(db),
+            Self::TypeAlias(downcast) => downcast.attributes(db),
+            Self::Trait(downcast) => downcast.attributes(db),
         }
     }
 
@@ -494,6 +816,8 @@ impl declaration::Declaration for DeclDescriptor {
             Self::Error(_) => Default::default(),
             Self::BindingGroup(downcast) => downcast.visibility(db),
             Self::Inductive(downcast) => downcast.visibility(db),
+            Self::TypeAlias(downcast) => downcast.visibility(db),
+            Self::Trait(downcast) => downcast.visibility(db),
         }
     }
 
@@ -502,6 +826,8 @@ impl declaration::Declaration for DeclDescriptor {
             Self::Error(_) => Default::default(),
             Self::BindingGroup(downcast) => downcast.docs(db),
             Self::Inductive(downcast) => downcast.docs(db),
+            Self::TypeAlias(downcast) => downcast.docs(db),
+            Self::Trait(downcast) => downcast.docs(db),
         }
     }
 
@@ -510,6 +836,8 @@ impl declaration::Declaration for DeclDescriptor {
             Self::Error(_) => default_with_db(db),
             Self::BindingGroup(downcast) => downcast.name(db),
             Self::Inductive(downcast) => downcast.name(db),
+            Self::TypeAlias(downcast) => downcast.name(db),
+            Self::Trait(downcast) => downcast.name(db),
         }
     }
 
@@ -518,6 +846,8 @@ impl declaration::Declaration for DeclDescriptor {
             Self::Error(_) => Default::default(),
             Self::BindingGroup(downcast) => downcast.parameters(db),
             Self::Inductive(downcast) => downcast.parameters(db),
+            Self::TypeAlias(downcast) => downcast.parameters(db),
+            Self::Trait(downcast) => downcast.parameters(db),
         }
     }
 
@@ -526,6 +856,8 @@ impl declaration::Declaration for DeclDescriptor {
             Self::Error(_) => Default::default(),
             Self::BindingGroup(downcast) => downcast.type_rep(db),
             Self::Inductive(downcast) => downcast.type_rep(db),
+            Self::TypeAlias(downcast) => downcast.type_rep(db),
+            Self::Trait(downcast) => downcast.type_rep(db),
         }
     }
 
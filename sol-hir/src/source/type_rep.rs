@@ -29,6 +29,8 @@ impl HirElement for TypeRep {
 
 impl walking::Walker for TypeRep {
     fn accept<T: walking::HirListener>(self, db: &dyn crate::HirDb, listener: &mut T) {
-        self.expr.accept(db, listener);
+        listener.enter_type_rep(self.clone());
+        self.expr.clone().accept(db, listener);
+        listener.exit_type_rep(self);
     }
 }
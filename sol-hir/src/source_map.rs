@@ -0,0 +1,101 @@
+//! Defines the source map, the bidirectional mapping between the syntax nodes and the HIR
+//! elements that they're lowered into. It's recorded by the lowering, as each node is lowered,
+//! so the IDE features, and the code actions, can translate between the tree-sitter nodes and
+//! the HIR elements without searching by their ranges each time.
+//!
+//! The HIR doesn't depend on tree-sitter, so the syntax nodes are stored as [`SyntaxPtr`]s,
+//! that are resolved against the syntax tree by the lowering crate.
+
+use fxhash::FxHashMap;
+
+use crate::source::{
+    expr::Expr, pattern::Pattern, stmt::Stmt, top_level::TopLevel, HirElement, Location,
+};
+
+/// A pointer to a syntax node, that is its kind, and its byte range. It's stable while the
+/// syntax tree doesn't change, and it's resolved back into the node by the lowering crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SyntaxPtr {
+    pub kind_id: u16,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+/// A HIR element that a syntax node was lowered into.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HirNode {
+    TopLevel(TopLevel),
+    Expr(Expr),
+    Pattern(Pattern),
+    Stmt(Stmt),
+}
+
+impl HirElement for HirNode {
+    fn location(&self, db: &dyn crate::HirDb) -> Location {
+        match self {
+            HirNode::TopLevel(top_level) => top_level.location(db),
+            HirNode::Expr(expr) => expr.location(db),
+            HirNode::Pattern(pattern) => pattern.location(db),
+            HirNode::Stmt(stmt) => stmt.location(db),
+        }
+    }
+}
+
+/// An entry of the source map, that is a syntax node, and the HIR element that it was lowered
+/// into.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceMapEntry {
+    pub node: SyntaxPtr,
+    pub hir: HirNode,
+}
+
+/// The entries of the source map of the lowering of a file.
+#[salsa::accumulator]
+pub struct SourceMapEntries(SourceMapEntry);
+
+/// The source map of a single file, from the HIR locations to the syntax nodes, and from the
+/// syntax nodes to the HIR elements.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    nodes: FxHashMap<Location, SyntaxPtr>,
+    elements: FxHashMap<SyntaxPtr, Vec<HirNode>>,
+}
+
+impl SourceMap {
+    /// Creates the source map from the accumulated entries. The entries are recorded after
+    /// their children are lowered, so the innermost nodes are kept for the locations that are
+    /// shared, like the ones of the macro expansions.
+    pub fn new(db: &dyn crate::HirDb, entries: Vec<SourceMapEntry>) -> Self {
+        let mut source_map = Self::default();
+        for SourceMapEntry { node, hir } in entries {
+            let location = hir.location(db);
+            if !matches!(location, Location::CallSite) {
+                source_map.nodes.entry(location).or_insert(node);
+            }
+
+            let elements = source_map.elements.entry(node).or_default();
+            if !elements.contains(&hir) {
+                elements.push(hir);
+            }
+        }
+        source_map
+    }
+
+    /// Gets the syntax node that the HIR element at the location was lowered from.
+    pub fn node_for_location(&self, location: &Location) -> Option<SyntaxPtr> {
+        self.nodes.get(location).copied()
+    }
+
+    /// Gets the HIR elements that the syntax node was lowered into, in the lowering order.
+    pub fn hir_for_node(&self, node: SyntaxPtr) -> &[HirNode] {
+        self.elements
+            .get(&node)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Counts the HIR elements that were lowered from the syntax nodes of the file.
+    pub fn element_count(&self) -> usize {
+        self.elements.values().map(Vec::len).sum()
+    }
+}
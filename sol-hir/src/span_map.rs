@@ -0,0 +1,199 @@
+//! Defines the span ids of the HIR nodes, that are stored by the HIR instead of the locations,
+//! and the span map, the side-table that resolves them into the locations of the current
+//! revision.
+//!
+//! The span ids are relative to the top-level declaration that contains them, and the
+//! declarations are keyed by their names, not by their positions. So inserting, removing or
+//! moving a declaration, or editing the whitespace and the comments between the declarations,
+//! doesn't change the HIR of the other declarations, and salsa can cut off the downstream
+//! queries early.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use fxhash::FxHashMap;
+use sol_diagnostic::TextSource;
+use sol_syntax::Source;
+use tree_sitter::Node;
+
+use crate::{source::Location, walking};
+
+/// The id of the span of a HIR node. It's the byte range of the node, relative to the start of
+/// the top-level declaration that contains it, that is its anchor. The anchor is identified by
+/// the [`AnchorKey`] of the declaration, that only depends on its kind and its name.
+#[derive(Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SpanId {
+    /// A byte range relative to an anchor of the source file.
+    Anchored {
+        source: Source,
+        anchor: AnchorKey,
+        start: u32,
+        end: u32,
+    },
+
+    /// A node that doesn't come from the source code, like the desugared ones.
+    #[default]
+    CallSite,
+}
+
+impl SpanId {
+    /// Creates the span id of the byte range of the source file, it's anchored to the last
+    /// top-level declaration that starts before the range.
+    pub fn new(db: &dyn crate::HirDb, source: Source, start: usize, end: usize) -> Self {
+        let map = span_map(db, source);
+        let (anchor, base) = map.anchors[map.anchor_of(start)];
+
+        Self::Anchored {
+            source,
+            anchor,
+            start: (start - base) as u32,
+            end: end.saturating_sub(base) as u32,
+        }
+    }
+
+    /// Creates the span id of the location, the locations that aren't text ranges are created
+    /// as [`SpanId::CallSite`].
+    pub fn of(db: &dyn crate::HirDb, location: &Location) -> Self {
+        match location.source() {
+            Some(source) => Self::new(
+                db,
+                source,
+                location.start().offset(),
+                location.end().offset(),
+            ),
+            None => Self::CallSite,
+        }
+    }
+
+    /// Resolves the span id into the location of the current revision, through the span map of
+    /// its source file.
+    pub fn resolve(self, db: &dyn crate::HirDb) -> Location {
+        let Self::Anchored {
+            source,
+            anchor,
+            start,
+            end,
+        } = self
+        else {
+            return Location::call_site(db);
+        };
+
+        let map = span_map(db, source);
+        let base = map.starts.get(&anchor).copied().unwrap_or_default();
+
+        Location::new(
+            source,
+            map.text.clone(),
+            base + start as usize,
+            base + end as usize,
+        )
+    }
+}
+
+impl Debug for SpanId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Anchored { .. } => write!(f, "Span"),
+            Self::CallSite => write!(f, "CallSite"),
+        }
+    }
+}
+
+impl walking::Walker for SpanId {
+    fn accept<T: walking::HirListener>(self, _db: &dyn crate::HirDb, _listener: &mut T) {}
+}
+
+/// The key of a top-level declaration, it's the hash of its kind and its name, with the
+/// number of the declarations before it with the same kind and name, like the clauses of the
+/// same function. The start of the file, before any declaration, has the key `0`.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnchorKey(pub u64);
+
+impl std::fmt::Display for AnchorKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+/// The side-table of a source file, that resolves the [`SpanId`]s into locations. It has the
+/// key and the start of each top-level declaration, in the order of the file, and the first
+/// anchor is the start of the file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpanMap {
+    pub text: TextSource,
+    pub anchors: Vec<(AnchorKey, usize)>,
+    pub starts: BTreeMap<AnchorKey, usize>,
+}
+
+impl SpanMap {
+    /// Finds the index of the last anchor that starts before the offset.
+    pub fn anchor_of(&self, offset: usize) -> usize {
+        self.anchors
+            .partition_point(|(_, start)| *start <= offset)
+            .saturating_sub(1)
+    }
+}
+
+/// Defines the [`span_map`] query.
+///
+/// Collects the anchors of the source file, that are the starts of its top-level declarations,
+/// keyed by their names, ignoring the comments between them.
+#[salsa::tracked]
+pub fn span_map(db: &dyn crate::HirDb, source: Source) -> SpanMap {
+    let contents = source.source_text(db);
+    let text = TextSource::new(
+        source.file_path(db).to_string_lossy(),
+        Arc::new(contents.to_string()),
+    );
+
+    let root = source.syntax_node(db).root_node();
+    let mut cursor = root.walk();
+    let mut occurrences = FxHashMap::<String, usize>::default();
+    let mut anchors = vec![(AnchorKey::default(), 0)];
+    for node in root.children(&mut cursor).filter(|node| !node.is_extra()) {
+        let name = declaration_name(node, contents.as_bytes());
+        let occurrence = occurrences.entry(name.clone()).or_default();
+        let key = AnchorKey(fxhash::hash64(&(name, *occurrence)).max(1));
+        *occurrence += 1;
+
+        anchors.push((key, node.start_byte()));
+    }
+
+    let starts = anchors.iter().copied().collect();
+
+    SpanMap {
+        text,
+        anchors,
+        starts,
+    }
+}
+
+/// Renders the kind and the name of the top-level declaration, that identifies it regardless
+/// of its position. The instances are also named by their arguments, and the mutual blocks by
+/// the name of their first inductive.
+fn declaration_name(node: Node, contents: &[u8]) -> String {
+    let text = |node: Node| node.utf8_text(contents).unwrap_or_default().to_string();
+    let mut cursor = node.walk();
+
+    let name = match node.kind() {
+        "mutual" => node
+            .child_by_field_name("inductive")
+            .map(|inductive| declaration_name(inductive, contents)),
+        "using" => node.child_by_field_name("path").map(text),
+        "command" => node.child_by_field_name("command").map(text),
+        "instance" => Some(
+            std::iter::once(node.child_by_field_name("name"))
+                .flatten()
+                .chain(node.children_by_field_name("argument", &mut cursor))
+                .map(text)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        _ => node.child_by_field_name("name").map(text),
+    };
+
+    format!("{}:{}", node.kind(), name.unwrap_or_default())
+}
@@ -0,0 +1,99 @@
+//! Defines the workspace symbols search, that finds the definitions of all registered packages
+//! by their names. It's used by the language server and the command line.
+
+use crate::{
+    solver::{Definition, DefinitionKind},
+    source::Location,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HirSymbol {
+    pub definition: Definition,
+    pub name: String,
+    pub kind: DefinitionKind,
+
+    /// The module that contains the symbol, it's the qualified prefix of the name, or the
+    /// module of the file, if the name is not qualified.
+    pub container: String,
+    pub location: Location,
+}
+
+/// The query string of the [`workspace_symbols`] search. It's interned, so the search can be
+/// memoized by the query.
+#[salsa::interned]
+pub struct SymbolQuery {
+    #[return_ref]
+    pub text: String,
+}
+
+/// Searches the definitions of all registered packages, whose names fuzzy-matches the given
+/// query. The characters of the query should appear in the name, in the same order, ignoring
+/// the case, like `fmap` matches `flatMap`.
+///
+/// The symbols are sorted by how good is the match, so the exact and prefix matches come
+/// first.
+#[salsa::tracked]
+pub fn workspace_symbols(db: &dyn crate::HirDb, query: SymbolQuery) -> Vec<HirSymbol> {
+    let query = query.text(db);
+    let mut symbols = vec![];
+
+    for package in db.all_packages() {
+        for file in package.all_files(db) {
+            let hir_source = db.hir_lower(package, file);
+
+            for (name, definition) in hir_source.scope(db).all_definitions() {
+                let Some(score) = fuzzy_score(query, &name) else {
+                    continue;
+                };
+
+                let segments = definition.name(db).segments(db);
+                let container = match segments.split_last() {
+                    Some((_, prefix)) if !prefix.is_empty() => prefix
+                        .iter()
+                        .map(|segment| segment.contents(db))
+                        .collect::<Vec<_>>()
+                        .join("."),
+                    _ => file.module_name(db).clone(),
+                };
+
+                let symbol = HirSymbol {
+                    definition,
+                    name,
+                    kind: definition.kind(db),
+                    container,
+                    location: definition.location(db),
+                };
+
+                symbols.push((score, symbol));
+            }
+        }
+    }
+
+    symbols.sort_by_key(|(score, symbol)| (*score, symbol.name.clone()));
+    symbols.into_iter().map(|(_, symbol)| symbol).collect()
+}
+
+/// Scores how well the query matches the name, the lower the better. It returns [`None`] if
+/// the query characters doesn't appear in the name in the same order.
+///
+/// The score is the number of characters skipped in the name, between the first and the last
+/// matched characters, plus the position of the first matched character.
+fn fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut name_chars = name.chars().map(|c| c.to_ascii_lowercase()).enumerate();
+    let mut first = None;
+    let mut last = 0;
+
+    for query_char in query.chars().map(|c| c.to_ascii_lowercase()) {
+        let (index, _) = name_chars.find(|(_, name_char)| *name_char == query_char)?;
+        first.get_or_insert(index);
+        last = index;
+    }
+
+    let first = first?;
+
+    Some(first + (last - first + 1).saturating_sub(query.chars().count()))
+}
@@ -0,0 +1,71 @@
+//! Defines the `todo` lint, that lists the remaining `todo` placeholders of a package, like in:
+//!
+//! ```sol
+//! parse (input : String) : Maybe Json = todo
+//! ```
+//!
+//! Each placeholder is reported as a warning with the `solc::todo` code, so it can be denied
+//! in the `[lints]` table of the manifest, like `todo = "deny"`, to check that a release
+//! doesn't have unfinished code.
+
+use sol_diagnostic::{diagnostic, Diagnostic};
+
+use crate::{
+    package::Package,
+    source::{
+        expr::{CallExpr, Callee},
+        Location,
+    },
+    span_map::SpanId,
+    walking::{HirListener, Walker},
+};
+
+/// A `todo` placeholder that remains in the package.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("remaining `todo`")]
+#[diagnostic(code(solc::todo), severity(Warning), url(docsrs))]
+pub struct TodoWarning {
+    #[source_code]
+    #[label = "not yet implemented"]
+    pub location: Location,
+}
+
+/// Collects the `todo` placeholders of the files.
+#[derive(Default)]
+struct TodoCollector {
+    todos: Vec<SpanId>,
+}
+
+impl HirListener for TodoCollector {
+    fn enter_call_expr(&mut self, call_expr: CallExpr) {
+        if call_expr.callee == Callee::Todo {
+            self.todos.push(call_expr.span);
+        }
+    }
+}
+
+/// Defines the [`todos`] query.
+///
+/// Gets the locations of the `todo` placeholders of all the files of the package, in the
+/// order of the files.
+#[salsa::tracked]
+pub fn todos(db: &dyn crate::HirDb, package: Package) -> Vec<Location> {
+    let mut collector = TodoCollector::default();
+    for file in package.all_files(db) {
+        db.hir_lower(package, file).accept(db, &mut collector);
+    }
+
+    collector
+        .todos
+        .into_iter()
+        .map(|span| span.resolve(db))
+        .collect()
+}
+
+/// Reports the `todo` placeholders of the package, as the warnings of the `todo` lint.
+pub fn todo_lint(db: &dyn crate::HirDb, package: Package) -> Vec<Diagnostic> {
+    todos(db, package)
+        .into_iter()
+        .map(|location| diagnostic(TodoWarning { location }))
+        .collect()
+}
@@ -0,0 +1,97 @@
+//! Defines the unicode handling of the identifiers, that can be written with any unicode letter,
+//! like `α`. They are normalized to NFC, so the same name written with composed or decomposed
+//! code points, like `é` and `e\u{301}`, resolves to the same definition and is displayed the
+//! same way.
+
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+use crate::source::Location;
+
+/// Identifier that mixes ASCII letters with a character that looks like an ASCII letter, like the
+/// cyrillic `а` in `pаir`, that would be confused with another identifier.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("identifier `{name}` contains the character `{confusable}`, that looks like `{ascii}`")]
+#[diagnostic(code(solc::confusable_identifier), severity(Warning), url(docsrs))]
+pub struct ConfusableIdentifier {
+    pub name: String,
+    pub confusable: char,
+    pub ascii: char,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// The characters that looks like ASCII letters, with the letter that they look like. It's a
+/// subset of the unicode confusables, of the greek and cyrillic letters.
+const CONFUSABLES: &[(char, char)] = &[
+    ('а', 'a'),
+    ('в', 'B'),
+    ('е', 'e'),
+    ('к', 'k'),
+    ('м', 'M'),
+    ('н', 'H'),
+    ('о', 'o'),
+    ('р', 'p'),
+    ('с', 'c'),
+    ('т', 'T'),
+    ('у', 'y'),
+    ('х', 'x'),
+    ('і', 'i'),
+    ('ј', 'j'),
+    ('ѕ', 's'),
+    ('А', 'A'),
+    ('В', 'B'),
+    ('Е', 'E'),
+    ('К', 'K'),
+    ('М', 'M'),
+    ('Н', 'H'),
+    ('О', 'O'),
+    ('Р', 'P'),
+    ('С', 'C'),
+    ('Т', 'T'),
+    ('Х', 'X'),
+    ('ο', 'o'),
+    ('ν', 'v'),
+    ('Α', 'A'),
+    ('Β', 'B'),
+    ('Ε', 'E'),
+    ('Ζ', 'Z'),
+    ('Η', 'H'),
+    ('Ι', 'I'),
+    ('Κ', 'K'),
+    ('Μ', 'M'),
+    ('Ν', 'N'),
+    ('Ο', 'O'),
+    ('Ρ', 'P'),
+    ('Τ', 'T'),
+    ('Υ', 'Y'),
+    ('Χ', 'X'),
+];
+
+/// Normalizes the identifier to NFC. It skips the normalization if the identifier is already
+/// normalized, that is the case of the ASCII identifiers.
+pub fn normalize(name: &str) -> String {
+    match is_nfc_quick(name.chars()) {
+        IsNormalized::Yes => name.to_string(),
+        _ => name.nfc().collect(),
+    }
+}
+
+/// Finds the first character of the identifier that looks like an ASCII letter, returning it
+/// with the letter that it looks like.
+///
+/// The identifiers without ASCII letters, like `αβ`, aren't confusable, as they're written in a
+/// single script, so they can't be mistaken by another identifier.
+pub fn find_confusable(name: &str) -> Option<(char, char)> {
+    if !name.chars().any(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    name.chars().find_map(|c| {
+        CONFUSABLES
+            .iter()
+            .find(|(confusable, _)| *confusable == c)
+            .copied()
+    })
+}
@@ -0,0 +1,140 @@
+//! Defines the `unused_definition` lint, that lists the top-level functions of a package that
+//! aren't referenced by any file of the workspace, like `helper` in:
+//!
+//! ```sol
+//! helper (x : Int) : Int = x + 1
+//!
+//! main = println "hello"
+//! ```
+//!
+//! The recursive calls of a function don't count as its uses. The `main` function, the `@test`,
+//! `@export` and `@keep` definitions, and the exported definitions of the libraries are used
+//! from outside of the workspace, so they're never reported.
+//!
+//! Each unused definition is reported as a warning with the `solc::unused_definition` code, so
+//! it can be configured in the `[lints]` table of the manifest, like
+//! `unused_definition = "deny"`.
+
+use std::collections::HashSet;
+
+use sol_diagnostic::{diagnostic, Diagnostic};
+
+use crate::{
+    lenses::TEST_ATTRIBUTE,
+    package::{Package, PackageKind},
+    solver::{Definition, Reference},
+    source::{
+        top_level::{BindingGroup, Signature, TopLevel},
+        Location,
+    },
+    walking::{HirListener, Walker},
+};
+
+/// A top-level function that isn't used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, thiserror::Error, miette::Diagnostic)]
+#[error("unused definition `{name}`")]
+#[diagnostic(code(solc::unused_definition), severity(Warning), url(docsrs))]
+pub struct UnusedDefinitionWarning {
+    pub name: String,
+
+    #[source_code]
+    #[label = "never used"]
+    pub location: Location,
+}
+
+/// Collects the references of the files, with the binding group that contains them, so the
+/// recursive calls can be told apart.
+#[derive(Default)]
+struct UsageCollector {
+    current: Option<BindingGroup>,
+    references: Vec<(Option<BindingGroup>, Reference)>,
+}
+
+impl HirListener for UsageCollector {
+    fn enter_binding_top_level(&mut self, binding: BindingGroup) {
+        self.current = Some(binding);
+    }
+
+    fn exit_binding_top_level(&mut self, _: BindingGroup) {
+        self.current = None;
+    }
+
+    fn visit_reference(&mut self, reference: Reference) {
+        self.references.push((self.current, reference));
+    }
+}
+
+/// Defines the [`unused_definitions`] query.
+///
+/// Gets the top-level functions of the package that aren't referenced by any file of the
+/// workspace, in the order of the files.
+#[salsa::tracked]
+pub fn unused_definitions(db: &dyn crate::HirDb, package: Package) -> Vec<Definition> {
+    let mut collector = UsageCollector::default();
+    for package in db.all_packages() {
+        for file in package.all_files(db) {
+            db.hir_lower(package, file).accept(db, &mut collector);
+        }
+    }
+
+    let used = collector
+        .references
+        .into_iter()
+        .filter_map(|(group, reference)| {
+            let id = reference.definition(db).id(db);
+            let recursive = group.is_some_and(|group| group.signature(db).name(db).id(db) == id);
+
+            (!recursive).then_some(id)
+        })
+        .collect::<HashSet<_>>();
+
+    let mut unused = vec![];
+    for file in package.all_files(db) {
+        for top_level in db.hir_lower(package, file).contents(db) {
+            let TopLevel::BindingGroup(group) = top_level else {
+                continue;
+            };
+
+            let signature = group.signature(db);
+            let definition = signature.name(db);
+            if !used.contains(&definition.id(db)) && !is_used_outside(db, package, signature) {
+                unused.push(definition);
+            }
+        }
+    }
+
+    unused
+}
+
+/// Checks if the function is used from outside of the workspace, like the `main` function, the
+/// tests, and the exported definitions of the libraries.
+fn is_used_outside(db: &dyn crate::HirDb, package: Package, signature: Signature) -> bool {
+    let name = signature.name(db).to_string(db);
+    let has_attribute = |expected: &str| {
+        signature
+            .attributes(db)
+            .iter()
+            .any(|attribute| attribute.name(db).to_string(db).as_deref() == Some(expected))
+    };
+    let is_exported = package.kind(db) == PackageKind::Library && package.is_exported(db, &name);
+
+    name.rsplit('.').next() == Some("main")
+        || is_exported
+        || has_attribute(TEST_ATTRIBUTE)
+        || has_attribute("export")
+        || has_attribute("keep")
+}
+
+/// Reports the unused definitions of the package, as the warnings of the `unused_definition`
+/// lint.
+pub fn unused_definition_lint(db: &dyn crate::HirDb, package: Package) -> Vec<Diagnostic> {
+    unused_definitions(db, package)
+        .into_iter()
+        .map(|definition| {
+            diagnostic(UnusedDefinitionWarning {
+                name: definition.to_string(db),
+                location: definition.location(db),
+            })
+        })
+        .collect()
+}
@@ -46,7 +46,11 @@ impl<T: Walker> Walker for Box<T> {
 /// and the `exit` method is called when the node is left.
 ///
 /// All functions in this trait have a default implementation that does nothing,
-/// so all the functions have the `()` return type.
+/// so all the functions have the `()` return type. The [`Walker`] implementations
+/// do the depth-first traversal of every node, so the listeners only override the
+/// nodes they care about.
+///
+/// To produce a transformed HIR, see [`crate::rewriting::HirRewriter`].
 #[allow(dead_code, unused_variables, clippy::boxed_local)]
 #[rustfmt::skip]
 pub trait HirListener {
@@ -62,11 +66,15 @@ pub trait HirListener {
     fn enter_call_expr(&mut self, call_expr: expr::CallExpr) {}
     fn enter_ann_expr(&mut self, call_expr: expr::AnnExpr) {}
     fn enter_lam_expr(&mut self, call_expr: expr::LamExpr) {}
+    fn enter_let_expr(&mut self, let_expr: expr::LetExpr) {}
+    fn enter_record_expr(&mut self, record_expr: expr::RecordExpr) {}
     fn enter_match_expr(&mut self, match_expr: expr::MatchExpr) {}
     fn enter_upgrade_expr(&mut self, type_rep: Box<type_rep::TypeRep>) {}
     fn enter_pi(&mut self, type_rep: expr::Pi) {}
     fn enter_sigma(&mut self, type_rep: expr::Pi) {}
     fn enter_fun(&mut self, type_rep: expr::Pi) {}
+    fn enter_match_arm(&mut self, match_arm: expr::MatchArm) {}
+    fn enter_type_rep(&mut self, type_rep: type_rep::TypeRep) {}
 
     // SECTION: stmt
     fn visit_empty_stmt(&mut self) {}
@@ -83,11 +91,23 @@ pub trait HirListener {
     fn enter_constructor_pattern(&mut self, constructor: pattern::ConstructorPattern) {}
     fn enter_binding_pattern(&mut self, binding: pattern::BindingPattern) {}
 
+    // SECTION: declaration
+    fn enter_attribute(&mut self, attribute: declaration::Attribute) {}
+    fn enter_doc_string(&mut self, doc_string: declaration::DocString) {}
+    fn enter_parameter(&mut self, parameter: declaration::Parameter) {}
+    fn enter_constraint(&mut self, constraint: declaration::Constraint) {}
+
     // SECTION: top_level
+    fn enter_signature(&mut self, signature: top_level::Signature) {}
+    fn enter_clause(&mut self, clause: top_level::Clause) {}
+    fn enter_constructor(&mut self, constructor: top_level::Constructor) {}
     fn enter_using_top_level(&mut self, using: top_level::UsingTopLevel) {}
     fn enter_binding_top_level(&mut self, binding: top_level::BindingGroup) {}
     fn enter_command_top_level(&mut self, command: top_level::CommandTopLevel) {}
     fn enter_inductive_top_level(&mut self, inductive: top_level::Inductive) {}
+    fn enter_type_alias_top_level(&mut self, alias: top_level::TypeAlias) {}
+    fn enter_trait_top_level(&mut self, trait_decl: top_level::Trait) {}
+    fn enter_instance_top_level(&mut self, instance: top_level::Instance) {}
 
     // SECTION: type_rep
 
@@ -97,10 +117,14 @@ pub trait HirListener {
     fn exit_call_expr(&mut self, call_expr: expr::CallExpr) {}
     fn exit_ann_expr(&mut self, call_expr: expr::AnnExpr) {}
     fn exit_lam_expr(&mut self, call_expr: expr::LamExpr) {}
+    fn exit_let_expr(&mut self, let_expr: expr::LetExpr) {}
+    fn exit_record_expr(&mut self, record_expr: expr::RecordExpr) {}
     fn exit_match_expr(&mut self, match_expr: expr::MatchExpr) {}
     fn exit_pi(&mut self, type_rep: expr::Pi) {}
     fn exit_sigma(&mut self, type_rep: expr::Pi) {}
     fn exit_fun(&mut self, type_rep: expr::Pi) {}
+    fn exit_match_arm(&mut self, match_arm: expr::MatchArm) {}
+    fn exit_type_rep(&mut self, type_rep: type_rep::TypeRep) {}
 
     // SECTION: stmt
     fn exit_let_stmt(&mut self, let_stmt: stmt::LetStmt) {}
@@ -115,9 +139,21 @@ pub trait HirListener {
     fn exit_constructor_pattern(&mut self, constructor: pattern::ConstructorPattern) {}
     fn exit_binding_pattern(&mut self, binding: pattern::BindingPattern) {}
 
+    // SECTION: declaration
+    fn exit_attribute(&mut self, attribute: declaration::Attribute) {}
+    fn exit_doc_string(&mut self, doc_string: declaration::DocString) {}
+    fn exit_parameter(&mut self, parameter: declaration::Parameter) {}
+    fn exit_constraint(&mut self, constraint: declaration::Constraint) {}
+
     // SECTION: top_level
+    fn exit_signature(&mut self, signature: top_level::Signature) {}
+    fn exit_clause(&mut self, clause: top_level::Clause) {}
+    fn exit_constructor(&mut self, constructor: top_level::Constructor) {}
     fn exit_using_top_level(&mut self, using: top_level::UsingTopLevel) {}
     fn exit_binding_top_level(&mut self, binding: top_level::BindingGroup) {}
     fn exit_command_top_level(&mut self, command: top_level::CommandTopLevel) {}
     fn exit_inductive_top_level(&mut self, inductive: top_level::Inductive) {}
+    fn exit_type_alias_top_level(&mut self, alias: top_level::TypeAlias) {}
+    fn exit_trait_top_level(&mut self, trait_decl: top_level::Trait) {}
+    fn exit_instance_top_level(&mut self, instance: top_level::Instance) {}
 }
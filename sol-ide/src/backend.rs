@@ -1,6 +1,13 @@
-use std::sync::{Arc, RwLock, RwLockReadGuard};
+use std::sync::{Arc, OnceLock, RwLock, RwLockReadGuard};
 
-use tower_lsp::{async_trait, jsonrpc::Result, lsp_types::*, LanguageServer};
+use salsa_2022::ParallelDatabase;
+use sol_vfs::line_index::{LineCol, LineIndex, PositionEncoding};
+use tower_lsp::{
+    async_trait,
+    jsonrpc::{Error, ErrorCode, Result},
+    lsp_types::*,
+    LanguageServer,
+};
 
 use crate::{functions::*, LEGEND_TYPE};
 
@@ -9,6 +16,14 @@ pub struct Backend {
     pub client: tower_lsp::Client,
     pub db: Arc<RwLock<sol_driver::RootDb>>,
     pub workspace: Arc<crate::workspace::Workspace>,
+
+    /// The global environment of the elaboration, it's kept between the requests, so the
+    /// queries that depend on it are reused.
+    pub global_env: sol_thir::shared::GlobalEnv,
+
+    /// The encoding of the columns of the positions, it's negotiated with the client in the
+    /// `initialize` request.
+    pub encoding: Arc<OnceLock<PositionEncoding>>,
 }
 
 /// This struct represents a file that is opened in the editor.
@@ -30,6 +45,45 @@ impl Backend {
         self.db.read().unwrap()
     }
 
+    /// Runs the request in a snapshot of the database, so it doesn't hold the lock, and it's
+    /// cancelled when a new edit arrives. The cancelled requests fail with `ContentModified`,
+    /// so the client can request them again.
+    pub fn cancellable<T>(&self, f: impl FnOnce(&sol_driver::RootDb) -> T) -> Result<T> {
+        let snapshot = self.db().snapshot();
+
+        snapshot.cancellable(f).map_err(|_| Error {
+            code: ErrorCode::ContentModified,
+            message: "the content was modified".into(),
+            data: None,
+        })
+    }
+
+    /// Gets the negotiated encoding of the columns, it's UTF-16 until the client is initialized,
+    /// as it's the default of the protocol.
+    pub fn encoding(&self) -> PositionEncoding {
+        self.encoding
+            .get()
+            .copied()
+            .unwrap_or(PositionEncoding::Utf16)
+    }
+
+    /// Converts the byte offset to a position, with the columns in the negotiated encoding.
+    pub fn position(&self, line_index: &LineIndex, offset: usize) -> Option<Position> {
+        let line_col = line_index.line_col(offset, self.encoding())?;
+
+        Some(Position::new(line_col.line, line_col.column))
+    }
+
+    /// Converts the position, with the columns in the negotiated encoding, to a byte offset.
+    pub fn offset(&self, line_index: &LineIndex, position: Position) -> Option<usize> {
+        let line_col = LineCol {
+            line: position.line,
+            column: position.character,
+        };
+
+        line_index.offset(line_col, self.encoding())
+    }
+
     /// This function is called when a file is opened or changed. It will parse the file and
     /// send the diagnostics to the client.
     pub async fn on_change(&self, params: TextDocumentItem) {
@@ -45,7 +99,20 @@ impl Backend {
         InitializeResult {
             server_info: None,
             capabilities: ServerCapabilities {
-                inlay_hint_provider: Some(OneOf::Left(false)),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::SOURCE_ORGANIZE_IMPORTS,
+                            CodeActionKind::QUICKFIX,
+                        ]),
+                        work_done_progress_options: Default::default(),
+                        resolve_provider: Some(false),
+                    },
+                )),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(false),
+                }),
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
                     TextDocumentSyncKind::FULL,
                 )),
@@ -93,6 +160,9 @@ impl Backend {
                 definition_provider: Some(OneOf::Left(true)),
                 references_provider: Some(OneOf::Left(true)),
                 rename_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
                 ..ServerCapabilities::default()
             },
         }
@@ -102,8 +172,32 @@ impl Backend {
 /// This trait is used to implement the Language Server Protocol.
 #[async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
-        Ok(Self::default_initialize_result())
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        // The editors can limit the memory of long sessions, with the `lruCapacity` option.
+        let lru_capacity = params
+            .initialization_options
+            .as_ref()
+            .and_then(|options| options.get("lruCapacity"))
+            .and_then(|capacity| capacity.as_u64());
+        if let Some(capacity) = lru_capacity {
+            self.db().set_lru_capacity(capacity as usize);
+        }
+
+        // The first encoding that the client supports, in its order of preference, is used, or
+        // UTF-16 if it doesn't support any other.
+        let encoding = params
+            .capabilities
+            .general
+            .as_ref()
+            .and_then(|general| general.position_encodings.as_ref())
+            .and_then(|kinds| kinds.iter().find_map(position_encoding))
+            .unwrap_or(PositionEncoding::Utf16);
+        let _ = self.encoding.set(encoding);
+
+        let mut result = Self::default_initialize_result();
+        result.capabilities.position_encoding = Some(position_encoding_kind(encoding));
+
+        Ok(result)
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -135,6 +229,49 @@ impl LanguageServer for Backend {
     async fn completion(&self, params: completions::Input) -> completions::Output {
         completions::invoke(self, params).await
     }
+
+    async fn symbol(&self, params: workspace_symbol::Input) -> workspace_symbol::Output {
+        workspace_symbol::invoke(self, params).await
+    }
+
+    async fn selection_range(&self, params: selection_range::Input) -> selection_range::Output {
+        selection_range::invoke(self, params).await
+    }
+
+    async fn folding_range(&self, params: folding_range::Input) -> folding_range::Output {
+        folding_range::invoke(self, params).await
+    }
+
+    async fn inlay_hint(&self, params: inlay_hint::Input) -> inlay_hint::Output {
+        inlay_hint::invoke(self, params).await
+    }
+
+    async fn code_lens(&self, params: code_lens::Input) -> code_lens::Output {
+        code_lens::invoke(self, params).await
+    }
+
+    async fn code_action(&self, params: code_action::Input) -> code_action::Output {
+        code_action::invoke(self, params).await
+    }
+}
+
+/// Gets the encoding of the LSP position encoding kind, if it's supported.
+fn position_encoding(kind: &PositionEncodingKind) -> Option<PositionEncoding> {
+    match kind.as_str() {
+        "utf-8" => Some(PositionEncoding::Utf8),
+        "utf-16" => Some(PositionEncoding::Utf16),
+        "utf-32" => Some(PositionEncoding::Utf32),
+        _ => None,
+    }
+}
+
+/// Gets the LSP position encoding kind of the encoding.
+fn position_encoding_kind(encoding: PositionEncoding) -> PositionEncodingKind {
+    match encoding {
+        PositionEncoding::Utf8 => PositionEncodingKind::UTF8,
+        PositionEncoding::Utf16 => PositionEncodingKind::UTF16,
+        PositionEncoding::Utf32 => PositionEncodingKind::UTF32,
+    }
 }
 
 fn _assert_send_sync() {
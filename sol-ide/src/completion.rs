@@ -1,13 +1,17 @@
-use sol_diagnostic::Offset;
+use miette::SourceOffset;
 use sol_driver::RootDb;
 use sol_hir::{
-    completions::{completions, HirCompletion, Position},
+    completions::{completions, HirCompletion},
     solver::DefinitionKind,
     source::HirSource,
 };
-use tower_lsp::lsp_types::{
-    CompletionItem, CompletionItemKind, CompletionResponse, Documentation, MessageType,
-    TextDocumentPositionParams,
+use sol_typer::auto::expected_type;
+use tower_lsp::{
+    jsonrpc::Result,
+    lsp_types::{
+        CompletionItem, CompletionItemKind, CompletionResponse, Documentation, InsertTextFormat,
+        MessageType, TextDocumentPositionParams,
+    },
 };
 
 use crate::backend::Backend;
@@ -17,39 +21,48 @@ impl Backend {
         &self,
         params: TextDocumentPositionParams,
         hir_source: HirSource,
-    ) -> Option<CompletionResponse> {
-        let path = params.text_document.uri.to_string();
-        let text_file = self.workspace.file_map.get(&path).unwrap();
+    ) -> Result<Option<CompletionResponse>> {
         let searching_for_name = Default::default();
-        let offset = text_file
-            .try_line_to_char(params.position.line as usize)
-            .ok()?;
-        let position = Position {
-            offset: Offset(offset + (params.position.character as usize)),
+        let Some(offset) = self.cancellable(|db| {
+            let line_index = hir_source.source(db).file(db).line_index(db);
+
+            self.offset(line_index, params.position)
+        })?
+        else {
+            return Ok(None);
         };
+        let position = SourceOffset::from(offset);
 
         // log for client
         self.client
             .log_message(
                 MessageType::INFO,
-                format!("trying completions at {:?}", position.offset.0),
+                format!("trying completions at {:?}", position.offset()),
             )
             .await;
 
-        let completions = completions(&*self.db(), hir_source, searching_for_name, position);
+        // The expected type is the goal of the hole at the cursor, if any, it ranks the
+        // completions whose result types unify with it first.
+        let new_completions = self.cancellable(|db| {
+            let expected = expected_type(db, self.global_env, hir_source, position.offset());
 
-        let new_completions = completions
-            .into_iter()
-            .map(|c| CompletionItem {
-                label: c.name.clone(),
-                kind: Some(hir_completion_kind(&c)),
-                detail: Some(hir_detail(&c)),
-                documentation: hir_docs(&self.db(), &c),
-                ..CompletionItem::default()
-            })
-            .collect();
+            completions(db, hir_source, searching_for_name, position, expected)
+                .into_iter()
+                .enumerate()
+                .map(|(index, c)| CompletionItem {
+                    label: c.name.clone(),
+                    kind: Some(hir_completion_kind(&c)),
+                    detail: Some(hir_detail(&c)),
+                    documentation: hir_docs(db, &c),
+                    sort_text: Some(format!("{index:05}")),
+                    insert_text: c.snippet.clone(),
+                    insert_text_format: c.snippet.as_ref().map(|_| InsertTextFormat::SNIPPET),
+                    ..CompletionItem::default()
+                })
+                .collect()
+        })?;
 
-        Some(CompletionResponse::Array(new_completions))
+        Ok(Some(CompletionResponse::Array(new_completions)))
     }
 }
 
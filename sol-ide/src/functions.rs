@@ -192,11 +192,370 @@ pub mod completions {
             .await;
 
         if let Some(hir_source) = server.hir_source(path.clone()) {
-            return Ok(server
+            return server
                 .completions(params.text_document_position, hir_source)
-                .await);
+                .await;
         }
 
         Ok(Some(CompletionResponse::Array(new_completions)))
     }
 }
+
+/// Defines the handler for the `workspace/symbol` request.
+///
+/// The `workspace/symbol` request is sent from the client to the server to
+/// list project-wide symbols matching the query string.
+pub mod workspace_symbol {
+    use sol_hir::{
+        solver::DefinitionKind,
+        symbols::{workspace_symbols, HirSymbol, SymbolQuery},
+    };
+
+    use super::*;
+
+    pub type Input = WorkspaceSymbolParams;
+    pub type Output = Result<Option<Vec<SymbolInformation>>>;
+
+    pub async fn invoke(server: &Backend, params: Input) -> Output {
+        let symbols = server.cancellable(|db| {
+            workspace_symbols(db, SymbolQuery::new(db, params.query))
+                .into_iter()
+                .filter_map(|symbol| symbol_information(server, db, symbol))
+                .collect_vec()
+        })?;
+
+        Ok(Some(symbols))
+    }
+
+    #[allow(deprecated)]
+    fn symbol_information(
+        server: &Backend,
+        db: &sol_driver::RootDb,
+        symbol: HirSymbol,
+    ) -> Option<SymbolInformation> {
+        let source = symbol.location.source()?;
+        let path = source.file_path(db).to_string_lossy().to_string();
+        let uri = Url::parse(&path)
+            .ok()
+            .or_else(|| Url::from_file_path(&path).ok())?;
+
+        let line_index = source.file(db).line_index(db);
+        let position = |offset: usize| server.position(line_index, offset);
+
+        Some(SymbolInformation {
+            name: symbol.name,
+            kind: match symbol.kind {
+                DefinitionKind::Function => SymbolKind::FUNCTION,
+                DefinitionKind::Constructor => SymbolKind::CONSTRUCTOR,
+                DefinitionKind::Type => SymbolKind::STRUCT,
+                DefinitionKind::Variable => SymbolKind::VARIABLE,
+                DefinitionKind::Module => SymbolKind::MODULE,
+                DefinitionKind::Command => SymbolKind::FUNCTION,
+                DefinitionKind::Trait => SymbolKind::INTERFACE,
+                DefinitionKind::Unresolved => return None,
+            },
+            tags: None,
+            deprecated: None,
+            location: Location {
+                uri,
+                range: Range {
+                    start: position(symbol.location.start().offset())?,
+                    end: position(symbol.location.end().offset())?,
+                },
+            },
+            container_name: Some(symbol.container),
+        })
+    }
+}
+
+/// Defines the handler for the `textDocument/selectionRange` request.
+///
+/// The `textDocument/selectionRange` request is sent from the client to the
+/// server to get the ranges to expand the selection around the given positions.
+pub mod selection_range {
+    use super::*;
+
+    pub type Input = SelectionRangeParams;
+    pub type Output = Result<Option<Vec<SelectionRange>>>;
+
+    pub async fn invoke(server: &Backend, params: Input) -> Output {
+        let uri = params.text_document.uri;
+        let Some(hir_source) = server.hir_source(uri) else {
+            return Ok(None);
+        };
+
+        let selections = server.cancellable(|db| {
+            let source = hir_source.source(db);
+            let line_index = source.file(db).line_index(db);
+            let to_position = |byte: usize| server.position(line_index, byte);
+
+            params
+                .positions
+                .into_iter()
+                .filter_map(|position| {
+                    let offset = server.offset(line_index, position)?;
+
+                    // Builds the linked list from the outermost range to the innermost one, so
+                    // each range has its enclosing range as parent.
+                    source
+                        .selection_ranges(db, offset)
+                        .into_iter()
+                        .rev()
+                        .try_fold(None, |parent, range| {
+                            Some(Some(SelectionRange {
+                                range: Range::new(
+                                    to_position(range.start)?,
+                                    to_position(range.end)?,
+                                ),
+                                parent: parent.map(Box::new),
+                            }))
+                        })?
+                })
+                .collect_vec()
+        })?;
+
+        Ok(Some(selections))
+    }
+}
+
+/// Defines the handler for the `textDocument/foldingRange` request.
+///
+/// The `textDocument/foldingRange` request is sent from the client to the
+/// server to get the folding ranges of a text document.
+pub mod folding_range {
+    use sol_syntax::folding::FoldingKind;
+
+    use super::*;
+
+    pub type Input = FoldingRangeParams;
+    pub type Output = Result<Option<Vec<FoldingRange>>>;
+
+    pub async fn invoke(server: &Backend, params: Input) -> Output {
+        let uri = params.text_document.uri;
+        let Some(hir_source) = server.hir_source(uri.clone()) else {
+            return Ok(None);
+        };
+        let Some(rope) = server
+            .workspace
+            .file_map
+            .get(&uri.to_string())
+            .map(|r| r.clone())
+        else {
+            return Ok(None);
+        };
+
+        let ranges = server.cancellable(|db| hir_source.source(db).folding_ranges(db))?;
+        let ranges = ranges
+            .into_iter()
+            .filter_map(|folding| {
+                Some(FoldingRange {
+                    start_line: rope.try_byte_to_line(folding.range.start).ok()? as u32,
+                    end_line: rope.try_byte_to_line(folding.range.end).ok()? as u32,
+                    kind: Some(match folding.kind {
+                        FoldingKind::Region => FoldingRangeKind::Region,
+                        FoldingKind::Comment => FoldingRangeKind::Comment,
+                    }),
+                    ..Default::default()
+                })
+            })
+            .collect_vec();
+
+        Ok(Some(ranges))
+    }
+}
+
+/// Defines the handler for the `textDocument/inlayHint` request.
+///
+/// The `textDocument/inlayHint` request is sent from the client to the server to get the
+/// inline hints of a range, that are the normal forms of the `#eval` commands.
+pub mod inlay_hint {
+    use super::*;
+
+    pub type Input = InlayHintParams;
+    pub type Output = Result<Option<Vec<InlayHint>>>;
+
+    pub async fn invoke(server: &Backend, params: Input) -> Output {
+        let uri = params.text_document.uri;
+        let Some(hir_source) = server.hir_source(uri) else {
+            return Ok(None);
+        };
+
+        let hints = server.cancellable(|db| {
+            let line_index = hir_source.source(db).file(db).line_index(db);
+
+            sol_typer::eval_hints(db, server.global_env, hir_source)
+                .into_iter()
+                .filter_map(|hint| {
+                    // The hint is placed after the evaluated argument.
+                    let position = server.position(line_index, hint.location.end().offset())?;
+                    if position < params.range.start || position > params.range.end {
+                        return None;
+                    }
+
+                    let label = match hint.value {
+                        Ok(value) => format!(" = {value}"),
+                        Err(error) => format!(" = error: {error}"),
+                    };
+
+                    Some(InlayHint {
+                        position,
+                        label: InlayHintLabel::String(label),
+                        kind: None,
+                        text_edits: None,
+                        tooltip: None,
+                        padding_left: Some(true),
+                        padding_right: None,
+                        data: None,
+                    })
+                })
+                .collect_vec()
+        })?;
+
+        Ok(Some(hints))
+    }
+}
+
+/// Defines the handler for the `textDocument/codeLens` request.
+///
+/// The `textDocument/codeLens` request is sent from the client to the server to get the
+/// actions shown above the declarations, like running the tests.
+pub mod code_lens {
+    use sol_hir::lenses::code_lenses;
+
+    use super::*;
+
+    pub type Input = CodeLensParams;
+    pub type Output = Result<Option<Vec<CodeLens>>>;
+
+    pub async fn invoke(server: &Backend, params: Input) -> Output {
+        let uri = params.text_document.uri;
+        let Some(hir_source) = server.hir_source(uri) else {
+            return Ok(None);
+        };
+
+        let lenses = server.cancellable(|db| {
+            let line_index = hir_source.source(db).file(db).line_index(db);
+            let to_position = |byte: usize| server.position(line_index, byte);
+
+            code_lenses(db, hir_source)
+                .into_iter()
+                .filter_map(|lens| {
+                    let arguments = lens
+                        .command
+                        .arguments(db)
+                        .into_iter()
+                        .map(serde_json::Value::String)
+                        .collect_vec();
+
+                    Some(CodeLens {
+                        range: Range::new(
+                            to_position(lens.location.start().offset())?,
+                            to_position(lens.location.end().offset())?,
+                        ),
+                        command: Some(Command {
+                            title: lens.title,
+                            command: lens.command.name().into(),
+                            arguments: Some(arguments),
+                        }),
+                        data: None,
+                    })
+                })
+                .collect_vec()
+        })?;
+
+        Ok(Some(lenses))
+    }
+}
+
+/// Defines the handler for the `textDocument/codeAction` request.
+///
+/// The `textDocument/codeAction` request is sent from the client to the server to get the
+/// actions of a range, like organizing the imports of the file, or filling the holes.
+pub mod code_action {
+    use std::collections::HashMap;
+
+    use sol_hir::{imports::organize_imports, source::HirLocation};
+    use sol_typer::{auto::auto, source_hole_goals};
+
+    use super::*;
+
+    pub type Input = CodeActionParams;
+    pub type Output = Result<Option<CodeActionResponse>>;
+
+    pub async fn invoke(server: &Backend, params: Input) -> Output {
+        let uri = params.text_document.uri;
+        let Some(hir_source) = server.hir_source(uri.clone()) else {
+            return Ok(None);
+        };
+
+        let actions = server.cancellable(|db| {
+            let line_index = hir_source.source(db).file(db).line_index(db);
+            let to_position = |byte: usize| server.position(line_index, byte);
+
+            let edits = organize_imports(db, hir_source)
+                .into_iter()
+                .filter_map(|edit| {
+                    Some(TextEdit {
+                        range: Range::new(
+                            to_position(edit.location.start().offset())?,
+                            to_position(edit.location.end().offset())?,
+                        ),
+                        new_text: edit.new_text,
+                    })
+                })
+                .collect_vec();
+
+            let mut actions = vec![];
+            if !edits.is_empty() {
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: "Organize imports".into(),
+                    kind: Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(uri.clone(), edits)])),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }));
+            }
+
+            // The holes in the range are filled by the candidates of the search, the
+            // applications are parenthesized, as the holes can be arguments.
+            for goal in source_hole_goals(db, server.global_env, hir_source) {
+                let (Some(start), Some(end)) = (
+                    to_position(goal.location.start().offset()),
+                    to_position(goal.location.end().offset()),
+                ) else {
+                    continue;
+                };
+                if end < params.range.start || start > params.range.end {
+                    continue;
+                }
+
+                let hole = HirLocation::new(db, goal.location.clone());
+                for candidate in auto(db, server.global_env, hir_source, hole) {
+                    let new_text = match candidate.arguments {
+                        0 => candidate.expr.clone(),
+                        _ => format!("({})", candidate.expr),
+                    };
+
+                    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                        title: format!("Fill the hole with `{}`", candidate.expr),
+                        kind: Some(CodeActionKind::QUICKFIX),
+                        edit: Some(WorkspaceEdit {
+                            changes: Some(HashMap::from([(uri.clone(), vec![TextEdit {
+                                range: Range::new(start, end),
+                                new_text,
+                            }])])),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }));
+                }
+            }
+
+            actions
+        })?;
+
+        Ok(Some(actions))
+    }
+}
@@ -40,7 +40,9 @@ async fn main() {
     let (service, socket) = LspService::build(|client| Backend {
         client,
         workspace: Arc::new(Workspace::default_with_db(&db)),
+        global_env: db.global_env(),
         db: Arc::new(RwLock::new(db)),
+        encoding: Default::default(),
     })
     .finish();
 
@@ -5,6 +5,7 @@ use std::{
 
 use dashmap::DashMap;
 use ropey::Rope;
+use salsa_2022::{Database, Durability};
 use sol_driver::RootDb;
 use sol_hir::{
     package::{Package, PackageKind, Version},
@@ -47,13 +48,18 @@ impl Backend {
 
         let path = PathBuf::from(item.uri.to_string());
 
+        // The edit starts a new revision, that cancels the requests running in the snapshots,
+        // as their results are stale.
+        let mut db = self.db.write().unwrap();
+        db.synthetic_write(Durability::LOW);
+
         // TODO:
-        let file = SourceFile::new(&*self.db(), path, item.uri.to_string(), item.text.clone());
-        let source = sol_syntax::parse(&*self.db(), file);
+        let file = SourceFile::new(&*db, path, item.uri.to_string(), item.text.clone());
+        let source = sol_syntax::parse(&*db, file);
 
-        let package = create_default_package(&self.db(), source, "main");
+        let package = create_default_package(&db, source, "main");
 
-        let hir = hir_lower(&*self.db(), package, source);
+        let hir = hir_lower(&*db, package, source);
         self.workspace.hir_map.insert(item.uri.to_string(), hir);
         hir
     }
@@ -71,7 +77,7 @@ fn create_default_package(db: &RootDb, source: Source, name: &str) -> Package {
     let kind = PackageKind::Binary;
 
     // Creates a new package with the given `name`, `version`, `source` and `kind`.
-    let package = Package::new(db, name.into(), version, source, kind, vec![]);
+    let package = Package::new(db, name.into(), version, source, kind, vec![], None);
 
     // Registers the package in the database.
     db.register_package(package)
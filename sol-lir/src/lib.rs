@@ -0,0 +1,368 @@
+//! The low-level intermediate representation of Sol, that sits between the THIR and the
+//! backends, so the VM, C, LLVM and wasm backends doesn't re-implement the closure conversion
+//! each one.
+//!
+//! The functions are first-order, the lambdas are lifted into top level functions, that take
+//! their captured variables as the first parameters, and are referenced by explicit closures.
+//! The control flow is explicit too, with join points, and flat dispatches over the tags of the
+//! values.
+
+use std::fmt::Display;
+
+use num_bigint::BigInt;
+use sol_hir::{package::OverflowMode, primitives::Intrinsic};
+
+/// Defines the lowering of the THIR into the LIR, with the closure conversion.
+pub mod lowering;
+
+/// Defines the tail call optimization, that turns the tail calls into loops and trampolines.
+pub mod tail_calls;
+
+/// Defines the insertion of the reference counting instructions, with the reuse analysis.
+pub mod rc;
+
+/// Defines the representation of the values at runtime, that is shared by the backends.
+pub mod runtime;
+
+/// A variable of a function, it's only assigned once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Variable(pub u32);
+
+/// The identifier of a function in the [`Program`], it's its index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FunctionId(pub usize);
+
+/// The identifier of a join point, it's unique inside of a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JoinId(pub u32);
+
+/// An operand of the instructions, they're always atomic, so the instructions are flat.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Variable(Variable),
+
+    /// A top level definition, by its qualified name.
+    Global(String),
+    Function(FunctionId),
+    Int(isize),
+
+    /// An arbitrary-precision integer that doesn't fit in a scalar, see
+    /// [`runtime::small_int`].
+    BigInt(BigInt),
+    String(String),
+    Bool(bool),
+    Unit,
+
+    /// A computationally irrelevant value, like a type.
+    Erased,
+}
+
+/// The right hand side of an assignment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr {
+    Operand(Operand),
+
+    /// Calls the function or the closure, with all the arguments at once.
+    Call(Operand, Vec<Operand>),
+
+    /// Creates a closure of the lifted function, with the captured values.
+    MakeClosure(FunctionId, Vec<Operand>),
+
+    /// Allocates a value of a constructor, with its tag and its fields.
+    Construct(u32, Vec<Operand>),
+
+    /// Gets the field of a constructed value, by its position.
+    Field(Operand, u32),
+
+    /// Allocates an array, with its items.
+    Array(Vec<Operand>),
+
+    /// Calls the intrinsic of the runtime, like `Array.length`, with all the arguments.
+    Intrinsic(Intrinsic, Vec<Operand>),
+
+    /// Gets the memory of the value if it's uniquely owned, so it can be reused by an
+    /// allocation, otherwise decrements its reference count, and gets a null token.
+    Reset(Variable),
+
+    /// Allocates the value in the memory of the token, if it isn't null, or in a new memory.
+    Reuse(Variable, Box<Expr>),
+
+    /// Aborts the program, it's used for the terms with errors.
+    Panic(String),
+}
+
+/// An instruction of a block.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Let(Variable, Expr),
+
+    /// Increments the reference count of the value, it's a no-op for the scalars.
+    Inc(Variable),
+
+    /// Decrements the reference count of the value, freeing it if it reaches zero.
+    Dec(Variable),
+}
+
+/// The terminator of a block, that transfers the control flow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Terminator {
+    Return(Operand),
+
+    /// Calls the function in tail position, returning its result. The backends should reuse
+    /// the stack frame, or bounce it through a trampoline.
+    TailCall(Operand, Vec<Operand>),
+
+    /// Jumps to the join point, with the values of its parameters.
+    Jump(JoinId, Vec<Operand>),
+
+    /// Dispatches on the tag of the scrutinee, jumping to the join point of the case that
+    /// matches, or to the default one.
+    Switch {
+        scrutinee: Operand,
+        cases: Vec<(isize, JoinId)>,
+        default: Option<JoinId>,
+    },
+}
+
+/// A join point, it's a local continuation, that is only called in tail position, so it
+/// compiles to a label, instead of a function.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JoinPoint {
+    pub id: JoinId,
+    pub parameters: Vec<Variable>,
+    pub body: Block,
+}
+
+/// A block of instructions, with its terminator and the join points that it can jump to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Block {
+    pub instructions: Vec<Instruction>,
+    pub join_points: Vec<JoinPoint>,
+    pub terminator: Terminator,
+}
+
+/// A first-order function, the lifted lambdas take their captures as the first parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Function {
+    pub name: String,
+    pub captures: Vec<Variable>,
+    pub parameters: Vec<Variable>,
+    pub body: Block,
+
+    /// If the function is part of a group of mutually tail recursive functions, so the
+    /// backends should compile its tail calls into a trampoline.
+    pub trampoline: bool,
+}
+
+impl Expr {
+    /// Gets the operands of the expression, that are consumed by it.
+    pub fn operands(&self) -> Vec<&Operand> {
+        match self {
+            Expr::Operand(operand) | Expr::Field(operand, _) => vec![operand],
+            Expr::Call(callee, arguments) => std::iter::once(callee).chain(arguments).collect(),
+            Expr::MakeClosure(_, operands)
+            | Expr::Construct(_, operands)
+            | Expr::Array(operands)
+            | Expr::Intrinsic(_, operands) => operands.iter().collect(),
+            Expr::Reuse(_, expr) => expr.operands(),
+            Expr::Reset(_) | Expr::Panic(_) => vec![],
+        }
+    }
+
+    /// Gets the operands of the expression mutably, like [`Expr::operands`].
+    pub fn operands_mut(&mut self) -> Vec<&mut Operand> {
+        match self {
+            Expr::Operand(operand) | Expr::Field(operand, _) => vec![operand],
+            Expr::Call(callee, arguments) => std::iter::once(callee)
+                .chain(arguments.iter_mut())
+                .collect(),
+            Expr::MakeClosure(_, operands)
+            | Expr::Construct(_, operands)
+            | Expr::Array(operands)
+            | Expr::Intrinsic(_, operands) => operands.iter_mut().collect(),
+            Expr::Reuse(_, expr) => expr.operands_mut(),
+            Expr::Reset(_) | Expr::Panic(_) => vec![],
+        }
+    }
+
+    /// Checks if the expression allocates a new value, so it can reuse memory.
+    pub fn is_allocation(&self) -> bool {
+        matches!(
+            self,
+            Expr::MakeClosure(..) | Expr::Construct(..) | Expr::Array(..)
+        )
+    }
+}
+
+impl Function {
+    /// Gets the next variable that isn't used in the function, to create fresh ones.
+    pub fn next_variable(&self) -> u32 {
+        fn block_max(block: &Block) -> Option<Variable> {
+            let assigned = block
+                .instructions
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::Let(variable, _) => Some(*variable),
+                    _ => None,
+                });
+            let join_points = block.join_points.iter().flat_map(|join_point| {
+                let parameters = join_point.parameters.iter().copied();
+                parameters.chain(block_max(&join_point.body))
+            });
+
+            assigned.chain(join_points).max()
+        }
+
+        let parameters = self.captures.iter().chain(&self.parameters).copied();
+        parameters
+            .chain(block_max(&self.body))
+            .max()
+            .map_or(0, |Variable(last)| last + 1)
+    }
+}
+
+/// A program, with all the functions, including the lifted ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Program {
+    pub functions: Vec<Function>,
+
+    /// The behavior of the integer arithmetic when it overflows, the backends should trap with
+    /// the location of the operation in the checked mode, and wrap in the wrapping mode, like
+    /// the evaluator of the type checker.
+    pub overflow: OverflowMode,
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Variable(variable) => write!(f, "{variable}"),
+            Operand::Global(name) => write!(f, "@{name}"),
+            Operand::Function(FunctionId(id)) => write!(f, "fn{id}"),
+            Operand::Int(value) => write!(f, "{value}"),
+            Operand::BigInt(value) => write!(f, "{value}"),
+            Operand::String(value) => write!(f, "{value:?}"),
+            Operand::Bool(value) => write!(f, "{value}"),
+            Operand::Unit => write!(f, "()"),
+            Operand::Erased => write!(f, "erased"),
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |operands: &[Operand]| {
+            operands
+                .iter()
+                .map(Operand::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        match self {
+            Expr::Operand(operand) => write!(f, "{operand}"),
+            Expr::Call(callee, arguments) => write!(f, "call {callee}({})", join(arguments)),
+            Expr::MakeClosure(FunctionId(id), captures) => {
+                write!(f, "closure fn{id}[{}]", join(captures))
+            }
+            Expr::Construct(tag, fields) => write!(f, "construct {tag}({})", join(fields)),
+            Expr::Field(operand, position) => write!(f, "field {operand}.{position}"),
+            Expr::Array(items) => write!(f, "array [{}]", join(items)),
+            Expr::Intrinsic(intrinsic, arguments) => {
+                write!(f, "intrinsic {}({})", intrinsic.name(), join(arguments))
+            }
+            Expr::Reset(variable) => write!(f, "reset {variable}"),
+            Expr::Reuse(token, expr) => write!(f, "reuse {token} in {expr}"),
+            Expr::Panic(message) => write!(f, "panic {message:?}"),
+        }
+    }
+}
+
+impl Display for Variable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (id, function) in self.functions.iter().enumerate() {
+            let parameters = function
+                .captures
+                .iter()
+                .map(|variable| format!("[{variable}]"))
+                .chain(function.parameters.iter().map(Variable::to_string))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let trampoline = if function.trampoline {
+                " trampoline"
+            } else {
+                ""
+            };
+
+            writeln!(f, "fn{id} {}({parameters}){trampoline} {{", function.name)?;
+            write_block(f, &function.body, 1)?;
+            writeln!(f, "}}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the block, indented by the `depth`.
+fn write_block(f: &mut std::fmt::Formatter<'_>, block: &Block, depth: usize) -> std::fmt::Result {
+    let indent = "  ".repeat(depth);
+    let join = |operands: &[Operand]| {
+        operands
+            .iter()
+            .map(Operand::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    for instruction in &block.instructions {
+        match instruction {
+            Instruction::Let(variable, expr) => writeln!(f, "{indent}{variable} = {expr}")?,
+            Instruction::Inc(variable) => writeln!(f, "{indent}inc {variable}")?,
+            Instruction::Dec(variable) => writeln!(f, "{indent}dec {variable}")?,
+        }
+    }
+
+    match &block.terminator {
+        Terminator::Return(operand) => writeln!(f, "{indent}return {operand}")?,
+        Terminator::TailCall(callee, arguments) => {
+            writeln!(f, "{indent}tailcall {callee}({})", join(arguments))?
+        }
+        Terminator::Jump(JoinId(id), arguments) => {
+            writeln!(f, "{indent}jump j{id}({})", join(arguments))?
+        }
+        Terminator::Switch {
+            scrutinee,
+            cases,
+            default,
+        } => {
+            writeln!(f, "{indent}switch {scrutinee} {{")?;
+            for (tag, JoinId(id)) in cases {
+                writeln!(f, "{indent}  {tag} => j{id}")?;
+            }
+            if let Some(JoinId(id)) = default {
+                writeln!(f, "{indent}  _ => j{id}")?;
+            }
+            writeln!(f, "{indent}}}")?;
+        }
+    }
+
+    for join_point in &block.join_points {
+        let parameters = join_point
+            .parameters
+            .iter()
+            .map(Variable::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(f, "{indent}j{}({parameters}):", join_point.id.0)?;
+        write_block(f, &join_point.body, depth + 1)?;
+    }
+
+    Ok(())
+}
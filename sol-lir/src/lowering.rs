@@ -0,0 +1,487 @@
+//! Defines the lowering of the elaborated terms of the THIR into the LIR. The lambdas that
+//! aren't the parameters of a top level definition are lifted into new functions, and their
+//! free variables are captured by explicit closures.
+//!
+//! The curried applications are flattened into calls with all the arguments at once, and the
+//! computationally irrelevant terms, like the types, are lowered into [`Operand::Erased`], so
+//! the erasure pass should run before the lowering, to remove them.
+//!
+//! The case trees of the matches are lowered into switches, their branches are join points,
+//! that jump to the continuation of the match with the value of the arm. The code after the
+//! match is the body of the continuation, so the blocks are nested, like in:
+//!
+//! ```text
+//! switch %0 {
+//!   1 => j1
+//!   _ => j2
+//! }
+//! j1():
+//!   jump j0("yes")
+//! j2():
+//!   jump j0("no")
+//! j0(%1):
+//!   return %1
+//! ```
+//!
+//! The switches only dispatch on the scalars, so the tests of the strings, and of the integers
+//! that don't fit in a word, aren't lowered yet, and panic.
+
+use std::collections::BTreeSet;
+
+use num_bigint::BigInt;
+use sol_hir::HirDb;
+use sol_thir::{
+    case_tree::CaseTree,
+    debruijin::Index,
+    shared::{Constructor, ConstructorKind},
+    source::Term,
+};
+
+use crate::*;
+
+/// Lowers the top level definitions into a [`Program`].
+pub struct LirLowering<'db> {
+    db: &'db dyn HirDb,
+    program: Program,
+}
+
+/// The state of the function that is being lowered.
+struct FunctionBuilder {
+    name: String,
+    next_variable: u32,
+    next_lambda: usize,
+    next_join: u32,
+    instructions: Vec<Instruction>,
+    join_points: Vec<JoinPoint>,
+
+    /// The blocks that end in a match, the instructions that follow the match are in the body
+    /// of their continuations.
+    frames: Vec<Frame>,
+
+    /// The values of the de Bruijn indices, the last one is the index `0`.
+    env: Vec<Operand>,
+}
+
+/// A block that ends in a match, waiting for the body of its continuation.
+struct Frame {
+    instructions: Vec<Instruction>,
+    join_points: Vec<JoinPoint>,
+    terminator: Terminator,
+    continuation: JoinId,
+    result: Variable,
+}
+
+impl FunctionBuilder {
+    fn new(name: String, env: Vec<Operand>) -> Self {
+        Self {
+            name,
+            next_variable: 0,
+            next_lambda: 0,
+            next_join: 0,
+            instructions: vec![],
+            join_points: vec![],
+            frames: vec![],
+            env,
+        }
+    }
+
+    fn fresh_join(&mut self) -> JoinId {
+        let id = JoinId(self.next_join);
+        self.next_join += 1;
+        id
+    }
+
+    fn fresh(&mut self) -> Variable {
+        let variable = Variable(self.next_variable);
+        self.next_variable += 1;
+        variable
+    }
+
+    fn assign(&mut self, expr: Expr) -> Operand {
+        let variable = self.fresh();
+        self.instructions.push(Instruction::Let(variable, expr));
+        Operand::Variable(variable)
+    }
+
+    /// Ends the current block with the terminator of a match, the next instructions are in the
+    /// body of the continuation, that gets the value of the match in the `result`.
+    fn suspend(&mut self, terminator: Terminator, continuation: JoinId, result: Variable) {
+        self.frames.push(Frame {
+            instructions: std::mem::take(&mut self.instructions),
+            join_points: std::mem::take(&mut self.join_points),
+            terminator,
+            continuation,
+            result,
+        });
+    }
+
+    /// Ends the current block with the terminator, nesting it in the continuations of the
+    /// suspended blocks.
+    fn finish(&mut self, terminator: Terminator) -> Block {
+        let mut block = Block {
+            instructions: std::mem::take(&mut self.instructions),
+            join_points: std::mem::take(&mut self.join_points),
+            terminator,
+        };
+
+        while let Some(frame) = self.frames.pop() {
+            let mut join_points = frame.join_points;
+            join_points.push(JoinPoint {
+                id: frame.continuation,
+                parameters: vec![frame.result],
+                body: block,
+            });
+
+            block = Block {
+                instructions: frame.instructions,
+                join_points,
+                terminator: frame.terminator,
+            };
+        }
+
+        block
+    }
+}
+
+impl<'db> LirLowering<'db> {
+    pub fn new(db: &'db dyn HirDb) -> Self {
+        Self {
+            db,
+            program: Program::default(),
+        }
+    }
+
+    /// Lowers the term of a top level definition, its leading lambdas are the parameters of
+    /// the function.
+    pub fn definition(&mut self, name: String, term: &Term) -> FunctionId {
+        self.function(FunctionBuilder::new(name, vec![]), vec![], term)
+    }
+
+    /// Finishes the lowering, returning the program with all the functions.
+    pub fn finish(self) -> Program {
+        self.program
+    }
+
+    /// Lowers the function, the `captures` are the variables of the closure, that are already
+    /// in the environment of the builder.
+    fn function(
+        &mut self,
+        mut builder: FunctionBuilder,
+        captures: Vec<Variable>,
+        term: &Term,
+    ) -> FunctionId {
+        // Reserves the id, so the lifted functions are added after it.
+        let id = FunctionId(self.program.functions.len());
+        self.program.functions.push(Function {
+            name: builder.name.clone(),
+            captures: vec![],
+            parameters: vec![],
+            body: Block {
+                instructions: vec![],
+                join_points: vec![],
+                terminator: Terminator::Return(Operand::Unit),
+            },
+            trampoline: false,
+        });
+
+        let mut parameters = vec![];
+        let mut body = term;
+        loop {
+            match body {
+                Term::Lam(_, _, next) => {
+                    let parameter = builder.fresh();
+                    builder.env.push(Operand::Variable(parameter));
+                    parameters.push(parameter);
+                    body = next.as_ref();
+                }
+                Term::Location(_, next) | Term::Ann(next, _) | Term::Glued(_, _, next) => {
+                    body = next.as_ref()
+                }
+                _ => break,
+            }
+        }
+
+        let result = self.term(&mut builder, body);
+        self.program.functions[id.0] = Function {
+            body: builder.finish(Terminator::Return(result)),
+            name: builder.name,
+            captures,
+            parameters,
+            trampoline: false,
+        };
+
+        id
+    }
+
+    /// Lowers the term into the instructions of the builder, returning the operand with its
+    /// value.
+    fn term(&mut self, builder: &mut FunctionBuilder, term: &Term) -> Operand {
+        match term {
+            Term::Var(Index(index), _) => builder
+                .env
+                .len()
+                .checked_sub(index + 1)
+                .map(|position| builder.env[position].clone())
+                .unwrap_or(Operand::Erased),
+            Term::U | Term::Prop | Term::Pi(..) | Term::InsertedMeta(_) => Operand::Erased,
+
+            // The proofs are irrelevant, so they're erased entirely.
+            Term::Irrelevant(_) => Operand::Erased,
+
+            // The quoted expressions only exist at compile time, so they're erased too.
+            Term::Quote(..) => Operand::Erased,
+            Term::Constructor(Constructor { kind, .. }) => self.constructor(kind),
+            Term::Ann(value, _) => self.term(builder, value),
+            Term::Location(_, term) | Term::Glued(_, _, term) => self.term(builder, term),
+            Term::Sorry(..) => builder.assign(Expr::Panic("sorry".into())),
+            Term::Panic(message, location) => {
+                // Resolves where the program panicked from the location of the call, so the VM
+                // reports it, like `not yet implemented, at Main.sol:1:8`.
+                let message = match location.position() {
+                    Some(position) => format!("{message}, at {position}"),
+                    None => message.clone(),
+                };
+
+                builder.assign(Expr::Panic(message))
+            }
+            Term::App(..) => {
+                let (head, arguments) = term.spine();
+                let callee = self.term(builder, head);
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| self.term(builder, argument))
+                    .collect();
+
+                builder.assign(Expr::Call(callee, arguments))
+            }
+            Term::Lam(..) => self.lambda(builder, term),
+            Term::RecordType(_) | Term::Eq(..) | Term::Refl => Operand::Erased,
+
+            // The transports are the identity at runtime, as the equalities are erased.
+            Term::Transport(_, value) => self.term(builder, value),
+            Term::Record(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(_, value)| self.term(builder, value))
+                    .collect();
+
+                builder.assign(Expr::Construct(0, fields))
+            }
+            Term::Project(record, _, position) => {
+                let record = self.term(builder, record);
+
+                builder.assign(Expr::Field(record, *position as u32))
+            }
+            Term::ArrayType(_) => Operand::Erased,
+            Term::Array(items) => {
+                let items = items.iter().map(|item| self.term(builder, item)).collect();
+
+                builder.assign(Expr::Array(items))
+            }
+            Term::Intrinsic(intrinsic, arguments, _) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.term(builder, argument))
+                    .collect();
+
+                builder.assign(Expr::Intrinsic(*intrinsic, arguments))
+            }
+            Term::Match(scrutinee, tree) => {
+                let scrutinee = self.term(builder, scrutinee);
+                let continuation = builder.fresh_join();
+                let result = builder.fresh();
+                let terminator = self.case_tree(builder, &scrutinee, tree, continuation);
+                builder.suspend(terminator, continuation, result);
+
+                Operand::Variable(result)
+            }
+        }
+    }
+
+    /// Lowers the case tree into the terminator of the current block, its branches are join
+    /// points, that jump to the `continuation` with the values of the arms.
+    fn case_tree(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        scrutinee: &Operand,
+        tree: &CaseTree,
+        continuation: JoinId,
+    ) -> Terminator {
+        let (operand, tag, then, otherwise) = match tree {
+            CaseTree::Leaf(body) => {
+                let value = self.term(builder, body);
+
+                return Terminator::Jump(continuation, vec![value]);
+            }
+            CaseTree::Test(Constructor { kind, .. }, then, otherwise) => match tag(kind) {
+                Some(tag) => (scrutinee.clone(), tag, then, otherwise),
+                None => {
+                    let message = format!("the test of {kind:?} isn't lowered yet");
+                    let value = builder.assign(Expr::Panic(message));
+
+                    return Terminator::Jump(continuation, vec![value]);
+                }
+            },
+            CaseTree::Guard(guard, then, otherwise) => {
+                (self.term(builder, guard), 1, then, otherwise)
+            }
+            CaseTree::Fail(_) => {
+                let value = builder.assign(Expr::Panic("the match isn't exhaustive".into()));
+
+                return Terminator::Jump(continuation, vec![value]);
+            }
+        };
+
+        let then = self.join_point(builder, scrutinee, then, continuation);
+        let otherwise = self.join_point(builder, scrutinee, otherwise, continuation);
+
+        Terminator::Switch {
+            scrutinee: operand,
+            cases: vec![(tag, then)],
+            default: Some(otherwise),
+        }
+    }
+
+    /// Lowers the case tree into a new join point of the current block, without parameters.
+    fn join_point(
+        &mut self,
+        builder: &mut FunctionBuilder,
+        scrutinee: &Operand,
+        tree: &CaseTree,
+        continuation: JoinId,
+    ) -> JoinId {
+        let id = builder.fresh_join();
+        let instructions = std::mem::take(&mut builder.instructions);
+        let join_points = std::mem::take(&mut builder.join_points);
+        let frames = std::mem::take(&mut builder.frames);
+
+        let terminator = self.case_tree(builder, scrutinee, tree, continuation);
+        let body = builder.finish(terminator);
+
+        builder.instructions = instructions;
+        builder.join_points = join_points;
+        builder.frames = frames;
+        builder.join_points.push(JoinPoint {
+            id,
+            parameters: vec![],
+            body,
+        });
+
+        id
+    }
+
+    /// Lifts the lambda into a new function, capturing its free variables in a closure.
+    fn lambda(&mut self, builder: &mut FunctionBuilder, term: &Term) -> Operand {
+        let mut free = BTreeSet::new();
+        free_variables(term, 0, &mut free);
+
+        let mut lifted = FunctionBuilder::new(
+            format!("{}$lambda{}", builder.name, builder.next_lambda),
+            vec![Operand::Erased; builder.env.len()],
+        );
+        builder.next_lambda += 1;
+
+        // The free variables that are values, like the constants, doesn't need to be captured.
+        let mut captures = vec![];
+        let mut captured = vec![];
+        for index in free {
+            let Some(position) = builder.env.len().checked_sub(index + 1) else {
+                continue;
+            };
+
+            match &builder.env[position] {
+                Operand::Variable(variable) => {
+                    let capture = lifted.fresh();
+                    lifted.env[position] = Operand::Variable(capture);
+                    captures.push(capture);
+                    captured.push(Operand::Variable(*variable));
+                }
+                operand => lifted.env[position] = operand.clone(),
+            }
+        }
+
+        let function = self.function(lifted, captures, term);
+
+        builder.assign(Expr::MakeClosure(function, captured))
+    }
+
+    fn constructor(&self, kind: &ConstructorKind) -> Operand {
+        match kind {
+            ConstructorKind::Unit => Operand::Unit,
+            ConstructorKind::True => Operand::Bool(true),
+            ConstructorKind::False => Operand::Bool(false),
+            ConstructorKind::Int(value) => Operand::Int(*value),
+            ConstructorKind::Nat(value) => big_int(BigInt::from(value.clone())),
+            ConstructorKind::Integer(value) => big_int(value.clone()),
+            ConstructorKind::String(value) => Operand::String(value.clone()),
+            ConstructorKind::Reference(reference) => {
+                Operand::Global(reference.definition(self.db).to_string(self.db))
+            }
+            ConstructorKind::UnitType
+            | ConstructorKind::BooleanType
+            | ConstructorKind::NatType
+            | ConstructorKind::IntegerType
+            | ConstructorKind::IntType(..)
+            | ConstructorKind::StringType
+            | ConstructorKind::SyntaxType
+            | ConstructorKind::PrimitiveType(_) => Operand::Erased,
+        }
+    }
+}
+
+/// The tag of the literal in the switches, the booleans are `0` and `1`, and the integers are
+/// their values, if they fit in the word.
+fn tag(kind: &ConstructorKind) -> Option<isize> {
+    match kind {
+        ConstructorKind::Unit | ConstructorKind::False => Some(0),
+        ConstructorKind::True => Some(1),
+        ConstructorKind::Int(value) => Some(*value),
+        ConstructorKind::Nat(value) => runtime::small_int(&BigInt::from(value.clone())),
+        ConstructorKind::Integer(value) => runtime::small_int(value),
+        _ => None,
+    }
+}
+
+/// Lowers the arbitrary-precision integer into a scalar, if it fits in the word.
+fn big_int(value: BigInt) -> Operand {
+    match runtime::small_int(&value) {
+        Some(value) => Operand::Int(value),
+        None => Operand::BigInt(value),
+    }
+}
+
+/// Collects the free variables of the term, as the de Bruijn indices of the environment where
+/// the term is.
+fn free_variables(term: &Term, depth: usize, free: &mut BTreeSet<usize>) {
+    match term {
+        Term::Var(Index(index), _) if *index >= depth => {
+            free.insert(index - depth);
+        }
+        Term::Lam(_, _, body) => free_variables(body, depth + 1, free),
+        Term::App(callee, argument) => {
+            free_variables(callee, depth, free);
+            free_variables(argument, depth, free);
+        }
+        Term::Ann(value, _) => free_variables(value, depth, free),
+        Term::Location(_, term) | Term::Glued(_, _, term) => free_variables(term, depth, free),
+        Term::Record(fields) => {
+            for (_, value) in fields {
+                free_variables(value, depth, free);
+            }
+        }
+        Term::Project(record, _, _) => free_variables(record, depth, free),
+        Term::Transport(_, value) => free_variables(value, depth, free),
+        Term::Array(terms) | Term::Intrinsic(_, terms, _) => {
+            for term in terms {
+                free_variables(term, depth, free);
+            }
+        }
+        Term::Match(scrutinee, tree) => {
+            free_variables(scrutinee, depth, free);
+            for term in tree.terms() {
+                free_variables(term, depth, free);
+            }
+        }
+        _ => {}
+    }
+}
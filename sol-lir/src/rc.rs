@@ -0,0 +1,290 @@
+//! Defines the insertion of the reference counting instructions, in the style of Perceus, so
+//! the backends don't need a tracing garbage collector.
+//!
+//! All the variables are owned, the parameters, the captures and the join point parameters
+//! too, and each use of a variable consumes one reference. So an [`Instruction::Inc`] is
+//! inserted before each use that isn't the last one, and an [`Instruction::Dec`] is inserted
+//! where a variable becomes dead without being consumed. The free variables of a join point
+//! are owned by it, the blocks that jump to it transfer their references, and the case join
+//! points of a [`Terminator::Switch`] drop the references that they don't use.
+//!
+//! The reuse analysis pairs the dropped values with the allocations that follow them in the
+//! same block, so a functional update of a uniquely owned value, like `map f (x :: xs)`,
+//! reuses its memory in place, instead of freeing and allocating it again.
+
+use std::collections::BTreeSet;
+
+use fxhash::{FxHashMap, FxHashSet};
+
+use crate::*;
+
+/// The variables that are owned by the join points in the scope, that are their free
+/// variables.
+type Scope = FxHashMap<JoinId, BTreeSet<Variable>>;
+
+/// Inserts the reference counting instructions in all the functions of the program.
+pub fn insert(program: &mut Program) {
+    for function in &mut program.functions {
+        let mut next_variable = function.next_variable();
+        let owned = function
+            .captures
+            .iter()
+            .chain(&function.parameters)
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let body = std::mem::replace(
+            &mut function.body,
+            Block {
+                instructions: vec![],
+                join_points: vec![],
+                terminator: Terminator::Return(Operand::Unit),
+            },
+        );
+        function.body = insert_block(body, owned, &Scope::default(), &mut next_variable);
+    }
+}
+
+/// Inserts the reference counting instructions in the block, the `owned` variables are the
+/// ones that are owned when the block starts.
+fn insert_block(
+    block: Block,
+    owned: BTreeSet<Variable>,
+    scope: &Scope,
+    next_variable: &mut u32,
+) -> Block {
+    let scope = join_points_scope(&block, scope);
+
+    // The variables that are used after the current instruction, as the instructions are
+    // processed backwards.
+    let mut live = BTreeSet::new();
+    let mut instructions = vec![];
+
+    let mut switched = BTreeSet::new();
+    match &block.terminator {
+        Terminator::Return(operand) => consume(&mut live, &mut instructions, operand),
+        Terminator::TailCall(callee, arguments) => {
+            for operand in std::iter::once(callee).chain(arguments) {
+                consume(&mut live, &mut instructions, operand);
+            }
+        }
+        Terminator::Jump(id, arguments) => {
+            live.extend(scope.get(id).into_iter().flatten().copied());
+            for operand in arguments {
+                consume(&mut live, &mut instructions, operand);
+            }
+        }
+        Terminator::Switch {
+            scrutinee,
+            cases,
+            default,
+        } => {
+            // The references are transferred to the case that is taken, and the scrutinee is
+            // only read by the switch.
+            let targets = cases.iter().map(|(_, id)| id).chain(default);
+            for id in targets {
+                switched.extend(scope.get(id).into_iter().flatten().copied());
+            }
+            if let Operand::Variable(variable) = scrutinee {
+                switched.insert(*variable);
+            }
+            live.extend(switched.iter().copied());
+        }
+    }
+
+    for instruction in block.instructions.into_iter().rev() {
+        let Instruction::Let(variable, expr) = instruction else {
+            instructions.push(instruction);
+            continue;
+        };
+
+        if !live.remove(&variable) {
+            instructions.push(Instruction::Dec(variable));
+        }
+
+        let mut uses = vec![];
+        for operand in expr.operands() {
+            consume(&mut live, &mut uses, operand);
+        }
+        match &expr {
+            Expr::Reset(value) => consume_variable(&mut live, &mut uses, *value),
+            Expr::Reuse(token, _) => consume_variable(&mut live, &mut uses, *token),
+            _ => {}
+        }
+
+        instructions.push(Instruction::Let(variable, expr));
+        instructions.extend(uses);
+    }
+
+    // The owned variables that aren't used by the block are dropped at its start.
+    instructions.extend(
+        owned
+            .difference(&live)
+            .map(|variable| Instruction::Dec(*variable)),
+    );
+    instructions.reverse();
+
+    let join_points = block
+        .join_points
+        .into_iter()
+        .map(|join_point| {
+            let mut owned = join_point
+                .parameters
+                .iter()
+                .copied()
+                .collect::<BTreeSet<_>>();
+            owned.extend(scope[&join_point.id].iter().copied());
+            if is_case(&block.terminator, join_point.id) {
+                owned.extend(switched.iter().copied());
+            }
+
+            JoinPoint {
+                body: insert_block(join_point.body, owned, &scope, next_variable),
+                ..join_point
+            }
+        })
+        .collect();
+
+    Block {
+        instructions: reuse(instructions, next_variable),
+        join_points,
+        terminator: block.terminator,
+    }
+}
+
+/// Consumes the operand, if it's a variable.
+fn consume(live: &mut BTreeSet<Variable>, instructions: &mut Vec<Instruction>, operand: &Operand) {
+    if let Operand::Variable(variable) = operand {
+        consume_variable(live, instructions, *variable);
+    }
+}
+
+/// Consumes a reference of the variable, if it's used later, the reference is duplicated.
+fn consume_variable(
+    live: &mut BTreeSet<Variable>,
+    instructions: &mut Vec<Instruction>,
+    variable: Variable,
+) {
+    if !live.insert(variable) {
+        instructions.push(Instruction::Inc(variable));
+    }
+}
+
+/// Pairs the dropped values with the allocations that follow them, so the allocations reuse
+/// their memory, if they're uniquely owned at runtime.
+fn reuse(mut instructions: Vec<Instruction>, next_variable: &mut u32) -> Vec<Instruction> {
+    let mut reused = FxHashSet::default();
+
+    for position in 0..instructions.len() {
+        let Instruction::Dec(dropped) = instructions[position] else {
+            continue;
+        };
+
+        let allocation = (position + 1..instructions.len()).find(|allocation| {
+            matches!(&instructions[*allocation], Instruction::Let(_, expr) if expr.is_allocation())
+                && !reused.contains(allocation)
+        });
+        let Some(allocation) = allocation else {
+            continue;
+        };
+
+        let token = Variable(*next_variable);
+        *next_variable += 1;
+        reused.insert(allocation);
+
+        instructions[position] = Instruction::Let(token, Expr::Reset(dropped));
+        if let Instruction::Let(_, expr) = &mut instructions[allocation] {
+            let allocate = std::mem::replace(expr, Expr::Operand(Operand::Unit));
+            *expr = Expr::Reuse(token, allocate.into());
+        }
+    }
+
+    instructions
+}
+
+/// Computes the free variables of the join points of the block, that are owned by them, and
+/// adds them to the scope.
+fn join_points_scope(block: &Block, scope: &Scope) -> Scope {
+    let mut scope = scope.clone();
+    for join_point in &block.join_points {
+        scope.insert(join_point.id, BTreeSet::new());
+    }
+
+    // It's a fixed point, as the join points can jump to each other, like the loops.
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for join_point in &block.join_points {
+            let mut free = free_variables(&join_point.body, &scope);
+            for parameter in &join_point.parameters {
+                free.remove(parameter);
+            }
+
+            if free != scope[&join_point.id] {
+                scope.insert(join_point.id, free);
+                changed = true;
+            }
+        }
+    }
+
+    scope
+}
+
+/// Collects the free variables of the block, including the ones that are owned by the join
+/// points that it jumps to.
+fn free_variables(block: &Block, scope: &Scope) -> BTreeSet<Variable> {
+    let scope = join_points_scope(block, scope);
+    let mut free = BTreeSet::new();
+    let mut use_operand = |operand: &Operand| {
+        if let Operand::Variable(variable) = operand {
+            free.insert(*variable);
+        }
+    };
+
+    match &block.terminator {
+        Terminator::Return(value) => use_operand(value),
+        Terminator::TailCall(callee, arguments) => std::iter::once(callee)
+            .chain(arguments)
+            .for_each(&mut use_operand),
+        Terminator::Jump(_, arguments) => arguments.iter().for_each(&mut use_operand),
+        Terminator::Switch { scrutinee, .. } => use_operand(scrutinee),
+    }
+    for instruction in &block.instructions {
+        match instruction {
+            Instruction::Let(_, expr) => expr.operands().into_iter().for_each(&mut use_operand),
+            Instruction::Inc(variable) | Instruction::Dec(variable) => {
+                use_operand(&Operand::Variable(*variable))
+            }
+        }
+    }
+
+    let targets = match &block.terminator {
+        Terminator::Jump(id, _) => vec![*id],
+        Terminator::Switch { cases, default, .. } => {
+            cases.iter().map(|(_, id)| *id).chain(*default).collect()
+        }
+        _ => vec![],
+    };
+    for id in targets {
+        free.extend(scope.get(&id).into_iter().flatten().copied());
+    }
+
+    for instruction in &block.instructions {
+        if let Instruction::Let(variable, _) = instruction {
+            free.remove(variable);
+        }
+    }
+
+    free
+}
+
+/// Checks if the join point is a case of the switch.
+fn is_case(terminator: &Terminator, id: JoinId) -> bool {
+    match terminator {
+        Terminator::Switch { cases, default, .. } => {
+            cases.iter().any(|(_, case)| *case == id) || *default == Some(id)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,90 @@
+//! Defines the representation of the values at runtime, that is shared by the VM and the C
+//! backend, so the values can be passed between them through the FFI.
+//!
+//! The values are a single machine word. The scalars, like the integers, booleans and the
+//! unit, are tagged with the lowest bit set, and the other values are pointers to objects in
+//! the heap, that start with an [`ObjectHeader`]. The objects are reference counted, and the
+//! [`Instruction::Inc`] and [`Instruction::Dec`] instructions are no-ops for the scalars, so
+//! the reference counting is inserted for all the variables, without knowing their types.
+//!
+//! The arbitrary-precision integers, of the `Nat` and `Integer` types, are scalars while they
+//! fit in the word, and are promoted to [`ObjectKind::BigInt`] objects when the arithmetic
+//! overflows, so the backends fallback to the big integer arithmetic of the runtime.
+//!
+//! The static objects, like the string literals and the top level functions, have the
+//! [`STATIC_REFERENCE_COUNT`], so they're never freed.
+//!
+//! [`Instruction::Inc`]: crate::Instruction::Inc
+//! [`Instruction::Dec`]: crate::Instruction::Dec
+
+use num_bigint::BigInt;
+
+/// The tag of the scalar values, in the lowest bit of the word.
+pub const SCALAR_TAG: usize = 1;
+
+/// The reference count of the static objects, that are never freed.
+pub const STATIC_REFERENCE_COUNT: u32 = 0;
+
+/// The kind of an object in the heap.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectKind {
+    /// A value of a constructor, its fields are the values of its arguments.
+    Constructor = 0,
+
+    /// A closure, its first field is the pointer to the function, and the other fields are
+    /// the captured values.
+    Closure = 1,
+
+    /// A string, its fields are the UTF-8 bytes of the string.
+    String = 2,
+
+    /// An arbitrary-precision integer, its tag is `1` if it's negative, and its fields are the
+    /// 32-bit digits of its magnitude, from the least significant.
+    BigInt = 3,
+
+    /// An array, its fields are the values of its items.
+    Array = 4,
+}
+
+/// The header of the objects in the heap, it's followed by the fields of the object.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectHeader {
+    /// The reference count of the object, it's [`STATIC_REFERENCE_COUNT`] for the static
+    /// objects.
+    pub reference_count: u32,
+    pub kind: ObjectKind,
+
+    /// The tag of the constructor, it's `0` for the other kinds of objects.
+    pub tag: u8,
+
+    /// The number of fields, so a reset object can be reused by an allocation of the same
+    /// size or smaller.
+    pub fields: u16,
+}
+
+impl ObjectHeader {
+    /// Checks if the object is uniquely owned, so it can be updated in place.
+    pub fn is_unique(&self) -> bool {
+        self.reference_count == 1
+    }
+
+    /// Checks if the object can be reused by an allocation with the number of fields.
+    pub fn can_reuse(&self, fields: usize) -> bool {
+        self.is_unique() && fields <= self.fields as usize
+    }
+}
+
+/// Checks if the value is a scalar, so it isn't reference counted.
+pub fn is_scalar(word: usize) -> bool {
+    word & SCALAR_TAG == SCALAR_TAG
+}
+
+/// Gets the arbitrary-precision integer as a scalar, if it fits in the word without the tag,
+/// otherwise it should be allocated as a [`ObjectKind::BigInt`] object.
+pub fn small_int(value: &BigInt) -> Option<isize> {
+    isize::try_from(value)
+        .ok()
+        .filter(|value| (value << 1) >> 1 == *value)
+}
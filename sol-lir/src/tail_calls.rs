@@ -0,0 +1,220 @@
+//! Defines the tail call optimization, so the idiomatic recursive code, like the fold-style
+//! loops, doesn't blow the stack at runtime.
+//!
+//! The calls that are immediately returned become [`Terminator::TailCall`]s. The self tail
+//! calls are compiled into loops, with a join point at the entry of the function, that is
+//! jumped to with the new arguments. The functions that are mutually tail recursive are
+//! marked with [`Function::trampoline`], so the backends bounce their tail calls through a
+//! trampoline, as they can't be compiled into a single loop.
+
+use fxhash::FxHashMap;
+use petgraph::{algo::tarjan_scc, graph::DiGraph};
+
+use crate::*;
+
+/// Optimizes the tail calls of the program, returning the groups of the mutually tail
+/// recursive functions.
+pub fn optimize(program: &mut Program) -> Vec<Vec<FunctionId>> {
+    let globals = program
+        .functions
+        .iter()
+        .enumerate()
+        .filter(|(_, function)| function.captures.is_empty())
+        .map(|(id, function)| (function.name.clone(), FunctionId(id)))
+        .collect::<FxHashMap<_, _>>();
+    let callee_id = |callee: &Operand| match callee {
+        Operand::Function(id) => Some(*id),
+        Operand::Global(name) => globals.get(name).copied(),
+        _ => None,
+    };
+
+    let mut graph = DiGraph::<FunctionId, ()>::new();
+    let nodes = (0..program.functions.len())
+        .map(|id| graph.add_node(FunctionId(id)))
+        .collect::<Vec<_>>();
+
+    for (id, function) in program.functions.iter_mut().enumerate() {
+        mark_tail_calls(&mut function.body);
+
+        let mut callees = vec![];
+        tail_callees(&function.body, &mut callees);
+
+        let mut recursive = false;
+        for callee in callees.iter().filter_map(|callee| callee_id(callee)) {
+            if callee == FunctionId(id) {
+                recursive = true;
+            } else {
+                graph.add_edge(nodes[id], nodes[callee.0], ());
+            }
+        }
+
+        if recursive {
+            into_loop(FunctionId(id), function, &callee_id);
+        }
+    }
+
+    let groups = tarjan_scc(&graph)
+        .into_iter()
+        .filter(|group| group.len() > 1)
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|node| graph[node])
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    for id in groups.iter().flatten() {
+        program.functions[id.0].trampoline = true;
+    }
+
+    groups
+}
+
+/// Replaces the calls that are immediately returned with tail calls.
+fn mark_tail_calls(block: &mut Block) {
+    for join_point in &mut block.join_points {
+        mark_tail_calls(&mut join_point.body);
+    }
+
+    let Terminator::Return(Operand::Variable(result)) = block.terminator else {
+        return;
+    };
+
+    if let Some(Instruction::Let(variable, Expr::Call(..))) = block.instructions.last() {
+        if *variable != result {
+            return;
+        }
+
+        let Some(Instruction::Let(_, Expr::Call(callee, arguments))) = block.instructions.pop()
+        else {
+            unreachable!("the last instruction is a call");
+        };
+        block.terminator = Terminator::TailCall(callee, arguments);
+    }
+}
+
+/// Collects the callees of the tail calls of the block.
+fn tail_callees(block: &Block, callees: &mut Vec<Operand>) {
+    if let Terminator::TailCall(callee, _) = &block.terminator {
+        callees.push(callee.clone());
+    }
+
+    for join_point in &block.join_points {
+        tail_callees(&join_point.body, callees);
+    }
+}
+
+/// Compiles the self tail calls of the function into a loop, the body is moved into a join
+/// point, that is jumped to at the entry, and by the self tail calls.
+fn into_loop(
+    id: FunctionId,
+    function: &mut Function,
+    callee_id: &impl Fn(&Operand) -> Option<FunctionId>,
+) {
+    let mut next_variable = function.next_variable();
+    let entry = JoinId(max_join_id(&function.body).map_or(0, |JoinId(last)| last + 1));
+
+    // The parameters of the join point are fresh, so the variables are still assigned once.
+    let renames = function
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let fresh = Variable(next_variable);
+            next_variable += 1;
+            (*parameter, fresh)
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    let mut body = std::mem::replace(
+        &mut function.body,
+        Block {
+            instructions: vec![],
+            join_points: vec![],
+            terminator: Terminator::Jump(
+                entry,
+                function
+                    .parameters
+                    .iter()
+                    .map(|parameter| Operand::Variable(*parameter))
+                    .collect(),
+            ),
+        },
+    );
+    rename_block(&mut body, &renames);
+    jump_self_calls(id, function.parameters.len(), entry, &mut body, callee_id);
+
+    function.body.join_points.push(JoinPoint {
+        id: entry,
+        parameters: function
+            .parameters
+            .iter()
+            .map(|parameter| renames[parameter])
+            .collect(),
+        body,
+    });
+}
+
+/// Replaces the self tail calls, with all the arguments, with jumps to the entry.
+fn jump_self_calls(
+    id: FunctionId,
+    arity: usize,
+    entry: JoinId,
+    block: &mut Block,
+    callee_id: &impl Fn(&Operand) -> Option<FunctionId>,
+) {
+    for join_point in &mut block.join_points {
+        jump_self_calls(id, arity, entry, &mut join_point.body, callee_id);
+    }
+
+    if let Terminator::TailCall(callee, arguments) = &block.terminator {
+        if callee_id(callee) == Some(id) && arguments.len() == arity {
+            block.terminator = Terminator::Jump(entry, arguments.clone());
+        }
+    }
+}
+
+/// Renames the variables of the block.
+fn rename_block(block: &mut Block, renames: &FxHashMap<Variable, Variable>) {
+    let rename = |operand: &mut Operand| {
+        if let Operand::Variable(variable) = operand {
+            if let Some(renamed) = renames.get(variable) {
+                *variable = *renamed;
+            }
+        }
+    };
+
+    for instruction in &mut block.instructions {
+        match instruction {
+            Instruction::Let(_, expr) => expr.operands_mut().into_iter().for_each(rename),
+            Instruction::Inc(variable) | Instruction::Dec(variable) => {
+                if let Some(renamed) = renames.get(variable) {
+                    *variable = *renamed;
+                }
+            }
+        }
+    }
+
+    match &mut block.terminator {
+        Terminator::Return(operand) => rename(operand),
+        Terminator::TailCall(callee, arguments) => {
+            rename(callee);
+            arguments.iter_mut().for_each(rename);
+        }
+        Terminator::Jump(_, arguments) => arguments.iter_mut().for_each(rename),
+        Terminator::Switch { scrutinee, .. } => rename(scrutinee),
+    }
+
+    for join_point in &mut block.join_points {
+        rename_block(&mut join_point.body, renames);
+    }
+}
+
+/// Gets the last join point id of the block, to create fresh ones.
+fn max_join_id(block: &Block) -> Option<JoinId> {
+    block
+        .join_points
+        .iter()
+        .flat_map(|join_point| std::iter::once(join_point.id).chain(max_join_id(&join_point.body)))
+        .max()
+}
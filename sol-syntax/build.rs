@@ -1,60 +1,97 @@
-use std::{fmt::Display, fs::File, io::Write, path::Path, process::Command};
-
-use rust_format::{Formatter, RustFmt};
-use type_sitter_gen::tree_sitter;
+//! The generated parser, in `tree-sitter-sol/src`, and the node types, in
+//! `src/generated/node_types.rs`, with the version of the grammar they were generated from, in
+//! `src/generated/version.rs`, are vendored, so the builds don't need the `tree-sitter` CLI.
+//! They're regenerated from the grammar with the `regen` feature, like:
+//!
+//! ```sh
+//! cargo build -p sol-syntax --features regen
+//! ```
 
 fn main() {
-    regenerate_tree_sitter_grammar();
+    #[cfg(feature = "regen")]
+    regen::run();
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+#[cfg(feature = "regen")]
+mod regen {
+    use std::{fmt::Display, fs::File, io::Write, path::Path, process::Command};
 
-    if std::env::var("NO_REBUILD_TYPE_SITTER").is_err() {
+    use rust_format::{Formatter, RustFmt};
+    use type_sitter_gen::tree_sitter;
+
+    /// Regenerates the parser, and the node types, from the grammar.
+    pub fn run() {
+        regenerate_tree_sitter_grammar();
         regenerate_node_types();
+        regenerate_version();
+
+        println!("cargo:rerun-if-changed=../tree-sitter-sol/grammar.js");
     }
 
-    println!("cargo:rerun-if-changed=../tree-sitter-sol/grammar.js");
-}
+    /// Regenerate tree-sitter grammar
+    fn regenerate_tree_sitter_grammar() {
+        let status = Command::new("tree-sitter")
+            .current_dir(Path::new("../tree-sitter-sol"))
+            .arg("generate")
+            .status()
+            .expect("failed to run the tree-sitter CLI, is it installed?");
+        assert!(status.success(), "failed to generate tree-sitter grammar");
 
-/// Regenerate tree-sitter grammar
-fn regenerate_tree_sitter_grammar() {
-    Command::new("tree-sitter")
-        .current_dir(Path::new("../tree-sitter-sol"))
-        .arg("generate")
-        .status()
-        .expect("failed to generate tree-sitter grammar");
-
-    Command::new("tree-sitter")
-        .current_dir(Path::new("../tree-sitter-sol"))
-        .arg("build-wasm")
-        .status()
-        .expect("failed to generate tree-sitter grammar for wasm");
-}
+        let status = Command::new("tree-sitter")
+            .current_dir(Path::new("../tree-sitter-sol"))
+            .arg("build-wasm")
+            .status()
+            .expect("failed to run the tree-sitter CLI, is it installed?");
+        assert!(
+            status.success(),
+            "failed to generate tree-sitter grammar for wasm"
+        );
+    }
 
-/// Regenerates the `src/generated/sol.rs` file from the `src/node-types.json`
-fn regenerate_node_types() {
-    let input_path = Path::new("../tree-sitter-sol/src/node-types.json");
-    let target = Path::new("src/generated/node_types.rs");
-    let node_types = type_sitter_gen::generate_nodes(input_path, &tree_sitter())
-        .expect("failed to generate nodes");
-    clear_output_files(target);
-    write_rust_file(target, node_types);
-}
+    /// Regenerates the `src/generated/node_types.rs` file from the `src/node-types.json`
+    fn regenerate_node_types() {
+        let input_path = Path::new("../tree-sitter-sol/src/node-types.json");
+        let target = Path::new("src/generated/node_types.rs");
+        let node_types = type_sitter_gen::generate_nodes(input_path, &tree_sitter())
+            .expect("failed to generate nodes");
+        clear_output_files(target);
+        write_rust_file(target, node_types);
+    }
 
-/// Clears the output file so we don't write a directory or an invalid file
-/// when the build script fails.
-fn clear_output_files(target: &Path) {
-    if target.is_dir() {
-        std::fs::remove_dir_all(target).expect("failed to remove directory");
+    /// Regenerates the `src/generated/version.rs` file, recording the version of the grammar
+    /// that the node types were generated from.
+    fn regenerate_version() {
+        let target = Path::new("src/generated/version.rs");
+        let contents = format!(
+            "//! Generated by the `regen` feature of `sol-syntax`, don't edit it by hand.\n\n\
+             /// The version of the grammar that the node types were generated from.\n\
+             pub const GRAMMAR_VERSION: u32 = {};\n",
+            tree_sitter_sol::GRAMMAR_VERSION
+        );
+        clear_output_files(target);
+        std::fs::write(target, contents).expect("failed to write file");
     }
-    if target.exists() && target.is_file() {
-        std::fs::remove_file(target).expect("failed to remove file");
+
+    /// Clears the output file so we don't write a directory or an invalid file
+    /// when the build script fails.
+    fn clear_output_files(target: &Path) {
+        if target.is_dir() {
+            std::fs::remove_dir_all(target).expect("failed to remove directory");
+        }
+        if target.exists() && target.is_file() {
+            std::fs::remove_file(target).expect("failed to remove file");
+        }
     }
-}
 
-/// Writes the generated Rust code to the output file, formatting it
-/// with rustfmt.
-fn write_rust_file(path: &Path, contents: impl Display) {
-    let mut file = File::create(path).expect("failed to create file");
-    let contents = RustFmt::new()
-        .format_str(contents.to_string())
-        .expect("failed to format file");
-    write!(file, "{}", contents).expect("failed to write file");
+    /// Writes the generated Rust code to the output file, formatting it
+    /// with rustfmt.
+    fn write_rust_file(path: &Path, contents: impl Display) {
+        let mut file = File::create(path).expect("failed to create file");
+        let contents = RustFmt::new()
+            .format_str(contents.to_string())
+            .expect("failed to format file");
+        write!(file, "{}", contents).expect("failed to write file");
+    }
 }
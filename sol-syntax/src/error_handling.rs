@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use miette::{SourceOffset, SourceSpan};
 use sol_diagnostic::{report_error, TextSource};
+use tree_sitter::Node;
 
 use crate::Source;
 
@@ -42,12 +43,11 @@ impl Source {
             match true {
                 _ if node.has_error() && node.is_missing() => {
                     errors.push(SyntaxError {
-                        // The error message is the node's S-expression.
-                        message: node.to_sexp().to_lowercase(),
+                        message: missing_message(node),
                         source_code: TextSource::new(file_name.clone(), text.clone()),
                         location: SourceSpan::new(
+                            SourceOffset::from(node.start_byte()),
                             SourceOffset::from(0),
-                            SourceOffset::from(text.len()),
                         ),
                     });
                 }
@@ -91,3 +91,14 @@ impl Source {
         self
     }
 }
+
+/// Describes the token that tree-sitter inserted to recover from the error, with the construct
+/// that expects it, for the common typos, like the `=` of the clauses.
+fn missing_message(node: Node) -> String {
+    let kind = node.kind();
+    match (node.parent().map(|parent| parent.kind()), kind) {
+        (Some("clause"), "=") => "expected `=` after the clause patterns".into(),
+        (_, ")") => "expected `)` to close the parenthesis".into(),
+        _ => format!("expected `{kind}`"),
+    }
+}
@@ -0,0 +1,120 @@
+use std::ops::Range;
+
+use tree_sitter::Node;
+
+use crate::Source;
+
+/// The kind of a folding range, used by the editors to fold all the ranges of a kind at once,
+/// like all the doc comments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FoldingKind {
+    /// A range of code, like blocks, match arms and constructor lists.
+    Region,
+
+    /// A run of adjacent doc strings, or a block comment.
+    Comment,
+}
+
+/// A range of the source code that can be folded by the editors.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FoldingRange {
+    pub kind: FoldingKind,
+    pub range: Range<usize>,
+}
+
+#[salsa::tracked]
+impl Source {
+    /// Defines the [`Source::folding_ranges`] query.
+    ///
+    /// Computes the folding ranges of the program, that are the blocks, the match arms, the
+    /// constructor lists of the inductive types, the long parameter telescopes, the doc
+    /// comments and the block comments. It's based on the syntax tree, so it works even on files with errors.
+    ///
+    /// Only the ranges that spans over more than one line are returned, sorted by their start.
+    #[salsa::tracked]
+    pub fn folding_ranges(self, db: &dyn crate::ParseDb) -> Vec<FoldingRange> {
+        let text = self.source_text(db);
+        let root = self.syntax_node(db).root_node();
+
+        let mut ranges = vec![];
+        collect_folding_ranges(root, &mut ranges);
+
+        ranges.retain(|folding| {
+            text.get(folding.range.clone())
+                .is_some_and(|text| text.trim_end().contains('\n'))
+        });
+        ranges.sort_by_key(|folding| (folding.range.start, folding.range.end));
+        ranges.dedup();
+        ranges
+    }
+}
+
+fn collect_folding_ranges(node: Node, ranges: &mut Vec<FoldingRange>) {
+    let region = |range: Range<usize>| FoldingRange {
+        kind: FoldingKind::Region,
+        range,
+    };
+
+    match node.kind() {
+        "block" | "match_expr" | "match_arm" => ranges.push(region(node.byte_range())),
+        "block_comment" => ranges.push(FoldingRange {
+            kind: FoldingKind::Comment,
+            range: node.byte_range(),
+        }),
+        "inductive" => {
+            // Folds the constructor list, from the `{` to the `}`, keeping the name and the
+            // parameters of the inductive type visible.
+            let mut cursor = node.walk();
+            let open = node.children(&mut cursor).find(|child| child.kind() == "{");
+            if let Some(open) = open {
+                ranges.push(region(open.start_byte()..node.end_byte()));
+            }
+        }
+        _ => {}
+    }
+
+    // Folds the parameter telescopes, from the first to the last argument list, so the long
+    // signatures can be folded into a single line.
+    if matches!(node.kind(), "signature" | "inductive") {
+        let mut cursor = node.walk();
+        let arguments = node
+            .children_by_field_name("argument", &mut cursor)
+            .map(|argument| argument.byte_range())
+            .collect::<Vec<_>>();
+
+        if let (Some(first), Some(last)) = (arguments.first(), arguments.last()) {
+            ranges.push(region(first.start..last.end));
+        }
+    }
+
+    // Folds the runs of adjacent doc strings, they are not merged by the grammar, so each line
+    // is its own node.
+    let mut doc_run: Option<Range<usize>> = None;
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "doc_string" {
+            let range = child.byte_range();
+            doc_run = Some(match doc_run {
+                Some(run) => run.start..range.end,
+                None => range,
+            });
+            continue;
+        }
+
+        if let Some(run) = doc_run.take() {
+            ranges.push(FoldingRange {
+                kind: FoldingKind::Comment,
+                range: run,
+            });
+        }
+
+        collect_folding_ranges(child, ranges);
+    }
+
+    if let Some(run) = doc_run {
+        ranges.push(FoldingRange {
+            kind: FoldingKind::Comment,
+            range: run,
+        });
+    }
+}
@@ -70,7 +70,7 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for AnnExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `app_expr`\n\nThis node has these fields:\n- `argument`: `primary*` ([Primary])\n- `callee`: `primary` ([Primary])\n\nAnd an additional (optional) child: `block?` ([Block])\n"]
+#[doc = "Typed node `app_expr`\n\nThis node has these fields:\n- `argument`: `primary*` ([Primary])\n- `callee`: `primary` ([Primary])\n- `trailing`: `trailing_lambda?` ([TrailingLambda])\n\nAnd an additional (optional) child: `block?` ([Block])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct AppExpr<'tree>(tree_sitter::Node<'tree>);
@@ -97,6 +97,15 @@ impl<'tree> AppExpr<'tree> {
         self . 0 . child_by_field_name ("callee") . map (< Primary < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
+    #[doc = "Get the field `trailing` which has kind `trailing_lambda?` ([TrailingLambda])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn trailing(&self) -> Option<type_sitter_lib::NodeResult<'tree, TrailingLambda<'tree>>> {
+        self.0
+            .child_by_field_name("trailing")
+            .map(<TrailingLambda<'tree> as TryFrom<_>>::try_from)
+    }
+
     #[doc = "Get the node's named children"]
     #[doc = "This is guaranteed to return at least one child"]
     #[allow(dead_code)]
@@ -107,10 +116,10 @@ impl<'tree> AppExpr<'tree> {
     ) -> impl ExactSizeIterator<
         Item = type_sitter_lib::NodeResult<
             'tree,
-            type_sitter_lib::ExtraOr<'tree, anon_unions::Block_Primary<'tree>>,
+            type_sitter_lib::ExtraOr<'tree, anon_unions::Block_Primary_TrailingLambda<'tree>>,
         >,
     > + 'a {
-        self . 0 . named_children (c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Block_Primary < 'tree > > as TryFrom < _ >> :: try_from (n))
+        self . 0 . named_children (c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Block_Primary_TrailingLambda < 'tree > > as TryFrom < _ >> :: try_from (n))
     }
 
     #[doc = "Get the node's named child #i"]
@@ -122,10 +131,10 @@ impl<'tree> AppExpr<'tree> {
     ) -> Option<
         type_sitter_lib::NodeResult<
             'tree,
-            type_sitter_lib::ExtraOr<'tree, anon_unions::Block_Primary<'tree>>,
+            type_sitter_lib::ExtraOr<'tree, anon_unions::Block_Primary_TrailingLambda<'tree>>,
         >,
     > {
-        self . 0 . named_child (i) . map (< type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Block_Primary < 'tree > > as TryFrom < _ >> :: try_from)
+        self . 0 . named_child (i) . map (< type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Block_Primary_TrailingLambda < 'tree > > as TryFrom < _ >> :: try_from)
     }
 }
 #[automatically_derived]
@@ -241,22 +250,22 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for ArrayExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `ask_stmt`\n\nThis node has these fields:\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[doc = "Typed node `ask_stmt`\n\nThis node has these fields:\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct AskStmt<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
 impl<'tree> AskStmt<'tree> {
-    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
     #[allow(dead_code)]
     #[inline]
     pub fn pattern(
         &self,
     ) -> type_sitter_lib::NodeResult<
         'tree,
-        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
     > {
-        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
     #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
@@ -311,66 +320,31 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for AskStmt<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `attribute`\n\nThis node has these fields:\n- `argument`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `name`: `path` ([Path])\n\nAnd an additional (optional) child: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]\n\n"]
+#[doc = "Typed node `assert_stmt`\n\nThis node has these fields:\n- `condition`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Attribute<'tree>(tree_sitter::Node<'tree>);
+pub struct AssertStmt<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Attribute<'tree> {
-    #[doc = "Get the field `argument` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+impl<'tree> AssertStmt<'tree> {
+    #[doc = "Get the field `condition` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn arguments<'a>(
+    pub fn condition(
         &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                    'tree,
-                >,
-            >,
-        >,
-    > + 'a {
-        self.0.children_by_field_name("argument", c).map(|n| {
-            <type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                    'tree,
-                >,
-            > as TryFrom<_>>::try_from(n)
-        })
-    }
-
-    #[doc = "Get the field `name` which has kind `path` ([Path])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
-        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
-    }
-
-    #[doc = "Get the node's named children"]
-    #[doc = "This is guaranteed to return at least one child"]
-    #[allow(dead_code)]
-    #[inline]    pub fn children < 'a > (& self , c : & 'a mut tree_sitter :: TreeCursor < 'tree >) -> impl ExactSizeIterator < Item = type_sitter_lib :: NodeResult < 'tree , type_sitter_lib :: ExtraOr < 'tree , anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path < 'tree > > >> + 'a{
-        self . 0 . named_children (c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path < 'tree > > as TryFrom < _ >> :: try_from (n))
-    }
-
-    #[doc = "Get the node's named child #i"]
-    #[allow(dead_code)]
-    #[inline]    pub fn child (& self , i : usize) -> Option < type_sitter_lib :: NodeResult < 'tree , type_sitter_lib :: ExtraOr < 'tree , anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path < 'tree > > >>{
-        self . 0 . named_child (i) . map (< type_sitter_lib :: ExtraOr < 'tree , anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path < 'tree > > as TryFrom < _ >> :: try_from)
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("condition") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Attribute<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for AssertStmt<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "attribute" {
+        if node.kind() == "assert_stmt" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -381,8 +355,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Attribute<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Attribute<'tree> {
-    const KIND: &'static str = "attribute";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for AssertStmt<'tree> {
+    const KIND: &'static str = "assert_stmt";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -404,19 +378,53 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Attribute<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `binary`\n\nThis node has no children\n"]
+#[doc = "Typed node `attribute`\n\nThis node has these fields:\n- `argument`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `name`: `path` ([Path])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Binary<'tree>(tree_sitter::Node<'tree>);
+pub struct Attribute<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Binary<'tree> {}
+impl<'tree> Attribute<'tree> {
+    #[doc = "Get the field `argument` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn arguments<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            >,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("argument", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Binary<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Attribute<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "binary" {
+        if node.kind() == "attribute" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -427,8 +435,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Binary<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Binary<'tree> {
-    const KIND: &'static str = "binary";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Attribute<'tree> {
+    const KIND: &'static str = "attribute";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -524,13 +532,13 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for BinaryExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `block`\n\nThis node has these fields:\n- `statement`: `{ask_stmt | expr_stmt | if_stmt | let_stmt}*` ([anon_unions::AskStmt_ExprStmt_IfStmt_LetStmt])\n"]
+#[doc = "Typed node `block`\n\nThis node has these fields:\n- `statement`: `{ask_stmt | assert_stmt | expr_stmt | for_stmt | if_stmt | let_stmt | while_stmt}*` ([anon_unions::AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct Block<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
 impl<'tree> Block<'tree> {
-    #[doc = "Get the field `statement` which has kind `{ask_stmt | expr_stmt | if_stmt | let_stmt}*` ([anon_unions::AskStmt_ExprStmt_IfStmt_LetStmt])"]
+    #[doc = "Get the field `statement` which has kind `{ask_stmt | assert_stmt | expr_stmt | for_stmt | if_stmt | let_stmt | while_stmt}*` ([anon_unions::AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt])"]
     #[allow(dead_code)]
     #[inline]
     pub fn statements<'a>(
@@ -539,10 +547,18 @@ impl<'tree> Block<'tree> {
     ) -> impl Iterator<
         Item = type_sitter_lib::NodeResult<
             'tree,
-            type_sitter_lib::ExtraOr<'tree, anon_unions::AskStmt_ExprStmt_IfStmt_LetStmt<'tree>>,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt<'tree>,
+            >,
         >,
     > + 'a {
-        self . 0 . children_by_field_name ("statement" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: AskStmt_ExprStmt_IfStmt_LetStmt < 'tree > > as TryFrom < _ >> :: try_from (n))
+        self.0.children_by_field_name("statement", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt<'tree>,
+            > as TryFrom<_>>::try_from(n)
+        })
     }
 }
 #[automatically_derived]
@@ -585,7 +601,7 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Block<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `clause`\n\nThis node has these fields:\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `name`: `path` ([Path])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[doc = "Typed node `clause`\n\nThis node has these fields:\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `name`: `path` ([Path])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `with_pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `with_value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct Clause<'tree>(tree_sitter::Node<'tree>);
@@ -632,7 +648,7 @@ impl<'tree> Clause<'tree> {
         self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
-    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
     #[allow(dead_code)]
     #[inline]
     pub fn patterns<'a>(
@@ -643,14 +659,14 @@ impl<'tree> Clause<'tree> {
             'tree,
             type_sitter_lib::ExtraOr<
                 'tree,
-                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
             >,
         >,
     > + 'a {
         self.0.children_by_field_name("pattern", c).map(|n| {
             <type_sitter_lib::ExtraOr<
                 'tree,
-                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
             > as TryFrom<_>>::try_from(n)
         })
     }
@@ -674,6 +690,56 @@ impl<'tree> Clause<'tree> {
             > as TryFrom<_>>::try_from,
         )
     }
+
+    #[doc = "Get the field `with_pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn with_patterns<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            >,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("with_pattern", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
+
+    #[doc = "Get the field `with_value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn with_values<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            >,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("with_value", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
 }
 #[automatically_derived]
 impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Clause<'tree> {
@@ -829,7 +895,7 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Command<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `cons_pattern`\n\nThis node has these fields:\n- `name`: `path` ([Path])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n"]
+#[doc = "Typed node `cons_pattern`\n\nThis node has these fields:\n- `name`: `path` ([Path])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct ConsPattern<'tree>(tree_sitter::Node<'tree>);
@@ -842,7 +908,7 @@ impl<'tree> ConsPattern<'tree> {
         self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
-    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
     #[allow(dead_code)]
     #[inline]
     pub fn patterns<'a>(
@@ -853,14 +919,14 @@ impl<'tree> ConsPattern<'tree> {
             'tree,
             type_sitter_lib::ExtraOr<
                 'tree,
-                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
             >,
         >,
     > + 'a {
         self.0.children_by_field_name("pattern", c).map(|n| {
             <type_sitter_lib::ExtraOr<
                 'tree,
-                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
             > as TryFrom<_>>::try_from(n)
         })
     }
@@ -905,6 +971,73 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for ConsPattern<'tree> {
         Self(node)
     }
 }
+#[doc = "Typed node `constraint`\n\nThis node has these fields:\n- `argument`: `primary*` ([Primary])\n- `name`: `path` ([Path])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct Constraint<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> Constraint<'tree> {
+    #[doc = "Get the field `argument` which has kind `primary*` ([Primary])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn arguments<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, Primary<'tree>>>,
+    > + 'a {
+        self.0
+            .children_by_field_name("argument", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Primary<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Constraint<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "constraint" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Constraint<'tree> {
+    const KIND: &'static str = "constraint";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
 #[doc = "Typed node `doc_string`\n\nThis node has no children\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
@@ -1162,26 +1295,57 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for F64<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `forall_parameter`\n\nThis node has these fields:\n- `identifier`: `identifier` ([Identifier])\n"]
+#[doc = "Typed node `for_stmt`\n\nThis node has these fields:\n- `body`: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `iterable`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct ForallParameter<'tree>(tree_sitter::Node<'tree>);
+pub struct ForStmt<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> ForallParameter<'tree> {
-    #[doc = "Get the field `identifier` which has kind `identifier` ([Identifier])"]
+impl<'tree> ForStmt<'tree> {
+    #[doc = "Get the field `body` which has kind `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn identifier(&self) -> type_sitter_lib::NodeResult<'tree, Identifier<'tree>> {
-        self . 0 . child_by_field_name ("identifier") . map (< Identifier < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    pub fn body(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . child_by_field_name ("body") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `iterable` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn iterable(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("iterable") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn pattern(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+    > {
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ForallParameter<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ForStmt<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "forall_parameter" {
+        if node.kind() == "for_stmt" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -1192,8 +1356,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ForallParameter<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for ForallParameter<'tree> {
-    const KIND: &'static str = "forall_parameter";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for ForStmt<'tree> {
+    const KIND: &'static str = "for_stmt";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -1215,13 +1379,66 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for ForallParameter<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `forall_parameters`\n\nThis node has these fields:\n- `parameter`: `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])\n"]
+#[doc = "Typed node `forall_parameter`\n\nThis node has these fields:\n- `identifier`: `identifier` ([Identifier])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct ForallParameters<'tree>(tree_sitter::Node<'tree>);
+pub struct ForallParameter<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> ForallParameters<'tree> {
-    #[doc = "Get the field `parameter` which has kind `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])"]
+impl<'tree> ForallParameter<'tree> {
+    #[doc = "Get the field `identifier` which has kind `identifier` ([Identifier])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn identifier(&self) -> type_sitter_lib::NodeResult<'tree, Identifier<'tree>> {
+        self . 0 . child_by_field_name ("identifier") . map (< Identifier < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ForallParameter<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "forall_parameter" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for ForallParameter<'tree> {
+    const KIND: &'static str = "forall_parameter";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `forall_parameters`\n\nThis node has these fields:\n- `parameter`: `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct ForallParameters<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> ForallParameters<'tree> {
+    #[doc = "Get the field `parameter` which has kind `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])"]
     #[doc = "This is guaranteed to return at least one child"]
     #[allow(dead_code)]
     #[inline]
@@ -1423,22 +1640,22 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for FunctionConstructor<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `group_pattern`\n\nThis node has these fields:\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n"]
+#[doc = "Typed node `group_pattern`\n\nThis node has these fields:\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct GroupPattern<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
 impl<'tree> GroupPattern<'tree> {
-    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
     #[allow(dead_code)]
     #[inline]
     pub fn pattern(
         &self,
     ) -> type_sitter_lib::NodeResult<
         'tree,
-        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
     > {
-        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
@@ -1481,52 +1698,6 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for GroupPattern<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `hex`\n\nThis node has no children\n"]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[allow(non_camel_case_types)]
-pub struct Hex<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> Hex<'tree> {}
-#[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Hex<'tree> {
-    type Error = type_sitter_lib::IncorrectKind<'tree>;
-
-    #[inline]
-    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "hex" {
-            Ok(Self(node))
-        } else {
-            Err(type_sitter_lib::IncorrectKind {
-                node,
-                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-            })
-        }
-    }
-}
-#[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Hex<'tree> {
-    const KIND: &'static str = "hex";
-
-    #[inline]
-    fn node(&self) -> &tree_sitter::Node<'tree> {
-        &self.0
-    }
-
-    #[inline]
-    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-        &mut self.0
-    }
-
-    #[inline]
-    fn into_node(self) -> tree_sitter::Node<'tree> {
-        self.0
-    }
-
-    #[inline]
-    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
-        Self(node)
-    }
-}
 #[doc = "Typed node `i128`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
@@ -1811,7 +1982,7 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Identifier<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `if_expr`\n\nThis node has these fields:\n- `condition`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `otherwise`: `otherwise_body` ([OtherwiseBody])\n- `then`: `then_body` ([ThenBody])\n"]
+#[doc = "Typed node `if_expr`\n\nThis node has these fields:\n- `condition`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `otherwise`: `otherwise_body` ([OtherwiseBody])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `then`: `then_body` ([ThenBody])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct IfExpr<'tree>(tree_sitter::Node<'tree>);
@@ -1836,6 +2007,20 @@ impl<'tree> IfExpr<'tree> {
         self . 0 . child_by_field_name ("otherwise") . map (< OtherwiseBody < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn pattern(
+        &self,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+        >,
+    > {
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from)
+    }
+
     #[doc = "Get the field `then` which has kind `then_body` ([ThenBody])"]
     #[allow(dead_code)]
     #[inline]
@@ -1883,7 +2068,7 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for IfExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `if_stmt`\n\nThis node has these fields:\n- `condition`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `otherwise`: `otherwise_body?` ([OtherwiseBody])\n- `then`: `then_body` ([ThenBody])\n"]
+#[doc = "Typed node `if_stmt`\n\nThis node has these fields:\n- `condition`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `otherwise`: `otherwise_body?` ([OtherwiseBody])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `then`: `then_body` ([ThenBody])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 pub struct IfStmt<'tree>(tree_sitter::Node<'tree>);
@@ -1910,6 +2095,20 @@ impl<'tree> IfStmt<'tree> {
             .map(<OtherwiseBody<'tree> as TryFrom<_>>::try_from)
     }
 
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn pattern(
+        &self,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+        >,
+    > {
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from)
+    }
+
     #[doc = "Get the field `then` which has kind `then_body` ([ThenBody])"]
     #[allow(dead_code)]
     #[inline]
@@ -2221,125 +2420,97 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for InfixOp<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `lam_expr`\n\nThis node has these fields:\n- `parameter`: `{cons_pattern | group_pattern | literal | rest_pattern}+` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[doc = "Typed node `instance`\n\nThis node has these fields:\n- `argument`: `primary*` ([Primary])\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `method`: `clause*` ([Clause])\n- `name`: `path` ([Path])\n- `visibility`: `visibility?` ([Visibility])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct LamExpr<'tree>(tree_sitter::Node<'tree>);
+pub struct Instance<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> LamExpr<'tree> {
-    #[doc = "Get the field `parameter` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}+` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
-    #[doc = "This is guaranteed to return at least one child"]
+impl<'tree> Instance<'tree> {
+    #[doc = "Get the field `argument` which has kind `primary*` ([Primary])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn parameters<'a>(
+    pub fn arguments<'a>(
         &self,
         c: &'a mut tree_sitter::TreeCursor<'tree>,
     ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
-            >,
-        >,
+        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, Primary<'tree>>>,
     > + 'a {
-        self.0.children_by_field_name("parameter", c).map(|n| {
-            <type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
-            > as TryFrom<_>>::try_from(n)
-        })
+        self.0
+            .children_by_field_name("argument", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Primary<'tree>> as TryFrom<_>>::try_from(n))
     }
 
-    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn value(
+    pub fn attributes<'a>(
         &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
-    > {
-        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
-    }
-}
-#[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LamExpr<'tree> {
-    type Error = type_sitter_lib::IncorrectKind<'tree>;
-
-    #[inline]
-    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "lam_expr" {
-            Ok(Self(node))
-        } else {
-            Err(type_sitter_lib::IncorrectKind {
-                node,
-                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-            })
-        }
-    }
-}
-#[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for LamExpr<'tree> {
-    const KIND: &'static str = "lam_expr";
-
-    #[inline]
-    fn node(&self) -> &tree_sitter::Node<'tree> {
-        &self.0
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("attribute", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
     }
 
+    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
+    #[allow(dead_code)]
     #[inline]
-    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-        &mut self.0
+    pub fn doc_strings<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("doc_string", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
     }
 
+    #[doc = "Get the field `method` which has kind `clause*` ([Clause])"]
+    #[allow(dead_code)]
     #[inline]
-    fn into_node(self) -> tree_sitter::Node<'tree> {
+    pub fn methods<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, Clause<'tree>>>,
+    > + 'a {
         self.0
+            .children_by_field_name("method", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Clause<'tree>> as TryFrom<_>>::try_from(n))
     }
 
-    #[inline]
-    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
-        Self(node)
-    }
-}
-#[doc = "Typed node `let_stmt`\n\nThis node has these fields:\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[allow(non_camel_case_types)]
-pub struct LetStmt<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> LetStmt<'tree> {
-    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn pattern(
-        &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
-    > {
-        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
-    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[doc = "Get the field `visibility` which has kind `visibility?` ([Visibility])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn value(
-        &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
-    > {
-        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    pub fn visibility(&self) -> Option<type_sitter_lib::NodeResult<'tree, Visibility<'tree>>> {
+        self.0
+            .child_by_field_name("visibility")
+            .map(<Visibility<'tree> as TryFrom<_>>::try_from)
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LetStmt<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Instance<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "let_stmt" {
+        if node.kind() == "instance" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2350,8 +2521,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LetStmt<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for LetStmt<'tree> {
-    const KIND: &'static str = "let_stmt";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Instance<'tree> {
+    const KIND: &'static str = "instance";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2373,77 +2544,76 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for LetStmt<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `line_comment`\n\nThis node has no children\n"]
+#[doc = "Typed node `lam_expr`\n\nThis node has these fields:\n- `arm`: `match_arm*` ([MatchArm])\n- `parameter`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct LineComment<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> LineComment<'tree> {}
+pub struct LamExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LineComment<'tree> {
-    type Error = type_sitter_lib::IncorrectKind<'tree>;
-
+impl<'tree> LamExpr<'tree> {
+    #[doc = "Get the field `arm` which has kind `match_arm*` ([MatchArm])"]
+    #[allow(dead_code)]
     #[inline]
-    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "line_comment" {
-            Ok(Self(node))
-        } else {
-            Err(type_sitter_lib::IncorrectKind {
-                node,
-                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-            })
-        }
-    }
-}
-#[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for LineComment<'tree> {
-    const KIND: &'static str = "line_comment";
-
-    #[inline]
-    fn node(&self) -> &tree_sitter::Node<'tree> {
-        &self.0
-    }
-
-    #[inline]
-    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-        &mut self.0
-    }
-
-    #[inline]
-    fn into_node(self) -> tree_sitter::Node<'tree> {
+    pub fn arms<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, MatchArm<'tree>>>,
+    > + 'a {
         self.0
+            .children_by_field_name("arm", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, MatchArm<'tree>> as TryFrom<_>>::try_from(n))
     }
 
+    #[doc = "Get the field `parameter` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}*` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[allow(dead_code)]
     #[inline]
-    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
-        Self(node)
+    pub fn parameters<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            >,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("parameter", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            > as TryFrom<_>>::try_from(n)
+        })
     }
-}
-#[doc = "Typed node `literal`\n\nThis node has a child: `{char | f32 | f64 | i128 | i16 | i64 | i8 | nat | string | u1 | u128 | u16 | u32 | u64 | u8}`:\n- [Char]\n- [F32]\n- [F64]\n- [I128]\n- [I16]\n- [I64]\n- [I8]\n- [Nat]\n- [String]\n- [U1]\n- [U128]\n- [U16]\n- [U32]\n- [U64]\n- [U8]\n\n"]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[allow(non_camel_case_types)]
-pub struct Literal<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> Literal<'tree> {
-    #[doc = "Get the node's only named child"]
+
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
+    pub fn value(
         &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree>,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            >,
+        >,
     > {
-        self . 0 . named_child (0) . map (< anon_unions :: Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8 < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self.0.child_by_field_name("value").map(
+            <anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            > as TryFrom<_>>::try_from,
+        )
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Literal<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LamExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "literal" {
+        if node.kind() == "lam_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2454,8 +2624,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Literal<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Literal<'tree> {
-    const KIND: &'static str = "literal";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for LamExpr<'tree> {
+    const KIND: &'static str = "lam_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2477,45 +2647,55 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Literal<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `match_arm`\n\nThis node has these fields:\n- `body`: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n"]
+#[doc = "Typed node `let_expr`\n\nThis node has these fields:\n- `body`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct MatchArm<'tree>(tree_sitter::Node<'tree>);
+pub struct LetExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> MatchArm<'tree> {
-    #[doc = "Get the field `body` which has kind `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+impl<'tree> LetExpr<'tree> {
+    #[doc = "Get the field `body` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
     pub fn body(
         &self,
     ) -> type_sitter_lib::NodeResult<
         'tree,
-        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-            'tree,
-        >,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
     > {
-        self . 0 . child_by_field_name ("body") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . child_by_field_name ("body") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
-    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
     #[allow(dead_code)]
     #[inline]
     pub fn pattern(
         &self,
     ) -> type_sitter_lib::NodeResult<
         'tree,
-        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+    > {
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
     > {
-        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MatchArm<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LetExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "match_arm" {
+        if node.kind() == "let_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2526,8 +2706,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MatchArm<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for MatchArm<'tree> {
-    const KIND: &'static str = "match_arm";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for LetExpr<'tree> {
+    const KIND: &'static str = "let_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2549,45 +2729,43 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for MatchArm<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `match_expr`\n\nThis node has these fields:\n- `arm`: `match_arm*` ([MatchArm])\n- `scrutinee`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[doc = "Typed node `let_stmt`\n\nThis node has these fields:\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct MatchExpr<'tree>(tree_sitter::Node<'tree>);
+pub struct LetStmt<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> MatchExpr<'tree> {
-    #[doc = "Get the field `arm` which has kind `match_arm*` ([MatchArm])"]
+impl<'tree> LetStmt<'tree> {
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn arms<'a>(
+    pub fn pattern(
         &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, MatchArm<'tree>>>,
-    > + 'a {
-        self.0
-            .children_by_field_name("arm", c)
-            .map(|n| <type_sitter_lib::ExtraOr<'tree, MatchArm<'tree>> as TryFrom<_>>::try_from(n))
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+    > {
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
-    #[doc = "Get the field `scrutinee` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn scrutinee(
+    pub fn value(
         &self,
     ) -> type_sitter_lib::NodeResult<
         'tree,
         anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
     > {
-        self . 0 . child_by_field_name ("scrutinee") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MatchExpr<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LetStmt<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "match_expr" {
+        if node.kind() == "let_stmt" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2598,8 +2776,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MatchExpr<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for MatchExpr<'tree> {
-    const KIND: &'static str = "match_expr";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for LetStmt<'tree> {
+    const KIND: &'static str = "let_stmt";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2621,30 +2799,19 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for MatchExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `nat`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[doc = "Typed node `line_comment`\n\nThis node has no children\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Nat<'tree>(tree_sitter::Node<'tree>);
+pub struct LineComment<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Nat<'tree> {
-    #[doc = "Get the node's only named child"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn child(
-        &self,
-    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
-        self.0
-            .named_child(0)
-            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
-    }
-}
+impl<'tree> LineComment<'tree> {}
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Nat<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LineComment<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "nat" {
+        if node.kind() == "line_comment" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2655,8 +2822,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Nat<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Nat<'tree> {
-    const KIND: &'static str = "nat";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for LineComment<'tree> {
+    const KIND: &'static str = "line_comment";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2678,19 +2845,31 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Nat<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `octal`\n\nThis node has no children\n"]
+#[doc = "Typed node `literal`\n\nThis node has a child: `{char | f32 | f64 | i128 | i16 | i64 | i8 | nat | string | u1 | u128 | u16 | u32 | u64 | u8}`:\n- [Char]\n- [F32]\n- [F64]\n- [I128]\n- [I16]\n- [I64]\n- [I8]\n- [Nat]\n- [String]\n- [U1]\n- [U128]\n- [U16]\n- [U32]\n- [U64]\n- [U8]\n\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Octal<'tree>(tree_sitter::Node<'tree>);
+pub struct Literal<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Octal<'tree> {}
+impl<'tree> Literal<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree>,
+    > {
+        self . 0 . named_child (0) . map (< anon_unions :: Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8 < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Octal<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Literal<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "octal" {
+        if node.kind() == "literal" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2701,8 +2880,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Octal<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Octal<'tree> {
-    const KIND: &'static str = "octal";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Literal<'tree> {
+    const KIND: &'static str = "literal";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2724,33 +2903,53 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Octal<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `otherwise_body`\n\nThis node has these fields:\n- `value`: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[doc = "Typed node `macro_call`\n\nThis node has these fields:\n- `argument`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `name`: `path` ([Path])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct OtherwiseBody<'tree>(tree_sitter::Node<'tree>);
+pub struct MacroCall<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> OtherwiseBody<'tree> {
-    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+impl<'tree> MacroCall<'tree> {
+    #[doc = "Get the field `argument` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn value(
+    pub fn arguments<'a>(
         &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
             'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            >,
         >,
-    > {
-        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    > + 'a {
+        self.0.children_by_field_name("argument", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for OtherwiseBody<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MacroCall<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "otherwise_body" {
+        if node.kind() == "macro_call" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2761,8 +2960,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for OtherwiseBody<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for OtherwiseBody<'tree> {
-    const KIND: &'static str = "otherwise_body";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MacroCall<'tree> {
+    const KIND: &'static str = "macro_call";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2784,47 +2983,77 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for OtherwiseBody<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `parameter`\n\nThis node has these fields:\n- `parameter_type`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])\n"]
+#[doc = "Typed node `macro_decl`\n\nThis node has these fields:\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `name`: `path` ([Path])\n- `rule`: `macro_rule*` ([MacroRule])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Parameter<'tree>(tree_sitter::Node<'tree>);
+pub struct MacroDecl<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Parameter<'tree> {
-    #[doc = "Get the field `parameter_type` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
+impl<'tree> MacroDecl<'tree> {
+    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn parameter_type(
+    pub fn attributes<'a>(
         &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
             'tree,
+            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
         >,
-    > {
-        self . 0 . child_by_field_name ("parameter_type") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
-    }
-
-    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern])"]
-    #[allow(dead_code)]
+    > + 'a {
+        self.0
+            .children_by_field_name("attribute", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
+    #[allow(dead_code)]
     #[inline]
-    pub fn pattern(
+    pub fn doc_strings<'a>(
         &self,
-    ) -> Option<
-        type_sitter_lib::NodeResult<
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
             'tree,
-            anon_unions::ConsPattern_GroupPattern_Literal_RestPattern<'tree>,
+            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
         >,
-    > {
-        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern < 'tree > as TryFrom < _ >> :: try_from)
+    > + 'a {
+        self.0
+            .children_by_field_name("doc_string", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `rule` which has kind `macro_rule*` ([MacroRule])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn rules<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, MacroRule<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("rule", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, MacroRule<'tree>> as TryFrom<_>>::try_from(n))
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Parameter<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MacroDecl<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "parameter" {
+        if node.kind() == "macro_decl" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2835,8 +3064,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Parameter<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Parameter<'tree> {
-    const KIND: &'static str = "parameter";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MacroDecl<'tree> {
+    const KIND: &'static str = "macro_decl";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2858,37 +3087,46 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Parameter<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `path`\n\nThis node has these fields:\n- `segment`: `identifier+` ([Identifier])\n"]
+#[doc = "Typed node `macro_rule`\n\nThis node has these fields:\n- `body`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `pattern`: `{literal | macro_variable}*` ([anon_unions::Literal_MacroVariable])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Path<'tree>(tree_sitter::Node<'tree>);
+pub struct MacroRule<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Path<'tree> {
-    #[doc = "Get the field `segment` which has kind `identifier+` ([Identifier])"]
-    #[doc = "This is guaranteed to return at least one child"]
+impl<'tree> MacroRule<'tree> {
+    #[doc = "Get the field `body` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn segments<'a>(
+    pub fn body(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("body") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `pattern` which has kind `{literal | macro_variable}*` ([anon_unions::Literal_MacroVariable])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn patterns<'a>(
         &self,
         c: &'a mut tree_sitter::TreeCursor<'tree>,
     ) -> impl Iterator<
         Item = type_sitter_lib::NodeResult<
             'tree,
-            type_sitter_lib::ExtraOr<'tree, Identifier<'tree>>,
+            type_sitter_lib::ExtraOr<'tree, anon_unions::Literal_MacroVariable<'tree>>,
         >,
     > + 'a {
-        self.0.children_by_field_name("segment", c).map(|n| {
-            <type_sitter_lib::ExtraOr<'tree, Identifier<'tree>> as TryFrom<_>>::try_from(n)
-        })
+        self . 0 . children_by_field_name ("pattern" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Literal_MacroVariable < 'tree > > as TryFrom < _ >> :: try_from (n))
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Path<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MacroRule<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "path" {
+        if node.kind() == "macro_rule" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2899,8 +3137,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Path<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Path<'tree> {
-    const KIND: &'static str = "path";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MacroRule<'tree> {
+    const KIND: &'static str = "macro_rule";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2922,39 +3160,43 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Path<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `pi_expr`\n\nThis node has these fields:\n- `parameter`: `{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr])\n- `value`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n"]
+#[doc = "Typed node `map_entry`\n\nThis node has these fields:\n- `key`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct PiExpr<'tree>(tree_sitter::Node<'tree>);
+pub struct MapEntry<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> PiExpr<'tree> {
-    #[doc = "Get the field `parameter` which has kind `{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr])"]
+impl<'tree> MapEntry<'tree> {
+    #[doc = "Get the field `key` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
-    #[inline]    pub fn parameter (& self) -> type_sitter_lib :: NodeResult < 'tree , anon_unions :: AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > >{
-        self . 0 . child_by_field_name ("parameter") . map (< anon_unions :: AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    #[inline]
+    pub fn key(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("key") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
-    #[doc = "Get the field `value` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
     pub fn value(
         &self,
     ) -> type_sitter_lib::NodeResult<
         'tree,
-        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
-            'tree,
-        >,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
     > {
-        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for PiExpr<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MapEntry<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "pi_expr" {
+        if node.kind() == "map_entry" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -2965,8 +3207,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for PiExpr<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for PiExpr<'tree> {
-    const KIND: &'static str = "pi_expr";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MapEntry<'tree> {
+    const KIND: &'static str = "map_entry";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -2988,35 +3230,33 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for PiExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `pi_parameters`\n\nThis node has these fields:\n- `parameter`: `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])\n"]
+#[doc = "Typed node `map_expr`\n\nThis node has these fields:\n- `entry`: `map_entry*` ([MapEntry])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct PiParameters<'tree>(tree_sitter::Node<'tree>);
+pub struct MapExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> PiParameters<'tree> {
-    #[doc = "Get the field `parameter` which has kind `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])"]
-    #[doc = "This is guaranteed to return at least one child"]
+impl<'tree> MapExpr<'tree> {
+    #[doc = "Get the field `entry` which has kind `map_entry*` ([MapEntry])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn parameters<'a>(
+    pub fn entrys<'a>(
         &self,
         c: &'a mut tree_sitter::TreeCursor<'tree>,
     ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<'tree, anon_unions::Comma_ForallParameter_Parameter<'tree>>,
-        >,
+        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, MapEntry<'tree>>>,
     > + 'a {
-        self . 0 . children_by_field_name ("parameter" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Comma_ForallParameter_Parameter < 'tree > > as TryFrom < _ >> :: try_from (n))
+        self.0
+            .children_by_field_name("entry", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, MapEntry<'tree>> as TryFrom<_>>::try_from(n))
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for PiParameters<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MapExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "pi_parameters" {
+        if node.kind() == "map_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3027,8 +3267,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for PiParameters<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for PiParameters<'tree> {
-    const KIND: &'static str = "pi_parameters";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MapExpr<'tree> {
+    const KIND: &'static str = "map_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3050,25 +3290,65 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for PiParameters<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `primary`\n\nThis node has a child: `{array_expr | free_variable | if_expr | literal | match_expr | path | return_expr | tuple_expr | universe_expr}`:\n- [ArrayExpr]\n- [FreeVariable]\n- [IfExpr]\n- [Literal]\n- [MatchExpr]\n- [Path]\n- [ReturnExpr]\n- [TupleExpr]\n- [UniverseExpr]\n\n"]
+#[doc = "Typed node `match_arm`\n\nThis node has these fields:\n- `body`: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `guard`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Primary<'tree>(tree_sitter::Node<'tree>);
+pub struct MatchArm<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Primary<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> MatchArm<'tree> {
+    #[doc = "Get the field `body` which has kind `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn body(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . child_by_field_name ("body") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `guard` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
-    #[inline]    pub fn child (& self) -> type_sitter_lib :: NodeResult < 'tree , anon_unions :: ArrayExpr_FreeVariable_IfExpr_Literal_MatchExpr_Path_ReturnExpr_TupleExpr_UniverseExpr < 'tree > >{
-        self . 0 . named_child (0) . map (< anon_unions :: ArrayExpr_FreeVariable_IfExpr_Literal_MatchExpr_Path_ReturnExpr_TupleExpr_UniverseExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    #[inline]
+    pub fn guard(
+        &self,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            >,
+        >,
+    > {
+        self.0.child_by_field_name("guard").map(
+            <anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            > as TryFrom<_>>::try_from,
+        )
+    }
+
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn pattern(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+    > {
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Primary<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MatchArm<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "primary" {
+        if node.kind() == "match_arm" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3079,8 +3359,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Primary<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Primary<'tree> {
-    const KIND: &'static str = "primary";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MatchArm<'tree> {
+    const KIND: &'static str = "match_arm";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3102,39 +3382,45 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Primary<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `return_expr`\n\nThis node has these fields:\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[doc = "Typed node `match_expr`\n\nThis node has these fields:\n- `arm`: `match_arm*` ([MatchArm])\n- `scrutinee`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct ReturnExpr<'tree>(tree_sitter::Node<'tree>);
+pub struct MatchExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> ReturnExpr<'tree> {
-    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+impl<'tree> MatchExpr<'tree> {
+    #[doc = "Get the field `arm` which has kind `match_arm*` ([MatchArm])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn value(
+    pub fn arms<'a>(
         &self,
-    ) -> Option<
-        type_sitter_lib::NodeResult<
-            'tree,
-            anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                'tree,
-            >,
-        >,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, MatchArm<'tree>>>,
+    > + 'a {
+        self.0
+            .children_by_field_name("arm", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, MatchArm<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `scrutinee` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn scrutinee(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
     > {
-        self.0.child_by_field_name("value").map(
-            <anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                'tree,
-            > as TryFrom<_>>::try_from,
-        )
+        self . 0 . child_by_field_name ("scrutinee") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ReturnExpr<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MatchExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "return_expr" {
+        if node.kind() == "match_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3145,8 +3431,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ReturnExpr<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for ReturnExpr<'tree> {
-    const KIND: &'static str = "return_expr";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MatchExpr<'tree> {
+    const KIND: &'static str = "match_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3168,57 +3454,36 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for ReturnExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `sigma_expr`\n\nThis node has these fields:\n- `parameter`: `{, | cons_pattern | group_pattern | literal | parameter | rest_pattern}+` ([anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern])\n- `value`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n"]
+#[doc = "Typed node `mutual`\n\nThis node has these fields:\n- `inductive`: `inductive*` ([Inductive])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct SigmaExpr<'tree>(tree_sitter::Node<'tree>);
+pub struct Mutual<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> SigmaExpr<'tree> {
-    #[doc = "Get the field `parameter` which has kind `{, | cons_pattern | group_pattern | literal | parameter | rest_pattern}+` ([anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern])"]
-    #[doc = "This is guaranteed to return at least one child"]
+impl<'tree> Mutual<'tree> {
+    #[doc = "Get the field `inductive` which has kind `inductive*` ([Inductive])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn parameters<'a>(
+    pub fn inductives<'a>(
         &self,
         c: &'a mut tree_sitter::TreeCursor<'tree>,
     ) -> impl Iterator<
         Item = type_sitter_lib::NodeResult<
             'tree,
-            type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern<'tree>,
-            >,
+            type_sitter_lib::ExtraOr<'tree, Inductive<'tree>>,
         >,
     > + 'a {
-        self.0.children_by_field_name("parameter", c).map(|n| {
-            <type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern<'tree>,
-            > as TryFrom<_>>::try_from(n)
-        })
-    }
-
-    #[doc = "Get the field `value` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn value(
-        &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
-            'tree,
-        >,
-    > {
-        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self.0
+            .children_by_field_name("inductive", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Inductive<'tree>> as TryFrom<_>>::try_from(n))
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SigmaExpr<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Mutual<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "sigma_expr" {
+        if node.kind() == "mutual" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3229,8 +3494,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SigmaExpr<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for SigmaExpr<'tree> {
-    const KIND: &'static str = "sigma_expr";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Mutual<'tree> {
+    const KIND: &'static str = "mutual";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3252,116 +3517,30 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for SigmaExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `signature`\n\nThis node has these fields:\n- `argument`: `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])\n- `attribute`: `attribute*` ([Attribute])\n- `clause_type`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}?` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n- `doc_string`: `doc_string*` ([DocString])\n- `name`: `path` ([Path])\n- `value`: `block?` ([Block])\n- `visibility`: `visibility?` ([Visibility])\n"]
+#[doc = "Typed node `nat`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Signature<'tree>(tree_sitter::Node<'tree>);
+pub struct Nat<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Signature<'tree> {
-    #[doc = "Get the field `argument` which has kind `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])"]
+impl<'tree> Nat<'tree> {
+    #[doc = "Get the node's only named child"]
     #[allow(dead_code)]
     #[inline]
-    pub fn arguments<'a>(
+    pub fn child(
         &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::ExplicitArguments_ImplicitArguments<'tree>,
-            >,
-        >,
-    > + 'a {
-        self . 0 . children_by_field_name ("argument" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: ExplicitArguments_ImplicitArguments < 'tree > > as TryFrom < _ >> :: try_from (n))
-    }
-
-    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn attributes<'a>(
-        &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
-        >,
-    > + 'a {
-        self.0
-            .children_by_field_name("attribute", c)
-            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
-    }
-
-    #[doc = "Get the field `clause_type` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}?` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn clause_type(
-        &self,
-    ) -> Option<
-        type_sitter_lib::NodeResult<
-            'tree,
-            anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
-                'tree,
-            >,
-        >,
-    > {
-        self.0.child_by_field_name("clause_type").map(
-            <anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
-                'tree,
-            > as TryFrom<_>>::try_from,
-        )
-    }
-
-    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn doc_strings<'a>(
-        &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
-        >,
-    > + 'a {
-        self.0
-            .children_by_field_name("doc_string", c)
-            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
-    }
-
-    #[doc = "Get the field `name` which has kind `path` ([Path])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
-        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
-    }
-
-    #[doc = "Get the field `value` which has kind `block?` ([Block])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn value(&self) -> Option<type_sitter_lib::NodeResult<'tree, Block<'tree>>> {
-        self.0
-            .child_by_field_name("value")
-            .map(<Block<'tree> as TryFrom<_>>::try_from)
-    }
-
-    #[doc = "Get the field `visibility` which has kind `visibility?` ([Visibility])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn visibility(&self) -> Option<type_sitter_lib::NodeResult<'tree, Visibility<'tree>>> {
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
         self.0
-            .child_by_field_name("visibility")
-            .map(<Visibility<'tree> as TryFrom<_>>::try_from)
+            .named_child(0)
+            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Signature<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Nat<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "signature" {
+        if node.kind() == "nat" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3372,8 +3551,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Signature<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Signature<'tree> {
-    const KIND: &'static str = "signature";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Nat<'tree> {
+    const KIND: &'static str = "nat";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3395,74 +3574,33 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Signature<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `signature_constructor`\n\nThis node has these fields:\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `field_type`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n- `name`: `path` ([Path])\n"]
+#[doc = "Typed node `otherwise_body`\n\nThis node has these fields:\n- `value`: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct SignatureConstructor<'tree>(tree_sitter::Node<'tree>);
+pub struct OtherwiseBody<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> SignatureConstructor<'tree> {
-    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn attributes<'a>(
-        &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
-        >,
-    > + 'a {
-        self.0
-            .children_by_field_name("attribute", c)
-            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
-    }
-
-    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn doc_strings<'a>(
-        &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
-        >,
-    > + 'a {
-        self.0
-            .children_by_field_name("doc_string", c)
-            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
-    }
-
-    #[doc = "Get the field `field_type` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
+impl<'tree> OtherwiseBody<'tree> {
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn field_type(
+    pub fn value(
         &self,
     ) -> type_sitter_lib::NodeResult<
         'tree,
-        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
             'tree,
         >,
     > {
-        self . 0 . child_by_field_name ("field_type") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
-    }
-
-    #[doc = "Get the field `name` which has kind `path` ([Path])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
-        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SignatureConstructor<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for OtherwiseBody<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "signature_constructor" {
+        if node.kind() == "otherwise_body" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3473,8 +3611,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SignatureConstructor<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for SignatureConstructor<'tree> {
-    const KIND: &'static str = "signature_constructor";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for OtherwiseBody<'tree> {
+    const KIND: &'static str = "otherwise_body";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3496,51 +3634,28 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for SignatureConstructor<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `source_file`\n\nThis node has these fields:\n- `decl`: `{clause | command | inductive | signature | using}*` ([anon_unions::Clause_Command_Inductive_Signature_Using])\n- `hash_bang`: `hash_bang?` ([HashBang])\n"]
+#[doc = "Typed node `panic_expr`\n\nThis node has these fields:\n- `message`: `literal?` ([Literal])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct SourceFile<'tree>(tree_sitter::Node<'tree>);
+pub struct PanicExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> SourceFile<'tree> {
-    #[doc = "Get the field `decl` which has kind `{clause | command | inductive | signature | using}*` ([anon_unions::Clause_Command_Inductive_Signature_Using])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn decls<'a>(
-        &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::Clause_Command_Inductive_Signature_Using<'tree>,
-            >,
-        >,
-    > + 'a {
-        self.0.children_by_field_name("decl", c).map(|n| {
-            <type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::Clause_Command_Inductive_Signature_Using<'tree>,
-            > as TryFrom<_>>::try_from(n)
-        })
-    }
-
-    #[doc = "Get the field `hash_bang` which has kind `hash_bang?` ([HashBang])"]
+impl<'tree> PanicExpr<'tree> {
+    #[doc = "Get the field `message` which has kind `literal?` ([Literal])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn hash_bang(&self) -> Option<type_sitter_lib::NodeResult<'tree, HashBang<'tree>>> {
+    pub fn message(&self) -> Option<type_sitter_lib::NodeResult<'tree, Literal<'tree>>> {
         self.0
-            .child_by_field_name("hash_bang")
-            .map(<HashBang<'tree> as TryFrom<_>>::try_from)
+            .child_by_field_name("message")
+            .map(<Literal<'tree> as TryFrom<_>>::try_from)
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SourceFile<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for PanicExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "source_file" {
+        if node.kind() == "panic_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3551,8 +3666,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SourceFile<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for SourceFile<'tree> {
-    const KIND: &'static str = "source_file";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for PanicExpr<'tree> {
+    const KIND: &'static str = "panic_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3574,26 +3689,47 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for SourceFile<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `symbol_identifier`\n\nThis node has a child: `infix_op` ([InfixOp])\n"]
+#[doc = "Typed node `parameter`\n\nThis node has these fields:\n- `parameter_type`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct SymbolIdentifier<'tree>(tree_sitter::Node<'tree>);
+pub struct Parameter<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> SymbolIdentifier<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> Parameter<'tree> {
+    #[doc = "Get the field `parameter_type` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(&self) -> type_sitter_lib::NodeResult<'tree, InfixOp<'tree>> {
-        self . 0 . named_child (0) . map (< InfixOp < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    pub fn parameter_type(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . child_by_field_name ("parameter_type") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}?` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn pattern(
+        &self,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+        >,
+    > {
+        self . 0 . child_by_field_name ("pattern") . map (< anon_unions :: ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern < 'tree > as TryFrom < _ >> :: try_from)
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SymbolIdentifier<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Parameter<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "symbol_identifier" {
+        if node.kind() == "parameter" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3604,8 +3740,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SymbolIdentifier<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for SymbolIdentifier<'tree> {
-    const KIND: &'static str = "symbol_identifier";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Parameter<'tree> {
+    const KIND: &'static str = "parameter";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3627,33 +3763,37 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for SymbolIdentifier<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `then_body`\n\nThis node has a child: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [Block]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]\n\n"]
+#[doc = "Typed node `path`\n\nThis node has these fields:\n- `segment`: `identifier+` ([Identifier])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct ThenBody<'tree>(tree_sitter::Node<'tree>);
+pub struct Path<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> ThenBody<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> Path<'tree> {
+    #[doc = "Get the field `segment` which has kind `identifier+` ([Identifier])"]
+    #[doc = "This is guaranteed to return at least one child"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
+    pub fn segments<'a>(
         &self,
-    ) -> type_sitter_lib::NodeResult<
-        'tree,
-        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
             'tree,
+            type_sitter_lib::ExtraOr<'tree, Identifier<'tree>>,
         >,
-    > {
-        self . 0 . named_child (0) . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    > + 'a {
+        self.0.children_by_field_name("segment", c).map(|n| {
+            <type_sitter_lib::ExtraOr<'tree, Identifier<'tree>> as TryFrom<_>>::try_from(n)
+        })
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ThenBody<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Path<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "then_body" {
+        if node.kind() == "path" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3664,8 +3804,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ThenBody<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for ThenBody<'tree> {
-    const KIND: &'static str = "then_body";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Path<'tree> {
+    const KIND: &'static str = "path";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3687,73 +3827,39 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for ThenBody<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `tuple_expr`\n\nThis node has children: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]\n\n"]
+#[doc = "Typed node `pi_expr`\n\nThis node has these fields:\n- `parameter`: `{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr])\n- `value`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct TupleExpr<'tree>(tree_sitter::Node<'tree>);
+pub struct PiExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> TupleExpr<'tree> {
-    #[doc = "Get the node's named children"]
+impl<'tree> PiExpr<'tree> {
+    #[doc = "Get the field `parameter` which has kind `{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr])"]
     #[allow(dead_code)]
-    #[inline]
-    pub fn children<'a>(
-        &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl ExactSizeIterator<
-        Item = type_sitter_lib::NodeResult<
-            'tree,
-            type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                    'tree,
-                >,
-            >,
-        >,
-    > + 'a {
-        self.0.named_children(c).map(|n| {
-            <type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                    'tree,
-                >,
-            > as TryFrom<_>>::try_from(n)
-        })
+    #[inline]    pub fn parameter (& self) -> type_sitter_lib :: NodeResult < 'tree , anon_unions :: AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > >{
+        self . 0 . child_by_field_name ("parameter") . map (< anon_unions :: AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
-    #[doc = "Get the node's named child #i"]
+    #[doc = "Get the field `value` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
+    pub fn value(
         &self,
-        i: usize,
-    ) -> Option<
-        type_sitter_lib::NodeResult<
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
             'tree,
-            type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                    'tree,
-                >,
-            >,
         >,
     > {
-        self.0.named_child(i).map(
-            <type_sitter_lib::ExtraOr<
-                'tree,
-                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
-                    'tree,
-                >,
-            > as TryFrom<_>>::try_from,
-        )
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TupleExpr<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for PiExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "tuple_expr" {
+        if node.kind() == "pi_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3764,8 +3870,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TupleExpr<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for TupleExpr<'tree> {
-    const KIND: &'static str = "tuple_expr";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for PiExpr<'tree> {
+    const KIND: &'static str = "pi_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3787,40 +3893,35 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for TupleExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `type_app_expr`\n\nThis node has these fields:\n- `argument`: `primary*` ([Primary])\n- `callee`: `primary` ([Primary])\n"]
+#[doc = "Typed node `pi_parameters`\n\nThis node has these fields:\n- `parameter`: `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct TypeAppExpr<'tree>(tree_sitter::Node<'tree>);
+pub struct PiParameters<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> TypeAppExpr<'tree> {
-    #[doc = "Get the field `argument` which has kind `primary*` ([Primary])"]
+impl<'tree> PiParameters<'tree> {
+    #[doc = "Get the field `parameter` which has kind `{, | forall_parameter | parameter}+` ([anon_unions::Comma_ForallParameter_Parameter])"]
+    #[doc = "This is guaranteed to return at least one child"]
     #[allow(dead_code)]
     #[inline]
-    pub fn arguments<'a>(
+    pub fn parameters<'a>(
         &self,
         c: &'a mut tree_sitter::TreeCursor<'tree>,
     ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, Primary<'tree>>>,
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, anon_unions::Comma_ForallParameter_Parameter<'tree>>,
+        >,
     > + 'a {
-        self.0
-            .children_by_field_name("argument", c)
-            .map(|n| <type_sitter_lib::ExtraOr<'tree, Primary<'tree>> as TryFrom<_>>::try_from(n))
-    }
-
-    #[doc = "Get the field `callee` which has kind `primary` ([Primary])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn callee(&self) -> type_sitter_lib::NodeResult<'tree, Primary<'tree>> {
-        self . 0 . child_by_field_name ("callee") . map (< Primary < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+        self . 0 . children_by_field_name ("parameter" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Comma_ForallParameter_Parameter < 'tree > > as TryFrom < _ >> :: try_from (n))
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TypeAppExpr<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for PiParameters<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "type_app_expr" {
+        if node.kind() == "pi_parameters" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3831,8 +3932,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TypeAppExpr<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for TypeAppExpr<'tree> {
-    const KIND: &'static str = "type_app_expr";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for PiParameters<'tree> {
+    const KIND: &'static str = "pi_parameters";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3854,30 +3955,25 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for TypeAppExpr<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `u1`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[doc = "Typed node `primary`\n\nThis node has a child: `{array_expr | free_variable | if_expr | let_expr | literal | macro_call | macro_variable | map_expr | match_expr | panic_expr | path | projection_expr | quote_expr | record_expr | record_update | refl_expr | return_expr | rewrite_expr | section_expr | set_expr | splice_expr | tuple_expr | universe_expr}`:\n- [ArrayExpr]\n- [FreeVariable]\n- [IfExpr]\n- [LetExpr]\n- [Literal]\n- [MacroCall]\n- [MacroVariable]\n- [MapExpr]\n- [MatchExpr]\n- [PanicExpr]\n- [Path]\n- [ProjectionExpr]\n- [QuoteExpr]\n- [RecordExpr]\n- [RecordUpdate]\n- [ReflExpr]\n- [ReturnExpr]\n- [RewriteExpr]\n- [SectionExpr]\n- [SetExpr]\n- [SpliceExpr]\n- [TupleExpr]\n- [UniverseExpr]\n\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct U1<'tree>(tree_sitter::Node<'tree>);
+pub struct Primary<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> U1<'tree> {
+impl<'tree> Primary<'tree> {
     #[doc = "Get the node's only named child"]
     #[allow(dead_code)]
-    #[inline]
-    pub fn child(
-        &self,
-    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
-        self.0
-            .named_child(0)
-            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    #[inline]    pub fn child (& self) -> type_sitter_lib :: NodeResult < 'tree , anon_unions :: ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr < 'tree > >{
+        self . 0 . named_child (0) . map (< anon_unions :: ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U1<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Primary<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "u1" {
+        if node.kind() == "primary" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3888,8 +3984,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U1<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for U1<'tree> {
-    const KIND: &'static str = "u1";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Primary<'tree> {
+    const KIND: &'static str = "primary";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3911,30 +4007,33 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for U1<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `u128`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[doc = "Typed node `projection_expr`\n\nThis node has these fields:\n- `position`: `tuple_position` ([TuplePosition])\n- `value`: `primary` ([Primary])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct U128<'tree>(tree_sitter::Node<'tree>);
+pub struct ProjectionExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> U128<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> ProjectionExpr<'tree> {
+    #[doc = "Get the field `position` which has kind `tuple_position` ([TuplePosition])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
-        &self,
-    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
-        self.0
-            .named_child(0)
-            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    pub fn position(&self) -> type_sitter_lib::NodeResult<'tree, TuplePosition<'tree>> {
+        self . 0 . child_by_field_name ("position") . map (< TuplePosition < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `value` which has kind `primary` ([Primary])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(&self) -> type_sitter_lib::NodeResult<'tree, Primary<'tree>> {
+        self . 0 . child_by_field_name ("value") . map (< Primary < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U128<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ProjectionExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "u128" {
+        if node.kind() == "projection_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -3945,8 +4044,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U128<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for U128<'tree> {
-    const KIND: &'static str = "u128";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for ProjectionExpr<'tree> {
+    const KIND: &'static str = "projection_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -3968,30 +4067,31 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for U128<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `u16`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[doc = "Typed node `quote_expr`\n\nThis node has these fields:\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct U16<'tree>(tree_sitter::Node<'tree>);
+pub struct QuoteExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> U16<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> QuoteExpr<'tree> {
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
+    pub fn value(
         &self,
-    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
-        self.0
-            .named_child(0)
-            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U16<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for QuoteExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "u16" {
+        if node.kind() == "quote_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4002,8 +4102,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U16<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for U16<'tree> {
-    const KIND: &'static str = "u16";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for QuoteExpr<'tree> {
+    const KIND: &'static str = "quote_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4025,30 +4125,37 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for U16<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `u32`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[doc = "Typed node `record_expr`\n\nThis node has these fields:\n- `field`: `record_field+` ([RecordField])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct U32<'tree>(tree_sitter::Node<'tree>);
+pub struct RecordExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> U32<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> RecordExpr<'tree> {
+    #[doc = "Get the field `field` which has kind `record_field+` ([RecordField])"]
+    #[doc = "This is guaranteed to return at least one child"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
+    pub fn fields<'a>(
         &self,
-    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
-        self.0
-            .named_child(0)
-            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, RecordField<'tree>>,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("field", c).map(|n| {
+            <type_sitter_lib::ExtraOr<'tree, RecordField<'tree>> as TryFrom<_>>::try_from(n)
+        })
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U32<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for RecordExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "u32" {
+        if node.kind() == "record_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4059,8 +4166,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U32<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for U32<'tree> {
-    const KIND: &'static str = "u32";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for RecordExpr<'tree> {
+    const KIND: &'static str = "record_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4082,30 +4189,38 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for U32<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `u64`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[doc = "Typed node `record_field`\n\nThis node has these fields:\n- `name`: `path` ([Path])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct U64<'tree>(tree_sitter::Node<'tree>);
+pub struct RecordField<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> U64<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> RecordField<'tree> {
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(
         &self,
-    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
-        self.0
-            .named_child(0)
-            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U64<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for RecordField<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "u64" {
+        if node.kind() == "record_field" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4116,8 +4231,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U64<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for U64<'tree> {
-    const KIND: &'static str = "u64";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for RecordField<'tree> {
+    const KIND: &'static str = "record_field";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4139,30 +4254,49 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for U64<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `u8`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[doc = "Typed node `record_update`\n\nThis node has these fields:\n- `field`: `record_field+` ([RecordField])\n- `record`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct U8<'tree>(tree_sitter::Node<'tree>);
+pub struct RecordUpdate<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> U8<'tree> {
-    #[doc = "Get the node's only named child"]
+impl<'tree> RecordUpdate<'tree> {
+    #[doc = "Get the field `field` which has kind `record_field+` ([RecordField])"]
+    #[doc = "This is guaranteed to return at least one child"]
     #[allow(dead_code)]
     #[inline]
-    pub fn child(
+    pub fn fields<'a>(
         &self,
-    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
-        self.0
-            .named_child(0)
-            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, RecordField<'tree>>,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("field", c).map(|n| {
+            <type_sitter_lib::ExtraOr<'tree, RecordField<'tree>> as TryFrom<_>>::try_from(n)
+        })
+    }
+
+    #[doc = "Get the field `record` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn record(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("record") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U8<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for RecordUpdate<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "u8" {
+        if node.kind() == "record_update" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4173,8 +4307,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U8<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for U8<'tree> {
-    const KIND: &'static str = "u8";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for RecordUpdate<'tree> {
+    const KIND: &'static str = "record_update";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4196,43 +4330,39 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for U8<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `using`\n\nThis node has these fields:\n- `attribute`: `attribute*` ([Attribute])\n- `path`: `path` ([Path])\n"]
+#[doc = "Typed node `return_expr`\n\nThis node has these fields:\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Using<'tree>(tree_sitter::Node<'tree>);
+pub struct ReturnExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> Using<'tree> {
-    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
+impl<'tree> ReturnExpr<'tree> {
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
     #[allow(dead_code)]
     #[inline]
-    pub fn attributes<'a>(
+    pub fn value(
         &self,
-        c: &'a mut tree_sitter::TreeCursor<'tree>,
-    ) -> impl Iterator<
-        Item = type_sitter_lib::NodeResult<
+    ) -> Option<
+        type_sitter_lib::NodeResult<
             'tree,
-            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
+            anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            >,
         >,
-    > + 'a {
-        self.0
-            .children_by_field_name("attribute", c)
-            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
-    }
-
-    #[doc = "Get the field `path` which has kind `path` ([Path])"]
-    #[allow(dead_code)]
-    #[inline]
-    pub fn path(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
-        self . 0 . child_by_field_name ("path") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    > {
+        self.0.child_by_field_name("value").map(
+            <anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            > as TryFrom<_>>::try_from,
+        )
     }
 }
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Using<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ReturnExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "using" {
+        if node.kind() == "return_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4243,8 +4373,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Using<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Using<'tree> {
-    const KIND: &'static str = "using";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for ReturnExpr<'tree> {
+    const KIND: &'static str = "return_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4266,65 +4396,43 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Using<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `visibility`\n\nThis node has no children\n"]
+#[doc = "Typed node `rewrite_expr`\n\nThis node has these fields:\n- `equality`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct Visibility<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> Visibility<'tree> {}
-#[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Visibility<'tree> {
-    type Error = type_sitter_lib::IncorrectKind<'tree>;
-
-    #[inline]
-    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "visibility" {
-            Ok(Self(node))
-        } else {
-            Err(type_sitter_lib::IncorrectKind {
-                node,
-                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-            })
-        }
-    }
-}
+pub struct RewriteExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Visibility<'tree> {
-    const KIND: &'static str = "visibility";
-
-    #[inline]
-    fn node(&self) -> &tree_sitter::Node<'tree> {
-        &self.0
-    }
-
-    #[inline]
-    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-        &mut self.0
-    }
-
+impl<'tree> RewriteExpr<'tree> {
+    #[doc = "Get the field `equality` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
     #[inline]
-    fn into_node(self) -> tree_sitter::Node<'tree> {
-        self.0
+    pub fn equality(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("equality") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
     #[inline]
-    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
-        Self(node)
+    pub fn value(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 }
-#[doc = "Typed node `char`\n\nThis node has no children\n"]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[allow(non_camel_case_types)]
-pub struct Char<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> Char<'tree> {}
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Char<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for RewriteExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "char" {
+        if node.kind() == "rewrite_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4335,8 +4443,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Char<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for Char<'tree> {
-    const KIND: &'static str = "char";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for RewriteExpr<'tree> {
+    const KIND: &'static str = "rewrite_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4358,65 +4466,57 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for Char<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `hash_bang`\n\nThis node has no children\n"]
+#[doc = "Typed node `section_expr`\n\nThis node has these fields:\n- `lhs`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `op`: `infix_op` ([InfixOp])\n- `rhs`: `{binary_expr | primary}?` ([anon_unions::BinaryExpr_Primary])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct HashBang<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> HashBang<'tree> {}
-#[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for HashBang<'tree> {
-    type Error = type_sitter_lib::IncorrectKind<'tree>;
-
-    #[inline]
-    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "hash_bang" {
-            Ok(Self(node))
-        } else {
-            Err(type_sitter_lib::IncorrectKind {
-                node,
-                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-            })
-        }
-    }
-}
+pub struct SectionExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for HashBang<'tree> {
-    const KIND: &'static str = "hash_bang";
-
+impl<'tree> SectionExpr<'tree> {
+    #[doc = "Get the field `lhs` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}?` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
     #[inline]
-    fn node(&self) -> &tree_sitter::Node<'tree> {
-        &self.0
+    pub fn lhs(
+        &self,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            >,
+        >,
+    > {
+        self.0.child_by_field_name("lhs").map(
+            <anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                'tree,
+            > as TryFrom<_>>::try_from,
+        )
     }
 
+    #[doc = "Get the field `op` which has kind `infix_op` ([InfixOp])"]
+    #[allow(dead_code)]
     #[inline]
-    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-        &mut self.0
+    pub fn op(&self) -> type_sitter_lib::NodeResult<'tree, InfixOp<'tree>> {
+        self . 0 . child_by_field_name ("op") . map (< InfixOp < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
     }
 
+    #[doc = "Get the field `rhs` which has kind `{binary_expr | primary}?` ([anon_unions::BinaryExpr_Primary])"]
+    #[allow(dead_code)]
     #[inline]
-    fn into_node(self) -> tree_sitter::Node<'tree> {
+    pub fn rhs(
+        &self,
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::BinaryExpr_Primary<'tree>>> {
         self.0
-    }
-
-    #[inline]
-    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
-        Self(node)
+            .child_by_field_name("rhs")
+            .map(<anon_unions::BinaryExpr_Primary<'tree> as TryFrom<_>>::try_from)
     }
 }
-#[doc = "Typed node `rest_pattern`\n\nThis node has no children\n"]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[allow(non_camel_case_types)]
-pub struct RestPattern<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> RestPattern<'tree> {}
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for RestPattern<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SectionExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "rest_pattern" {
+        if node.kind() == "section_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4427,8 +4527,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for RestPattern<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for RestPattern<'tree> {
-    const KIND: &'static str = "rest_pattern";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SectionExpr<'tree> {
+    const KIND: &'static str = "section_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4450,19 +4550,47 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for RestPattern<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `simple_identifier`\n\nThis node has no children\n"]
+#[doc = "Typed node `set_expr`\n\nThis node has these fields:\n- `item`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}+` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct SimpleIdentifier<'tree>(tree_sitter::Node<'tree>);
+pub struct SetExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> SimpleIdentifier<'tree> {}
+impl<'tree> SetExpr<'tree> {
+    #[doc = "Get the field `item` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}+` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[doc = "This is guaranteed to return at least one child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn items<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            >,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("item", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
+}
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SimpleIdentifier<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SetExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "simple_identifier" {
+        if node.kind() == "set_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4473,8 +4601,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SimpleIdentifier<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for SimpleIdentifier<'tree> {
-    const KIND: &'static str = "simple_identifier";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SetExpr<'tree> {
+    const KIND: &'static str = "set_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4496,19 +4624,40 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for SimpleIdentifier<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `string`\n\nThis node has no children\n"]
+#[doc = "Typed node `sigma_expr`\n\nThis node has these fields:\n- `parameter`: `{, | cons_pattern | group_pattern | literal | parameter | rest_pattern | tuple_pattern}+` ([anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern])\n- `value`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct String<'tree>(tree_sitter::Node<'tree>);
+pub struct SigmaExpr<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> String<'tree> {}
+impl<'tree> SigmaExpr<'tree> {
+    #[doc = "Get the field `parameter` which has kind `{, | cons_pattern | group_pattern | literal | parameter | rest_pattern | tuple_pattern}+` ([anon_unions::Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern])"]
+    #[doc = "This is guaranteed to return at least one child"]
+    #[allow(dead_code)]
+    #[inline]    pub fn parameters < 'a > (& self , c : & 'a mut tree_sitter :: TreeCursor < 'tree >) -> impl Iterator < Item = type_sitter_lib :: NodeResult < 'tree , type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern < 'tree > > >> + 'a{
+        self . 0 . children_by_field_name ("parameter" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern < 'tree > > as TryFrom < _ >> :: try_from (n))
+    }
+
+    #[doc = "Get the field `value` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for String<'tree> {
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SigmaExpr<'tree> {
     type Error = type_sitter_lib::IncorrectKind<'tree>;
 
     #[inline]
     fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "string" {
+        if node.kind() == "sigma_expr" {
             Ok(Self(node))
         } else {
             Err(type_sitter_lib::IncorrectKind {
@@ -4519,8 +4668,8 @@ impl<'tree> TryFrom<tree_sitter::Node<'tree>> for String<'tree> {
     }
 }
 #[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for String<'tree> {
-    const KIND: &'static str = "string";
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SigmaExpr<'tree> {
+    const KIND: &'static str = "sigma_expr";
 
     #[inline]
     fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4542,68 +4691,3095 @@ impl<'tree> type_sitter_lib::TypedNode<'tree> for String<'tree> {
         Self(node)
     }
 }
-#[doc = "Typed node `universe_expr`\n\nThis node has no children\n"]
+#[doc = "Typed node `signature`\n\nThis node has these fields:\n- `argument`: `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])\n- `attribute`: `attribute*` ([Attribute])\n- `clause_type`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}?` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n- `doc_string`: `doc_string*` ([DocString])\n- `name`: `path` ([Path])\n- `value`: `block?` ([Block])\n- `visibility`: `visibility?` ([Visibility])\n"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct UniverseExpr<'tree>(tree_sitter::Node<'tree>);
-#[automatically_derived]
-impl<'tree> UniverseExpr<'tree> {}
+pub struct Signature<'tree>(tree_sitter::Node<'tree>);
 #[automatically_derived]
-impl<'tree> TryFrom<tree_sitter::Node<'tree>> for UniverseExpr<'tree> {
-    type Error = type_sitter_lib::IncorrectKind<'tree>;
-
+impl<'tree> Signature<'tree> {
+    #[doc = "Get the field `argument` which has kind `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])"]
+    #[allow(dead_code)]
     #[inline]
-    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-        if node.kind() == "universe_expr" {
-            Ok(Self(node))
-        } else {
-            Err(type_sitter_lib::IncorrectKind {
-                node,
-                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-            })
-        }
+    pub fn arguments<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ExplicitArguments_ImplicitArguments<'tree>,
+            >,
+        >,
+    > + 'a {
+        self . 0 . children_by_field_name ("argument" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: ExplicitArguments_ImplicitArguments < 'tree > > as TryFrom < _ >> :: try_from (n))
     }
-}
-#[automatically_derived]
-impl<'tree> type_sitter_lib::TypedNode<'tree> for UniverseExpr<'tree> {
-    const KIND: &'static str = "universe_expr";
 
+    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
+    #[allow(dead_code)]
     #[inline]
-    fn node(&self) -> &tree_sitter::Node<'tree> {
-        &self.0
-    }
+    pub fn attributes<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("attribute", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `clause_type` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}?` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn clause_type(
+        &self,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+                'tree,
+            >,
+        >,
+    > {
+        self.0.child_by_field_name("clause_type").map(
+            <anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+                'tree,
+            > as TryFrom<_>>::try_from,
+        )
+    }
+
+    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn doc_strings<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("doc_string", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `value` which has kind `block?` ([Block])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(&self) -> Option<type_sitter_lib::NodeResult<'tree, Block<'tree>>> {
+        self.0
+            .child_by_field_name("value")
+            .map(<Block<'tree> as TryFrom<_>>::try_from)
+    }
+
+    #[doc = "Get the field `visibility` which has kind `visibility?` ([Visibility])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn visibility(&self) -> Option<type_sitter_lib::NodeResult<'tree, Visibility<'tree>>> {
+        self.0
+            .child_by_field_name("visibility")
+            .map(<Visibility<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Signature<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "signature" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Signature<'tree> {
+    const KIND: &'static str = "signature";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `signature_constructor`\n\nThis node has these fields:\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `field_type`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n- `name`: `path` ([Path])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct SignatureConstructor<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> SignatureConstructor<'tree> {
+    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn attributes<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("attribute", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn doc_strings<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("doc_string", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `field_type` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn field_type(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . child_by_field_name ("field_type") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SignatureConstructor<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "signature_constructor" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SignatureConstructor<'tree> {
+    const KIND: &'static str = "signature_constructor";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `source_file`\n\nThis node has these fields:\n- `decl`: `{clause | command | inductive | instance | macro_decl | mutual | signature | trait_decl | type_alias | using}*` ([anon_unions::Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using])\n- `hash_bang`: `hash_bang?` ([HashBang])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct SourceFile<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> SourceFile<'tree> {
+    #[doc = "Get the field `decl` which has kind `{clause | command | inductive | instance | macro_decl | mutual | signature | trait_decl | type_alias | using}*` ([anon_unions::Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using])"]
+    #[allow(dead_code)]
+    #[inline]    pub fn decls < 'a > (& self , c : & 'a mut tree_sitter :: TreeCursor < 'tree >) -> impl Iterator < Item = type_sitter_lib :: NodeResult < 'tree , type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using < 'tree > > >> + 'a{
+        self . 0 . children_by_field_name ("decl" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using < 'tree > > as TryFrom < _ >> :: try_from (n))
+    }
+
+    #[doc = "Get the field `hash_bang` which has kind `hash_bang?` ([HashBang])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn hash_bang(&self) -> Option<type_sitter_lib::NodeResult<'tree, HashBang<'tree>>> {
+        self.0
+            .child_by_field_name("hash_bang")
+            .map(<HashBang<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SourceFile<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "source_file" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SourceFile<'tree> {
+    const KIND: &'static str = "source_file";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `splice_expr`\n\nThis node has these fields:\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct SpliceExpr<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> SpliceExpr<'tree> {
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SpliceExpr<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "splice_expr" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SpliceExpr<'tree> {
+    const KIND: &'static str = "splice_expr";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `symbol_identifier`\n\nThis node has a child: `infix_op` ([InfixOp])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct SymbolIdentifier<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> SymbolIdentifier<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(&self) -> type_sitter_lib::NodeResult<'tree, InfixOp<'tree>> {
+        self . 0 . named_child (0) . map (< InfixOp < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SymbolIdentifier<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "symbol_identifier" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SymbolIdentifier<'tree> {
+    const KIND: &'static str = "symbol_identifier";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `then_body`\n\nThis node has a child: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [Block]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct ThenBody<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> ThenBody<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . named_child (0) . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ThenBody<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "then_body" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for ThenBody<'tree> {
+    const KIND: &'static str = "then_body";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `trailing_lambda`\n\nThis node has these fields:\n- `parameter`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}+` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n- `value`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct TrailingLambda<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> TrailingLambda<'tree> {
+    #[doc = "Get the field `parameter` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}+` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[doc = "This is guaranteed to return at least one child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn parameters<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            >,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("parameter", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
+
+    #[doc = "Get the field `value` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TrailingLambda<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "trailing_lambda" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for TrailingLambda<'tree> {
+    const KIND: &'static str = "trailing_lambda";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `trait_decl`\n\nThis node has these fields:\n- `argument`: `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `method`: `signature*` ([Signature])\n- `name`: `path` ([Path])\n- `requirement`: `constraint*` ([Constraint])\n- `visibility`: `visibility?` ([Visibility])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct TraitDecl<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> TraitDecl<'tree> {
+    #[doc = "Get the field `argument` which has kind `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn arguments<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ExplicitArguments_ImplicitArguments<'tree>,
+            >,
+        >,
+    > + 'a {
+        self . 0 . children_by_field_name ("argument" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: ExplicitArguments_ImplicitArguments < 'tree > > as TryFrom < _ >> :: try_from (n))
+    }
+
+    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn attributes<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("attribute", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn doc_strings<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("doc_string", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `method` which has kind `signature*` ([Signature])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn methods<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Signature<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("method", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Signature<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `requirement` which has kind `constraint*` ([Constraint])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn requirements<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Constraint<'tree>>,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("requirement", c).map(|n| {
+            <type_sitter_lib::ExtraOr<'tree, Constraint<'tree>> as TryFrom<_>>::try_from(n)
+        })
+    }
+
+    #[doc = "Get the field `visibility` which has kind `visibility?` ([Visibility])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn visibility(&self) -> Option<type_sitter_lib::NodeResult<'tree, Visibility<'tree>>> {
+        self.0
+            .child_by_field_name("visibility")
+            .map(<Visibility<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TraitDecl<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "trait_decl" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for TraitDecl<'tree> {
+    const KIND: &'static str = "trait_decl";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `tuple_expr`\n\nThis node has children: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}*`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct TupleExpr<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> TupleExpr<'tree> {
+    #[doc = "Get the node's named children"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn children<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl ExactSizeIterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            >,
+        >,
+    > + 'a {
+        self.0.named_children(c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
+
+    #[doc = "Get the node's named child #i"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+        i: usize,
+    ) -> Option<
+        type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            >,
+        >,
+    > {
+        self.0.named_child(i).map(
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+                    'tree,
+                >,
+            > as TryFrom<_>>::try_from,
+        )
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TupleExpr<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "tuple_expr" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for TupleExpr<'tree> {
+    const KIND: &'static str = "tuple_expr";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `tuple_pattern`\n\nThis node has these fields:\n- `pattern`: `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}+` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct TuplePattern<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> TuplePattern<'tree> {
+    #[doc = "Get the field `pattern` which has kind `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}+` ([anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern])"]
+    #[doc = "This is guaranteed to return at least one child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn patterns<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            >,
+        >,
+    > + 'a {
+        self.0.children_by_field_name("pattern", c).map(|n| {
+            <type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>,
+            > as TryFrom<_>>::try_from(n)
+        })
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TuplePattern<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "tuple_pattern" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for TuplePattern<'tree> {
+    const KIND: &'static str = "tuple_pattern";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `type_alias`\n\nThis node has these fields:\n- `argument`: `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])\n- `attribute`: `attribute*` ([Attribute])\n- `doc_string`: `doc_string*` ([DocString])\n- `name`: `path` ([Path])\n- `value`: `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])\n- `visibility`: `visibility?` ([Visibility])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct TypeAlias<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> TypeAlias<'tree> {
+    #[doc = "Get the field `argument` which has kind `{explicit_arguments | implicit_arguments}*` ([anon_unions::ExplicitArguments_ImplicitArguments])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn arguments<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<
+                'tree,
+                anon_unions::ExplicitArguments_ImplicitArguments<'tree>,
+            >,
+        >,
+    > + 'a {
+        self . 0 . children_by_field_name ("argument" , c) . map (| n | < type_sitter_lib :: ExtraOr < 'tree , anon_unions :: ExplicitArguments_ImplicitArguments < 'tree > > as TryFrom < _ >> :: try_from (n))
+    }
+
+    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn attributes<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("attribute", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `doc_string` which has kind `doc_string*` ([DocString])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn doc_strings<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, DocString<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("doc_string", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, DocString<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `name` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn name(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("name") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `value` which has kind `{ann_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | type_app_expr}` ([anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn value(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . child_by_field_name ("value") . map (< anon_unions :: AnnExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_TypeAppExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `visibility` which has kind `visibility?` ([Visibility])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn visibility(&self) -> Option<type_sitter_lib::NodeResult<'tree, Visibility<'tree>>> {
+        self.0
+            .child_by_field_name("visibility")
+            .map(<Visibility<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TypeAlias<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "type_alias" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for TypeAlias<'tree> {
+    const KIND: &'static str = "type_alias";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `type_app_expr`\n\nThis node has these fields:\n- `argument`: `primary*` ([Primary])\n- `callee`: `primary` ([Primary])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct TypeAppExpr<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> TypeAppExpr<'tree> {
+    #[doc = "Get the field `argument` which has kind `primary*` ([Primary])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn arguments<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<'tree, type_sitter_lib::ExtraOr<'tree, Primary<'tree>>>,
+    > + 'a {
+        self.0
+            .children_by_field_name("argument", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Primary<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `callee` which has kind `primary` ([Primary])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn callee(&self) -> type_sitter_lib::NodeResult<'tree, Primary<'tree>> {
+        self . 0 . child_by_field_name ("callee") . map (< Primary < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TypeAppExpr<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "type_app_expr" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for TypeAppExpr<'tree> {
+    const KIND: &'static str = "type_app_expr";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `u1`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct U1<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> U1<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
+        self.0
+            .named_child(0)
+            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U1<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "u1" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for U1<'tree> {
+    const KIND: &'static str = "u1";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `u128`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct U128<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> U128<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
+        self.0
+            .named_child(0)
+            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U128<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "u128" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for U128<'tree> {
+    const KIND: &'static str = "u128";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `u16`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct U16<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> U16<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
+        self.0
+            .named_child(0)
+            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U16<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "u16" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for U16<'tree> {
+    const KIND: &'static str = "u16";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `u32`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct U32<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> U32<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
+        self.0
+            .named_child(0)
+            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U32<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "u32" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for U32<'tree> {
+    const KIND: &'static str = "u32";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `u64`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct U64<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> U64<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
+        self.0
+            .named_child(0)
+            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U64<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "u64" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for U64<'tree> {
+    const KIND: &'static str = "u64";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `u8`\n\nThis node has an (optional) child: `{binary | hex | octal}?`:\n- [Binary]\n- [Hex]\n- [Octal]\n\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct U8<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> U8<'tree> {
+    #[doc = "Get the node's only named child"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn child(
+        &self,
+    ) -> Option<type_sitter_lib::NodeResult<'tree, anon_unions::Binary_Hex_Octal<'tree>>> {
+        self.0
+            .named_child(0)
+            .map(<anon_unions::Binary_Hex_Octal<'tree> as TryFrom<_>>::try_from)
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U8<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "u8" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for U8<'tree> {
+    const KIND: &'static str = "u8";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `using`\n\nThis node has these fields:\n- `attribute`: `attribute*` ([Attribute])\n- `path`: `path` ([Path])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct Using<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> Using<'tree> {
+    #[doc = "Get the field `attribute` which has kind `attribute*` ([Attribute])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn attributes<'a>(
+        &self,
+        c: &'a mut tree_sitter::TreeCursor<'tree>,
+    ) -> impl Iterator<
+        Item = type_sitter_lib::NodeResult<
+            'tree,
+            type_sitter_lib::ExtraOr<'tree, Attribute<'tree>>,
+        >,
+    > + 'a {
+        self.0
+            .children_by_field_name("attribute", c)
+            .map(|n| <type_sitter_lib::ExtraOr<'tree, Attribute<'tree>> as TryFrom<_>>::try_from(n))
+    }
+
+    #[doc = "Get the field `path` which has kind `path` ([Path])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn path(&self) -> type_sitter_lib::NodeResult<'tree, Path<'tree>> {
+        self . 0 . child_by_field_name ("path") . map (< Path < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Using<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "using" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Using<'tree> {
+    const KIND: &'static str = "using";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `visibility`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct Visibility<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> Visibility<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Visibility<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "visibility" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Visibility<'tree> {
+    const KIND: &'static str = "visibility";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `while_stmt`\n\nThis node has these fields:\n- `body`: `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n- `condition`: `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct WhileStmt<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> WhileStmt<'tree> {
+    #[doc = "Get the field `body` which has kind `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn body(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<
+            'tree,
+        >,
+    > {
+        self . 0 . child_by_field_name ("body") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+
+    #[doc = "Get the field `condition` which has kind `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr}` ([anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr])"]
+    #[allow(dead_code)]
+    #[inline]
+    pub fn condition(
+        &self,
+    ) -> type_sitter_lib::NodeResult<
+        'tree,
+        anon_unions::AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>,
+    > {
+        self . 0 . child_by_field_name ("condition") . map (< anon_unions :: AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr < 'tree > as TryFrom < _ >> :: try_from) . expect ("tree-sitter node missing its required child, there should at least be a MISSING node in its place")
+    }
+}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for WhileStmt<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "while_stmt" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for WhileStmt<'tree> {
+    const KIND: &'static str = "while_stmt";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `binary`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct Binary<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> Binary<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Binary<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "binary" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Binary<'tree> {
+    const KIND: &'static str = "binary";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `block_comment`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct BlockComment<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> BlockComment<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for BlockComment<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "block_comment" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for BlockComment<'tree> {
+    const KIND: &'static str = "block_comment";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `char`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct Char<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> Char<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Char<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "char" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Char<'tree> {
+    const KIND: &'static str = "char";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `hash_bang`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct HashBang<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> HashBang<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for HashBang<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "hash_bang" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for HashBang<'tree> {
+    const KIND: &'static str = "hash_bang";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `hex`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct Hex<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> Hex<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Hex<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "hex" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Hex<'tree> {
+    const KIND: &'static str = "hex";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `macro_variable`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct MacroVariable<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> MacroVariable<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for MacroVariable<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "macro_variable" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for MacroVariable<'tree> {
+    const KIND: &'static str = "macro_variable";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `octal`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct Octal<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> Octal<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Octal<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "octal" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for Octal<'tree> {
+    const KIND: &'static str = "octal";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `refl_expr`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct ReflExpr<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> ReflExpr<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for ReflExpr<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "refl_expr" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for ReflExpr<'tree> {
+    const KIND: &'static str = "refl_expr";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `rest_pattern`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct RestPattern<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> RestPattern<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for RestPattern<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "rest_pattern" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for RestPattern<'tree> {
+    const KIND: &'static str = "rest_pattern";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `simple_identifier`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct SimpleIdentifier<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> SimpleIdentifier<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for SimpleIdentifier<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "simple_identifier" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for SimpleIdentifier<'tree> {
+    const KIND: &'static str = "simple_identifier";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `string`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct String<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> String<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for String<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "string" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for String<'tree> {
+    const KIND: &'static str = "string";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `tuple_position`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct TuplePosition<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> TuplePosition<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for TuplePosition<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "tuple_position" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for TuplePosition<'tree> {
+    const KIND: &'static str = "tuple_position";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+#[doc = "Typed node `universe_expr`\n\nThis node has no children\n"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub struct UniverseExpr<'tree>(tree_sitter::Node<'tree>);
+#[automatically_derived]
+impl<'tree> UniverseExpr<'tree> {}
+#[automatically_derived]
+impl<'tree> TryFrom<tree_sitter::Node<'tree>> for UniverseExpr<'tree> {
+    type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+    #[inline]
+    fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+        if node.kind() == "universe_expr" {
+            Ok(Self(node))
+        } else {
+            Err(type_sitter_lib::IncorrectKind {
+                node,
+                kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+            })
+        }
+    }
+}
+#[automatically_derived]
+impl<'tree> type_sitter_lib::TypedNode<'tree> for UniverseExpr<'tree> {
+    const KIND: &'static str = "universe_expr";
+
+    #[inline]
+    fn node(&self) -> &tree_sitter::Node<'tree> {
+        &self.0
+    }
+
+    #[inline]
+    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+        &mut self.0
+    }
+
+    #[inline]
+    fn into_node(self) -> tree_sitter::Node<'tree> {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+        Self(node)
+    }
+}
+pub mod unnamed {
+    #[allow(unused_imports)]
+    use super::*;
+    #[doc = "Typed node `assert`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Assert<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Assert<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Assert<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "assert" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Assert<'tree> {
+        const KIND: &'static str = "assert";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `do`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Do<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Do<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Do<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "do" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Do<'tree> {
+        const KIND: &'static str = "do";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `else`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Else<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Else<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Else<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "else" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Else<'tree> {
+        const KIND: &'static str = "else";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `end`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct End<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> End<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for End<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "end" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for End<'tree> {
+        const KIND: &'static str = "end";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `f32`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct F32<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> F32<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for F32<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "f32" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for F32<'tree> {
+        const KIND: &'static str = "f32";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `f64`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct F64<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> F64<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for F64<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "f64" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for F64<'tree> {
+        const KIND: &'static str = "f64";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `for`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct For<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> For<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for For<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "for" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for For<'tree> {
+        const KIND: &'static str = "for";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `i128`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct I128<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> I128<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I128<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "i128" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for I128<'tree> {
+        const KIND: &'static str = "i128";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `i16`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct I16<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> I16<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I16<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "i16" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for I16<'tree> {
+        const KIND: &'static str = "i16";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `i64`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct I64<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> I64<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I64<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "i64" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for I64<'tree> {
+        const KIND: &'static str = "i64";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `i8`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct I8<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> I8<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I8<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "i8" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for I8<'tree> {
+        const KIND: &'static str = "i8";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `if`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct If<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> If<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for If<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "if" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for If<'tree> {
+        const KIND: &'static str = "if";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `impl`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Impl<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Impl<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Impl<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "impl" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Impl<'tree> {
+        const KIND: &'static str = "impl";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `in`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct In<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> In<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for In<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "in" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for In<'tree> {
+        const KIND: &'static str = "in";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `inductive`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Inductive<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Inductive<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Inductive<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "inductive" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Inductive<'tree> {
+        const KIND: &'static str = "inductive";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `instance`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Instance<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Instance<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Instance<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "instance" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Instance<'tree> {
+        const KIND: &'static str = "instance";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `internal`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Internal<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Internal<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Internal<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "internal" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Internal<'tree> {
+        const KIND: &'static str = "internal";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `is`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Is<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Is<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Is<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "is" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Is<'tree> {
+        const KIND: &'static str = "is";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
 
-    #[inline]
-    fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-        &mut self.0
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
     }
+    #[doc = "Typed node `let`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Let<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Let<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Let<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
 
-    #[inline]
-    fn into_node(self) -> tree_sitter::Node<'tree> {
-        self.0
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "let" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
     }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Let<'tree> {
+        const KIND: &'static str = "let";
 
-    #[inline]
-    unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
-        Self(node)
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
     }
-}
-pub mod unnamed {
-    #[allow(unused_imports)]
-    use super::*;
-    #[doc = "Typed node `else`\n\nThis node has no children\n"]
+    #[doc = "Typed node `macro`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Else<'tree>(tree_sitter::Node<'tree>);
+    pub struct Macro<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Else<'tree> {}
+    impl<'tree> Macro<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Else<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Macro<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "else" {
+            if node.kind() == "macro" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4614,8 +7790,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Else<'tree> {
-        const KIND: &'static str = "else";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Macro<'tree> {
+        const KIND: &'static str = "macro";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4637,19 +7813,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `f32`\n\nThis node has no children\n"]
+    #[doc = "Typed node `match`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct F32<'tree>(tree_sitter::Node<'tree>);
+    pub struct Match<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> F32<'tree> {}
+    impl<'tree> Match<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for F32<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Match<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "f32" {
+            if node.kind() == "match" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4660,8 +7836,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for F32<'tree> {
-        const KIND: &'static str = "f32";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Match<'tree> {
+        const KIND: &'static str = "match";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4683,19 +7859,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `f64`\n\nThis node has no children\n"]
+    #[doc = "Typed node `mutual`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct F64<'tree>(tree_sitter::Node<'tree>);
+    pub struct Mutual<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> F64<'tree> {}
+    impl<'tree> Mutual<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for F64<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Mutual<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "f64" {
+            if node.kind() == "mutual" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4706,8 +7882,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for F64<'tree> {
-        const KIND: &'static str = "f64";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Mutual<'tree> {
+        const KIND: &'static str = "mutual";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4729,19 +7905,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `i128`\n\nThis node has no children\n"]
+    #[doc = "Typed node `n`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct I128<'tree>(tree_sitter::Node<'tree>);
+    pub struct N<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> I128<'tree> {}
+    impl<'tree> N<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I128<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for N<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "i128" {
+            if node.kind() == "n" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4752,8 +7928,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for I128<'tree> {
-        const KIND: &'static str = "i128";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for N<'tree> {
+        const KIND: &'static str = "n";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4775,19 +7951,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `i16`\n\nThis node has no children\n"]
+    #[doc = "Typed node `panic`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct I16<'tree>(tree_sitter::Node<'tree>);
+    pub struct Panic<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> I16<'tree> {}
+    impl<'tree> Panic<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I16<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Panic<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "i16" {
+            if node.kind() == "panic" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4798,8 +7974,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for I16<'tree> {
-        const KIND: &'static str = "i16";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Panic<'tree> {
+        const KIND: &'static str = "panic";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4821,19 +7997,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `i64`\n\nThis node has no children\n"]
+    #[doc = "Typed node `private`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct I64<'tree>(tree_sitter::Node<'tree>);
+    pub struct Private<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> I64<'tree> {}
+    impl<'tree> Private<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I64<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Private<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "i64" {
+            if node.kind() == "private" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4844,8 +8020,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for I64<'tree> {
-        const KIND: &'static str = "i64";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Private<'tree> {
+        const KIND: &'static str = "private";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4867,19 +8043,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `i8`\n\nThis node has no children\n"]
+    #[doc = "Typed node `public`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct I8<'tree>(tree_sitter::Node<'tree>);
+    pub struct Public<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> I8<'tree> {}
+    impl<'tree> Public<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for I8<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Public<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "i8" {
+            if node.kind() == "public" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4890,8 +8066,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for I8<'tree> {
-        const KIND: &'static str = "i8";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Public<'tree> {
+        const KIND: &'static str = "public";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4913,19 +8089,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `if`\n\nThis node has no children\n"]
+    #[doc = "Typed node `quote`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct If<'tree>(tree_sitter::Node<'tree>);
+    pub struct Quote<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> If<'tree> {}
+    impl<'tree> Quote<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for If<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Quote<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "if" {
+            if node.kind() == "quote" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4936,8 +8112,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for If<'tree> {
-        const KIND: &'static str = "if";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Quote<'tree> {
+        const KIND: &'static str = "quote";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -4959,19 +8135,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `inductive`\n\nThis node has no children\n"]
+    #[doc = "Typed node `requires`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Inductive<'tree>(tree_sitter::Node<'tree>);
+    pub struct Requires<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Inductive<'tree> {}
+    impl<'tree> Requires<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Inductive<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Requires<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "inductive" {
+            if node.kind() == "requires" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -4982,8 +8158,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Inductive<'tree> {
-        const KIND: &'static str = "inductive";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Requires<'tree> {
+        const KIND: &'static str = "requires";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5005,19 +8181,111 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `internal`\n\nThis node has no children\n"]
+    #[doc = "Typed node `return`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Internal<'tree>(tree_sitter::Node<'tree>);
+    pub struct Return<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Internal<'tree> {}
+    impl<'tree> Return<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Return<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "return" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Return<'tree> {
+        const KIND: &'static str = "return";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `rewrite`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Rewrite<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Rewrite<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Rewrite<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "rewrite" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Rewrite<'tree> {
+        const KIND: &'static str = "rewrite";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `sealed`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Sealed<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Sealed<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Internal<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Sealed<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "internal" {
+            if node.kind() == "sealed" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5028,8 +8296,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Internal<'tree> {
-        const KIND: &'static str = "internal";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Sealed<'tree> {
+        const KIND: &'static str = "sealed";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5051,19 +8319,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `is`\n\nThis node has no children\n"]
+    #[doc = "Typed node `splice`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Is<'tree>(tree_sitter::Node<'tree>);
+    pub struct Splice<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Is<'tree> {}
+    impl<'tree> Splice<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Is<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Splice<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "is" {
+            if node.kind() == "splice" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5074,8 +8342,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Is<'tree> {
-        const KIND: &'static str = "is";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Splice<'tree> {
+        const KIND: &'static str = "splice";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5097,19 +8365,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `let`\n\nThis node has no children\n"]
+    #[doc = "Typed node `then`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Let<'tree>(tree_sitter::Node<'tree>);
+    pub struct Then<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Let<'tree> {}
+    impl<'tree> Then<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Let<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Then<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "let" {
+            if node.kind() == "then" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5120,8 +8388,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Let<'tree> {
-        const KIND: &'static str = "let";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Then<'tree> {
+        const KIND: &'static str = "then";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5143,19 +8411,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `match`\n\nThis node has no children\n"]
+    #[doc = "Typed node `todo`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Match<'tree>(tree_sitter::Node<'tree>);
+    pub struct Todo<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Match<'tree> {}
+    impl<'tree> Todo<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Match<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Todo<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "match" {
+            if node.kind() == "todo" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5166,8 +8434,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Match<'tree> {
-        const KIND: &'static str = "match";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Todo<'tree> {
+        const KIND: &'static str = "todo";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5189,19 +8457,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `n`\n\nThis node has no children\n"]
+    #[doc = "Typed node `trait`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct N<'tree>(tree_sitter::Node<'tree>);
+    pub struct Trait<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> N<'tree> {}
+    impl<'tree> Trait<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for N<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Trait<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "n" {
+            if node.kind() == "trait" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5212,8 +8480,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for N<'tree> {
-        const KIND: &'static str = "n";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Trait<'tree> {
+        const KIND: &'static str = "trait";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5235,19 +8503,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `private`\n\nThis node has no children\n"]
+    #[doc = "Typed node `type`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Private<'tree>(tree_sitter::Node<'tree>);
+    pub struct Type<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Private<'tree> {}
+    impl<'tree> Type<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Private<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Type<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "private" {
+            if node.kind() == "type" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5258,8 +8526,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Private<'tree> {
-        const KIND: &'static str = "private";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Type<'tree> {
+        const KIND: &'static str = "type";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5281,19 +8549,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `public`\n\nThis node has no children\n"]
+    #[doc = "Typed node `u1`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Public<'tree>(tree_sitter::Node<'tree>);
+    pub struct U1<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Public<'tree> {}
+    impl<'tree> U1<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Public<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U1<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "public" {
+            if node.kind() == "u1" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5304,8 +8572,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Public<'tree> {
-        const KIND: &'static str = "public";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for U1<'tree> {
+        const KIND: &'static str = "u1";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5327,19 +8595,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `return`\n\nThis node has no children\n"]
+    #[doc = "Typed node `u128`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Return<'tree>(tree_sitter::Node<'tree>);
+    pub struct U128<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Return<'tree> {}
+    impl<'tree> U128<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Return<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U128<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "return" {
+            if node.kind() == "u128" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5350,8 +8618,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Return<'tree> {
-        const KIND: &'static str = "return";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for U128<'tree> {
+        const KIND: &'static str = "u128";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5373,19 +8641,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `sealed`\n\nThis node has no children\n"]
+    #[doc = "Typed node `u16`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Sealed<'tree>(tree_sitter::Node<'tree>);
+    pub struct U16<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Sealed<'tree> {}
+    impl<'tree> U16<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Sealed<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U16<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "sealed" {
+            if node.kind() == "u16" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5396,8 +8664,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Sealed<'tree> {
-        const KIND: &'static str = "sealed";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for U16<'tree> {
+        const KIND: &'static str = "u16";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5419,19 +8687,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `then`\n\nThis node has no children\n"]
+    #[doc = "Typed node `u32`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Then<'tree>(tree_sitter::Node<'tree>);
+    pub struct U32<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Then<'tree> {}
+    impl<'tree> U32<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Then<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U32<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "then" {
+            if node.kind() == "u32" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5442,8 +8710,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Then<'tree> {
-        const KIND: &'static str = "then";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for U32<'tree> {
+        const KIND: &'static str = "u32";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5465,19 +8733,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `u1`\n\nThis node has no children\n"]
+    #[doc = "Typed node `u64`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct U1<'tree>(tree_sitter::Node<'tree>);
+    pub struct U64<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> U1<'tree> {}
+    impl<'tree> U64<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U1<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U64<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "u1" {
+            if node.kind() == "u64" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5488,8 +8756,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for U1<'tree> {
-        const KIND: &'static str = "u1";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for U64<'tree> {
+        const KIND: &'static str = "u64";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5511,19 +8779,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `u128`\n\nThis node has no children\n"]
+    #[doc = "Typed node `u8`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct U128<'tree>(tree_sitter::Node<'tree>);
+    pub struct U8<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> U128<'tree> {}
+    impl<'tree> U8<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U128<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U8<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "u128" {
+            if node.kind() == "u8" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5534,8 +8802,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for U128<'tree> {
-        const KIND: &'static str = "u128";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for U8<'tree> {
+        const KIND: &'static str = "u8";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5557,19 +8825,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `u16`\n\nThis node has no children\n"]
+    #[doc = "Typed node `unreachable`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct U16<'tree>(tree_sitter::Node<'tree>);
+    pub struct Unreachable<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> U16<'tree> {}
+    impl<'tree> Unreachable<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U16<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Unreachable<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "u16" {
+            if node.kind() == "unreachable" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5580,8 +8848,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for U16<'tree> {
-        const KIND: &'static str = "u16";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Unreachable<'tree> {
+        const KIND: &'static str = "unreachable";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5603,19 +8871,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `u32`\n\nThis node has no children\n"]
+    #[doc = "Typed node `using`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct U32<'tree>(tree_sitter::Node<'tree>);
+    pub struct Using<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> U32<'tree> {}
+    impl<'tree> Using<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U32<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Using<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "u32" {
+            if node.kind() == "using" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5626,8 +8894,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for U32<'tree> {
-        const KIND: &'static str = "u32";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Using<'tree> {
+        const KIND: &'static str = "using";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5649,19 +8917,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `u64`\n\nThis node has no children\n"]
+    #[doc = "Typed node `while`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct U64<'tree>(tree_sitter::Node<'tree>);
+    pub struct While<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> U64<'tree> {}
+    impl<'tree> While<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U64<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for While<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "u64" {
+            if node.kind() == "while" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5672,8 +8940,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for U64<'tree> {
-        const KIND: &'static str = "u64";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for While<'tree> {
+        const KIND: &'static str = "while";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5695,19 +8963,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `u8`\n\nThis node has no children\n"]
+    #[doc = "Typed node `with`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct U8<'tree>(tree_sitter::Node<'tree>);
+    pub struct With<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> U8<'tree> {}
+    impl<'tree> With<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for U8<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for With<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "u8" {
+            if node.kind() == "with" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5718,8 +8986,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for U8<'tree> {
-        const KIND: &'static str = "u8";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for With<'tree> {
+        const KIND: &'static str = "with";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5741,19 +9009,23 @@ pub mod unnamed {
             Self(node)
         }
     }
-    #[doc = "Typed node `using`\n\nThis node has no children\n"]
+}
+pub mod symbols {
+    #[allow(unused_imports)]
+    use super::*;
+    #[doc = "Typed node `!`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Using<'tree>(tree_sitter::Node<'tree>);
+    pub struct Not<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Using<'tree> {}
+    impl<'tree> Not<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Using<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Not<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "using" {
+            if node.kind() == "!" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5764,8 +9036,8 @@ pub mod unnamed {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Using<'tree> {
-        const KIND: &'static str = "using";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Not<'tree> {
+        const KIND: &'static str = "!";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5787,23 +9059,19 @@ pub mod unnamed {
             Self(node)
         }
     }
-}
-pub mod symbols {
-    #[allow(unused_imports)]
-    use super::*;
-    #[doc = "Typed node `!`\n\nThis node has no children\n"]
+    #[doc = "Typed node `#`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Not<'tree>(tree_sitter::Node<'tree>);
+    pub struct Hash<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Not<'tree> {}
+    impl<'tree> Hash<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Not<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Hash<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "!" {
+            if node.kind() == "#" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5814,8 +9082,8 @@ pub mod symbols {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Not<'tree> {
-        const KIND: &'static str = "!";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Hash<'tree> {
+        const KIND: &'static str = "#";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -5837,19 +9105,19 @@ pub mod symbols {
             Self(node)
         }
     }
-    #[doc = "Typed node `#`\n\nThis node has no children\n"]
+    #[doc = "Typed node `#{`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub struct Hash<'tree>(tree_sitter::Node<'tree>);
+    pub struct HashLBrace<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> Hash<'tree> {}
+    impl<'tree> HashLBrace<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Hash<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for HashLBrace<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "#" {
+            if node.kind() == "#{" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -5860,8 +9128,8 @@ pub mod symbols {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Hash<'tree> {
-        const KIND: &'static str = "#";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for HashLBrace<'tree> {
+        const KIND: &'static str = "#{";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -6946,14 +10214,60 @@ pub mod symbols {
     #[allow(non_camel_case_types)]
     pub struct LBracket<'tree>(tree_sitter::Node<'tree>);
     #[automatically_derived]
-    impl<'tree> LBracket<'tree> {}
+    impl<'tree> LBracket<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LBracket<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "[" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for LBracket<'tree> {
+        const KIND: &'static str = "[";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
+    #[doc = "Typed node `\\`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct Backslash<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> Backslash<'tree> {}
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for LBracket<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Backslash<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            if node.kind() == "[" {
+            if node.kind() == "\\" {
                 Ok(Self(node))
             } else {
                 Err(type_sitter_lib::IncorrectKind {
@@ -6964,8 +10278,8 @@ pub mod symbols {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for LBracket<'tree> {
-        const KIND: &'static str = "[";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Backslash<'tree> {
+        const KIND: &'static str = "\\";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -7217,6 +10531,52 @@ pub mod symbols {
             Self(node)
         }
     }
+    #[doc = "Typed node `|>`\n\nThis node has no children\n"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub struct OrGt<'tree>(tree_sitter::Node<'tree>);
+    #[automatically_derived]
+    impl<'tree> OrGt<'tree> {}
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for OrGt<'tree> {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            if node.kind() == "|>" {
+                Ok(Self(node))
+            } else {
+                Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                })
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for OrGt<'tree> {
+        const KIND: &'static str = "|>";
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            &self.0
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            &mut self.0
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            self.0
+        }
+
+        #[inline]
+        unsafe fn from_node_unchecked(node: tree_sitter::Node<'tree>) -> Self {
+            Self(node)
+        }
+    }
     #[doc = "Typed node `||`\n\nThis node has no children\n"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
@@ -7743,15 +11103,16 @@ pub mod anon_unions {
             }
         }
     }
-    #[doc = "one of `{block | primary}`:\n- [Block]\n- [Primary]"]
+    #[doc = "one of `{block | primary | trailing_lambda}`:\n- [Block]\n- [Primary]\n- [TrailingLambda]"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub enum Block_Primary<'tree> {
+    pub enum Block_Primary_TrailingLambda<'tree> {
         Block(Block<'tree>),
         Primary(Primary<'tree>),
+        TrailingLambda(TrailingLambda<'tree>),
     }
     #[automatically_derived]
-    impl<'tree> Block_Primary<'tree> {
+    impl<'tree> Block_Primary_TrailingLambda<'tree> {
         #[doc = "Returns the node if it is of kind `block` ([Block]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -7771,9 +11132,19 @@ pub mod anon_unions {
                 _ => None,
             }
         }
+
+        #[doc = "Returns the node if it is of kind `trailing_lambda` ([TrailingLambda]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn trailing_lambda(self) -> Option<TrailingLambda<'tree>> {
+            match self {
+                Self::TrailingLambda(x) => Some(x),
+                _ => None,
+            }
+        }
     }
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Block_Primary<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Block_Primary_TrailingLambda<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
@@ -7789,6 +11160,11 @@ pub mod anon_unions {
                         Self :: Primary (< Primary < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                     })
                 }
+                "trailing_lambda" => {
+                    Ok(unsafe {
+                        Self :: TrailingLambda (< TrailingLambda < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
                 _ => Err(type_sitter_lib::IncorrectKind {
                     node,
                     kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
@@ -7797,14 +11173,15 @@ pub mod anon_unions {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Block_Primary<'tree> {
-        const KIND: &'static str = "{block | primary}";
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Block_Primary_TrailingLambda<'tree> {
+        const KIND: &'static str = "{block | primary | trailing_lambda}";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
             match self {
                 Self::Block(x) => x.node(),
                 Self::Primary(x) => x.node(),
+                Self::TrailingLambda(x) => x.node(),
             }
         }
 
@@ -7813,6 +11190,7 @@ pub mod anon_unions {
             match self {
                 Self::Block(x) => x.node_mut(),
                 Self::Primary(x) => x.node_mut(),
+                Self::TrailingLambda(x) => x.node_mut(),
             }
         }
 
@@ -7821,20 +11199,22 @@ pub mod anon_unions {
             match self {
                 Self::Block(x) => x.into_node(),
                 Self::Primary(x) => x.into_node(),
+                Self::TrailingLambda(x) => x.into_node(),
             }
         }
     }
-    #[doc = "one of `{cons_pattern | group_pattern | literal | rest_pattern}`:\n- [ConsPattern]\n- [GroupPattern]\n- [Literal]\n- [RestPattern]"]
+    #[doc = "one of `{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}`:\n- [ConsPattern]\n- [GroupPattern]\n- [Literal]\n- [RestPattern]\n- [TuplePattern]"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub enum ConsPattern_GroupPattern_Literal_RestPattern<'tree> {
+    pub enum ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree> {
         ConsPattern(ConsPattern<'tree>),
         GroupPattern(GroupPattern<'tree>),
         Literal(Literal<'tree>),
         RestPattern(RestPattern<'tree>),
+        TuplePattern(TuplePattern<'tree>),
     }
     #[automatically_derived]
-    impl<'tree> ConsPattern_GroupPattern_Literal_RestPattern<'tree> {
+    impl<'tree> ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree> {
         #[doc = "Returns the node if it is of kind `cons_pattern` ([ConsPattern]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -7874,10 +11254,20 @@ pub mod anon_unions {
                 _ => None,
             }
         }
+
+        #[doc = "Returns the node if it is of kind `tuple_pattern` ([TuplePattern]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn tuple_pattern(self) -> Option<TuplePattern<'tree>> {
+            match self {
+                Self::TuplePattern(x) => Some(x),
+                _ => None,
+            }
+        }
     }
     #[automatically_derived]
     impl<'tree> TryFrom<tree_sitter::Node<'tree>>
-        for ConsPattern_GroupPattern_Literal_RestPattern<'tree>
+        for ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>
     {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
@@ -7898,204 +11288,8 @@ pub mod anon_unions {
                 "rest_pattern" => Ok(unsafe {
                     Self :: RestPattern (< RestPattern < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                 }),
-                _ => Err(type_sitter_lib::IncorrectKind {
-                    node,
-                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-                }),
-            }
-        }
-    }
-    #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree>
-        for ConsPattern_GroupPattern_Literal_RestPattern<'tree>
-    {
-        const KIND: &'static str = "{cons_pattern | group_pattern | literal | rest_pattern}";
-
-        #[inline]
-        fn node(&self) -> &tree_sitter::Node<'tree> {
-            match self {
-                Self::ConsPattern(x) => x.node(),
-                Self::GroupPattern(x) => x.node(),
-                Self::Literal(x) => x.node(),
-                Self::RestPattern(x) => x.node(),
-            }
-        }
-
-        #[inline]
-        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-            match self {
-                Self::ConsPattern(x) => x.node_mut(),
-                Self::GroupPattern(x) => x.node_mut(),
-                Self::Literal(x) => x.node_mut(),
-                Self::RestPattern(x) => x.node_mut(),
-            }
-        }
-
-        #[inline]
-        fn into_node(self) -> tree_sitter::Node<'tree> {
-            match self {
-                Self::ConsPattern(x) => x.into_node(),
-                Self::GroupPattern(x) => x.into_node(),
-                Self::Literal(x) => x.into_node(),
-                Self::RestPattern(x) => x.into_node(),
-            }
-        }
-    }
-    #[doc = "one of `{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | path}`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]\n- [Path]"]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    #[allow(non_camel_case_types)]
-    pub enum AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path<'tree> {
-        AnnExpr(AnnExpr<'tree>),
-        AppExpr(AppExpr<'tree>),
-        BinaryExpr(BinaryExpr<'tree>),
-        LamExpr(LamExpr<'tree>),
-        MatchExpr(MatchExpr<'tree>),
-        PiExpr(PiExpr<'tree>),
-        Primary(Primary<'tree>),
-        SigmaExpr(SigmaExpr<'tree>),
-        Path(Path<'tree>),
-    }
-    #[automatically_derived]
-    impl<'tree> AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path<'tree> {
-        #[doc = "Returns the node if it is of kind `ann_expr` ([AnnExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn ann_expr(self) -> Option<AnnExpr<'tree>> {
-            match self {
-                Self::AnnExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `app_expr` ([AppExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn app_expr(self) -> Option<AppExpr<'tree>> {
-            match self {
-                Self::AppExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `binary_expr` ([BinaryExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn binary_expr(self) -> Option<BinaryExpr<'tree>> {
-            match self {
-                Self::BinaryExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `lam_expr` ([LamExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn lam_expr(self) -> Option<LamExpr<'tree>> {
-            match self {
-                Self::LamExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `match_expr` ([MatchExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn match_expr(self) -> Option<MatchExpr<'tree>> {
-            match self {
-                Self::MatchExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `pi_expr` ([PiExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn pi_expr(self) -> Option<PiExpr<'tree>> {
-            match self {
-                Self::PiExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `primary` ([Primary]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn primary(self) -> Option<Primary<'tree>> {
-            match self {
-                Self::Primary(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `sigma_expr` ([SigmaExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn sigma_expr(self) -> Option<SigmaExpr<'tree>> {
-            match self {
-                Self::SigmaExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `path` ([Path]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn path(self) -> Option<Path<'tree>> {
-            match self {
-                Self::Path(x) => Some(x),
-                _ => None,
-            }
-        }
-    }
-    #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>>
-        for AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path<'tree>
-    {
-        type Error = type_sitter_lib::IncorrectKind<'tree>;
-
-        #[inline]
-        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            match node.kind() {
-                "ann_expr" => {
-                    Ok(unsafe {
-                        Self :: AnnExpr (< AnnExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "app_expr" => {
-                    Ok(unsafe {
-                        Self :: AppExpr (< AppExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "binary_expr" => Ok(unsafe {
-                    Self :: BinaryExpr (< BinaryExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "lam_expr" => {
-                    Ok(unsafe {
-                        Self :: LamExpr (< LamExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "match_expr" => Ok(unsafe {
-                    Self :: MatchExpr (< MatchExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "pi_expr" => {
-                    Ok(unsafe {
-                        Self :: PiExpr (< PiExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "primary" => {
-                    Ok(unsafe {
-                        Self :: Primary (< Primary < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "sigma_expr" => Ok(unsafe {
-                    Self :: SigmaExpr (< SigmaExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "path" => Ok(unsafe {
-                    Self::Path(
-                        <Path<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
+                "tuple_pattern" => Ok(unsafe {
+                    Self :: TuplePattern (< TuplePattern < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                 }),
                 _ => Err(type_sitter_lib::IncorrectKind {
                     node,
@@ -8106,52 +11300,41 @@ pub mod anon_unions {
     }
     #[automatically_derived]
     impl<'tree> type_sitter_lib::TypedNode<'tree>
-        for AnnExpr_AppExpr_BinaryExpr_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr_Path<'tree>
+        for ConsPattern_GroupPattern_Literal_RestPattern_TuplePattern<'tree>
     {
-        const KIND : & 'static str = "{ann_expr | app_expr | binary_expr | lam_expr | match_expr | pi_expr | primary | sigma_expr | path}" ;
+        const KIND: &'static str =
+            "{cons_pattern | group_pattern | literal | rest_pattern | tuple_pattern}";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
             match self {
-                Self::AnnExpr(x) => x.node(),
-                Self::AppExpr(x) => x.node(),
-                Self::BinaryExpr(x) => x.node(),
-                Self::LamExpr(x) => x.node(),
-                Self::MatchExpr(x) => x.node(),
-                Self::PiExpr(x) => x.node(),
-                Self::Primary(x) => x.node(),
-                Self::SigmaExpr(x) => x.node(),
-                Self::Path(x) => x.node(),
+                Self::ConsPattern(x) => x.node(),
+                Self::GroupPattern(x) => x.node(),
+                Self::Literal(x) => x.node(),
+                Self::RestPattern(x) => x.node(),
+                Self::TuplePattern(x) => x.node(),
             }
         }
 
         #[inline]
         fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
             match self {
-                Self::AnnExpr(x) => x.node_mut(),
-                Self::AppExpr(x) => x.node_mut(),
-                Self::BinaryExpr(x) => x.node_mut(),
-                Self::LamExpr(x) => x.node_mut(),
-                Self::MatchExpr(x) => x.node_mut(),
-                Self::PiExpr(x) => x.node_mut(),
-                Self::Primary(x) => x.node_mut(),
-                Self::SigmaExpr(x) => x.node_mut(),
-                Self::Path(x) => x.node_mut(),
+                Self::ConsPattern(x) => x.node_mut(),
+                Self::GroupPattern(x) => x.node_mut(),
+                Self::Literal(x) => x.node_mut(),
+                Self::RestPattern(x) => x.node_mut(),
+                Self::TuplePattern(x) => x.node_mut(),
             }
         }
 
         #[inline]
         fn into_node(self) -> tree_sitter::Node<'tree> {
             match self {
-                Self::AnnExpr(x) => x.into_node(),
-                Self::AppExpr(x) => x.into_node(),
-                Self::BinaryExpr(x) => x.into_node(),
-                Self::LamExpr(x) => x.into_node(),
-                Self::MatchExpr(x) => x.into_node(),
-                Self::PiExpr(x) => x.into_node(),
-                Self::Primary(x) => x.into_node(),
-                Self::SigmaExpr(x) => x.into_node(),
-                Self::Path(x) => x.into_node(),
+                Self::ConsPattern(x) => x.into_node(),
+                Self::GroupPattern(x) => x.into_node(),
+                Self::Literal(x) => x.into_node(),
+                Self::RestPattern(x) => x.into_node(),
+                Self::TuplePattern(x) => x.into_node(),
             }
         }
     }
@@ -8234,17 +11417,20 @@ pub mod anon_unions {
             }
         }
     }
-    #[doc = "one of `{ask_stmt | expr_stmt | if_stmt | let_stmt}`:\n- [AskStmt]\n- [ExprStmt]\n- [IfStmt]\n- [LetStmt]"]
+    #[doc = "one of `{ask_stmt | assert_stmt | expr_stmt | for_stmt | if_stmt | let_stmt | while_stmt}`:\n- [AskStmt]\n- [AssertStmt]\n- [ExprStmt]\n- [ForStmt]\n- [IfStmt]\n- [LetStmt]\n- [WhileStmt]"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub enum AskStmt_ExprStmt_IfStmt_LetStmt<'tree> {
+    pub enum AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt<'tree> {
         AskStmt(AskStmt<'tree>),
+        AssertStmt(AssertStmt<'tree>),
         ExprStmt(ExprStmt<'tree>),
+        ForStmt(ForStmt<'tree>),
         IfStmt(IfStmt<'tree>),
         LetStmt(LetStmt<'tree>),
+        WhileStmt(WhileStmt<'tree>),
     }
     #[automatically_derived]
-    impl<'tree> AskStmt_ExprStmt_IfStmt_LetStmt<'tree> {
+    impl<'tree> AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt<'tree> {
         #[doc = "Returns the node if it is of kind `ask_stmt` ([AskStmt]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -8255,6 +11441,16 @@ pub mod anon_unions {
             }
         }
 
+        #[doc = "Returns the node if it is of kind `assert_stmt` ([AssertStmt]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn assert_stmt(self) -> Option<AssertStmt<'tree>> {
+            match self {
+                Self::AssertStmt(x) => Some(x),
+                _ => None,
+            }
+        }
+
         #[doc = "Returns the node if it is of kind `expr_stmt` ([ExprStmt]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -8265,6 +11461,16 @@ pub mod anon_unions {
             }
         }
 
+        #[doc = "Returns the node if it is of kind `for_stmt` ([ForStmt]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn for_stmt(self) -> Option<ForStmt<'tree>> {
+            match self {
+                Self::ForStmt(x) => Some(x),
+                _ => None,
+            }
+        }
+
         #[doc = "Returns the node if it is of kind `if_stmt` ([IfStmt]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -8284,9 +11490,21 @@ pub mod anon_unions {
                 _ => None,
             }
         }
+
+        #[doc = "Returns the node if it is of kind `while_stmt` ([WhileStmt]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn while_stmt(self) -> Option<WhileStmt<'tree>> {
+            match self {
+                Self::WhileStmt(x) => Some(x),
+                _ => None,
+            }
+        }
     }
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for AskStmt_ExprStmt_IfStmt_LetStmt<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>>
+        for AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt<'tree>
+    {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
@@ -8297,11 +11515,19 @@ pub mod anon_unions {
                         Self :: AskStmt (< AskStmt < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                     })
                 }
+                "assert_stmt" => Ok(unsafe {
+                    Self :: AssertStmt (< AssertStmt < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
                 "expr_stmt" => {
                     Ok(unsafe {
                         Self :: ExprStmt (< ExprStmt < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                     })
                 }
+                "for_stmt" => {
+                    Ok(unsafe {
+                        Self :: ForStmt (< ForStmt < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
                 "if_stmt" => {
                     Ok(unsafe {
                         Self :: IfStmt (< IfStmt < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
@@ -8312,6 +11538,9 @@ pub mod anon_unions {
                         Self :: LetStmt (< LetStmt < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                     })
                 }
+                "while_stmt" => Ok(unsafe {
+                    Self :: WhileStmt (< WhileStmt < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
                 _ => Err(type_sitter_lib::IncorrectKind {
                     node,
                     kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
@@ -8320,16 +11549,22 @@ pub mod anon_unions {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for AskStmt_ExprStmt_IfStmt_LetStmt<'tree> {
-        const KIND: &'static str = "{ask_stmt | expr_stmt | if_stmt | let_stmt}";
+    impl<'tree> type_sitter_lib::TypedNode<'tree>
+        for AskStmt_AssertStmt_ExprStmt_ForStmt_IfStmt_LetStmt_WhileStmt<'tree>
+    {
+        const KIND: &'static str =
+            "{ask_stmt | assert_stmt | expr_stmt | for_stmt | if_stmt | let_stmt | while_stmt}";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
             match self {
                 Self::AskStmt(x) => x.node(),
+                Self::AssertStmt(x) => x.node(),
                 Self::ExprStmt(x) => x.node(),
+                Self::ForStmt(x) => x.node(),
                 Self::IfStmt(x) => x.node(),
                 Self::LetStmt(x) => x.node(),
+                Self::WhileStmt(x) => x.node(),
             }
         }
 
@@ -8337,9 +11572,12 @@ pub mod anon_unions {
         fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
             match self {
                 Self::AskStmt(x) => x.node_mut(),
+                Self::AssertStmt(x) => x.node_mut(),
                 Self::ExprStmt(x) => x.node_mut(),
+                Self::ForStmt(x) => x.node_mut(),
                 Self::IfStmt(x) => x.node_mut(),
                 Self::LetStmt(x) => x.node_mut(),
+                Self::WhileStmt(x) => x.node_mut(),
             }
         }
 
@@ -8347,9 +11585,12 @@ pub mod anon_unions {
         fn into_node(self) -> tree_sitter::Node<'tree> {
             match self {
                 Self::AskStmt(x) => x.into_node(),
+                Self::AssertStmt(x) => x.into_node(),
                 Self::ExprStmt(x) => x.into_node(),
+                Self::ForStmt(x) => x.into_node(),
                 Self::IfStmt(x) => x.into_node(),
                 Self::LetStmt(x) => x.into_node(),
+                Self::WhileStmt(x) => x.into_node(),
             }
         }
     }
@@ -8419,16 +11660,228 @@ pub mod anon_unions {
         #[inline]
         fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
             match self {
-                Self::ForallParameter(x) => x.node_mut(),
-                Self::Parameter(x) => x.node_mut(),
+                Self::ForallParameter(x) => x.node_mut(),
+                Self::Parameter(x) => x.node_mut(),
+            }
+        }
+
+        #[inline]
+        fn into_node(self) -> tree_sitter::Node<'tree> {
+            match self {
+                Self::ForallParameter(x) => x.into_node(),
+                Self::Parameter(x) => x.into_node(),
+            }
+        }
+    }
+    #[doc = "one of `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [Block]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub enum AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree> {
+        AnnExpr(AnnExpr<'tree>),
+        AppExpr(AppExpr<'tree>),
+        BinaryExpr(BinaryExpr<'tree>),
+        Block(Block<'tree>),
+        LamExpr(LamExpr<'tree>),
+        MatchExpr(MatchExpr<'tree>),
+        PiExpr(PiExpr<'tree>),
+        Primary(Primary<'tree>),
+        SigmaExpr(SigmaExpr<'tree>),
+    }
+    #[automatically_derived]
+    impl<'tree> AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree> {
+        #[doc = "Returns the node if it is of kind `ann_expr` ([AnnExpr]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn ann_expr(self) -> Option<AnnExpr<'tree>> {
+            match self {
+                Self::AnnExpr(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `app_expr` ([AppExpr]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn app_expr(self) -> Option<AppExpr<'tree>> {
+            match self {
+                Self::AppExpr(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `binary_expr` ([BinaryExpr]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn binary_expr(self) -> Option<BinaryExpr<'tree>> {
+            match self {
+                Self::BinaryExpr(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `block` ([Block]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn block(self) -> Option<Block<'tree>> {
+            match self {
+                Self::Block(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `lam_expr` ([LamExpr]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn lam_expr(self) -> Option<LamExpr<'tree>> {
+            match self {
+                Self::LamExpr(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `match_expr` ([MatchExpr]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn match_expr(self) -> Option<MatchExpr<'tree>> {
+            match self {
+                Self::MatchExpr(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `pi_expr` ([PiExpr]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn pi_expr(self) -> Option<PiExpr<'tree>> {
+            match self {
+                Self::PiExpr(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `primary` ([Primary]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn primary(self) -> Option<Primary<'tree>> {
+            match self {
+                Self::Primary(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `sigma_expr` ([SigmaExpr]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn sigma_expr(self) -> Option<SigmaExpr<'tree>> {
+            match self {
+                Self::SigmaExpr(x) => Some(x),
+                _ => None,
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>>
+        for AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>
+    {
+        type Error = type_sitter_lib::IncorrectKind<'tree>;
+
+        #[inline]
+        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
+            match node.kind() {
+                "ann_expr" => {
+                    Ok(unsafe {
+                        Self :: AnnExpr (< AnnExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
+                "app_expr" => {
+                    Ok(unsafe {
+                        Self :: AppExpr (< AppExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
+                "binary_expr" => Ok(unsafe {
+                    Self :: BinaryExpr (< BinaryExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
+                "block" => {
+                    Ok(unsafe {
+                        Self :: Block (< Block < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
+                "lam_expr" => {
+                    Ok(unsafe {
+                        Self :: LamExpr (< LamExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
+                "match_expr" => Ok(unsafe {
+                    Self :: MatchExpr (< MatchExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
+                "pi_expr" => {
+                    Ok(unsafe {
+                        Self :: PiExpr (< PiExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
+                "primary" => {
+                    Ok(unsafe {
+                        Self :: Primary (< Primary < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
+                "sigma_expr" => Ok(unsafe {
+                    Self :: SigmaExpr (< SigmaExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
+                _ => Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                }),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl<'tree> type_sitter_lib::TypedNode<'tree>
+        for AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>
+    {
+        const KIND : & 'static str = "{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}" ;
+
+        #[inline]
+        fn node(&self) -> &tree_sitter::Node<'tree> {
+            match self {
+                Self::AnnExpr(x) => x.node(),
+                Self::AppExpr(x) => x.node(),
+                Self::BinaryExpr(x) => x.node(),
+                Self::Block(x) => x.node(),
+                Self::LamExpr(x) => x.node(),
+                Self::MatchExpr(x) => x.node(),
+                Self::PiExpr(x) => x.node(),
+                Self::Primary(x) => x.node(),
+                Self::SigmaExpr(x) => x.node(),
+            }
+        }
+
+        #[inline]
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
+            match self {
+                Self::AnnExpr(x) => x.node_mut(),
+                Self::AppExpr(x) => x.node_mut(),
+                Self::BinaryExpr(x) => x.node_mut(),
+                Self::Block(x) => x.node_mut(),
+                Self::LamExpr(x) => x.node_mut(),
+                Self::MatchExpr(x) => x.node_mut(),
+                Self::PiExpr(x) => x.node_mut(),
+                Self::Primary(x) => x.node_mut(),
+                Self::SigmaExpr(x) => x.node_mut(),
             }
         }
 
         #[inline]
         fn into_node(self) -> tree_sitter::Node<'tree> {
             match self {
-                Self::ForallParameter(x) => x.into_node(),
-                Self::Parameter(x) => x.into_node(),
+                Self::AnnExpr(x) => x.into_node(),
+                Self::AppExpr(x) => x.into_node(),
+                Self::BinaryExpr(x) => x.into_node(),
+                Self::Block(x) => x.into_node(),
+                Self::LamExpr(x) => x.into_node(),
+                Self::MatchExpr(x) => x.into_node(),
+                Self::PiExpr(x) => x.into_node(),
+                Self::Primary(x) => x.into_node(),
+                Self::SigmaExpr(x) => x.into_node(),
             }
         }
     }
@@ -8816,353 +12269,35 @@ pub mod anon_unions {
             }
         }
 
-        #[doc = "Returns the node if it is of kind `signature_constructor` ([SignatureConstructor]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn signature_constructor(self) -> Option<SignatureConstructor<'tree>> {
-            match self {
-                Self::SignatureConstructor(x) => Some(x),
-                _ => None,
-            }
-        }
-    }
-    #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>>
-        for Comma_FunctionConstructor_SignatureConstructor<'tree>
-    {
-        type Error = type_sitter_lib::IncorrectKind<'tree>;
-
-        #[inline]
-        fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
-            match node.kind() {
-                "," => Ok(unsafe {
-                    Self::Comma(<symbols::Comma<'tree> as type_sitter_lib::TypedNode<
-                        'tree,
-                    >>::from_node_unchecked(node))
-                }),
-                "function_constructor" => Ok(unsafe {
-                    Self :: FunctionConstructor (< FunctionConstructor < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "signature_constructor" => Ok(unsafe {
-                    Self :: SignatureConstructor (< SignatureConstructor < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                _ => Err(type_sitter_lib::IncorrectKind {
-                    node,
-                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
-                }),
-            }
-        }
-    }
-    #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree>
-        for Comma_FunctionConstructor_SignatureConstructor<'tree>
-    {
-        const KIND: &'static str = "{, | function_constructor | signature_constructor}";
-
-        #[inline]
-        fn node(&self) -> &tree_sitter::Node<'tree> {
-            match self {
-                Self::Comma(x) => x.node(),
-                Self::FunctionConstructor(x) => x.node(),
-                Self::SignatureConstructor(x) => x.node(),
-            }
-        }
-
-        #[inline]
-        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-            match self {
-                Self::Comma(x) => x.node_mut(),
-                Self::FunctionConstructor(x) => x.node_mut(),
-                Self::SignatureConstructor(x) => x.node_mut(),
-            }
-        }
-
-        #[inline]
-        fn into_node(self) -> tree_sitter::Node<'tree> {
-            match self {
-                Self::Comma(x) => x.into_node(),
-                Self::FunctionConstructor(x) => x.into_node(),
-                Self::SignatureConstructor(x) => x.into_node(),
-            }
-        }
-    }
-    #[doc = "one of `{char | f32 | f64 | i128 | i16 | i64 | i8 | nat | string | u1 | u128 | u16 | u32 | u64 | u8}`:\n- [Char]\n- [F32]\n- [F64]\n- [I128]\n- [I16]\n- [I64]\n- [I8]\n- [Nat]\n- [String]\n- [U1]\n- [U128]\n- [U16]\n- [U32]\n- [U64]\n- [U8]"]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    #[allow(non_camel_case_types)]
-    pub enum Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree> {
-        Char(Char<'tree>),
-        F32(F32<'tree>),
-        F64(F64<'tree>),
-        I128(I128<'tree>),
-        I16(I16<'tree>),
-        I64(I64<'tree>),
-        I8(I8<'tree>),
-        Nat(Nat<'tree>),
-        String(String<'tree>),
-        U1(U1<'tree>),
-        U128(U128<'tree>),
-        U16(U16<'tree>),
-        U32(U32<'tree>),
-        U64(U64<'tree>),
-        U8(U8<'tree>),
-    }
-    #[automatically_derived]
-    impl<'tree> Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree> {
-        #[doc = "Returns the node if it is of kind `char` ([Char]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn char(self) -> Option<Char<'tree>> {
-            match self {
-                Self::Char(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `f32` ([F32]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn f_32(self) -> Option<F32<'tree>> {
-            match self {
-                Self::F32(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `f64` ([F64]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn f_64(self) -> Option<F64<'tree>> {
-            match self {
-                Self::F64(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `i128` ([I128]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn i_128(self) -> Option<I128<'tree>> {
-            match self {
-                Self::I128(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `i16` ([I16]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn i_16(self) -> Option<I16<'tree>> {
-            match self {
-                Self::I16(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `i64` ([I64]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn i_64(self) -> Option<I64<'tree>> {
-            match self {
-                Self::I64(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `i8` ([I8]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn i_8(self) -> Option<I8<'tree>> {
-            match self {
-                Self::I8(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `nat` ([Nat]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn nat(self) -> Option<Nat<'tree>> {
-            match self {
-                Self::Nat(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `string` ([String]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn string(self) -> Option<String<'tree>> {
-            match self {
-                Self::String(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `u1` ([U1]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn u_1(self) -> Option<U1<'tree>> {
-            match self {
-                Self::U1(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `u128` ([U128]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn u_128(self) -> Option<U128<'tree>> {
-            match self {
-                Self::U128(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `u16` ([U16]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn u_16(self) -> Option<U16<'tree>> {
-            match self {
-                Self::U16(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `u32` ([U32]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn u_32(self) -> Option<U32<'tree>> {
-            match self {
-                Self::U32(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `u64` ([U64]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn u_64(self) -> Option<U64<'tree>> {
-            match self {
-                Self::U64(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `u8` ([U8]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `signature_constructor` ([SignatureConstructor]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn u_8(self) -> Option<U8<'tree>> {
+        pub fn signature_constructor(self) -> Option<SignatureConstructor<'tree>> {
             match self {
-                Self::U8(x) => Some(x),
+                Self::SignatureConstructor(x) => Some(x),
                 _ => None,
             }
         }
     }
     #[automatically_derived]
     impl<'tree> TryFrom<tree_sitter::Node<'tree>>
-        for Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree>
+        for Comma_FunctionConstructor_SignatureConstructor<'tree>
     {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
             match node.kind() {
-                "char" => Ok(unsafe {
-                    Self::Char(
-                        <Char<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "f32" => Ok(unsafe {
-                    Self::F32(
-                        <F32<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "f64" => Ok(unsafe {
-                    Self::F64(
-                        <F64<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "i128" => Ok(unsafe {
-                    Self::I128(
-                        <I128<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "i16" => Ok(unsafe {
-                    Self::I16(
-                        <I16<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "i64" => Ok(unsafe {
-                    Self::I64(
-                        <I64<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "i8" => Ok(unsafe {
-                    Self::I8(
-                        <I8<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(node),
-                    )
-                }),
-                "nat" => Ok(unsafe {
-                    Self::Nat(
-                        <Nat<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "string" => {
-                    Ok(unsafe {
-                        Self :: String (< String < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "u1" => Ok(unsafe {
-                    Self::U1(
-                        <U1<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(node),
-                    )
-                }),
-                "u128" => Ok(unsafe {
-                    Self::U128(
-                        <U128<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "u16" => Ok(unsafe {
-                    Self::U16(
-                        <U16<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "u32" => Ok(unsafe {
-                    Self::U32(
-                        <U32<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
+                "," => Ok(unsafe {
+                    Self::Comma(<symbols::Comma<'tree> as type_sitter_lib::TypedNode<
+                        'tree,
+                    >>::from_node_unchecked(node))
                 }),
-                "u64" => Ok(unsafe {
-                    Self::U64(
-                        <U64<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
+                "function_constructor" => Ok(unsafe {
+                    Self :: FunctionConstructor (< FunctionConstructor < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                 }),
-                "u8" => Ok(unsafe {
-                    Self::U8(
-                        <U8<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(node),
-                    )
+                "signature_constructor" => Ok(unsafe {
+                    Self :: SignatureConstructor (< SignatureConstructor < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                 }),
                 _ => Err(type_sitter_lib::IncorrectKind {
                     node,
@@ -9173,360 +12308,400 @@ pub mod anon_unions {
     }
     #[automatically_derived]
     impl<'tree> type_sitter_lib::TypedNode<'tree>
-        for Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree>
+        for Comma_FunctionConstructor_SignatureConstructor<'tree>
     {
-        const KIND : & 'static str = "{char | f32 | f64 | i128 | i16 | i64 | i8 | nat | string | u1 | u128 | u16 | u32 | u64 | u8}" ;
+        const KIND: &'static str = "{, | function_constructor | signature_constructor}";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
             match self {
-                Self::Char(x) => x.node(),
-                Self::F32(x) => x.node(),
-                Self::F64(x) => x.node(),
-                Self::I128(x) => x.node(),
-                Self::I16(x) => x.node(),
-                Self::I64(x) => x.node(),
-                Self::I8(x) => x.node(),
-                Self::Nat(x) => x.node(),
-                Self::String(x) => x.node(),
-                Self::U1(x) => x.node(),
-                Self::U128(x) => x.node(),
-                Self::U16(x) => x.node(),
-                Self::U32(x) => x.node(),
-                Self::U64(x) => x.node(),
-                Self::U8(x) => x.node(),
+                Self::Comma(x) => x.node(),
+                Self::FunctionConstructor(x) => x.node(),
+                Self::SignatureConstructor(x) => x.node(),
             }
         }
 
         #[inline]
         fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
             match self {
-                Self::Char(x) => x.node_mut(),
-                Self::F32(x) => x.node_mut(),
-                Self::F64(x) => x.node_mut(),
-                Self::I128(x) => x.node_mut(),
-                Self::I16(x) => x.node_mut(),
-                Self::I64(x) => x.node_mut(),
-                Self::I8(x) => x.node_mut(),
-                Self::Nat(x) => x.node_mut(),
-                Self::String(x) => x.node_mut(),
-                Self::U1(x) => x.node_mut(),
-                Self::U128(x) => x.node_mut(),
-                Self::U16(x) => x.node_mut(),
-                Self::U32(x) => x.node_mut(),
-                Self::U64(x) => x.node_mut(),
-                Self::U8(x) => x.node_mut(),
+                Self::Comma(x) => x.node_mut(),
+                Self::FunctionConstructor(x) => x.node_mut(),
+                Self::SignatureConstructor(x) => x.node_mut(),
             }
         }
 
         #[inline]
         fn into_node(self) -> tree_sitter::Node<'tree> {
             match self {
-                Self::Char(x) => x.into_node(),
-                Self::F32(x) => x.into_node(),
-                Self::F64(x) => x.into_node(),
-                Self::I128(x) => x.into_node(),
-                Self::I16(x) => x.into_node(),
-                Self::I64(x) => x.into_node(),
-                Self::I8(x) => x.into_node(),
-                Self::Nat(x) => x.into_node(),
-                Self::String(x) => x.into_node(),
-                Self::U1(x) => x.into_node(),
-                Self::U128(x) => x.into_node(),
-                Self::U16(x) => x.into_node(),
-                Self::U32(x) => x.into_node(),
-                Self::U64(x) => x.into_node(),
-                Self::U8(x) => x.into_node(),
+                Self::Comma(x) => x.into_node(),
+                Self::FunctionConstructor(x) => x.into_node(),
+                Self::SignatureConstructor(x) => x.into_node(),
             }
         }
     }
-    #[doc = "one of `{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}`:\n- [AnnExpr]\n- [AppExpr]\n- [BinaryExpr]\n- [Block]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [Primary]\n- [SigmaExpr]"]
+    #[doc = "one of `{char | f32 | f64 | i128 | i16 | i64 | i8 | nat | string | u1 | u128 | u16 | u32 | u64 | u8}`:\n- [Char]\n- [F32]\n- [F64]\n- [I128]\n- [I16]\n- [I64]\n- [I8]\n- [Nat]\n- [String]\n- [U1]\n- [U128]\n- [U16]\n- [U32]\n- [U64]\n- [U8]"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub enum AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree> {
-        AnnExpr(AnnExpr<'tree>),
-        AppExpr(AppExpr<'tree>),
-        BinaryExpr(BinaryExpr<'tree>),
-        Block(Block<'tree>),
-        LamExpr(LamExpr<'tree>),
-        MatchExpr(MatchExpr<'tree>),
-        PiExpr(PiExpr<'tree>),
-        Primary(Primary<'tree>),
-        SigmaExpr(SigmaExpr<'tree>),
+    pub enum Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree> {
+        Char(Char<'tree>),
+        F32(F32<'tree>),
+        F64(F64<'tree>),
+        I128(I128<'tree>),
+        I16(I16<'tree>),
+        I64(I64<'tree>),
+        I8(I8<'tree>),
+        Nat(Nat<'tree>),
+        String(String<'tree>),
+        U1(U1<'tree>),
+        U128(U128<'tree>),
+        U16(U16<'tree>),
+        U32(U32<'tree>),
+        U64(U64<'tree>),
+        U8(U8<'tree>),
     }
     #[automatically_derived]
-    impl<'tree> AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree> {
-        #[doc = "Returns the node if it is of kind `ann_expr` ([AnnExpr]), otherwise returns None"]
+    impl<'tree> Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree> {
+        #[doc = "Returns the node if it is of kind `char` ([Char]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn char(self) -> Option<Char<'tree>> {
+            match self {
+                Self::Char(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `f32` ([F32]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn f_32(self) -> Option<F32<'tree>> {
+            match self {
+                Self::F32(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `f64` ([F64]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn f_64(self) -> Option<F64<'tree>> {
+            match self {
+                Self::F64(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `i128` ([I128]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn i_128(self) -> Option<I128<'tree>> {
+            match self {
+                Self::I128(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `i16` ([I16]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn i_16(self) -> Option<I16<'tree>> {
+            match self {
+                Self::I16(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `i64` ([I64]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn i_64(self) -> Option<I64<'tree>> {
+            match self {
+                Self::I64(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `i8` ([I8]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn ann_expr(self) -> Option<AnnExpr<'tree>> {
+        pub fn i_8(self) -> Option<I8<'tree>> {
             match self {
-                Self::AnnExpr(x) => Some(x),
+                Self::I8(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `app_expr` ([AppExpr]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `nat` ([Nat]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn app_expr(self) -> Option<AppExpr<'tree>> {
+        pub fn nat(self) -> Option<Nat<'tree>> {
             match self {
-                Self::AppExpr(x) => Some(x),
+                Self::Nat(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `binary_expr` ([BinaryExpr]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `string` ([String]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn binary_expr(self) -> Option<BinaryExpr<'tree>> {
+        pub fn string(self) -> Option<String<'tree>> {
             match self {
-                Self::BinaryExpr(x) => Some(x),
+                Self::String(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `block` ([Block]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `u1` ([U1]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn block(self) -> Option<Block<'tree>> {
+        pub fn u_1(self) -> Option<U1<'tree>> {
             match self {
-                Self::Block(x) => Some(x),
+                Self::U1(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `lam_expr` ([LamExpr]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `u128` ([U128]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn lam_expr(self) -> Option<LamExpr<'tree>> {
+        pub fn u_128(self) -> Option<U128<'tree>> {
             match self {
-                Self::LamExpr(x) => Some(x),
+                Self::U128(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `match_expr` ([MatchExpr]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `u16` ([U16]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn match_expr(self) -> Option<MatchExpr<'tree>> {
+        pub fn u_16(self) -> Option<U16<'tree>> {
             match self {
-                Self::MatchExpr(x) => Some(x),
+                Self::U16(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `pi_expr` ([PiExpr]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `u32` ([U32]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn pi_expr(self) -> Option<PiExpr<'tree>> {
+        pub fn u_32(self) -> Option<U32<'tree>> {
             match self {
-                Self::PiExpr(x) => Some(x),
+                Self::U32(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `primary` ([Primary]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `u64` ([U64]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn primary(self) -> Option<Primary<'tree>> {
+        pub fn u_64(self) -> Option<U64<'tree>> {
             match self {
-                Self::Primary(x) => Some(x),
+                Self::U64(x) => Some(x),
                 _ => None,
             }
         }
 
-        #[doc = "Returns the node if it is of kind `sigma_expr` ([SigmaExpr]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `u8` ([U8]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn sigma_expr(self) -> Option<SigmaExpr<'tree>> {
+        pub fn u_8(self) -> Option<U8<'tree>> {
             match self {
-                Self::SigmaExpr(x) => Some(x),
+                Self::U8(x) => Some(x),
                 _ => None,
             }
         }
     }
     #[automatically_derived]
     impl<'tree> TryFrom<tree_sitter::Node<'tree>>
-        for AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>
+        for Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree>
     {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
             match node.kind() {
-                "ann_expr" => {
-                    Ok(unsafe {
-                        Self :: AnnExpr (< AnnExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "app_expr" => {
-                    Ok(unsafe {
-                        Self :: AppExpr (< AppExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "binary_expr" => Ok(unsafe {
-                    Self :: BinaryExpr (< BinaryExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                "char" => Ok(unsafe {
+                    Self::Char(
+                        <Char<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
                 }),
-                "block" => {
-                    Ok(unsafe {
-                        Self :: Block (< Block < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "lam_expr" => {
-                    Ok(unsafe {
-                        Self :: LamExpr (< LamExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "match_expr" => Ok(unsafe {
-                    Self :: MatchExpr (< MatchExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                "f32" => Ok(unsafe {
+                    Self::F32(
+                        <F32<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
                 }),
-                "pi_expr" => {
-                    Ok(unsafe {
-                        Self :: PiExpr (< PiExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
-                "primary" => {
+                "f64" => Ok(unsafe {
+                    Self::F64(
+                        <F64<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "i128" => Ok(unsafe {
+                    Self::I128(
+                        <I128<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "i16" => Ok(unsafe {
+                    Self::I16(
+                        <I16<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "i64" => Ok(unsafe {
+                    Self::I64(
+                        <I64<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "i8" => Ok(unsafe {
+                    Self::I8(
+                        <I8<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(node),
+                    )
+                }),
+                "nat" => Ok(unsafe {
+                    Self::Nat(
+                        <Nat<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "string" => {
                     Ok(unsafe {
-                        Self :: Primary (< Primary < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                        Self :: String (< String < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                     })
                 }
-                "sigma_expr" => Ok(unsafe {
-                    Self :: SigmaExpr (< SigmaExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                "u1" => Ok(unsafe {
+                    Self::U1(
+                        <U1<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(node),
+                    )
                 }),
-                _ => Err(type_sitter_lib::IncorrectKind {
-                    node,
-                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                "u128" => Ok(unsafe {
+                    Self::U128(
+                        <U128<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
                 }),
-            }
-        }
-    }
-    #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree>
-        for AnnExpr_AppExpr_BinaryExpr_Block_LamExpr_MatchExpr_PiExpr_Primary_SigmaExpr<'tree>
-    {
-        const KIND : & 'static str = "{ann_expr | app_expr | binary_expr | block | lam_expr | match_expr | pi_expr | primary | sigma_expr}" ;
-
-        #[inline]
-        fn node(&self) -> &tree_sitter::Node<'tree> {
-            match self {
-                Self::AnnExpr(x) => x.node(),
-                Self::AppExpr(x) => x.node(),
-                Self::BinaryExpr(x) => x.node(),
-                Self::Block(x) => x.node(),
-                Self::LamExpr(x) => x.node(),
-                Self::MatchExpr(x) => x.node(),
-                Self::PiExpr(x) => x.node(),
-                Self::Primary(x) => x.node(),
-                Self::SigmaExpr(x) => x.node(),
-            }
-        }
-
-        #[inline]
-        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
-            match self {
-                Self::AnnExpr(x) => x.node_mut(),
-                Self::AppExpr(x) => x.node_mut(),
-                Self::BinaryExpr(x) => x.node_mut(),
-                Self::Block(x) => x.node_mut(),
-                Self::LamExpr(x) => x.node_mut(),
-                Self::MatchExpr(x) => x.node_mut(),
-                Self::PiExpr(x) => x.node_mut(),
-                Self::Primary(x) => x.node_mut(),
-                Self::SigmaExpr(x) => x.node_mut(),
-            }
-        }
-
-        #[inline]
-        fn into_node(self) -> tree_sitter::Node<'tree> {
-            match self {
-                Self::AnnExpr(x) => x.into_node(),
-                Self::AppExpr(x) => x.into_node(),
-                Self::BinaryExpr(x) => x.into_node(),
-                Self::Block(x) => x.into_node(),
-                Self::LamExpr(x) => x.into_node(),
-                Self::MatchExpr(x) => x.into_node(),
-                Self::PiExpr(x) => x.into_node(),
-                Self::Primary(x) => x.into_node(),
-                Self::SigmaExpr(x) => x.into_node(),
-            }
-        }
-    }
-    #[doc = "one of `{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}`:\n- [AnnExpr]\n- [BinaryExpr]\n- [ForallParameters]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [PiParameters]\n- [Primary]\n- [SigmaExpr]\n- [TypeAppExpr]"]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    #[allow(non_camel_case_types)]
-    pub enum AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr<
-        'tree,
-    > {
-        AnnExpr(AnnExpr<'tree>),
-        BinaryExpr(BinaryExpr<'tree>),
-        ForallParameters(ForallParameters<'tree>),
-        LamExpr(LamExpr<'tree>),
-        MatchExpr(MatchExpr<'tree>),
-        PiExpr(PiExpr<'tree>),
-        PiParameters(PiParameters<'tree>),
-        Primary(Primary<'tree>),
-        SigmaExpr(SigmaExpr<'tree>),
-        TypeAppExpr(TypeAppExpr<'tree>),
-    }
-    #[automatically_derived]
-    impl < 'tree > AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > { # [doc = "Returns the node if it is of kind `ann_expr` ([AnnExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn ann_expr (self) -> Option < AnnExpr < 'tree > > { match self { Self :: AnnExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `binary_expr` ([BinaryExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn binary_expr (self) -> Option < BinaryExpr < 'tree > > { match self { Self :: BinaryExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `forall_parameters` ([ForallParameters]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn forall_parameters (self) -> Option < ForallParameters < 'tree > > { match self { Self :: ForallParameters (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `lam_expr` ([LamExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn lam_expr (self) -> Option < LamExpr < 'tree > > { match self { Self :: LamExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `match_expr` ([MatchExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn match_expr (self) -> Option < MatchExpr < 'tree > > { match self { Self :: MatchExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `pi_expr` ([PiExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn pi_expr (self) -> Option < PiExpr < 'tree > > { match self { Self :: PiExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `pi_parameters` ([PiParameters]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn pi_parameters (self) -> Option < PiParameters < 'tree > > { match self { Self :: PiParameters (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `primary` ([Primary]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn primary (self) -> Option < Primary < 'tree > > { match self { Self :: Primary (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `sigma_expr` ([SigmaExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn sigma_expr (self) -> Option < SigmaExpr < 'tree > > { match self { Self :: SigmaExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `type_app_expr` ([TypeAppExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn type_app_expr (self) -> Option < TypeAppExpr < 'tree > > { match self { Self :: TypeAppExpr (x) => Some (x) , _ => None , } } }
-    #[automatically_derived]
-    impl < 'tree > TryFrom < tree_sitter :: Node < 'tree >> for AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > { type Error = type_sitter_lib :: IncorrectKind < 'tree > ; # [inline] fn try_from (node : tree_sitter :: Node < 'tree >) -> Result < Self , Self :: Error > { match node . kind () { "ann_expr" => Ok (unsafe { Self :: AnnExpr (< AnnExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "binary_expr" => Ok (unsafe { Self :: BinaryExpr (< BinaryExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "forall_parameters" => Ok (unsafe { Self :: ForallParameters (< ForallParameters < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "lam_expr" => Ok (unsafe { Self :: LamExpr (< LamExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "match_expr" => Ok (unsafe { Self :: MatchExpr (< MatchExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "pi_expr" => Ok (unsafe { Self :: PiExpr (< PiExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "pi_parameters" => Ok (unsafe { Self :: PiParameters (< PiParameters < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "primary" => Ok (unsafe { Self :: Primary (< Primary < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "sigma_expr" => Ok (unsafe { Self :: SigmaExpr (< SigmaExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "type_app_expr" => Ok (unsafe { Self :: TypeAppExpr (< TypeAppExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , _ => Err (type_sitter_lib :: IncorrectKind { node , kind : < Self as type_sitter_lib :: TypedNode < 'tree >> :: KIND , }) } } }
-    #[automatically_derived]
-    impl < 'tree > type_sitter_lib :: TypedNode < 'tree > for AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > { const KIND : & 'static str = "{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}" ; # [inline] fn node (& self) -> & tree_sitter :: Node < 'tree > { match self { Self :: AnnExpr (x) => x . node () , Self :: BinaryExpr (x) => x . node () , Self :: ForallParameters (x) => x . node () , Self :: LamExpr (x) => x . node () , Self :: MatchExpr (x) => x . node () , Self :: PiExpr (x) => x . node () , Self :: PiParameters (x) => x . node () , Self :: Primary (x) => x . node () , Self :: SigmaExpr (x) => x . node () , Self :: TypeAppExpr (x) => x . node () , } } # [inline] fn node_mut (& mut self) -> & mut tree_sitter :: Node < 'tree > { match self { Self :: AnnExpr (x) => x . node_mut () , Self :: BinaryExpr (x) => x . node_mut () , Self :: ForallParameters (x) => x . node_mut () , Self :: LamExpr (x) => x . node_mut () , Self :: MatchExpr (x) => x . node_mut () , Self :: PiExpr (x) => x . node_mut () , Self :: PiParameters (x) => x . node_mut () , Self :: Primary (x) => x . node_mut () , Self :: SigmaExpr (x) => x . node_mut () , Self :: TypeAppExpr (x) => x . node_mut () , } } # [inline] fn into_node (self) -> tree_sitter :: Node < 'tree > { match self { Self :: AnnExpr (x) => x . into_node () , Self :: BinaryExpr (x) => x . into_node () , Self :: ForallParameters (x) => x . into_node () , Self :: LamExpr (x) => x . into_node () , Self :: MatchExpr (x) => x . into_node () , Self :: PiExpr (x) => x . into_node () , Self :: PiParameters (x) => x . into_node () , Self :: Primary (x) => x . into_node () , Self :: SigmaExpr (x) => x . into_node () , Self :: TypeAppExpr (x) => x . into_node () , } } }
-    #[doc = "one of `{array_expr | free_variable | if_expr | literal | match_expr | path | return_expr | tuple_expr | universe_expr}`:\n- [ArrayExpr]\n- [FreeVariable]\n- [IfExpr]\n- [Literal]\n- [MatchExpr]\n- [Path]\n- [ReturnExpr]\n- [TupleExpr]\n- [UniverseExpr]"]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    #[allow(non_camel_case_types)]
-    pub enum ArrayExpr_FreeVariable_IfExpr_Literal_MatchExpr_Path_ReturnExpr_TupleExpr_UniverseExpr<
-        'tree,
-    > {
-        ArrayExpr(ArrayExpr<'tree>),
-        FreeVariable(FreeVariable<'tree>),
-        IfExpr(IfExpr<'tree>),
-        Literal(Literal<'tree>),
-        MatchExpr(MatchExpr<'tree>),
-        Path(Path<'tree>),
-        ReturnExpr(ReturnExpr<'tree>),
-        TupleExpr(TupleExpr<'tree>),
-        UniverseExpr(UniverseExpr<'tree>),
+                "u16" => Ok(unsafe {
+                    Self::U16(
+                        <U16<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "u32" => Ok(unsafe {
+                    Self::U32(
+                        <U32<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "u64" => Ok(unsafe {
+                    Self::U64(
+                        <U64<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
+                            node,
+                        ),
+                    )
+                }),
+                "u8" => Ok(unsafe {
+                    Self::U8(
+                        <U8<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(node),
+                    )
+                }),
+                _ => Err(type_sitter_lib::IncorrectKind {
+                    node,
+                    kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
+                }),
+            }
+        }
     }
     #[automatically_derived]
-    impl<'tree>
-        ArrayExpr_FreeVariable_IfExpr_Literal_MatchExpr_Path_ReturnExpr_TupleExpr_UniverseExpr<
-            'tree,
-        >
+    impl<'tree> type_sitter_lib::TypedNode<'tree>
+        for Char_F32_F64_I128_I16_I64_I8_Nat_String_U1_U128_U16_U32_U64_U8<'tree>
     {
-        #[doc = "Returns the node if it is of kind `array_expr` ([ArrayExpr]), otherwise returns None"]
+        const KIND : & 'static str = "{char | f32 | f64 | i128 | i16 | i64 | i8 | nat | string | u1 | u128 | u16 | u32 | u64 | u8}" ;
+
         #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn array_expr(self) -> Option<ArrayExpr<'tree>> {
+        fn node(&self) -> &tree_sitter::Node<'tree> {
             match self {
-                Self::ArrayExpr(x) => Some(x),
-                _ => None,
+                Self::Char(x) => x.node(),
+                Self::F32(x) => x.node(),
+                Self::F64(x) => x.node(),
+                Self::I128(x) => x.node(),
+                Self::I16(x) => x.node(),
+                Self::I64(x) => x.node(),
+                Self::I8(x) => x.node(),
+                Self::Nat(x) => x.node(),
+                Self::String(x) => x.node(),
+                Self::U1(x) => x.node(),
+                Self::U128(x) => x.node(),
+                Self::U16(x) => x.node(),
+                Self::U32(x) => x.node(),
+                Self::U64(x) => x.node(),
+                Self::U8(x) => x.node(),
             }
         }
 
-        #[doc = "Returns the node if it is of kind `free_variable` ([FreeVariable]), otherwise returns None"]
         #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn free_variable(self) -> Option<FreeVariable<'tree>> {
+        fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
             match self {
-                Self::FreeVariable(x) => Some(x),
-                _ => None,
+                Self::Char(x) => x.node_mut(),
+                Self::F32(x) => x.node_mut(),
+                Self::F64(x) => x.node_mut(),
+                Self::I128(x) => x.node_mut(),
+                Self::I16(x) => x.node_mut(),
+                Self::I64(x) => x.node_mut(),
+                Self::I8(x) => x.node_mut(),
+                Self::Nat(x) => x.node_mut(),
+                Self::String(x) => x.node_mut(),
+                Self::U1(x) => x.node_mut(),
+                Self::U128(x) => x.node_mut(),
+                Self::U16(x) => x.node_mut(),
+                Self::U32(x) => x.node_mut(),
+                Self::U64(x) => x.node_mut(),
+                Self::U8(x) => x.node_mut(),
             }
         }
 
-        #[doc = "Returns the node if it is of kind `if_expr` ([IfExpr]), otherwise returns None"]
         #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn if_expr(self) -> Option<IfExpr<'tree>> {
+        fn into_node(self) -> tree_sitter::Node<'tree> {
             match self {
-                Self::IfExpr(x) => Some(x),
-                _ => None,
+                Self::Char(x) => x.into_node(),
+                Self::F32(x) => x.into_node(),
+                Self::F64(x) => x.into_node(),
+                Self::I128(x) => x.into_node(),
+                Self::I16(x) => x.into_node(),
+                Self::I64(x) => x.into_node(),
+                Self::I8(x) => x.into_node(),
+                Self::Nat(x) => x.into_node(),
+                Self::String(x) => x.into_node(),
+                Self::U1(x) => x.into_node(),
+                Self::U128(x) => x.into_node(),
+                Self::U16(x) => x.into_node(),
+                Self::U32(x) => x.into_node(),
+                Self::U64(x) => x.into_node(),
+                Self::U8(x) => x.into_node(),
             }
         }
-
+    }
+    #[doc = "one of `{literal | macro_variable}`:\n- [Literal]\n- [MacroVariable]"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub enum Literal_MacroVariable<'tree> {
+        Literal(Literal<'tree>),
+        MacroVariable(MacroVariable<'tree>),
+    }
+    #[automatically_derived]
+    impl<'tree> Literal_MacroVariable<'tree> {
         #[doc = "Returns the node if it is of kind `literal` ([Literal]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -9537,101 +12712,32 @@ pub mod anon_unions {
             }
         }
 
-        #[doc = "Returns the node if it is of kind `match_expr` ([MatchExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn match_expr(self) -> Option<MatchExpr<'tree>> {
-            match self {
-                Self::MatchExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `path` ([Path]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn path(self) -> Option<Path<'tree>> {
-            match self {
-                Self::Path(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `return_expr` ([ReturnExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn return_expr(self) -> Option<ReturnExpr<'tree>> {
-            match self {
-                Self::ReturnExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `tuple_expr` ([TupleExpr]), otherwise returns None"]
-        #[inline]
-        #[allow(unused, non_snake_case)]
-        pub fn tuple_expr(self) -> Option<TupleExpr<'tree>> {
-            match self {
-                Self::TupleExpr(x) => Some(x),
-                _ => None,
-            }
-        }
-
-        #[doc = "Returns the node if it is of kind `universe_expr` ([UniverseExpr]), otherwise returns None"]
+        #[doc = "Returns the node if it is of kind `macro_variable` ([MacroVariable]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
-        pub fn universe_expr(self) -> Option<UniverseExpr<'tree>> {
+        pub fn macro_variable(self) -> Option<MacroVariable<'tree>> {
             match self {
-                Self::UniverseExpr(x) => Some(x),
+                Self::MacroVariable(x) => Some(x),
                 _ => None,
             }
         }
     }
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>>
-        for ArrayExpr_FreeVariable_IfExpr_Literal_MatchExpr_Path_ReturnExpr_TupleExpr_UniverseExpr<
-            'tree,
-        >
-    {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Literal_MacroVariable<'tree> {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
         fn try_from(node: tree_sitter::Node<'tree>) -> Result<Self, Self::Error> {
             match node.kind() {
-                "array_expr" => Ok(unsafe {
-                    Self :: ArrayExpr (< ArrayExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "free_variable" => Ok(unsafe {
-                    Self :: FreeVariable (< FreeVariable < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "if_expr" => {
-                    Ok(unsafe {
-                        Self :: IfExpr (< IfExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                    })
-                }
                 "literal" => {
                     Ok(unsafe {
                         Self :: Literal (< Literal < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                     })
                 }
-                "match_expr" => Ok(unsafe {
-                    Self :: MatchExpr (< MatchExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "path" => Ok(unsafe {
-                    Self::Path(
-                        <Path<'tree> as type_sitter_lib::TypedNode<'tree>>::from_node_unchecked(
-                            node,
-                        ),
-                    )
-                }),
-                "return_expr" => Ok(unsafe {
-                    Self :: ReturnExpr (< ReturnExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "tuple_expr" => Ok(unsafe {
-                    Self :: TupleExpr (< TupleExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
-                }),
-                "universe_expr" => Ok(unsafe {
-                    Self :: UniverseExpr (< UniverseExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                "macro_variable" => Ok(unsafe {
+                    Self::MacroVariable(<MacroVariable<'tree> as type_sitter_lib::TypedNode<
+                        'tree,
+                    >>::from_node_unchecked(node))
                 }),
                 _ => Err(type_sitter_lib::IncorrectKind {
                     node,
@@ -9641,71 +12747,106 @@ pub mod anon_unions {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree>
-        for ArrayExpr_FreeVariable_IfExpr_Literal_MatchExpr_Path_ReturnExpr_TupleExpr_UniverseExpr<
-            'tree,
-        >
-    {
-        const KIND : & 'static str = "{array_expr | free_variable | if_expr | literal | match_expr | path | return_expr | tuple_expr | universe_expr}" ;
+    impl<'tree> type_sitter_lib::TypedNode<'tree> for Literal_MacroVariable<'tree> {
+        const KIND: &'static str = "{literal | macro_variable}";
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
             match self {
-                Self::ArrayExpr(x) => x.node(),
-                Self::FreeVariable(x) => x.node(),
-                Self::IfExpr(x) => x.node(),
                 Self::Literal(x) => x.node(),
-                Self::MatchExpr(x) => x.node(),
-                Self::Path(x) => x.node(),
-                Self::ReturnExpr(x) => x.node(),
-                Self::TupleExpr(x) => x.node(),
-                Self::UniverseExpr(x) => x.node(),
+                Self::MacroVariable(x) => x.node(),
             }
         }
 
         #[inline]
         fn node_mut(&mut self) -> &mut tree_sitter::Node<'tree> {
             match self {
-                Self::ArrayExpr(x) => x.node_mut(),
-                Self::FreeVariable(x) => x.node_mut(),
-                Self::IfExpr(x) => x.node_mut(),
                 Self::Literal(x) => x.node_mut(),
-                Self::MatchExpr(x) => x.node_mut(),
-                Self::Path(x) => x.node_mut(),
-                Self::ReturnExpr(x) => x.node_mut(),
-                Self::TupleExpr(x) => x.node_mut(),
-                Self::UniverseExpr(x) => x.node_mut(),
+                Self::MacroVariable(x) => x.node_mut(),
             }
         }
 
         #[inline]
         fn into_node(self) -> tree_sitter::Node<'tree> {
             match self {
-                Self::ArrayExpr(x) => x.into_node(),
-                Self::FreeVariable(x) => x.into_node(),
-                Self::IfExpr(x) => x.into_node(),
                 Self::Literal(x) => x.into_node(),
-                Self::MatchExpr(x) => x.into_node(),
-                Self::Path(x) => x.into_node(),
-                Self::ReturnExpr(x) => x.into_node(),
-                Self::TupleExpr(x) => x.into_node(),
-                Self::UniverseExpr(x) => x.into_node(),
+                Self::MacroVariable(x) => x.into_node(),
             }
         }
     }
-    #[doc = "one of `{, | cons_pattern | group_pattern | literal | parameter | rest_pattern}`:\n- [symbols::Comma]\n- [ConsPattern]\n- [GroupPattern]\n- [Literal]\n- [Parameter]\n- [RestPattern]"]
+    #[doc = "one of `{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}`:\n- [AnnExpr]\n- [BinaryExpr]\n- [ForallParameters]\n- [LamExpr]\n- [MatchExpr]\n- [PiExpr]\n- [PiParameters]\n- [Primary]\n- [SigmaExpr]\n- [TypeAppExpr]"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub enum AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr<
+        'tree,
+    > {
+        AnnExpr(AnnExpr<'tree>),
+        BinaryExpr(BinaryExpr<'tree>),
+        ForallParameters(ForallParameters<'tree>),
+        LamExpr(LamExpr<'tree>),
+        MatchExpr(MatchExpr<'tree>),
+        PiExpr(PiExpr<'tree>),
+        PiParameters(PiParameters<'tree>),
+        Primary(Primary<'tree>),
+        SigmaExpr(SigmaExpr<'tree>),
+        TypeAppExpr(TypeAppExpr<'tree>),
+    }
+    #[automatically_derived]
+    impl < 'tree > AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > { # [doc = "Returns the node if it is of kind `ann_expr` ([AnnExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn ann_expr (self) -> Option < AnnExpr < 'tree > > { match self { Self :: AnnExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `binary_expr` ([BinaryExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn binary_expr (self) -> Option < BinaryExpr < 'tree > > { match self { Self :: BinaryExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `forall_parameters` ([ForallParameters]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn forall_parameters (self) -> Option < ForallParameters < 'tree > > { match self { Self :: ForallParameters (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `lam_expr` ([LamExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn lam_expr (self) -> Option < LamExpr < 'tree > > { match self { Self :: LamExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `match_expr` ([MatchExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn match_expr (self) -> Option < MatchExpr < 'tree > > { match self { Self :: MatchExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `pi_expr` ([PiExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn pi_expr (self) -> Option < PiExpr < 'tree > > { match self { Self :: PiExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `pi_parameters` ([PiParameters]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn pi_parameters (self) -> Option < PiParameters < 'tree > > { match self { Self :: PiParameters (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `primary` ([Primary]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn primary (self) -> Option < Primary < 'tree > > { match self { Self :: Primary (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `sigma_expr` ([SigmaExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn sigma_expr (self) -> Option < SigmaExpr < 'tree > > { match self { Self :: SigmaExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `type_app_expr` ([TypeAppExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn type_app_expr (self) -> Option < TypeAppExpr < 'tree > > { match self { Self :: TypeAppExpr (x) => Some (x) , _ => None , } } }
+    #[automatically_derived]
+    impl < 'tree > TryFrom < tree_sitter :: Node < 'tree >> for AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > { type Error = type_sitter_lib :: IncorrectKind < 'tree > ; # [inline] fn try_from (node : tree_sitter :: Node < 'tree >) -> Result < Self , Self :: Error > { match node . kind () { "ann_expr" => Ok (unsafe { Self :: AnnExpr (< AnnExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "binary_expr" => Ok (unsafe { Self :: BinaryExpr (< BinaryExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "forall_parameters" => Ok (unsafe { Self :: ForallParameters (< ForallParameters < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "lam_expr" => Ok (unsafe { Self :: LamExpr (< LamExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "match_expr" => Ok (unsafe { Self :: MatchExpr (< MatchExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "pi_expr" => Ok (unsafe { Self :: PiExpr (< PiExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "pi_parameters" => Ok (unsafe { Self :: PiParameters (< PiParameters < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "primary" => Ok (unsafe { Self :: Primary (< Primary < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "sigma_expr" => Ok (unsafe { Self :: SigmaExpr (< SigmaExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "type_app_expr" => Ok (unsafe { Self :: TypeAppExpr (< TypeAppExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , _ => Err (type_sitter_lib :: IncorrectKind { node , kind : < Self as type_sitter_lib :: TypedNode < 'tree >> :: KIND , }) } } }
+    #[automatically_derived]
+    impl < 'tree > type_sitter_lib :: TypedNode < 'tree > for AnnExpr_BinaryExpr_ForallParameters_LamExpr_MatchExpr_PiExpr_PiParameters_Primary_SigmaExpr_TypeAppExpr < 'tree > { const KIND : & 'static str = "{ann_expr | binary_expr | forall_parameters | lam_expr | match_expr | pi_expr | pi_parameters | primary | sigma_expr | type_app_expr}" ; # [inline] fn node (& self) -> & tree_sitter :: Node < 'tree > { match self { Self :: AnnExpr (x) => x . node () , Self :: BinaryExpr (x) => x . node () , Self :: ForallParameters (x) => x . node () , Self :: LamExpr (x) => x . node () , Self :: MatchExpr (x) => x . node () , Self :: PiExpr (x) => x . node () , Self :: PiParameters (x) => x . node () , Self :: Primary (x) => x . node () , Self :: SigmaExpr (x) => x . node () , Self :: TypeAppExpr (x) => x . node () , } } # [inline] fn node_mut (& mut self) -> & mut tree_sitter :: Node < 'tree > { match self { Self :: AnnExpr (x) => x . node_mut () , Self :: BinaryExpr (x) => x . node_mut () , Self :: ForallParameters (x) => x . node_mut () , Self :: LamExpr (x) => x . node_mut () , Self :: MatchExpr (x) => x . node_mut () , Self :: PiExpr (x) => x . node_mut () , Self :: PiParameters (x) => x . node_mut () , Self :: Primary (x) => x . node_mut () , Self :: SigmaExpr (x) => x . node_mut () , Self :: TypeAppExpr (x) => x . node_mut () , } } # [inline] fn into_node (self) -> tree_sitter :: Node < 'tree > { match self { Self :: AnnExpr (x) => x . into_node () , Self :: BinaryExpr (x) => x . into_node () , Self :: ForallParameters (x) => x . into_node () , Self :: LamExpr (x) => x . into_node () , Self :: MatchExpr (x) => x . into_node () , Self :: PiExpr (x) => x . into_node () , Self :: PiParameters (x) => x . into_node () , Self :: Primary (x) => x . into_node () , Self :: SigmaExpr (x) => x . into_node () , Self :: TypeAppExpr (x) => x . into_node () , } } }
+    #[doc = "one of `{array_expr | free_variable | if_expr | let_expr | literal | macro_call | macro_variable | map_expr | match_expr | panic_expr | path | projection_expr | quote_expr | record_expr | record_update | refl_expr | return_expr | rewrite_expr | section_expr | set_expr | splice_expr | tuple_expr | universe_expr}`:\n- [ArrayExpr]\n- [FreeVariable]\n- [IfExpr]\n- [LetExpr]\n- [Literal]\n- [MacroCall]\n- [MacroVariable]\n- [MapExpr]\n- [MatchExpr]\n- [PanicExpr]\n- [Path]\n- [ProjectionExpr]\n- [QuoteExpr]\n- [RecordExpr]\n- [RecordUpdate]\n- [ReflExpr]\n- [ReturnExpr]\n- [RewriteExpr]\n- [SectionExpr]\n- [SetExpr]\n- [SpliceExpr]\n- [TupleExpr]\n- [UniverseExpr]"]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types)]
+    pub enum ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr<
+        'tree,
+    > {
+        ArrayExpr(ArrayExpr<'tree>),
+        FreeVariable(FreeVariable<'tree>),
+        IfExpr(IfExpr<'tree>),
+        LetExpr(LetExpr<'tree>),
+        Literal(Literal<'tree>),
+        MacroCall(MacroCall<'tree>),
+        MacroVariable(MacroVariable<'tree>),
+        MapExpr(MapExpr<'tree>),
+        MatchExpr(MatchExpr<'tree>),
+        PanicExpr(PanicExpr<'tree>),
+        Path(Path<'tree>),
+        ProjectionExpr(ProjectionExpr<'tree>),
+        QuoteExpr(QuoteExpr<'tree>),
+        RecordExpr(RecordExpr<'tree>),
+        RecordUpdate(RecordUpdate<'tree>),
+        ReflExpr(ReflExpr<'tree>),
+        ReturnExpr(ReturnExpr<'tree>),
+        RewriteExpr(RewriteExpr<'tree>),
+        SectionExpr(SectionExpr<'tree>),
+        SetExpr(SetExpr<'tree>),
+        SpliceExpr(SpliceExpr<'tree>),
+        TupleExpr(TupleExpr<'tree>),
+        UniverseExpr(UniverseExpr<'tree>),
+    }
+    #[automatically_derived]
+    impl < 'tree > ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr < 'tree > { # [doc = "Returns the node if it is of kind `array_expr` ([ArrayExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn array_expr (self) -> Option < ArrayExpr < 'tree > > { match self { Self :: ArrayExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `free_variable` ([FreeVariable]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn free_variable (self) -> Option < FreeVariable < 'tree > > { match self { Self :: FreeVariable (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `if_expr` ([IfExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn if_expr (self) -> Option < IfExpr < 'tree > > { match self { Self :: IfExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `let_expr` ([LetExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn let_expr (self) -> Option < LetExpr < 'tree > > { match self { Self :: LetExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `literal` ([Literal]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn literal (self) -> Option < Literal < 'tree > > { match self { Self :: Literal (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `macro_call` ([MacroCall]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn macro_call (self) -> Option < MacroCall < 'tree > > { match self { Self :: MacroCall (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `macro_variable` ([MacroVariable]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn macro_variable (self) -> Option < MacroVariable < 'tree > > { match self { Self :: MacroVariable (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `map_expr` ([MapExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn map_expr (self) -> Option < MapExpr < 'tree > > { match self { Self :: MapExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `match_expr` ([MatchExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn match_expr (self) -> Option < MatchExpr < 'tree > > { match self { Self :: MatchExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `panic_expr` ([PanicExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn panic_expr (self) -> Option < PanicExpr < 'tree > > { match self { Self :: PanicExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `path` ([Path]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn path (self) -> Option < Path < 'tree > > { match self { Self :: Path (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `projection_expr` ([ProjectionExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn projection_expr (self) -> Option < ProjectionExpr < 'tree > > { match self { Self :: ProjectionExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `quote_expr` ([QuoteExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn quote_expr (self) -> Option < QuoteExpr < 'tree > > { match self { Self :: QuoteExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `record_expr` ([RecordExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn record_expr (self) -> Option < RecordExpr < 'tree > > { match self { Self :: RecordExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `record_update` ([RecordUpdate]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn record_update (self) -> Option < RecordUpdate < 'tree > > { match self { Self :: RecordUpdate (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `refl_expr` ([ReflExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn refl_expr (self) -> Option < ReflExpr < 'tree > > { match self { Self :: ReflExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `return_expr` ([ReturnExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn return_expr (self) -> Option < ReturnExpr < 'tree > > { match self { Self :: ReturnExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `rewrite_expr` ([RewriteExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn rewrite_expr (self) -> Option < RewriteExpr < 'tree > > { match self { Self :: RewriteExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `section_expr` ([SectionExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn section_expr (self) -> Option < SectionExpr < 'tree > > { match self { Self :: SectionExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `set_expr` ([SetExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn set_expr (self) -> Option < SetExpr < 'tree > > { match self { Self :: SetExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `splice_expr` ([SpliceExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn splice_expr (self) -> Option < SpliceExpr < 'tree > > { match self { Self :: SpliceExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `tuple_expr` ([TupleExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn tuple_expr (self) -> Option < TupleExpr < 'tree > > { match self { Self :: TupleExpr (x) => Some (x) , _ => None , } } # [doc = "Returns the node if it is of kind `universe_expr` ([UniverseExpr]), otherwise returns None"] # [inline] # [allow (unused , non_snake_case)] pub fn universe_expr (self) -> Option < UniverseExpr < 'tree > > { match self { Self :: UniverseExpr (x) => Some (x) , _ => None , } } }
+    #[automatically_derived]
+    impl < 'tree > TryFrom < tree_sitter :: Node < 'tree >> for ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr < 'tree > { type Error = type_sitter_lib :: IncorrectKind < 'tree > ; # [inline] fn try_from (node : tree_sitter :: Node < 'tree >) -> Result < Self , Self :: Error > { match node . kind () { "array_expr" => Ok (unsafe { Self :: ArrayExpr (< ArrayExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "free_variable" => Ok (unsafe { Self :: FreeVariable (< FreeVariable < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "if_expr" => Ok (unsafe { Self :: IfExpr (< IfExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "let_expr" => Ok (unsafe { Self :: LetExpr (< LetExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "literal" => Ok (unsafe { Self :: Literal (< Literal < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "macro_call" => Ok (unsafe { Self :: MacroCall (< MacroCall < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "macro_variable" => Ok (unsafe { Self :: MacroVariable (< MacroVariable < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "map_expr" => Ok (unsafe { Self :: MapExpr (< MapExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "match_expr" => Ok (unsafe { Self :: MatchExpr (< MatchExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "panic_expr" => Ok (unsafe { Self :: PanicExpr (< PanicExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "path" => Ok (unsafe { Self :: Path (< Path < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "projection_expr" => Ok (unsafe { Self :: ProjectionExpr (< ProjectionExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "quote_expr" => Ok (unsafe { Self :: QuoteExpr (< QuoteExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "record_expr" => Ok (unsafe { Self :: RecordExpr (< RecordExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "record_update" => Ok (unsafe { Self :: RecordUpdate (< RecordUpdate < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "refl_expr" => Ok (unsafe { Self :: ReflExpr (< ReflExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "return_expr" => Ok (unsafe { Self :: ReturnExpr (< ReturnExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "rewrite_expr" => Ok (unsafe { Self :: RewriteExpr (< RewriteExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "section_expr" => Ok (unsafe { Self :: SectionExpr (< SectionExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "set_expr" => Ok (unsafe { Self :: SetExpr (< SetExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "splice_expr" => Ok (unsafe { Self :: SpliceExpr (< SpliceExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "tuple_expr" => Ok (unsafe { Self :: TupleExpr (< TupleExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , "universe_expr" => Ok (unsafe { Self :: UniverseExpr (< UniverseExpr < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node)) }) , _ => Err (type_sitter_lib :: IncorrectKind { node , kind : < Self as type_sitter_lib :: TypedNode < 'tree >> :: KIND , }) } } }
+    #[automatically_derived]
+    impl < 'tree > type_sitter_lib :: TypedNode < 'tree > for ArrayExpr_FreeVariable_IfExpr_LetExpr_Literal_MacroCall_MacroVariable_MapExpr_MatchExpr_PanicExpr_Path_ProjectionExpr_QuoteExpr_RecordExpr_RecordUpdate_ReflExpr_ReturnExpr_RewriteExpr_SectionExpr_SetExpr_SpliceExpr_TupleExpr_UniverseExpr < 'tree > { const KIND : & 'static str = "{array_expr | free_variable | if_expr | let_expr | literal | macro_call | macro_variable | map_expr | match_expr | panic_expr | path | projection_expr | quote_expr | record_expr | record_update | refl_expr | return_expr | rewrite_expr | section_expr | set_expr | splice_expr | tuple_expr | universe_expr}" ; # [inline] fn node (& self) -> & tree_sitter :: Node < 'tree > { match self { Self :: ArrayExpr (x) => x . node () , Self :: FreeVariable (x) => x . node () , Self :: IfExpr (x) => x . node () , Self :: LetExpr (x) => x . node () , Self :: Literal (x) => x . node () , Self :: MacroCall (x) => x . node () , Self :: MacroVariable (x) => x . node () , Self :: MapExpr (x) => x . node () , Self :: MatchExpr (x) => x . node () , Self :: PanicExpr (x) => x . node () , Self :: Path (x) => x . node () , Self :: ProjectionExpr (x) => x . node () , Self :: QuoteExpr (x) => x . node () , Self :: RecordExpr (x) => x . node () , Self :: RecordUpdate (x) => x . node () , Self :: ReflExpr (x) => x . node () , Self :: ReturnExpr (x) => x . node () , Self :: RewriteExpr (x) => x . node () , Self :: SectionExpr (x) => x . node () , Self :: SetExpr (x) => x . node () , Self :: SpliceExpr (x) => x . node () , Self :: TupleExpr (x) => x . node () , Self :: UniverseExpr (x) => x . node () , } } # [inline] fn node_mut (& mut self) -> & mut tree_sitter :: Node < 'tree > { match self { Self :: ArrayExpr (x) => x . node_mut () , Self :: FreeVariable (x) => x . node_mut () , Self :: IfExpr (x) => x . node_mut () , Self :: LetExpr (x) => x . node_mut () , Self :: Literal (x) => x . node_mut () , Self :: MacroCall (x) => x . node_mut () , Self :: MacroVariable (x) => x . node_mut () , Self :: MapExpr (x) => x . node_mut () , Self :: MatchExpr (x) => x . node_mut () , Self :: PanicExpr (x) => x . node_mut () , Self :: Path (x) => x . node_mut () , Self :: ProjectionExpr (x) => x . node_mut () , Self :: QuoteExpr (x) => x . node_mut () , Self :: RecordExpr (x) => x . node_mut () , Self :: RecordUpdate (x) => x . node_mut () , Self :: ReflExpr (x) => x . node_mut () , Self :: ReturnExpr (x) => x . node_mut () , Self :: RewriteExpr (x) => x . node_mut () , Self :: SectionExpr (x) => x . node_mut () , Self :: SetExpr (x) => x . node_mut () , Self :: SpliceExpr (x) => x . node_mut () , Self :: TupleExpr (x) => x . node_mut () , Self :: UniverseExpr (x) => x . node_mut () , } } # [inline] fn into_node (self) -> tree_sitter :: Node < 'tree > { match self { Self :: ArrayExpr (x) => x . into_node () , Self :: FreeVariable (x) => x . into_node () , Self :: IfExpr (x) => x . into_node () , Self :: LetExpr (x) => x . into_node () , Self :: Literal (x) => x . into_node () , Self :: MacroCall (x) => x . into_node () , Self :: MacroVariable (x) => x . into_node () , Self :: MapExpr (x) => x . into_node () , Self :: MatchExpr (x) => x . into_node () , Self :: PanicExpr (x) => x . into_node () , Self :: Path (x) => x . into_node () , Self :: ProjectionExpr (x) => x . into_node () , Self :: QuoteExpr (x) => x . into_node () , Self :: RecordExpr (x) => x . into_node () , Self :: RecordUpdate (x) => x . into_node () , Self :: ReflExpr (x) => x . into_node () , Self :: ReturnExpr (x) => x . into_node () , Self :: RewriteExpr (x) => x . into_node () , Self :: SectionExpr (x) => x . into_node () , Self :: SetExpr (x) => x . into_node () , Self :: SpliceExpr (x) => x . into_node () , Self :: TupleExpr (x) => x . into_node () , Self :: UniverseExpr (x) => x . into_node () , } } }
+    #[doc = "one of `{, | cons_pattern | group_pattern | literal | parameter | rest_pattern | tuple_pattern}`:\n- [symbols::Comma]\n- [ConsPattern]\n- [GroupPattern]\n- [Literal]\n- [Parameter]\n- [RestPattern]\n- [TuplePattern]"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub enum Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern<'tree> {
+    pub enum Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern<'tree> {
         Comma(symbols::Comma<'tree>),
         ConsPattern(ConsPattern<'tree>),
         GroupPattern(GroupPattern<'tree>),
         Literal(Literal<'tree>),
         Parameter(Parameter<'tree>),
         RestPattern(RestPattern<'tree>),
+        TuplePattern(TuplePattern<'tree>),
     }
     #[automatically_derived]
-    impl<'tree> Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern<'tree> {
+    impl<'tree> Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern<'tree> {
         #[doc = "Returns the node if it is of kind `,` ([symbols::Comma]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -9765,10 +12906,20 @@ pub mod anon_unions {
                 _ => None,
             }
         }
+
+        #[doc = "Returns the node if it is of kind `tuple_pattern` ([TuplePattern]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn tuple_pattern(self) -> Option<TuplePattern<'tree>> {
+            match self {
+                Self::TuplePattern(x) => Some(x),
+                _ => None,
+            }
+        }
     }
     #[automatically_derived]
     impl<'tree> TryFrom<tree_sitter::Node<'tree>>
-        for Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern<'tree>
+        for Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern<'tree>
     {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
@@ -9797,6 +12948,9 @@ pub mod anon_unions {
                 "rest_pattern" => Ok(unsafe {
                     Self :: RestPattern (< RestPattern < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                 }),
+                "tuple_pattern" => Ok(unsafe {
+                    Self :: TuplePattern (< TuplePattern < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
                 _ => Err(type_sitter_lib::IncorrectKind {
                     node,
                     kind: <Self as type_sitter_lib::TypedNode<'tree>>::KIND,
@@ -9806,10 +12960,9 @@ pub mod anon_unions {
     }
     #[automatically_derived]
     impl<'tree> type_sitter_lib::TypedNode<'tree>
-        for Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern<'tree>
+        for Comma_ConsPattern_GroupPattern_Literal_Parameter_RestPattern_TuplePattern<'tree>
     {
-        const KIND: &'static str =
-            "{, | cons_pattern | group_pattern | literal | parameter | rest_pattern}";
+        const KIND : & 'static str = "{, | cons_pattern | group_pattern | literal | parameter | rest_pattern | tuple_pattern}" ;
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -9820,6 +12973,7 @@ pub mod anon_unions {
                 Self::Literal(x) => x.node(),
                 Self::Parameter(x) => x.node(),
                 Self::RestPattern(x) => x.node(),
+                Self::TuplePattern(x) => x.node(),
             }
         }
 
@@ -9832,6 +12986,7 @@ pub mod anon_unions {
                 Self::Literal(x) => x.node_mut(),
                 Self::Parameter(x) => x.node_mut(),
                 Self::RestPattern(x) => x.node_mut(),
+                Self::TuplePattern(x) => x.node_mut(),
             }
         }
 
@@ -9844,21 +12999,33 @@ pub mod anon_unions {
                 Self::Literal(x) => x.into_node(),
                 Self::Parameter(x) => x.into_node(),
                 Self::RestPattern(x) => x.into_node(),
+                Self::TuplePattern(x) => x.into_node(),
             }
         }
     }
-    #[doc = "one of `{clause | command | inductive | signature | using}`:\n- [Clause]\n- [Command]\n- [Inductive]\n- [Signature]\n- [Using]"]
+    #[doc = "one of `{clause | command | inductive | instance | macro_decl | mutual | signature | trait_decl | type_alias | using}`:\n- [Clause]\n- [Command]\n- [Inductive]\n- [Instance]\n- [MacroDecl]\n- [Mutual]\n- [Signature]\n- [TraitDecl]\n- [TypeAlias]\n- [Using]"]
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[allow(non_camel_case_types)]
-    pub enum Clause_Command_Inductive_Signature_Using<'tree> {
+    pub enum Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using<
+        'tree,
+    > {
         Clause(Clause<'tree>),
         Command(Command<'tree>),
         Inductive(Inductive<'tree>),
+        Instance(Instance<'tree>),
+        MacroDecl(MacroDecl<'tree>),
+        Mutual(Mutual<'tree>),
         Signature(Signature<'tree>),
+        TraitDecl(TraitDecl<'tree>),
+        TypeAlias(TypeAlias<'tree>),
         Using(Using<'tree>),
     }
     #[automatically_derived]
-    impl<'tree> Clause_Command_Inductive_Signature_Using<'tree> {
+    impl<'tree>
+        Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using<
+            'tree,
+        >
+    {
         #[doc = "Returns the node if it is of kind `clause` ([Clause]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -9889,6 +13056,36 @@ pub mod anon_unions {
             }
         }
 
+        #[doc = "Returns the node if it is of kind `instance` ([Instance]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn instance(self) -> Option<Instance<'tree>> {
+            match self {
+                Self::Instance(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `macro_decl` ([MacroDecl]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn macro_decl(self) -> Option<MacroDecl<'tree>> {
+            match self {
+                Self::MacroDecl(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `mutual` ([Mutual]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn mutual(self) -> Option<Mutual<'tree>> {
+            match self {
+                Self::Mutual(x) => Some(x),
+                _ => None,
+            }
+        }
+
         #[doc = "Returns the node if it is of kind `signature` ([Signature]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -9899,6 +13096,26 @@ pub mod anon_unions {
             }
         }
 
+        #[doc = "Returns the node if it is of kind `trait_decl` ([TraitDecl]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn trait_decl(self) -> Option<TraitDecl<'tree>> {
+            match self {
+                Self::TraitDecl(x) => Some(x),
+                _ => None,
+            }
+        }
+
+        #[doc = "Returns the node if it is of kind `type_alias` ([TypeAlias]), otherwise returns None"]
+        #[inline]
+        #[allow(unused, non_snake_case)]
+        pub fn type_alias(self) -> Option<TypeAlias<'tree>> {
+            match self {
+                Self::TypeAlias(x) => Some(x),
+                _ => None,
+            }
+        }
+
         #[doc = "Returns the node if it is of kind `using` ([Using]), otherwise returns None"]
         #[inline]
         #[allow(unused, non_snake_case)]
@@ -9910,7 +13127,11 @@ pub mod anon_unions {
         }
     }
     #[automatically_derived]
-    impl<'tree> TryFrom<tree_sitter::Node<'tree>> for Clause_Command_Inductive_Signature_Using<'tree> {
+    impl<'tree> TryFrom<tree_sitter::Node<'tree>>
+        for Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using<
+            'tree,
+        >
+    {
         type Error = type_sitter_lib::IncorrectKind<'tree>;
 
         #[inline]
@@ -9929,9 +13150,28 @@ pub mod anon_unions {
                 "inductive" => Ok(unsafe {
                     Self :: Inductive (< Inductive < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                 }),
+                "instance" => {
+                    Ok(unsafe {
+                        Self :: Instance (< Instance < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
+                "macro_decl" => Ok(unsafe {
+                    Self :: MacroDecl (< MacroDecl < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
+                "mutual" => {
+                    Ok(unsafe {
+                        Self :: Mutual (< Mutual < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                    })
+                }
                 "signature" => Ok(unsafe {
                     Self :: Signature (< Signature < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
                 }),
+                "trait_decl" => Ok(unsafe {
+                    Self :: TraitDecl (< TraitDecl < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
+                "type_alias" => Ok(unsafe {
+                    Self :: TypeAlias (< TypeAlias < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
+                }),
                 "using" => {
                     Ok(unsafe {
                         Self :: Using (< Using < 'tree > as type_sitter_lib :: TypedNode < 'tree >> :: from_node_unchecked (node))
@@ -9945,8 +13185,12 @@ pub mod anon_unions {
         }
     }
     #[automatically_derived]
-    impl<'tree> type_sitter_lib::TypedNode<'tree> for Clause_Command_Inductive_Signature_Using<'tree> {
-        const KIND: &'static str = "{clause | command | inductive | signature | using}";
+    impl<'tree> type_sitter_lib::TypedNode<'tree>
+        for Clause_Command_Inductive_Instance_MacroDecl_Mutual_Signature_TraitDecl_TypeAlias_Using<
+            'tree,
+        >
+    {
+        const KIND : & 'static str = "{clause | command | inductive | instance | macro_decl | mutual | signature | trait_decl | type_alias | using}" ;
 
         #[inline]
         fn node(&self) -> &tree_sitter::Node<'tree> {
@@ -9954,7 +13198,12 @@ pub mod anon_unions {
                 Self::Clause(x) => x.node(),
                 Self::Command(x) => x.node(),
                 Self::Inductive(x) => x.node(),
+                Self::Instance(x) => x.node(),
+                Self::MacroDecl(x) => x.node(),
+                Self::Mutual(x) => x.node(),
                 Self::Signature(x) => x.node(),
+                Self::TraitDecl(x) => x.node(),
+                Self::TypeAlias(x) => x.node(),
                 Self::Using(x) => x.node(),
             }
         }
@@ -9965,7 +13214,12 @@ pub mod anon_unions {
                 Self::Clause(x) => x.node_mut(),
                 Self::Command(x) => x.node_mut(),
                 Self::Inductive(x) => x.node_mut(),
+                Self::Instance(x) => x.node_mut(),
+                Self::MacroDecl(x) => x.node_mut(),
+                Self::Mutual(x) => x.node_mut(),
                 Self::Signature(x) => x.node_mut(),
+                Self::TraitDecl(x) => x.node_mut(),
+                Self::TypeAlias(x) => x.node_mut(),
                 Self::Using(x) => x.node_mut(),
             }
         }
@@ -9976,7 +13230,12 @@ pub mod anon_unions {
                 Self::Clause(x) => x.into_node(),
                 Self::Command(x) => x.into_node(),
                 Self::Inductive(x) => x.into_node(),
+                Self::Instance(x) => x.into_node(),
+                Self::MacroDecl(x) => x.into_node(),
+                Self::Mutual(x) => x.into_node(),
                 Self::Signature(x) => x.into_node(),
+                Self::TraitDecl(x) => x.into_node(),
+                Self::TypeAlias(x) => x.into_node(),
                 Self::Using(x) => x.into_node(),
             }
         }
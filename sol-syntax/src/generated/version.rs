@@ -0,0 +1,4 @@
+//! Generated by the `regen` feature of `sol-syntax`, don't edit it by hand.
+
+/// The version of the grammar that the node types were generated from.
+pub const GRAMMAR_VERSION: u32 = 8;
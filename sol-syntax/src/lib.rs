@@ -12,18 +12,38 @@
 #[rustfmt::skip]
 pub mod generated {
   pub mod node_types;
+  pub mod version;
 }
 
 /// Contains error handling stuff for tree-sitter. This is a separate module because it is
 /// generated by tree-sitter.
 pub mod error_handling;
 
+/// Contains the trivia handling, like the association of doc strings with the declarations,
+/// that can't be expressed in the grammar.
+pub mod trivia;
+
+/// Contains the selection ranges, that are the chains of the syntax nodes enclosing an offset,
+/// used by the "expand selection" feature of the editors.
+pub mod selection;
+
+/// Contains the folding ranges, that are computed from the syntax tree, so they are available
+/// even on files with errors.
+pub mod folding;
+
+/// Contains the tree-sitter queries over the syntax tree, used for the structural search.
+pub mod query;
+
+/// Contains the flat token stream of the programs, with their trivia, for the external
+/// highlighters, and their comments, for the formatter.
+pub mod tokens;
+
 use std::{hash::Hash, ops::Deref, sync::Arc};
 
 pub use generated::node_types::*;
 use sol_diagnostic::DiagnosticDb;
 use sol_vfs::VfsDb;
-use tree_sitter::{Parser, Tree};
+use tree_sitter::{Language, Parser, Tree};
 
 extern crate salsa_2022 as salsa;
 
@@ -34,6 +54,10 @@ pub struct Jar(
     imp_parse,
     error_handling::Source_errors,
     error_handling::Source_validated,
+    trivia::Source_doc_comments,
+    folding::Source_folding_ranges,
+    tokens::tokens,
+    tokens::comments,
 );
 
 /// A database that contains all the information needed to parse a Sol program. This includes
@@ -62,6 +86,10 @@ pub struct Source {
     /// The syntax tree of the program.
     #[return_ref]
     pub syntax_node: ParseTree,
+
+    /// The file that this program was parsed from, it's used to get the memoized queries of
+    /// the file, like its line index.
+    pub file: sol_vfs::SourceFile,
 }
 
 impl serde::Serialize for Source {
@@ -79,6 +107,44 @@ pub fn parse(db: &dyn ParseDb, program: sol_vfs::SourceFile) -> Source {
     imp_parse(db, program).validated(db)
 }
 
+/// The parser, and the typed syntax nodes, were generated from different versions of the
+/// grammar, so the nodes can't be downcasted.
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error(
+    "the parser was generated from the version {parser} of the grammar, but the syntax nodes \
+     from the version {nodes}"
+)]
+#[diagnostic(
+    code(solc::grammar_version),
+    help("regenerate the parser with `cargo build -p sol-syntax --features regen`")
+)]
+pub struct GrammarVersionError {
+    pub parser: u32,
+    pub nodes: u32,
+}
+
+/// Checks that the parser, and the typed syntax nodes, were generated from the same version of
+/// the grammar.
+pub fn check_grammar_version() -> Result<(), GrammarVersionError> {
+    let parser = tree_sitter_sol::GRAMMAR_VERSION;
+    let nodes = generated::version::GRAMMAR_VERSION;
+    if parser != nodes {
+        return Err(GrammarVersionError { parser, nodes });
+    }
+
+    Ok(())
+}
+
+/// Gets the tree-sitter language of Sol, panicking if the parser doesn't match the typed syntax
+/// nodes, as the downcasts of the nodes would panic later, with cryptic errors.
+pub fn language() -> Language {
+    if let Err(error) = check_grammar_version() {
+        panic!("{:?}", miette::Report::new(error));
+    }
+
+    tree_sitter_sol::language()
+}
+
 /// Defines the [`parse`] query.
 ///
 /// Parses a Sol program into a syntax tree. This query is memoized, so it will only be executed
@@ -87,7 +153,7 @@ pub fn parse(db: &dyn ParseDb, program: sol_vfs::SourceFile) -> Source {
 pub fn imp_parse(db: &dyn ParseDb, program: sol_vfs::SourceFile) -> Source {
     let mut parser = Parser::new();
     parser
-        .set_language(tree_sitter_sol::language())
+        .set_language(language())
         .expect("Error loading sol language");
 
     let name = program.module_name(db).clone();
@@ -97,7 +163,9 @@ pub fn imp_parse(db: &dyn ParseDb, program: sol_vfs::SourceFile) -> Source {
         tree: parser.parse(&text, None).unwrap().into(),
     };
 
-    Source::new(db, program.file_path(db).clone(), name, text, tree)
+    let path = program.file_path(db).clone();
+
+    Source::new(db, path, name, text, tree, program)
 }
 
 /// Represents a Sol syntax tree. This is a wrapper around a tree-sitter syntax tree that
@@ -147,4 +215,9 @@ mod tests {
             .parse("Main { IO.println \"Hello, world\" }", None)
             .unwrap();
     }
+
+    #[test]
+    fn test_grammar_version() {
+        crate::check_grammar_version().expect("the parser should match the syntax nodes");
+    }
 }
@@ -0,0 +1,46 @@
+use std::ops::Range;
+
+use tree_sitter::{Point, Query, QueryCursor, QueryError};
+
+use crate::Source;
+
+/// A captured node of a tree-sitter query, with the name of the capture, like `name` in
+/// `(clause name: (path) @name)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryCapture {
+    pub name: String,
+    pub text: String,
+    pub range: Range<usize>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Source {
+    /// Runs the tree-sitter query against the syntax tree of the source, returning the
+    /// captures in the order they appear in the source. It's used for the structural search,
+    /// and to debug the grammar.
+    pub fn query(
+        self,
+        db: &dyn crate::ParseDb,
+        query: &str,
+    ) -> Result<Vec<QueryCapture>, QueryError> {
+        let query = Query::new(crate::language(), query)?;
+        let text = self.source_text(db);
+        let root = self.syntax_node(db).root_node();
+
+        let mut cursor = QueryCursor::new();
+        let captures = cursor
+            .captures(&query, root, text.as_bytes())
+            .map(|(query_match, index)| query_match.captures[index])
+            .map(|capture| QueryCapture {
+                name: query.capture_names()[capture.index as usize].clone(),
+                text: text[capture.node.byte_range()].to_string(),
+                range: capture.node.byte_range(),
+                start: capture.node.start_position(),
+                end: capture.node.end_position(),
+            })
+            .collect();
+
+        Ok(captures)
+    }
+}
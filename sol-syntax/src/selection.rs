@@ -0,0 +1,33 @@
+use std::ops::Range;
+
+use crate::Source;
+
+impl Source {
+    /// Gets the chain of the syntax nodes that encloses the given byte offset, from the
+    /// innermost to the outermost, like identifier, expression, statement, clause, and then the
+    /// top-level declaration. It's used to implement the "expand selection" feature.
+    ///
+    /// The nodes with the same range as their children are skipped, so each range of the chain
+    /// is strictly larger than the previous one.
+    pub fn selection_ranges(self, db: &dyn crate::ParseDb, offset: usize) -> Vec<Range<usize>> {
+        let root = self.syntax_node(db).root_node();
+        let Some(mut node) = root.descendant_for_byte_range(offset, offset) else {
+            return vec![];
+        };
+
+        let mut ranges: Vec<Range<usize>> = vec![];
+        loop {
+            let range = node.byte_range();
+            if ranges.last() != Some(&range) {
+                ranges.push(range);
+            }
+
+            match node.parent() {
+                Some(parent) => node = parent,
+                None => break,
+            }
+        }
+
+        ranges
+    }
+}
@@ -0,0 +1,156 @@
+use std::ops::Range;
+
+use tree_sitter::Node;
+
+/// The kind of a token, it's coarse, so the external highlighters can map it into their own
+/// scopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A reserved word, like `inductive` or `match`.
+    Keyword,
+    Identifier,
+
+    /// An operator, like `+` or `->`, including the user-defined infix operators.
+    Operator,
+
+    /// A delimiter, or a separator, like `(`, `,` or `;`.
+    Punctuation,
+    Number,
+    String,
+    Comment,
+
+    /// A doc string, like `//! Adds two numbers`.
+    DocString,
+    Whitespace,
+
+    /// A piece of the source code that couldn't be parsed.
+    Error,
+}
+
+impl TokenKind {
+    /// Checks if the tokens of the kind are trivia, that is they don't change the meaning of
+    /// the program.
+    pub fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            TokenKind::Whitespace | TokenKind::Comment | TokenKind::DocString
+        )
+    }
+}
+
+/// A token of the source code, the tokens of a program cover all of its source code, including
+/// the trivia, in order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub range: Range<usize>,
+    pub trivia: bool,
+}
+
+/// Defines the [`tokens`] query.
+///
+/// Computes the flat token stream of the program, from the leaves of the syntax tree, so the
+/// external tools, like highlighters and diff tools, don't need to embed tree-sitter. The gaps
+/// between the leaves, that aren't part of the syntax tree, are whitespace tokens.
+#[salsa::tracked]
+pub fn tokens(db: &dyn crate::ParseDb, program: sol_vfs::SourceFile) -> Vec<Token> {
+    let source = crate::parse(db, program);
+    let text = source.source_text(db);
+
+    let mut leaves = vec![];
+    collect_leaves(source.syntax_node(db).root_node(), text, &mut leaves);
+
+    let mut tokens = vec![];
+    let mut offset = 0;
+    for (kind, range) in leaves {
+        if range.start < offset {
+            continue;
+        }
+        if range.start > offset {
+            tokens.push(gap(text, offset..range.start));
+        }
+
+        offset = range.end;
+        tokens.push(Token {
+            kind,
+            range,
+            trivia: kind.is_trivia(),
+        });
+    }
+    if offset < text.len() {
+        tokens.push(gap(text, offset..text.len()));
+    }
+
+    tokens
+}
+
+/// Defines the [`comments`] query.
+///
+/// Gets the comment tokens of the program, the line and block comments, and the doc strings,
+/// in order, so the formatter and the documentation tools can preserve them.
+#[salsa::tracked]
+pub fn comments(db: &dyn crate::ParseDb, program: sol_vfs::SourceFile) -> Vec<Token> {
+    tokens(db, program)
+        .into_iter()
+        .filter(|token| matches!(token.kind, TokenKind::Comment | TokenKind::DocString))
+        .collect()
+}
+
+/// Creates the token of a gap between the leaves, it's whitespace, unless it holds the line
+/// breaks written as `;`, that are hidden by the grammar.
+fn gap(text: &str, range: Range<usize>) -> Token {
+    let kind = match text[range.clone()].trim().is_empty() {
+        true => TokenKind::Whitespace,
+        false => TokenKind::Punctuation,
+    };
+
+    Token {
+        kind,
+        range,
+        trivia: kind.is_trivia(),
+    }
+}
+
+/// Collects the leaves of the syntax tree, in order. The literals and the identifiers are
+/// leaves even if they have children, like the suffixes of the integers.
+fn collect_leaves(node: Node, text: &str, leaves: &mut Vec<(TokenKind, Range<usize>)>) {
+    let kind = match node.kind() {
+        _ if node.is_missing() => return,
+        "doc_string" => Some(TokenKind::DocString),
+        "line_comment" | "block_comment" | "hash_bang" => Some(TokenKind::Comment),
+        "string" | "char" => Some(TokenKind::String),
+        "simple_identifier" | "macro_variable" | "attribute_id" => Some(TokenKind::Identifier),
+        "infix_op" => Some(TokenKind::Operator),
+        "f32" | "f64" | "u1" | "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64"
+        | "i128" | "u128" | "nat" | "octal" | "hex" | "binary" => Some(TokenKind::Number),
+        _ if node.child_count() == 0 && node.is_error() => Some(TokenKind::Error),
+        _ if node.child_count() == 0 => Some(leaf_kind(node, text)),
+        _ => None,
+    };
+
+    if let Some(kind) = kind {
+        leaves.push((kind, node.byte_range()));
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_leaves(child, text, leaves);
+    }
+}
+
+/// Classifies the anonymous leaves, that are the keywords, the operators and the delimiters.
+fn leaf_kind(node: Node, text: &str) -> TokenKind {
+    let contents = node.utf8_text(text.as_bytes()).unwrap_or_default();
+
+    if contents.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        TokenKind::Keyword
+    } else if matches!(
+        contents,
+        "(" | ")" | "{" | "}" | "[" | "]" | "," | ";" | ":" | "`"
+    ) {
+        TokenKind::Punctuation
+    } else {
+        TokenKind::Operator
+    }
+}
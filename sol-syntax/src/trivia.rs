@@ -0,0 +1,134 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use tree_sitter::Node;
+
+use crate::Source;
+
+/// The maximum number of blank lines between two doc strings, or between a doc string and the
+/// declaration, so they are still associated. More blank lines than this detach the doc strings
+/// above from the declaration.
+pub const MAX_BLANK_LINES: usize = 1;
+
+/// The doc strings associated with each top-level declaration, keyed by the start byte of the
+/// declaration node.
+///
+/// The doc strings are kept as byte ranges, including the `//!` prefix, to be mapped into
+/// locations by the lowering.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DocComments {
+    attached: BTreeMap<usize, Vec<Range<usize>>>,
+}
+
+impl DocComments {
+    /// Gets the doc strings attached to the declaration that starts at the given byte.
+    pub fn get(&self, start_byte: usize) -> &[Range<usize>] {
+        self.attached
+            .get(&start_byte)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+#[salsa::tracked]
+impl Source {
+    /// Defines the [`Source::doc_comments`] query.
+    ///
+    /// Associates the doc strings of the program with the top-level declarations following
+    /// them. The doc strings that are directly adjacent are parsed as part of the declaration,
+    /// but the ones separated by blank lines, or by plain comments, are parsed as trivia, so
+    /// this query does collect both of them.
+    ///
+    /// A block of doc strings is attached to the following declaration if there's at most
+    /// [`MAX_BLANK_LINES`] blank lines between them, and the same rule merges the blocks of doc
+    /// strings above it.
+    #[salsa::tracked]
+    pub fn doc_comments(self, db: &dyn crate::ParseDb) -> DocComments {
+        let text = self.source_text(db);
+        let root = self.syntax_node(db).root_node();
+
+        let mut doc_strings = vec![];
+        collect_doc_strings(root, &mut doc_strings);
+        doc_strings.sort_by_key(|range| range.start);
+
+        let mut attached = BTreeMap::new();
+        let mut cursor = root.walk();
+        for decl in root.children_by_field_name("decl", &mut cursor) {
+            let anchor = declaration_anchor(decl);
+
+            // Walks backwards from the declaration, collecting the doc strings while they are
+            // close enough to the previous collected item.
+            let mut docs = vec![];
+            let mut next_start = anchor;
+            for range in doc_strings.iter().rev().filter(|range| range.end <= anchor) {
+                match blank_lines(&text[range.end..next_start]) {
+                    Some(count) if count <= MAX_BLANK_LINES => {}
+                    _ => break,
+                }
+
+                docs.push(range.clone());
+                next_start = range.start;
+            }
+
+            if !docs.is_empty() {
+                docs.reverse();
+                attached.insert(decl.start_byte(), docs);
+            }
+        }
+
+        DocComments { attached }
+    }
+}
+
+/// Collects all the doc strings of the tree, both the ones parsed as part of the declarations
+/// and the ones parsed as trivia.
+fn collect_doc_strings(node: Node, doc_strings: &mut Vec<Range<usize>>) {
+    if node.kind() == "doc_string" {
+        doc_strings.push(node.byte_range());
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_doc_strings(child, doc_strings);
+    }
+}
+
+/// The start byte of the declaration, skipping the doc strings and comments parsed as part
+/// of it.
+fn declaration_anchor(decl: Node) -> usize {
+    let mut cursor = decl.walk();
+    decl.children(&mut cursor)
+        .find(|child| {
+            !matches!(
+                child.kind(),
+                "doc_string" | "line_comment" | "block_comment"
+            )
+        })
+        .map(|child| child.start_byte())
+        .unwrap_or(decl.start_byte())
+}
+
+/// Counts the blank lines in the text between two items. The first and last lines are the
+/// rest of the lines of the items themselves, so they are not counted.
+///
+/// Returns [`None`] if there's anything other than whitespace and comments between them, like
+/// the end of another declaration.
+fn blank_lines(gap: &str) -> Option<usize> {
+    let lines = gap.split('\n').map(str::trim).collect::<Vec<_>>();
+    if lines
+        .iter()
+        .any(|line| !line.is_empty() && !line.starts_with("//"))
+    {
+        return None;
+    }
+    if lines.len() <= 2 {
+        return Some(0);
+    }
+
+    Some(
+        lines[1..lines.len() - 1]
+            .iter()
+            .filter(|line| line.is_empty())
+            .count(),
+    )
+}
@@ -0,0 +1,174 @@
+//! Parses the tree-sitter corpus of `tree-sitter-sol`, and compares the trees against the
+//! expected ones, so the regressions of the grammar are caught before they break the lowering.
+//!
+//! The corpus uses the format of `tree-sitter test`, like:
+//!
+//! ```txt
+//! ==================
+//! Using
+//! ==================
+//!
+//! using Std.IO
+//!
+//! ------------------
+//!
+//! (source_file
+//!   (using (path (identifier (simple_identifier)) (identifier (simple_identifier)))))
+//! ```
+//!
+//! The inputs must parse without errors, unless their expected trees have `ERROR`, or
+//! `MISSING` nodes.
+
+use std::path::{Path, PathBuf};
+
+use tree_sitter::Parser;
+
+/// An entry of the corpus, with its input and its expected tree.
+struct CorpusEntry {
+    file: PathBuf,
+    name: String,
+    input: String,
+    expected: String,
+}
+
+impl CorpusEntry {
+    /// If the entry expects the parser to recover from errors.
+    fn expects_errors(&self) -> bool {
+        self.expected.contains("ERROR") || self.expected.contains("MISSING")
+    }
+}
+
+/// Gets the directory of the corpus, inside of the `tree-sitter-sol` crate.
+fn corpus_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("tree-sitter-sol")
+        .join("test")
+        .join("corpus")
+}
+
+/// Checks if the line is a delimiter of the corpus, made of at least 3 of the character.
+fn is_delimiter(line: &str, c: char) -> bool {
+    let line = line.trim_end();
+    line.len() >= 3 && line.chars().all(|other| other == c)
+}
+
+/// Reads the entries of the corpus file, that are separated by the `===` headers.
+fn read_entries(file: &Path) -> Vec<CorpusEntry> {
+    let text = std::fs::read_to_string(file).expect("can't read the corpus file");
+    let lines = text.lines().collect::<Vec<_>>();
+
+    let mut entries = vec![];
+    let mut i = 0;
+    while i < lines.len() {
+        if !is_delimiter(lines[i], '=') {
+            i += 1;
+            continue;
+        }
+
+        let name = lines[i + 1].trim().to_string();
+        i += 3;
+
+        let start = i;
+        while i < lines.len() && !is_delimiter(lines[i], '-') {
+            i += 1;
+        }
+        let input = lines[start..i].join("\n").trim().to_string() + "\n";
+
+        i += 1;
+        let start = i;
+        while i < lines.len() && !is_delimiter(lines[i], '=') {
+            i += 1;
+        }
+        let expected = normalize(&lines[start..i].join("\n"));
+
+        entries.push(CorpusEntry {
+            file: file.to_path_buf(),
+            name,
+            input,
+            expected,
+        });
+    }
+
+    entries
+}
+
+/// Normalizes the S-expression, removing the field names and collapsing the whitespace, as
+/// the corpus doesn't have the field names.
+fn normalize(sexp: &str) -> String {
+    let mut tokens = vec![];
+    let mut chars = sexp.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '(' | ')' => tokens.push(c.to_string()),
+            '"' => {
+                let mut token = String::from('"');
+                for c in chars.by_ref() {
+                    token.push(c);
+                    if c == '"' {
+                        break;
+                    }
+                }
+                tokens.push(token);
+            }
+            c if c.is_whitespace() => {}
+            c => {
+                let mut token = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    token.push(c);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+        }
+    }
+
+    let mut sexp = String::new();
+    for token in tokens.iter().filter(|token| !token.ends_with(':')) {
+        if !sexp.is_empty() && !sexp.ends_with('(') && token != ")" {
+            sexp.push(' ');
+        }
+        sexp.push_str(token);
+    }
+    sexp
+}
+
+#[test]
+fn corpus() {
+    let mut files = std::fs::read_dir(corpus_dir())
+        .expect("can't read the corpus directory")
+        .map(|entry| entry.expect("can't read the corpus entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect::<Vec<_>>();
+    files.sort();
+    assert!(!files.is_empty(), "the corpus is empty");
+
+    let mut parser = Parser::new();
+    parser
+        .set_language(tree_sitter_sol::language())
+        .expect("can't set the language");
+
+    let mut failures = vec![];
+    for entry in files.iter().flat_map(|file| read_entries(file)) {
+        let tree = parser.parse(&entry.input, None).expect("can't parse");
+        let root = tree.root_node();
+        let actual = normalize(&root.to_sexp());
+        let location = format!("{}: {}", entry.file.display(), entry.name);
+
+        if root.has_error() && !entry.expects_errors() {
+            failures.push(format!("{location}: unexpected errors:\n  {actual}"));
+        } else if !root.has_error() && entry.expects_errors() {
+            failures.push(format!("{location}: expected errors, got:\n  {actual}"));
+        } else if actual != entry.expected {
+            failures.push(format!(
+                "{location}: the trees are different:\n  expected: {}\n  actual:   {actual}",
+                entry.expected
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
@@ -0,0 +1,146 @@
+//! Defines the unfolding of the type aliases. The aliases are transparent, so the references
+//! to them are replaced by their values when elaborating. The values are glued to the names of
+//! the aliases, so the conversion checking sees the unfolded types, and the error messages
+//! print the names.
+//!
+//! The recursive aliases can't be unfolded, so they are reported and kept opaque.
+
+use std::collections::HashSet;
+
+use sol_diagnostic::{fail, report_error, Result};
+use sol_hir::{
+    source::{
+        pattern::Pattern,
+        top_level::{type_alias, TypeAlias},
+    },
+    walking::{HirListener, Walker},
+};
+use sol_thir::{
+    shared::{Constructor, ConstructorKind},
+    ElaboratedTerm,
+};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the type alias `{name}` is recursive")]
+#[diagnostic(
+    code(sol::thir::recursive_type_alias),
+    help("use an inductive type to define recursive types")
+)]
+pub struct RecursiveTypeAliasError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Collects the definitions referenced by the value of a type alias.
+struct AliasReferences<'db> {
+    db: &'db dyn ThirLoweringDb,
+    definitions: Vec<Definition>,
+}
+
+impl HirListener for AliasReferences<'_> {
+    fn visit_reference(&mut self, reference: Reference) {
+        self.definitions.push(reference.definition(self.db));
+    }
+}
+
+/// Checks if the type alias references itself, directly or through other aliases. The
+/// references are compared by their [`DefinitionId`], like the [`type_alias`] query does.
+pub fn is_recursive(db: &dyn ThirLoweringDb, alias: TypeAlias) -> bool {
+    let id = alias.name(db).id(db);
+    let mut visited = HashSet::new();
+    let mut stack = vec![alias];
+
+    while let Some(alias) = stack.pop() {
+        if !visited.insert(alias.name(db).id(db)) {
+            continue;
+        }
+
+        let mut references = AliasReferences {
+            db,
+            definitions: vec![],
+        };
+        alias.parameters(db).accept(db, &mut references);
+        alias.value(db).accept(db, &mut references);
+
+        for definition in references.definitions {
+            if definition.id(db) == id {
+                return true;
+            }
+
+            if let Some(alias) = type_alias(db, definition) {
+                stack.push(alias);
+            }
+        }
+    }
+
+    false
+}
+
+/// Unfolds the type alias into its value, glued to the reference. The parameters of the alias
+/// are elaborated into lambdas, and its type is a pi type over the parameters, ending in the
+/// universe.
+///
+/// If the alias is recursive, it's reported, and the reference is kept opaque.
+pub fn unfold(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    alias: TypeAlias,
+    reference: Reference,
+) -> Result<Option<ElaboratedTerm>> {
+    if is_recursive(db, alias) {
+        report_error(
+            db,
+            RecursiveTypeAliasError {
+                name: alias.name(db).name(db).to_string(db).unwrap_or_default(),
+                location: reference.location(db),
+            },
+        );
+
+        return Ok(None);
+    }
+
+    let mut body_ctx = ctx;
+    let mut binders = Vec::new();
+    for parameter in alias.parameters(db) {
+        let Pattern::Binding(binding) = parameter.binding(db) else {
+            return fail(infer::UnsupportedTermError {
+                location: parameter.location(db),
+            });
+        };
+        let parameter_type = parameter.parameter_type(db);
+        let domain = db.thir_check(body_ctx, *parameter_type.expr, Value::U)?;
+        let domain_value = db.thir_eval(body_ctx.locals(db), domain.clone())?;
+        let implicitness = if parameter.is_implicit(db) {
+            Implicitness::Implicit
+        } else {
+            Implicitness::Explicit
+        };
+
+        body_ctx = body_ctx.create_new_value(db, binding.name, domain_value);
+        binders.push((binding.name, implicitness, domain));
+    }
+
+    let value = alias.value(db);
+    let mut term = db.thir_check(body_ctx, *value.expr, Value::U)?;
+    let mut type_term = Term::U;
+    for (name, implicitness, domain) in binders.into_iter().rev() {
+        term = Term::Lam(name, implicitness, term.into());
+        type_term = Term::Pi(Some(name), implicitness, domain.into(), type_term.into());
+    }
+
+    let alias_type = db.thir_eval(ctx.locals(db), type_term)?;
+    let name = Constructor {
+        kind: ConstructorKind::Reference(reference),
+        location: reference.location(db),
+    };
+
+    Ok(Some(ElaboratedTerm(
+        Term::Glued(name, vec![], term.into()),
+        alias_type,
+    )))
+}
@@ -0,0 +1,236 @@
+//! Defines the elaboration of the arrays, and of their intrinsics, like in:
+//!
+//! ```sol
+//! xs : Array Int = [1, 2, 3]
+//!
+//! first : Int = Array.get xs 0
+//! ```
+//!
+//! The arrays are homogeneous, so their items are checked against the type of the items of
+//! the expected type, or the type of the first item, if there's no expected type. The
+//! intrinsics, like `Array.length` and `Array.get`, are elaborated saturated into
+//! [`Term::Intrinsic`], and they're evaluated when their arrays are known, otherwise they're
+//! stuck.
+
+use sol_diagnostic::{fail, Result};
+use sol_hir::{
+    primitives::{Intrinsic, PrimitiveProvider},
+    source::expr::{CallExpr, Callee},
+};
+use sol_thir::{
+    meta::MetaKind,
+    shared::{Constructor, ConstructorKind},
+    ElaboratedTerm,
+};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the array type expects 1 argument, got {arity}")]
+#[diagnostic(code(sol::thir::array_arity))]
+pub struct ArrayArityError {
+    pub arity: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("`{name}` expects {expected} arguments, got {arity}")]
+#[diagnostic(
+    code(sol::thir::intrinsic_arity),
+    help("the intrinsics can't be partially applied")
+)]
+pub struct IntrinsicArityError {
+    pub name: &'static str,
+    pub expected: usize,
+    pub arity: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("index out of bounds: the length is {length} but the index is {index}")]
+#[diagnostic(code(sol::thir::index_out_of_bounds))]
+pub struct IndexOutOfBoundsError {
+    pub index: isize,
+    pub length: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// The type of the lengths, and of the indices of the arrays, that is the default integer type.
+fn int_type(location: Location) -> Type {
+    Value::Constructor(Constructor {
+        kind: ConstructorKind::IntType(true, 32),
+        location,
+    })
+}
+
+/// Gets the intrinsic that the callee refers to, if it's a reference to an intrinsic, like
+/// `Array.length`.
+pub fn intrinsic(db: &dyn ThirLoweringDb, callee: &Callee) -> Option<Intrinsic> {
+    match callee {
+        Callee::Reference(reference) => db.primitives().intrinsic(reference.definition(db)),
+        Callee::Expr(callee) => match **callee {
+            Expr::Path(reference) => db.primitives().intrinsic(reference.definition(db)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Infers the type of the array type applied to the type of its items, like `Array Int`.
+pub fn infer_array_type(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let arity = call.arguments.len();
+    let Ok([item_type]) = <[Expr; 1]>::try_from(call.arguments) else {
+        return fail(ArrayArityError {
+            arity,
+            location: call.location(db),
+        });
+    };
+
+    let item_type = db.thir_check(ctx, item_type, Value::U)?;
+
+    Ok(ElaboratedTerm(Term::ArrayType(item_type.into()), Value::U))
+}
+
+/// Infers the type of the array literal, like `[1, 2, 3]`, from the type of its first item.
+/// The type of the items of the empty arrays is a fresh meta variable.
+pub fn infer_array(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let mut items = call.arguments.into_iter();
+    let Some(first) = items.next() else {
+        let meta = ctx
+            .metas(db)
+            .fresh(db, MetaKind::Implicit, call.location(db));
+        let item_type = Value::Flexible(meta, vec![]);
+
+        return Ok(ElaboratedTerm(
+            Term::Array(vec![]),
+            Value::ArrayType(item_type.into()),
+        ));
+    };
+
+    let ElaboratedTerm(first, item_type) = db.thir_infer(ctx, first)?;
+    let items = std::iter::once(Ok(first))
+        .chain(items.map(|item| db.thir_check(ctx, item, item_type.clone())))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ElaboratedTerm(
+        Term::Array(items),
+        Value::ArrayType(item_type.into()),
+    ))
+}
+
+/// CASE: array
+///
+/// Checks the items of the array literal against the type of the items of the expected type.
+pub fn check_array(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+    item_type: Type,
+) -> Result<Term> {
+    let items = call
+        .arguments
+        .into_iter()
+        .map(|item| db.thir_check(ctx, item, item_type.clone()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Term::Array(items))
+}
+
+/// Infers the type of the saturated application of the intrinsic, like `Array.get xs 0`. The
+/// type of the items of the array is a fresh meta variable, that is solved by checking the
+/// array against it.
+pub fn infer_intrinsic(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let intrinsic = intrinsic(db, &call.callee).expect("the callee should be an intrinsic");
+    let location = call.location(db);
+    if call.arguments.len() != intrinsic.arity() {
+        return fail(IntrinsicArityError {
+            name: intrinsic.name(),
+            expected: intrinsic.arity(),
+            arity: call.arguments.len(),
+            location,
+        });
+    }
+
+    let meta = ctx
+        .metas(db)
+        .fresh(db, MetaKind::Implicit, location.clone());
+    let item_type = Value::Flexible(meta, vec![]);
+    let array_type = Value::ArrayType(item_type.clone().into());
+
+    let mut arguments = call.arguments.into_iter();
+    let array = db.thir_check(ctx, arguments.next().unwrap_or(Expr::Empty), array_type)?;
+    let (arguments, type_repr) = match intrinsic {
+        Intrinsic::ArrayLength => (vec![array], int_type(location.clone())),
+        Intrinsic::ArrayGet => {
+            let index = arguments.next().unwrap_or(Expr::Empty);
+            let index = db.thir_check(ctx, index, int_type(location.clone()))?;
+
+            (vec![array, index], item_type)
+        }
+    };
+
+    Ok(ElaboratedTerm(
+        Term::Intrinsic(intrinsic, arguments, location),
+        type_repr,
+    ))
+}
+
+/// Applies the intrinsic to the evaluated arguments, if the array is known, otherwise the
+/// application is stuck. The indices out of the bounds of the array fail the evaluation.
+pub fn apply_intrinsic(
+    db: &dyn ThirLoweringDb,
+    intrinsic: Intrinsic,
+    arguments: Vec<Value>,
+    location: Location,
+) -> Result<Value> {
+    let forced = arguments
+        .iter()
+        .map(|argument| Ok(argument.clone().force(db)?.1))
+        .collect::<Result<Vec<_>>>()?;
+
+    match (intrinsic, forced.as_slice()) {
+        (Intrinsic::ArrayLength, [Value::Array(items)]) => Ok(Value::Constructor(Constructor {
+            kind: ConstructorKind::Int(items.len() as isize),
+            location,
+        })),
+        (
+            Intrinsic::ArrayGet,
+            [Value::Array(items), Value::Constructor(Constructor {
+                kind: ConstructorKind::Int(index),
+                ..
+            })],
+        ) => match usize::try_from(*index)
+            .ok()
+            .and_then(|index| items.get(index))
+        {
+            Some(item) => Ok(item.clone()),
+            None => fail(IndexOutOfBoundsError {
+                index: *index,
+                length: items.len(),
+                location,
+            }),
+        },
+        _ => Ok(Value::Intrinsic(intrinsic, arguments, location)),
+    }
+}
@@ -0,0 +1,67 @@
+//! Defines the elaboration of the panics, that abort the program with a message, like the
+//! `panic`, `todo` and `unreachable` expressions, and the failed assertions in:
+//!
+//! ```sol
+//! main = do {
+//!   assert x > 0
+//! }
+//! ```
+//!
+//! The assertions are lowered into `if x > 0 then () else panic "..."`, so the panics are
+//! elaborated into [`Term::Panic`], that has any type, as it never returns. They only abort the
+//! program at runtime, so the evaluation at compile time fails with their messages, that holds
+//! where they're located.
+
+use sol_diagnostic::Result;
+use sol_hir::source::{expr::CallExpr, literal::Literal};
+use sol_thir::{meta::MetaKind, ElaboratedTerm};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("{message}")]
+#[diagnostic(
+    code(sol::thir::panic),
+    help("the panics abort the program, so they can't be evaluated at compile time")
+)]
+pub struct PanicError {
+    pub message: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Gets the message of the panic, that is its string literal argument.
+fn message(call: &CallExpr) -> String {
+    match call.arguments.first() {
+        Some(Expr::Literal(literal)) => match &literal.value {
+            Literal::String(message) => message.clone(),
+            _ => "explicit panic".into(),
+        },
+        _ => "explicit panic".into(),
+    }
+}
+
+/// Infers the type of `panic "message"`, that is unknown, as it never returns.
+pub fn infer_panic(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let meta = ctx
+        .metas(db)
+        .fresh(db, MetaKind::Implicit, call.location(db));
+
+    Ok(ElaboratedTerm(
+        Term::Panic(message(&call), call.location(db)),
+        Value::Flexible(meta, vec![]),
+    ))
+}
+
+/// CASE: panic
+///
+/// Checks `panic "message"` against any type, as it never returns.
+pub fn check_panic(db: &dyn ThirLoweringDb, call: CallExpr) -> Result<Term> {
+    Ok(Term::Panic(message(&call), call.location(db)))
+}
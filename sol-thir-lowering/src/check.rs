@@ -1,4 +1,11 @@
-use sol_thir::ElaboratedTerm;
+use num_bigint::BigInt;
+use sol_hir::source::{expr::Callee, literal::Literal, Spanned};
+use sol_thir::{
+    meta::{HoleGoal, HoleGoals, MetaKind},
+    shared::{Constructor, ConstructorKind},
+    source::is_tuple,
+    ElaboratedTerm,
+};
 use Implicitness::*;
 
 use super::*;
@@ -56,8 +63,94 @@ fn implicit_fun_eta(
     Ok(Term::Lam(pi.name.unwrap(), Implicit, elab_term.into()))
 }
 
-fn type_hole() -> sol_diagnostic::Result<Term> {
-    Ok(Term::InsertedMeta(MetaVar::new(None)))
+fn is_int_literal(literal: &Literal) -> bool {
+    !matches!(
+        literal,
+        Literal::Empty | Literal::String(_) | Literal::Boolean(_) | Literal::Nat(_)
+    )
+}
+
+fn is_big_literal(literal: &Literal) -> bool {
+    is_int_literal(literal) || matches!(literal, Literal::Nat(_))
+}
+
+/// Checks if the checking is guided by the shape of the type, like the function types, so the
+/// glued types are unfolded to check against them. Otherwise, they are kept glued, to print
+/// their names if they don't unify.
+fn is_shape_guided(type_repr: &Type) -> bool {
+    match type_repr {
+        Type::Glued(_, _, type_repr) => is_shape_guided(type_repr),
+        Type::Pi(_) | Type::ArrayType(_) => true,
+        Type::Constructor(Constructor {
+            kind:
+                ConstructorKind::IntType(..) | ConstructorKind::NatType | ConstructorKind::IntegerType,
+            ..
+        }) => true,
+        _ => false,
+    }
+}
+
+fn type_hole(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    location: Location,
+    goal: Type,
+) -> sol_diagnostic::Result<Term> {
+    // The goal is recorded, so the terms that fill the hole can be searched.
+    HoleGoals::push(
+        db,
+        HoleGoal {
+            location: location.clone(),
+            ctx,
+            goal,
+        },
+    );
+
+    let meta = ctx.metas(db).fresh(db, MetaKind::Hole, location);
+    Ok(Term::InsertedMeta(meta))
+}
+
+/// CASE: int-literal
+///
+/// If the expected type is an integer type, the literal is checked to fit into its width,
+/// instead of being inferred as the default integer type.
+fn int_literal(
+    db: &dyn ThirLoweringDb,
+    literal: Spanned<Literal>,
+    signed: bool,
+    width: isize,
+) -> sol_diagnostic::Result<Term> {
+    let location = literal.location(db);
+    let kind = ConstructorKind::from(literal.value);
+    if let ConstructorKind::Int(value) = kind {
+        folding::check_int_width(db, value, signed, width, location.clone());
+    }
+
+    Ok(Term::Constructor(Constructor { kind, location }))
+}
+
+/// CASE: big-literal
+///
+/// If the expected type is `Nat` or `Integer`, the literal is elaborated as an
+/// arbitrary-precision integer, so it can be of any size.
+fn big_literal(
+    db: &dyn ThirLoweringDb,
+    literal: Spanned<Literal>,
+    integer: bool,
+) -> sol_diagnostic::Result<Term> {
+    let location = literal.location(db);
+    let value = match ConstructorKind::from(literal.value) {
+        ConstructorKind::Nat(value) => BigInt::from(value),
+        ConstructorKind::Int(value) => BigInt::from(value),
+        kind => return Ok(Term::Constructor(Constructor { kind, location })),
+    };
+    let kind = match integer {
+        true => ConstructorKind::Integer(value),
+        // The literals are never negative, so they're always natural numbers.
+        false => ConstructorKind::Nat(value.magnitude().clone()),
+    };
+
+    Ok(Term::Constructor(Constructor { kind, location }))
 }
 
 /// CASE: term_equality
@@ -77,10 +170,26 @@ fn term_equality(
 #[salsa::tracked]
 #[rustfmt::skip]
 pub fn thir_check(db: &dyn ThirLoweringDb, ctx: Context, expr: Expr, type_repr: Type) -> sol_diagnostic::Result<Term> {
-    match (expr, type_repr) {
+    // The proofs of the propositions are irrelevant, so they are marked to be erased.
+    let proposition = relevance::is_proposition(db, ctx, type_repr.clone());
+    let term = match (expr, type_repr) {
         (Expr::Lam(abs), Type::Pi(pi)) => lam_pi(db, ctx, new_curried_function(db, abs), pi.clone(), pi.implicitness),
         (value, Type::Pi(pi @ Pi { implicitness: Implicit, .. })) => implicit_fun_eta(db, ctx, value, pi),
-        (Expr::Hole(_), _) => type_hole(),
+        (value, Type::Glued(_, _, box type_repr)) if is_shape_guided(&type_repr) => db.thir_check(ctx, value, type_repr),
+        (Expr::Hole(span), expected) => type_hole(db, ctx, span.resolve(db), expected),
+        (Expr::Match(expr), expected) => matching::check_match(db, ctx, expr, expected),
+        (Expr::Call(call), expected) if call.callee == Callee::Refl => equality::check_refl(db, ctx, call.location(db), expected),
+        (Expr::Call(call), expected) if call.callee == Callee::Rewrite => equality::check_rewrite(db, ctx, call, expected),
+        (Expr::Call(call), expected) if call.callee == Callee::Splice => staging::check_splice(db, ctx, call, expected),
+        (Expr::Call(call), _) if matches!(call.callee, Callee::Panic | Callee::Todo) => assertions::check_panic(db, call),
+        (Expr::Call(call), Type::ArrayType(box item_type)) if call.callee == Callee::Array => array::check_array(db, ctx, call, item_type),
+        (Expr::Call(call), Type::U) if call.callee == Callee::Tuple => tuple::check_tuple_type(db, ctx, call),
+        (Expr::Call(call), Type::RecordType(element_types)) if call.callee == Callee::Tuple && call.arguments.len() == element_types.len() && is_tuple(&element_types) => tuple::check_tuple(db, ctx, call, element_types),
+        (Expr::Literal(literal), Type::Constructor(Constructor { kind: ConstructorKind::IntType(signed, width), .. })) if is_int_literal(&literal.value) => int_literal(db, literal, signed, width),
+        (Expr::Literal(literal), Type::Constructor(Constructor { kind: ConstructorKind::NatType, .. })) if is_big_literal(&literal.value) => big_literal(db, literal, false),
+        (Expr::Literal(literal), Type::Constructor(Constructor { kind: ConstructorKind::IntegerType, .. })) if is_big_literal(&literal.value) => big_literal(db, literal, true),
         (value, expected) => term_equality(db, ctx, value, expected),
-    }
+    }?;
+
+    Ok(relevance::mark(term, proposition))
 }
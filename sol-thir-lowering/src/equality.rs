@@ -0,0 +1,271 @@
+//! Defines the elaboration of the propositional equality type, like `Eq x y`, that is
+//! inhabited by `refl` when both sides are the same, like in:
+//!
+//! ```hs
+//! plus_zero : Eq (plus Zero n) n
+//! plus_zero = refl
+//! ```
+//!
+//! The `rewrite eq in value` expressions transport the goal along the equality `eq : Eq x y`,
+//! so the value is checked against the goal with the occurrences of `x` replaced by `y`. They
+//! are elaborated into [`Term::Transport`], that is the J eliminator of the equality type.
+
+use sol_diagnostic::{fail, Result};
+use sol_hir::source::expr::CallExpr;
+use sol_thir::{display, meta::MetaKind, ElaboratedTerm};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the equality type expects 2 arguments, got {arity}")]
+#[diagnostic(code(sol::thir::equality_arity))]
+pub struct EqualityArityError {
+    pub arity: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("expected an equality, got the type: `{actual}`")]
+#[diagnostic(
+    code(sol::thir::not_an_equality),
+    help("the rewrite expressions only transports along values of the `Eq` type")
+)]
+pub struct NotAnEqualityError {
+    pub actual: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Infers the type of the equality type applied to its sides, like `Eq x y`. The type of the
+/// sides is inferred from the left-hand side.
+pub fn infer_equality_type(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let arity = call.arguments.len();
+    let Ok([lhs, rhs]) = <[Expr; 2]>::try_from(call.arguments) else {
+        return fail(EqualityArityError {
+            arity,
+            location: call.location(db),
+        });
+    };
+
+    let ElaboratedTerm(lhs, type_repr) = db.thir_infer(ctx, lhs)?;
+    let rhs = db.thir_check(ctx, rhs, type_repr.clone())?;
+    let type_repr = db.thir_quote(ctx.lvl(db), type_repr)?;
+
+    Ok(ElaboratedTerm(
+        Term::Eq(type_repr.into(), lhs.into(), rhs.into()),
+        Value::U,
+    ))
+}
+
+/// Infers the type of `refl`, without an expected type, the sides are the same unknown value.
+pub fn infer_refl(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    location: Location,
+) -> Result<ElaboratedTerm> {
+    let fresh = || {
+        let meta = ctx
+            .metas(db)
+            .fresh(db, MetaKind::Implicit, location.clone());
+        Value::Flexible(meta, vec![])
+    };
+    let type_repr = fresh();
+    let value = fresh();
+
+    Ok(ElaboratedTerm(
+        Term::Refl,
+        Value::Eq(type_repr.into(), value.clone().into(), value.into()),
+    ))
+}
+
+/// CASE: refl
+///
+/// Checks `refl` against the equality type, by unifying its sides.
+pub fn check_refl(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    location: Location,
+    expected: Type,
+) -> Result<Term> {
+    match expected.force(db)?.1 {
+        Value::Eq(_, box lhs, box rhs) => {
+            elaboration::unify_catch(db, ctx, lhs, rhs);
+
+            Ok(Term::Refl)
+        }
+        actual => fail(NotAnEqualityError {
+            actual: display::show_value(db, ctx, actual)?,
+            location,
+        }),
+    }
+}
+
+/// CASE: rewrite
+///
+/// Checks the value of `rewrite eq in value` against the goal with the left-hand side of the
+/// equality replaced by the right-hand side, and transports it back to the goal.
+pub fn check_rewrite(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+    expected: Type,
+) -> Result<Term> {
+    let (equality, lhs, rhs, value) = infer_rewrite_equality(db, ctx, call)?;
+    let goal = rewrite_goal(db, ctx, expected, lhs, rhs)?;
+    let value = db.thir_check(ctx, value, goal)?;
+
+    Ok(Term::Transport(equality.into(), value.into()))
+}
+
+/// Infers the type of `rewrite eq in value`, by replacing the right-hand side of the equality
+/// by the left-hand side in the type of the value.
+pub fn infer_rewrite(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let (equality, lhs, rhs, value) = infer_rewrite_equality(db, ctx, call)?;
+    let ElaboratedTerm(value, value_type) = db.thir_infer(ctx, value)?;
+    let type_repr = rewrite_goal(db, ctx, value_type, rhs, lhs)?;
+
+    Ok(ElaboratedTerm(
+        Term::Transport(equality.into(), value.into()),
+        type_repr,
+    ))
+}
+
+/// Infers the equality of the rewrite expression, and splits it into its sides.
+fn infer_rewrite_equality(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<(Term, Value, Value, Expr)> {
+    let Ok([equality, value]) = <[Expr; 2]>::try_from(call.arguments) else {
+        unreachable!("rewrite expressions have an equality and a value")
+    };
+
+    let location = equality.location(db);
+    let ElaboratedTerm(equality, equality_type) = db.thir_infer(ctx, equality)?;
+    match equality_type.force(db)?.1 {
+        Value::Eq(_, box lhs, box rhs) => Ok((equality, lhs, rhs, value)),
+        actual => fail(NotAnEqualityError {
+            actual: display::show_value(db, ctx, actual)?,
+            location,
+        }),
+    }
+}
+
+/// Replaces the occurrences of `from` by `to` in the goal. The occurrences under binders
+/// aren't replaced, as the sides would need to be shifted into the scope of the binders.
+fn rewrite_goal(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    goal: Type,
+    from: Value,
+    to: Value,
+) -> Result<Type> {
+    let goal = db.thir_quote(ctx.lvl(db), goal)?;
+    let from = db.thir_quote(ctx.lvl(db), from)?;
+    let to = db.thir_quote(ctx.lvl(db), to)?;
+
+    db.thir_eval(ctx.locals(db), replace(db, goal, &from, &to))
+}
+
+fn replace(db: &dyn ThirLoweringDb, term: Term, from: &Term, to: &Term) -> Term {
+    if same(db, &term, from) {
+        return to.clone();
+    }
+
+    let go = |term: Box<Term>| Box::new(replace(db, *term, from, to));
+    match term {
+        // The names of the glued terms would be stale after the replacement, so they are
+        // replaced in the unfolded terms only.
+        Term::Glued(_, _, term) => replace(db, *term, from, to),
+        Term::Location(location, term) => Term::Location(location, go(term)),
+        Term::App(callee, argument) => Term::App(go(callee), go(argument)),
+        Term::Pi(name, implicitness, domain, codomain) => {
+            Term::Pi(name, implicitness, go(domain), codomain)
+        }
+        Term::Ann(value, type_repr) => Term::Ann(go(value), go(type_repr)),
+        Term::Project(record, name, position) => Term::Project(go(record), name, position),
+        Term::Eq(type_repr, lhs, rhs) => Term::Eq(go(type_repr), go(lhs), go(rhs)),
+        Term::Transport(equality, value) => Term::Transport(go(equality), go(value)),
+        Term::ArrayType(type_repr) => Term::ArrayType(go(type_repr)),
+        Term::Array(items) => Term::Array(
+            items
+                .into_iter()
+                .map(|item| replace(db, item, from, to))
+                .collect(),
+        ),
+        Term::Intrinsic(intrinsic, arguments, location) => Term::Intrinsic(
+            intrinsic,
+            arguments
+                .into_iter()
+                .map(|argument| replace(db, argument, from, to))
+                .collect(),
+            location,
+        ),
+        Term::Record(fields) => Term::Record(
+            fields
+                .into_iter()
+                .map(|(name, term)| (name, replace(db, term, from, to)))
+                .collect(),
+        ),
+        Term::RecordType(fields) => Term::RecordType(
+            fields
+                .into_iter()
+                .map(|(name, term)| (name, replace(db, term, from, to)))
+                .collect(),
+        ),
+        term => term,
+    }
+}
+
+/// Checks if the terms are the same, ignoring their locations and glued names, so the terms
+/// quoted from different places can be compared. The references are compared by their definitions.
+fn same(db: &dyn ThirLoweringDb, lhs: &Term, rhs: &Term) -> bool {
+    use sol_thir::shared::ConstructorKind;
+
+    match (lhs, rhs) {
+        (Term::Location(_, lhs), rhs) => same(db, lhs, rhs),
+        (lhs, Term::Location(_, rhs)) => same(db, lhs, rhs),
+        (Term::Glued(_, _, lhs), rhs) => same(db, lhs, rhs),
+        (lhs, Term::Glued(_, _, rhs)) => same(db, lhs, rhs),
+        (Term::U, Term::U) | (Term::Refl, Term::Refl) => true,
+        (Term::Var(lhs, _), Term::Var(rhs, _)) => lhs == rhs,
+        (Term::InsertedMeta(lhs), Term::InsertedMeta(rhs)) => lhs == rhs,
+        (Term::Constructor(lhs), Term::Constructor(rhs)) => match (&lhs.kind, &rhs.kind) {
+            (ConstructorKind::Reference(lhs), ConstructorKind::Reference(rhs)) => {
+                lhs.definition(db) == rhs.definition(db)
+            }
+            (lhs, rhs) => lhs == rhs,
+        },
+        (Term::App(f, a), Term::App(g, b)) => same(db, f, g) && same(db, a, b),
+        (Term::Project(lhs, _, i), Term::Project(rhs, _, j)) => i == j && same(db, lhs, rhs),
+        (Term::Eq(a, x, y), Term::Eq(b, z, w)) => {
+            same(db, a, b) && same(db, x, z) && same(db, y, w)
+        }
+        (Term::Transport(eq_a, a), Term::Transport(eq_b, b)) => {
+            same(db, eq_a, eq_b) && same(db, a, b)
+        }
+        (Term::ArrayType(lhs), Term::ArrayType(rhs)) => same(db, lhs, rhs),
+        (Term::Array(lhs), Term::Array(rhs)) => {
+            lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(lhs, rhs)| same(db, lhs, rhs))
+        }
+        (Term::Intrinsic(f, lhs, _), Term::Intrinsic(g, rhs, _)) => {
+            f == g
+                && lhs.len() == rhs.len()
+                && lhs.iter().zip(rhs).all(|(lhs, rhs)| same(db, lhs, rhs))
+        }
+        _ => false,
+    }
+}
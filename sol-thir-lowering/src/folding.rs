@@ -0,0 +1,386 @@
+//! Defines the constant folding of closed arithmetic over literals. It's used by the
+//! [`thir_eval`] function to normalise terms like `1 + 2` into `3`, without needing the
+//! arithmetic functions to be defined in the prelude.
+//!
+//! Only the built-in operators, see [`Operator`], are folded. The operators defined by the
+//! packages shadow them, and they're evaluated as any other function.
+//!
+//! The overflow of the operators follows the [`OverflowMode`] of the profile, so `+` traps in
+//! the `dev` profile and wraps in the `release` profile, like the backends. The explicit
+//! operations, like `wrapping_add` and `checked_add`, always wrap or trap.
+//!
+//! The arbitrary-precision integers, of the `Nat` and `Integer` types, are folded with the big
+//! integer arithmetic, so they never overflow, and the subtraction of the natural numbers
+//! saturates at zero.
+
+use num_bigint::{BigInt, BigUint};
+use sol_diagnostic::{fail, report_error};
+use sol_hir::{
+    package::{HasManifest, OverflowMode},
+    primitives::{Operator, PrimitiveProvider},
+};
+use sol_thir::shared::{Constructor, ConstructorKind};
+
+use super::*;
+
+/// Integer literal that does not fit the annotated integer type.
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("literal `{value}` out of range for `{type_name}`")]
+#[diagnostic(
+    code(sol::thir::integer_overflow),
+    help("the literal `{value}` does not fit into the type `{type_name}`, whose range is `{min}..={max}`")
+)]
+pub struct IntegerOverflowError {
+    pub value: isize,
+    pub type_name: String,
+    pub min: i128,
+    pub max: i128,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Integer type whose width can't be represented by the compile-time arithmetic.
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("unsupported integer width `{width}`")]
+#[diagnostic(
+    code(sol::thir::integer_width),
+    help("the widths of the integer types should be between 1 and 127 bits")
+)]
+pub struct IntegerWidthError {
+    pub width: isize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Arithmetic operation that overflowed in the checked mode, it's the trap of the evaluator.
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("attempt to compute `{lhs} {op} {rhs}`, which would overflow")]
+#[diagnostic(
+    code(sol::thir::arithmetic_overflow),
+    help("use the wrapping operations, like `wrapping_add`, if the overflow is intended")
+)]
+pub struct ArithmeticOverflowError {
+    pub lhs: isize,
+    pub rhs: isize,
+    pub op: &'static str,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Arithmetic operators that can be folded at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl BinaryOp {
+    /// Gets the arithmetic of the built-in operator, and its overflow mode, if it's an explicit
+    /// operation, like `wrapping_add`.
+    fn from_operator(operator: Operator) -> (Self, Option<OverflowMode>) {
+        match operator {
+            Operator::Add => (Self::Add, None),
+            Operator::Sub => (Self::Sub, None),
+            Operator::Mul => (Self::Mul, None),
+            Operator::Div => (Self::Div, None),
+            Operator::Rem => (Self::Rem, None),
+            Operator::WrappingAdd => (Self::Add, Some(OverflowMode::Wrapping)),
+            Operator::WrappingSub => (Self::Sub, Some(OverflowMode::Wrapping)),
+            Operator::WrappingMul => (Self::Mul, Some(OverflowMode::Wrapping)),
+            Operator::CheckedAdd => (Self::Add, Some(OverflowMode::Checked)),
+            Operator::CheckedSub => (Self::Sub, Some(OverflowMode::Checked)),
+            Operator::CheckedMul => (Self::Mul, Some(OverflowMode::Checked)),
+        }
+    }
+
+    /// Gets the symbol of the operator, to report its overflow.
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+        }
+    }
+
+    /// Applies the operator, returning [`None`] if the operation overflows, or if it's a
+    /// division by zero, so the term is kept symbolically.
+    fn apply(self, lhs: isize, rhs: isize) -> Option<isize> {
+        match self {
+            Self::Add => lhs.checked_add(rhs),
+            Self::Sub => lhs.checked_sub(rhs),
+            Self::Mul => lhs.checked_mul(rhs),
+            Self::Div => lhs.checked_div(rhs),
+            Self::Rem => lhs.checked_rem(rhs),
+        }
+    }
+
+    /// Applies the operator wrapping around the bounds of the integers, returning [`None`]
+    /// only if it's a division by zero.
+    fn apply_wrapping(self, lhs: isize, rhs: isize) -> Option<isize> {
+        match self {
+            Self::Add => Some(lhs.wrapping_add(rhs)),
+            Self::Sub => Some(lhs.wrapping_sub(rhs)),
+            Self::Mul => Some(lhs.wrapping_mul(rhs)),
+            Self::Div if rhs != 0 => Some(lhs.wrapping_div(rhs)),
+            Self::Rem if rhs != 0 => Some(lhs.wrapping_rem(rhs)),
+            Self::Div | Self::Rem => None,
+        }
+    }
+
+    /// Applies the operator to arbitrary-precision integers, returning [`None`] only if it's a
+    /// division by zero.
+    fn apply_integer(self, lhs: BigInt, rhs: BigInt) -> Option<BigInt> {
+        match self {
+            Self::Add => Some(lhs + rhs),
+            Self::Sub => Some(lhs - rhs),
+            Self::Mul => Some(lhs * rhs),
+            Self::Div if rhs != BigInt::default() => Some(lhs / rhs),
+            Self::Rem if rhs != BigInt::default() => Some(lhs % rhs),
+            Self::Div | Self::Rem => None,
+        }
+    }
+
+    /// Applies the operator to natural numbers, the subtraction saturates at zero, and it
+    /// returns [`None`] only if it's a division by zero.
+    fn apply_nat(self, lhs: BigUint, rhs: BigUint) -> Option<BigUint> {
+        match self {
+            Self::Sub if lhs < rhs => Some(BigUint::default()),
+            Self::Sub => Some(lhs - rhs),
+            _ => self
+                .apply_integer(lhs.into(), rhs.into())
+                .map(|value| value.magnitude().clone()),
+        }
+    }
+}
+
+/// Removes the location wrappers of a term, to match against its shape.
+pub fn unlocated(term: &Term) -> &Term {
+    match term {
+        Term::Location(_, term) => unlocated(term),
+        _ => term,
+    }
+}
+
+/// Removes the location wrappers, and the glued names of a value, to match against its shape.
+pub fn unlocated_value(value: Value) -> Value {
+    match value {
+        Value::Location(_, value) | Value::Glued(_, _, value) => unlocated_value(*value),
+        _ => value,
+    }
+}
+
+/// Evaluates a term to a constant, if it's closed.
+fn eval_constant(
+    db: &dyn ThirLoweringDb,
+    env: Env,
+    term: Term,
+) -> sol_diagnostic::Result<Option<ConstructorKind>> {
+    match unlocated_value(db.thir_eval(env, term)?) {
+        Value::Constructor(Constructor { kind, .. }) => Ok(Some(kind)),
+        _ => Ok(None),
+    }
+}
+
+/// Folds the application of a built-in arithmetic operator to two integer constants, like
+/// `App(App(+, 1), 2)`, into a single integer constant.
+///
+/// It returns [`None`] if the application can't be folded, so the evaluation proceeds as usual.
+pub fn fold_constant(
+    db: &dyn ThirLoweringDb,
+    env: Env,
+    callee: &Term,
+    argument: &Term,
+) -> sol_diagnostic::Result<Option<Value>> {
+    let Term::App(operator, lhs) = unlocated(callee) else {
+        return Ok(None);
+    };
+    let Term::Constructor(Constructor {
+        kind: ConstructorKind::Reference(reference),
+        location,
+    }) = unlocated(operator)
+    else {
+        return Ok(None);
+    };
+    // Only the built-in operators are folded, the operators defined by the packages are
+    // evaluated as usual, even if they have the same names.
+    let Some(operator) = db.primitives().operator(reference.definition(db)) else {
+        return Ok(None);
+    };
+    let (op, mode) = BinaryOp::from_operator(operator);
+    let Some(lhs) = eval_constant(db, env, *lhs.clone())? else {
+        return Ok(None);
+    };
+    let Some(rhs) = eval_constant(db, env, argument.clone())? else {
+        return Ok(None);
+    };
+
+    let kind = match (lhs, rhs) {
+        (ConstructorKind::Int(lhs), ConstructorKind::Int(rhs)) => {
+            fold_int(db, op, mode, lhs, rhs, location)?.map(ConstructorKind::Int)
+        }
+        (ConstructorKind::Nat(lhs), ConstructorKind::Nat(rhs)) => {
+            op.apply_nat(lhs, rhs).map(ConstructorKind::Nat)
+        }
+        (ConstructorKind::Integer(lhs), ConstructorKind::Integer(rhs)) => {
+            op.apply_integer(lhs, rhs).map(ConstructorKind::Integer)
+        }
+        _ => None,
+    };
+
+    Ok(kind.map(|kind| {
+        Value::Constructor(Constructor {
+            kind,
+            location: location.clone(),
+        })
+    }))
+}
+
+/// Folds the fixed-width integer arithmetic, with the overflow mode of the profile, if it
+/// isn't an explicit operation.
+fn fold_int(
+    db: &dyn ThirLoweringDb,
+    op: BinaryOp,
+    mode: Option<OverflowMode>,
+    lhs: isize,
+    rhs: isize,
+    location: &Location,
+) -> sol_diagnostic::Result<Option<isize>> {
+    let mode = mode.unwrap_or_else(|| db.profile().settings(db).overflow);
+    let value = match mode {
+        OverflowMode::Wrapping => op.apply_wrapping(lhs, rhs),
+        // The division by zero isn't an overflow, so it's kept symbolically.
+        OverflowMode::Checked if rhs == 0 && matches!(op, BinaryOp::Div | BinaryOp::Rem) => None,
+        OverflowMode::Checked => match op.apply(lhs, rhs) {
+            Some(value) => Some(value),
+            None => {
+                return fail(ArithmeticOverflowError {
+                    lhs,
+                    rhs,
+                    op: op.symbol(),
+                    location: location.clone(),
+                })
+            }
+        },
+    };
+
+    Ok(value)
+}
+
+/// Checks that an integer literal fits into the integer type of the given signedness and
+/// width, reporting an [`IntegerOverflowError`] otherwise. The widths out of the range of the
+/// compile-time arithmetic are reported as an [`IntegerWidthError`] instead.
+pub fn check_int_width(
+    db: &dyn ThirLoweringDb,
+    value: isize,
+    signed: bool,
+    width: isize,
+    location: Location,
+) {
+    let Some((min, max)) = int_range(signed, width) else {
+        return report_error(db, IntegerWidthError { width, location });
+    };
+
+    if !(min..=max).contains(&(value as i128)) {
+        let type_name = format!("{}{width}", if signed { "Int" } else { "UInt" });
+
+        report_error(db, IntegerOverflowError {
+            value,
+            type_name,
+            min,
+            max,
+            location,
+        });
+    }
+}
+
+/// Gets the bounds of the integer type of the given signedness and width, or [`None`] if the
+/// width is out of the range of the compile-time arithmetic.
+fn int_range(signed: bool, width: isize) -> Option<(i128, i128)> {
+    let width = u32::try_from(width)
+        .ok()
+        .filter(|width| (1..=127).contains(width))?;
+
+    Some(if signed {
+        (-(1i128 << (width - 1)), (1i128 << (width - 1)) - 1)
+    } else {
+        (0, (1i128 << width) - 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(BinaryOp::Add.apply(1, 2), Some(3));
+        assert_eq!(BinaryOp::Add.apply(isize::MAX, 1), None);
+        assert_eq!(BinaryOp::Mul.apply(isize::MIN, -1), None);
+        assert_eq!(BinaryOp::Div.apply(1, 0), None);
+    }
+
+    #[test]
+    fn test_wrapping_arithmetic() {
+        assert_eq!(
+            BinaryOp::Add.apply_wrapping(isize::MAX, 1),
+            Some(isize::MIN)
+        );
+        assert_eq!(
+            BinaryOp::Sub.apply_wrapping(isize::MIN, 1),
+            Some(isize::MAX)
+        );
+        assert_eq!(BinaryOp::Rem.apply_wrapping(1, 0), None);
+    }
+
+    #[test]
+    fn test_explicit_operations() {
+        let (op, mode) = BinaryOp::from_operator(Operator::WrappingAdd);
+        assert_eq!((op, mode), (BinaryOp::Add, Some(OverflowMode::Wrapping)));
+
+        let (op, mode) = BinaryOp::from_operator(Operator::CheckedMul);
+        assert_eq!((op, mode), (BinaryOp::Mul, Some(OverflowMode::Checked)));
+
+        // The implicit operators follow the overflow mode of the profile.
+        assert_eq!(
+            BinaryOp::from_operator(Operator::Add),
+            (BinaryOp::Add, None)
+        );
+    }
+
+    #[test]
+    fn test_arbitrary_precision_arithmetic() {
+        let big = BigInt::from(isize::MAX);
+        assert_eq!(
+            BinaryOp::Add.apply_integer(big.clone(), BigInt::from(1)),
+            Some(big + 1)
+        );
+        assert_eq!(
+            BinaryOp::Sub.apply_nat(BigUint::from(1u8), BigUint::from(2u8)),
+            Some(BigUint::default())
+        );
+        assert_eq!(
+            BinaryOp::Div.apply_nat(BigUint::from(1u8), BigUint::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_int_range() {
+        assert_eq!(int_range(true, 8), Some((-128, 127)));
+        assert_eq!(int_range(false, 8), Some((0, 255)));
+        assert_eq!(int_range(false, 1), Some((0, 1)));
+        assert_eq!(int_range(true, 0), None);
+        assert_eq!(int_range(true, 128), None);
+    }
+}
@@ -0,0 +1,73 @@
+//! Defines the reduction fuel of the evaluation. The terms aren't checked for termination
+//! before they are evaluated by the type checker, so the reductions of a non-terminating term,
+//! like `(|x| x x) (|x| x x)`, would loop forever.
+//!
+//! The nested reductions are counted, and when they exceed the `reduction-fuel` setting of
+//! the profile, the evaluation is aborted, pointing at the definition being unfolded.
+
+use std::cell::Cell;
+
+use sol_diagnostic::{fail, Result};
+use sol_hir::package::HasManifest;
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("possible non-termination during reduction of `{name}`, after {fuel} nested reductions")]
+#[diagnostic(
+    code(sol::thir::possible_non_termination),
+    help("the limit can be raised with the `reduction-fuel` setting of the profile")
+)]
+pub struct PossibleNonTerminationError {
+    pub name: String,
+    pub fuel: usize,
+
+    #[source_code]
+    #[label = "unfolded here"]
+    pub location: Location,
+}
+
+thread_local! {
+    /// The nested reductions of the current evaluation, they are on the same thread, as the
+    /// queries are executed on the thread that requested them.
+    static DEPTH: Cell<usize> = Cell::new(0);
+}
+
+/// Restores the depth of the reductions when the reduction is finished.
+struct Reduction;
+
+impl Drop for Reduction {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Applies the callee to the argument, consuming fuel if it's a lambda being unfolded.
+pub fn apply(db: &dyn ThirLoweringDb, callee: Value, argument: Value) -> Result<Value> {
+    let Some(name) = unfolded(&callee) else {
+        return callee.apply_to_spine(db, argument);
+    };
+
+    let fuel = db.profile().settings(db).reduction_fuel;
+    if DEPTH.with(|depth| depth.get()) >= fuel {
+        return fail(PossibleNonTerminationError {
+            name: name.to_string(db),
+            fuel,
+            location: name.location(db),
+        });
+    }
+
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _reduction = Reduction;
+
+    callee.apply_to_spine(db, argument)
+}
+
+/// Gets the definition of the lambda that is unfolded by the application.
+fn unfolded(callee: &Value) -> Option<Definition> {
+    match callee {
+        Value::Location(_, callee) | Value::Glued(_, _, callee) => unfolded(callee),
+        Value::Lam(name, _, _) => Some(*name),
+        _ => None,
+    }
+}
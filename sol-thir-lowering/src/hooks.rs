@@ -0,0 +1,84 @@
+//! Defines the evaluation of the primitive functions registered by the embedder, like:
+//!
+//! ```rust,ignore
+//! let db = RootDb::builder()
+//!     .primitive_type("Vec3")
+//!     .primitive_function("Vec3.dot", ["Vec3", "Vec3"], "Int", |arguments| ...)
+//!     .build();
+//! ```
+//!
+//! The saturated applications of the primitive functions, to literal arguments, are evaluated
+//! at compile time by the hooks of the functions, just like the constant folding of
+//! [`crate::folding`].
+
+use sol_hir::{primitives::PrimitiveProvider, source::literal::Literal};
+use sol_thir::shared::{Constructor, ConstructorKind};
+
+use super::*;
+
+/// Converts the constructor back into the literal that it was created from, if it's a literal.
+fn literal(kind: ConstructorKind) -> Option<Literal> {
+    Some(match kind {
+        ConstructorKind::Unit => Literal::Empty,
+        ConstructorKind::True => Literal::Boolean(true),
+        ConstructorKind::False => Literal::Boolean(false),
+        ConstructorKind::Int(value) => Literal::Int64(value as _),
+        ConstructorKind::Nat(value) => Literal::Nat(value),
+        ConstructorKind::String(value) => Literal::String(value),
+        _ => return None,
+    })
+}
+
+/// Applies the hook of the primitive function to the arguments of the application, like
+/// `App(App(Vec3.dot, x), y)`, if it's saturated, and all the arguments are literals.
+///
+/// It returns [`None`] if the application can't be evaluated by the hook, so the evaluation
+/// proceeds as usual.
+pub fn apply_hook(
+    db: &dyn ThirLoweringDb,
+    env: Env,
+    callee: &Term,
+    argument: &Term,
+) -> sol_diagnostic::Result<Option<Value>> {
+    // Collects the spine of the application, in the order of the parameters.
+    let mut arguments = vec![argument.clone()];
+    let mut head = folding::unlocated(callee);
+    while let Term::App(callee, argument) = head {
+        arguments.push(*argument.clone());
+        head = folding::unlocated(callee);
+    }
+    arguments.reverse();
+
+    let Term::Constructor(Constructor {
+        kind: ConstructorKind::Reference(reference),
+        location,
+    }) = head
+    else {
+        return Ok(None);
+    };
+    let Some(function) = db.primitives().function(reference.definition(db)) else {
+        return Ok(None);
+    };
+    if function.parameters.len() != arguments.len() {
+        return Ok(None);
+    }
+
+    let mut literals = Vec::with_capacity(arguments.len());
+    for argument in arguments {
+        let value = folding::unlocated_value(db.thir_eval(env, argument)?);
+        let Value::Constructor(Constructor { kind, .. }) = value else {
+            return Ok(None);
+        };
+        let Some(literal) = literal(kind) else {
+            return Ok(None);
+        };
+        literals.push(literal);
+    }
+
+    Ok((function.hook)(&literals).map(|literal| {
+        Value::Constructor(Constructor {
+            kind: literal.into(),
+            location: location.clone(),
+        })
+    }))
+}
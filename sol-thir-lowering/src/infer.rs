@@ -1,6 +1,8 @@
 use sol_diagnostic::{fail, Result};
+use sol_hir::source::top_level::type_alias;
 use sol_thir::{
     find_reference_type, infer_constructor,
+    meta::MetaKind,
     shared::{Constructor, ConstructorKind},
     ElaboratedTerm,
 };
@@ -23,11 +25,13 @@ fn create_from_type(definition: sol_hir::source::expr::Type, location: Location)
         location,
         kind: match definition {
             Universe => return Term::U,
+            Prop => return Term::Prop,
             This => todo!("handle: error"),
             Unit => ConstructorKind::UnitType,
             String => ConstructorKind::StringType,
             Bool => ConstructorKind::BooleanType,
             Nat => ConstructorKind::NatType,
+            Integer => ConstructorKind::IntegerType,
             Int8 => ConstructorKind::IntType(true, 8),
             UInt8 => ConstructorKind::IntType(false, 8),
             Int16 => ConstructorKind::IntType(true, 16),
@@ -36,6 +40,10 @@ fn create_from_type(definition: sol_hir::source::expr::Type, location: Location)
             UInt32 => ConstructorKind::IntType(false, 32),
             Int64 => ConstructorKind::IntType(true, 64),
             UInt64 => ConstructorKind::IntType(false, 64),
+            Eq => unreachable!("the equality type is elaborated with its arguments"),
+            Array => unreachable!("the array type is elaborated with its argument"),
+            Syntax => ConstructorKind::SyntaxType,
+            Primitive(path) => ConstructorKind::PrimitiveType(path),
         },
     })
 }
@@ -43,7 +51,10 @@ fn create_from_type(definition: sol_hir::source::expr::Type, location: Location)
 fn infer_lam(db: &dyn ThirLoweringDb, ctx: Context, fun: Curried) -> Result<ElaboratedTerm> {
     match fun {
         Curried::Lam(domain, codomain) => {
-            let domain_type = Value::default();
+            let meta = ctx
+                .metas(db)
+                .fresh(db, MetaKind::Implicit, domain.location(db));
+            let domain_type = Value::Flexible(meta, vec![]);
             let codomain_ctx = ctx.create_new_value(db, domain, domain_type.clone());
             let ElaboratedTerm(codomain_term, codomain_type) =
                 infer_lam(db, codomain_ctx, *codomain)?;
@@ -55,10 +66,11 @@ fn infer_lam(db: &dyn ThirLoweringDb, ctx: Context, fun: Curried) -> Result<Elab
                     name: Some(domain),
                     implicitness: Implicitness::Explicit,
                     domain: Box::new(domain_type),
-                    codomain: Closure {
-                        env: ctx.locals(db),
-                        expr: db.thir_quote(ctx.lvl(db), codomain_type)?,
-                    },
+                    codomain: Closure::new(
+                        db,
+                        ctx.locals(db),
+                        db.thir_quote(ctx.lvl(db), codomain_type)?,
+                    ),
                 }),
             ))
         }
@@ -69,17 +81,27 @@ fn infer_lam(db: &dyn ThirLoweringDb, ctx: Context, fun: Curried) -> Result<Elab
 /// The infer function to infer the type of the term.
 #[salsa::tracked]
 pub fn thir_infer(db: &dyn ThirLoweringDb, ctx: Context, expr: Expr) -> Result<ElaboratedTerm> {
-    use sol_hir::source::expr::Pi as EPi;
-    use sol_hir::source::pattern::Pattern;
+    use sol_hir::source::expr::{Callee, Pi as EPi, Type as EType};
+    use sol_hir::source::pattern::{
+        Constructor as PatternConstructor, ConstructorPattern, Pattern,
+    };
     use Expr::*;
 
     Ok(ElaboratedTerm::from(match expr {
-        Empty | Error(_) | Match(_) | Sigma(_) => {
+        Empty | Error(_) | Sigma(_) => {
             return fail(UnsupportedTermError {
                 location: expr.location(db),
             })
         }
         Path(path) => {
+            // The type aliases are transparent, so they are unfolded into their values, that
+            // are glued to their names, to print them in the error messages.
+            if let Some(alias) = type_alias(db, path.definition(db)) {
+                if let Some(elaborated) = alias::unfold(db, ctx, alias, path)? {
+                    return Ok(elaborated);
+                }
+            }
+
             let constructor = Constructor {
                 kind: ConstructorKind::Reference(path),
                 location: path.location(db),
@@ -97,7 +119,12 @@ pub fn thir_infer(db: &dyn ThirLoweringDb, ctx: Context, expr: Expr) -> Result<E
 
             (Term::Constructor(constructor), inferred_type)
         }
-        Type(definition, location) => match create_from_type(definition, location) {
+        Type(EType::Eq | EType::Array, span) => {
+            return fail(UnsupportedTermError {
+                location: span.resolve(db),
+            })
+        }
+        Type(definition, span) => match create_from_type(definition, span.resolve(db)) {
             Term::U => (Term::U, Value::U),
             term => (term, Value::U),
         },
@@ -107,8 +134,61 @@ pub fn thir_infer(db: &dyn ThirLoweringDb, ctx: Context, expr: Expr) -> Result<E
             let term = db.thir_check(ctx, *ann.value, actual_type.clone())?;
             (term, actual_type)
         }
-        Call(_) => todo!(),
+        Call(call) => match call.callee {
+            Callee::Refl => return equality::infer_refl(db, ctx, call.location(db)),
+            Callee::Rewrite => return equality::infer_rewrite(db, ctx, call),
+            Callee::Quote => return staging::infer_quote(db, ctx, call),
+            Callee::Splice => return staging::infer_splice(db, ctx, call),
+            Callee::Panic | Callee::Todo => return assertions::infer_panic(db, ctx, call),
+            Callee::Expr(ref callee) if matches!(**callee, Type(EType::Eq, _)) => {
+                return equality::infer_equality_type(db, ctx, call)
+            }
+            Callee::Expr(ref callee) if matches!(**callee, Type(EType::Array, _)) => {
+                return array::infer_array_type(db, ctx, call)
+            }
+            Callee::Reference(_) | Callee::Expr(_)
+                if array::intrinsic(db, &call.callee).is_some() =>
+            {
+                return array::infer_intrinsic(db, ctx, call)
+            }
+            Callee::Array => return array::infer_array(db, ctx, call),
+            Callee::Tuple => return tuple::infer_tuple(db, ctx, call),
+            Callee::Project(position) => return tuple::infer_projection(db, ctx, position, call),
+            // The applications of the functions aren't elaborated yet.
+            _ => {
+                return fail(UnsupportedTermError {
+                    location: call.location(db),
+                })
+            }
+        },
         Lam(lam) => return infer_lam(db, ctx, new_curried_function(db, lam)),
+        Let(let_expr) => {
+            if let Pattern::Constructor(
+                pattern @ ConstructorPattern {
+                    name: PatternConstructor::Tuple,
+                    ..
+                },
+            ) = let_expr.pattern
+            {
+                return tuple::infer_let_tuple(db, ctx, pattern, *let_expr.value, *let_expr.body);
+            }
+
+            let Pattern::Binding(binding) = let_expr.pattern else {
+                return fail(UnsupportedTermError {
+                    location: let_expr.location(db),
+                });
+            };
+
+            // The let expression is elaborated into a redex, like `(λx. body) value`.
+            let ElaboratedTerm(value, value_type) = db.thir_infer(ctx, *let_expr.value)?;
+            let body_ctx = ctx.create_new_value(db, binding.name, value_type);
+            let ElaboratedTerm(body, body_type) = db.thir_infer(body_ctx, *let_expr.body)?;
+            let lam = Term::Lam(binding.name, Implicitness::Explicit, body.into());
+
+            (Term::App(lam.into(), value.into()), body_type)
+        }
+        Record(record) => return record::infer_record(db, ctx, record),
+        Match(match_expr) => return matching::infer_match(db, ctx, match_expr),
         Pi(EPi {
             parameters, value, ..
         }) => {
@@ -131,8 +211,8 @@ pub fn thir_infer(db: &dyn ThirLoweringDb, ctx: Context, expr: Expr) -> Result<E
 
             (codomain, Value::U)
         }
-        Hole(_) => {
-            let meta = MetaVar::new(None);
+        Hole(span) => {
+            let meta = ctx.metas(db).fresh(db, MetaKind::Hole, span.resolve(db));
             let term = Term::InsertedMeta(meta.clone());
             (term, Value::Flexible(meta, vec![]))
         }
@@ -15,10 +15,11 @@ use sol_hir::{
         pattern::Pattern,
         HirElement, HirPath, Location,
     },
+    span_map::SpanId,
 };
 use sol_thir::{
     debruijin::Level,
-    shared::{Context, Env, Implicitness, MetaVar},
+    shared::{Context, Env, Implicitness},
     source::Term,
     value::{Closure, Pi, Type, Value},
     ThirDb,
@@ -26,9 +27,21 @@ use sol_thir::{
 
 extern crate salsa_2022 as salsa;
 
+pub mod alias;
+pub mod array;
+pub mod assertions;
 pub mod check;
 pub mod elaboration;
+pub mod equality;
+pub mod folding;
+pub mod fuel;
+pub mod hooks;
 pub mod infer;
+pub mod matching;
+pub mod record;
+pub mod relevance;
+pub mod staging;
+pub mod tuple;
 
 #[salsa::jar(db = ThirLoweringDb)]
 pub struct Jar(
@@ -49,30 +62,102 @@ impl<T> ThirLoweringDb for T where T: ThirDb + DbWithJar<Jar> {}
 pub fn thir_eval(db: &dyn ThirLoweringDb, env: Env, term: Term) -> sol_diagnostic::Result<Value> {
     Ok(match term {
         Term::U => Value::U,
+        Term::Prop => Value::Prop,
         Term::Var(idx, _) => env.get(db, idx),
         Term::Lam(name, implicitness, value) => {
-            Value::Lam(name, implicitness, Closure { env, expr: *value })
+            Value::Lam(name, implicitness, Closure::new(db, env, *value))
         }
-        Term::App(callee, argument) => db
-            .thir_eval(env, *callee)?
-            .apply_to_spine(db, db.thir_eval(env, *argument)?)?,
+        Term::App(callee, argument) => match folding::fold_constant(db, env, &callee, &argument)? {
+            Some(value) => value,
+            None => match hooks::apply_hook(db, env, &callee, &argument)? {
+                Some(value) => value,
+                None => fuel::apply(
+                    db,
+                    db.thir_eval(env, *callee)?,
+                    db.thir_eval(env, *argument)?,
+                )?,
+            },
+        },
         Term::Pi(name, implicitness, domain, codomain) => Value::Pi(Pi {
             name,
             implicitness,
             domain: Box::new(db.thir_eval(env, *domain)?),
-            codomain: Closure {
-                env,
-                expr: *codomain,
-            },
+            codomain: Closure::new(db, env, *codomain),
         }),
         Term::Constructor(constructor) => Value::Constructor(constructor),
         Term::Ann(value, _) => db.thir_eval(env, *value)?,
         Term::InsertedMeta(meta) => meta.get().unwrap_or_else(|| Value::Flexible(meta, vec![])),
         Term::Location(location, term) => Value::located(location, db.thir_eval(env, *term)?),
         Term::Sorry(_, _) => panic!("sorry :("),
+        Term::Panic(message, location) => {
+            return sol_diagnostic::fail(assertions::PanicError { message, location })
+        }
+        Term::Record(fields) => Value::Record(eval_fields(db, env, fields)?),
+        Term::RecordType(fields) => Value::RecordType(eval_fields(db, env, fields)?),
+        Term::Project(record, name, position) => match db.thir_eval(env, *record)?.force(db)?.1 {
+            Value::Record(mut fields) if position < fields.len() => fields.swap_remove(position).1,
+            record => Value::Project(record.into(), name, position),
+        },
+        Term::Eq(type_repr, lhs, rhs) => Value::Eq(
+            db.thir_eval(env, *type_repr)?.into(),
+            db.thir_eval(env, *lhs)?.into(),
+            db.thir_eval(env, *rhs)?.into(),
+        ),
+        Term::Refl => Value::Refl,
+        Term::Irrelevant(term) => Value::Irrelevant(db.thir_eval(env, *term)?.into()),
+        Term::Transport(equality, value) => match db.thir_eval(env, *equality)?.force(db)?.1 {
+            Value::Refl => db.thir_eval(env, *value)?,
+            equality => Value::Transport(equality.into(), db.thir_eval(env, *value)?.into()),
+        },
+        Term::Glued(constructor, spine, term) => Value::Glued(
+            constructor,
+            spine
+                .into_iter()
+                .map(|argument| db.thir_eval(env, argument))
+                .collect::<sol_diagnostic::Result<_>>()?,
+            db.thir_eval(env, *term)?.into(),
+        ),
+        Term::Quote(expr, splices) => Value::Quote(
+            expr,
+            splices
+                .into_iter()
+                .map(|(location, term)| Ok((location, db.thir_eval(env, term)?)))
+                .collect::<sol_diagnostic::Result<_>>()?,
+        ),
+        Term::ArrayType(type_repr) => Value::ArrayType(db.thir_eval(env, *type_repr)?.into()),
+        Term::Array(items) => Value::Array(eval_terms(db, env, items)?),
+        Term::Intrinsic(intrinsic, arguments, location) => {
+            let arguments = eval_terms(db, env, arguments)?;
+            array::apply_intrinsic(db, intrinsic, arguments, location)?
+        }
+        Term::Match(scrutinee, tree) => matching::eval_match(db, env, *scrutinee, tree)?,
     })
 }
 
+/// Evaluates the terms, like the items of an array.
+fn eval_terms(
+    db: &dyn ThirLoweringDb,
+    env: Env,
+    terms: Vec<Term>,
+) -> sol_diagnostic::Result<Vec<Value>> {
+    terms
+        .into_iter()
+        .map(|term| db.thir_eval(env, term))
+        .collect()
+}
+
+/// Evaluates the fields of a record, or of a record type.
+fn eval_fields(
+    db: &dyn ThirLoweringDb,
+    env: Env,
+    fields: Vec<(String, Term)>,
+) -> sol_diagnostic::Result<Vec<(String, Value)>> {
+    fields
+        .into_iter()
+        .map(|(name, term)| Ok((name, db.thir_eval(env, term)?)))
+        .collect()
+}
+
 /// The quoting function to convert the value back to the term.
 #[salsa::tracked]
 pub fn thir_quote(
@@ -92,6 +177,7 @@ pub fn thir_quote(
 
         Ok(match value {
             U => Term::U,
+            Prop => Term::Prop,
             Constructor(constructor) => Term::Constructor(constructor),
             Flexible(meta, spine) => {
                 let default = Term::InsertedMeta(meta);
@@ -124,10 +210,71 @@ pub fn thir_quote(
                 Term::Lam(name, implicitness, closure.into())
             }
             Location(location, term) => Term::Location(location, db.thir_quote(lvl, *term)?.into()),
+            Record(fields) => Term::Record(quote_fields(db, lvl, fields)?),
+            RecordType(fields) => Term::RecordType(quote_fields(db, lvl, fields)?),
+            Project(record, name, position) => {
+                Term::Project(db.thir_quote(lvl, *record)?.into(), name, position)
+            }
+            Eq(type_repr, lhs, rhs) => Term::Eq(
+                db.thir_quote(lvl, *type_repr)?.into(),
+                db.thir_quote(lvl, *lhs)?.into(),
+                db.thir_quote(lvl, *rhs)?.into(),
+            ),
+            Refl => Term::Refl,
+            Irrelevant(value) => Term::Irrelevant(db.thir_quote(lvl, *value)?.into()),
+            Transport(equality, value) => Term::Transport(
+                db.thir_quote(lvl, *equality)?.into(),
+                db.thir_quote(lvl, *value)?.into(),
+            ),
+            Glued(constructor, spine, value) => Term::Glued(
+                constructor,
+                spine
+                    .into_iter()
+                    .map(|argument| db.thir_quote(lvl, argument))
+                    .collect::<sol_diagnostic::Result<_>>()?,
+                db.thir_quote(lvl, *value)?.into(),
+            ),
+            Quote(expr, splices) => Term::Quote(
+                expr,
+                splices
+                    .into_iter()
+                    .map(|(location, value)| Ok((location, db.thir_quote(lvl, value)?)))
+                    .collect::<sol_diagnostic::Result<_>>()?,
+            ),
+            ArrayType(type_repr) => Term::ArrayType(db.thir_quote(lvl, *type_repr)?.into()),
+            Array(items) => Term::Array(quote_values(db, lvl, items)?),
+            Intrinsic(intrinsic, arguments, location) => {
+                Term::Intrinsic(intrinsic, quote_values(db, lvl, arguments)?, location)
+            }
+            Match(scrutinee, env, tree) => matching::quote_match(db, lvl, *scrutinee, env, tree)?,
         })
     }
 
-    let (location, value) = value.force(db)?;
+    /// Quotes the values, like the items of an array.
+    fn quote_values(
+        db: &dyn ThirLoweringDb,
+        lvl: Level,
+        values: Vec<Value>,
+    ) -> sol_diagnostic::Result<Vec<Term>> {
+        values
+            .into_iter()
+            .map(|value| db.thir_quote(lvl, value))
+            .collect()
+    }
+
+    /// Quotes the fields of a record, or of a record type.
+    fn quote_fields(
+        db: &dyn ThirLoweringDb,
+        lvl: Level,
+        fields: Vec<(String, Value)>,
+    ) -> sol_diagnostic::Result<Vec<(String, Term)>> {
+        fields
+            .into_iter()
+            .map(|(name, value)| Ok((name, db.thir_quote(lvl, value)?)))
+            .collect()
+    }
+
+    let (location, value) = value.force_glued(db)?;
 
     location
         .map(|location| {
@@ -156,11 +303,11 @@ fn new_curried_function(db: &dyn ThirLoweringDb, abs: LamExpr) -> Curried {
 }
 
 pub fn extract_parameter_definition(db: &dyn ThirLoweringDb, pattern: Pattern) -> Definition {
-    let location = pattern.location(db);
+    let span = SpanId::of(db, &pattern.location(db));
     let hole = HirPath::create(db, "_");
     let fallback = Definition::new(
         db,
-        /* id = */ DefinitionId::new(db, location, hole.to_string(db)),
+        /* id = */ DefinitionId::new(db, span, hole.to_string(db)),
         /* kind = */ Variable,
         /* name = */ hole,
     );
@@ -182,5 +329,5 @@ fn create_reference_of(
 ) -> Option<Reference> {
     maybe_definition
         .zip(maybe_location)
-        .map(|(definition, location)| Reference::new(db, definition, location))
+        .map(|(definition, location)| Reference::new(db, definition, SpanId::of(db, &location)))
 }
@@ -0,0 +1,402 @@
+//! Defines the elaboration of the match expressions into case trees, like in:
+//!
+//! ```sol
+//! describe : Int -> String = |n| match n {
+//!   0 => {
+//!     "zero"
+//!   }
+//!   n if n > 0 => {
+//!     "positive"
+//!   }
+//!   _ => {
+//!     "negative"
+//!   }
+//! }
+//! ```
+//!
+//! The scrutinee is bound to a variable, and the arms are compiled, in order, into a
+//! [`CaseTree`], that tests the literals of their patterns. The guards are tests too, that fall
+//! through to the arms below them when they don't hold, so a guarded arm never covers its
+//! pattern. The match is exhaustive if the tree has no [`CaseTree::Fail`] leaf, the booleans,
+//! and the unit are covered by their literals, and the other types only by an arm without a
+//! guard, that matches any value.
+//!
+//! Only the literal, the binding, and the wildcard patterns are matched, the constructor and
+//! the rest patterns are reported with [`UnsupportedPatternError`], as the case trees can't
+//! bind the arguments of the constructors yet.
+
+use sol_diagnostic::{fail, report_error, Result};
+use sol_hir::source::{expr::MatchExpr, literal::Literal, Spanned};
+use sol_thir::{
+    case_tree::CaseTree,
+    debruijin::Index,
+    meta::MetaKind,
+    shared::{Constructor, ConstructorKind},
+    ElaboratedTerm,
+};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the match isn't exhaustive")]
+#[diagnostic(
+    code(solc::non_exhaustive_match),
+    help("the arms with guards don't cover their patterns, add an arm that matches any value")
+)]
+pub struct NonExhaustiveMatchError {
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// A constructor, or a rest pattern, in an arm of the match. The case trees only test the
+/// literals, so these patterns aren't supported yet.
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("unsupported pattern in the match")]
+#[diagnostic(
+    code(solc::unsupported_pattern),
+    help("only the literal, the binding, and the wildcard patterns can be matched")
+)]
+pub struct UnsupportedPatternError {
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// An elaborated arm of the match, with the literal that its pattern tests, if it isn't
+/// irrefutable. The guard, and the body are in the context of the match.
+struct Arm {
+    test: Option<Constructor>,
+    guard: Option<Term>,
+    body: Term,
+}
+
+/// What is known about the scrutinee, in a branch of the case tree, by the tests above it.
+#[derive(Clone, Default)]
+struct Known {
+    /// The literals of the type of the scrutinee, if they're finite, like the booleans.
+    domain: Option<Vec<ConstructorKind>>,
+
+    /// The literal that the scrutinee is known to be.
+    is: Option<ConstructorKind>,
+
+    /// The literals that the scrutinee is known to not be.
+    is_not: Vec<ConstructorKind>,
+}
+
+impl Known {
+    /// Checks if the scrutinee can't be the literal.
+    fn excludes(&self, kind: &ConstructorKind) -> bool {
+        self.is_not.contains(kind) || self.is.as_ref().is_some_and(|is| is != kind)
+    }
+
+    /// Checks if the scrutinee is the literal, without testing it, like `false` after the
+    /// scrutinee is known to not be `true`.
+    fn implies(&self, kind: &ConstructorKind) -> bool {
+        if self.is.as_ref() == Some(kind) {
+            return true;
+        }
+
+        let Some(domain) = &self.domain else {
+            return false;
+        };
+        let mut left = domain.iter().filter(|value| !self.is_not.contains(value));
+
+        left.next() == Some(kind) && left.next().is_none()
+    }
+
+    fn with(&self, kind: &ConstructorKind) -> Known {
+        Known {
+            is: Some(kind.clone()),
+            ..self.clone()
+        }
+    }
+
+    fn without(&self, kind: &ConstructorKind) -> Known {
+        let mut known = self.clone();
+        known.is_not.push(kind.clone());
+        known
+    }
+}
+
+/// Infers the type of the match, from the type of its first arm.
+pub fn infer_match(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    expr: MatchExpr,
+) -> Result<ElaboratedTerm> {
+    elaborate(db, ctx, expr, None)
+}
+
+/// CASE: match
+///
+/// Checks the arms of the match against the expected type.
+pub fn check_match(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    expr: MatchExpr,
+    expected: Type,
+) -> Result<Term> {
+    let ElaboratedTerm(term, _) = elaborate(db, ctx, expr, Some(expected))?;
+
+    Ok(term)
+}
+
+/// Elaborates the match into a redex, like `(λs. match s { ... }) scrutinee`, so the
+/// scrutinee is evaluated only once, and its case tree tests the variable.
+fn elaborate(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    expr: MatchExpr,
+    mut expected: Option<Type>,
+) -> Result<ElaboratedTerm> {
+    let location = expr.location(db);
+    let ElaboratedTerm(scrutinee, scrutinee_type) = db.thir_infer(ctx, *expr.scrutinee)?;
+    let binder = extract_parameter_definition(db, Pattern::Hole);
+    let match_ctx = ctx.create_new_value(db, binder, scrutinee_type.clone());
+
+    let mut arms = vec![];
+    for arm in expr.clauses {
+        let (test, binding) = match arm.pattern {
+            Pattern::Hole | Pattern::Wildcard(_) | Pattern::Error(_) => (None, None),
+            Pattern::Binding(binding) => (None, Some(binding.name)),
+            Pattern::Literal(literal) => {
+                let test = literal_test(db, match_ctx, literal, scrutinee_type.clone())?;
+                (Some(test), None)
+            }
+            pattern @ (Pattern::Constructor(_) | Pattern::Rest(_)) => {
+                return fail(UnsupportedPatternError {
+                    location: pattern.location(db),
+                })
+            }
+        };
+
+        // The bindings are elaborated into redexes too, like `(λx. body) s`.
+        let arm_ctx = match binding {
+            Some(name) => match_ctx.create_new_value(db, name, scrutinee_type.clone()),
+            None => match_ctx,
+        };
+        let bind = |term: Term| match binding {
+            Some(name) => {
+                let lam = Term::Lam(name, Implicitness::Explicit, term.into());
+                Term::App(lam.into(), Term::Var(Index(0), None).into())
+            }
+            None => term,
+        };
+
+        let guard = arm
+            .guard
+            .map(|guard| {
+                let location = guard.location(db);
+                db.thir_check(arm_ctx, guard, boolean_type(location))
+            })
+            .transpose()?
+            .map(bind);
+        let body = match expected.clone() {
+            Some(expected) => db.thir_check(arm_ctx, arm.value, expected)?,
+            None => {
+                let ElaboratedTerm(body, body_type) = db.thir_infer(arm_ctx, arm.value)?;
+                expected = Some(body_type);
+                body
+            }
+        };
+
+        arms.push(Arm {
+            test,
+            guard,
+            body: bind(body),
+        });
+    }
+
+    let known = Known {
+        domain: domain(db, scrutinee_type)?,
+        ..Known::default()
+    };
+    let tree = compile(&arms, &known, &location);
+    if tree.is_partial() {
+        report_error(db, NonExhaustiveMatchError {
+            location: location.clone(),
+        });
+    }
+
+    // The type of the match without arms isn't known, so it's solved by its uses.
+    let match_type = expected.unwrap_or_else(|| {
+        let meta = ctx.metas(db).fresh(db, MetaKind::Implicit, location);
+        Value::Flexible(meta, vec![])
+    });
+    let term = Term::Match(Term::Var(Index(0), None).into(), tree);
+    let lam = Term::Lam(binder, Implicitness::Explicit, term.into());
+
+    Ok(ElaboratedTerm(
+        Term::App(lam.into(), scrutinee.into()),
+        match_type,
+    ))
+}
+
+/// Checks the literal of the pattern against the type of the scrutinee, so it's elaborated
+/// into the same constructor as the values of the type, like `0` into a natural number.
+fn literal_test(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    literal: Spanned<Literal>,
+    scrutinee_type: Type,
+) -> Result<Constructor> {
+    let location = literal.location(db);
+    let mut term = db.thir_check(ctx, Expr::Literal(literal.clone()), scrutinee_type)?;
+    while let Term::Location(_, inner) = term {
+        term = *inner;
+    }
+
+    Ok(match term {
+        Term::Constructor(constructor) => constructor,
+        _ => Constructor {
+            kind: literal.value.into(),
+            location,
+        },
+    })
+}
+
+/// The literals of the type, if they're finite, so they can cover it without a wildcard.
+fn domain(db: &dyn ThirLoweringDb, type_repr: Type) -> Result<Option<Vec<ConstructorKind>>> {
+    Ok(match type_repr.force(db)?.1 {
+        Value::Constructor(Constructor {
+            kind: ConstructorKind::BooleanType,
+            ..
+        }) => Some(vec![ConstructorKind::True, ConstructorKind::False]),
+        Value::Constructor(Constructor {
+            kind: ConstructorKind::UnitType,
+            ..
+        }) => Some(vec![ConstructorKind::Unit]),
+        _ => None,
+    })
+}
+
+/// The type of the guards.
+fn boolean_type(location: Location) -> Type {
+    Value::Constructor(Constructor {
+        kind: ConstructorKind::BooleanType,
+        location,
+    })
+}
+
+/// Compiles the arms into a case tree, the first arm that matches is taken. The arms that can't
+/// match, because their literals were already tested, are skipped.
+fn compile(arms: &[Arm], known: &Known, location: &Location) -> CaseTree {
+    let Some((arm, rest)) = arms.split_first() else {
+        return CaseTree::Fail(location.clone());
+    };
+    let Some(test) = &arm.test else {
+        return arm_tree(arm, rest, known, location);
+    };
+
+    if known.excludes(&test.kind) {
+        return compile(rest, known, location);
+    }
+    if known.implies(&test.kind) {
+        return arm_tree(arm, rest, known, location);
+    }
+
+    let then = arm_tree(arm, rest, &known.with(&test.kind), location);
+    let otherwise = compile(rest, &known.without(&test.kind), location);
+
+    CaseTree::Test(test.clone(), then.into(), otherwise.into())
+}
+
+/// The tree of the arm, after its pattern matches. If it has a guard, the arms below it are
+/// tried when the guard doesn't hold.
+fn arm_tree(arm: &Arm, rest: &[Arm], known: &Known, location: &Location) -> CaseTree {
+    let leaf = CaseTree::Leaf(arm.body.clone());
+
+    match &arm.guard {
+        Some(guard) => CaseTree::Guard(
+            guard.clone(),
+            leaf.into(),
+            compile(rest, known, location).into(),
+        ),
+        None => leaf,
+    }
+}
+
+/// Checks if the constructor is a literal, that can be tested by the case trees.
+fn is_literal(kind: &ConstructorKind) -> bool {
+    matches!(
+        kind,
+        ConstructorKind::Unit
+            | ConstructorKind::True
+            | ConstructorKind::False
+            | ConstructorKind::Int(_)
+            | ConstructorKind::Nat(_)
+            | ConstructorKind::Integer(_)
+            | ConstructorKind::String(_)
+    )
+}
+
+/// Evaluates the match, by walking its case tree. It's stuck if the scrutinee, or a guard,
+/// isn't known yet.
+pub fn eval_match(
+    db: &dyn ThirLoweringDb,
+    env: Env,
+    scrutinee: Term,
+    tree: CaseTree,
+) -> Result<Value> {
+    let scrutinee = db.thir_eval(env, scrutinee)?;
+
+    eval_case_tree(db, env, scrutinee, tree)
+}
+
+fn eval_case_tree(
+    db: &dyn ThirLoweringDb,
+    env: Env,
+    scrutinee: Value,
+    tree: CaseTree,
+) -> Result<Value> {
+    match tree {
+        CaseTree::Leaf(body) => db.thir_eval(env, body),
+        CaseTree::Test(constructor, then, otherwise) => match scrutinee.clone().force(db)?.1 {
+            Value::Constructor(value) if value.kind == constructor.kind => {
+                eval_case_tree(db, env, scrutinee, *then)
+            }
+            Value::Constructor(value) if is_literal(&value.kind) => {
+                eval_case_tree(db, env, scrutinee, *otherwise)
+            }
+            _ => Ok(Value::Match(
+                scrutinee.into(),
+                env,
+                CaseTree::Test(constructor, then, otherwise),
+            )),
+        },
+        CaseTree::Guard(guard, then, otherwise) => {
+            match db.thir_eval(env, guard.clone())?.force(db)?.1 {
+                Value::Constructor(Constructor {
+                    kind: ConstructorKind::True,
+                    ..
+                }) => eval_case_tree(db, env, scrutinee, *then),
+                Value::Constructor(Constructor {
+                    kind: ConstructorKind::False,
+                    ..
+                }) => eval_case_tree(db, env, scrutinee, *otherwise),
+                _ => Ok(Value::Match(
+                    scrutinee.into(),
+                    env,
+                    CaseTree::Guard(guard, then, otherwise),
+                )),
+            }
+        }
+        CaseTree::Fail(location) => fail(NonExhaustiveMatchError { location }),
+    }
+}
+
+/// Quotes the stuck match, its terms are evaluated in its environment, and quoted back at the
+/// level, so they're in the same context as the scrutinee.
+pub fn quote_match(
+    db: &dyn ThirLoweringDb,
+    lvl: Level,
+    scrutinee: Value,
+    env: Env,
+    tree: CaseTree,
+) -> Result<Term> {
+    let scrutinee = db.thir_quote(lvl, scrutinee)?;
+    let tree = tree.try_map(&mut |term| db.thir_quote(lvl, db.thir_eval(env, term)?))?;
+
+    Ok(Term::Match(scrutinee.into(), tree))
+}
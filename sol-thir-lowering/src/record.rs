@@ -0,0 +1,148 @@
+//! Defines the elaboration of the anonymous records. The records are structural, so their
+//! types are the names and the types of their fields, sorted by the names, and two records
+//! with the same fields have the same type.
+//!
+//! The functional updates, like `{ r | x = 3 }`, are elaborated into record literals, that
+//! copies the fields that aren't updated from the record being updated.
+
+use std::collections::BTreeMap;
+
+use sol_diagnostic::{fail, report_error, Result};
+use sol_hir::source::expr::{RecordExpr, RecordField};
+use sol_thir::{debruijin::Index, display, ElaboratedTerm};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the field `{name}` is defined more than once")]
+#[diagnostic(code(sol::thir::duplicate_field))]
+pub struct DuplicateFieldError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the record has no field `{name}`")]
+#[diagnostic(
+    code(sol::thir::unknown_field),
+    help("the functional update can't add new fields to the record")
+)]
+pub struct UnknownFieldError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("expected a record, got the type: `{actual}`")]
+#[diagnostic(code(sol::thir::not_a_record))]
+pub struct NotARecordError {
+    pub actual: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Infers the type of the record literal, or of the functional update.
+pub fn infer_record(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    record: RecordExpr,
+) -> Result<ElaboratedTerm> {
+    match record.record {
+        Some(base) => infer_update(db, ctx, *base, record.fields),
+        None => infer_literal(db, ctx, record.fields),
+    }
+}
+
+/// Sorts the fields by their names, and reports the duplicated ones. The last definition of
+/// a duplicated field is kept, to recover from the error.
+fn sort_fields(db: &dyn ThirLoweringDb, fields: Vec<RecordField>) -> BTreeMap<String, RecordField> {
+    let mut sorted = BTreeMap::new();
+    for field in fields {
+        let name = field.name.contents(db);
+        if sorted.contains_key(&name) {
+            report_error(db, DuplicateFieldError {
+                name: name.clone(),
+                location: field.location(db),
+            });
+        }
+
+        sorted.insert(name, field);
+    }
+
+    sorted
+}
+
+fn infer_literal(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    fields: Vec<RecordField>,
+) -> Result<ElaboratedTerm> {
+    let mut terms = vec![];
+    let mut types = vec![];
+    for (name, field) in sort_fields(db, fields) {
+        let ElaboratedTerm(term, field_type) = db.thir_infer(ctx, field.value)?;
+        terms.push((name.clone(), term));
+        types.push((name, field_type));
+    }
+
+    Ok(ElaboratedTerm(
+        Term::Record(terms),
+        Value::RecordType(types),
+    ))
+}
+
+/// The update is elaborated into a redex, like `(λr. { x = 3, y = r.y }) record`, so the
+/// record being updated is evaluated only once. The updated fields keeps their types.
+fn infer_update(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    record: Expr,
+    fields: Vec<RecordField>,
+) -> Result<ElaboratedTerm> {
+    let location = record.location(db);
+    let ElaboratedTerm(record_term, record_type) = db.thir_infer(ctx, record)?;
+    let Value::RecordType(field_types) = record_type.clone().force(db)?.1 else {
+        return fail(NotARecordError {
+            actual: display::show_value(db, ctx, record_type)?,
+            location,
+        });
+    };
+
+    // The fields are elaborated under the binder of the record being updated, as the fields
+    // that aren't updated are projected from it.
+    let binder = extract_parameter_definition(db, Pattern::Hole);
+    let body_ctx = ctx.create_new_value(db, binder, record_type.clone());
+
+    let mut updates = sort_fields(db, fields);
+    let mut terms = vec![];
+    for (position, (name, field_type)) in field_types.into_iter().enumerate() {
+        let term = match updates.remove(&name) {
+            Some(field) => db.thir_check(body_ctx, field.value, field_type)?,
+            None => Term::Project(Term::Var(Index(0), None).into(), name.clone(), position),
+        };
+
+        terms.push((name, term));
+    }
+
+    for (name, field) in updates {
+        report_error(db, UnknownFieldError {
+            name,
+            location: field.location(db),
+        });
+    }
+
+    let lam = Term::Lam(binder, Implicitness::Explicit, Term::Record(terms).into());
+
+    Ok(ElaboratedTerm(
+        Term::App(lam.into(), record_term.into()),
+        record_type,
+    ))
+}
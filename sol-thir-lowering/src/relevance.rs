@@ -0,0 +1,69 @@
+//! Defines the relevance of the terms. The types in the `Prop` universe are propositions, and
+//! their proofs are computationally irrelevant, like in:
+//!
+//! ```hs
+//! inductive Even : Nat -> Prop {
+//!   EvenZero : Even Zero,
+//!   EvenSucc : Even n -> Even (Succ (Succ n))
+//! }
+//! ```
+//!
+//! The checked terms whose types are propositions are marked with [`Term::Irrelevant`], so the
+//! conversion checking treats all the proofs of a proposition as equal, and the code generation
+//! erases them entirely.
+
+use sol_thir::{
+    find_reference_type,
+    shared::{Constructor, ConstructorKind},
+};
+
+use super::*;
+
+/// Checks if the type is a proposition, that is, if its type is `Prop`. The functions that
+/// return propositions are propositions too.
+pub fn is_proposition(db: &dyn ThirLoweringDb, ctx: Context, type_repr: Type) -> bool {
+    let Ok((_, type_repr)) = type_repr.force(db) else {
+        return false;
+    };
+
+    match type_repr {
+        Value::Location(_, type_repr) => is_proposition(db, ctx, *type_repr),
+        Value::Pi(pi) => match pi.codomain.apply(db, Value::new_var(ctx.lvl(db), None)) {
+            Ok(codomain) => is_proposition(db, ctx.increase_level(db), codomain),
+            Err(_) => false,
+        },
+        Value::Constructor(Constructor {
+            kind: ConstructorKind::Reference(reference),
+            ..
+        }) => find_reference_type(db, ctx, reference)
+            .is_ok_and(|(_, sort)| is_prop_sort(db, ctx, sort)),
+        _ => false,
+    }
+}
+
+/// Checks if the sort is `Prop`, following the arrows of the type families, like in
+/// `Nat -> Prop`.
+fn is_prop_sort(db: &dyn ThirLoweringDb, ctx: Context, sort: Type) -> bool {
+    let Ok((_, sort)) = sort.force(db) else {
+        return false;
+    };
+
+    match sort {
+        Value::Prop => true,
+        Value::Location(_, sort) => is_prop_sort(db, ctx, *sort),
+        Value::Pi(pi) => match pi.codomain.apply(db, Value::new_var(ctx.lvl(db), None)) {
+            Ok(codomain) => is_prop_sort(db, ctx.increase_level(db), codomain),
+            Err(_) => false,
+        },
+        _ => false,
+    }
+}
+
+/// Marks the term as irrelevant, if its type is a proposition.
+pub fn mark(term: Term, proposition: bool) -> Term {
+    match term {
+        Term::Irrelevant(_) => term,
+        term if proposition => Term::Irrelevant(term.into()),
+        term => term,
+    }
+}
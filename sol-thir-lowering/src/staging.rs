@@ -0,0 +1,239 @@
+//! Defines the elaboration of the quotes and the splices, for the staged metaprogramming, like
+//! in:
+//!
+//! ```sol
+//! twice (syntax : Syntax) : Syntax = quote { splice { syntax } + splice { syntax } }
+//!
+//! four : Int = splice { twice (quote { 2 }) }
+//! ```
+//!
+//! The quotes reify the HIR expressions as values of the `Syntax` type, and the splices
+//! evaluate the `Syntax` values at compile time, with the evaluator of the type checker, and
+//! elaborate the expressions they hold in place. So the procedural macros are written in Sol
+//! itself, as functions over the `Syntax` values.
+//!
+//! The splices inside of a quote are elaborated, and evaluated, with the quote, so they can
+//! refer to the local variables, and they're replaced by the expressions they hold when the
+//! quote is spliced.
+
+use std::{cell::Cell, collections::HashMap};
+
+use sol_diagnostic::{fail, Result};
+use sol_hir::{
+    rewriting::{HirRewriter, Rewrite},
+    source::expr::{CallExpr, Callee},
+    walking::{HirListener, Walker},
+    HirDb,
+};
+use sol_thir::{
+    display,
+    shared::{Constructor, ConstructorKind},
+    ElaboratedTerm,
+};
+
+use super::*;
+
+/// The maximum depth of the nested splices, like when a spliced expression holds another
+/// splice.
+const MAX_SPLICE_DEPTH: usize = 64;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("expected a quoted expression, got: `{actual}`")]
+#[diagnostic(
+    code(sol::thir::stuck_splice),
+    help("the spliced value should be known at compile time, like `quote {{ x }}`")
+)]
+pub struct StuckSpliceError {
+    pub actual: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the splices exceeded the depth limit of {limit}")]
+#[diagnostic(
+    code(sol::thir::splice_depth),
+    help("the spliced expressions are probably splicing themselves")
+)]
+pub struct SpliceDepthError {
+    pub limit: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+thread_local! {
+    /// The nested splices of the current elaboration, they are on the same thread, as the
+    /// queries are executed on the thread that requested them.
+    static DEPTH: Cell<usize> = Cell::new(0);
+}
+
+/// Restores the depth of the splices when the splice is elaborated.
+struct Splicing;
+
+impl Drop for Splicing {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Collects the splices inside of a quote.
+#[derive(Default)]
+struct SpliceCollector {
+    splices: Vec<CallExpr>,
+}
+
+impl HirListener for SpliceCollector {
+    fn enter_call_expr(&mut self, call_expr: CallExpr) {
+        if call_expr.callee == Callee::Splice {
+            self.splices.push(call_expr);
+        }
+    }
+}
+
+/// Replaces the splices of a quoted expression, by the expressions their values hold.
+struct SpliceRewriter {
+    splices: HashMap<Location, Expr>,
+}
+
+impl HirRewriter for SpliceRewriter {
+    fn rewrite_expr(&mut self, db: &dyn HirDb, expr: Expr) -> Expr {
+        match expr {
+            Expr::Call(ref call) if call.callee == Callee::Splice => self
+                .splices
+                .get(&call.location(db))
+                .cloned()
+                .unwrap_or(expr),
+            expr => expr,
+        }
+    }
+}
+
+/// The `Syntax` type, of the quoted expressions.
+fn syntax_type(location: Location) -> Type {
+    Value::Constructor(Constructor {
+        kind: ConstructorKind::SyntaxType,
+        location,
+    })
+}
+
+/// Infers the type of `quote { expr }`, that is always `Syntax`. The splices inside of the
+/// quote are checked against `Syntax`, except the ones inside of other splices, as they're
+/// elaborated with the splices that hold them.
+pub fn infer_quote(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let hir_db: &dyn HirDb = db;
+    let expr = call.arguments.into_iter().next().unwrap_or(Expr::Empty);
+
+    let mut collector = SpliceCollector::default();
+    expr.clone().accept(hir_db, &mut collector);
+
+    let nested = |splice: &CallExpr| {
+        let location = splice.location(db);
+        collector.splices.iter().any(|other| {
+            let other = other.location(db);
+            other != location
+                && other.start().offset() <= location.start().offset()
+                && location.end().offset() <= other.end().offset()
+        })
+    };
+    let splices = collector
+        .splices
+        .iter()
+        .filter(|splice| !nested(splice))
+        .map(|splice| {
+            let value = splice.arguments.first().cloned().unwrap_or(Expr::Empty);
+            let term = db.thir_check(ctx, value, syntax_type(splice.location(db)))?;
+            Ok((splice.location(db), term))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ElaboratedTerm(
+        Term::Quote(expr, splices),
+        syntax_type(call.location(db)),
+    ))
+}
+
+/// Infers the type of `splice { value }`, by inferring the type of the expression that the
+/// value holds.
+pub fn infer_splice(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let location = call.location(db);
+    splicing(location, || db.thir_infer(ctx, expand(db, ctx, call)?))
+}
+
+/// CASE: splice
+///
+/// Checks the expression that the value of `splice { value }` holds against the expected type.
+pub fn check_splice(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+    expected: Type,
+) -> Result<Term> {
+    let location = call.location(db);
+    splicing(location, || {
+        db.thir_check(ctx, expand(db, ctx, call)?, expected)
+    })
+}
+
+/// Runs the elaboration of a splice, failing if the splices are nested too deep.
+fn splicing<T>(location: Location, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    if DEPTH.with(|depth| depth.get()) >= MAX_SPLICE_DEPTH {
+        return fail(SpliceDepthError {
+            limit: MAX_SPLICE_DEPTH,
+            location,
+        });
+    }
+
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _splicing = Splicing;
+
+    f()
+}
+
+/// Evaluates the spliced value at compile time, and reflects it back into the expression it
+/// holds.
+fn expand(db: &dyn ThirLoweringDb, ctx: Context, call: CallExpr) -> Result<Expr> {
+    let value = call.arguments.into_iter().next().unwrap_or(Expr::Empty);
+    let term = db.thir_check(ctx, value, syntax_type(call.location(db)))?;
+    let value = db.thir_eval(ctx.locals(db), term)?;
+
+    reflect(db, ctx, value, call.location(db))
+}
+
+/// Reflects the quoted value back into the expression it holds, replacing its splices by the
+/// expressions their values hold.
+fn reflect(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    value: Value,
+    location: Location,
+) -> Result<Expr> {
+    match value.force(db)?.1 {
+        Value::Quote(expr, splices) => {
+            let splices = splices
+                .into_iter()
+                .map(|(location, value)| {
+                    let expr = reflect(db, ctx, value, location.clone())?;
+                    Ok((location, expr))
+                })
+                .collect::<Result<_>>()?;
+
+            Ok(expr.rewrite(db, &mut SpliceRewriter { splices }))
+        }
+        actual => fail(StuckSpliceError {
+            actual: display::show_value(db, ctx, actual)?,
+            location,
+        }),
+    }
+}
@@ -0,0 +1,220 @@
+//! Defines the elaboration of the tuples, their projections, and their destructuring, like in:
+//!
+//! ```sol
+//! pair : (Int, Bool) = (1, true)
+//!
+//! first : Int = pair.0
+//!
+//! second : Bool = let (_, b) = pair in b
+//! ```
+//!
+//! The tuples are records whose fields are named by their positions, so `(Int, Bool)` is the
+//! record type `{0 : Int, 1 : Bool}`, and the projections, like `pair.0`, are projections of
+//! the fields at the positions.
+
+use sol_diagnostic::{fail, Result};
+use sol_hir::source::{
+    expr::CallExpr,
+    pattern::{Constructor as PatternConstructor, ConstructorPattern},
+};
+use sol_thir::{debruijin::Index, display, source::is_tuple, ElaboratedTerm};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("expected a tuple, got the type: `{actual}`")]
+#[diagnostic(code(sol::thir::not_a_tuple))]
+pub struct NotATupleError {
+    pub actual: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the tuple has {arity} elements, so it has no element at the position {position}")]
+#[diagnostic(code(sol::thir::tuple_position))]
+pub struct TuplePositionError {
+    pub position: usize,
+    pub arity: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the pattern expects a tuple of {expected} elements, got {arity}")]
+#[diagnostic(code(sol::thir::tuple_arity))]
+pub struct TupleArityError {
+    pub expected: usize,
+    pub arity: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Gets the elements of the tuple type, failing if the type isn't a tuple.
+fn elements(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    type_repr: Type,
+    location: Location,
+) -> Result<Vec<(String, Type)>> {
+    match type_repr.clone().force(db)?.1 {
+        Value::RecordType(fields) if is_tuple(&fields) => Ok(fields),
+        _ => fail(NotATupleError {
+            actual: display::show_value(db, ctx, type_repr)?,
+            location,
+        }),
+    }
+}
+
+/// Infers the type of the tuple, like `(1, true)`, from the types of its elements.
+pub fn infer_tuple(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let mut terms = vec![];
+    let mut types = vec![];
+    for (position, element) in call.arguments.into_iter().enumerate() {
+        let ElaboratedTerm(term, element_type) = db.thir_infer(ctx, element)?;
+        terms.push((position.to_string(), term));
+        types.push((position.to_string(), element_type));
+    }
+
+    Ok(ElaboratedTerm(
+        Term::Record(terms),
+        Value::RecordType(types),
+    ))
+}
+
+/// CASE: tuple
+///
+/// Checks the elements of the tuple against the types of the elements of the expected type,
+/// that has the same arity.
+pub fn check_tuple(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    call: CallExpr,
+    element_types: Vec<(String, Type)>,
+) -> Result<Term> {
+    let terms = call
+        .arguments
+        .into_iter()
+        .zip(element_types)
+        .map(|(element, (name, element_type))| {
+            Ok((name, db.thir_check(ctx, element, element_type)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Term::Record(terms))
+}
+
+/// CASE: tuple type
+///
+/// Checks the tuple against the universe, so it's the tuple type of its elements, like
+/// `(Int, Bool)`.
+pub fn check_tuple_type(db: &dyn ThirLoweringDb, ctx: Context, call: CallExpr) -> Result<Term> {
+    let types = call
+        .arguments
+        .into_iter()
+        .enumerate()
+        .map(|(position, element)| {
+            Ok((position.to_string(), db.thir_check(ctx, element, Value::U)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Term::RecordType(types))
+}
+
+/// Infers the type of the projection of the tuple, like `pair.0`, that is the type of the
+/// element at the position.
+pub fn infer_projection(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    position: usize,
+    call: CallExpr,
+) -> Result<ElaboratedTerm> {
+    let tuple = call.arguments.into_iter().next().unwrap_or(Expr::Empty);
+    let ElaboratedTerm(term, tuple_type) = db.thir_infer(ctx, tuple)?;
+    let mut elements = elements(db, ctx, tuple_type, call.location(db))?;
+    if position >= elements.len() {
+        return fail(TuplePositionError {
+            position,
+            arity: elements.len(),
+            location: call.location(db),
+        });
+    }
+
+    let (name, element_type) = elements.swap_remove(position);
+
+    Ok(ElaboratedTerm(
+        Term::Project(term.into(), name, position),
+        element_type,
+    ))
+}
+
+/// Infers the type of the destructuring of the tuple, like `let (a, b) = pair in body`. It's
+/// elaborated into nested redexes, like `(λp. (λa. (λb. body) p.1) p.0) pair`, so the tuple is
+/// evaluated only once. The elements are only bound to names, or ignored.
+pub fn infer_let_tuple(
+    db: &dyn ThirLoweringDb,
+    ctx: Context,
+    pattern: ConstructorPattern,
+    value: Expr,
+    body: Expr,
+) -> Result<ElaboratedTerm> {
+    debug_assert!(pattern.name == PatternConstructor::Tuple);
+
+    let location = pattern.location(db);
+    let ElaboratedTerm(value, value_type) = db.thir_infer(ctx, value)?;
+    let elements = elements(db, ctx, value_type.clone(), location.clone())?;
+    if elements.len() != pattern.arguments.len() {
+        return fail(TupleArityError {
+            expected: pattern.arguments.len(),
+            arity: elements.len(),
+            location,
+        });
+    }
+
+    let binder = extract_parameter_definition(db, Pattern::Hole);
+    let mut body_ctx = ctx.create_new_value(db, binder, value_type);
+    let mut bindings = vec![];
+    for (position, (pattern, (name, element_type))) in
+        pattern.arguments.into_iter().zip(elements).enumerate()
+    {
+        if !matches!(
+            pattern,
+            Pattern::Binding(_) | Pattern::Wildcard(_) | Pattern::Hole
+        ) {
+            return fail(infer::UnsupportedTermError {
+                location: pattern.location(db),
+            });
+        }
+
+        // The tuple is below the elements that are already bound.
+        let definition = extract_parameter_definition(db, pattern);
+        let projection = Term::Project(Term::Var(Index(position), None).into(), name, position);
+        bindings.push((definition, projection));
+        body_ctx = body_ctx.create_new_value(db, definition, element_type);
+    }
+
+    let ElaboratedTerm(body, body_type) = db.thir_infer(body_ctx, body)?;
+    let body = bindings
+        .into_iter()
+        .rev()
+        .fold(body, |body, (definition, projection)| {
+            let lam = Term::Lam(definition, Implicitness::Explicit, body.into());
+            Term::App(lam.into(), projection.into())
+        });
+    let lam = Term::Lam(binder, Implicitness::Explicit, body.into());
+
+    Ok(ElaboratedTerm(
+        Term::App(lam.into(), value.into()),
+        body_type,
+    ))
+}
@@ -0,0 +1,136 @@
+//! Defines the alpha-equivalence of the terms and values. The variables are De Bruijn indices,
+//! so the terms are alpha-equivalent if they are the same, ignoring the names of the binders,
+//! the locations, and the glued names, like `|x| x` and `|y| y`.
+//!
+//! The solved meta variables are compared by their solutions, and the unsolved ones by their
+//! identities, so the elaborated terms can be compared before, and after the solutions.
+
+use crate::{case_tree::CaseTree, shared::ConstructorKind};
+
+use super::*;
+
+impl Term {
+    /// Checks if the terms are alpha-equivalent, the terms are in a context at the level, that
+    /// is used to quote the solutions of the meta variables.
+    pub fn alpha_eq(&self, db: &dyn ThirDb, lvl: Level, other: &Term) -> bool {
+        alpha_eq(db, lvl.value(db), self, other)
+    }
+}
+
+impl Value {
+    /// Checks if the values are alpha-equivalent, by quoting them at the level, and comparing
+    /// their terms.
+    pub fn alpha_eq(&self, db: &dyn ThirDb, lvl: Level, other: &Value) -> bool {
+        match (
+            db.thir_quote(lvl, self.clone()),
+            db.thir_quote(lvl, other.clone()),
+        ) {
+            (Ok(lhs), Ok(rhs)) => lhs.alpha_eq(db, lvl, &rhs),
+            _ => false,
+        }
+    }
+}
+
+fn alpha_eq(db: &dyn ThirDb, lvl: usize, lhs: &Term, rhs: &Term) -> bool {
+    let solution = |term: &Term| match term {
+        Term::InsertedMeta(meta) => meta
+            .get()
+            .and_then(|value| db.thir_quote(Level::new(db, lvl), value).ok()),
+        _ => None,
+    };
+
+    if let Some(lhs) = solution(lhs) {
+        return alpha_eq(db, lvl, &lhs, rhs);
+    }
+    if let Some(rhs) = solution(rhs) {
+        return alpha_eq(db, lvl, lhs, &rhs);
+    }
+
+    match (lhs, rhs) {
+        (Term::Location(_, lhs) | Term::Glued(_, _, lhs), rhs) => alpha_eq(db, lvl, lhs, rhs),
+        (lhs, Term::Location(_, rhs) | Term::Glued(_, _, rhs)) => alpha_eq(db, lvl, lhs, rhs),
+        (Term::InsertedMeta(lhs), Term::InsertedMeta(rhs)) => lhs == rhs,
+        (Term::U, Term::U) | (Term::Prop, Term::Prop) | (Term::Refl, Term::Refl) => true,
+        (Term::Sorry(..), Term::Sorry(..)) => true,
+        (Term::Panic(lhs, _), Term::Panic(rhs, _)) => lhs == rhs,
+        (Term::Var(lhs, _), Term::Var(rhs, _)) => lhs == rhs,
+        (Term::Constructor(lhs), Term::Constructor(rhs)) => match (&lhs.kind, &rhs.kind) {
+            (ConstructorKind::Reference(lhs), ConstructorKind::Reference(rhs)) => {
+                lhs.definition(db) == rhs.definition(db)
+            }
+            (lhs, rhs) => lhs == rhs,
+        },
+        (Term::Lam(_, icit_a, a), Term::Lam(_, icit_b, b)) => {
+            icit_a == icit_b && alpha_eq(db, lvl + 1, a, b)
+        }
+        (Term::Pi(_, icit_a, dom_a, cod_a), Term::Pi(_, icit_b, dom_b, cod_b)) => {
+            icit_a == icit_b
+                && alpha_eq(db, lvl, dom_a, dom_b)
+                && alpha_eq(db, lvl + 1, cod_a, cod_b)
+        }
+        (Term::App(f, a), Term::App(g, b))
+        | (Term::Ann(f, a), Term::Ann(g, b))
+        | (Term::Transport(f, a), Term::Transport(g, b)) => {
+            alpha_eq(db, lvl, f, g) && alpha_eq(db, lvl, a, b)
+        }
+        (Term::Eq(a, x, y), Term::Eq(b, z, w)) => {
+            alpha_eq(db, lvl, a, b) && alpha_eq(db, lvl, x, z) && alpha_eq(db, lvl, y, w)
+        }
+        (Term::Irrelevant(lhs), Term::Irrelevant(rhs))
+        | (Term::ArrayType(lhs), Term::ArrayType(rhs)) => alpha_eq(db, lvl, lhs, rhs),
+        (Term::Array(lhs), Term::Array(rhs)) => all_alpha_eq(db, lvl, lhs, rhs),
+        (Term::Intrinsic(a, lhs, _), Term::Intrinsic(b, rhs, _)) => {
+            a == b && all_alpha_eq(db, lvl, lhs, rhs)
+        }
+        (Term::Quote(a, lhs), Term::Quote(b, rhs)) => {
+            a == b
+                && lhs.len() == rhs.len()
+                && lhs
+                    .iter()
+                    .zip(rhs)
+                    .all(|((_, lhs), (_, rhs))| alpha_eq(db, lvl, lhs, rhs))
+        }
+        (Term::Project(lhs, name_a, i), Term::Project(rhs, name_b, j)) => {
+            name_a == name_b && i == j && alpha_eq(db, lvl, lhs, rhs)
+        }
+        (Term::Match(lhs, tree_a), Term::Match(rhs, tree_b)) => {
+            alpha_eq(db, lvl, lhs, rhs) && alpha_eq_trees(db, lvl, tree_a, tree_b)
+        }
+        (Term::Record(lhs), Term::Record(rhs)) | (Term::RecordType(lhs), Term::RecordType(rhs)) => {
+            lhs.len() == rhs.len()
+                && lhs
+                    .iter()
+                    .zip(rhs)
+                    .all(|((name_a, a), (name_b, b))| name_a == name_b && alpha_eq(db, lvl, a, b))
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the case trees have the same shape, and their terms are alpha-equivalent.
+fn alpha_eq_trees(db: &dyn ThirDb, lvl: usize, lhs: &CaseTree, rhs: &CaseTree) -> bool {
+    match (lhs, rhs) {
+        (CaseTree::Leaf(lhs), CaseTree::Leaf(rhs)) => alpha_eq(db, lvl, lhs, rhs),
+        (CaseTree::Test(a, then_a, else_a), CaseTree::Test(b, then_b, else_b)) => {
+            a.kind == b.kind
+                && alpha_eq_trees(db, lvl, then_a, then_b)
+                && alpha_eq_trees(db, lvl, else_a, else_b)
+        }
+        (CaseTree::Guard(a, then_a, else_a), CaseTree::Guard(b, then_b, else_b)) => {
+            alpha_eq(db, lvl, a, b)
+                && alpha_eq_trees(db, lvl, then_a, then_b)
+                && alpha_eq_trees(db, lvl, else_a, else_b)
+        }
+        (CaseTree::Fail(_), CaseTree::Fail(_)) => true,
+        _ => false,
+    }
+}
+
+/// Checks if the terms are pairwise alpha-equivalent, like the items of two arrays.
+fn all_alpha_eq(db: &dyn ThirDb, lvl: usize, lhs: &[Term], rhs: &[Term]) -> bool {
+    lhs.len() == rhs.len()
+        && lhs
+            .iter()
+            .zip(rhs)
+            .all(|(lhs, rhs)| alpha_eq(db, lvl, lhs, rhs))
+}
@@ -0,0 +1,94 @@
+//! Defines the case trees, that the match expressions are compiled into. The arms are tested in
+//! order, like in:
+//!
+//! ```sol
+//! sign : Int -> Int = |n| match n {
+//!   0 => {
+//!     0
+//!   }
+//!   n if n > 0 => {
+//!     1
+//!   }
+//!   _ => {
+//!     -1
+//!   }
+//! }
+//! ```
+//!
+//! The tests of the literals are the branches of the tree, and the guards are branches too, that
+//! fall through to the arms below them when they don't hold. So a guarded arm never covers its
+//! pattern, and the tree has a [`CaseTree::Fail`] leaf if the match isn't exhaustive.
+
+use crate::shared::Constructor;
+
+use super::*;
+
+/// A case tree of a [`Term::Match`], its terms are in the same context as the match.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum CaseTree {
+    /// The body of an arm, that is reached when its pattern matches, and its guard holds.
+    Leaf(Term),
+
+    /// Tests if the scrutinee is the literal, going to the first tree if it is, and to the
+    /// second one otherwise.
+    Test(Constructor, Box<CaseTree>, Box<CaseTree>),
+
+    /// Tests the guard of an arm, going to the first tree if it holds, and falling through to
+    /// the second one otherwise.
+    Guard(Term, Box<CaseTree>, Box<CaseTree>),
+
+    /// No arm matches the scrutinee, it's only reachable if the match isn't exhaustive.
+    Fail(Location),
+}
+
+impl CaseTree {
+    /// Gets the terms of the tree, the guards and the bodies of the arms.
+    pub fn terms(&self) -> Vec<&Term> {
+        match self {
+            CaseTree::Leaf(term) => vec![term],
+            CaseTree::Test(_, then, otherwise) => {
+                then.terms().into_iter().chain(otherwise.terms()).collect()
+            }
+            CaseTree::Guard(guard, then, otherwise) => std::iter::once(guard)
+                .chain(then.terms())
+                .chain(otherwise.terms())
+                .collect(),
+            CaseTree::Fail(_) => vec![],
+        }
+    }
+
+    /// Transforms the terms of the tree, keeping its shape.
+    pub fn map(self, f: &mut impl FnMut(Term) -> Term) -> CaseTree {
+        self.try_map(&mut |term| Ok::<_, std::convert::Infallible>(f(term)))
+            .unwrap_or_else(|never| match never {})
+    }
+
+    /// Transforms the terms of the tree, like [`CaseTree::map`], stopping at the first error.
+    pub fn try_map<E>(self, f: &mut impl FnMut(Term) -> Result<Term, E>) -> Result<CaseTree, E> {
+        Ok(match self {
+            CaseTree::Leaf(term) => CaseTree::Leaf(f(term)?),
+            CaseTree::Test(constructor, then, otherwise) => CaseTree::Test(
+                constructor,
+                then.try_map(f)?.into(),
+                otherwise.try_map(f)?.into(),
+            ),
+            CaseTree::Guard(guard, then, otherwise) => CaseTree::Guard(
+                f(guard)?,
+                then.try_map(f)?.into(),
+                otherwise.try_map(f)?.into(),
+            ),
+            CaseTree::Fail(location) => CaseTree::Fail(location),
+        })
+    }
+
+    /// Checks if the tree has a [`CaseTree::Fail`] leaf, so the match isn't exhaustive.
+    pub fn is_partial(&self) -> bool {
+        match self {
+            CaseTree::Leaf(_) => false,
+            CaseTree::Test(_, then, otherwise) | CaseTree::Guard(_, then, otherwise) => {
+                then.is_partial() || otherwise.is_partial()
+            }
+            CaseTree::Fail(_) => true,
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Defines the hash-consing of the terms and values, and the cache of the conversion checking.
+//! The large types, like the record types, and the types of the derived code, are usually
+//! unified many times against identical types, so the values are interned, and the results of
+//! their conversions are cached by the database, instead of being compared again.
+//!
+//! The values with meta variables aren't cached, as their unification solves the meta
+//! variables, and the solutions are side effects that a cached result would skip.
+
+use super::*;
+use crate::value::Closure;
+
+/// Hash-consed term, the identical terms are the same id.
+#[salsa::interned]
+pub struct InternedTerm {
+    #[return_ref]
+    pub term: Term,
+}
+
+/// Hash-consed value, the identical values are the same id.
+#[salsa::interned]
+pub struct InternedValue {
+    #[return_ref]
+    pub value: Value,
+}
+
+/// Checks the conversion of the values, it's cached by the values, and by the context, that
+/// has the level where the conversion is happening.
+#[salsa::tracked]
+pub fn conversion(
+    db: &dyn ThirDb,
+    ctx: Context,
+    lhs: InternedValue,
+    rhs: InternedValue,
+) -> sol_diagnostic::Result<()> {
+    lhs.value(db)
+        .clone()
+        .unify_uncached(db, ctx, rhs.value(db).clone())
+}
+
+/// Checks if the term has meta variables, or errors, that can't be cached. It's cached by the
+/// hash-consed bodies of the closures.
+#[salsa::tracked]
+pub fn term_has_metas(db: &dyn ThirDb, term: InternedTerm) -> bool {
+    has_metas(term.term(db))
+}
+
+fn has_metas(term: &Term) -> bool {
+    match term {
+        Term::InsertedMeta(_) | Term::Sorry(..) => true,
+        Term::Lam(_, _, term)
+        | Term::Location(_, term)
+        | Term::Project(term, _, _)
+        | Term::Irrelevant(term)
+        | Term::ArrayType(term) => has_metas(term),
+        Term::App(a, b) | Term::Pi(_, _, a, b) | Term::Ann(a, b) | Term::Transport(a, b) => {
+            has_metas(a) || has_metas(b)
+        }
+        Term::Eq(a, b, c) => has_metas(a) || has_metas(b) || has_metas(c),
+        Term::Glued(_, spine, term) => spine.iter().any(has_metas) || has_metas(term),
+        Term::Quote(_, splices) => splices.iter().any(|(_, term)| has_metas(term)),
+        Term::Array(terms) | Term::Intrinsic(_, terms, _) => terms.iter().any(has_metas),
+        Term::Match(scrutinee, tree) => {
+            has_metas(scrutinee) || tree.terms().into_iter().any(has_metas)
+        }
+        Term::Record(fields) | Term::RecordType(fields) => {
+            fields.iter().any(|(_, term)| has_metas(term))
+        }
+        Term::U | Term::Prop | Term::Refl | Term::Var(..) | Term::Constructor(_) => false,
+        Term::Panic(..) => false,
+    }
+}
+
+impl Value {
+    /// Checks if the value has meta variables, even the solved ones, so the conversions that
+    /// can solve them aren't cached.
+    pub fn has_metas(&self, db: &dyn ThirDb) -> bool {
+        match self {
+            Value::Flexible(..) => true,
+            Value::Rigid(_, spine) => spine.iter().any(|value| value.has_metas(db)),
+            Value::Pi(pi) => pi.domain.has_metas(db) || pi.codomain.has_metas(db),
+            Value::Lam(_, _, closure) => closure.has_metas(db),
+            Value::Location(_, value)
+            | Value::Project(value, _, _)
+            | Value::Irrelevant(value)
+            | Value::ArrayType(value) => value.has_metas(db),
+            Value::Record(fields) | Value::RecordType(fields) => {
+                fields.iter().any(|(_, value)| value.has_metas(db))
+            }
+            Value::Eq(a, b, c) => a.has_metas(db) || b.has_metas(db) || c.has_metas(db),
+            Value::Transport(a, b) => a.has_metas(db) || b.has_metas(db),
+            Value::Glued(_, spine, value) => {
+                spine.iter().any(|value| value.has_metas(db)) || value.has_metas(db)
+            }
+            Value::Quote(_, splices) => splices.iter().any(|(_, value)| value.has_metas(db)),
+            Value::Array(values) | Value::Intrinsic(_, values, _) => {
+                values.iter().any(|value| value.has_metas(db))
+            }
+            Value::Match(scrutinee, env, tree) => {
+                scrutinee.has_metas(db)
+                    || env.values(db).iter().any(|value| value.has_metas(db))
+                    || tree.terms().into_iter().any(has_metas)
+            }
+            Value::U | Value::Prop | Value::Refl | Value::Constructor(_) => false,
+        }
+    }
+}
+
+impl Closure {
+    /// Checks if the environment, or the body of the closure have meta variables.
+    pub fn has_metas(&self, db: &dyn ThirDb) -> bool {
+        term_has_metas(db, self.expr) || self.env.values(db).iter().any(|value| value.has_metas(db))
+    }
+}
@@ -0,0 +1,365 @@
+//! Defines the pretty printer of the terms and values. The De Bruijn indices are converted back
+//! to the names of their binders, and the free variables are named by their references, or by
+//! their levels in the [`Context`], like in:
+//!
+//! ```hs
+//! {a : U} -> (x : a) -> Eq x x
+//! ```
+//!
+//! The parentheses are inserted by the precedence of the terms, and the inserted meta variables,
+//! that are the implicit arguments, are rendered as `{x}`.
+
+use salsa::DebugWithDb;
+use sol_hir::fmt::HirFormatter;
+
+use crate::{
+    case_tree::CaseTree,
+    debruijin::Index,
+    shared::{Constructor, ConstructorKind, Context, Implicitness},
+};
+
+use super::*;
+
+/// The precedence of the terms, the terms with lower precedence are parenthesized when they
+/// are inside of terms with higher precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    /// Lambdas, pi types, annotations and rewrites, that extend as far as possible.
+    Lowest,
+
+    /// Applications, that are left associative.
+    App,
+
+    /// Variables, constructors, records and projections.
+    Atom,
+}
+
+/// Shows the term in the context, its free variables are named by their references, or by
+/// their levels in the context.
+pub fn show_term(db: &dyn ThirDb, ctx: Context, term: &Term) -> String {
+    let mut printer = Printer {
+        db,
+        level: ctx.lvl(db).value(db),
+        names: vec![],
+    };
+
+    printer.term(term, Precedence::Lowest)
+}
+
+/// Shows the value in the context, by quoting it at the level of the context.
+pub fn show_value(db: &dyn ThirDb, ctx: Context, value: Value) -> sol_diagnostic::Result<String> {
+    let term = db.thir_quote(ctx.lvl(db), value)?;
+
+    Ok(show_term(db, ctx, &term))
+}
+
+struct Printer<'db> {
+    db: &'db dyn ThirDb,
+
+    /// The level of the context, the variables above the binders of the term are named by it.
+    level: usize,
+
+    /// The names of the binders of the term, the innermost binder is the last one.
+    names: Vec<String>,
+}
+
+impl Printer<'_> {
+    fn term(&mut self, term: &Term, precedence: Precedence) -> String {
+        match term {
+            Term::U => "U".into(),
+            Term::Prop => "Prop".into(),
+            Term::Refl => "refl".into(),
+            Term::Sorry(..) => "sorry".into(),
+            Term::Panic(message, _) => format!("panic {message:?}"),
+            Term::Var(index, reference) => self.variable(*index, *reference),
+            Term::Constructor(constructor) => self.constructor(constructor),
+            Term::InsertedMeta(meta) => match meta.get() {
+                Some(value) => match self.db.thir_quote(Level::new(self.db, self.depth()), value) {
+                    Ok(term) => self.term(&term, precedence),
+                    Err(_) => "?".into(),
+                },
+                None => "?".into(),
+            },
+            Term::Location(_, term) | Term::Irrelevant(term) => self.term(term, precedence),
+            Term::Quote(expr, _) => {
+                let db: &dyn HirDb = self.db;
+                format!("quote {{ {:?} }}", expr.formatter().debug(db))
+            }
+            Term::Lam(..) => parenthesize(precedence > Precedence::Lowest, self.lam(term)),
+            Term::Pi(name, implicitness, domain, codomain) => {
+                let binder = self.fresh(name.map_or("_".into(), |name| name.to_string(self.db)));
+                let domain = match (name, implicitness) {
+                    (None, Implicitness::Explicit) => self.term(domain, Precedence::App),
+                    (_, Implicitness::Implicit) => {
+                        format!("{{{binder} : {}}}", self.term(domain, Precedence::Lowest))
+                    }
+                    (_, Implicitness::Explicit) => {
+                        format!("({binder} : {})", self.term(domain, Precedence::Lowest))
+                    }
+                };
+
+                self.names.push(binder);
+                let codomain = self.term(codomain, Precedence::Lowest);
+                self.names.pop();
+
+                parenthesize(
+                    precedence > Precedence::Lowest,
+                    format!("{domain} -> {codomain}"),
+                )
+            }
+            Term::Ann(value, type_repr) => {
+                let value = self.term(value, Precedence::App);
+                let type_repr = self.term(type_repr, Precedence::App);
+
+                parenthesize(
+                    precedence > Precedence::Lowest,
+                    format!("{value} is {type_repr}"),
+                )
+            }
+            Term::Transport(equality, value) => {
+                let equality = self.term(equality, Precedence::Lowest);
+                let value = self.term(value, Precedence::Lowest);
+
+                parenthesize(
+                    precedence > Precedence::Lowest,
+                    format!("rewrite {equality} in {value}"),
+                )
+            }
+            Term::App(..) => {
+                let (head, arguments) = term.spine();
+                let head = self.term(head, Precedence::App);
+                let arguments = arguments
+                    .into_iter()
+                    .map(|argument| self.argument(argument))
+                    .collect();
+
+                application(head, arguments, precedence)
+            }
+            Term::Eq(_, lhs, rhs) => {
+                let arguments = vec![
+                    self.term(lhs, Precedence::Atom),
+                    self.term(rhs, Precedence::Atom),
+                ];
+
+                application("Eq".into(), arguments, precedence)
+            }
+            Term::Glued(constructor, spine, _) => {
+                let arguments = spine
+                    .iter()
+                    .map(|argument| self.argument(argument))
+                    .collect();
+
+                application(self.constructor(constructor), arguments, precedence)
+            }
+            Term::Record(fields) => self.fields(fields, "="),
+            Term::RecordType(fields) => self.fields(fields, ":"),
+            Term::ArrayType(type_repr) => {
+                let arguments = vec![self.term(type_repr, Precedence::Atom)];
+
+                application("Array".into(), arguments, precedence)
+            }
+            Term::Array(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| self.term(item, Precedence::Lowest))
+                    .collect::<Vec<_>>();
+
+                format!("[{}]", items.join(", "))
+            }
+            Term::Intrinsic(intrinsic, arguments, _) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| self.term(argument, Precedence::Atom))
+                    .collect();
+
+                application(intrinsic.name().into(), arguments, precedence)
+            }
+            Term::Project(record, name, _) => {
+                format!("{}.{name}", self.term(record, Precedence::Atom))
+            }
+            Term::Match(scrutinee, tree) => {
+                let scrutinee = self.term(scrutinee, Precedence::App);
+
+                self.case_tree(&scrutinee, tree)
+            }
+        }
+    }
+
+    /// Shows the case tree as the match of its arms, the tests of the literals, and the guards,
+    /// like `match x { 0 => a, _ if p => b, _ => c }`. The trees below the tests are shown as
+    /// nested matches, on the same scrutinee.
+    fn case_tree(&mut self, scrutinee: &str, tree: &CaseTree) -> String {
+        let mut arms = vec![];
+        let mut next = tree;
+        loop {
+            match next {
+                CaseTree::Leaf(body) => {
+                    arms.push(format!("_ => {}", self.term(body, Precedence::Lowest)));
+                    break;
+                }
+                CaseTree::Test(constructor, then, otherwise) => {
+                    let then = self.branch(scrutinee, then);
+                    arms.push(format!("{} => {then}", self.constructor(constructor)));
+                    next = otherwise;
+                }
+                CaseTree::Guard(guard, then, otherwise) => {
+                    let guard = self.term(guard, Precedence::Lowest);
+                    let then = self.branch(scrutinee, then);
+                    arms.push(format!("_ if {guard} => {then}"));
+                    next = otherwise;
+                }
+                CaseTree::Fail(_) => break,
+            }
+        }
+
+        format!("match {scrutinee} {{ {} }}", arms.join(", "))
+    }
+
+    /// Shows the branch of a case tree, the leaves are shown by their bodies.
+    fn branch(&mut self, scrutinee: &str, tree: &CaseTree) -> String {
+        match tree {
+            CaseTree::Leaf(body) => self.term(body, Precedence::Lowest),
+            _ => self.case_tree(scrutinee, tree),
+        }
+    }
+
+    /// Shows the argument of an application, the inserted meta variables are the implicit
+    /// arguments, so they are shown between braces.
+    fn argument(&mut self, argument: &Term) -> String {
+        match argument {
+            Term::InsertedMeta(_) => format!("{{{}}}", self.term(argument, Precedence::Lowest)),
+            _ => self.term(argument, Precedence::Atom),
+        }
+    }
+
+    /// Shows the lambdas, with their consecutive parameters merged, like `|x y| x`.
+    fn lam(&mut self, term: &Term) -> String {
+        let mut parameters = vec![];
+        let mut body = term;
+        let mut bound = 0;
+        loop {
+            match body {
+                Term::Lam(name, implicitness, next) => {
+                    let name = self.fresh(name.to_string(self.db));
+                    parameters.push(match implicitness {
+                        Implicitness::Implicit => format!("{{{name}}}"),
+                        Implicitness::Explicit => name.clone(),
+                    });
+                    self.names.push(name);
+                    bound += 1;
+                    body = next;
+                }
+                Term::Location(_, next) => body = next,
+                _ => break,
+            }
+        }
+
+        let body = self.term(body, Precedence::Lowest);
+        self.names.truncate(self.names.len() - bound);
+
+        format!("|{}| {body}", parameters.join(" "))
+    }
+
+    fn fields(&mut self, fields: &[(String, Term)], separator: &str) -> String {
+        // The tuples are shown by their elements, like `(1, true)`.
+        if source::is_tuple(fields) {
+            let elements = fields
+                .iter()
+                .map(|(_, value)| self.term(value, Precedence::Lowest))
+                .collect::<Vec<_>>();
+
+            return match elements.as_slice() {
+                [element] => format!("({element},)"),
+                _ => format!("({})", elements.join(", ")),
+            };
+        }
+
+        let fields = fields
+            .iter()
+            .map(|(name, value)| {
+                format!(
+                    "{name} {separator} {}",
+                    self.term(value, Precedence::Lowest)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("{{{}}}", fields.join(", "))
+    }
+
+    fn variable(&self, Index(index): Index, reference: Option<Reference>) -> String {
+        if let Some(name) = self
+            .names
+            .len()
+            .checked_sub(index + 1)
+            .map(|i| &self.names[i])
+        {
+            return name.clone();
+        }
+
+        match reference {
+            Some(reference) => reference.definition(self.db).to_string(self.db),
+            None => match self.depth().checked_sub(index + 1) {
+                Some(level) => format!("x{level}"),
+                None => format!("#{index}"),
+            },
+        }
+    }
+
+    fn constructor(&self, constructor: &Constructor) -> String {
+        match &constructor.kind {
+            ConstructorKind::Unit => "()".into(),
+            ConstructorKind::UnitType => "Unit".into(),
+            ConstructorKind::True => "true".into(),
+            ConstructorKind::False => "false".into(),
+            ConstructorKind::BooleanType => "Bool".into(),
+            ConstructorKind::NatType => "Nat".into(),
+            ConstructorKind::Reference(reference) => {
+                reference.definition(self.db).to_string(self.db)
+            }
+            ConstructorKind::IntType(true, width) => format!("Int{width}"),
+            ConstructorKind::IntType(false, width) => format!("UInt{width}"),
+            ConstructorKind::Int(value) => value.to_string(),
+            ConstructorKind::IntegerType => "Integer".into(),
+            ConstructorKind::Nat(value) => format!("{value}n"),
+            ConstructorKind::Integer(value) => value.to_string(),
+            ConstructorKind::StringType => "String".into(),
+            ConstructorKind::String(value) => format!("{value:?}"),
+            ConstructorKind::SyntaxType => "Syntax".into(),
+            ConstructorKind::PrimitiveType(path) => path.to_string(self.db).unwrap_or_default(),
+        }
+    }
+
+    /// Renames the binder with primes, if its name is already bound, like `x'`, so the
+    /// shadowed variables can still be told apart. The anonymous binders are named `_`.
+    fn fresh(&self, mut name: String) -> String {
+        while name != "_" && self.names.contains(&name) {
+            name.push('\'');
+        }
+
+        name
+    }
+
+    /// The level of the innermost binder, counting the context and the binders of the term.
+    fn depth(&self) -> usize {
+        self.level + self.names.len()
+    }
+}
+
+/// Shows the head applied to the arguments, the heads without arguments are shown alone.
+fn application(head: String, arguments: Vec<String>, precedence: Precedence) -> String {
+    if arguments.is_empty() {
+        return head;
+    }
+
+    let application = format!("{head} {}", arguments.join(" "));
+    parenthesize(precedence > Precedence::App, application)
+}
+
+fn parenthesize(condition: bool, shown: String) -> String {
+    if condition {
+        format!("({shown})")
+    } else {
+        shown
+    }
+}
@@ -10,24 +10,32 @@ use salsa::DbWithJar;
 use sol_diagnostic::{fail, DiagnosticDb};
 use sol_hir::{
     lowering::HirLowering,
-    package::HasManifest,
-    primitives::PrimitiveProvider,
+    package::{HasManifest, Package},
+    primitives::{
+        initialize_primitive_bag, primitive_type_rep, Intrinsic, PrimitiveFunction,
+        PrimitiveProvider,
+    },
     solver::{Definition, Reference},
-    source::{expr::Expr, literal::Literal, HirSource, Location},
+    source::{expr::Expr, literal::Literal, HirPath, HirSource, Location},
     HirDb,
 };
 use sol_syntax::ParseDb;
 
 use crate::{
     debruijin::Level,
-    shared::{Constructor, ConstructorKind, Context, Env},
+    shared::{Constructor, ConstructorKind, Context, Env, GlobalEnv, Implicitness},
     source::Term,
     value::{Type, Value},
 };
 
 extern crate salsa_2022 as salsa;
 
+pub mod alpha;
+pub mod case_tree;
+pub mod conversion;
 pub mod debruijin;
+pub mod display;
+pub mod meta;
 pub mod sexpr;
 pub mod shared;
 pub mod source;
@@ -50,6 +58,13 @@ pub struct Jar(
     debruijin::Level,
     debruijin::Level_as_idx,
     debruijin::Level_increase,
+    conversion::InternedTerm,
+    conversion::InternedValue,
+    conversion::conversion,
+    conversion::term_has_metas,
+    meta::MetaContext,
+    meta::UnsolvedMetas,
+    meta::HoleGoals,
 );
 
 pub trait ThirDb:
@@ -85,6 +100,16 @@ pub type TypeTable = im::HashMap<Definition, (Term, Type)>;
 
 pub trait Typer {
     fn infer_type_table(&self, source: HirSource) -> sol_diagnostic::Result<TypeTable>;
+
+    /// Infers the type of a top-level definition of another file, it only depends on the
+    /// elaboration of its binding group, so editing the other definitions of the file doesn't
+    /// check its references again.
+    fn infer_definition_type(
+        &self,
+        global_env: GlobalEnv,
+        package: Package,
+        definition: Definition,
+    ) -> sol_diagnostic::Result<Option<(Term, Type)>>;
 }
 
 /// Represents the lowering functions for Low-Level Intermediate Representation.
@@ -169,6 +194,20 @@ pub struct CouldNotFindTypeOfDefinitionError {
     pub location: Location,
 }
 
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("could not find the primitive type: {name}")]
+#[diagnostic(
+    code(sol::thir::unknown_primitive_type),
+    help("the types of the primitive functions should be builtins, or registered primitive types")
+)]
+pub struct UnknownPrimitiveTypeError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
 pub fn infer_constructor(
     db: &dyn ThirDb,
     ctx: Context,
@@ -178,7 +217,10 @@ pub fn infer_constructor(
         ConstructorKind::UnitType
         | ConstructorKind::BooleanType
         | ConstructorKind::StringType
+        | ConstructorKind::SyntaxType
+        | ConstructorKind::PrimitiveType(_)
         | ConstructorKind::NatType
+        | ConstructorKind::IntegerType
         | ConstructorKind::IntType(_, _) => Type::U,
         ConstructorKind::Unit => Type::Constructor(Constructor {
             kind: ConstructorKind::UnitType,
@@ -196,6 +238,14 @@ pub fn infer_constructor(
             kind: ConstructorKind::IntType(true, 32),
             location: constructor.location,
         }),
+        ConstructorKind::Nat(_) => Type::Constructor(Constructor {
+            kind: ConstructorKind::NatType,
+            location: constructor.location,
+        }),
+        ConstructorKind::Integer(_) => Type::Constructor(Constructor {
+            kind: ConstructorKind::IntegerType,
+            location: constructor.location,
+        }),
         ConstructorKind::Reference(reference) => find_reference_type(db, ctx, reference)?.1,
     })
 }
@@ -207,21 +257,27 @@ pub fn find_reference_type(
     reference: Reference,
 ) -> sol_diagnostic::Result<(Term, Value)> {
     let definition = reference.definition(db);
-    let Some(src) = definition.location(db).source() else {
+    if let Some(function) = db.primitives().function(definition) {
+        return primitive_function_type(db, ctx, reference, function);
+    }
+    if db.primitives().operator(definition).is_some() {
+        return operator_type(db, ctx, reference);
+    }
+
+    if definition.location(db).source().is_none() {
         return fail(CouldNotFindLocationSourceError {
             location: reference.location(db),
         });
-    };
+    }
 
-    let type_table =
+    let elaborated_type =
         if definition.location(db).text_source() == reference.location(db).text_source() {
-            ctx.env(db).definitions(db)
+            ctx.env(db).definitions(db).get(&definition).cloned()
         } else {
-            let hir_src = db.hir_lower(ctx.pkg(db), src);
-            db.infer_type_table(hir_src)?
+            db.infer_definition_type(ctx.env(db), ctx.pkg(db), definition)?
         };
 
-    let Some(elaborated_type) = type_table.get(&definition).cloned() else {
+    let Some(elaborated_type) = elaborated_type else {
         return fail(CouldNotFindTypeOfDefinitionError {
             name: definition.name(db).to_string(db).unwrap(),
             location: reference.location(db),
@@ -230,3 +286,81 @@ pub fn find_reference_type(
 
     Ok(elaborated_type)
 }
+
+/// Elaborates the type of a built-in operator, like `+`, that is `{a : U} -> a -> a -> a`, so it
+/// can be applied to any of the integer types. The applications to other types are kept
+/// symbolically, as they can't be folded.
+fn operator_type(
+    db: &dyn ThirDb,
+    ctx: Context,
+    reference: Reference,
+) -> sol_diagnostic::Result<(Term, Value)> {
+    let var = |index| Term::Var(debruijin::Index(index), None);
+    let binary = Term::Pi(
+        None,
+        Implicitness::Explicit,
+        var(0).into(),
+        Term::Pi(None, Implicitness::Explicit, var(1).into(), var(2).into()).into(),
+    );
+    let type_repr = Term::Pi(None, Implicitness::Implicit, Term::U.into(), binary.into());
+
+    let constructor = Constructor {
+        kind: ConstructorKind::Reference(reference),
+        location: reference.location(db),
+    };
+
+    Ok((
+        Term::Constructor(constructor),
+        db.thir_eval(ctx.locals(db), type_repr)?,
+    ))
+}
+
+/// Elaborates the type of a primitive function registered by the embedder, from the names of
+/// the types of its parameters, and of its return type.
+fn primitive_function_type(
+    db: &dyn ThirDb,
+    ctx: Context,
+    reference: Reference,
+    function: PrimitiveFunction,
+) -> sol_diagnostic::Result<(Term, Value)> {
+    initialize_primitive_bag(db);
+
+    let location = reference.location(db);
+    let elaborate = |name: &String| {
+        let Some(type_rep) = primitive_type_rep(db, HirPath::create(db, name)) else {
+            return fail(UnknownPrimitiveTypeError {
+                name: name.clone(),
+                location: location.clone(),
+            });
+        };
+
+        db.thir_check(ctx, *type_rep.expr, Value::U)
+    };
+
+    // The parameters aren't dependent, so the types are closed, and don't need to be shifted.
+    let return_type = elaborate(&function.return_type)?;
+    let type_repr =
+        function
+            .parameters
+            .iter()
+            .rev()
+            .try_fold(return_type, |codomain, parameter| {
+                let domain = elaborate(parameter)?;
+                Ok(Term::Pi(
+                    None,
+                    Implicitness::Explicit,
+                    domain.into(),
+                    codomain.into(),
+                ))
+            })?;
+
+    let constructor = Constructor {
+        kind: ConstructorKind::Reference(reference),
+        location,
+    };
+
+    Ok((
+        Term::Constructor(constructor),
+        db.thir_eval(ctx.locals(db), type_repr)?,
+    ))
+}
@@ -0,0 +1,218 @@
+//! Defines the meta context, that stores the meta variables created while elaborating a
+//! definition, with their creation sites. The meta variables are created inside of the
+//! elaboration queries, so they're pushed into a shared store of the context, instead of
+//! setting the input.
+//!
+//! The unsolved meta variables of a definition are accumulated into [`UnsolvedMetas`] when its
+//! elaboration finishes, so they can be inspected, and reported, like in:
+//!
+//! ```hs
+//! f : _ -> Int
+//! f = |x| 10
+//! ```
+//!
+//! The unification problems that are blocked on unsolved meta variables, like `?f x = Int`,
+//! are postponed into the context, and retried when the meta variables are solved. The ones
+//! that are still blocked when the elaboration finishes are reported.
+
+use std::fmt::Display;
+
+use sol_diagnostic::{report_error, Diagnostics};
+
+use crate::shared::{Context, MetaVar};
+
+use super::*;
+
+/// The kind of the meta variable, used to report it if it's unsolved.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum MetaKind {
+    /// An implicit argument, or an inferred type, like the types of the parameters of the
+    /// lambdas.
+    Implicit,
+
+    /// A hole written in the source code, like `_`.
+    Hole,
+}
+
+impl Display for MetaKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetaKind::Implicit => write!(f, "implicit argument"),
+            MetaKind::Hole => write!(f, "hole"),
+        }
+    }
+}
+
+/// A meta variable, with its creation site.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct MetaEntry {
+    pub meta: MetaVar,
+    pub kind: MetaKind,
+    pub location: Location,
+}
+
+/// The unsolved meta variables of the elaboration of a definition.
+#[salsa::accumulator]
+pub struct UnsolvedMetas(MetaEntry);
+
+/// The goal of a hole written in the source code, that is its expected type, in the context
+/// where it's checked.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct HoleGoal {
+    pub location: Location,
+    pub ctx: Context,
+    pub goal: Type,
+}
+
+/// The goals of the holes of the elaboration of a definition, they're used to search the
+/// terms that fill the holes.
+#[salsa::accumulator]
+pub struct HoleGoals(HoleGoal);
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("unsolved {kind}")]
+#[diagnostic(
+    code(sol::thir::unsolved_meta),
+    help("the type can't be inferred, so it should be annotated")
+)]
+pub struct UnsolvedMetaError {
+    pub kind: MetaKind,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("couldn't solve the constraint `{lhs}` = `{rhs}`")]
+#[diagnostic(
+    code(sol::thir::unsolved_constraint),
+    help("the meta variables applied to arguments are only solved by other constraints")
+)]
+pub struct UnsolvedConstraintError {
+    pub lhs: String,
+    pub rhs: String,
+
+    #[source_code]
+    #[label = "in this definition"]
+    pub location: Location,
+}
+
+/// A unification problem that is blocked on an unsolved meta variable, it's retried when the
+/// meta variable is solved.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Constraint {
+    pub blocker: MetaVar,
+    pub ctx: Context,
+    pub lhs: Value,
+    pub rhs: Value,
+}
+
+/// The meta context of the elaboration of a definition.
+#[salsa::input]
+pub struct MetaContext {
+    #[return_ref]
+    pub store: shared::MetaStore,
+
+    /// The postponed unification problems.
+    #[return_ref]
+    pub constraints: shared::ConstraintStore,
+}
+
+impl MetaContext {
+    /// Creates an empty meta context, for a new elaboration.
+    pub fn empty(db: &dyn ThirDb) -> Self {
+        Self::new(
+            db,
+            /* store       = */ shared::MetaStore::new(vec![]),
+            /* constraints = */ shared::ConstraintStore::new(vec![]),
+        )
+    }
+
+    /// Creates a fresh meta variable, recording its creation site.
+    pub fn fresh(self, db: &dyn ThirDb, kind: MetaKind, location: Location) -> MetaVar {
+        let meta = MetaVar::new(None);
+        let mut entries = self.store(db).get();
+        entries.push(MetaEntry {
+            meta: meta.clone(),
+            kind,
+            location,
+        });
+        self.store(db).update(entries);
+        meta
+    }
+
+    /// Gets the meta variables that weren't solved, in their creation order.
+    pub fn unsolved(self, db: &dyn ThirDb) -> Vec<MetaEntry> {
+        self.store(db)
+            .get()
+            .into_iter()
+            .filter(|entry| entry.meta.get().is_none())
+            .collect()
+    }
+
+    /// Postpones the unification problem, until its blocker is solved.
+    pub fn postpone(self, db: &dyn ThirDb, constraint: Constraint) {
+        let mut constraints = self.constraints(db).get();
+        constraints.push(constraint);
+        self.constraints(db).update(constraints);
+    }
+
+    /// Retries the postponed problems whose blockers were solved, until there's no progress,
+    /// as retrying them can solve the blockers of the other ones.
+    pub fn retry(self, db: &dyn ThirDb) {
+        loop {
+            let (ready, blocked): (Vec<_>, Vec<_>) = self
+                .constraints(db)
+                .get()
+                .into_iter()
+                .partition(|constraint| constraint.blocker.get().is_some());
+            if ready.is_empty() {
+                return;
+            }
+
+            // The retried problems can be postponed again, so the store is updated before.
+            self.constraints(db).update(blocked);
+            for constraint in ready {
+                let Constraint { ctx, lhs, rhs, .. } = constraint;
+                if let Err(diagnostic) = lhs.unify_uncached(db, ctx, rhs) {
+                    Diagnostics::push(db, diagnostic);
+                }
+            }
+        }
+    }
+
+    /// Accumulates the unsolved meta variables, and reports them with the problems that are
+    /// still blocked, when the elaboration of the definition at the location finishes.
+    pub fn finish(self, db: &dyn ThirDb, location: Location) {
+        self.retry(db);
+
+        let constraints = self.constraints(db).get();
+        for constraint in constraints.iter() {
+            let Constraint { ctx, lhs, rhs, .. } = constraint.clone();
+            report_error(
+                db,
+                UnsolvedConstraintError {
+                    lhs: display::show_value(db, ctx, lhs).unwrap_or_default(),
+                    rhs: display::show_value(db, ctx, rhs).unwrap_or_default(),
+                    location: location.clone(),
+                },
+            );
+        }
+
+        for entry in self.unsolved(db) {
+            // The blockers of the reported problems aren't reported again.
+            let blocker = constraints.iter().any(|constraint| constraint.blocker == entry.meta);
+            if !blocker {
+                report_error(
+                    db,
+                    UnsolvedMetaError {
+                        kind: entry.kind,
+                        location: entry.location.clone(),
+                    },
+                );
+            }
+            UnsolvedMetas::push(db, entry);
+        }
+    }
+}
@@ -4,6 +4,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use num_bigint::{BigInt, BigUint};
 use sol_hir::package::Package;
 
 use self::debruijin::Index;
@@ -27,6 +28,9 @@ pub struct Context {
     pub locals: Env,
     pub env: GlobalEnv,
     pub pkg: Package,
+
+    /// The meta variables created by the elaboration, it's shared by the nested contexts.
+    pub metas: meta::MetaContext,
 }
 
 impl Context {
@@ -37,6 +41,7 @@ impl Context {
             Env::new(db, VecDeque::new()),
             env,
             pkg,
+            meta::MetaContext::empty(db),
         )
     }
 }
@@ -46,7 +51,14 @@ impl Context {
     #[salsa::tracked]
     pub fn increase_level(self, db: &dyn ThirDb) -> Context {
         let lvl = self.lvl(db).increase(db);
-        Context::new(db, lvl, self.locals(db), self.env(db), self.pkg(db))
+        Context::new(
+            db,
+            lvl,
+            self.locals(db),
+            self.env(db),
+            self.pkg(db),
+            self.metas(db),
+        )
     }
 
     #[salsa::tracked]
@@ -114,8 +126,20 @@ pub enum ConstructorKind {
     Reference(Reference),
     IntType(bool, isize),
     Int(isize),
+
+    /// The arbitrary-precision integers, they're folded without overflowing, and the natural
+    /// numbers saturate at zero.
+    IntegerType,
+    Nat(BigUint),
+    Integer(BigInt),
     StringType,
     String(String),
+
+    /// The type of the quoted expressions, see [`crate::source::Term::Quote`].
+    SyntaxType,
+
+    /// An opaque type registered by the embedder, see [`sol_hir::primitives::PrimitiveBag`].
+    PrimitiveType(HirPath),
 }
 
 impl From<Literal> for ConstructorKind {
@@ -130,6 +154,7 @@ impl From<Literal> for ConstructorKind {
             Literal::UInt32(value) => ConstructorKind::Int(value as _),
             Literal::Int64(value) => ConstructorKind::Int(value as _),
             Literal::UInt64(value) => ConstructorKind::Int(value as _),
+            Literal::Nat(value) => ConstructorKind::Nat(value),
             Literal::String(string) => ConstructorKind::String(string),
             Literal::Boolean(true) => ConstructorKind::True,
             Literal::Boolean(false) => ConstructorKind::False,
@@ -187,3 +212,11 @@ macro_rules! mutable_reference {
 mutable_reference! {
   struct MetaVar = Option<Value>
 }
+
+mutable_reference! {
+  struct MetaStore = Vec<meta::MetaEntry>
+}
+
+mutable_reference! {
+  struct ConstraintStore = Vec<meta::Constraint>
+}
@@ -7,6 +7,9 @@ pub type Expr = Box<Term>;
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Term {
     U,
+
+    /// The universe of the propositions, it's a type, like [`Term::U`].
+    Prop,
     Var(debruijin::Index, Option<Reference>),
     Lam(Definition, shared::Implicitness, Expr),
     App(Expr, Expr),
@@ -16,6 +19,68 @@ pub enum Term {
     Location(Location, Expr),
     Sorry(Location, Option<ThirError>),
     InsertedMeta(shared::MetaVar),
+
+    /// An anonymous record, with its fields sorted by their names.
+    Record(Vec<(String, Term)>),
+
+    /// A structural record type, with the types of its fields sorted by their names.
+    ///
+    /// The tuples are records whose fields are named by their positions, like `(Int, Bool)`
+    /// is `{0 : Int, 1 : Bool}`, see [`is_tuple`].
+    RecordType(Vec<(String, Term)>),
+
+    /// Projects a field of a record, by its name, and its position in the sorted fields.
+    Project(Expr, String, usize),
+
+    /// The propositional equality type, between two values of the type, like `Eq x y`.
+    Eq(Expr, Expr, Expr),
+
+    /// The reflexivity proof of the equality type.
+    Refl,
+
+    /// Transports a value along an equality, it's the J eliminator of the equality type, that
+    /// reduces to the value when the equality is [`Term::Refl`].
+    Transport(Expr, Expr),
+
+    /// A proof of a proposition, that is irrelevant, so it's equal to any other proof of the
+    /// same proposition, and it's erased in the code generation.
+    Irrelevant(Expr),
+
+    /// A top-level name applied to its spine, with its unfolded term, that is already applied
+    /// to the spine. The name is used to print the term, and the unfolded term to evaluate it.
+    Glued(shared::Constructor, Vec<Term>, Expr),
+
+    /// A quoted expression, with the splices inside of it, by their locations. The splices are
+    /// evaluated with the quote, and replaced by the expressions they hold.
+    Quote(sol_hir::source::expr::Expr, Vec<(Location, Term)>),
+
+    /// Aborts the program with the message, like when an `assert` fails. It can't be evaluated
+    /// at compile time, so the evaluation fails with the message.
+    Panic(String, Location),
+
+    /// The type of the arrays of the type, like `Array Int`.
+    ArrayType(Expr),
+
+    /// An array literal, like `[1, 2, 3]`.
+    Array(Vec<Term>),
+
+    /// A saturated application of an intrinsic, like `Array.length xs`, with the location of
+    /// the application, to report its failures.
+    Intrinsic(Intrinsic, Vec<Term>, Location),
+
+    /// A match expression, with the scrutinee, and the case tree that its arms are compiled
+    /// into, see [`case_tree::CaseTree`].
+    Match(Expr, case_tree::CaseTree),
+}
+
+/// Checks if the fields are the elements of a tuple, that is, if they're named by their
+/// positions.
+pub fn is_tuple<T>(fields: &[(String, T)]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .enumerate()
+            .all(|(position, (name, _))| *name == position.to_string())
 }
 
 impl Default for Term {
@@ -28,10 +93,76 @@ impl Term {
     pub fn normalise(self, db: &dyn ThirDb, env: shared::Env) -> sol_diagnostic::Result<Term> {
         db.thir_quote(Level::new(db, env.len(db)), db.thir_eval(env, self)?)
     }
+
+    /// Splits the application into its head and arguments, like `f a b` into `f` and `[a, b]`.
+    /// The locations, and the glued terms, are looked through, so the head is never one of
+    /// them.
+    pub fn spine(&self) -> (&Term, Vec<&Term>) {
+        match self {
+            Term::App(callee, argument) => {
+                let (head, mut arguments) = callee.spine();
+                arguments.push(argument);
+                (head, arguments)
+            }
+            Term::Location(_, term) | Term::Glued(_, _, term) => term.spine(),
+            _ => (self, vec![]),
+        }
+    }
+
+    /// Replaces the glued terms by their top-level names applied to their spines, so the terms
+    /// are printed by the names, instead of their unfolded bodies.
+    pub fn fold_names(self) -> Term {
+        let go = |term: Expr| Box::new(term.fold_names());
+        let fields = |fields: Vec<(String, Term)>| -> Vec<(String, Term)> {
+            fields
+                .into_iter()
+                .map(|(name, term)| (name, term.fold_names()))
+                .collect()
+        };
+
+        match self {
+            Term::Glued(constructor, spine, _) => spine
+                .into_iter()
+                .fold(Term::Constructor(constructor), |acc, argument| {
+                    Term::App(acc.into(), argument.fold_names().into())
+                }),
+            Term::Lam(name, implicitness, body) => Term::Lam(name, implicitness, go(body)),
+            Term::App(callee, argument) => Term::App(go(callee), go(argument)),
+            Term::Pi(name, implicitness, domain, codomain) => {
+                Term::Pi(name, implicitness, go(domain), go(codomain))
+            }
+            Term::Ann(value, type_repr) => Term::Ann(go(value), go(type_repr)),
+            Term::Location(location, term) => Term::Location(location, go(term)),
+            Term::Record(record) => Term::Record(fields(record)),
+            Term::RecordType(record) => Term::RecordType(fields(record)),
+            Term::Project(record, name, position) => Term::Project(go(record), name, position),
+            Term::Eq(type_repr, lhs, rhs) => Term::Eq(go(type_repr), go(lhs), go(rhs)),
+            Term::Transport(equality, value) => Term::Transport(go(equality), go(value)),
+            Term::Irrelevant(term) => Term::Irrelevant(go(term)),
+            Term::ArrayType(type_repr) => Term::ArrayType(go(type_repr)),
+            Term::Array(items) => Term::Array(items.into_iter().map(Term::fold_names).collect()),
+            Term::Intrinsic(intrinsic, arguments, location) => Term::Intrinsic(
+                intrinsic,
+                arguments.into_iter().map(Term::fold_names).collect(),
+                location,
+            ),
+            Term::Quote(expr, splices) => Term::Quote(
+                expr,
+                splices
+                    .into_iter()
+                    .map(|(location, term)| (location, term.fold_names()))
+                    .collect(),
+            ),
+            Term::Match(scrutinee, tree) => {
+                Term::Match(go(scrutinee), tree.map(&mut Term::fold_names))
+            }
+            term => term,
+        }
+    }
 }
 
 impl Display for Term {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        write!(f, "{:?}", self.clone().fold_names())
     }
 }
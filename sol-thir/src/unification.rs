@@ -1,6 +1,8 @@
 use sol_diagnostic::fail;
 
 use crate::{
+    conversion, display,
+    meta::Constraint,
     shared::{self, Context, Implicitness},
     source::Term,
     value::Value,
@@ -23,16 +25,16 @@ pub enum UnifyError {
     /// Icit mismatch between two values,
     #[error("expected a value with implicitness: {0:?} of `{2}`, and got: {1:?} of `{3}`")]
     #[diagnostic(url(docsrs), code(unify::icit_mismatch))]
-    IcitMismatch(Implicitness, Implicitness, Term, Term),
+    IcitMismatch(Implicitness, Implicitness, String, String),
 
     /// Unification error between two types
     #[error("expected type: `{0}`, got the type: `{1}`")]
     #[diagnostic(url(docsrs), code(unify::cant_unify))]
-    CantUnify(Term, Term),
+    CantUnify(String, String),
 
     #[error("could not apply the value: `{0}` to the value: `{1}`")]
     #[diagnostic(url(docsrs), code(unify::could_not_apply))]
-    CouldNotApply(Term, Term),
+    CouldNotApply(String, String),
 }
 
 /// Unifies a spine of applications, it does unifies two list of applications
@@ -49,12 +51,92 @@ fn unify_sp(
     assert!(sp_a.len() == sp_b.len(), "spines must have the same length");
 
     for (lhs, rhs) in sp_a.into_iter().zip(sp_b) {
-        lhs.unify(db, ctx, rhs)?;
+        lhs.unify_uncached(db, ctx, rhs)?;
     }
 
     Ok(())
 }
 
+/// Checks if the fields of two records, or of two record types, have the same names. The
+/// fields are sorted by their names, so they are compared in order.
+fn same_names(fields_a: &[(String, Value)], fields_b: &[(String, Value)]) -> bool {
+    fields_a.len() == fields_b.len()
+        && fields_a
+            .iter()
+            .zip(fields_b)
+            .all(|((name_a, _), (name_b, _))| name_a == name_b)
+}
+
+/// Unifies the fields of two records, or of two record types, with the same names.
+fn unify_fields(
+    db: &dyn ThirDb,
+    fields_a: Vec<(String, Value)>,
+    fields_b: Vec<(String, Value)>,
+    ctx: Context,
+) -> sol_diagnostic::Result<()> {
+    for ((_, lhs), (_, rhs)) in fields_a.into_iter().zip(fields_b) {
+        lhs.unify_uncached(db, ctx, rhs)?;
+    }
+
+    Ok(())
+}
+
+/// Unifies the glued values, by their top-level names and spines if they are the same names,
+/// otherwise by their unfolded values. The mismatches are reported with the names, instead of
+/// the unfolded values, as they are more readable.
+fn unify_glued(
+    db: &dyn ThirDb,
+    ctx: Context,
+    lhs: Value,
+    rhs: Value,
+) -> sol_diagnostic::Result<()> {
+    // The spines with meta variables aren't compared, as a failed comparison could have solved
+    // some of them, even if the unfolded values unify.
+    if let (Value::Glued(name_a, sp_a, _), Value::Glued(name_b, sp_b, _)) = (&lhs, &rhs) {
+        if same_name(db, name_a, name_b)
+            && sp_a.len() == sp_b.len()
+            && !sp_a.iter().chain(sp_b).any(|value| value.has_metas(db))
+            && unify_sp(db, sp_a.clone(), sp_b.clone(), ctx).is_ok()
+        {
+            return Ok(());
+        }
+    }
+
+    let unfold = |value: Value| match value {
+        Value::Glued(_, _, value) => *value,
+        value => value,
+    };
+
+    match unfold(lhs.clone()).unify_uncached(db, ctx, unfold(rhs.clone())) {
+        Err(error) if is_mismatch(&error) => {
+            let lhs = display::show_value(db, ctx, lhs)?;
+            let rhs = display::show_value(db, ctx, rhs)?;
+            fail(UnifyError::CantUnify(lhs, rhs))
+        }
+        result => result,
+    }
+}
+
+/// Checks if the top-level names are the same, the references are compared by their
+/// definitions, as each occurrence of a name is a different reference.
+fn same_name(db: &dyn ThirDb, lhs: &shared::Constructor, rhs: &shared::Constructor) -> bool {
+    match (&lhs.kind, &rhs.kind) {
+        (shared::ConstructorKind::Reference(lhs), shared::ConstructorKind::Reference(rhs)) => {
+            lhs.definition(db) == rhs.definition(db)
+        }
+        (lhs, rhs) => lhs == rhs,
+    }
+}
+
+/// Checks if the error is a mismatch of the unification, so it can be reported again with the
+/// names of the glued values.
+fn is_mismatch(error: &sol_diagnostic::Diagnostic) -> bool {
+    match error.0.as_ref() {
+        sol_eyre::Report::Miette(report) => report.downcast_ref::<UnifyError>().is_some(),
+        sol_eyre::Report::Eyre(_) => false,
+    }
+}
+
 /// Pattern unification module
 pub mod pattern {
     use shared::MetaVar;
@@ -135,15 +217,52 @@ impl Value {
     ///
     /// It does returns an error if the unification fails. And a
     /// unit if the unification succeeds.
+    pub fn unify(
+        self,
+        db: &dyn crate::ThirDb,
+        ctx: Context,
+        rhs: Self,
+    ) -> sol_diagnostic::Result<()> {
+        // The unification of values with meta variables solves them, so it can't be cached.
+        if self.has_metas(db) || rhs.has_metas(db) {
+            let result = self.unify_uncached(db, ctx, rhs);
+
+            // The solved meta variables can unblock the postponed problems.
+            ctx.metas(db).retry(db);
+            return result;
+        }
+
+        let lhs = conversion::InternedValue::new(db, self);
+        let rhs = conversion::InternedValue::new(db, rhs);
+        if lhs == rhs {
+            return Ok(());
+        }
+
+        conversion::conversion(db, ctx, lhs, rhs)
+    }
+
+    /// Performs the unification without the conversion cache, see [`Value::unify`].
     #[rustfmt::skip]
-    pub fn unify(self, db: &dyn crate::ThirDb, ctx: Context, rhs: Self) -> sol_diagnostic::Result<()> {
+    pub(crate) fn unify_uncached(self, db: &dyn crate::ThirDb, ctx: Context, rhs: Self) -> sol_diagnostic::Result<()> {
         use crate::value::Pi as VPi;
         use Value::*;
 
-        match (self.force(db)?.1, rhs.force(db)?.1) {
+        match (self.force_glued(db)?.1, rhs.force_glued(db)?.1) {
             (U                                 , U)                                 => Ok(()),
+            (Prop                              , Prop)                              => Ok(()),
             (Constructor(lhs)                  , Constructor(rhs))    if lhs == rhs => Ok(()),
 
+            // Proof irrelevance, the values are compared only if they have the same type, so
+            // if one of them is a proof, both are proofs of the same proposition.
+            (Irrelevant(_)                     , _) | (_, Irrelevant(_))            => Ok(()),
+
+            // Glued values unification, the top-level names are compared before unfolding
+            // them, and the meta variables are solved with the names, instead of the unfolded
+            // values.
+            (lhs @ Glued(..), rhs) | (lhs, rhs @ Glued(..)) if !matches!(lhs, Flexible(..)) && !matches!(rhs, Flexible(..)) => {
+                unify_glued(db, ctx, lhs, rhs)
+            }
+
             // Unification of application spines or meta variables, it does unifies
             // flexibles, rigids and meta variable's spines.
             //
@@ -160,21 +279,21 @@ impl Value {
             //
             // It does unifies the closures and the pi types.
             (ref lhs @ Lam(_, icit_a, _)       , ref rhs @ Lam(_, icit_b, _)) if icit_a != icit_b => {
-                let lhs = db.thir_quote(ctx.lvl(db), lhs.clone())?;
-                let rhs = db.thir_quote(ctx.lvl(db), rhs.clone())?;
+                let lhs = display::show_value(db, ctx, lhs.clone())?;
+                let rhs = display::show_value(db, ctx, rhs.clone())?;
                 fail(UnifyError::IcitMismatch(icit_a, icit_b, lhs, rhs))
             }
             (Lam(_, _, clos_a)                 , Lam(_, _, clos_b)) => {
                 clos_a.apply(db, Value::new_var(ctx.lvl(db), None))?
-                    .unify(db, ctx.increase_level(db), clos_b.apply(db, Value::new_var(ctx.lvl(db), None))?)
+                    .unify_uncached(db, ctx.increase_level(db), clos_b.apply(db, Value::new_var(ctx.lvl(db), None))?)
             }
             (Lam(_, _, clos_a)                 , rhs) => {
                 clos_a.apply(db, Value::new_var(ctx.lvl(db), None))?
-                    .unify(db, ctx.increase_level(db), rhs.apply_to_spine(db, Value::new_var(ctx.lvl(db), None))?)
+                    .unify_uncached(db, ctx.increase_level(db), rhs.apply_to_spine(db, Value::new_var(ctx.lvl(db), None))?)
             }
             (lhs                               , Lam(_, _, clos_b)) => {
                 lhs.apply_to_spine(db, Value::new_var(ctx.lvl(db), None))?
-                    .unify(db, ctx.increase_level(db), clos_b.apply(db, Value::new_var(ctx.lvl(db), None))?)
+                    .unify_uncached(db, ctx.increase_level(db), clos_b.apply(db, Value::new_var(ctx.lvl(db), None))?)
             }
 
             // Pi type unification, it does unifies the domain and the codomain
@@ -182,30 +301,89 @@ impl Value {
             //
             // NOTE: cod stands for codomain, and dom stands for domain.
             (ref lhs @ Pi(VPi { implicitness: icit_a, .. })      , ref rhs @ Pi(VPi { implicitness: icit_b, .. })) if icit_a != icit_b => {
-                let lhs = db.thir_quote(ctx.lvl(db), lhs.clone())?;
-                let rhs = db.thir_quote(ctx.lvl(db), rhs.clone())?;
+                let lhs = display::show_value(db, ctx, lhs.clone())?;
+                let rhs = display::show_value(db, ctx, rhs.clone())?;
                 fail(UnifyError::IcitMismatch(icit_a, icit_b, lhs, rhs))
             }
             (Pi(VPi { domain: box dom_a, codomain: cod_a, .. }), Pi(VPi { domain: box dom_b, codomain: cod_b, .. })) => {
-                dom_a.unify(db, ctx, dom_b)?;
+                dom_a.unify_uncached(db, ctx, dom_b)?;
                 cod_a.apply(db, Value::new_var(ctx.lvl(db), None))?
-                    .unify(db, ctx.increase_level(db), cod_b.apply(db, Value::new_var(ctx.lvl(db), None))?)?;
+                    .unify_uncached(db, ctx.increase_level(db), cod_b.apply(db, Value::new_var(ctx.lvl(db), None))?)?;
 
                 Ok(())
             }
 
+            // Record unification, the records are structural, so they unify if they have
+            // the same fields, and the values of the fields unify.
+            (Record(fields_a)                  , Record(fields_b))    if same_names(&fields_a, &fields_b) => {
+                unify_fields(db, fields_a, fields_b, ctx)
+            }
+            (RecordType(fields_a)              , RecordType(fields_b)) if same_names(&fields_a, &fields_b) => {
+                unify_fields(db, fields_a, fields_b, ctx)
+            }
+            (Project(box lhs, name_a, _)       , Project(box rhs, name_b, _)) if name_a == name_b => {
+                lhs.unify_uncached(db, ctx, rhs)
+            }
+
+            // Equality unification, the equality types unify if their types and sides unify,
+            // and the stuck transports if their equalities and values unify.
+            (Eq(box type_a, box lhs_a, box rhs_a), Eq(box type_b, box lhs_b, box rhs_b)) => {
+                type_a.unify_uncached(db, ctx, type_b)?;
+                lhs_a.unify_uncached(db, ctx, lhs_b)?;
+                rhs_a.unify_uncached(db, ctx, rhs_b)
+            }
+            (Refl                              , Refl)                              => Ok(()),
+            (Transport(box eq_a, box value_a)  , Transport(box eq_b, box value_b)) => {
+                eq_a.unify_uncached(db, ctx, eq_b)?;
+                value_a.unify_uncached(db, ctx, value_b)
+            }
+
+            // Arrays unification, the array types unify if their types unify, the arrays if
+            // they have the same length, and their items unify, and the stuck intrinsics if
+            // they're the same, and their arguments unify.
+            (ArrayType(box type_a)             , ArrayType(box type_b))             => {
+                type_a.unify_uncached(db, ctx, type_b)
+            }
+            (Array(items_a)                    , Array(items_b))                    if items_a.len() == items_b.len() => {
+                unify_sp(db, items_a, items_b, ctx)
+            }
+            (Intrinsic(a, sp_a, _)             , Intrinsic(b, sp_b, _))             if a == b => {
+                unify_sp(db, sp_a, sp_b, ctx)
+            }
+
+            // Stuck matches unification, they're only compared by their terms, as the arms can't
+            // be unified pairwise without their scrutinees.
+            (lhs @ Match(..)                   , rhs @ Match(..))                   if lhs.alpha_eq(db, ctx.lvl(db), &rhs) => Ok(()),
+
+            // Quotes unification, the quoted expressions should be the same, and the values of
+            // their splices should unify.
+            (Quote(expr_a, splices_a)          , Quote(expr_b, splices_b))          if expr_a == expr_b && splices_a.len() == splices_b.len() => {
+                splices_a
+                    .into_iter()
+                    .zip(splices_b)
+                    .try_for_each(|((_, a), (_, b))| a.unify_uncached(db, ctx, b))
+            }
+
             // Unification of meta variables, it does unifies meta variables that
             // are present in the context.
             //
             // It does require a solver function.
             //
+            // The meta variables applied to spines aren't solved by the solver, so the problem
+            // is postponed until the meta variable is solved by another problem.
+            //
             // TODO: Solve
+            (Flexible(m, sp), t) | (t, Flexible(m, sp)) if !sp.is_empty() => {
+                let lhs = Flexible(m.clone(), sp);
+                ctx.metas(db).postpone(db, Constraint { blocker: m, ctx, lhs, rhs: t });
+                Ok(())
+            }
             (Flexible(m, sp), t) | (t, Flexible(m, sp)) => pattern::solve(db, ctx.lvl(db), m, t, sp),
 
             // Fallback case, it does fail with the unification error.
             (lhs, rhs) => {
-                let lhs = db.thir_quote(ctx.lvl(db), lhs)?;
-                let rhs = db.thir_quote(ctx.lvl(db), rhs)?;
+                let lhs = display::show_value(db, ctx, lhs)?;
+                let rhs = display::show_value(db, ctx, rhs)?;
                 fail(UnifyError::CantUnify(lhs, rhs))
             }
 
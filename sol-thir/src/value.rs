@@ -8,12 +8,40 @@ pub type Type = Value;
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Value {
     U,
+    Prop,
     Constructor(shared::Constructor),
     Flexible(shared::MetaVar, Vec<Value>),
     Rigid(debruijin::Level, Vec<Value>),
     Pi(Pi),
     Lam(Definition, shared::Implicitness, Closure),
     Location(Location, Box<Value>),
+    Record(Vec<(String, Value)>),
+    RecordType(Vec<(String, Type)>),
+
+    /// A projection that is stuck, because the record isn't known yet.
+    Project(Box<Value>, String, usize),
+    Eq(Box<Type>, Box<Value>, Box<Value>),
+    Refl,
+
+    /// A transport that is stuck, because the equality isn't known to be `refl` yet.
+    Transport(Box<Value>, Box<Value>),
+    Irrelevant(Box<Value>),
+
+    /// A top-level name applied to its spine, with its unfolded value. The name is kept to
+    /// quote the value back, and the unfolded value is used when the value is forced.
+    Glued(shared::Constructor, Vec<Value>, Box<Value>),
+
+    /// A quoted expression, with the values of the splices inside of it, by their locations.
+    Quote(sol_hir::source::expr::Expr, Vec<(Location, Value)>),
+    ArrayType(Box<Type>),
+    Array(Vec<Value>),
+
+    /// An application of an intrinsic that is stuck, because its arguments aren't known yet.
+    Intrinsic(Intrinsic, Vec<Value>, Location),
+
+    /// A match that is stuck, because the scrutinee, or a guard, isn't known yet. The terms of
+    /// the case tree are evaluated in the environment.
+    Match(Box<Value>, shared::Env, case_tree::CaseTree),
 }
 
 impl Default for Value {
@@ -28,15 +56,24 @@ impl Value {
     }
 
     pub fn force(self, db: &dyn ThirDb) -> sol_diagnostic::Result<(Option<Location>, Value)> {
-        // TODO: use db
-        let _ = db;
+        match self.force_glued(db)? {
+            (location, Value::Glued(_, _, value)) => {
+                let (unfolded_location, value) = value.force(db)?;
+                Ok((location.or(unfolded_location), value))
+            }
+            forced => Ok(forced),
+        }
+    }
 
+    /// Forces the value, without unfolding the glued values, so they can be compared, and
+    /// quoted by their top-level names.
+    pub fn force_glued(self, db: &dyn ThirDb) -> sol_diagnostic::Result<(Option<Location>, Value)> {
         match self {
             Value::Flexible(ref m, ref spine) => Ok((None, match m.get() {
                 Some(value) => value.apply_with_spine(db, spine.clone())?,
                 None => self,
             })),
-            Value::Location(ref location, _) => Ok((Some(location.clone()), self.force(db)?.1)),
+            Value::Location(location, value) => Ok((Some(location), value.force_glued(db)?.1)),
             _ => Ok((None, self)),
         }
     }
@@ -63,6 +100,13 @@ impl Value {
                 spine.push(argument);
                 Ok(Value::Rigid(lvl, spine))
             }
+            Value::Irrelevant(value) => Ok(Value::Irrelevant(
+                value.apply_to_spine(db, argument)?.into(),
+            )),
+            Value::Glued(constructor, mut spine, value) => {
+                spine.push(argument.clone());
+                Ok(Value::Glued(constructor, spine, value.apply_to_spine(db, argument)?.into()))
+            }
             _ => panic!("vapp: can't apply non-function value"),
         }
     }
@@ -77,16 +121,26 @@ impl Value {
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Closure {
     pub env: shared::Env,
-    pub expr: Term,
+
+    /// The body of the closure, it's hash-consed, so the values are cheap to clone, and
+    /// to compare.
+    pub expr: conversion::InternedTerm,
 }
 
 impl Closure {
+    pub fn new(db: &dyn ThirDb, env: shared::Env, expr: Term) -> Self {
+        Self {
+            env,
+            expr: conversion::InternedTerm::new(db, expr),
+        }
+    }
+
     /// Apply the closure to the value. It does apply as as snoc list in the environment
     /// to be the first to be applied.
     pub fn apply(self, db: &dyn ThirDb, value: Value) -> sol_diagnostic::Result<Value> {
         let closure_env = self.env.push(db, value);
 
-        db.thir_eval(closure_env, self.expr)
+        db.thir_eval(closure_env, self.expr.term(db).clone())
     }
 }
 
@@ -0,0 +1,186 @@
+//! Defines the search of the terms that fill the holes, like `_`. The candidates are the
+//! definitions of the file, and their applications to the other definitions, up to a bounded
+//! number of arguments, whose types are alpha-equivalent to the goal of the hole, like:
+//!
+//! ```sol
+//! zero : Int = 0
+//! succ (n : Int) : Int = n + 1
+//!
+//! one : Int = _ -- `zero`, `succ zero`, ...
+//! ```
+//!
+//! The local variables aren't searched yet, as the context doesn't store their types.
+
+use sol_hir::{
+    completions::ExpectedType,
+    source::{HirLocation, HirSource},
+};
+use sol_thir::{
+    debruijin::Level,
+    meta::HoleGoal,
+    shared::{Env, GlobalEnv, Implicitness},
+    value::{Type, Value},
+};
+
+use super::*;
+
+/// The maximum number of arguments applied to a candidate, like `f x y` has two arguments.
+const MAX_DEPTH: usize = 2;
+
+/// The maximum number of candidates returned for a hole.
+const MAX_CANDIDATES: usize = 10;
+
+/// A term that fills a hole, the candidates with less arguments are ranked first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AutoCandidate {
+    /// The expression that fills the hole, like `succ zero`.
+    pub expr: String,
+
+    /// The number of arguments applied to the definition.
+    pub arguments: usize,
+}
+
+/// A definition, or an application of a definition, that is being searched.
+struct Candidate {
+    expr: String,
+    type_repr: Type,
+    arguments: usize,
+}
+
+/// Defines the [`auto`] query.
+///
+/// Searches the terms that fill the hole at the location, ranked by their number of arguments,
+/// and then by their names. It returns no candidates if there's no hole at the location.
+#[salsa::tracked]
+pub fn auto(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    source: HirSource,
+    hole: HirLocation,
+) -> Vec<AutoCandidate> {
+    let Some(HoleGoal { ctx, goal, .. }) = source_hole_goals(db, global_env, source)
+        .into_iter()
+        .find(|goal| goal.location == hole.location(db))
+    else {
+        return vec![];
+    };
+
+    // The definitions are evaluated, as the types of the applications can depend on the values
+    // of the arguments.
+    let mut definitions = infer_type_table(db, global_env, source)
+        .into_iter()
+        .filter_map(|(definition, (term, type_repr))| {
+            let value = db.thir_eval(Env::new(db, Default::default()), term).ok()?;
+            Some((definition.to_string(db), value, type_repr))
+        })
+        .collect::<Vec<_>>();
+    definitions.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+    let lvl = ctx.lvl(db);
+    let mut candidates = vec![];
+    let mut frontier = definitions
+        .iter()
+        .map(|(expr, _, type_repr)| Candidate {
+            expr: expr.clone(),
+            type_repr: type_repr.clone(),
+            arguments: 0,
+        })
+        .collect::<Vec<_>>();
+
+    for depth in 0..=MAX_DEPTH {
+        let mut next = vec![];
+        for candidate in frontier {
+            if candidate.type_repr.alpha_eq(db, lvl, &goal) {
+                candidates.push(AutoCandidate {
+                    expr: candidate.expr.clone(),
+                    arguments: candidate.arguments,
+                });
+            }
+
+            // The functions are applied to the definitions of their domains, the implicit
+            // arguments aren't searched.
+            let Ok((_, Value::Pi(pi))) = candidate.type_repr.force(db) else {
+                continue;
+            };
+            if depth == MAX_DEPTH || pi.implicitness == Implicitness::Implicit {
+                continue;
+            }
+
+            for (expr, value, type_repr) in &definitions {
+                if !type_repr.alpha_eq(db, lvl, &pi.domain) {
+                    continue;
+                }
+
+                let Ok(type_repr) = pi.codomain.clone().apply(db, value.clone()) else {
+                    continue;
+                };
+
+                next.push(Candidate {
+                    expr: format!("{} {expr}", candidate.expr),
+                    type_repr,
+                    arguments: candidate.arguments + 1,
+                });
+            }
+        }
+
+        frontier = next;
+    }
+
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+/// Gets the expected type at the offset, from the goal of the hole at the offset, as the
+/// definitions of the file whose result types are alpha-equivalent to it, so the completions
+/// can be ranked by it.
+pub fn expected_type(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    source: HirSource,
+    offset: usize,
+) -> Option<ExpectedType> {
+    let HoleGoal { ctx, goal, .. } =
+        source_hole_goals(db, global_env, source)
+            .into_iter()
+            .find(|goal| {
+                goal.location.start().offset() <= offset && offset <= goal.location.end().offset()
+            })?;
+
+    let lvl = ctx.lvl(db).value(db);
+    let candidates = infer_type_table(db, global_env, source)
+        .into_iter()
+        .filter_map(|(definition, (_, type_repr))| {
+            Some((definition, result_parameters(db, lvl, type_repr, &goal)?))
+        })
+        .collect();
+
+    Some(ExpectedType { candidates })
+}
+
+/// Applies the type to fresh variables, until its result type is alpha-equivalent to the
+/// goal, returning the names of the explicit parameters that were applied.
+fn result_parameters(
+    db: &dyn TyperDb,
+    lvl: usize,
+    mut type_repr: Type,
+    goal: &Type,
+) -> Option<Vec<String>> {
+    let mut parameters = vec![];
+    for depth in lvl.. {
+        let level = Level::new(db, depth);
+        if type_repr.alpha_eq(db, level, goal) {
+            return Some(parameters);
+        }
+
+        let Ok((_, Value::Pi(pi))) = type_repr.force(db) else {
+            return None;
+        };
+        if pi.implicitness == Implicitness::Explicit {
+            let name = pi.name.map(|name| name.to_string(db));
+            parameters.push(name.unwrap_or_else(|| "_".into()));
+        }
+        type_repr = pi.codomain.apply(db, Value::new_var(level, None)).ok()?;
+    }
+
+    None
+}
@@ -0,0 +1,98 @@
+//! Defines the tracking of the definitions of the other files that each definition references,
+//! to narrow the elaboration that is done again when a file changes.
+//!
+//! The references to the definitions of the other files depend on [`definition_type`], instead
+//! of on the type table of the whole file. As salsa compares the results of the queries, the
+//! elaborated type of a definition is its fingerprint, so editing a helper of a big module only
+//! checks again the definitions that reference the helper, like in:
+//!
+//! ```sol
+//! -- Utils.sol
+//! double (n : Int) : Int = n * 2
+//! triple (n : Int) : Int = n * 3 -- editing the body doesn't check `Main.six` again
+//!
+//! -- Main.sol
+//! six : Int = double 3
+//! ```
+
+use sol_hir::{
+    solver::{Definition, Reference},
+    source::top_level::{BindingGroup, TopLevel},
+    walking::{HirListener, Walker},
+};
+use sol_thir::{shared::GlobalEnv, source::Term, value::Type};
+
+use super::*;
+
+/// Collects the definitions referenced by a declaration.
+struct DefinitionCollector<'db> {
+    db: &'db dyn HirDb,
+    definitions: Vec<Definition>,
+}
+
+impl HirListener for DefinitionCollector<'_> {
+    fn visit_reference(&mut self, reference: Reference) {
+        let definition = reference.definition(self.db);
+        if !self.definitions.contains(&definition) {
+            self.definitions.push(definition);
+        }
+    }
+}
+
+/// Defines the [`definition_dependencies`] query.
+///
+/// Gets the definitions of the other files that the definition references, in the order they
+/// are referenced.
+#[salsa::tracked]
+pub fn definition_dependencies(db: &dyn TyperDb, group: BindingGroup) -> Vec<Definition> {
+    let mut collector = DefinitionCollector {
+        db,
+        definitions: vec![],
+    };
+    group.accept(db, &mut collector);
+
+    let source = group.location(db).source();
+    collector
+        .definitions
+        .into_iter()
+        .filter(|definition| {
+            let definition_source = definition.location(db).source();
+            definition_source.is_some() && definition_source != source
+        })
+        .collect()
+}
+
+/// Defines the [`definition_type`] query.
+///
+/// Gets the elaborated type of the definition, it only depends on the elaboration of its
+/// binding group, and not on the other definitions of its file. It returns `None` if the
+/// definition isn't a top-level binding.
+#[salsa::tracked]
+pub fn definition_type(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    package: Package,
+    definition: Definition,
+) -> Option<(Term, Type)> {
+    let src = definition.location(db).source()?;
+    let hir_src = db.hir_lower(package, src);
+
+    hir_src
+        .contents(db)
+        .iter()
+        .filter_map(|top_level| match top_level {
+            TopLevel::BindingGroup(group) => Some(*group),
+            _ => None,
+        })
+        .find(|group| {
+            group
+                .clauses(db)
+                .iter()
+                .any(|clause| clause.name(db) == definition)
+        })
+        .and_then(|group| {
+            infer_binding_group(db, global_env, package, group)
+                .get(&definition)
+                .cloned()
+        })
+}
@@ -0,0 +1,145 @@
+//! Defines the checking of the instances against their traits. The methods of an instance are
+//! elaborated against the signatures of the trait, with the parameters of the trait replaced by
+//! the type arguments of the instance, like in:
+//!
+//! ```sol
+//! trait Show (a : U) {
+//!   show : a -> String
+//! }
+//!
+//! instance Show Bool {
+//!   show = |b| "bool" -- checked against `Bool -> String`
+//! }
+//! ```
+
+use sol_diagnostic::{fail, report_error, Result};
+use sol_hir::source::{
+    pattern::Pattern,
+    top_level::{trait_declaration, Instance, Trait},
+};
+
+use super::*;
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the trait `{name}` expects {expected} type arguments, but the instance has {actual}")]
+#[diagnostic(code(sol::typer::instance_arity))]
+pub struct InstanceArityError {
+    pub name: String,
+    pub expected: usize,
+    pub actual: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the method `{method}` isn't declared by the trait `{name}`")]
+#[diagnostic(code(sol::typer::unknown_method))]
+pub struct UnknownMethodError {
+    pub method: String,
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("unsupported trait parameter")]
+#[diagnostic(code(sol::typer::unsupported_trait_parameter))]
+pub struct UnsupportedTraitParameterError {
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+/// Checks the methods of the instance against the signatures of its trait. The instances of
+/// the unresolved traits are skipped, as their references are already reported.
+pub fn check_instance(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    package: Package,
+    instance: Instance,
+) {
+    let Some(declaration) = trait_declaration(db, instance.trait_reference(db).definition(db))
+    else {
+        return;
+    };
+
+    let ctx = Context::default_with_env(db, global_env, package);
+    check_methods(db, ctx, declaration, instance).unwrap_or_report(db);
+
+    ctx.metas(db).finish(db, instance.location(db));
+}
+
+/// Elaborates the methods of the instance against the signatures of the trait. The signatures
+/// are elaborated in the context of the parameters of the trait, and evaluated in the
+/// environment of the type arguments of the instance.
+fn check_methods(
+    db: &dyn TyperDb,
+    ctx: Context,
+    declaration: Trait,
+    instance: Instance,
+) -> Result<()> {
+    let name = declaration
+        .name(db)
+        .name(db)
+        .to_string(db)
+        .unwrap_or_default();
+    let parameters = declaration.parameters(db);
+    let arguments = instance.arguments(db);
+    if parameters.len() != arguments.len() {
+        return fail(InstanceArityError {
+            name,
+            expected: parameters.len(),
+            actual: arguments.len(),
+            location: instance.location(db),
+        });
+    }
+
+    // The parameters are a telescope, so the type of each argument is evaluated with the
+    // previous arguments, like `Vec 3 Int` against `(n : Nat) (a : U)`.
+    let mut signature_ctx = ctx;
+    let mut env = ctx.locals(db);
+    for (parameter, argument) in parameters.into_iter().zip(arguments) {
+        let Pattern::Binding(binding) = parameter.binding(db) else {
+            return fail(UnsupportedTraitParameterError {
+                location: parameter.location(db),
+            });
+        };
+        let parameter_type = parameter.parameter_type(db);
+        let domain = db.thir_check(signature_ctx, *parameter_type.expr, Value::U)?;
+        let argument_type = db.thir_eval(env, domain.clone())?;
+        let argument = db.thir_check(ctx, *argument.expr, argument_type)?;
+
+        let domain_value = db.thir_eval(signature_ctx.locals(db), domain)?;
+        signature_ctx = signature_ctx.create_new_value(db, binding.name, domain_value);
+        env = env.push(db, db.thir_eval(ctx.locals(db), argument)?);
+    }
+
+    let signatures = declaration.methods(db);
+    for clause in instance.methods(db) {
+        let method = clause.name(db).name(db).to_string(db);
+        let Some(signature) = signatures
+            .iter()
+            .find(|signature| signature.name(db).name(db).to_string(db) == method)
+        else {
+            report_error(db, UnknownMethodError {
+                method: method.unwrap_or_default(),
+                name: name.clone(),
+                location: clause.location(db),
+            });
+            continue;
+        };
+
+        let return_type = signature.return_type(db);
+        let signature_type = db.thir_check(signature_ctx, *return_type.expr, Value::U)?;
+        let expected_type = db.thir_eval(env, signature_type)?;
+
+        db.thir_check(ctx, clause.value(db), expected_type)
+            .unwrap_or_report(db);
+    }
+
+    Ok(())
+}
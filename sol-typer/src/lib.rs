@@ -10,22 +10,39 @@ use std::{
     sync::Arc,
 };
 
-use salsa::DbWithJar;
+use salsa::{Database, DbWithJar};
 use sol_diagnostic::{report_error, TextSource, UnwrapOrReport};
 use sol_hir::{
-    source::{declaration::Declaration, top_level::TopLevel, HirSource},
+    package::Package,
+    source::{
+        declaration::Declaration,
+        expr::Expr,
+        top_level::{BindingGroup, TopLevel},
+        HirElement, HirSource, Location,
+    },
     HirDb,
 };
 use sol_thir::{
-    shared::{Context, Env, GlobalEnv, MetaVar},
-    value::Type,
-    ThirDb, TypeTable,
+    display,
+    meta::{HoleGoal, HoleGoals, MetaEntry, MetaKind, UnsolvedMetas},
+    shared::{Constructor, ConstructorKind, Context, Env, GlobalEnv},
+    value::{Type, Value},
+    ElaboratedTerm, ThirDb, TypeTable,
 };
 
 extern crate salsa_2022 as salsa;
 
 #[salsa::jar(db = TyperDb)]
-pub struct Jar(infer_type_table);
+pub struct Jar(
+    infer_type_table,
+    infer_binding_group,
+    unsolved_metas,
+    hole_goals,
+    eval_hints,
+    auto::auto,
+    dependencies::definition_dependencies,
+    dependencies::definition_type,
+);
 
 /// The database that Typer uses internally. This is a trait so that we can
 /// mock it during testing.
@@ -33,12 +50,45 @@ pub trait TyperDb: ThirDb + HirDb + DbWithJar<Jar> {}
 
 impl<DB: ThirDb> TyperDb for DB where DB: ?Sized + HirDb + salsa::DbWithJar<Jar> {}
 
+pub mod auto;
+pub mod dependencies;
+pub mod instance;
 pub mod options;
 pub mod utils;
 
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the signature `{name}` doesn't have a definition")]
+#[diagnostic(
+    code(sol::typer::missing_definition),
+    help("define it like `{name} = ...`")
+)]
+pub struct MissingDefinitionError {
+    pub name: String,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+#[error("the definition `{name}` has {clauses} clauses, but only one is supported")]
+#[diagnostic(
+    code(sol::typer::multiple_clauses),
+    help("match on the arguments in a single clause")
+)]
+pub struct MultipleClausesError {
+    pub name: String,
+    pub clauses: usize,
+
+    #[source_code]
+    #[label = "here"]
+    pub location: Location,
+}
+
 fn check_top_level(
     db: &dyn TyperDb,
-    ctx: Context,
+    global_env: GlobalEnv,
+    package: Package,
     item: TopLevel,
     table: &mut TypeTable,
 ) -> std::thread::Result<()> {
@@ -46,36 +96,146 @@ fn check_top_level(
 
     catch_unwind(AssertUnwindSafe(|| {
         match item {
-            Error(_) | Inductive(_) | Using(_) | Command(_) => todo!("handle: error"),
+            // The erroneous declarations are reported and skipped by the lowering, and the
+            // imports are resolved by it, so there's nothing to check.
+            Error(_) | Using(_) => {}
+            Inductive(inductive) => {
+                sol_hir::positivity::check_positivity(db, inductive);
+                sol_hir::indices::check_indices(db, inductive);
+            }
+            // The commands are executed by the [`sol_hir::commands::CommandRegistry`], after
+            // the type checking.
+            Command(_) => {}
+            // The type aliases are unfolded where they are referenced, so there's nothing to
+            // infer for the declaration itself.
+            TypeAlias(_) => {}
+            // The traits only declare the signatures, that are checked by the instances.
+            Trait(_) => {}
+            Instance(instance) => {
+                sol_hir::entailment::check_superclasses(db, instance);
+                instance::check_instance(db, global_env, package, instance);
+            }
             BindingGroup(group) => {
-                let actual_type = match group.signature(db).type_rep(db) {
-                    Some(value) => {
-                        let term = db
-                            .thir_check(ctx, *value.expr, Type::U)
-                            .unwrap_or_report(db);
-
-                        db.thir_eval(Env::new(db, VecDeque::new()), term)
-                            .unwrap_or_report(db)
-                    }
-                    None => Type::Flexible(MetaVar::new(None), vec![]),
-                };
-
-                match group.clauses(db).len() {
-                    0 => todo!("handle: error"),
-                    1 => {
-                        let clause = group.clauses(db).into_iter().next().unwrap();
-                        let term = db
-                            .thir_check(ctx, clause.value(db), actual_type.clone())
-                            .unwrap_or_report(db);
-                        table.insert(clause.name(db), (term, actual_type));
-                    }
-                    _ => todo!("handle: different error"),
-                }
+                sol_hir::indices::check_impossible_clauses(db, group);
+                table.extend(infer_binding_group(db, global_env, package, group));
             }
         };
     }))
 }
 
+/// Defines the [`infer_binding_group`] query.
+///
+/// Elaborates a single top-level definition, it's keyed by the HIR binding group, so editing
+/// the body of a definition doesn't elaborate the other definitions of the file again.
+#[salsa::tracked(lru = 128)]
+pub fn infer_binding_group(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    package: Package,
+    group: BindingGroup,
+) -> TypeTable {
+    let mut table = TypeTable::new();
+    let ctx = Context::default_with_env(db, global_env, package);
+
+    // The definitions of the other files are elaborated first, so the group only depends on
+    // their types, and not on the whole files.
+    for definition in dependencies::definition_dependencies(db, group) {
+        dependencies::definition_type(db, global_env, package, definition);
+    }
+
+    let signature = group.signature(db);
+    let actual_type = match signature.type_rep(db) {
+        Some(value) => {
+            let term = db
+                .thir_check(ctx, *value.expr, Type::U)
+                .unwrap_or_report(db);
+
+            db.thir_eval(Env::new(db, VecDeque::new()), term)
+                .unwrap_or_report(db)
+        }
+        None => {
+            let location = signature.location(db);
+            let meta = ctx.metas(db).fresh(db, MetaKind::Implicit, location);
+            Type::Flexible(meta, vec![])
+        }
+    };
+
+    // The definitions without a single clause are reported, and aren't elaborated, so their
+    // metas aren't reported as unsolved too.
+    match group.clauses(db).len() {
+        0 => {
+            report_error(db, MissingDefinitionError {
+                name: signature.name(db).to_string(db).unwrap_or_default(),
+                location: signature.location(db),
+            });
+            return table;
+        }
+        1 => {
+            let clause = group.clauses(db).into_iter().next().unwrap();
+            let term = db
+                .thir_check(ctx, clause.value(db), actual_type.clone())
+                .unwrap_or_report(db);
+            table.insert(clause.name(db), (term, actual_type));
+        }
+        clauses => {
+            report_error(db, MultipleClausesError {
+                name: signature.name(db).to_string(db).unwrap_or_default(),
+                clauses,
+                location: signature.location(db),
+            });
+            return table;
+        }
+    }
+
+    ctx.metas(db).finish(db, signature.location(db));
+
+    table
+}
+
+/// Defines the [`unsolved_metas`] query.
+///
+/// Gets the meta variables that weren't solved by the elaboration of the definition, in their
+/// creation order.
+#[salsa::tracked]
+pub fn unsolved_metas(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    package: Package,
+    group: BindingGroup,
+) -> Vec<MetaEntry> {
+    infer_binding_group::accumulated::<UnsolvedMetas>(db, global_env, package, group)
+}
+
+/// Defines the [`hole_goals`] query.
+///
+/// Gets the goals of the holes of the definition, in the order they were checked.
+#[salsa::tracked]
+pub fn hole_goals(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    package: Package,
+    group: BindingGroup,
+) -> Vec<HoleGoal> {
+    infer_binding_group::accumulated::<HoleGoals>(db, global_env, package, group)
+}
+
+/// Gets the goals of the holes of all the definitions of the source file.
+pub fn source_hole_goals(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    source: HirSource,
+) -> Vec<HoleGoal> {
+    source
+        .contents(db)
+        .iter()
+        .filter_map(|top_level| match top_level {
+            TopLevel::BindingGroup(group) => Some(*group),
+            _ => None,
+        })
+        .flat_map(|group| hole_goals(db, global_env, source.package(db), group))
+        .collect()
+}
+
 #[derive(Debug, thiserror::Error, miette::Diagnostic)]
 #[error("type checker panicked, please report an error: {message}")]
 #[diagnostic(code(sol::typer::type_checker_panic))]
@@ -86,17 +246,26 @@ pub struct TyperPanicError {
     pub source_code: TextSource,
 }
 
-#[salsa::tracked]
+#[salsa::tracked(lru = 128)]
 pub fn infer_type_table(db: &dyn TyperDb, global_env: GlobalEnv, source: HirSource) -> TypeTable {
     let mut table = TypeTable::new();
-    let ctx = Context::default_with_env(db, global_env, source.package(db));
     let text_source = TextSource::new(
         source.source(db).file_path(db).to_string_lossy(),
         Arc::new(source.source(db).source_text(db).to_string()),
     );
 
     for item in source.contents(db).iter() {
-        if let Err(panic_error) = check_top_level(db, ctx, item.clone(), &mut table) {
+        // Stops the elaboration if there's a new revision, before checking the next item.
+        db.unwind_if_cancelled();
+
+        if let Err(panic_error) =
+            check_top_level(db, global_env, source.package(db), item.clone(), &mut table)
+        {
+            // The cancellation isn't an error, it should unwind to the caller of the query.
+            if panic_error.is::<salsa::Cancelled>() {
+                std::panic::resume_unwind(panic_error);
+            }
+
             report_error(db, TyperPanicError {
                 source_code: text_source.clone(),
                 message: panic_error
@@ -109,3 +278,83 @@ pub fn infer_type_table(db: &dyn TyperDb, global_env: GlobalEnv, source: HirSour
 
     table
 }
+
+/// Normalises the expression in the package, returning its normal form, as shown by the
+/// `#eval` command.
+pub fn eval_expr(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    package: Package,
+    expr: Expr,
+) -> sol_diagnostic::Result<String> {
+    let ctx = Context::default_with_env(db, global_env, package);
+    let ElaboratedTerm(term, _) = db.thir_infer(ctx, expr)?;
+
+    Ok(display::show_term(
+        db,
+        ctx,
+        &term.normalise(db, ctx.locals(db))?,
+    ))
+}
+
+/// Evaluates the boolean expression in the package, returning if it normalises to `true`, as
+/// checked by the `#static_assert` command.
+pub fn assert_expr(
+    db: &dyn TyperDb,
+    global_env: GlobalEnv,
+    package: Package,
+    expr: Expr,
+) -> sol_diagnostic::Result<bool> {
+    let ctx = Context::default_with_env(db, global_env, package);
+    let bool_type = Type::Constructor(Constructor {
+        kind: ConstructorKind::BooleanType,
+        location: expr.location(db),
+    });
+    let term = db.thir_check(ctx, expr, bool_type)?;
+    let value = db.thir_eval(ctx.locals(db), term)?;
+
+    Ok(matches!(
+        value.force(db)?.1,
+        Value::Constructor(Constructor {
+            kind: ConstructorKind::True,
+            ..
+        })
+    ))
+}
+
+/// The normal form of an argument of an `#eval` command, it's shown by the editors next to the
+/// argument. The errors are kept as their messages, as they're already reported by the command.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EvalHint {
+    pub location: Location,
+    pub value: Result<String, String>,
+}
+
+/// Defines the [`eval_hints`] query.
+///
+/// Evaluates the arguments of the `#eval` commands of the source file, so the editors can show
+/// their normal forms inline. It's evaluated again only when the definitions they depend on
+/// change.
+#[salsa::tracked]
+pub fn eval_hints(db: &dyn TyperDb, global_env: GlobalEnv, source: HirSource) -> Vec<EvalHint> {
+    let mut hints = vec![];
+    for item in source.contents(db).iter() {
+        let TopLevel::Command(command) = item else {
+            continue;
+        };
+
+        if command.path(db).to_string(db) != "eval" {
+            continue;
+        }
+
+        for expr in command.arguments(db) {
+            hints.push(EvalHint {
+                location: expr.location(db),
+                value: eval_expr(db, global_env, source.package(db), expr)
+                    .map_err(|error| error.0.to_string()),
+            });
+        }
+    }
+
+    hints
+}
@@ -4,6 +4,8 @@ use salsa::DbWithJar;
 
 extern crate salsa_2022 as salsa;
 
+pub mod line_index;
+
 #[salsa::input]
 pub struct SourceFile {
     #[return_ref]
@@ -17,7 +19,7 @@ pub struct SourceFile {
 }
 
 #[salsa::jar(db = VfsDb)]
-pub struct Jar(crate::SourceFile);
+pub struct Jar(crate::SourceFile, line_index::SourceFile_line_index);
 
 pub trait VfsDb: DbWithJar<Jar> {}
 
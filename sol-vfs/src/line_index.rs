@@ -0,0 +1,279 @@
+//! Defines the [`LineIndex`] of the source files, that converts the byte offsets, that are used
+//! by the locations, to lines and columns, in the encoding that the consumer needs. The language
+//! server uses UTF-16 columns, and the command line uses UTF-32 columns, that are the characters
+//! that a human counts.
+
+use std::collections::BTreeMap;
+
+use crate::SourceFile;
+
+/// The encoding of the columns. The lines are the same in all encodings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PositionEncoding {
+    /// The columns are counted in bytes.
+    #[default]
+    Utf8,
+
+    /// The columns are counted in UTF-16 code units, it's the default of the language server
+    /// protocol.
+    Utf16,
+
+    /// The columns are counted in unicode scalar values.
+    Utf32,
+}
+
+impl PositionEncoding {
+    /// Gets the length of the character in this encoding.
+    pub fn len(self, c: char) -> usize {
+        match self {
+            PositionEncoding::Utf8 => c.len_utf8(),
+            PositionEncoding::Utf16 => c.len_utf16(),
+            PositionEncoding::Utf32 => 1,
+        }
+    }
+}
+
+/// A zero-based line and column, the column is counted in a [`PositionEncoding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct LineCol {
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A character that is longer than a byte, with its byte column in the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct WideChar {
+    column: usize,
+    c: char,
+}
+
+/// The index of the lines of a text. It stores the offsets of the line starts and ends, and the
+/// characters that are not ASCII, so the columns can be converted between the encodings without
+/// the text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+
+    /// The offsets of the line ends, before the line terminators, so the carriage returns of
+    /// the CRLF line endings aren't part of the lines.
+    line_ends: Vec<usize>,
+    wide_chars: BTreeMap<usize, Vec<WideChar>>,
+    len: usize,
+}
+
+impl LineIndex {
+    /// Creates the index of the lines of the text.
+    pub fn new(text: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut line_ends = vec![];
+        let mut wide_chars = BTreeMap::<usize, Vec<WideChar>>::new();
+        let mut line_start = 0;
+
+        for (offset, c) in text.char_indices() {
+            if c == '\n' {
+                let crlf = offset > line_start && text.as_bytes()[offset - 1] == b'\r';
+                line_ends.push(if crlf { offset - 1 } else { offset });
+                line_start = offset + 1;
+                line_starts.push(line_start);
+            } else if !c.is_ascii() {
+                let line = line_starts.len() - 1;
+                let column = offset - line_start;
+
+                wide_chars
+                    .entry(line)
+                    .or_default()
+                    .push(WideChar { column, c });
+            }
+        }
+
+        line_ends.push(text.len());
+
+        Self {
+            line_starts,
+            line_ends,
+            wide_chars,
+            len: text.len(),
+        }
+    }
+
+    /// Gets the number of lines.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Converts the byte offset to a line and column in the given encoding. It returns [`None`]
+    /// if the offset is out of the text.
+    pub fn line_col(&self, offset: usize, encoding: PositionEncoding) -> Option<LineCol> {
+        if offset > self.len {
+            return None;
+        }
+
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        let byte_column = offset.min(self.line_ends[line]) - self.line_starts[line];
+        let column = self
+            .wide_chars(line)
+            .iter()
+            .take_while(|wide| wide.column < byte_column)
+            .fold(byte_column, |column, wide| {
+                (column + encoding.len(wide.c)).saturating_sub(wide.c.len_utf8())
+            });
+
+        Some(LineCol {
+            line: line as u32,
+            column: column as u32,
+        })
+    }
+
+    /// Converts the line and column in the given encoding to a byte offset. It returns [`None`]
+    /// if the line is out of the text, the columns after the end of the line are clamped to it,
+    /// and the columns in the middle of a character, like between the surrogates of a pair, are
+    /// moved to its start.
+    pub fn offset(&self, line_col: LineCol, encoding: PositionEncoding) -> Option<usize> {
+        let line = line_col.line as usize;
+        let start = *self.line_starts.get(line)?;
+        let end = self.line_ends[line];
+
+        let mut remaining = line_col.column as usize;
+        let mut byte_column = 0;
+        for wide in self.wide_chars(line) {
+            // The characters before the wide one are ASCII, so they have the same length in all
+            // the encodings.
+            let ascii = wide.column - byte_column;
+            if remaining <= ascii {
+                break;
+            }
+
+            remaining -= ascii;
+            byte_column = wide.column;
+
+            let units = encoding.len(wide.c);
+            if remaining < units {
+                remaining = 0;
+                break;
+            }
+
+            remaining -= units;
+            byte_column += wide.c.len_utf8();
+        }
+
+        Some((start + byte_column + remaining).min(end))
+    }
+
+    fn wide_chars(&self, line: usize) -> &[WideChar] {
+        self.wide_chars.get(&line).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[salsa::tracked]
+impl SourceFile {
+    /// Gets the [`LineIndex`] of the file, it's memoized, so it's only computed again when the
+    /// text of the file changes.
+    #[salsa::tracked(return_ref)]
+    pub fn line_index(self, db: &dyn crate::VfsDb) -> LineIndex {
+        LineIndex::new(self.source_text(db))
+    }
+}
+
+impl SourceFile {
+    /// Converts the byte offset to a line and column in the given encoding.
+    pub fn line_col(
+        self,
+        db: &dyn crate::VfsDb,
+        offset: usize,
+        encoding: PositionEncoding,
+    ) -> Option<LineCol> {
+        self.line_index(db).line_col(offset, encoding)
+    }
+
+    /// Converts the line and column in the given encoding to a byte offset.
+    pub fn offset(
+        self,
+        db: &dyn crate::VfsDb,
+        line_col: LineCol,
+        encoding: PositionEncoding,
+    ) -> Option<usize> {
+        self.line_index(db).offset(line_col, encoding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The `é` is 2 bytes, 1 UTF-16 code unit, and the `😀` is 4 bytes, a surrogate pair in
+    /// UTF-16, and the lines end with CRLF.
+    const TEXT: &str = "a = \"é😀\"\r\nb = 1\r\n";
+
+    fn line_col(line: u32, column: u32) -> LineCol {
+        LineCol { line, column }
+    }
+
+    #[test]
+    fn test_utf8_columns() {
+        let index = LineIndex::new(TEXT);
+        let encoding = PositionEncoding::Utf8;
+
+        assert_eq!(index.line_col(7, encoding), Some(line_col(0, 7)));
+        assert_eq!(index.line_col(11, encoding), Some(line_col(0, 11)));
+        assert_eq!(index.offset(line_col(0, 11), encoding), Some(11));
+
+        // The column in the middle of the `é` is moved to its start.
+        assert_eq!(index.offset(line_col(0, 6), encoding), Some(5));
+    }
+
+    #[test]
+    fn test_utf16_columns() {
+        let index = LineIndex::new(TEXT);
+        let encoding = PositionEncoding::Utf16;
+
+        assert_eq!(index.line_col(7, encoding), Some(line_col(0, 6)));
+        assert_eq!(index.line_col(11, encoding), Some(line_col(0, 8)));
+        assert_eq!(index.offset(line_col(0, 6), encoding), Some(7));
+        assert_eq!(index.offset(line_col(0, 8), encoding), Some(11));
+    }
+
+    #[test]
+    fn test_utf16_surrogate_pairs() {
+        let index = LineIndex::new(TEXT);
+
+        // The column between the surrogates of the `😀` is moved to its start.
+        assert_eq!(
+            index.offset(line_col(0, 7), PositionEncoding::Utf16),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn test_utf32_columns() {
+        let index = LineIndex::new(TEXT);
+        let encoding = PositionEncoding::Utf32;
+
+        assert_eq!(index.line_col(7, encoding), Some(line_col(0, 6)));
+        assert_eq!(index.line_col(11, encoding), Some(line_col(0, 7)));
+        assert_eq!(index.offset(line_col(0, 6), encoding), Some(7));
+        assert_eq!(index.offset(line_col(0, 7), encoding), Some(11));
+    }
+
+    #[test]
+    fn test_crlf_line_endings() {
+        let index = LineIndex::new(TEXT);
+        let encoding = PositionEncoding::Utf16;
+
+        assert_eq!(index.line_count(), 3);
+        assert_eq!(index.line_col(14, encoding), Some(line_col(1, 0)));
+        assert_eq!(index.offset(line_col(1, 0), encoding), Some(14));
+
+        // The columns after the end of the line are clamped before the carriage return.
+        assert_eq!(index.offset(line_col(0, 100), encoding), Some(12));
+        assert_eq!(index.offset(line_col(1, 100), encoding), Some(19));
+        assert_eq!(index.line_col(13, encoding), Some(line_col(0, 9)));
+    }
+
+    #[test]
+    fn test_out_of_the_text() {
+        let index = LineIndex::new(TEXT);
+
+        assert_eq!(index.line_col(TEXT.len() + 1, PositionEncoding::Utf8), None);
+        assert_eq!(index.offset(line_col(3, 0), PositionEncoding::Utf8), None);
+    }
+}
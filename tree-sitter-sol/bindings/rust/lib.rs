@@ -30,6 +30,11 @@ pub fn language() -> Language {
     unsafe { tree_sitter_sol() }
 }
 
+/// The version of the grammar, it's bumped when the grammar changes, and the parser is
+/// regenerated. The typed syntax nodes of `sol-syntax` record the version they were generated
+/// from, so the mismatched parsers are detected before their nodes are downcasted.
+pub const GRAMMAR_VERSION: u32 = 8;
+
 /// The content of the [`node-types.json`][] file for this grammar.
 ///
 /// [`node-types.json`]: https://tree-sitter.github.io/tree-sitter/using-parsers#static-node-types
@@ -1,12 +1,26 @@
 module.exports = grammar({
   name: 'sol',
 
-  extras: ($) => [$.line_comment, /[\s\r\n\uFEFF\u2060\u200B]/],
+  extras: ($) => [
+    $.line_comment,
+    $.block_comment,
+    $.doc_string,
+    /[\s\r\n\uFEFF\u2060\u200B]/,
+  ],
+
+  // The layout of the `do` blocks, the nested block comments, and the recovery of the unclosed
+  // parentheses, implemented by the external scanner in `src/scanner.c`.
+  externals: ($) => [
+    $._layout_start,
+    $._layout_semicolon,
+    $._layout_end,
+    $.block_comment,
+    $._implicit_close_paren,
+  ],
 
   conflicts: ($) => [
     [$.cons_pattern, $.primary],
     [$.if_stmt, $.if_expr],
-    [$._expr, $.type_app_expr, $.app_expr],
     [$._pattern, $.primary],
     [$._expr, $._primary_or_binary],
     [$._type_expr, $._primary_or_binary],
@@ -14,6 +28,17 @@ module.exports = grammar({
     [$._primary_or_binary, $.type_app_expr, $.app_expr],
     [$._primary_or_binary, $._expr, $.app_expr],
     [$._primary_or_binary, $._type_expr, $.type_app_expr],
+    [$.clause, $.record_field],
+    [$.record_field, $.primary],
+    [$.clause, $.primary],
+    [$.clause, $.record_field, $.primary],
+    [$.record_update, $._symbol],
+    [$.lam_expr, $._symbol],
+    // The parentheses are either a tuple or the parameters of a pi type, like `(a, b)` and
+    // `(a, b) -> c`, so both are tried until the arrow, or its absence, decides.
+    [$._expr, $._type_expr],
+    [$._expr, $._type_expr, $.type_app_expr, $.app_expr],
+    [$.type_app_expr, $.app_expr],
   ],
 
   precedences: ($) => [
@@ -38,7 +63,8 @@ module.exports = grammar({
     [$.sigma_expr, $.array_expr],
     [$.primary, $._expr],
     [$.primary, $._type_expr],
-    [$._type_expr, $.type_app_expr, $._expr, $.app_expr],
+    [$._type_expr, $.type_app_expr],
+    [$._expr, $.app_expr],
     [$._arm_body, $.sigma_expr, $.binary_expr],
     [$._arm_body, $.ann_expr],
     [$.tuple_expr, $._pi_parameter_set],
@@ -76,7 +102,18 @@ module.exports = grammar({
     // Declarations
 
     _decl: ($) =>
-      choice($.command, $.using, $.inductive, $.signature, $.clause),
+      choice(
+        $.command,
+        $.using,
+        $.inductive,
+        $.mutual,
+        $.macro_decl,
+        $.type_alias,
+        $.instance,
+        $.trait_decl,
+        $.signature,
+        $.clause,
+      ),
 
     _argument_list: ($) => choice($.explicit_arguments, $.implicit_arguments),
 
@@ -88,7 +125,7 @@ module.exports = grammar({
           optional(
             seq(
               '(',
-              $._expr,
+              field('argument', $._expr),
               repeat(seq(',', field('argument', $._expr))),
               optional(','),
               ')',
@@ -142,8 +179,15 @@ module.exports = grammar({
         repeat(field('attribute', $.attribute)),
         field('name', $.path),
         repeat(field('pattern', $._pattern)),
-        '=',
-        optional(field('value', $._expr)),
+        repeat(seq('|', field('with_pattern', $._pattern))),
+        choice(
+          seq('=', optional(field('value', $._expr))),
+          seq(
+            'with',
+            field('with_value', $._expr),
+            repeat(seq(',', field('with_value', $._expr))),
+          ),
+        ),
       ),
 
     inductive: ($) =>
@@ -161,6 +205,104 @@ module.exports = grammar({
         '}',
       ),
 
+    mutual: ($) =>
+      seq(
+        'mutual',
+        optional($._line_break),
+        repeat(seq(field('inductive', $.inductive), optional($._line_break))),
+        'end',
+      ),
+
+    macro_decl: ($) =>
+      seq(
+        repeat(field('doc_string', $.doc_string)),
+        repeat(field('attribute', $.attribute)),
+        'macro',
+        field('name', $.path),
+        '{',
+        repeat(seq(field('rule', $.macro_rule), optional(','))),
+        '}',
+      ),
+
+    macro_rule: ($) =>
+      seq(
+        '(',
+        optional(
+          seq(
+            field('pattern', $._macro_pattern),
+            repeat(seq(',', field('pattern', $._macro_pattern))),
+            optional(','),
+          ),
+        ),
+        ')',
+        '=>',
+        field('body', $._expr),
+      ),
+
+    _macro_pattern: ($) => choice($.macro_variable, $.literal),
+
+    type_alias: ($) =>
+      seq(
+        repeat(field('doc_string', $.doc_string)),
+        repeat(field('attribute', $.attribute)),
+        optional(field('visibility', $.visibility)),
+        'type',
+        field('name', $.path),
+        repeat(field('argument', $._argument_list)),
+        '=',
+        field('value', $._type_expr),
+      ),
+
+    trait_decl: ($) =>
+      seq(
+        repeat(field('doc_string', $.doc_string)),
+        repeat(field('attribute', $.attribute)),
+        optional(field('visibility', $.visibility)),
+        'trait',
+        field('name', $.path),
+        repeat(field('argument', $._argument_list)),
+        optional(
+          seq(
+            'requires',
+            field('requirement', $.constraint),
+            repeat(seq(',', field('requirement', $.constraint))),
+          ),
+        ),
+        '{',
+        optional(
+          seq(
+            field('method', $.signature),
+            repeat(seq(';', field('method', $.signature))),
+          ),
+        ),
+        optional(';'),
+        '}',
+      ),
+
+    constraint: ($) =>
+      prec.left(
+        seq(field('name', $.path), repeat(field('argument', $.primary))),
+      ),
+
+    instance: ($) =>
+      seq(
+        repeat(field('doc_string', $.doc_string)),
+        repeat(field('attribute', $.attribute)),
+        optional(field('visibility', $.visibility)),
+        choice('instance', 'impl'),
+        field('name', $.path),
+        repeat(field('argument', $.primary)),
+        '{',
+        optional(
+          seq(
+            field('method', $.clause),
+            repeat(seq(';', field('method', $.clause))),
+          ),
+        ),
+        optional(';'),
+        '}',
+      ),
+
     _data_constructor: ($) =>
       choice($.signature_constructor, $.function_constructor),
 
@@ -197,16 +339,51 @@ module.exports = grammar({
       ),
 
     // Statements
-    _stmt: ($) => choice($.let_stmt, $.if_stmt, $.ask_stmt, $.expr_stmt),
+    _stmt: ($) =>
+      choice(
+        $.let_stmt,
+        $.if_stmt,
+        $.for_stmt,
+        $.while_stmt,
+        $.assert_stmt,
+        $.ask_stmt,
+        $.expr_stmt,
+      ),
 
+    // The statement is preferred over the `if_expr`, so the ifs in the blocks are parsed the
+    // same by every runtime, instead of depending on how the ambiguity is resolved.
     if_stmt: ($) =>
+      prec.dynamic(
+        1,
+        seq(
+          'if',
+          optional(seq('let', field('pattern', $._pattern), '=')),
+          field('condition', $._expr),
+          field('then', $.then_body),
+          optional(field('otherwise', $.otherwise_body)),
+        ),
+      ),
+
+    for_stmt: ($) =>
       seq(
-        'if',
+        'for',
+        field('pattern', $._pattern),
+        'in',
+        field('iterable', $._expr),
+        'do',
+        field('body', $._do_body),
+      ),
+
+    while_stmt: ($) =>
+      seq(
+        'while',
         field('condition', $._expr),
-        field('then', $.then_body),
-        optional(field('otherwise', $.otherwise_body)),
+        'do',
+        field('body', $._do_body),
       ),
 
+    assert_stmt: ($) => seq('assert', field('condition', $._expr)),
+
     ask_stmt: ($) =>
       seq(seq(field('pattern', $._pattern), '<-', field('value', $._expr))),
 
@@ -230,9 +407,26 @@ module.exports = grammar({
         '}',
       ),
 
+    // The body of `do`, that is an implicit block when it starts on the next line.
+    _do_body: ($) => choice($._arm_body, alias($._layout_block, $.block)),
+
+    _layout_block: ($) =>
+      seq(
+        $._layout_start,
+        field('statement', $._stmt),
+        repeat(seq($._layout_semicolon, field('statement', $._stmt))),
+        $._layout_end,
+      ),
+
     // Patterns
     _pattern: ($) =>
-      choice($.group_pattern, $.cons_pattern, $.rest_pattern, $.literal),
+      choice(
+        $.group_pattern,
+        $.tuple_pattern,
+        $.cons_pattern,
+        $.rest_pattern,
+        $.literal,
+      ),
 
     rest_pattern: () => '..',
 
@@ -244,6 +438,17 @@ module.exports = grammar({
     group_pattern: ($) =>
       prec.left(seq('(', field('pattern', $._pattern), ')')),
 
+    // The destructuring of the tuples, like `(a, b)`, it has at least two elements, so it
+    // isn't a group pattern.
+    tuple_pattern: ($) =>
+      seq(
+        '(',
+        field('pattern', $._pattern),
+        repeat1(seq(',', field('pattern', $._pattern))),
+        optional(','),
+        ')',
+      ),
+
     // Expressions
     _expr: ($) =>
       choice(
@@ -292,15 +497,44 @@ module.exports = grammar({
         seq(
           field('callee', $.primary),
           repeat(field('argument', $.primary)),
-          optional($.block),
+          optional(choice($.block, field('trailing', $.trailing_lambda))),
         ),
       ),
 
+    trailing_lambda: ($) =>
+      seq(
+        '{',
+        repeat1(field('parameter', $._pattern)),
+        '=>',
+        field('value', $._expr),
+        '}',
+      ),
+
+    // The parentheses left open at the end of the line, before a declaration, are closed by
+    // the scanner, so the rest of the file is still parsed. The lowering reports them.
     tuple_expr: ($) =>
       seq(
         '(',
         optional(seq($._expr, repeat(seq(',', $._expr)), optional(','))),
-        ')',
+        choice(')', $._implicit_close_paren),
+      ),
+
+    // The projections of the elements of the tuples, like `pair.0`, the position is written
+    // right after the value, with the dot, so it isn't the composition operator.
+    projection_expr: ($) =>
+      prec.left(
+        1,
+        seq(field('value', $.primary), field('position', $.tuple_position)),
+      ),
+
+    tuple_position: () => token.immediate(/\.[0-9]+/),
+
+    // The operator sections, like `(+ 1)` and `(1 +)`, are partial applications of the
+    // infix operators.
+    section_expr: ($) =>
+      choice(
+        seq('(', field('op', $.infix_op), field('rhs', $._primary_or_binary), ')'),
+        seq('(', field('lhs', $._expr), field('op', $.infix_op), ')'),
       ),
 
     array_expr: ($) =>
@@ -316,6 +550,55 @@ module.exports = grammar({
         ']',
       ),
 
+    // The collection literals, like `#{"a": 1, "b": 2}` for the maps, and `#{1, 2}` for the
+    // sets, the empty literal `#{}` is an empty map.
+    map_expr: ($) =>
+      seq(
+        '#{',
+        optional(
+          seq(
+            field('entry', $.map_entry),
+            repeat(seq(',', field('entry', $.map_entry))),
+            optional(','),
+          ),
+        ),
+        '}',
+      ),
+
+    map_entry: ($) => seq(field('key', $._expr), ':', field('value', $._expr)),
+
+    set_expr: ($) =>
+      seq(
+        '#{',
+        field('item', $._expr),
+        repeat(seq(',', field('item', $._expr))),
+        optional(','),
+        '}',
+      ),
+
+    record_expr: ($) =>
+      seq(
+        '{',
+        field('field', $.record_field),
+        repeat(seq(',', field('field', $.record_field))),
+        optional(','),
+        '}',
+      ),
+
+    record_update: ($) =>
+      seq(
+        '{',
+        field('record', $._expr),
+        '|',
+        field('field', $.record_field),
+        repeat(seq(',', field('field', $.record_field))),
+        optional(','),
+        '}',
+      ),
+
+    record_field: ($) =>
+      seq(field('name', $.path), '=', field('value', $._expr)),
+
     ann_expr: ($) =>
       prec.left(
         seq(seq(field('value', $._expr), 'is', field('against', $._type_expr))),
@@ -355,12 +638,23 @@ module.exports = grammar({
     _any_parameter: ($) => choice($.forall_parameter, $.parameter),
 
     lam_expr: ($) =>
-      prec.left(
-        seq(
-          '|',
-          repeat1(field('parameter', $._pattern)),
-          '|',
-          field('value', $._expr),
+      choice(
+        prec.left(
+          seq(
+            '|',
+            repeat1(field('parameter', $._pattern)),
+            '|',
+            field('value', $._expr),
+          ),
+        ),
+        // The lambdas with clauses, like `\ Nil => 0 | Cons _ _ => 1`, match their single
+        // argument against the clauses.
+        prec.right(
+          seq(
+            '\\',
+            field('arm', $.match_arm),
+            repeat(seq('|', field('arm', $.match_arm))),
+          ),
         ),
       ),
 
@@ -372,12 +666,23 @@ module.exports = grammar({
     _pi_parameter_set: ($) =>
       choice($.pi_parameters, $.forall_parameters, $._type_expr),
 
+    // The pi types whose parameters are named, like `(n : U) n`, are still parsed without the
+    // `->`, with the lowest precedence, so the lowering can report the missing arrow.
     pi_expr: ($) =>
-      prec.left(
-        seq(
-          field('parameter', $._pi_parameter_set),
-          '->',
-          field('value', $._type_expr),
+      choice(
+        prec.left(
+          seq(
+            field('parameter', $._pi_parameter_set),
+            '->',
+            field('value', $._type_expr),
+          ),
+        ),
+        prec.dynamic(
+          -1,
+          seq(
+            field('parameter', choice($.pi_parameters, $.forall_parameters)),
+            field('value', $._type_expr),
+          ),
         ),
       ),
 
@@ -392,9 +697,22 @@ module.exports = grammar({
         ),
       ),
 
+    let_expr: ($) =>
+      prec.right(
+        seq(
+          'let',
+          field('pattern', $._pattern),
+          '=',
+          field('value', $._expr),
+          'in',
+          field('body', $._expr),
+        ),
+      ),
+
     if_expr: ($) =>
       seq(
         'if',
+        optional(seq('let', field('pattern', $._pattern), '=')),
         field('condition', $._expr),
         field('then', $.then_body),
         field('otherwise', $.otherwise_body),
@@ -412,8 +730,36 @@ module.exports = grammar({
     return_expr: ($) =>
       prec.left(seq('return', optional(field('value', $._expr)))),
 
+    refl_expr: () => 'refl',
+
+    panic_expr: ($) =>
+      choice(
+        seq('panic', field('message', $.literal)),
+        'todo',
+        'unreachable',
+      ),
+
+    rewrite_expr: ($) =>
+      prec.right(
+        seq(
+          'rewrite',
+          field('equality', $._expr),
+          'in',
+          field('value', $._expr),
+        ),
+      ),
+
+    quote_expr: ($) => seq('quote', '{', field('value', $._expr), '}'),
+
+    splice_expr: ($) => seq('splice', '{', field('value', $._expr), '}'),
+
     match_arm: ($) =>
-      seq(field('pattern', $._pattern), '=>', field('body', $._arm_body)),
+      seq(
+        field('pattern', $._pattern),
+        optional(seq('if', field('guard', $._expr))),
+        '=>',
+        field('body', $._arm_body),
+      ),
 
     then_body: ($) => prec.left(choice($.block, seq('then', $._expr))),
 
@@ -423,6 +769,23 @@ module.exports = grammar({
 
     free_variable: ($) => seq('^', $.identifier),
 
+    macro_call: ($) =>
+      seq(
+        field('name', $.path),
+        token.immediate('!'),
+        '(',
+        optional(
+          seq(
+            field('argument', $._expr),
+            repeat(seq(',', field('argument', $._expr))),
+            optional(','),
+          ),
+        ),
+        ')',
+      ),
+
+    macro_variable: () => token(seq('$', /[\p{XID_Start}_][\p{XID_Continue}]*/)),
+
     universe_expr: () => 'U',
 
     // Primaries
@@ -430,12 +793,26 @@ module.exports = grammar({
       choice(
         $.literal,
         $.path,
+        $.projection_expr,
         $.free_variable,
+        $.macro_call,
+        $.macro_variable,
+        $.map_expr,
+        $.set_expr,
         $.tuple_expr,
         $.array_expr,
         $.if_expr,
+        $.let_expr,
         $.match_expr,
+        $.panic_expr,
+        $.quote_expr,
+        $.record_expr,
+        $.record_update,
+        $.refl_expr,
         $.return_expr,
+        $.rewrite_expr,
+        $.section_expr,
+        $.splice_expr,
         $.universe_expr,
       ),
 
@@ -475,9 +852,12 @@ module.exports = grammar({
     u128: ($) => seq($._integer, optional('u128')),
     nat: ($) => seq($._integer, optional('n')),
 
-    octal: ($) => seq(/Oo/i, $._octal),
-    hex: ($) => seq(/Ox/i, $._hex),
-    binary: ($) => seq(/Ob/i, $._binary),
+    // The digits are limited to the radix, so the suffixes, like `u8` in `0xffu8`,
+    // are lexed as their own tokens. The prefixes without digits, like `0x`, are
+    // still literals, so they're reported by the lowering.
+    octal: () => /0o[0-7_]*/i,
+    hex: () => /0x[0-9a-fA-F_]*/i,
+    binary: () => /0b[01_]*/i,
 
     // LEXER
     _line_break: () => /(\n|\r\n|;)+/,
@@ -494,6 +874,7 @@ module.exports = grammar({
         '%',
         '&',
         '|',
+        '|>',
         '&&',
         '||',
         '!',
@@ -503,10 +884,7 @@ module.exports = grammar({
         '>',
       ),
 
-    _octal: () => /[0-7]+/i,
-    _hex: () => /[0-8a-fA-F]+/i,
-    _binary: () => /[0-1]+/i,
-    _decimal: () => /[0-9]+/i,
+    _decimal: () => /[0-9][0-9_]*/,
     _float: () => /\d+(\.[\d_]+)?/,
 
     char: () => /'[^'\\]'/,
@@ -521,6 +899,8 @@ module.exports = grammar({
     doc_string: () => prec(2, token(seq('//!', /.*/))),
     line_comment: () => prec(1, token(seq('//', /.*/))),
 
-    simple_identifier: () => /[a-zA-Z_'][a-zA-Z'\d_$]*/,
+    // The identifiers accepts the unicode letters, like `α`, they are normalized to NFC when
+    // lowered, so the same name written with different code points resolves the same.
+    simple_identifier: () => /[\p{XID_Start}_'][\p{XID_Continue}'$]*/,
   },
 });
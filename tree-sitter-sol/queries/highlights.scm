@@ -48,6 +48,7 @@
 ] @keyword
 
 (line_comment) @comment
+(block_comment) @comment
 (doc_string) @comment
 
 "@" @attribute
@@ -0,0 +1,219 @@
+// The external scanner of Sol, that implements the layout rule of the `do` blocks, like:
+//
+// ```sol
+// for x in xs do
+//   let y = x + 1
+//   println y
+// ```
+//
+// The first token on the line after `do` opens an implicit block at its column, the lines
+// starting at the same column are new statements, and the lines starting at a lesser column,
+// or the closing brackets, close it. The tokens are zero-width, so the whitespace is still
+// lexed by the grammar, and the implicit blocks are parsed as the `block` nodes.
+//
+// It also scans the block comments, like `{- a {- nested -} comment -}`, as their nesting
+// can't be expressed by a regular expression, and closes the parentheses left open at the end
+// of a line, when the next line starts a declaration, so the rest of the file is still parsed.
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "tree_sitter/parser.h"
+
+enum TokenType {
+  LAYOUT_START,
+  LAYOUT_SEMICOLON,
+  LAYOUT_END,
+  BLOCK_COMMENT,
+  IMPLICIT_CLOSE_PAREN,
+};
+
+// The maximum number of nested implicit blocks, that fits in the serialization buffer.
+#define MAX_LAYOUTS (TREE_SITTER_SERIALIZATION_BUFFER_SIZE / sizeof(uint32_t))
+
+typedef struct {
+  // The columns of the open implicit blocks, the innermost is the last.
+  uint32_t columns[MAX_LAYOUTS];
+  uint32_t length;
+} Scanner;
+
+void *tree_sitter_sol_external_scanner_create() {
+  return calloc(1, sizeof(Scanner));
+}
+
+void tree_sitter_sol_external_scanner_destroy(void *payload) { free(payload); }
+
+unsigned tree_sitter_sol_external_scanner_serialize(void *payload,
+                                                    char *buffer) {
+  Scanner *scanner = (Scanner *)payload;
+  unsigned size = scanner->length * sizeof(uint32_t);
+  memcpy(buffer, scanner->columns, size);
+  return size;
+}
+
+void tree_sitter_sol_external_scanner_deserialize(void *payload,
+                                                  const char *buffer,
+                                                  unsigned length) {
+  Scanner *scanner = (Scanner *)payload;
+  scanner->length = length / sizeof(uint32_t);
+  memcpy(scanner->columns, buffer, scanner->length * sizeof(uint32_t));
+}
+
+// Skips the rest of the block comment, after its `{`, including the nested ones. The
+// unterminated comments end at the end of the file.
+static void skip_block_comment(TSLexer *lexer, bool skip) {
+  uint32_t depth = 1;
+  lexer->advance(lexer, skip);
+  while (depth > 0 && !lexer->eof(lexer)) {
+    switch (lexer->lookahead) {
+    case '{':
+      lexer->advance(lexer, skip);
+      if (lexer->lookahead == '-') {
+        lexer->advance(lexer, skip);
+        depth++;
+      }
+      break;
+    case '-':
+      lexer->advance(lexer, skip);
+      if (lexer->lookahead == '}') {
+        lexer->advance(lexer, skip);
+        depth--;
+      }
+      break;
+    default:
+      lexer->advance(lexer, skip);
+      break;
+    }
+  }
+}
+
+// Skips the whitespace before the next token. Returns if there's a line break in it.
+static bool skip_whitespace(TSLexer *lexer) {
+  bool line_break = false;
+  for (;;) {
+    switch (lexer->lookahead) {
+    case '\n':
+      line_break = true;
+      lexer->advance(lexer, true);
+      break;
+    case ' ':
+    case '\t':
+    case '\r':
+      lexer->advance(lexer, true);
+      break;
+    default:
+      return line_break;
+    }
+  }
+}
+
+// Skips the whitespace, and the comments, before the next token, storing its column, and its
+// first character. Returns if there's a line break before it.
+static bool skip_trivia(TSLexer *lexer, uint32_t *column, int32_t *first) {
+  bool line_break = false;
+  for (;;) {
+    line_break |= skip_whitespace(lexer);
+    *column = lexer->get_column(lexer);
+    *first = lexer->lookahead;
+    switch (lexer->lookahead) {
+    case '/':
+      // The division isn't a statement, so it never starts a line of an implicit block.
+      lexer->advance(lexer, true);
+      if (lexer->lookahead != '/') {
+        return line_break;
+      }
+      while (lexer->lookahead != '\n' && !lexer->eof(lexer)) {
+        lexer->advance(lexer, true);
+      }
+      break;
+    case '{':
+      lexer->advance(lexer, true);
+      if (lexer->lookahead != '-') {
+        return line_break;
+      }
+      skip_block_comment(lexer, true);
+      break;
+    default:
+      return line_break;
+    }
+  }
+}
+
+// Checks if the next token closes the brackets that enclose the implicit block.
+static bool is_closing_bracket(int32_t c) {
+  return c == ')' || c == ']' || c == '}';
+}
+
+bool tree_sitter_sol_external_scanner_scan(void *payload, TSLexer *lexer,
+                                           const bool *valid_symbols) {
+  Scanner *scanner = (Scanner *)payload;
+
+  // All the tokens are valid while recovering from errors, so the layout isn't changed.
+  bool recovering = valid_symbols[LAYOUT_START] &&
+                    valid_symbols[LAYOUT_SEMICOLON] && valid_symbols[LAYOUT_END];
+
+  // The layout tokens are zero-width, so they end before the trivia.
+  lexer->mark_end(lexer);
+  bool line_break = skip_whitespace(lexer);
+
+  // The block comments are scanned before the layout, that is decided by the token after
+  // them.
+  uint32_t column = lexer->get_column(lexer);
+  int32_t first = lexer->lookahead;
+  if (first == '{') {
+    lexer->advance(lexer, false);
+    if (lexer->lookahead == '-' && valid_symbols[BLOCK_COMMENT]) {
+      skip_block_comment(lexer, false);
+      lexer->mark_end(lexer);
+      lexer->result_symbol = BLOCK_COMMENT;
+      return true;
+    }
+  }
+  if (recovering) {
+    return false;
+  }
+
+  // The `{` of a block is the next token, otherwise the comments are skipped to find it.
+  if (first != '{') {
+    line_break |= skip_trivia(lexer, &column, &first);
+  }
+  bool in_layout = scanner->length > 0;
+  uint32_t current = in_layout ? scanner->columns[scanner->length - 1] : 0;
+
+  // The declarations start at the first column, so the parentheses can't be still open.
+  if (valid_symbols[IMPLICIT_CLOSE_PAREN] && first != ')' &&
+      (lexer->eof(lexer) || (line_break && column == 0))) {
+    lexer->result_symbol = IMPLICIT_CLOSE_PAREN;
+    return true;
+  }
+
+  if (valid_symbols[LAYOUT_END] && in_layout) {
+    if (lexer->eof(lexer) || is_closing_bracket(first) ||
+        (line_break && column < current)) {
+      scanner->length--;
+      lexer->result_symbol = LAYOUT_END;
+      return true;
+    }
+  }
+
+  if (valid_symbols[LAYOUT_SEMICOLON] && in_layout) {
+    if (line_break && column == current && !lexer->eof(lexer)) {
+      lexer->result_symbol = LAYOUT_SEMICOLON;
+      return true;
+    }
+  }
+
+  // The implicit blocks are only opened by the tokens on the next lines, so `do` followed by
+  // an expression on the same line is a regular body.
+  if (valid_symbols[LAYOUT_START] && line_break && !lexer->eof(lexer) &&
+      first != '{' && (!in_layout || column > current) &&
+      scanner->length < MAX_LAYOUTS) {
+    scanner->columns[scanner->length++] = column;
+    lexer->result_symbol = LAYOUT_START;
+    return true;
+  }
+
+  return false;
+}